@@ -1,9 +1,11 @@
-use soroban_sdk::{contracttype, Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol};
+use stellar_swipe_common::emergency::{
+    CircuitBreakerConfig, CircuitBreakerStats, PauseState, CAT_ALL,
+};
 
 use crate::errors::AutoTradeError;
 use crate::storage::{self, RateLimitInfo};
 
-// Constants
 /// Rate limit duration: 720 ledgers ≈ 1 hour (assuming 5-second block time)
 pub const RATE_LIMIT_DURATION_LEDGERS: u64 = 720;
 
@@ -11,42 +13,64 @@ pub const RATE_LIMIT_DURATION_LEDGERS: u64 = 720;
 pub const RATE_LIMIT_DURATION_SECONDS: u64 = 3600;
 
 #[contracttype]
-#[derive(Clone)]
 pub enum AdminStorageKey {
     Admin,
     Operator,
+    Guardian,
+    OracleAddress,
+    OracleCircuitBreaker,
+    OracleWhitelist(u32), // keyed by asset_pair
+    OracleAgreementConfig,
+    OracleReport(u32, Address), // latest push per (asset_pair, oracle)
+    PauseStates,
+    CircuitBreakerStats,
+    CircuitBreakerConfig,
+    PendingAdmin,
+    PendingAdminExpiry,
+    PreventSelfDestruct,
 }
 
-/// Initialize admin (called once at contract deployment)
-pub fn init_admin(env: &Env, admin: Address) -> Result<(), AutoTradeError> {
-    if has_admin(env) {
-        return Err(AutoTradeError::Unauthorized);
+pub fn init_admin(env: &Env, admin: Address) {
+    if env.storage().instance().has(&AdminStorageKey::Admin) {
+        panic!("Already initialized");
     }
+    env.storage()
+        .instance()
+        .set(&AdminStorageKey::Admin, &admin);
 
-    env.storage().instance().set(&AdminStorageKey::Admin, &admin);
-    Ok(())
-}
+    // Self-destruct protection enabled by default.
+    env.storage()
+        .instance()
+        .set(&AdminStorageKey::PreventSelfDestruct, &true);
 
-/// Check if admin is initialized
-pub fn has_admin(env: &Env) -> bool {
-    env.storage().instance().has(&AdminStorageKey::Admin)
-}
+    let states: Map<String, PauseState> = Map::new(env);
+    env.storage()
+        .instance()
+        .set(&AdminStorageKey::PauseStates, &states);
 
-/// Get current admin
-pub fn get_admin(env: &Env) -> Result<Address, AutoTradeError> {
+    let stats = CircuitBreakerStats {
+        attempts_window: 0,
+        failures_window: 0,
+        window_start: env.ledger().timestamp(),
+        volume_1h: 0,
+        volume_24h_avg: 0,
+        last_price: 0,
+        last_price_time: 0,
+    };
     env.storage()
         .instance()
-        .get(&AdminStorageKey::Admin)
-        .ok_or(AutoTradeError::Unauthorized)
+        .set(&AdminStorageKey::CircuitBreakerStats, &stats);
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&AdminStorageKey::Admin)
 }
 
-/// Require caller is admin
 pub fn require_admin(env: &Env, caller: &Address) -> Result<(), AutoTradeError> {
-    let admin = get_admin(env)?;
+    let admin = get_admin(env).ok_or(AutoTradeError::Unauthorized)?;
     if caller != &admin {
         return Err(AutoTradeError::Unauthorized);
     }
-    caller.require_auth();
     Ok(())
 }
 
@@ -61,6 +85,7 @@ pub fn get_operator(env: &Env) -> Result<Address, AutoTradeError> {
 /// Set operator (admin only)
 pub fn set_operator(env: &Env, caller: &Address, operator: Address) -> Result<(), AutoTradeError> {
     require_admin(env, caller)?;
+    caller.require_auth();
 
     env.storage()
         .instance()
@@ -108,70 +133,45 @@ pub fn set_rate_limited(
     #[allow(deprecated)]
     env.events().publish(
         (Symbol::new(env, "user_rate_limited"), user.clone()),
-use soroban_sdk::{contracttype, Address, Env, Map, String};
-use stellar_swipe_common::emergency::{
-    CircuitBreakerConfig, CircuitBreakerStats, PauseState, CAT_ALL, CAT_TRADING,
-};
-
-use crate::errors::AutoTradeError;
+        expires_at,
+    );
 
-#[contracttype]
-pub enum AdminStorageKey {
-    Admin,
-    Guardian,
-    OracleAddress,
-    OracleCircuitBreaker,
-    OracleWhitelist(u32), // keyed by asset_pair
-    PauseStates,
-    CircuitBreakerStats,
-    CircuitBreakerConfig,
-    PendingAdmin,
-    PendingAdminExpiry,
-    PreventSelfDestruct,
+    Ok(())
 }
 
-pub fn init_admin(env: &Env, admin: Address) {
-    if env.storage().instance().has(&AdminStorageKey::Admin) {
-        panic!("Already initialized");
-    }
-    env.storage()
-        .instance()
-        .set(&AdminStorageKey::Admin, &admin);
+/// Clear rate limit flag for a user (operator only)
+pub fn clear_rate_limited(
+    env: &Env,
+    caller: &Address,
+    user: &Address,
+) -> Result<(), AutoTradeError> {
+    require_operator(env, caller)?;
 
-    // Self-destruct protection enabled by default.
-    env.storage()
-        .instance()
-        .set(&AdminStorageKey::PreventSelfDestruct, &true);
+    let info = RateLimitInfo {
+        user: user.clone(),
+        is_limited: false,
+        expires_at: 0,
+    };
 
-    let states: Map<String, PauseState> = Map::new(env);
-    env.storage()
-        .instance()
-        .set(&AdminStorageKey::PauseStates, &states);
+    storage::set_rate_limit_info(env, user, &info);
 
-    let stats = CircuitBreakerStats {
-        attempts_window: 0,
-        failures_window: 0,
-        window_start: env.ledger().timestamp(),
-        volume_1h: 0,
-        volume_24h_avg: 0,
-        last_price: 0,
-        last_price_time: 0,
-    };
-    env.storage()
-        .instance()
-        .set(&AdminStorageKey::CircuitBreakerStats, &stats);
+    #[allow(deprecated)]
+    env.events().publish(
+        (Symbol::new(env, "user_rate_limit_cleared"), user.clone()),
+        (),
+    );
+
+    Ok(())
 }
 
-pub fn get_admin(env: &Env) -> Option<Address> {
-    env.storage().instance().get(&AdminStorageKey::Admin)
+/// Get rate limit info for a user
+pub fn get_rate_limit_info(env: &Env, user: &Address) -> Option<RateLimitInfo> {
+    storage::get_rate_limit_info(env, user)
 }
 
-pub fn require_admin(env: &Env, caller: &Address) -> Result<(), AutoTradeError> {
-    let admin = get_admin(env).ok_or(AutoTradeError::Unauthorized)?;
-    if caller != &admin {
-        return Err(AutoTradeError::Unauthorized);
-    }
-    Ok(())
+/// Check if user is rate limited (and auto-expire if necessary)
+pub fn is_rate_limited(env: &Env, user: &Address) -> bool {
+    storage::is_rate_limited(env, user)
 }
 
 pub fn set_guardian(env: &Env, caller: &Address, guardian: Address) -> Result<(), AutoTradeError> {
@@ -400,25 +400,6 @@ pub fn propose_admin_transfer(
     Ok(())
 }
 
-/// Clear rate limit flag for a user (operator only)
-pub fn clear_rate_limited(
-    env: &Env,
-    caller: &Address,
-    user: &Address,
-) -> Result<(), AutoTradeError> {
-    require_operator(env, caller)?;
-
-    let info = RateLimitInfo {
-        user: user.clone(),
-        is_limited: false,
-        expires_at: 0,
-    };
-
-    storage::set_rate_limit_info(env, user, &info);
-
-    #[allow(deprecated)]
-    env.events().publish(
-        (Symbol::new(env, "user_rate_limit_cleared"), user.clone()),
 /// Accept admin transfer (called by new admin)
 pub fn accept_admin_transfer(env: &Env, caller: &Address) -> Result<(), AutoTradeError> {
     caller.require_auth();
@@ -475,17 +456,6 @@ pub fn accept_admin_transfer(env: &Env, caller: &Address) -> Result<(), AutoTrad
     Ok(())
 }
 
-/// Get rate limit info for a user
-pub fn get_rate_limit_info(
-    env: &Env,
-    user: &Address,
-) -> Option<RateLimitInfo> {
-    storage::get_rate_limit_info(env, user)
-}
-
-/// Check if user is rate limited (and auto-expire if necessary)
-pub fn is_rate_limited(env: &Env, user: &Address) -> bool {
-    storage::is_rate_limited(env, user)
 /// Cancel pending admin transfer (current admin only)
 pub fn cancel_admin_transfer(env: &Env, caller: &Address) -> Result<(), AutoTradeError> {
     require_admin(env, caller)?;
@@ -123,7 +123,7 @@ pub fn process_price_update(
     let liquidity_key = (symbol_short!("asset_liq"), asset_id);
     let available_liquidity: i128 = env
         .storage()
-        .temporary()
+        .persistent()
         .get(&liquidity_key)
         .unwrap_or(position.amount);
     let sold_amount = core::cmp::min(position.amount, available_liquidity.max(0));
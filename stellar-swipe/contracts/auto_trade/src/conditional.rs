@@ -367,16 +367,21 @@ mod tests {
     use super::*;
     use crate::risk::RiskDataKey;
     use soroban_sdk::{
+        contract,
         testutils::{Address as _, Ledger as _},
         Env,
     };
 
-    fn setup() -> (Env, Address) {
+    #[contract]
+    struct TestContract;
+
+    fn setup() -> (Env, Address, Address) {
         let env = Env::default();
         env.mock_all_auths();
         env.ledger().set_timestamp(1_000);
         let user = Address::generate(&env);
-        (env, user)
+        let contract = env.register(TestContract, ());
+        (env, user, contract)
     }
 
     fn set_price(env: &Env, asset_id: u32, price: i128) {
@@ -395,41 +400,50 @@ mod tests {
 
     #[test]
     fn test_create_and_get() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         let conditions = simple_price_condition(&env, 1, PriceDirection::Above, 110_000);
         let id = create_conditional_order(&env, user.clone(), 1, ConditionalSide::Buy, 1_000, 0, conditions, LogicOp::And, 3_600).unwrap();
         let order = get_conditional_order(&env, id).unwrap();
         assert_eq!(order.status, ConditionalStatus::Pending);
         assert_eq!(order.reference_price, 100_000);
+        });
     }
 
     #[test]
     fn test_cancel_order() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        let id = env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         let conditions = simple_price_condition(&env, 1, PriceDirection::Above, 110_000);
-        let id = create_conditional_order(&env, user.clone(), 1, ConditionalSide::Buy, 1_000, 0, conditions, LogicOp::And, 3_600).unwrap();
+        create_conditional_order(&env, user.clone(), 1, ConditionalSide::Buy, 1_000, 0, conditions, LogicOp::And, 3_600).unwrap()
+        });
+        env.as_contract(&contract, || {
         cancel_conditional_order(&env, id, user).unwrap();
         let order = get_conditional_order(&env, id).unwrap();
         assert_eq!(order.status, ConditionalStatus::Cancelled);
+        });
     }
 
     #[test]
     fn test_cancel_wrong_user_fails() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         let other = Address::generate(&env);
         set_price(&env, 1, 100_000);
         let conditions = simple_price_condition(&env, 1, PriceDirection::Above, 110_000);
         let id = create_conditional_order(&env, user.clone(), 1, ConditionalSide::Buy, 1_000, 0, conditions, LogicOp::And, 3_600).unwrap();
         assert_eq!(cancel_conditional_order(&env, id, other), Err(AutoTradeError::Unauthorized));
+        });
     }
 
     // ── price trigger ─────────────────────────────────────────────────────────
 
     #[test]
     fn test_price_above_triggers() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         let conditions = simple_price_condition(&env, 1, PriceDirection::Above, 110_000);
         let id = create_conditional_order(&env, user.clone(), 1, ConditionalSide::Buy, 1_000, 0, conditions, LogicOp::And, 3_600).unwrap();
@@ -444,11 +458,13 @@ mod tests {
         assert_eq!(triggered.len(), 1);
         assert_eq!(triggered.get(0).unwrap(), id);
         assert_eq!(get_conditional_order(&env, id).unwrap().status, ConditionalStatus::Triggered);
+        });
     }
 
     #[test]
     fn test_price_below_triggers() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         let conditions = simple_price_condition(&env, 1, PriceDirection::Below, 90_000);
         let id = create_conditional_order(&env, user.clone(), 1, ConditionalSide::Sell, 500, 0, conditions, LogicOp::And, 3_600).unwrap();
@@ -457,13 +473,15 @@ mod tests {
         let triggered = check_and_trigger(&env);
         assert_eq!(triggered.len(), 1);
         assert_eq!(triggered.get(0).unwrap(), id);
+        });
     }
 
     // ── time trigger ──────────────────────────────────────────────────────────
 
     #[test]
     fn test_time_after_triggers() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         let mut conditions = Vec::new(&env);
         conditions.push_back(Condition::TimeAfter(2_000));
@@ -477,13 +495,15 @@ mod tests {
         let triggered = check_and_trigger(&env);
         assert_eq!(triggered.len(), 1);
         assert_eq!(triggered.get(0).unwrap(), id);
+        });
     }
 
     // ── drop-rebound trigger ──────────────────────────────────────────────────
 
     #[test]
     fn test_drop_rebound_triggers() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         // Reference price = 100_000
         set_price(&env, 1, 100_000);
         let mut conditions = Vec::new(&env);
@@ -501,13 +521,15 @@ mod tests {
         let triggered = check_and_trigger(&env);
         assert_eq!(triggered.len(), 1);
         assert_eq!(triggered.get(0).unwrap(), id);
+        });
     }
 
     // ── volatility breakout ───────────────────────────────────────────────────
 
     #[test]
     fn test_volatility_breakout_triggers() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         let mut conditions = Vec::new(&env);
         // 5% breakout = 500 bps
@@ -522,13 +544,15 @@ mod tests {
         let triggered = check_and_trigger(&env);
         assert_eq!(triggered.len(), 1);
         assert_eq!(triggered.get(0).unwrap(), id);
+        });
     }
 
     // ── AND / OR logic ────────────────────────────────────────────────────────
 
     #[test]
     fn test_and_logic_requires_all() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         set_price(&env, 2, 50_000);
         let mut conditions = Vec::new(&env);
@@ -546,11 +570,13 @@ mod tests {
         let triggered = check_and_trigger(&env);
         assert_eq!(triggered.len(), 1);
         assert_eq!(triggered.get(0).unwrap(), id);
+        });
     }
 
     #[test]
     fn test_or_logic_requires_one() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         set_price(&env, 2, 50_000);
         let mut conditions = Vec::new(&env);
@@ -563,13 +589,15 @@ mod tests {
         let triggered = check_and_trigger(&env);
         assert_eq!(triggered.len(), 1);
         assert_eq!(triggered.get(0).unwrap(), id);
+        });
     }
 
     // ── expiry ────────────────────────────────────────────────────────────────
 
     #[test]
     fn test_order_expires() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         let conditions = simple_price_condition(&env, 1, PriceDirection::Above, 200_000);
         let id = create_conditional_order(&env, user.clone(), 1, ConditionalSide::Buy, 1_000, 0, conditions, LogicOp::And, 500).unwrap();
@@ -579,13 +607,15 @@ mod tests {
         let triggered = check_and_trigger(&env);
         assert_eq!(triggered.len(), 0);
         assert_eq!(get_conditional_order(&env, id).unwrap().status, ConditionalStatus::Expired);
+        });
     }
 
     // ── mark_executed ─────────────────────────────────────────────────────────
 
     #[test]
     fn test_mark_executed() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 120_000);
         let conditions = simple_price_condition(&env, 1, PriceDirection::Above, 110_000);
         let id = create_conditional_order(&env, user.clone(), 1, ConditionalSide::Buy, 1_000, 0, conditions, LogicOp::And, 3_600).unwrap();
@@ -595,15 +625,18 @@ mod tests {
 
         mark_executed(&env, id).unwrap();
         assert_eq!(get_conditional_order(&env, id).unwrap().status, ConditionalStatus::Executed);
+        });
     }
 
     #[test]
     fn test_mark_executed_wrong_state_fails() {
-        let (env, user) = setup();
+        let (env, user, contract) = setup();
+        env.as_contract(&contract, || {
         set_price(&env, 1, 100_000);
         let conditions = simple_price_condition(&env, 1, PriceDirection::Above, 110_000);
         let id = create_conditional_order(&env, user.clone(), 1, ConditionalSide::Buy, 1_000, 0, conditions, LogicOp::And, 3_600).unwrap();
         // Still Pending — should fail
         assert_eq!(mark_executed(&env, id), Err(AutoTradeError::ConditionalOrderNotTriggered));
+        });
     }
 }
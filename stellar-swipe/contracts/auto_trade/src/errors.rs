@@ -1,4 +1,5 @@
 use soroban_sdk::contracterror;
+use stellar_swipe_common::{ErrorDomain, RemoteError};
 
 /// AutoTrade contract errors (≤ 50 variants — Soroban XDR limit).
 ///
@@ -17,13 +18,7 @@ pub enum AutoTradeError {
     InsufficientLiquidity = 6,
     DailyTradeLimitExceeded = 7,
     PositionLimitExceeded = 8,
-    StopLossTriggered = 9,
-    StrategyNotFound = 10,
-    PositionAlreadyExists = 11,
-    InsufficientPriceHistory = 12,
-    RankingDisabled = 13,
-    RateLimited = 14,
-    PrivacyModeEnabled = 10,
+    PrivacyModeEnabled = 9,
     TradingPaused = 10,
     StrategyNotFound = 11,
     PositionAlreadyExists = 12,
@@ -79,6 +74,9 @@ pub enum AutoTradeError {
     RankingDisabled = 46,
     LastOracleForPair = 47,
     NotPaused = 48,
+    StrategyTagError = 49,
+    // ── Time-in-force orders (NotFound / NotOpen / InvalidTimeInForce) ──────
+    OrderError = 50,
 }
 
 // ── Backward-compatible aliases ───────────────────────────────────────────────
@@ -99,6 +97,7 @@ impl AutoTradeError {
     pub const ConditionalOrderNotPending: AutoTradeError = AutoTradeError::ConditionalOrderError;
     pub const ConditionalOrderNotTriggered: AutoTradeError = AutoTradeError::ConditionalOrderError;
 
+    pub const RateLimited: AutoTradeError = AutoTradeError::RateLimitExceeded;
     pub const RateLimitPenalty: AutoTradeError = AutoTradeError::RateLimitExceeded;
     pub const BelowMinTransfer: AutoTradeError = AutoTradeError::RateLimitExceeded;
     pub const CooldownNotElapsed: AutoTradeError = AutoTradeError::RateLimitExceeded;
@@ -130,4 +129,29 @@ impl AutoTradeError {
     pub const BridgePaused: AutoTradeError = AutoTradeError::SystemError;
     pub const RecoveryNotFound: AutoTradeError = AutoTradeError::SystemError;
     pub const RecoveryIncomplete: AutoTradeError = AutoTradeError::SystemError;
+
+    pub const StrategyTagNotRegistered: AutoTradeError = AutoTradeError::StrategyTagError;
+    pub const StrategyTagLimitExceeded: AutoTradeError = AutoTradeError::StrategyTagError;
+
+    pub const LowOracleAgreement: AutoTradeError = AutoTradeError::OracleUnavailable;
+
+    pub const OrderNotFound: AutoTradeError = AutoTradeError::OrderError;
+    pub const OrderNotOpen: AutoTradeError = AutoTradeError::OrderError;
+    pub const InvalidTimeInForce: AutoTradeError = AutoTradeError::OrderError;
+}
+
+/// Map a failed call into another contract onto the closest local variant,
+/// keeping the oracle's and the registry's answers apart.
+impl From<RemoteError> for AutoTradeError {
+    fn from(error: RemoteError) -> Self {
+        if error.is_signal_not_found() {
+            AutoTradeError::SignalNotFound
+        } else if error.is_signal_expired() {
+            AutoTradeError::SignalExpired
+        } else if error.domain == ErrorDomain::Oracle {
+            AutoTradeError::OracleUnavailable
+        } else {
+            AutoTradeError::SystemError
+        }
+    }
 }
@@ -0,0 +1,157 @@
+#![allow(dead_code)]
+//! Per-pair execution quality analytics.
+//!
+//! Every `execute_trade` call folds its outcome into running totals for the
+//! signal's asset pair so users can see where copy-trading actually executes
+//! well: average realized spread against the oracle mid, average fill ratio
+//! and how often orders only partially fill.
+
+use soroban_sdk::{contracttype, Env};
+
+#[contracttype]
+pub enum ExecutionStatsKey {
+    Pair(u32),
+}
+
+/// Running totals stored per pair.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PairExecutionTotals {
+    pub trade_count: u32,
+    pub partial_fill_count: u32,
+    pub failed_count: u32,
+    /// Sum of `executed / requested` in bps over all trades.
+    pub fill_ratio_bps_sum: i128,
+    /// Number of fills that had an oracle mid to compare against.
+    pub spread_samples: u32,
+    /// Sum of `|executed_price - oracle_mid| / oracle_mid` in bps.
+    pub spread_bps_sum: i128,
+}
+
+/// Averages returned by `get_pair_execution_stats`.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PairExecutionStats {
+    pub trade_count: u32,
+    /// Average realized spread vs oracle mid in bps (0 when no samples).
+    pub avg_spread_bps: i128,
+    /// Average fill ratio in bps (10_000 = always fully filled).
+    pub avg_fill_ratio_bps: i128,
+    /// Share of trades that were partially filled, in bps.
+    pub partial_fill_rate_bps: i128,
+    /// Share of trades that filled nothing, in bps.
+    pub failure_rate_bps: i128,
+}
+
+fn get_totals(env: &Env, pair: u32) -> PairExecutionTotals {
+    env.storage()
+        .persistent()
+        .get(&ExecutionStatsKey::Pair(pair))
+        .unwrap_or_default()
+}
+
+/// Fold one execution into the pair's totals. `oracle_mid` is `None` when no
+/// oracle price was available, in which case only fill metrics are updated.
+pub fn record_execution(
+    env: &Env,
+    pair: u32,
+    requested_amount: i128,
+    executed_amount: i128,
+    executed_price: i128,
+    oracle_mid: Option<i128>,
+) {
+    if requested_amount <= 0 {
+        return;
+    }
+
+    let mut totals = get_totals(env, pair);
+    totals.trade_count += 1;
+
+    if executed_amount == 0 {
+        totals.failed_count += 1;
+    } else if executed_amount < requested_amount {
+        totals.partial_fill_count += 1;
+    }
+
+    let filled = executed_amount.min(requested_amount).max(0);
+    totals.fill_ratio_bps_sum += filled * 10_000 / requested_amount;
+
+    if let Some(mid) = oracle_mid {
+        if mid > 0 && executed_amount > 0 {
+            totals.spread_samples += 1;
+            totals.spread_bps_sum += (executed_price - mid).abs() * 10_000 / mid;
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&ExecutionStatsKey::Pair(pair), &totals);
+}
+
+/// Averaged execution quality for `pair`.
+pub fn get_pair_execution_stats(env: &Env, pair: u32) -> PairExecutionStats {
+    let totals = get_totals(env, pair);
+    if totals.trade_count == 0 {
+        return PairExecutionStats::default();
+    }
+
+    let trades = totals.trade_count as i128;
+    let avg_spread_bps = if totals.spread_samples == 0 {
+        0
+    } else {
+        totals.spread_bps_sum / totals.spread_samples as i128
+    };
+
+    PairExecutionStats {
+        trade_count: totals.trade_count,
+        avg_spread_bps,
+        avg_fill_ratio_bps: totals.fill_ratio_bps_sum / trades,
+        partial_fill_rate_bps: totals.partial_fill_count as i128 * 10_000 / trades,
+        failure_rate_bps: totals.failed_count as i128 * 10_000 / trades,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn empty_pair_has_zero_stats() {
+        let env = Env::default();
+        let contract_addr = env.register(TestContract, ());
+        env.as_contract(&contract_addr, || {
+            assert_eq!(get_pair_execution_stats(&env, 1), PairExecutionStats::default());
+        });
+    }
+
+    #[test]
+    fn aggregates_spread_fill_ratio_and_partials() {
+        let env = Env::default();
+        let contract_addr = env.register(TestContract, ());
+        env.as_contract(&contract_addr, || {
+            // Full fill, 1% above mid.
+            record_execution(&env, 1, 1_000, 1_000, 101, Some(100));
+            // Half fill, 3% below mid.
+            record_execution(&env, 1, 1_000, 500, 97, Some(100));
+            // Full fill with no oracle price: not a spread sample.
+            record_execution(&env, 1, 1_000, 1_000, 100, None);
+            // Nothing filled.
+            record_execution(&env, 1, 1_000, 0, 0, Some(100));
+            // Other pairs are tracked separately.
+            record_execution(&env, 2, 1_000, 1_000, 100, Some(100));
+
+            let stats = get_pair_execution_stats(&env, 1);
+            assert_eq!(stats.trade_count, 4);
+            assert_eq!(stats.avg_spread_bps, 200);
+            assert_eq!(stats.avg_fill_ratio_bps, 6_250);
+            assert_eq!(stats.partial_fill_rate_bps, 2_500);
+            assert_eq!(stats.failure_rate_bps, 2_500);
+
+            assert_eq!(get_pair_execution_stats(&env, 2).trade_count, 1);
+        });
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
 
 use crate::errors::AutoTradeError;
 
@@ -196,7 +196,7 @@ pub fn check_and_execute_exits(
 
         if let Some(trail_pct) = tightest_trail {
             let stop_price = calculate_trailing_stop(strategy.highest_price, trail_pct);
-            if current_price <= stop_price && strategy.current_position_size > 0 {
+            if current_price < stop_price && strategy.current_position_size > 0 {
                 let trade_id = execute_sell(
                     env,
                     &strategy.user,
@@ -511,9 +511,10 @@ mod tests {
             // entry = 1000, trail 10% from start
             let id = preset_conservative(&env, user, 1, 1_000, 10_000).unwrap();
 
-            // Price rises to 1100 (no TP hit), then drops 10% → stop at 990
+            // Price rises to 1100 (no TP hit), then drops below the stop at 990
             check_and_execute_exits(&env, id, 1_100).unwrap();
-            let trades = check_and_execute_exits(&env, id, 990).unwrap();
+            assert_eq!(check_and_execute_exits(&env, id, 990).unwrap().len(), 0);
+            let trades = check_and_execute_exits(&env, id, 989).unwrap();
             assert_eq!(trades.len(), 1);
 
             let s = get_exit_strategy(&env, id).unwrap();
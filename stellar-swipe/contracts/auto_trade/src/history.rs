@@ -4,7 +4,10 @@
 //! Stores all executed trades per user with full details.
 //! Gas: ~O(limit) per get_trade_history query.
 
-use soroban_sdk::{contracttype, Address, Env, Vec};
+use soroban_sdk::{contracttype, Address, Env, Map, Symbol, TryFromVal, Val, Vec};
+use stellar_swipe_common::SignalRef;
+
+use crate::storage;
 /// Default page size for trade history
 pub const DEFAULT_HISTORY_LIMIT: u32 = 20;
 
@@ -25,12 +28,48 @@ pub enum HistoryTradeStatus {
 pub struct HistoryTrade {
     pub id: u64,
     pub signal_id: u64,
+    /// Chain-wide reference to the executed signal.
+    pub signal_ref: SignalRef,
     pub base_asset: u32,
     pub amount: i128,
     pub price: i128,
     pub fee: i128,
     pub timestamp: u64,
     pub status: HistoryTradeStatus,
+    /// Executor-defined strategy label, if the trade was tagged.
+    pub strategy_tag: Option<Symbol>,
+}
+
+/// `HistoryTrade` as written before trades carried a `signal_ref` and
+/// `strategy_tag`. Records in this layout are upgraded on read.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct HistoryTradeV1 {
+    pub id: u64,
+    pub signal_id: u64,
+    pub base_asset: u32,
+    pub amount: i128,
+    pub price: i128,
+    pub fee: i128,
+    pub timestamp: u64,
+    pub status: HistoryTradeStatus,
+}
+
+impl HistoryTradeV1 {
+    fn upgrade(self, env: &Env) -> HistoryTrade {
+        HistoryTrade {
+            id: self.id,
+            signal_id: self.signal_id,
+            signal_ref: storage::signal_ref(env, self.signal_id),
+            base_asset: self.base_asset,
+            amount: self.amount,
+            price: self.price,
+            fee: self.fee,
+            timestamp: self.timestamp,
+            status: self.status,
+            strategy_tag: None,
+        }
+    }
 }
 
 #[contracttype]
@@ -58,6 +97,7 @@ pub fn record_trade(
     price: i128,
     fee: i128,
     status: HistoryTradeStatus,
+    strategy_tag: Option<Symbol>,
 ) -> u64 {
     let count = get_user_trade_count(env, user);
     let id = count;
@@ -65,12 +105,14 @@ pub fn record_trade(
     let trade = HistoryTrade {
         id,
         signal_id,
+        signal_ref: storage::signal_ref(env, signal_id),
         base_asset,
         amount,
         price,
         fee,
         timestamp: env.ledger().timestamp(),
         status,
+        strategy_tag,
     };
 
     env.storage()
@@ -85,11 +127,20 @@ pub fn record_trade(
     id
 }
 
-/// Get trade by user and index
+/// Get trade by user and index, upgrading records in the v1 layout.
 fn get_trade_by_index(env: &Env, user: &Address, index: u64) -> Option<HistoryTrade> {
-    env.storage()
+    let raw: Val = env
+        .storage()
         .persistent()
-        .get(&HistoryDataKey::Trade(user.clone(), index))
+        .get(&HistoryDataKey::Trade(user.clone(), index))?;
+    let fields = Map::<Symbol, Val>::try_from_val(env, &raw).ok()?;
+    if fields.contains_key(Symbol::new(env, "signal_ref")) {
+        HistoryTrade::try_from_val(env, &raw).ok()
+    } else {
+        HistoryTradeV1::try_from_val(env, &raw)
+            .ok()
+            .map(|v1| v1.upgrade(env))
+    }
 }
 
 /// Get trade history for user, newest first, with pagination.
@@ -128,3 +179,65 @@ pub fn get_trade_history(env: &Env, user: &Address, offset: u32, limit: u32) ->
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, symbol_short};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn test_reads_v1_trades() {
+        let env = Env::default();
+        let contract_id = env.register(TestContract, ());
+        let user = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            let old = HistoryTradeV1 {
+                id: 0,
+                signal_id: 7,
+                base_asset: 1,
+                amount: 500,
+                price: 100,
+                fee: 2,
+                timestamp: 900,
+                status: HistoryTradeStatus::Executed,
+            };
+            env.storage()
+                .persistent()
+                .set(&HistoryDataKey::Trade(user.clone(), 0), &old);
+            env.storage()
+                .persistent()
+                .set(&HistoryDataKey::UserTradeCount(user.clone()), &1u64);
+
+            record_trade(
+                &env,
+                &user,
+                8,
+                1,
+                300,
+                110,
+                1,
+                HistoryTradeStatus::Executed,
+                Some(symbol_short!("swing")),
+            );
+
+            let history = get_trade_history(&env, &user, 0, 10);
+            assert_eq!(history.len(), 2);
+
+            let new = history.get(0).unwrap();
+            assert_eq!(new.signal_id, 8);
+            assert_eq!(new.strategy_tag, Some(symbol_short!("swing")));
+
+            let upgraded = history.get(1).unwrap();
+            assert_eq!(upgraded.signal_id, 7);
+            assert_eq!(upgraded.amount, 500);
+            assert_eq!(upgraded.timestamp, 900);
+            assert_eq!(upgraded.signal_ref, storage::signal_ref(&env, 7));
+            assert_eq!(upgraded.strategy_tag, None);
+        });
+    }
+}
@@ -5,7 +5,7 @@
 
 #![allow(dead_code)]
 
-use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
 
 /// Order side (buy or sell)
 #[contracttype]
@@ -26,13 +26,8 @@ pub enum OrderStatus {
     Failed,
 }
 
-/// Asset pair
-#[contracttype]
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct AssetPair {
-    pub base: String,
-    pub quote: String,
-}
+/// Asset pair, shared with the oracle and signal registry.
+pub use stellar_swipe_common::AssetPair;
 
 /// Iceberg order structure
 #[contracttype]
@@ -564,7 +559,7 @@ fn place_sdex_limit_order(
 }
 
 /// Cancel SDEX order
-fn cancel_sdex_order(env: &Env, _sdex_order_id: u64) -> Result<(), String> {
+fn cancel_sdex_order(_env: &Env, _sdex_order_id: u64) -> Result<(), String> {
     // Placeholder - would integrate with actual SDEX
     Ok(())
 }
@@ -588,10 +583,7 @@ mod tests {
     }
 
     fn create_test_pair(env: &Env) -> AssetPair {
-        AssetPair {
-            base: String::from_str(env, "XLM"),
-            quote: String::from_str(env, "USDC"),
-        }
+        AssetPair::parse(env, &String::from_str(env, "XLM/USDC")).unwrap()
     }
 
     #[test]
@@ -1,17 +1,14 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol};
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec};
 
 mod admin;
 mod advanced_risk;
-#[cfg(not(feature = "testutils"))]
-mod auth;
-#[cfg(feature = "testutils")]
 pub mod auth;
 mod conditional;
 mod correlation;
 mod errors;
+mod execution_stats;
 mod exit_strategy;
 mod history;
 mod iceberg;
@@ -19,29 +16,25 @@ mod multi_asset;
 mod oracle;
 mod portfolio;
 mod portfolio_insurance;
-#[cfg(not(feature = "testutils"))]
-mod positions;
-#[cfg(feature = "testutils")]
 pub mod positions;
-#[cfg(not(feature = "testutils"))]
-mod rate_limit;
-#[cfg(feature = "testutils")]
 pub mod rate_limit;
 mod referral;
 mod risk;
 mod risk_parity;
 mod sdex;
 mod smart_routing;
-#[cfg(not(feature = "testutils"))]
-mod storage;
-#[cfg(feature = "testutils")]
 pub mod storage;
 mod strategies;
+mod strategy_tags;
+mod time_in_force;
 mod twap;
+mod watch_only;
 
 pub use errors::AutoTradeError;
 pub use risk::RiskConfig;
 
+pub use storage::SignalAction;
+
 #[cfg(feature = "testutils")]
 pub use storage::{authorize_user_with_limits, set_signal, Signal};
 #[cfg(feature = "testutils")]
@@ -49,8 +42,8 @@ pub use auth::AuthConfig;
 
 use crate::storage::DataKey;
 use advanced_risk::AutoSellResult;
-use stellar_swipe_common::emergency::{CAT_ALL, CAT_TRADING, PauseState};
-use stellar_swipe_common::{health_uninitialized, HealthStatus};
+use stellar_swipe_common::emergency::{CAT_TRADING, PauseState};
+use stellar_swipe_common::SignalRef;
 
 use risk_parity::{AssetRisk, RebalanceTrade};
 
@@ -60,13 +53,14 @@ pub use iceberg::{
     FullOrderView, IcebergOrder, OrderSide, OrderStatus, PublicOrderView,
 };
 pub use smart_routing::{LiquidityVenue, RouteSegment, RoutingPlan, VenueLiquidity};
+pub use time_in_force::{PlacedOrder, RestingOrder, RestingOrderStatus, TimeInForce};
 
 /// ==========================
 /// Types
 /// ==========================
 
 #[contracttype]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum OrderType {
     Market,
     Limit,
@@ -85,6 +79,8 @@ pub enum TradeStatus {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Trade {
     pub signal_id: u64,
+    /// Chain-wide reference to the executed signal.
+    pub signal_ref: SignalRef,
     pub user: Address,
     pub requested_amount: i128,
     pub executed_amount: i128,
@@ -153,6 +149,10 @@ impl AutoTradeContract {
             return failed_simulation(&env, "signal_expired");
         }
 
+        if !oracle::get_agreement_gate(&env, signal.base_asset).auto_copy_allowed {
+            return failed_simulation(&env, "low_oracle_agreement");
+        }
+
         if !auth::is_authorized(&env, &user, amount) {
             return failed_simulation(&env, "unauthorized");
         }
@@ -283,6 +283,31 @@ impl AutoTradeContract {
         oracle::get_oracle_address(&env)
     }
 
+    /// Set the signal registry whose ids this contract's signals refer to
+    /// (admin only). Trade receipts, history and positions record signals as
+    /// `SignalRef { registry, signal_id }`; without a registry the contract's
+    /// own address is used.
+    pub fn set_signal_registry(
+        env: Env,
+        caller: Address,
+        registry: Address,
+    ) -> Result<(), AutoTradeError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        storage::set_signal_registry(&env, &registry);
+        Ok(())
+    }
+
+    /// Get the configured signal registry, if any.
+    pub fn get_signal_registry(env: Env) -> Option<Address> {
+        storage::get_signal_registry(&env)
+    }
+
+    /// Chain-wide reference for a signal id of this deployment.
+    pub fn get_signal_ref(env: Env, signal_id: u64) -> SignalRef {
+        storage::signal_ref(&env, signal_id)
+    }
+
     /// Admin override for the oracle circuit breaker.
     /// When `enabled = true`, trading proceeds even if the oracle is unavailable.
     /// When `enabled = false`, the normal circuit breaker logic applies.
@@ -342,6 +367,28 @@ impl AutoTradeContract {
         oracle::push_price_update(&env, &caller, asset_pair, price)
     }
 
+    /// Set the minimum oracle agreement a pair needs before `execute_trade`
+    /// copies signals on it (admin only). A `min_score_bps` of 0 disables
+    /// the gate.
+    pub fn set_agreement_config(
+        env: Env,
+        caller: Address,
+        config: oracle::AgreementConfig,
+    ) -> Result<(), AutoTradeError> {
+        oracle::set_agreement_config(&env, &caller, config)
+    }
+
+    pub fn get_agreement_config(env: Env) -> oracle::AgreementConfig {
+        oracle::get_agreement_config(&env)
+    }
+
+    /// Current oracle agreement on `asset_pair` and whether automatic copying
+    /// of its signals is allowed. Signals on a gated pair can still be
+    /// created and their trades recorded manually.
+    pub fn get_agreement_gate(env: Env, asset_pair: u32) -> oracle::AgreementGate {
+        oracle::get_agreement_gate(&env, asset_pair)
+    }
+
     /// Set the circuit breaker configuration (admin only)
     pub fn set_circuit_breaker_config(
         env: Env,
@@ -356,6 +403,11 @@ impl AutoTradeContract {
     /// circuit-breaker check, risk validation (stop-loss, position limits,
     /// daily trade limit), smart routing, and records the trade.
     ///
+    /// The trade side follows the signal's action: buys add to the user's
+    /// position, sells reduce it and may leave it short, in which case the
+    /// short counts against the position limit like a long would. Sell limit
+    /// orders fill when the market is at or above the signal price.
+    ///
     /// # Parameters
     /// - `env`: Soroban environment.
     /// - `user`: Address of the trader (must authorize).
@@ -368,7 +420,8 @@ impl AutoTradeContract {
     ///
     /// # Errors
     /// - [`AutoTradeError::TradingPaused`] — trading category is paused.
-    /// - [`AutoTradeError::OracleUnavailable`] — oracle circuit breaker is tripped.
+    /// - [`AutoTradeError::OracleUnavailable`] — oracle circuit breaker is tripped,
+    ///   or the signal's pair is below the minimum oracle agreement.
     /// - [`AutoTradeError::InvalidAmount`] — amount <= 0.
     /// - [`AutoTradeError::SignalNotFound`] — signal_id does not exist.
     /// - [`AutoTradeError::SignalExpired`] — signal has expired.
@@ -389,185 +442,26 @@ impl AutoTradeContract {
         order_type: OrderType,
         amount: i128,
     ) -> Result<TradeResult, AutoTradeError> {
-        if admin::is_paused(&env, String::from_str(&env, CAT_TRADING)) {
-            return Err(AutoTradeError::TradingPaused);
-        }
-
-        // Oracle circuit breaker: halt if oracle is unavailable (unless admin override)
-        oracle::check_oracle_circuit_breaker(&env, signal_id as u32)?;
-
-        if amount <= 0 {
-            return Err(AutoTradeError::InvalidAmount);
-        }
-
-        user.require_auth();
-
-        // Check if user is rate limited
-        if admin::is_rate_limited(&env, &user) {
-            return Err(AutoTradeError::RateLimited);
-        }
-
-        let signal = storage::get_signal(&env, signal_id).ok_or(AutoTradeError::SignalNotFound)?;
-
-        if env.ledger().timestamp() > signal.expiry {
-            return Err(AutoTradeError::SignalExpired);
-        }
-
-        if !auth::is_authorized(&env, &user, amount) {
-            return Err(AutoTradeError::Unauthorized);
-        }
-
-        rate_limit::check_rate_limits(&env, &user, amount)?;
-
-        if !sdex::has_sufficient_balance(&env, &user, &signal.base_asset, amount) {
-            return Err(AutoTradeError::InsufficientBalance);
-        }
-
-        let is_sell = false;
-
-        risk::set_asset_price(&env, signal.base_asset, signal.price);
-
-        // Fetch oracle price for manipulation-resistant stop-loss evaluation.
-        // Falls back to None (SDEX spot) when no oracle is configured.
-        let oracle_price: Option<i128> = oracle::get_oracle_price(&env, signal.base_asset)
-            .ok()
-            .map(|op| oracle::oracle_price_to_i128(&op));
-
-        // Perform risk checks
-        let stop_loss_triggered = risk::validate_trade(
-            &env,
-            &user,
-            signal.base_asset,
-            amount,
-            signal.price,
-            is_sell,
-            oracle_price,
-        )?;
-
-        if stop_loss_triggered {
-            #[allow(deprecated)]
-            env.events().publish(
-                (
-                    Symbol::new(&env, "stop_loss_triggered"),
-                    user.clone(),
-                    signal.base_asset,
-                ),
-                signal.price,
-            );
-        }
-
-        let execution = match order_type {
-            OrderType::Market => {
-                match smart_routing::execute_best_route(&env, &signal, amount, 500) {
-                    Ok(result) => result,
-                    Err(AutoTradeError::RoutingPlanNotFound) => {
-                        sdex::execute_market_order(&env, &user, &signal, amount)?
-                    }
-                    Err(err) => return Err(err),
-                }
-            }
-            OrderType::Limit => sdex::execute_limit_order(&env, &user, &signal, amount)?,
-        };
-
-        let status = if execution.executed_amount == 0 {
-            TradeStatus::Failed
-        } else if execution.executed_amount < amount {
-            TradeStatus::PartiallyFilled
-        } else {
-            TradeStatus::Filled
-        };
-
-        admin::update_cb_stats(
-            &env,
-            status == TradeStatus::Failed,
-            execution.executed_amount,
-            execution.executed_price,
-        );
-
-        let trade = Trade {
-            signal_id,
-            user: user.clone(),
-            requested_amount: amount,
-            executed_amount: execution.executed_amount,
-            executed_price: execution.executed_price,
-            timestamp: env.ledger().timestamp(),
-            status: status.clone(),
-        };
-
-        if execution.executed_amount > 0 {
-            let positions = risk::get_user_positions(&env, &user);
-            let current_amount = positions
-                .get(signal.base_asset)
-                .map(|p| p.amount)
-                .unwrap_or(0);
-
-            let new_amount = if is_sell {
-                current_amount - execution.executed_amount
-            } else {
-                current_amount + execution.executed_amount
-            };
-
-            risk::update_position(
-                &env,
-                &user,
-                signal.base_asset,
-                new_amount,
-                execution.executed_price,
-            );
-
-            risk::add_trade_record(&env, &user, signal_id, execution.executed_amount);
-        }
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::Trades(user.clone(), signal_id), &trade);
-
-        if execution.executed_amount > 0 {
-            // ── Referral fee split ────────────────────────────────────────────
-            // Platform fee = 7% of executed amount (0.7 XLM per 10 XLM trade).
-            // Referral reward = 10% of platform fee → deducted from platform share.
-            let platform_fee = execution.executed_amount * 7 / 100;
-            let referral_reward =
-                referral::process_referral_reward(&env, &user, signal.base_asset, platform_fee);
-
-            let hist_status = match status {
-                TradeStatus::Filled | TradeStatus::PartiallyFilled => {
-                    history::HistoryTradeStatus::Executed
-                }
-                TradeStatus::Failed => history::HistoryTradeStatus::Failed,
-                TradeStatus::Pending => history::HistoryTradeStatus::Pending,
-            };
-            history::record_trade(
-                &env,
-                &user,
-                signal_id,
-                signal.base_asset,
-                execution.executed_amount,
-                execution.executed_price,
-                platform_fee - referral_reward,
-                hist_status,
-            );
-        }
-
-        #[allow(deprecated)]
-        env.events().publish(
-            (Symbol::new(&env, "trade_executed"), user.clone(), signal_id),
-            trade.clone(),
-        );
-
-        if status == TradeStatus::Failed {
-            #[allow(deprecated)]
-            env.events().publish(
-                (
-                    Symbol::new(&env, "risk_limit_block"),
-                    user.clone(),
-                    signal_id,
-                ),
-                amount,
-            );
-        }
+        execute_trade_internal(env, user, signal_id, order_type, amount, None)
+    }
 
-        Ok(TradeResult { trade })
+    /// Execute a trade tagged with one of the user's strategy labels.
+    ///
+    /// Behaves like [`Self::execute_trade`]; in addition the trade's history
+    /// entry carries the tag and the execution is folded into the tag's P&L.
+    ///
+    /// # Errors
+    /// - [`AutoTradeError::StrategyTagNotRegistered`] — `strategy_tag` is not in
+    ///   the user's strategy set.
+    pub fn execute_tagged_trade(
+        env: Env,
+        user: Address,
+        signal_id: u64,
+        order_type: OrderType,
+        amount: i128,
+        strategy_tag: Symbol,
+    ) -> Result<TradeResult, AutoTradeError> {
+        execute_trade_internal(env, user, signal_id, order_type, amount, Some(strategy_tag))
     }
 
     // ── Position Management (Issues #191, #192, #193) ────────────────────────
@@ -675,6 +569,41 @@ impl AutoTradeContract {
         );
     }
 
+    /// Declare a holding kept outside the contract so sizing and exposure
+    /// checks see the user's full portfolio. Entries are unverified and never
+    /// touched by settlement or withdrawal logic. Replaces any existing entry
+    /// for `asset_id`.
+    pub fn register_watch_only_position(
+        env: Env,
+        user: Address,
+        asset_id: u32,
+        amount: i128,
+        entry_price: i128,
+    ) -> Result<watch_only::WatchOnlyPosition, AutoTradeError> {
+        user.require_auth();
+        let position = watch_only::register_position(&env, &user, asset_id, amount, entry_price)?;
+
+        #[allow(deprecated)]
+        env.events().publish(
+            (Symbol::new(&env, "watch_only_registered"), user, asset_id),
+            (amount, entry_price),
+        );
+        Ok(position)
+    }
+
+    /// Remove a watch-only holding. Returns whether one existed.
+    pub fn remove_watch_only_position(env: Env, user: Address, asset_id: u32) -> bool {
+        user.require_auth();
+        watch_only::remove_position(&env, &user, asset_id)
+    }
+
+    pub fn get_watch_only_positions(
+        env: Env,
+        user: Address,
+    ) -> soroban_sdk::Map<u32, watch_only::WatchOnlyPosition> {
+        watch_only::get_positions(&env, &user)
+    }
+
     /// Get user's current positions
     pub fn get_user_positions(env: Env, user: Address) -> soroban_sdk::Map<u32, risk::Position> {
         risk::get_user_positions(&env, &user)
@@ -698,6 +627,54 @@ impl AutoTradeContract {
         history::get_trade_history(&env, &user, offset, limit)
     }
 
+    /// Execution quality for a pair: average realized spread vs oracle mid,
+    /// average fill ratio and partial-fill frequency (all in bps).
+    pub fn get_pair_execution_stats(
+        env: Env,
+        asset_pair: u32,
+    ) -> execution_stats::PairExecutionStats {
+        execution_stats::get_pair_execution_stats(&env, asset_pair)
+    }
+
+    /// Add a label to the caller's strategy set (at most
+    /// `strategy_tags::MAX_STRATEGY_TAGS`) for use with `execute_tagged_trade`.
+    pub fn register_strategy_tag(
+        env: Env,
+        user: Address,
+        tag: Symbol,
+    ) -> Result<(), AutoTradeError> {
+        user.require_auth();
+        strategy_tags::register_tag(&env, &user, &tag)
+    }
+
+    /// Retire a strategy label and discard its P&L totals.
+    pub fn remove_strategy_tag(env: Env, user: Address, tag: Symbol) -> bool {
+        user.require_auth();
+        strategy_tags::remove_tag(&env, &user, &tag)
+    }
+
+    /// The user's strategy labels.
+    pub fn get_strategy_tags(env: Env, user: Address) -> Vec<Symbol> {
+        strategy_tags::get_tags(&env, &user)
+    }
+
+    /// Volume, fees and realized P&L of trades tagged with `tag`.
+    pub fn get_strategy_stats(
+        env: Env,
+        user: Address,
+        tag: Symbol,
+    ) -> strategy_tags::StrategyTagStats {
+        strategy_tags::get_stats(&env, &user, &tag)
+    }
+
+    /// Per-tag totals for every strategy label of the user.
+    pub fn get_strategy_report(
+        env: Env,
+        user: Address,
+    ) -> soroban_sdk::Map<Symbol, strategy_tags::StrategyTagStats> {
+        strategy_tags::get_report(&env, &user)
+    }
+
     /// Get user portfolio with holdings and P&L
     pub fn get_portfolio(env: Env, user: Address) -> portfolio::Portfolio {
         portfolio::get_portfolio(&env, &user)
@@ -714,6 +691,8 @@ impl AutoTradeContract {
         user_b: Address,
     ) -> Result<portfolio::PortfolioComparison, AutoTradeError> {
         portfolio::compare_portfolios(&env, user_a, user_b)
+    }
+
     /// Set risk parity configuration
     pub fn set_risk_parity_config(
         env: Env,
@@ -870,6 +849,15 @@ impl AutoTradeContract {
         auth::get_auth_config(&env, &user)
     }
 
+    /// Set the operator allowed to flag rate-limited users (admin only)
+    pub fn set_operator(
+        env: Env,
+        caller: Address,
+        operator: Address,
+    ) -> Result<(), AutoTradeError> {
+        admin::set_operator(&env, &caller, operator)
+    }
+
     /// Set rate limit flag for a user (operator only)
     /// Flag expires after 1 hour (RATE_LIMIT_DURATION_SECONDS = 3600)
     pub fn set_rate_limited(env: Env, operator: Address, user: Address) -> Result<(), AutoTradeError> {
@@ -891,18 +879,6 @@ impl AutoTradeContract {
         admin::is_rate_limited(&env, &user)
     }
 
-fn failed_simulation(env: &Env, reason: &str) -> TradeSimulation {
-    TradeSimulation {
-        expected_output: 0,
-        fee_amount: 0,
-        slippage_bps: 0,
-        price_impact_bps: 0,
-        would_succeed: false,
-        failure_reason: Some(String::from_str(env, reason)),
-    }
-}
-
-mod test;
     /// Returns estimated storage usage metrics.
     ///
     /// # Estimation methodology
@@ -942,6 +918,20 @@ mod test;
         }
     }
 
+    /// Tracked balances, prices and positions whose TTL runs out within
+    /// `storage::TTL_WARNING_LEDGERS`, soonest first, with each category's
+    /// tracked count and minimum remaining TTL.
+    pub fn get_storage_health(env: Env) -> storage::StorageHealth {
+        storage::get_storage_health(&env)
+    }
+
+    /// Extend up to `limit` (at most `MAX_BUMP_BATCH`) tracked entries of
+    /// `category` ("balance", "price" or "position"), soonest expiring first.
+    /// Anyone may pay for the extension. Returns the number extended.
+    pub fn bump_ttls(env: Env, category: Symbol, limit: u32) -> u32 {
+        storage::bump_ttls(&env, &category, limit)
+    }
+
     // ── DCA ──────────────────────────────────────────────────────────────────
 
     pub fn create_dca(
@@ -1625,13 +1615,310 @@ mod test;
     pub fn mark_conditional_executed(env: Env, id: u64) -> Result<(), AutoTradeError> {
         conditional::mark_executed(&env, id)
     }
+
+    // ── Time-in-force orders ──────────────────────────────────────────────────
+
+    /// Execute a trade with a time in force.
+    ///
+    /// `Ioc` keeps whatever fills now and cancels the rest; `Fok` fails with
+    /// [`AutoTradeError::InsufficientLiquidity`] unless the whole amount fills
+    /// now. For `Gtc` and `Gtt` the unfilled remainder rests as an open order,
+    /// held in escrow out of the user's balance.
+    ///
+    /// # Errors
+    /// - [`AutoTradeError::InvalidTimeInForce`] — a `Gtt` deadline not in the future.
+    /// - Any error of [`Self::execute_trade`].
+    pub fn place_order(
+        env: Env,
+        user: Address,
+        signal_id: u64,
+        order_type: OrderType,
+        amount: i128,
+        time_in_force: TimeInForce,
+    ) -> Result<PlacedOrder, AutoTradeError> {
+        time_in_force::place_order(&env, user, signal_id, order_type, amount, time_in_force)
+    }
+
+    /// Retry an open order against the current market. Anyone may retry a
+    /// limit order; a market order needs the user's authorisation.
+    pub fn execute_open_order(env: Env, order_id: u64) -> Result<RestingOrder, AutoTradeError> {
+        time_in_force::execute_open_order(&env, order_id)
+    }
+
+    /// Cancel an open order and release its escrow.
+    pub fn cancel_order(env: Env, user: Address, order_id: u64) -> Result<(), AutoTradeError> {
+        time_in_force::cancel_order(&env, user, order_id)
+    }
+
+    /// Keeper sweep: check up to `limit` open orders with ids above `after`
+    /// and expire those past their `Gtt` deadline or signal expiry, releasing
+    /// their escrow. Returns the ids expired.
+    pub fn expire_orders(env: Env, after: u64, limit: u32) -> Vec<u64> {
+        time_in_force::expire_orders(&env, after, limit)
+    }
+
+    pub fn get_order(env: Env, order_id: u64) -> Result<RestingOrder, AutoTradeError> {
+        time_in_force::get_order(&env, order_id)
+    }
+
+    pub fn get_open_orders(env: Env) -> Vec<u64> {
+        time_in_force::get_open_order_ids(&env)
+    }
+}
+
+fn failed_simulation(env: &Env, reason: &str) -> TradeSimulation {
+    TradeSimulation {
+        expected_output: 0,
+        fee_amount: 0,
+        slippage_bps: 0,
+        price_impact_bps: 0,
+        would_succeed: false,
+        failure_reason: Some(String::from_str(env, reason)),
+    }
+}
+
+fn execute_trade_internal(
+    env: Env,
+    user: Address,
+    signal_id: u64,
+    order_type: OrderType,
+    amount: i128,
+    strategy_tag: Option<Symbol>,
+) -> Result<TradeResult, AutoTradeError> {
+    user.require_auth();
+    execute_authorized_trade(env, user, signal_id, order_type, amount, strategy_tag)
+}
+
+/// Trade execution once the user's consent is established: either by
+/// `require_auth` in `execute_trade_internal` or, for resting orders, when
+/// the order was placed.
+fn execute_authorized_trade(
+    env: Env,
+    user: Address,
+    signal_id: u64,
+    order_type: OrderType,
+    amount: i128,
+    strategy_tag: Option<Symbol>,
+) -> Result<TradeResult, AutoTradeError> {
+    if admin::is_paused(&env, String::from_str(&env, CAT_TRADING)) {
+        return Err(AutoTradeError::TradingPaused);
+    }
+
+    // Oracle circuit breaker: halt if oracle is unavailable (unless admin override)
+    oracle::check_oracle_circuit_breaker(&env, signal_id as u32)?;
+
+    if amount <= 0 {
+        return Err(AutoTradeError::InvalidAmount);
+    }
+
+    if let Some(tag) = &strategy_tag {
+        if !strategy_tags::is_registered(&env, &user, tag) {
+            return Err(AutoTradeError::StrategyTagNotRegistered);
+        }
+    }
+
+    // Check if user is rate limited
+    if admin::is_rate_limited(&env, &user) {
+        return Err(AutoTradeError::RateLimited);
+    }
+
+    let signal = storage::get_signal(&env, signal_id).ok_or(AutoTradeError::SignalNotFound)?;
+
+    if env.ledger().timestamp() > signal.expiry {
+        return Err(AutoTradeError::SignalExpired);
+    }
+
+    // Don't copy onto a pair whose oracles disagree on the price.
+    oracle::require_oracle_agreement(&env, signal.base_asset)?;
+
+    if !auth::is_authorized(&env, &user, amount) {
+        return Err(AutoTradeError::Unauthorized);
+    }
+
+    rate_limit::check_rate_limits(&env, &user, amount)?;
+
+    if !sdex::has_sufficient_balance(&env, &user, &signal.base_asset, amount) {
+        return Err(AutoTradeError::InsufficientBalance);
+    }
+    storage::touch_user_balance(&env, &user);
+
+    let is_sell = signal.action == SignalAction::Sell;
+
+    risk::set_asset_price(&env, signal.base_asset, signal.price);
+
+    // Fetch oracle price for manipulation-resistant stop-loss evaluation.
+    // Falls back to None (SDEX spot) when no oracle is configured.
+    let oracle_price: Option<i128> = oracle::get_oracle_price(&env, signal.base_asset)
+        .ok()
+        .map(|op| oracle::oracle_price_to_i128(&op));
+
+    // Perform risk checks
+    let stop_loss_triggered = risk::validate_trade(
+        &env,
+        &user,
+        signal.base_asset,
+        amount,
+        signal.price,
+        is_sell,
+        oracle_price,
+    )?;
+
+    if stop_loss_triggered {
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                Symbol::new(&env, "stop_loss_triggered"),
+                user.clone(),
+                signal.base_asset,
+            ),
+            signal.price,
+        );
+    }
+
+    let execution = match order_type {
+        OrderType::Market => {
+            match smart_routing::execute_best_route(&env, &signal, amount, 500) {
+                Ok(result) => result,
+                Err(AutoTradeError::RoutingPlanNotFound) => {
+                    sdex::execute_market_order(&env, &user, &signal, amount)?
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        OrderType::Limit => sdex::execute_limit_order(&env, &user, &signal, amount)?,
+    };
+
+    let status = if execution.executed_amount == 0 {
+        TradeStatus::Failed
+    } else if execution.executed_amount < amount {
+        TradeStatus::PartiallyFilled
+    } else {
+        TradeStatus::Filled
+    };
+
+    admin::update_cb_stats(
+        &env,
+        status == TradeStatus::Failed,
+        execution.executed_amount,
+        execution.executed_price,
+    );
+
+    execution_stats::record_execution(
+        &env,
+        signal.base_asset,
+        amount,
+        execution.executed_amount,
+        execution.executed_price,
+        oracle_price,
+    );
+
+    let trade = Trade {
+        signal_id,
+        signal_ref: storage::signal_ref(&env, signal_id),
+        user: user.clone(),
+        requested_amount: amount,
+        executed_amount: execution.executed_amount,
+        executed_price: execution.executed_price,
+        timestamp: env.ledger().timestamp(),
+        status: status.clone(),
+    };
+
+    if execution.executed_amount > 0 {
+        let positions = risk::get_user_positions(&env, &user);
+        let current_amount = positions
+            .get(signal.base_asset)
+            .map(|p| p.amount)
+            .unwrap_or(0);
+
+        let new_amount = if is_sell {
+            current_amount - execution.executed_amount
+        } else {
+            current_amount + execution.executed_amount
+        };
+
+        risk::update_position(
+            &env,
+            &user,
+            signal.base_asset,
+            new_amount,
+            execution.executed_price,
+        );
+
+        risk::add_trade_record(&env, &user, signal_id, execution.executed_amount);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Trades(user.clone(), signal_id), &trade);
+
+    if execution.executed_amount > 0 {
+        // ── Referral fee split ────────────────────────────────────────────
+        // Platform fee = 7% of executed amount (0.7 XLM per 10 XLM trade).
+        // Referral reward = 10% of platform fee → deducted from platform share.
+        let platform_fee = execution.executed_amount * 7 / 100;
+        let referral_reward =
+            referral::process_referral_reward(&env, &user, signal.base_asset, platform_fee);
+
+        let hist_status = match status {
+            TradeStatus::Filled | TradeStatus::PartiallyFilled => {
+                history::HistoryTradeStatus::Executed
+            }
+            TradeStatus::Failed => history::HistoryTradeStatus::Failed,
+            TradeStatus::Pending => history::HistoryTradeStatus::Pending,
+        };
+        history::record_trade(
+            &env,
+            &user,
+            signal_id,
+            signal.base_asset,
+            execution.executed_amount,
+            execution.executed_price,
+            platform_fee - referral_reward,
+            hist_status,
+            strategy_tag.clone(),
+        );
+
+        if let Some(tag) = &strategy_tag {
+            strategy_tags::record_execution(
+                &env,
+                &user,
+                tag,
+                signal.base_asset,
+                execution.executed_amount,
+                execution.executed_price,
+                is_sell,
+                platform_fee - referral_reward,
+            );
+        }
+    }
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (Symbol::new(&env, "trade_executed"), user.clone(), signal_id),
+        trade.clone(),
+    );
+
+    if status == TradeStatus::Failed {
+        #[allow(deprecated)]
+        env.events().publish(
+            (
+                Symbol::new(&env, "risk_limit_block"),
+                user.clone(),
+                signal_id,
+            ),
+            amount,
+        );
+    }
+
+    Ok(TradeResult { trade })
 }
 
 #[cfg(test)]
 mod test;
-mod test_oracle_whitelist;
 #[cfg(test)]
 mod test_admin_transfer;
+#[cfg(test)]
+mod test_oracle_whitelist;
 
 // ── Oracle integration tests ─────────────────────────────────────────────────
 #[cfg(test)]
@@ -1825,6 +2112,17 @@ mod oracle_cb_tests {
         }
     }
 
+    /// On-chain oracle that always reports a fresh price.
+    #[contract]
+    struct PriceFeed;
+
+    #[contractimpl]
+    impl PriceFeed {
+        pub fn get_price(env: Env, _asset_pair: u32) -> OraclePrice {
+            fresh_price(&env, 100)
+        }
+    }
+
     /// When oracle is available, get_aggregated_price returns the price and
     /// circuit breaker stays un-tripped.
     #[test]
@@ -1910,8 +2208,9 @@ mod oracle_cb_tests {
                 .instance()
                 .set(&crate::admin::AdminStorageKey::OracleCircuitBreaker, &state);
 
-            // Oracle recovers — seed a fresh price
-            MockOracleClient::set_price(&env, 1, fresh_price(&env, 100));
+            // Oracle recovers — point the contract at a feed serving fresh prices
+            let feed = env.register(PriceFeed, ());
+            oracle::set_oracle_address(&env, &admin, feed).unwrap();
 
             // check_oracle_circuit_breaker should reset and return Ok
             let result = oracle::check_oracle_circuit_breaker(&env, 1);
@@ -1963,7 +2262,9 @@ mod oracle_cb_tests {
                 .instance()
                 .set(&crate::admin::AdminStorageKey::OracleCircuitBreaker, &state);
             oracle::override_oracle_circuit_breaker(&env, &admin, true).unwrap();
+        });
 
+        env.as_contract(&contract_id, || {
             // Disable override — oracle still down → should block again
             oracle::override_oracle_circuit_breaker(&env, &admin, false).unwrap();
             let result = oracle::check_oracle_circuit_breaker(&env, 1);
@@ -6,6 +6,8 @@
 //! - `get_aggregated_price()` — fetches a fresh price or returns `OracleUnavailable`
 //! - Oracle circuit breaker — auto-pauses trading when oracle is unavailable,
 //!   auto-resets when oracle recovers, admin can manually override
+//! - Oracle agreement gate — refuses automatic copying on a pair whose
+//!   whitelisted oracles disagree with each other
 
 use soroban_sdk::{contracttype, Address, Env, String, Symbol};
 use stellar_swipe_common::oracle::{
@@ -20,6 +22,10 @@ use crate::errors::AutoTradeError;
 /// Maximum age (seconds) before a price is considered stale.
 pub const MAX_PRICE_AGE_SECS: u64 = 300; // 5 minutes
 
+/// Default distance from the median (BPS) within which an oracle report
+/// counts as agreeing.
+pub const DEFAULT_AGREEMENT_TOLERANCE_BPS: u32 = 100; // 1%
+
 // ── Circuit breaker state ─────────────────────────────────────────────────────
 
 /// Persisted state of the oracle circuit breaker.
@@ -44,6 +50,37 @@ impl OracleCircuitBreakerState {
     }
 }
 
+// ── Oracle agreement ──────────────────────────────────────────────────────────
+
+/// Admin settings of the agreement gate. `min_score_bps == 0` disables it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AgreementConfig {
+    /// Share of fresh reports (BPS) that must agree before auto-copy is allowed.
+    pub min_score_bps: u32,
+    /// Distance from the median (BPS) within which a report agrees.
+    pub tolerance_bps: u32,
+}
+
+/// Latest price pushed by one whitelisted oracle for a pair.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OracleReport {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// The gating decision for a pair, as `execute_trade` would take it now.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AgreementGate {
+    /// Share of fresh reports within tolerance of their median, in BPS.
+    pub score_bps: u32,
+    pub fresh_reports: u32,
+    pub min_score_bps: u32,
+    pub auto_copy_allowed: bool,
+}
+
 // ── Storage helpers ───────────────────────────────────────────────────────────
 
 pub fn get_cb_state(env: &Env) -> OracleCircuitBreakerState {
@@ -335,6 +372,7 @@ pub fn require_whitelisted_oracle(
 ///
 /// - Verifies `caller` is in the whitelist for `asset_pair`.
 /// - Validates freshness of the supplied price.
+/// - Records it as the caller's latest report for the agreement gate.
 /// - Stores the price via `risk::set_asset_price` and `risk::record_price`.
 /// - Emits `OraclePriceUpdated { asset_pair, price }` event.
 pub fn push_price_update(
@@ -348,6 +386,7 @@ pub fn push_price_update(
     validate_freshness(env, &price).map_err(|_| AutoTradeError::OracleUnavailable)?;
 
     let scaled = oracle_price_to_i128(&price);
+    record_report(env, caller, asset_pair, scaled, price.timestamp);
     crate::risk::set_asset_price(env, asset_pair, scaled);
     crate::risk::record_price(env, asset_pair, scaled);
 
@@ -357,3 +396,115 @@ pub fn push_price_update(
     );
     Ok(())
 }
+
+// ── Oracle agreement gate ─────────────────────────────────────────────────────
+
+pub fn get_agreement_config(env: &Env) -> AgreementConfig {
+    env.storage()
+        .instance()
+        .get(&AdminStorageKey::OracleAgreementConfig)
+        .unwrap_or(AgreementConfig {
+            min_score_bps: 0,
+            tolerance_bps: DEFAULT_AGREEMENT_TOLERANCE_BPS,
+        })
+}
+
+/// Set the minimum agreement score and tolerance (admin-only).
+pub fn set_agreement_config(
+    env: &Env,
+    caller: &Address,
+    config: AgreementConfig,
+) -> Result<(), AutoTradeError> {
+    require_admin(env, caller)?;
+    caller.require_auth();
+    if config.min_score_bps > 10_000 || config.tolerance_bps == 0 {
+        return Err(AutoTradeError::InvalidAmount);
+    }
+    env.storage()
+        .instance()
+        .set(&AdminStorageKey::OracleAgreementConfig, &config);
+    Ok(())
+}
+
+fn record_report(env: &Env, caller: &Address, asset_pair: u32, price: i128, timestamp: u64) {
+    env.storage().persistent().set(
+        &AdminStorageKey::OracleReport(asset_pair, caller.clone()),
+        &OracleReport { price, timestamp },
+    );
+}
+
+/// Score how closely the whitelisted oracles of `asset_pair` agree: the
+/// share of fresh reports within the tolerance of their median.
+pub fn get_agreement_gate(env: &Env, asset_pair: u32) -> AgreementGate {
+    let config = get_agreement_config(env);
+    let now = env.ledger().timestamp();
+
+    let mut prices = soroban_sdk::Vec::new(env);
+    for oracle_addr in get_oracle_whitelist(env, asset_pair).iter() {
+        let report: Option<OracleReport> = env
+            .storage()
+            .persistent()
+            .get(&AdminStorageKey::OracleReport(asset_pair, oracle_addr));
+        if let Some(report) = report {
+            if now.saturating_sub(report.timestamp) <= MAX_PRICE_AGE_SECS {
+                prices.push_back(report.price);
+            }
+        }
+    }
+
+    let fresh_reports = prices.len();
+    let score_bps = if fresh_reports == 0 {
+        0
+    } else {
+        let median = median(&prices);
+        let agreeing = prices
+            .iter()
+            .filter(|price| {
+                median > 0
+                    && (price - median).abs() * 10_000 / median <= config.tolerance_bps as i128
+            })
+            .count() as u32;
+        agreeing * 10_000 / fresh_reports
+    };
+
+    AgreementGate {
+        score_bps,
+        fresh_reports,
+        min_score_bps: config.min_score_bps,
+        auto_copy_allowed: config.min_score_bps == 0 || score_bps >= config.min_score_bps,
+    }
+}
+
+/// Refuse automatic copying on `asset_pair` while its oracles disagree.
+/// Emits `auto_copy_blocked { asset_pair, score_bps }` when refusing.
+pub fn require_oracle_agreement(env: &Env, asset_pair: u32) -> Result<(), AutoTradeError> {
+    let gate = get_agreement_gate(env, asset_pair);
+    if gate.auto_copy_allowed {
+        return Ok(());
+    }
+    env.events().publish(
+        (Symbol::new(env, "auto_copy_blocked"), asset_pair),
+        gate.score_bps,
+    );
+    Err(AutoTradeError::LowOracleAgreement)
+}
+
+fn median(values: &soroban_sdk::Vec<i128>) -> i128 {
+    let mut sorted = values.clone();
+    // Insertion sort: the whitelist of a pair is small.
+    for i in 1..sorted.len() {
+        let mut j = i;
+        while j > 0 && sorted.get(j - 1).unwrap() > sorted.get(j).unwrap() {
+            let previous = sorted.get(j - 1).unwrap();
+            sorted.set(j - 1, sorted.get(j).unwrap());
+            sorted.set(j, previous);
+            j -= 1;
+        }
+    }
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted.get(mid - 1).unwrap() + sorted.get(mid).unwrap()) / 2
+    } else {
+        sorted.get(mid).unwrap()
+    }
+}
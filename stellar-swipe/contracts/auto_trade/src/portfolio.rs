@@ -7,6 +7,7 @@ use soroban_sdk::{contracttype, Address, Env, Vec};
 
 use crate::errors::AutoTradeError;
 use crate::risk;
+use crate::watch_only;
 
 #[contracttype]
 #[derive(Clone)]
@@ -22,14 +23,19 @@ pub struct AssetHolding {
     pub current_value_xlm: i128,
     pub avg_entry_price: i128,
     pub unrealized_pnl: i128,
+    /// Self-reported holding kept outside the contract (unverified).
+    pub watch_only: bool,
 }
 
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct Portfolio {
     pub assets: Vec<AssetHolding>,
+    /// Value of holdings custodied by the contract only.
     pub total_value_xlm: i128,
     pub total_pnl: i128,
+    /// Value of watch-only holdings, reported separately from the totals above.
+    pub watch_only_value_xlm: i128,
 }
 
 #[contracttype]
@@ -58,6 +64,8 @@ pub fn is_privacy_mode_enabled(env: &Env, user: &Address) -> bool {
 }
 
 /// Get portfolio for user. Uses risk::get_user_positions and risk::get_asset_price.
+/// Watch-only holdings are listed with `watch_only = true` and kept out of
+/// `total_value_xlm` / `total_pnl`.
 pub fn get_portfolio(env: &Env, user: &Address) -> Portfolio {
     let positions = risk::get_user_positions(env, user);
     let mut assets = Vec::new(env);
@@ -82,15 +90,33 @@ pub fn get_portfolio(env: &Env, user: &Address) -> Portfolio {
                     current_value_xlm,
                     avg_entry_price: position.entry_price,
                     unrealized_pnl,
+                    watch_only: false,
                 });
             }
         }
     }
 
+    let mut watch_only_value_xlm = 0i128;
+    for (asset_id, position) in watch_only::get_positions(env, user).iter() {
+        let current_price = risk::get_asset_price(env, asset_id).unwrap_or(position.entry_price);
+        let current_value_xlm = position.amount * current_price;
+        watch_only_value_xlm += current_value_xlm;
+
+        assets.push_back(AssetHolding {
+            asset_id,
+            amount: position.amount,
+            current_value_xlm,
+            avg_entry_price: position.entry_price,
+            unrealized_pnl: (current_price - position.entry_price) * position.amount,
+            watch_only: true,
+        });
+    }
+
     Portfolio {
         assets,
         total_value_xlm,
         total_pnl,
+        watch_only_value_xlm,
     }
 }
 
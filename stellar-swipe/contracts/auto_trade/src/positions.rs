@@ -3,7 +3,10 @@
 //!
 //! Issues #191 (open_position) and #192 (close_position).
 
-use soroban_sdk::{contracttype, Address, BytesN, Env, Map, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, Symbol, TryFromVal, Val, Vec};
+use stellar_swipe_common::SignalRef;
+
+use crate::storage;
 
 /// Position status
 #[contracttype]
@@ -17,6 +20,28 @@ pub enum PositionStatus {
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct PositionData {
+    pub trade_id: BytesN<32>,
+    pub user: Address,
+    pub signal_id: u64,
+    /// Chain-wide reference to the signal the position copies.
+    pub signal_ref: SignalRef,
+    pub asset_pair: u32,
+    pub amount: i128,
+    pub entry_price: i128,
+    pub stop_loss: i128,
+    pub take_profit: i128,
+    pub status: PositionStatus,
+    pub exit_price: i128,
+    pub pnl: i128,
+    pub opened_at: u64,
+    pub closed_at: u64,
+}
+
+/// `PositionData` as written before positions carried a `signal_ref`.
+/// Records in this layout are upgraded on read.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PositionDataV1 {
     pub trade_id: BytesN<32>,
     pub user: Address,
     pub signal_id: u64,
@@ -32,6 +57,27 @@ pub struct PositionData {
     pub closed_at: u64,
 }
 
+impl PositionDataV1 {
+    fn upgrade(self, env: &Env) -> PositionData {
+        PositionData {
+            trade_id: self.trade_id,
+            user: self.user,
+            signal_id: self.signal_id,
+            signal_ref: storage::signal_ref(env, self.signal_id),
+            asset_pair: self.asset_pair,
+            amount: self.amount,
+            entry_price: self.entry_price,
+            stop_loss: self.stop_loss,
+            take_profit: self.take_profit,
+            status: self.status,
+            exit_price: self.exit_price,
+            pnl: self.pnl,
+            opened_at: self.opened_at,
+            closed_at: self.closed_at,
+        }
+    }
+}
+
 /// Result returned by close_position.
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -97,12 +143,23 @@ fn save_position(env: &Env, position: &PositionData) {
     env.storage()
         .persistent()
         .set(&PositionKey::Position(position.trade_id.clone()), position);
+    storage::touch_position(env, &position.trade_id);
 }
 
+/// Load a position, upgrading records in the v1 layout.
 pub fn get_position(env: &Env, trade_id: &BytesN<32>) -> Option<PositionData> {
-    env.storage()
+    let raw: Val = env
+        .storage()
         .persistent()
-        .get(&PositionKey::Position(trade_id.clone()))
+        .get(&PositionKey::Position(trade_id.clone()))?;
+    let fields = Map::<Symbol, Val>::try_from_val(env, &raw).ok()?;
+    if fields.contains_key(Symbol::new(env, "signal_ref")) {
+        PositionData::try_from_val(env, &raw).ok()
+    } else {
+        PositionDataV1::try_from_val(env, &raw)
+            .ok()
+            .map(|v1| v1.upgrade(env))
+    }
 }
 
 fn get_user_trade_ids(env: &Env, user: &Address) -> Vec<BytesN<32>> {
@@ -141,6 +198,7 @@ pub fn open_position(
         trade_id: trade_id.clone(),
         user: user.clone(),
         signal_id,
+        signal_ref: storage::signal_ref(env, signal_id),
         asset_pair,
         amount,
         entry_price,
@@ -389,4 +447,42 @@ mod tests {
             assert_ne!(id1, id2);
         });
     }
+
+    #[test]
+    fn test_reads_and_closes_v1_positions() {
+        let (env, contract_id) = setup();
+        let user = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            let trade_id = BytesN::from_array(&env, &[7u8; 32]);
+            let old = PositionDataV1 {
+                trade_id: trade_id.clone(),
+                user: user.clone(),
+                signal_id: 3,
+                asset_pair: 42,
+                amount: 10,
+                entry_price: 500,
+                stop_loss: 400,
+                take_profit: 700,
+                status: PositionStatus::Open,
+                exit_price: 0,
+                pnl: 0,
+                opened_at: 900,
+                closed_at: 0,
+            };
+            env.storage()
+                .persistent()
+                .set(&PositionKey::Position(trade_id.clone()), &old);
+            save_user_trade_ids(&env, &user, &Vec::from_array(&env, [trade_id.clone()]));
+
+            let pos = get_position(&env, &trade_id).unwrap();
+            assert_eq!(pos.signal_ref, storage::signal_ref(&env, 3));
+            assert_eq!(pos.opened_at, 900);
+            assert_eq!(get_open_positions(&env, &user).len(), 1);
+
+            let result = close_position(&env, &user, &trade_id, 600).unwrap();
+            assert_eq!(result.pnl, 1000);
+            assert_eq!(get_position(&env, &trade_id).unwrap().status, PositionStatus::Closed);
+        });
+    }
 }
@@ -224,7 +224,10 @@ pub fn update_position(env: &Env, user: &Address, asset_id: u32, amount: i128, p
         positions.remove(asset_id);
     } else {
         let position = if let Some(existing) = positions.get(asset_id) {
-            let is_reduction = amount < existing.amount;
+            // Shrinking exposure on the same side keeps the original entry;
+            // growing it or flipping between long and short re-prices it.
+            let is_reduction =
+                (amount > 0) == (existing.amount > 0) && amount.abs() < existing.amount.abs();
             Position {
                 asset_id,
                 amount,
@@ -292,14 +295,14 @@ pub fn add_trade_record(env: &Env, user: &Address, signal_id: u64, amount: i128)
 /// ==========================
 pub fn get_asset_price(env: &Env, asset_id: u32) -> Option<i128> {
     env.storage()
-        .temporary()
+        .persistent()
         .get(&RiskDataKey::AssetPrice(asset_id))
 }
 
 pub fn set_asset_price(env: &Env, asset_id: u32, price: i128) {
-    env.storage()
-        .temporary()
-        .set(&RiskDataKey::AssetPrice(asset_id), &price);
+    let key = RiskDataKey::AssetPrice(asset_id);
+    env.storage().persistent().set(&key, &price);
+    crate::storage::touch_asset_price(env, &key, asset_id);
 }
 
 /// ==========================
@@ -350,7 +353,26 @@ pub fn calculate_portfolio_value(env: &Env, user: &Address) -> i128 {
     total_value
 }
 
-/// Check if position limit would be exceeded
+/// Gross exposure including declared watch-only holdings: short positions
+/// count by their absolute value rather than netting against longs. Used for
+/// sizing and exposure checks; custody-sensitive logic uses
+/// `calculate_portfolio_value` instead.
+pub fn calculate_total_exposure_value(env: &Env, user: &Address) -> i128 {
+    let mut total_value = crate::watch_only::calculate_value(env, user);
+    for (asset_id, position) in get_user_positions(env, user).iter() {
+        if let Some(price) = get_asset_price(env, asset_id) {
+            total_value += position.amount.abs() * price / 100;
+        }
+    }
+    total_value
+}
+
+/// Check if position limit would be exceeded. Watch-only holdings count toward
+/// both the asset's exposure and the portfolio it is measured against.
+///
+/// `trade_amount` is the signed change to the position: positive for buys,
+/// negative for sells. Short positions are limited by their absolute size,
+/// and trades that shrink the position's absolute size are always allowed.
 pub fn check_position_limit(
     env: &Env,
     user: &Address,
@@ -359,23 +381,34 @@ pub fn check_position_limit(
     trade_price: i128,
     config: &RiskConfig,
 ) -> Result<(), AutoTradeError> {
-    let current_portfolio_value = calculate_portfolio_value(env, user);
+    let positions = get_user_positions(env, user);
+    let current_position = positions.get(asset_id).map(|p| p.amount).unwrap_or(0)
+        + crate::watch_only::amount_of(env, user, asset_id);
+    let new_position_amount = current_position + trade_amount;
 
-    // Handle first trade case - allow if within absolute limit
-    if current_portfolio_value == 0 {
-        // For first trade, we'll allow it as long as it's reasonable
+    if new_position_amount.abs() <= current_position.abs() {
         return Ok(());
     }
 
-    let positions = get_user_positions(env, user);
-    let current_position = positions.get(asset_id).map(|p| p.amount).unwrap_or(0);
+    let current_portfolio_value = calculate_total_exposure_value(env, user);
 
-    let new_position_amount = current_position + trade_amount;
-    let new_position_value = new_position_amount * trade_price / 100;
+    // Handle first trade case - a long is allowed outright, but a short needs
+    // a portfolio to be measured against
+    if current_portfolio_value == 0 {
+        if new_position_amount < 0 {
+            return Err(AutoTradeError::PositionLimitExceeded);
+        }
+        return Ok(());
+    }
+
+    // Re-value this asset's exposure at the trade price in the NEW portfolio
+    let current_position_value = current_position.abs() * trade_price / 100;
+    let new_position_value = new_position_amount.abs() * trade_price / 100;
+    let new_portfolio_value = current_portfolio_value - current_position_value + new_position_value;
 
-    // Calculate the new portfolio value including this trade
-    let trade_value = trade_amount * trade_price / 100;
-    let new_portfolio_value = current_portfolio_value + trade_value;
+    if new_portfolio_value <= 0 {
+        return Err(AutoTradeError::PositionLimitExceeded);
+    }
 
     // Calculate what percentage this position would be of the NEW portfolio
     let position_pct = (new_position_value * 100) / new_portfolio_value;
@@ -431,10 +464,9 @@ pub fn validate_trade(
     // Check daily trade limit
     check_daily_trade_limit(env, user, &config)?;
 
-    // Check position limit (only for buys)
-    if !is_sell {
-        check_position_limit(env, user, asset_id, amount, price, &config)?;
-    }
+    // Check position limit; sells count against it once they open a short
+    let position_change = if is_sell { -amount } else { amount };
+    check_position_limit(env, user, asset_id, position_change, price, &config)?;
 
     // Check stop-loss (only for sells), using oracle price when available
     let stop_loss_triggered = if is_sell {
@@ -582,6 +614,40 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_position_limit_bounds_short_exposure() {
+        let env = setup_env();
+        let user = test_user(&env);
+        let contract_addr = env.register(TestContract, ());
+
+        env.as_contract(&contract_addr, || {
+            let config = RiskConfig::default();
+
+            // Naked short with nothing to measure it against
+            assert_eq!(
+                check_position_limit(&env, &user, 1, -100, 100, &config),
+                Err(AutoTradeError::PositionLimitExceeded)
+            );
+
+            set_asset_price(&env, 1, 100);
+            set_asset_price(&env, 2, 100);
+            update_position(&env, &user, 1, 1000, 100);
+            update_position(&env, &user, 2, 4000, 100);
+
+            // Selling down the long only shrinks exposure
+            assert!(check_position_limit(&env, &user, 1, -1000, 100, &config).is_ok());
+
+            // Short 500: value 500 of portfolio 4000 + 500 = 11%
+            assert!(check_position_limit(&env, &user, 1, -1500, 100, &config).is_ok());
+
+            // Short 2000: value 2000 of portfolio 4000 + 2000 = 33% > 20%
+            assert_eq!(
+                check_position_limit(&env, &user, 1, -3000, 100, &config),
+                Err(AutoTradeError::PositionLimitExceeded)
+            );
+        });
+    }
+
     #[test]
     fn test_stop_loss_not_triggered() {
         let env = setup_env();
@@ -3,7 +3,7 @@
 //! Equalizes the risk contribution of each asset in a portfolio.
 //! High volatility assets receive lower weights, low volatility assets receive higher weights.
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
 
 use crate::errors::AutoTradeError;
 use crate::portfolio;
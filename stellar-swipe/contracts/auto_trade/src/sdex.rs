@@ -2,7 +2,7 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env};
 
 use crate::errors::AutoTradeError;
-use crate::storage::Signal;
+use crate::storage::{Signal, SignalAction};
 
 /// ==========================
 /// Types
@@ -18,19 +18,23 @@ pub struct ExecutionResult {
 /// Balance Check
 /// ==========================
 pub fn has_sufficient_balance(env: &Env, user: &Address, _asset: &u32, amount: i128) -> bool {
-    let key = (user.clone(), symbol_short!("balance"));
-    let balance: i128 = env.storage().temporary().get(&key).unwrap_or(0);
-    balance >= amount
+    crate::storage::get_user_balance(env, user) >= amount
 }
 
+/// Liquidity on the side of the book the signal trades against: buys take
+/// asks (`liquidity`), sells hit bids (`bid_liq`).
 pub fn get_available_liquidity(env: &Env, signal: &Signal, amount: i128) -> i128 {
-    let key = (symbol_short!("liquidity"), signal.signal_id);
-    env.storage().temporary().get(&key).unwrap_or(amount)
+    let side = match signal.action {
+        SignalAction::Buy => symbol_short!("liquidity"),
+        SignalAction::Sell => symbol_short!("bid_liq"),
+    };
+    let key = (side, signal.signal_id);
+    env.storage().persistent().get(&key).unwrap_or(amount)
 }
 
 pub fn get_current_price(env: &Env, signal: &Signal) -> i128 {
     let key = (symbol_short!("price"), signal.signal_id);
-    env.storage().temporary().get(&key).unwrap_or(signal.price)
+    env.storage().persistent().get(&key).unwrap_or(signal.price)
 }
 
 /// ==========================
@@ -65,6 +69,16 @@ pub fn execute_market_order(
 /// ==========================
 /// Limit Order
 /// ==========================
+
+/// Whether a limit at `limit_price` is marketable: buys fill at or below the
+/// limit, sells at or above it.
+pub fn limit_is_marketable(action: SignalAction, market_price: i128, limit_price: i128) -> bool {
+    match action {
+        SignalAction::Buy => market_price <= limit_price,
+        SignalAction::Sell => market_price >= limit_price,
+    }
+}
+
 pub fn execute_limit_order(
     env: &Env,
     _user: &Address,
@@ -79,7 +93,7 @@ pub fn execute_limit_order(
 
     let market_price = get_current_price(env, signal);
 
-    if market_price > signal.price {
+    if !limit_is_marketable(signal.action, market_price, signal.price) {
         return Ok(ExecutionResult {
             executed_amount: 0,
             executed_price: 0,
@@ -113,6 +127,14 @@ mod tests {
             price: 100,
             expiry: env.ledger().timestamp() + 1_000,
             base_asset: 1,
+            action: SignalAction::Buy,
+        }
+    }
+
+    fn setup_sell_signal(env: &Env, id: u64) -> Signal {
+        Signal {
+            action: SignalAction::Sell,
+            ..setup_signal(env, id)
         }
     }
 
@@ -133,7 +155,7 @@ mod tests {
         env.as_contract(&contract_addr, || {
             // Initialize liquidity in storage
             let key = (symbol_short!("liquidity"), 1u64);
-            env.storage().temporary().set(&key, &500i128);
+            env.storage().persistent().set(&key, &500i128);
 
             let res = execute_market_order(&env, &user, &signal, 400).unwrap();
             assert_eq!(res.executed_amount, 400);
@@ -151,7 +173,7 @@ mod tests {
 
         env.as_contract(&contract_addr, || {
             let key = (symbol_short!("liquidity"), 2u64);
-            env.storage().temporary().set(&key, &100i128);
+            env.storage().persistent().set(&key, &100i128);
 
             let res = execute_market_order(&env, &user, &signal, 300).unwrap();
             assert_eq!(res.executed_amount, 100);
@@ -169,7 +191,7 @@ mod tests {
 
         env.as_contract(&contract_addr, || {
             let key = (symbol_short!("price"), 3u64);
-            env.storage().temporary().set(&key, &150i128);
+            env.storage().persistent().set(&key, &150i128);
 
             let res = execute_limit_order(&env, &user, &signal, 200).unwrap();
             assert_eq!(res.executed_amount, 0);
@@ -177,6 +199,49 @@ mod tests {
         });
     }
 
+    #[test]
+    fn sell_market_order_uses_bid_liquidity() {
+        let env = setup_env();
+        let user = test_user(&env, 5);
+        let contract_addr = env.register(TestContract, ());
+
+        let signal = setup_sell_signal(&env, 5);
+
+        env.as_contract(&contract_addr, || {
+            env.storage()
+                .persistent()
+                .set(&(symbol_short!("liquidity"), 5u64), &1_000i128);
+            env.storage()
+                .persistent()
+                .set(&(symbol_short!("bid_liq"), 5u64), &150i128);
+
+            let res = execute_market_order(&env, &user, &signal, 300).unwrap();
+            assert_eq!(res.executed_amount, 150);
+        });
+    }
+
+    #[test]
+    fn sell_limit_order_fills_at_or_above_limit() {
+        let env = setup_env();
+        let user = test_user(&env, 6);
+        let contract_addr = env.register(TestContract, ());
+
+        let signal = setup_sell_signal(&env, 6);
+
+        env.as_contract(&contract_addr, || {
+            let key = (symbol_short!("price"), 6u64);
+
+            env.storage().persistent().set(&key, &90i128);
+            let res = execute_limit_order(&env, &user, &signal, 200).unwrap();
+            assert_eq!(res.executed_amount, 0);
+
+            env.storage().persistent().set(&key, &150i128);
+            let res = execute_limit_order(&env, &user, &signal, 200).unwrap();
+            assert_eq!(res.executed_amount, 200);
+            assert_eq!(res.executed_price, 100);
+        });
+    }
+
     #[test]
     fn expired_signal_rejected() {
         let env = setup_env();
@@ -188,6 +253,7 @@ mod tests {
             price: 100,
             expiry: env.ledger().timestamp() - 1, // expired
             base_asset: 1,
+            action: SignalAction::Buy,
         };
 
         env.as_contract(&contract_addr, || {
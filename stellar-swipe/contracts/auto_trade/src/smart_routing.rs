@@ -266,7 +266,7 @@ pub fn set_execution_failure(env: &Env, signal_id: u64, venue: LiquidityVenue, v
 }
 
 fn finalize_plan(
-    env: &Env,
+    _env: &Env,
     reference_price: i128,
     requested_amount: i128,
     segments: Vec<RouteSegment>,
@@ -364,6 +364,7 @@ mod tests {
             price: 100,
             expiry: 5_000,
             base_asset: 1,
+            action: crate::storage::SignalAction::Buy,
         }
     }
 
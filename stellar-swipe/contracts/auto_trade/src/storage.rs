@@ -1,7 +1,19 @@
 #![allow(dead_code)]
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
 
 use crate::auth::{AuthConfig, AuthKey};
+use crate::positions::PositionKey;
+use crate::risk::RiskDataKey;
+use stellar_swipe_common::storage_health::{self, CategoryHealth, TrackedEntry};
+use stellar_swipe_common::SignalRef;
+
+/// Direction of a signal, mirroring the registry's `SignalAction`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignalAction {
+    Buy,
+    Sell,
+}
 
 #[contracttype]
 #[derive(Clone)]
@@ -10,6 +22,9 @@ pub struct Signal {
     pub price: i128,
     pub expiry: u64,
     pub base_asset: u32,
+    /// Buy signals add to the user's position in `base_asset`; sell signals
+    /// reduce it and may take it negative (short).
+    pub action: SignalAction,
 }
 
 #[contracttype]
@@ -25,6 +40,7 @@ pub enum DataKey {
     Trades(Address, u64),
     Signal(u64),
     RateLimitInfo(Address),
+    SignalRegistry,
 }
 
 /// Get a signal by ID
@@ -37,26 +53,226 @@ pub fn set_signal(env: &Env, id: u64, signal: &Signal) {
     env.storage().persistent().set(&DataKey::Signal(id), signal);
 }
 
-/// Test helper: auth plus max temporary SDEX balance.
-pub fn authorize_user(env: &Env, user: &Address) {
-    authorize_user_with_limits(env, user, i128::MAX / 4, 30);
+/// Registry that issues the signals this contract trades. When unset, signals
+/// are the ones stored locally via `set_signal`.
+pub fn get_signal_registry(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::SignalRegistry)
+}
+
+pub fn set_signal_registry(env: &Env, registry: &Address) {
     env.storage()
-        .temporary()
-        .set(&(user.clone(), symbol_short!("balance")), &i128::MAX);
+        .instance()
+        .set(&DataKey::SignalRegistry, registry);
 }
 
-/// Authorize a user with default limits (test helper).
-#[cfg(test)]
-pub fn authorize_user(env: &Env, user: &Address) {
-    let config = AuthConfig {
-        authorized: true,
-        max_trade_amount: 1_000_000_000_000,
-        expires_at: env.ledger().timestamp() + (30 * 86400),
-        granted_at: env.ledger().timestamp(),
-    };
+pub fn get_rate_limit_info(env: &Env, user: &Address) -> Option<RateLimitInfo> {
     env.storage()
         .persistent()
-        .set(&AuthKey::Authorization(user.clone()), &config);
+        .get(&DataKey::RateLimitInfo(user.clone()))
+}
+
+pub fn set_rate_limit_info(env: &Env, user: &Address, info: &RateLimitInfo) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RateLimitInfo(user.clone()), info);
+}
+
+/// Whether `user` is flagged; a flag past its `expires_at` is cleared.
+pub fn is_rate_limited(env: &Env, user: &Address) -> bool {
+    match get_rate_limit_info(env, user) {
+        Some(info) if info.is_limited => {
+            if env.ledger().timestamp() >= info.expires_at {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::RateLimitInfo(user.clone()));
+                false
+            } else {
+                true
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Chain-wide reference for `signal_id`.
+pub fn signal_ref(env: &Env, signal_id: u64) -> SignalRef {
+    let registry = get_signal_registry(env).unwrap_or_else(|| env.current_contract_address());
+    SignalRef::new(registry, signal_id)
+}
+
+// ── Market data ──────────────────────────────────────────────────────────────
+//
+// Balances, liquidity and prices live in persistent storage so they never
+// silently disappear with a temporary entry's TTL. Every write through these
+// helpers extends the entry's TTL and records when it will expire, which
+// `get_storage_health` reports on and `bump_ttls` acts on.
+
+/// ~24 hours of ledgers at 5s close time.
+pub const LEDGERS_PER_DAY: u32 = 17_280;
+/// TTL market data entries are extended to on write.
+pub const MARKET_DATA_TTL_LEDGERS: u32 = LEDGERS_PER_DAY * 30;
+/// Entries with fewer ledgers left than this are reported as expiring.
+pub const TTL_WARNING_LEDGERS: u32 = storage_health::TTL_WARNING_LEDGERS;
+/// Upper bound on entries tracked for the storage health report.
+pub const MAX_TRACKED_ENTRIES: u32 = storage_health::MAX_TRACKED_ENTRIES;
+
+/// A persistent entry whose expiry is tracked.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageEntry {
+    Balance(Address),
+    AssetPrice(u32),
+    Position(BytesN<32>),
+}
+
+impl TrackedEntry for StorageEntry {
+    fn category(&self) -> Symbol {
+        match self {
+            StorageEntry::Balance(_) => symbol_short!("balance"),
+            StorageEntry::AssetPrice(_) => symbol_short!("price"),
+            StorageEntry::Position(_) => symbol_short!("position"),
+        }
+    }
+
+    fn extend(&self, env: &Env, ledgers: u32) -> bool {
+        match self {
+            StorageEntry::Balance(user) => {
+                extend_if_present(env, &(user.clone(), symbol_short!("balance")), ledgers)
+            }
+            StorageEntry::AssetPrice(asset_id) => {
+                extend_if_present(env, &RiskDataKey::AssetPrice(*asset_id), ledgers)
+            }
+            StorageEntry::Position(trade_id) => {
+                extend_if_present(env, &PositionKey::Position(trade_id.clone()), ledgers)
+            }
+        }
+    }
+}
+
+fn extend_if_present<K: IntoVal<Env, Val>>(env: &Env, key: &K, ledgers: u32) -> bool {
+    if !env.storage().persistent().has(key) {
+        return false;
+    }
+    env.storage().persistent().extend_ttl(key, ledgers, ledgers);
+    true
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpiringEntry {
+    pub entry: StorageEntry,
+    pub live_until_ledger: u32,
+    /// Zero once the entry has expired.
+    pub ledgers_left: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageHealth {
+    pub tracked_entries: u32,
+    /// Tracked entries with fewer than `TTL_WARNING_LEDGERS` left.
+    pub expiring: Vec<ExpiringEntry>,
+    /// Tracked entries and their minimum remaining TTL, per category.
+    pub categories: Vec<CategoryHealth>,
+}
+
+/// Categories `get_storage_health` reports and `bump_ttls` accepts.
+pub fn storage_categories() -> [Symbol; 3] {
+    [
+        symbol_short!("balance"),
+        symbol_short!("price"),
+        symbol_short!("position"),
+    ]
+}
+
+/// Extend `key` to `MARKET_DATA_TTL_LEDGERS` and record its new expiry.
+fn extend_market_data<K: IntoVal<Env, Val>>(env: &Env, key: &K, entry: StorageEntry) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, MARKET_DATA_TTL_LEDGERS, MARKET_DATA_TTL_LEDGERS);
+    storage_health::record(env, &entry, MARKET_DATA_TTL_LEDGERS);
+}
+
+/// Simulated SDEX balance of `user`.
+pub fn get_user_balance(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(user.clone(), symbol_short!("balance")))
+        .unwrap_or(0)
+}
+
+pub fn set_user_balance(env: &Env, user: &Address, amount: i128) {
+    let key = (user.clone(), symbol_short!("balance"));
+    env.storage().persistent().set(&key, &amount);
+    extend_market_data(env, &key, StorageEntry::Balance(user.clone()));
+}
+
+/// Keep a user's balance alive; called whenever the balance is relied on.
+pub fn touch_user_balance(env: &Env, user: &Address) {
+    let key = (user.clone(), symbol_short!("balance"));
+    if env.storage().persistent().has(&key) {
+        extend_market_data(env, &key, StorageEntry::Balance(user.clone()));
+    }
+}
+
+/// Record the TTL extension of a freshly written asset price.
+pub fn touch_asset_price<K: IntoVal<Env, Val>>(env: &Env, key: &K, asset_id: u32) {
+    extend_market_data(env, key, StorageEntry::AssetPrice(asset_id));
+}
+
+/// Keep a position alive for as long as market data; called on every write.
+pub fn touch_position(env: &Env, trade_id: &BytesN<32>) {
+    extend_market_data(
+        env,
+        &PositionKey::Position(trade_id.clone()),
+        StorageEntry::Position(trade_id.clone()),
+    );
+}
+
+/// Tracked entries that expire within `TTL_WARNING_LEDGERS`, soonest first,
+/// plus a per-category summary.
+pub fn get_storage_health(env: &Env) -> StorageHealth {
+    let expiries = storage_health::get_expiries::<StorageEntry>(env);
+    let now = env.ledger().sequence();
+
+    let mut expiring: Vec<ExpiringEntry> = Vec::new(env);
+    for (entry, live_until_ledger) in expiries.iter() {
+        let ledgers_left = live_until_ledger.saturating_sub(now);
+        if ledgers_left >= TTL_WARNING_LEDGERS {
+            continue;
+        }
+        let item = ExpiringEntry {
+            entry,
+            live_until_ledger,
+            ledgers_left,
+        };
+        let mut index = expiring.len();
+        for i in 0..expiring.len() {
+            if expiring.get(i).unwrap().live_until_ledger > live_until_ledger {
+                index = i;
+                break;
+            }
+        }
+        expiring.insert(index, item);
+    }
+
+    StorageHealth {
+        tracked_entries: expiries.len(),
+        expiring,
+        categories: storage_health::category_health::<StorageEntry>(env, &storage_categories()),
+    }
+}
+
+/// Extend up to `limit` tracked entries of `category`, soonest expiring
+/// first. Returns the number extended.
+pub fn bump_ttls(env: &Env, category: &Symbol, limit: u32) -> u32 {
+    storage_health::bump::<StorageEntry>(env, category, limit, MARKET_DATA_TTL_LEDGERS)
+}
+
+/// Test helper: auth plus max SDEX balance.
+pub fn authorize_user(env: &Env, user: &Address) {
+    authorize_user_with_limits(env, user, i128::MAX / 4, 30);
+    set_user_balance(env, user, i128::MAX);
 }
 
 /// Authorize a user with explicit limits.
@@ -75,9 +291,7 @@ pub fn authorize_user_with_limits(
     env.storage()
         .persistent()
         .set(&AuthKey::Authorization(user.clone()), &config);
-    env.storage()
-        .temporary()
-        .set(&(user.clone(), symbol_short!("balance")), &i128::MAX);
+    set_user_balance(env, user, i128::MAX);
 }
 
 pub fn revoke_user_authorization(env: &Env, user: &Address) {
@@ -85,8 +299,3 @@ pub fn revoke_user_authorization(env: &Env, user: &Address) {
         .persistent()
         .remove(&AuthKey::Authorization(user.clone()));
 }
-
-#[cfg(test)]
-pub fn authorize_user(env: &Env, user: &Address) {
-    authorize_user_with_limits(env, user, 1_000_000_000_000, 30);
-}
@@ -100,7 +100,7 @@ fn min(a: i128, b: i128) -> i128 {
 }
 
 fn generate_arb_id(env: &Env) -> u64 {
-    let mut id: u64 = env.storage().persistent().get(&ArbStorageKey::NextOpportunityId).unwrap_or(1);
+    let id: u64 = env.storage().persistent().get(&ArbStorageKey::NextOpportunityId).unwrap_or(1);
     env.storage().persistent().set(&ArbStorageKey::NextOpportunityId, &(id + 1));
     id
 }
@@ -138,7 +138,7 @@ fn sim_execute_buy(env: &Env, asset_id: u32, amount: i128) -> Result<(i128, i128
     let price_key = (symbol_short!("price"), asset_id);
     let price: i128 = env
         .storage()
-        .temporary()
+        .persistent()
         .get(&price_key)
         .unwrap_or(100_i128);
 
@@ -147,10 +147,7 @@ fn sim_execute_buy(env: &Env, asset_id: u32, amount: i128) -> Result<(i128, i128
 }
 
 fn get_balance(env: &Env, user: &Address) -> i128 {
-    env.storage()
-        .temporary()
-        .get(&(user.clone(), symbol_short!("balance")))
-        .unwrap_or(0)
+    crate::storage::get_user_balance(env, user)
 }
 
 // ── Core functions ───────────────────────────────────────────────────────────
@@ -376,7 +373,7 @@ pub fn analyze_dca_performance(env: &Env, id: u64) -> Result<DCAPerformance, Aut
     let price_key = (symbol_short!("price"), s.asset_pair);
     let current_price: i128 = env
         .storage()
-        .temporary()
+        .persistent()
         .get(&price_key)
         .unwrap_or(100_i128);
 
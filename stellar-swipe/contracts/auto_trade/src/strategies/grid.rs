@@ -121,7 +121,7 @@ fn load(env: &Env, id: u64) -> Result<GridStrategy, AutoTradeError> {
 
 fn get_current_price(env: &Env, asset_pair: u32) -> i128 {
     env.storage()
-        .temporary()
+        .persistent()
         .get(&(symbol_short!("price"), asset_pair))
         .unwrap_or(0)
 }
@@ -129,7 +129,7 @@ fn get_current_price(env: &Env, asset_pair: u32) -> i128 {
 /// Test helper: seed a mock price for an asset pair.
 pub fn set_mock_price(env: &Env, asset_pair: u32, price: i128) {
     env.storage()
-        .temporary()
+        .persistent()
         .set(&(symbol_short!("price"), asset_pair), &price);
 }
 
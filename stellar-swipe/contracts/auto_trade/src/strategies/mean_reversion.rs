@@ -139,7 +139,7 @@ fn get_historical_prices(env: &Env, asset_pair: u32, lookback_seconds: u64) -> R
     // Prices stored as Vec<i128> keyed by (symbol, asset_pair)
     let key = (symbol_short!("hist_px"), asset_pair, lookback_seconds);
     env.storage()
-        .temporary()
+        .persistent()
         .get(&key)
         .ok_or(AutoTradeError::MrInsufficientHistory)
 }
@@ -147,16 +147,13 @@ fn get_historical_prices(env: &Env, asset_pair: u32, lookback_seconds: u64) -> R
 fn get_current_price(env: &Env, asset_pair: u32) -> Result<i128, AutoTradeError> {
     let key = (symbol_short!("price"), asset_pair);
     env.storage()
-        .temporary()
+        .persistent()
         .get(&key)
         .ok_or(AutoTradeError::MrInsufficientHistory)
 }
 
 fn get_portfolio_value(env: &Env, user: &Address) -> i128 {
-    env.storage()
-        .temporary()
-        .get(&(user.clone(), symbol_short!("balance")))
-        .unwrap_or(0)
+    crate::storage::get_user_balance(env, user)
 }
 
 // ── Statistical calculations ──────────────────────────────────────────────────
@@ -180,9 +177,10 @@ pub fn calculate_statistical_metrics(
     let variance: i128 = (0..prices.len())
         .map(|i| {
             let diff = prices.get(i).unwrap() - mean;
-            (diff * diff) / n
+            diff * diff
         })
-        .sum();
+        .sum::<i128>()
+        / n;
     let std_dev = isqrt(variance);
 
     // Require minimum volatility to avoid division by near-zero
@@ -480,19 +478,19 @@ mod tests {
 
     fn set_price(env: &Env, asset: u32, price: i128) {
         env.as_contract(&env.register(TestContract, ()), || {
-            env.storage().temporary().set(&(symbol_short!("price"), asset), &price);
+            env.storage().persistent().set(&(symbol_short!("price"), asset), &price);
         });
     }
 
     fn set_hist_prices(env: &Env, asset: u32, lookback: u64, prices: Vec<i128>) {
         env.as_contract(&env.register(TestContract, ()), || {
-            env.storage().temporary().set(&(symbol_short!("hist_px"), asset, lookback), &prices);
+            env.storage().persistent().set(&(symbol_short!("hist_px"), asset, lookback), &prices);
         });
     }
 
     fn set_balance(env: &Env, user: &Address, balance: i128) {
         env.as_contract(&env.register(TestContract, ()), || {
-            env.storage().temporary().set(&(user.clone(), symbol_short!("balance")), &balance);
+            env.storage().persistent().set(&(user.clone(), symbol_short!("balance")), &balance);
         });
     }
 
@@ -525,11 +523,11 @@ mod tests {
         let contract_addr = env.register(TestContract, ());
         env.as_contract(&contract_addr, || {
             let prices = make_prices(&env, 100_000, 30);
-            env.storage().temporary().set(
+            env.storage().persistent().set(
                 &(symbol_short!("hist_px"), 1u32, 14u64 * 86_400),
                 &prices,
             );
-            env.storage().temporary().set(&(symbol_short!("price"), 1u32), &102_500i128);
+            env.storage().persistent().set(&(symbol_short!("price"), 1u32), &102_500i128);
 
             let m = calculate_statistical_metrics(&env, 1, 14).unwrap();
             assert!(m.mean > 0);
@@ -548,7 +546,7 @@ mod tests {
             for _ in 0..30 {
                 prices.push_back(100_000);
             }
-            env.storage().temporary().set(
+            env.storage().persistent().set(
                 &(symbol_short!("hist_px"), 1u32, 14u64 * 86_400),
                 &prices,
             );
@@ -558,12 +556,12 @@ mod tests {
             for i in 0..30i128 {
                 varied.push_back(100_000 + (i % 10) * 100 - 450);
             }
-            env.storage().temporary().set(
+            env.storage().persistent().set(
                 &(symbol_short!("hist_px"), 1u32, 14u64 * 86_400),
                 &varied,
             );
             // Set current price far above mean (~2.5 std devs)
-            env.storage().temporary().set(&(symbol_short!("price"), 1u32), &101_500i128);
+            env.storage().persistent().set(&(symbol_short!("price"), 1u32), &101_500i128);
 
             let id = create_mean_reversion_strategy(
                 &env, user.clone(), 1, 14, 20_000, 5_000, 1_000, 3,
@@ -587,12 +585,12 @@ mod tests {
             for i in 0..30i128 {
                 varied.push_back(100_000 + (i % 10) * 100 - 450);
             }
-            env.storage().temporary().set(
+            env.storage().persistent().set(
                 &(symbol_short!("hist_px"), 1u32, 14u64 * 86_400),
                 &varied,
             );
-            env.storage().temporary().set(&(symbol_short!("price"), 1u32), &101_500i128);
-            env.storage().temporary().set(&(user.clone(), symbol_short!("balance")), &1_000_000i128);
+            env.storage().persistent().set(&(symbol_short!("price"), 1u32), &101_500i128);
+            env.storage().persistent().set(&(user.clone(), symbol_short!("balance")), &1_000_000i128);
 
             let id = create_mean_reversion_strategy(
                 &env, user.clone(), 1, 14, 5_000, 2_000, 1_000, 3,
@@ -613,7 +611,7 @@ mod tests {
             assert_eq!(pos_id, 0);
 
             // Simulate price reverting to mean
-            env.storage().temporary().set(&(symbol_short!("price"), 1u32), &metrics.mean);
+            env.storage().persistent().set(&(symbol_short!("price"), 1u32), &metrics.mean);
 
             let closed = check_reversion_exits(&env, id).unwrap();
             assert_eq!(closed.len(), 1);
@@ -630,12 +628,12 @@ mod tests {
             for i in 0..30i128 {
                 varied.push_back(100_000 + (i % 10) * 100 - 450);
             }
-            env.storage().temporary().set(
+            env.storage().persistent().set(
                 &(symbol_short!("hist_px"), 1u32, 14u64 * 86_400),
                 &varied,
             );
-            env.storage().temporary().set(&(symbol_short!("price"), 1u32), &101_500i128);
-            env.storage().temporary().set(&(user.clone(), symbol_short!("balance")), &1_000_000i128);
+            env.storage().persistent().set(&(symbol_short!("price"), 1u32), &101_500i128);
+            env.storage().persistent().set(&(user.clone(), symbol_short!("balance")), &1_000_000i128);
 
             let id = create_mean_reversion_strategy(
                 &env, user.clone(), 1, 14, 5_000, 2_000, 1_000, 3,
@@ -656,7 +654,7 @@ mod tests {
             execute_mean_reversion_trade(&env, id, signal).unwrap();
 
             // Price blows through stop loss
-            env.storage().temporary().set(&(symbol_short!("price"), 1u32), &(stop + 1));
+            env.storage().persistent().set(&(symbol_short!("price"), 1u32), &(stop + 1));
 
             let closed = check_reversion_exits(&env, id).unwrap();
             assert_eq!(closed.len(), 1);
@@ -672,12 +670,12 @@ mod tests {
             for i in 0..30i128 {
                 varied.push_back(100_000 + (i % 10) * 100 - 450);
             }
-            env.storage().temporary().set(
+            env.storage().persistent().set(
                 &(symbol_short!("hist_px"), 1u32, 14u64 * 86_400),
                 &varied,
             );
-            env.storage().temporary().set(&(symbol_short!("price"), 1u32), &101_500i128);
-            env.storage().temporary().set(&(user.clone(), symbol_short!("balance")), &1_000_000i128);
+            env.storage().persistent().set(&(symbol_short!("price"), 1u32), &101_500i128);
+            env.storage().persistent().set(&(user.clone(), symbol_short!("balance")), &1_000_000i128);
 
             let id = create_mean_reversion_strategy(
                 &env, user.clone(), 1, 14, 5_000, 2_000, 1_000, 1, // max 1 position
@@ -710,11 +708,11 @@ mod tests {
             for _ in 0..5 {
                 prices.push_back(100_000);
             }
-            env.storage().temporary().set(
+            env.storage().persistent().set(
                 &(symbol_short!("hist_px"), 1u32, 14u64 * 86_400),
                 &prices,
             );
-            env.storage().temporary().set(&(symbol_short!("price"), 1u32), &100_000i128);
+            env.storage().persistent().set(&(symbol_short!("price"), 1u32), &100_000i128);
 
             let id = create_mean_reversion_strategy(
                 &env, user.clone(), 1, 14, 20_000, 5_000, 1_000, 3,
@@ -94,7 +94,7 @@ pub enum MomentumDataKey {
 /// Calculate Rate of Change (ROC) indicator over a period
 ///
 /// ROC = ((Current Price - Old Price) / Old Price) * 10000 (in basis points)
-fn calculate_rate_of_change(prices: &Vec<i128>, period_days: u32) -> Result<i128, AutoTradeError> {
+fn calculate_rate_of_change(prices: &Vec<i128>, _period_days: u32) -> Result<i128, AutoTradeError> {
     if prices.len() < 2 {
         return Err(AutoTradeError::InsufficientPriceHistory);
     }
@@ -373,7 +373,7 @@ pub fn execute_momentum_trade(
     current_price: i128,
     portfolio_value: i128,
 ) -> Result<u64, AutoTradeError> {
-    let mut strategy = get_momentum_strategy(env, strategy_id)?;
+    let strategy = get_momentum_strategy(env, strategy_id)?;
 
     // Check if already have position in this asset pair
     let mut positions = get_strategy_positions(env, strategy_id);
@@ -580,12 +580,12 @@ pub fn store_price_snapshot(
 /// Returns prices from the given time period
 pub fn get_historical_prices(
     env: &Env,
-    asset_pair: AssetPair,
+    _asset_pair: AssetPair,
     period_seconds: u64,
 ) -> Result<Vec<i128>, AutoTradeError> {
     let current_time = env.ledger().timestamp();
-    let start_time = current_time.saturating_sub(period_seconds);
-    let mut prices = Vec::new(env);
+    let _start_time = current_time.saturating_sub(period_seconds);
+    let prices = Vec::new(env);
 
     // In a real implementation, iterate through stored price snapshots
     // For now, return empty vector - actual implementation would fetch from storage
@@ -599,11 +599,18 @@ pub fn get_historical_prices(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Ledger;
-    use soroban_sdk::Env;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
 
     fn setup_test_prices(env: &Env) -> Vec<i128> {
         let mut prices = Vec::new(env);
+        // Consolidation long enough for the 26-period MACD average
+        for _ in 0..6 {
+            prices.push_back(100);
+        }
         // Create a series of prices showing uptrend
         prices.push_back(100); // Start
         prices.push_back(102); // +2%
@@ -630,6 +637,10 @@ mod tests {
 
     fn setup_test_prices_downtrend(env: &Env) -> Vec<i128> {
         let mut prices = Vec::new(env);
+        // Consolidation long enough for the 26-period MACD average
+        for _ in 0..6 {
+            prices.push_back(100);
+        }
         // Create a series of prices showing downtrend
         prices.push_back(100); // Start
         prices.push_back(98);  // -2%
@@ -719,8 +730,6 @@ mod tests {
 
     #[test]
     fn test_calculate_momentum_confidence() {
-        let env = Env::default();
-        
         let indicators = MomentumIndicators {
             rate_of_change: 3000,  // 30% ROC
             rsi: 7500,             // >70% (extreme)
@@ -793,7 +802,8 @@ mod tests {
     fn test_execute_momentum_trade() {
         let env = Env::default();
         env.ledger().set_timestamp(1000);
-
+        let contract = env.register(TestContract, ());
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let asset_pair = AssetPair { base: 1, quote: 2 };
         
@@ -823,7 +833,7 @@ mod tests {
         let current_price = 1000;
         let portfolio_value = 10000;
 
-        let trade_id = execute_momentum_trade(&env, 1, signal, current_price, portfolio_value).unwrap();
+        let _trade_id = execute_momentum_trade(&env, 1, signal, current_price, portfolio_value).unwrap();
 
         // Verify position was created
         let positions = get_strategy_positions(&env, 1);
@@ -833,13 +843,15 @@ mod tests {
         assert_eq!(position.asset_pair, asset_pair);
         assert_eq!(position.entry_price, current_price);
         assert_eq!(position.amount, 1000); // 10% of 10000
+        });
     }
 
     #[test]
     fn test_trailing_stop_update() {
         let env = Env::default();
         env.ledger().set_timestamp(1000);
-
+        let contract = env.register(TestContract, ());
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let asset_pair = AssetPair { base: 1, quote: 2 };
         
@@ -871,6 +883,7 @@ mod tests {
 
         let closed = update_trailing_stops(&env, 1).unwrap();
         assert_eq!(closed.len(), 0); // No positions closed if price doesn't move
+        });
     }
 
     #[test]
@@ -920,7 +933,8 @@ mod tests {
     fn test_rebalance_by_momentum_rank() {
         let env = Env::default();
         env.ledger().set_timestamp(1000);
-
+        let contract = env.register(TestContract, ());
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let pair1 = AssetPair { base: 1, quote: 2 };
         let pair2 = AssetPair { base: 3, quote: 4 };
@@ -950,6 +964,7 @@ mod tests {
 
         let result = rebalance_by_momentum_rank(&env, 1, &ranked, 1);
         assert!(result.is_ok());
+        });
     }
 
     #[test]
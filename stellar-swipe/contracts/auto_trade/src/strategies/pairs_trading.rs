@@ -3,7 +3,6 @@
 use soroban_sdk::{contracttype, Address, Env, Vec, Symbol};
 
 use crate::errors::AutoTradeError;
-use crate::risk;
 
 pub const PRECISION: i128 = 10_000;
 
@@ -259,7 +259,7 @@ pub fn aggregate_sentiment(env: &Env, strategy_id: u64) -> Result<SentimentScore
 
 /// Calculate confidence based on source agreement
 fn calculate_sentiment_confidence(
-    env: &Env,
+    _env: &Env,
     source_scores: &Map<String, i32>,
 ) -> Result<u32, String> {
     if source_scores.len() < 2 {
@@ -308,7 +308,7 @@ fn format_source_name(env: &Env, source: &SentimentSource) -> String {
 /// ==========================
 
 /// Collect Twitter sentiment (would integrate with oracle)
-fn collect_twitter_sentiment(env: &Env, _handle: &String) -> Result<(i32, u32), String> {
+fn collect_twitter_sentiment(_env: &Env, _handle: &String) -> Result<(i32, u32), String> {
     // Placeholder - would verify oracle-provided sentiment data
     // Oracle analyzes tweets mentioning the asset
     let sentiment_score = 5000; // Neutral for now
@@ -317,7 +317,7 @@ fn collect_twitter_sentiment(env: &Env, _handle: &String) -> Result<(i32, u32),
 }
 
 /// Collect Reddit sentiment (would integrate with oracle)
-fn collect_reddit_sentiment(env: &Env, _subreddit: &String) -> Result<(i32, u32), String> {
+fn collect_reddit_sentiment(_env: &Env, _subreddit: &String) -> Result<(i32, u32), String> {
     // Placeholder - would verify oracle-provided sentiment data
     let sentiment_score = 6000; // Slightly bullish
     let weight = 25; // Reddit gets 25% weight
@@ -325,7 +325,7 @@ fn collect_reddit_sentiment(env: &Env, _subreddit: &String) -> Result<(i32, u32)
 }
 
 /// Collect news sentiment (would integrate with oracle)
-fn collect_news_sentiment(env: &Env, _feed_url: &String) -> Result<(i32, u32), String> {
+fn collect_news_sentiment(_env: &Env, _feed_url: &String) -> Result<(i32, u32), String> {
     // Placeholder - would verify oracle-provided sentiment data
     let sentiment_score = 5500;
     let weight = 10; // News gets 10% weight
@@ -359,7 +359,7 @@ fn collect_onchain_sentiment(
 
 /// Calculate sentiment from active addresses
 fn calculate_active_addresses_sentiment(
-    env: &Env,
+    _env: &Env,
     _asset_pair: &AssetPair,
 ) -> Result<i32, String> {
     // Placeholder - would query actual on-chain data
@@ -375,7 +375,7 @@ fn calculate_active_addresses_sentiment(
 
 /// Calculate sentiment from transaction volume
 fn calculate_transaction_volume_sentiment(
-    env: &Env,
+    _env: &Env,
     _asset_pair: &AssetPair,
 ) -> Result<i32, String> {
     // Placeholder - increasing volume = bullish
@@ -390,7 +390,7 @@ fn calculate_transaction_volume_sentiment(
 
 /// Calculate sentiment from holder concentration
 fn calculate_holder_concentration_sentiment(
-    env: &Env,
+    _env: &Env,
     _asset_pair: &AssetPair,
 ) -> Result<i32, String> {
     // Placeholder - decreasing concentration = bullish (more distribution)
@@ -405,7 +405,7 @@ fn calculate_holder_concentration_sentiment(
 
 /// Calculate sentiment from exchange inflows
 fn calculate_exchange_inflows_sentiment(
-    env: &Env,
+    _env: &Env,
     _asset_pair: &AssetPair,
 ) -> Result<i32, String> {
     // High exchange inflows = bearish (potential selling)
@@ -559,7 +559,7 @@ pub fn check_sentiment_signal(
 
 /// Check technical confirmation (placeholder)
 fn check_technical_confirmation(
-    env: &Env,
+    _env: &Env,
     _asset_pair: &AssetPair,
     is_bullish: bool,
 ) -> Result<bool, String> {
@@ -840,12 +840,17 @@ fn sqrt(n: u32) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::{testutils::{Address as _, Ledger as _}, Env};
+    use soroban_sdk::{contract, testutils::{Address as _, Ledger as _}, Env};
+
+    #[contract]
+    struct TestContract;
 
-    fn setup_env() -> Env {
+    fn setup_env() -> (Env, Address) {
         let env = Env::default();
+        env.mock_all_auths();
         env.ledger().set_timestamp(1000);
-        env
+        let contract = env.register(TestContract, ());
+        (env, contract)
     }
 
     fn create_test_asset_pair(env: &Env) -> AssetPair {
@@ -865,13 +870,12 @@ mod tests {
 
     #[test]
     fn test_create_sentiment_strategy() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let asset_pair = create_test_asset_pair(&env);
         let sources = create_test_sources(&env);
         
-        env.mock_all_auths();
-        
         let result = create_sentiment_strategy(
             &env,
             user.clone(),
@@ -891,17 +895,17 @@ mod tests {
         assert_eq!(strategy.user, user);
         assert_eq!(strategy.sentiment_threshold, 5000);
         assert_eq!(strategy.position_size_pct, 2000);
+        });
     }
 
     #[test]
     fn test_create_strategy_invalid_threshold() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
         let user = Address::generate(&env);
         let asset_pair = create_test_asset_pair(&env);
         let sources = create_test_sources(&env);
         
-        env.mock_all_auths();
-        
+        env.as_contract(&contract, || {
         // Too low threshold
         let result = create_sentiment_strategy(
             &env,
@@ -914,7 +918,8 @@ mod tests {
             24,
         );
         assert!(result.is_err());
-        
+        });
+        env.as_contract(&contract, || {
         // Too high threshold
         let result = create_sentiment_strategy(
             &env,
@@ -927,17 +932,17 @@ mod tests {
             24,
         );
         assert!(result.is_err());
+        });
     }
 
     #[test]
     fn test_aggregate_sentiment() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let asset_pair = create_test_asset_pair(&env);
         let sources = create_test_sources(&env);
         
-        env.mock_all_auths();
-        
         let strategy_id = create_sentiment_strategy(
             &env,
             user,
@@ -956,17 +961,17 @@ mod tests {
         assert!(sentiment.confidence > 0);
         assert!(sentiment.confidence <= 10000);
         assert!(sentiment.source_scores.len() > 0);
+        });
     }
 
     #[test]
     fn test_sentiment_decay() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let asset_pair = create_test_asset_pair(&env);
         let sources = create_test_sources(&env);
         
-        env.mock_all_auths();
-        
         let strategy_id = create_sentiment_strategy(
             &env,
             user,
@@ -989,17 +994,17 @@ mod tests {
         // Score should be decayed (50% after 12 hours with 24 hour decay)
         assert!(sentiment.overall_score.abs() < original_score.abs());
         assert_eq!(sentiment.decay_factor, 5000); // 50%
+        });
     }
 
     #[test]
     fn test_check_sentiment_signal_bullish() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let asset_pair = create_test_asset_pair(&env);
         let sources = create_test_sources(&env);
         
-        env.mock_all_auths();
-        
         let strategy_id = create_sentiment_strategy(
             &env,
             user,
@@ -1018,17 +1023,17 @@ mod tests {
             assert!(sig.sentiment_score.abs() >= 3000);
             assert!(sig.confidence > 0);
         }
+        });
     }
 
     #[test]
     fn test_execute_sentiment_trade() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let asset_pair = create_test_asset_pair(&env);
         let sources = create_test_sources(&env);
         
-        env.mock_all_auths();
-        
         let strategy_id = create_sentiment_strategy(
             &env,
             user,
@@ -1061,17 +1066,17 @@ mod tests {
 
         let position = strategy.active_position;
         assert_eq!(position.entry_sentiment, 7000);
+        });
     }
 
     #[test]
     fn test_check_sentiment_exit() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let asset_pair = create_test_asset_pair(&env);
         let sources = create_test_sources(&env);
         
-        env.mock_all_auths();
-        
         let strategy_id = create_sentiment_strategy(
             &env,
             user,
@@ -1099,17 +1104,17 @@ mod tests {
         // Check exit (would exit based on conditions)
         let exit_result = check_sentiment_exit(&env, strategy_id);
         assert!(exit_result.is_ok());
+        });
     }
 
     #[test]
     fn test_sentiment_accuracy_tracking() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
+        env.as_contract(&contract, || {
         let user = Address::generate(&env);
         let asset_pair = create_test_asset_pair(&env);
         let sources = create_test_sources(&env);
         
-        env.mock_all_auths();
-        
         let strategy_id = create_sentiment_strategy(
             &env,
             user,
@@ -1136,11 +1141,13 @@ mod tests {
         assert_eq!(accuracy.total_signals, 1);
         assert_eq!(accuracy.accurate_predictions, 1);
         assert_eq!(accuracy.false_positives, 0);
+        });
     }
 
     #[test]
     fn test_analyze_rationale_sentiment() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
+        env.as_contract(&contract, || {
         
         let bullish_text = String::from_str(&env, "Very bullish breakout with strong momentum");
         let sentiment = analyze_rationale_sentiment(&env, &bullish_text).unwrap();
@@ -1153,11 +1160,13 @@ mod tests {
         let neutral_text = String::from_str(&env, "Market analysis shows mixed signals");
         let sentiment = analyze_rationale_sentiment(&env, &neutral_text).unwrap();
         assert_eq!(sentiment, 0);
+        });
     }
 
     #[test]
     fn test_calculate_sentiment_confidence() {
-        let env = setup_env();
+        let (env, contract) = setup_env();
+        env.as_contract(&contract, || {
         
         // High agreement = high confidence
         let mut scores = Map::new(&env);
@@ -1176,6 +1185,7 @@ mod tests {
         
         let confidence2 = calculate_sentiment_confidence(&env, &scores2).unwrap();
         assert!(confidence2 < confidence); // Lower confidence
+        });
     }
 
     #[test]
@@ -0,0 +1,238 @@
+#![allow(dead_code)]
+//! Strategy tags for executor-side analytics.
+//!
+//! Systematic copiers can define a small set of strategy labels and tag each
+//! execution with one of them. Every tagged execution is folded into running
+//! per-tag totals (volume, fees, realized P&L against an average cost basis)
+//! so performance can be attributed across the copier's own strategies. The
+//! tag is also stored on the trade's history entry.
+
+use soroban_sdk::{contracttype, Address, Env, Map, Symbol, Vec};
+
+use crate::errors::AutoTradeError;
+
+/// Upper bound on strategy tags a user can define.
+pub const MAX_STRATEGY_TAGS: u32 = 10;
+
+#[contracttype]
+pub enum StrategyTagKey {
+    Tags(Address),
+    Stats(Address, Symbol),
+}
+
+/// Open quantity bought under a tag and its average entry price.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TagHolding {
+    pub amount: i128,
+    pub avg_price: i128,
+}
+
+/// Running totals for one strategy tag.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StrategyTagStats {
+    pub trade_count: u32,
+    /// Sum of executed amounts.
+    pub volume: i128,
+    pub fees: i128,
+    /// P&L realized by sells against the tag's average cost, before fees.
+    pub realized_pnl: i128,
+    /// Open holdings per asset, used as the cost basis for later sells.
+    pub holdings: Map<u32, TagHolding>,
+}
+
+impl StrategyTagStats {
+    fn empty(env: &Env) -> Self {
+        StrategyTagStats {
+            trade_count: 0,
+            volume: 0,
+            fees: 0,
+            realized_pnl: 0,
+            holdings: Map::new(env),
+        }
+    }
+}
+
+pub fn get_tags(env: &Env, user: &Address) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&StrategyTagKey::Tags(user.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save_tags(env: &Env, user: &Address, tags: &Vec<Symbol>) {
+    let key = StrategyTagKey::Tags(user.clone());
+    if tags.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, tags);
+    }
+}
+
+pub fn is_registered(env: &Env, user: &Address, tag: &Symbol) -> bool {
+    get_tags(env, user).contains(tag)
+}
+
+/// Add `tag` to the user's strategy set. Registering an existing tag is a no-op.
+pub fn register_tag(env: &Env, user: &Address, tag: &Symbol) -> Result<(), AutoTradeError> {
+    let mut tags = get_tags(env, user);
+    if tags.contains(tag) {
+        return Ok(());
+    }
+    if tags.len() >= MAX_STRATEGY_TAGS {
+        return Err(AutoTradeError::StrategyTagLimitExceeded);
+    }
+    tags.push_back(tag.clone());
+    save_tags(env, user, &tags);
+    Ok(())
+}
+
+/// Retire `tag` and discard its totals. History entries keep their label.
+/// Returns whether the tag was registered.
+pub fn remove_tag(env: &Env, user: &Address, tag: &Symbol) -> bool {
+    let mut tags = get_tags(env, user);
+    match tags.first_index_of(tag) {
+        Some(index) => {
+            tags.remove(index);
+            save_tags(env, user, &tags);
+            env.storage()
+                .persistent()
+                .remove(&StrategyTagKey::Stats(user.clone(), tag.clone()));
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn get_stats(env: &Env, user: &Address, tag: &Symbol) -> StrategyTagStats {
+    env.storage()
+        .persistent()
+        .get(&StrategyTagKey::Stats(user.clone(), tag.clone()))
+        .unwrap_or_else(|| StrategyTagStats::empty(env))
+}
+
+/// Fold one tagged execution into the tag's totals. Sells realize P&L only
+/// up to the quantity previously bought under the same tag.
+#[allow(clippy::too_many_arguments)]
+pub fn record_execution(
+    env: &Env,
+    user: &Address,
+    tag: &Symbol,
+    asset_id: u32,
+    amount: i128,
+    price: i128,
+    is_sell: bool,
+    fee: i128,
+) {
+    let mut stats = get_stats(env, user, tag);
+    stats.trade_count += 1;
+    stats.volume += amount;
+    stats.fees += fee;
+
+    let mut holding = stats.holdings.get(asset_id).unwrap_or_default();
+    if is_sell {
+        let closed = amount.min(holding.amount);
+        stats.realized_pnl += (price - holding.avg_price) * closed;
+        holding.amount -= closed;
+    } else {
+        let total = holding.amount + amount;
+        holding.avg_price = (holding.amount * holding.avg_price + amount * price) / total;
+        holding.amount = total;
+    }
+
+    if holding.amount > 0 {
+        stats.holdings.set(asset_id, holding);
+    } else {
+        stats.holdings.remove(asset_id);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&StrategyTagKey::Stats(user.clone(), tag.clone()), &stats);
+}
+
+/// Totals for every registered tag of `user`.
+pub fn get_report(env: &Env, user: &Address) -> Map<Symbol, StrategyTagStats> {
+    let mut report = Map::new(env);
+    for tag in get_tags(env, user).iter() {
+        let stats = get_stats(env, user, &tag);
+        report.set(tag, stats);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as TestAddress;
+    use soroban_sdk::{contract, symbol_short, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn tag_set_is_bounded() {
+        let env = Env::default();
+        let user = <Address as TestAddress>::generate(&env);
+        let contract_addr = env.register(TestContract, ());
+
+        env.as_contract(&contract_addr, || {
+            let names = ["s0", "s1", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9"];
+            for name in names.iter().take(MAX_STRATEGY_TAGS as usize) {
+                register_tag(&env, &user, &Symbol::new(&env, name)).unwrap();
+            }
+            assert_eq!(
+                register_tag(&env, &user, &symbol_short!("extra")),
+                Err(AutoTradeError::StrategyTagLimitExceeded)
+            );
+            // Re-registering an existing tag is still allowed at the cap.
+            assert!(register_tag(&env, &user, &symbol_short!("s0")).is_ok());
+
+            assert!(remove_tag(&env, &user, &symbol_short!("s0")));
+            assert!(!remove_tag(&env, &user, &symbol_short!("s0")));
+            assert!(register_tag(&env, &user, &symbol_short!("extra")).is_ok());
+        });
+    }
+
+    #[test]
+    fn pnl_attributed_per_tag() {
+        let env = Env::default();
+        let user = <Address as TestAddress>::generate(&env);
+        let contract_addr = env.register(TestContract, ());
+        let momentum = symbol_short!("momentum");
+        let carry = symbol_short!("carry");
+
+        env.as_contract(&contract_addr, || {
+            register_tag(&env, &user, &momentum).unwrap();
+            register_tag(&env, &user, &carry).unwrap();
+
+            record_execution(&env, &user, &momentum, 1, 100, 10, false, 1);
+            record_execution(&env, &user, &momentum, 1, 100, 20, false, 1);
+            // Sold at 25 against an average cost of 15.
+            record_execution(&env, &user, &momentum, 1, 150, 25, true, 2);
+            // A sell with no tagged cost basis realizes nothing.
+            record_execution(&env, &user, &carry, 1, 50, 30, true, 1);
+
+            let stats = get_stats(&env, &user, &momentum);
+            assert_eq!(stats.trade_count, 3);
+            assert_eq!(stats.volume, 350);
+            assert_eq!(stats.fees, 4);
+            assert_eq!(stats.realized_pnl, 1_500);
+            assert_eq!(
+                stats.holdings.get(1),
+                Some(TagHolding {
+                    amount: 50,
+                    avg_price: 15
+                })
+            );
+
+            let report = get_report(&env, &user);
+            assert_eq!(report.len(), 2);
+            assert_eq!(report.get(carry.clone()).unwrap().realized_pnl, 0);
+
+            remove_tag(&env, &user, &carry);
+            assert_eq!(get_stats(&env, &user, &carry).trade_count, 0);
+        });
+    }
+}
@@ -7,7 +7,7 @@ use crate::storage;
 use soroban_sdk::{
     symbol_short,
     testutils::{Address as _, Events as _, Ledger as _},
-    Address, Env, IntoVal, Symbol, Val,
+    Address, Env, IntoVal, Symbol, TryFromVal,
 };
 
 fn setup_env() -> Env {
@@ -17,12 +17,26 @@ fn setup_env() -> Env {
     env
 }
 
+/// The bridge rate limits' minimum amount and cooldown would reject the
+/// small, back-to-back trades these tests place.
+fn lift_rate_limits(env: &Env) {
+    crate::rate_limit::set_limits(
+        env,
+        &crate::rate_limit::BridgeRateLimits {
+            min_transfer_amount: 0,
+            cooldown_between_transfers: 0,
+            ..Default::default()
+        },
+    );
+}
+
 fn setup_signal(_env: &Env, signal_id: u64, expiry: u64) -> storage::Signal {
     storage::Signal {
         signal_id,
         price: 100,
         expiry,
         base_asset: 1,
+        action: storage::SignalAction::Buy,
     }
 }
 
@@ -234,8 +248,9 @@ fn test_execute_trade_insufficient_balance() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &50i128);
 
         let res = AutoTradeContract::execute_trade(
@@ -261,11 +276,12 @@ fn test_execute_trade_market_full_fill() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &500i128);
 
         let res = AutoTradeContract::execute_trade(
@@ -294,11 +310,12 @@ fn test_execute_trade_market_partial_fill() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &100i128);
 
         let res = AutoTradeContract::execute_trade(
@@ -316,6 +333,59 @@ fn test_execute_trade_market_partial_fill() {
     });
 }
 
+#[test]
+fn test_execute_trade_sell_signal_reduces_then_bounds_short() {
+    let env = setup_env();
+    let contract_id = env.register(AutoTradeContract, ());
+    let user = Address::generate(&env);
+    let signal_id = 3;
+    let signal = storage::Signal {
+        action: storage::SignalAction::Sell,
+        ..setup_signal(&env, signal_id, env.ledger().timestamp() + 100_000)
+    };
+
+    env.as_contract(&contract_id, || {
+        storage::set_signal(&env, signal_id, &signal);
+        auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
+        env.storage()
+            .persistent()
+            .set(&(user.clone(), symbol_short!("balance")), &5000i128);
+        risk::set_asset_price(&env, 1, 100);
+        risk::set_asset_price(&env, 2, 100);
+        risk::update_position(&env, &user, 1, 1000, 100);
+        risk::update_position(&env, &user, 2, 4000, 100);
+
+        let res = AutoTradeContract::execute_trade(
+            env.clone(),
+            user.clone(),
+            signal_id,
+            OrderType::Market,
+            400,
+        )
+        .unwrap();
+
+        assert_eq!(res.trade.executed_amount, 400);
+        assert_eq!(res.trade.status, TradeStatus::Filled);
+        let position = risk::get_user_positions(&env, &user).get(1).unwrap();
+        assert_eq!(position.amount, 600);
+    });
+
+    env.as_contract(&contract_id, || {
+        // Short 1400 would be 1400 / (4000 + 1400) = 25% of exposure > 20%
+        env.ledger().set_timestamp(env.ledger().timestamp() + 7200);
+        let res = AutoTradeContract::execute_trade(
+            env.clone(),
+            user.clone(),
+            signal_id,
+            OrderType::Market,
+            2000,
+        );
+        assert_eq!(res, Err(AutoTradeError::PositionLimitExceeded));
+        assert_eq!(risk::get_user_positions(&env, &user).get(1).unwrap().amount, 600);
+    });
+}
+
 #[test]
 fn test_simulate_copy_trade_success() {
     let env = setup_env();
@@ -328,13 +398,13 @@ fn test_simulate_copy_trade_success() {
         storage::set_signal(&env, signal_id, &signal);
         storage::authorize_user(&env, &user);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &500i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("price"), signal_id), &101i128);
 
         let simulation =
@@ -383,11 +453,12 @@ fn test_execute_trade_limit_filled() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("price"), signal_id), &90i128);
 
         let res = AutoTradeContract::execute_trade(
@@ -416,11 +487,12 @@ fn test_execute_trade_limit_not_filled() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("price"), signal_id), &150i128);
 
         let res = AutoTradeContract::execute_trade(
@@ -449,11 +521,12 @@ fn test_get_trade_existing() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &500i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &500i128);
     });
 
@@ -475,6 +548,58 @@ fn test_get_trade_existing() {
     });
 }
 
+#[test]
+fn test_trade_receipt_carries_signal_ref() {
+    let env = setup_env();
+    let contract_id = env.register(AutoTradeContract, ());
+    let admin = Address::generate(&env);
+    let registry = Address::generate(&env);
+    let user = Address::generate(&env);
+    let signal_id = 1;
+    let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
+
+    env.as_contract(&contract_id, || {
+        // Without a registry the contract itself is the issuer.
+        assert_eq!(
+            AutoTradeContract::get_signal_ref(env.clone(), signal_id).registry,
+            contract_id
+        );
+
+        AutoTradeContract::initialize(env.clone(), admin.clone());
+        assert_eq!(
+            AutoTradeContract::set_signal_registry(env.clone(), user.clone(), registry.clone()),
+            Err(AutoTradeError::Unauthorized)
+        );
+        AutoTradeContract::set_signal_registry(env.clone(), admin.clone(), registry.clone())
+            .unwrap();
+
+        storage::set_signal(&env, signal_id, &signal);
+        auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
+        storage::set_user_balance(&env, &user, 500);
+    });
+
+    env.as_contract(&contract_id, || {
+        let result = AutoTradeContract::execute_trade(
+            env.clone(),
+            user.clone(),
+            signal_id,
+            OrderType::Market,
+            400,
+        )
+        .unwrap();
+        assert_eq!(
+            result.trade.signal_ref,
+            stellar_swipe_common::SignalRef::new(registry.clone(), signal_id)
+        );
+    });
+
+    env.as_contract(&contract_id, || {
+        let history = AutoTradeContract::get_trade_history(env.clone(), user.clone(), 0, 10);
+        assert_eq!(history.get(0).unwrap().signal_ref.registry, registry);
+    });
+}
+
 #[test]
 fn test_get_trade_non_existing() {
     let env = setup_env();
@@ -543,11 +668,12 @@ fn test_position_limit_allows_first_trade() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &1000i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &1000i128);
 
         // First trade should be allowed
@@ -574,11 +700,12 @@ fn test_get_user_positions() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &1000i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &500i128);
 
         // Execute a trade
@@ -615,11 +742,11 @@ fn test_stop_loss_check() {
         let config = risk::RiskConfig::default(); // 15% stop loss
 
         // Price at 90 (10% drop) - should NOT trigger
-        let triggered = risk::check_stop_loss(&env, &user, 1, 90, &config);
+        let triggered = risk::check_stop_loss(&env, &user, 1, 90, None, &config);
         assert!(!triggered);
 
         // Price at 80 (20% drop) - should trigger
-        let triggered = risk::check_stop_loss(&env, &user, 1, 80, &config);
+        let triggered = risk::check_stop_loss(&env, &user, 1, 80, None, &config);
         assert!(triggered);
     });
 }
@@ -702,10 +829,10 @@ fn test_trailing_stop_triggers_auto_sell_and_event() {
             1u32,
         )
             .into_val(&env);
-        let expected_data: Val = result.into_val(&env);
         let events = env.events().all();
         assert!(events.iter().any(|event| {
-            event.1 == expected_topics && event.2 == expected_data
+            event.1 == expected_topics
+                && AutoSellResult::try_from_val(&env, &event.2).ok() == Some(result.clone())
         }));
     });
 }
@@ -731,7 +858,7 @@ fn test_trailing_stop_partial_fill_keeps_remaining_position() {
         risk::update_position(&env, &user, 1, 1_000, 100);
         AutoTradeContract::process_price_update(env.clone(), user.clone(), 1, 200);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("asset_liq"), 1u32), &400i128);
 
         let result = AutoTradeContract::process_price_update(env.clone(), user.clone(), 1, 170)
@@ -843,6 +970,7 @@ fn test_get_trade_history_paginated() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         risk::set_risk_config(
             &env,
             &user,
@@ -855,10 +983,10 @@ fn test_get_trade_history_paginated() {
             },
         );
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &5000i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &5000i128);
     });
 
@@ -898,6 +1026,196 @@ fn test_get_trade_history_empty() {
     });
 }
 
+#[test]
+fn test_execute_tagged_trade_records_strategy() {
+    let env = setup_env();
+    let contract_id = env.register(AutoTradeContract, ());
+    let user = Address::generate(&env);
+    let signal_id = 1;
+    let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
+    let tag = symbol_short!("momentum");
+
+    env.as_contract(&contract_id, || {
+        storage::set_signal(&env, signal_id, &signal);
+        auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
+        env.storage()
+            .persistent()
+            .set(&(user.clone(), symbol_short!("balance")), &5000i128);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("liquidity"), signal_id), &5000i128);
+
+        let res = AutoTradeContract::execute_tagged_trade(
+            env.clone(),
+            user.clone(),
+            signal_id,
+            OrderType::Market,
+            100,
+            tag.clone(),
+        );
+        assert_eq!(res, Err(AutoTradeError::StrategyTagNotRegistered));
+    });
+
+    env.as_contract(&contract_id, || {
+        AutoTradeContract::register_strategy_tag(env.clone(), user.clone(), tag.clone()).unwrap();
+    });
+
+    env.as_contract(&contract_id, || {
+        AutoTradeContract::execute_tagged_trade(
+            env.clone(),
+            user.clone(),
+            signal_id,
+            OrderType::Market,
+            100,
+            tag.clone(),
+        )
+        .unwrap();
+    });
+
+    env.as_contract(&contract_id, || {
+        let history = AutoTradeContract::get_trade_history(env.clone(), user.clone(), 0, 10);
+        assert_eq!(history.get(0).unwrap().strategy_tag, Some(tag.clone()));
+
+        let stats = AutoTradeContract::get_strategy_stats(env.clone(), user.clone(), tag.clone());
+        assert_eq!(stats.trade_count, 1);
+        assert_eq!(stats.volume, 100);
+        assert_eq!(
+            AutoTradeContract::get_strategy_report(env.clone(), user.clone()).len(),
+            1
+        );
+    });
+}
+
+#[test]
+fn test_place_order_applies_time_in_force() {
+    let env = setup_env();
+    let contract_id = env.register(AutoTradeContract, ());
+    let client = AutoTradeContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+    let signal_id = 1;
+    let signal = setup_signal(&env, signal_id, env.ledger().timestamp() + 1000);
+    let balance = |env: &Env| env.as_contract(&contract_id, || storage::get_user_balance(env, &user));
+
+    env.as_contract(&contract_id, || {
+        storage::set_signal(&env, signal_id, &signal);
+        auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
+        env.storage()
+            .persistent()
+            .set(&(user.clone(), symbol_short!("balance")), &500i128);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("liquidity"), signal_id), &100i128);
+        // A larger holding elsewhere keeps the fills inside the position limit.
+        risk::set_asset_price(&env, 2, 100);
+        risk::update_position(&env, &user, 2, 10_000, 100);
+    });
+
+    // Fill or kill: only 100 of 300 can fill, so nothing happens.
+    assert_eq!(
+        client.try_place_order(&user, &signal_id, &OrderType::Market, &300, &TimeInForce::Fok),
+        Err(Ok(AutoTradeError::InsufficientLiquidity))
+    );
+    assert!(client.get_trade(&user, &signal_id).is_none());
+
+    // Immediate or cancel: keep the 100, drop the rest.
+    let placed = client.place_order(&user, &signal_id, &OrderType::Market, &300, &TimeInForce::Ioc);
+    assert_eq!(placed.trade.executed_amount, 100);
+    assert_eq!(placed.order_id, None);
+    assert_eq!(balance(&env), 500);
+
+    // Good till time: the remainder rests with its size held in escrow.
+    let expires_at = env.ledger().timestamp() + 100;
+    let placed = client.place_order(
+        &user,
+        &signal_id,
+        &OrderType::Market,
+        &300,
+        &TimeInForce::Gtt(expires_at),
+    );
+    let order_id = placed.order_id.unwrap();
+    let order = client.get_order(&order_id);
+    assert_eq!(order.status, RestingOrderStatus::Open);
+    assert_eq!((order.filled, order.escrowed), (100, 200));
+    assert_eq!(balance(&env), 300);
+    assert_eq!(client.expire_orders(&0, &10).len(), 0);
+
+    env.ledger().set_timestamp(expires_at);
+    // The sweep only looks past the cursor.
+    assert_eq!(client.expire_orders(&order_id, &10).len(), 0);
+    assert_eq!(client.expire_orders(&0, &10), soroban_sdk::vec![&env, order_id]);
+    let order = client.get_order(&order_id);
+    assert_eq!(order.status, RestingOrderStatus::Expired);
+    assert_eq!(order.escrowed, 0);
+    assert_eq!(balance(&env), 500);
+    assert_eq!(client.get_open_orders().len(), 0);
+    assert_eq!(
+        client.try_cancel_order(&user, &order_id),
+        Err(Ok(AutoTradeError::OrderNotOpen))
+    );
+}
+
+#[test]
+fn test_storage_health_reports_expiring_entries() {
+    let env = setup_env();
+    let contract_id = env.register(AutoTradeContract, ());
+    let user = Address::generate(&env);
+    let start = env.ledger().sequence();
+
+    env.as_contract(&contract_id, || {
+        env.storage().instance().extend_ttl(
+            storage::MARKET_DATA_TTL_LEDGERS * 2,
+            storage::MARKET_DATA_TTL_LEDGERS * 2,
+        );
+        storage::set_user_balance(&env, &user, 500);
+        risk::set_asset_price(&env, 1, 100);
+
+        let health = AutoTradeContract::get_storage_health(env.clone());
+        assert_eq!(health.tracked_entries, 2);
+        assert!(health.expiring.is_empty());
+    });
+
+    // Move into the warning window, then refresh only the balance.
+    let elapsed = storage::MARKET_DATA_TTL_LEDGERS - storage::TTL_WARNING_LEDGERS + 1;
+    env.ledger().set_sequence_number(start + elapsed);
+
+    env.as_contract(&contract_id, || {
+        let health = AutoTradeContract::get_storage_health(env.clone());
+        assert_eq!(health.expiring.len(), 2);
+        assert_eq!(
+            health.expiring.get(0).unwrap().ledgers_left,
+            storage::TTL_WARNING_LEDGERS - 1
+        );
+
+        assert_eq!(storage::get_user_balance(&env, &user), 500);
+        storage::touch_user_balance(&env, &user);
+
+        let health = AutoTradeContract::get_storage_health(env.clone());
+        assert_eq!(health.expiring.len(), 1);
+        assert_eq!(
+            health.expiring.get(0).unwrap().entry,
+            storage::StorageEntry::AssetPrice(1)
+        );
+        let prices = health.categories.get(1).unwrap();
+        assert_eq!(prices.category, symbol_short!("price"));
+        assert_eq!(prices.tracked, 1);
+        assert_eq!(prices.min_ttl_ledgers, storage::TTL_WARNING_LEDGERS - 1);
+
+        // A maintenance bump pulls the price out of the warning window.
+        assert_eq!(
+            AutoTradeContract::bump_ttls(env.clone(), symbol_short!("price"), 10),
+            1
+        );
+        let health = AutoTradeContract::get_storage_health(env.clone());
+        assert!(health.expiring.is_empty());
+        assert_eq!(
+            health.categories.get(1).unwrap().min_ttl_ledgers,
+            storage::MARKET_DATA_TTL_LEDGERS
+        );
+    });
+}
+
 #[test]
 fn test_get_portfolio() {
     let env = setup_env();
@@ -909,11 +1227,12 @@ fn test_get_portfolio() {
     env.as_contract(&contract_id, || {
         storage::set_signal(&env, signal_id, &signal);
         auth::grant_authorization(&env, &user, 1000000, 30).unwrap();
+        lift_rate_limits(&env);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &1000i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &500i128);
 
         let _ = AutoTradeContract::execute_trade(
@@ -1082,10 +1401,10 @@ fn test_trade_under_limit_succeeds() {
         storage::set_signal(&env, signal_id, &signal);
         storage::authorize_user_with_limits(&env, &user, 500_0000000, 30);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &1000_0000000i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &1000_0000000i128);
 
         let res = AutoTradeContract::execute_trade(
@@ -1114,7 +1433,7 @@ fn test_trade_over_limit_fails() {
         storage::authorize_user_with_limits(&env, &user, 500_0000000, 30);
 
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &1000_0000000i128);
 
         let res = AutoTradeContract::execute_trade(
@@ -1220,10 +1539,10 @@ fn test_authorization_at_exact_limit() {
         storage::authorize_user_with_limits(&env, &user, 500_0000000, 30);
 
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(user.clone(), symbol_short!("balance")), &1000_0000000i128);
         env.storage()
-            .temporary()
+            .persistent()
             .set(&(symbol_short!("liquidity"), signal_id), &1000_0000000i128);
 
         let res = AutoTradeContract::execute_trade(
@@ -1236,8 +1555,6 @@ fn test_authorization_at_exact_limit() {
         assert!(res.is_ok());
     });
 }
-feat/batch-copy-trade
-
 
 // ========================================
 // DCA Strategy Tests
@@ -1248,55 +1565,310 @@ mod dca_tests {
     use crate::strategies::dca::*;
     use soroban_sdk::{
         symbol_short,
+        testutils::{Address as _, Ledger as _},
+        Env,
+    };
 
-#[test]
-fn test_stat_arb_trade_creates_active_portfolio_state_correctly() {
-    let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
-    let user = Address::generate(&env);
-
-    env.as_contract(&contract_id, || {
-        AutoTradeContract::set_stat_arb_price_history(
-            env.clone(),
-            1,
-            stat_arb_history(&env, &[100, 101, 102, 103, 104, 180]),
-        )
-        .unwrap();
-        AutoTradeContract::set_stat_arb_price_history(
-            env.clone(),
-            2,
-            stat_arb_history(&env, &[80, 81, 82, 83, 84, 85]),
-        )
-        .unwrap();
-        AutoTradeContract::set_stat_arb_price_history(
-            env.clone(),
-            3,
-            stat_arb_history(&env, &[60, 61, 62, 63, 64, 65]),
-        )
-        .unwrap();
-    });
+    fn setup() -> (Env, soroban_sdk::Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+        let user = soroban_sdk::Address::generate(&env);
+        (env, user)
+    }
 
-    configure_stat_arb(&env, &contract_id, &user, 500, 250);
+    fn set_price(env: &Env, asset: u32, price: i128) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("price"), asset), &price);
+    }
 
-    env.as_contract(&contract_id, || {
-        let portfolio =
-            AutoTradeContract::execute_stat_arb_trade(env.clone(), user.clone(), 90_000).unwrap();
+    fn set_balance(env: &Env, user: &soroban_sdk::Address, bal: i128) {
+        env.storage()
+            .persistent()
+            .set(&(user.clone(), symbol_short!("balance")), &bal);
+    }
 
-        assert_eq!(portfolio.asset_positions.len(), 3);
-        assert_eq!(portfolio.total_value, 90_000);
-        assert!(
-            AutoTradeContract::get_active_stat_arb_portfolio(env.clone(), user.clone()).is_some()
-        );
-    });
-}
+    #[test]
+    fn test_create_dca_strategy() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, Some(30))
+                .unwrap();
+            assert_eq!(id, 0);
+            let s = get_dca_strategy(&env, id).unwrap();
+            assert_eq!(s.purchase_amount, 10);
+            assert_eq!(s.status, DCAStatus::Active);
+            assert_eq!(s.end_time, 1_000 + 30 * 86_400);
+        });
+    }
 
-#[test]
-fn test_stat_arb_rebalance_updates_toward_new_hedge_ratios() {
-    let env = setup_env();
-    let contract_id = env.register(AutoTradeContract, ());
-    let user = Address::generate(&env);
+    #[test]
+    fn test_first_purchase_executes_immediately() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_price(&env, 1, 100);
+            set_balance(&env, &user, 1_000);
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None).unwrap();
+            assert!(is_purchase_due(&env, id).unwrap());
+            execute_dca_purchase(&env, id).unwrap();
+            let s = get_dca_strategy(&env, id).unwrap();
+            assert_eq!(s.purchases.len(), 1);
+            assert_eq!(s.total_invested, 10);
+        });
+    }
 
-    env.as_contract(&contract_id, || {
+    #[test]
+    fn test_second_purchase_after_one_day() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_price(&env, 1, 100);
+            set_balance(&env, &user, 1_000);
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None).unwrap();
+            execute_dca_purchase(&env, id).unwrap();
+
+            // Not due yet
+            assert!(!is_purchase_due(&env, id).unwrap());
+
+            // Advance 1 day
+            env.ledger().set_timestamp(1_000 + 86_400);
+            assert!(is_purchase_due(&env, id).unwrap());
+            execute_dca_purchase(&env, id).unwrap();
+
+            let s = get_dca_strategy(&env, id).unwrap();
+            assert_eq!(s.purchases.len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_average_entry_price_calculation() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_balance(&env, &user, 10_000);
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 100, DCAFrequency::Daily, None).unwrap();
+
+            // Purchase 1 at price 100
+            set_price(&env, 1, 100);
+            execute_dca_purchase(&env, id).unwrap();
+
+            // Purchase 2 at price 200
+            env.ledger().set_timestamp(1_000 + 86_400);
+            set_price(&env, 1, 200);
+            execute_dca_purchase(&env, id).unwrap();
+
+            let s = get_dca_strategy(&env, id).unwrap();
+            // total_invested = 200, total_acquired = 1_000_000 + 500_000 = 1_500_000 (PRECISION=1_000_000)
+            // avg = (200 * 1_000_000) / 1_500_000 = 133
+            assert!(s.average_entry_price > 0);
+            assert!(s.average_entry_price < 200);
+        });
+    }
+
+    #[test]
+    fn test_pause_stops_purchases() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_price(&env, 1, 100);
+            set_balance(&env, &user, 1_000);
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None).unwrap();
+            execute_dca_purchase(&env, id).unwrap();
+            pause_dca_strategy(&env, id).unwrap();
+
+            env.ledger().set_timestamp(1_000 + 86_400);
+            assert!(!is_purchase_due(&env, id).unwrap());
+        });
+    }
+
+    #[test]
+    fn test_resume_restarts_purchases() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_price(&env, 1, 100);
+            set_balance(&env, &user, 1_000);
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None).unwrap();
+            execute_dca_purchase(&env, id).unwrap();
+            pause_dca_strategy(&env, id).unwrap();
+
+            env.ledger().set_timestamp(1_000 + 86_400);
+            assert!(!is_purchase_due(&env, id).unwrap());
+
+            resume_dca_strategy(&env, id).unwrap();
+            assert!(is_purchase_due(&env, id).unwrap());
+            execute_dca_purchase(&env, id).unwrap();
+
+            let s = get_dca_strategy(&env, id).unwrap();
+            assert_eq!(s.purchases.len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_analyze_performance() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_price(&env, 1, 100);
+            set_balance(&env, &user, 1_000);
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 100, DCAFrequency::Daily, None).unwrap();
+            execute_dca_purchase(&env, id).unwrap();
+
+            let perf = analyze_dca_performance(&env, id).unwrap();
+            assert_eq!(perf.total_invested, 100);
+            assert_eq!(perf.total_purchases, 1);
+            assert_eq!(perf.current_price, 100);
+        });
+    }
+
+    #[test]
+
+    fn test_end_time_stops_purchases() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_price(&env, 1, 100);
+            set_balance(&env, &user, 1_000);
+            // 1-day duration
+            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, Some(1))
+                .unwrap();
+            execute_dca_purchase(&env, id).unwrap();
+
+            // Advance past end_time
+            env.ledger().set_timestamp(1_000 + 86_400 + 1);
+            assert!(!is_purchase_due(&env, id).unwrap());
+        });
+    }
+
+    #[test]
+    fn test_insufficient_balance_pauses_strategy() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_price(&env, 1, 100);
+            set_balance(&env, &user, 5); // less than purchase_amount=10
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None).unwrap();
+            let err = execute_dca_purchase(&env, id).unwrap_err();
+            assert_eq!(err, crate::errors::AutoTradeError::InsufficientBalance);
+            let s = get_dca_strategy(&env, id).unwrap();
+            assert_eq!(s.status, DCAStatus::Paused);
+        });
+    }
+
+    #[test]
+    fn test_update_dca_schedule() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None).unwrap();
+            update_dca_schedule(&env, id, Some(50), Some(DCAFrequency::Weekly)).unwrap();
+            let s = get_dca_strategy(&env, id).unwrap();
+            assert_eq!(s.purchase_amount, 50);
+            assert_eq!(s.frequency, DCAFrequency::Weekly);
+        });
+    }
+
+    #[test]
+    fn test_handle_missed_purchases() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_price(&env, 1, 100);
+            set_balance(&env, &user, 10_000);
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None).unwrap();
+
+            // Advance 3 days without executing
+            env.ledger().set_timestamp(1_000 + 3 * 86_400);
+            let missed = handle_missed_dca_purchases(&env, id).unwrap();
+            assert_eq!(missed, 3);
+            let s = get_dca_strategy(&env, id).unwrap();
+            assert_eq!(s.purchases.len(), 3);
+        });
+    }
+
+    #[test]
+    fn test_custom_frequency() {
+        let (env, user) = setup();
+        let contract = env.register(crate::AutoTradeContract, ());
+        env.as_contract(&contract, || {
+            set_price(&env, 1, 100);
+            set_balance(&env, &user, 1_000);
+            let id =
+                create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Custom(3_600), None)
+                    .unwrap();
+            execute_dca_purchase(&env, id).unwrap();
+
+            // Not due after 30 min
+            env.ledger().set_timestamp(1_000 + 1_800);
+            assert!(!is_purchase_due(&env, id).unwrap());
+
+            // Due after 1 hour
+            env.ledger().set_timestamp(1_000 + 3_600);
+            assert!(is_purchase_due(&env, id).unwrap());
+        });
+    }
+}
+
+#[test]
+fn test_stat_arb_trade_creates_active_portfolio_state_correctly() {
+    let env = setup_env();
+    let contract_id = env.register(AutoTradeContract, ());
+    let user = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        AutoTradeContract::set_stat_arb_price_history(
+            env.clone(),
+            1,
+            stat_arb_history(&env, &[100, 101, 102, 103, 104, 180]),
+        )
+        .unwrap();
+        AutoTradeContract::set_stat_arb_price_history(
+            env.clone(),
+            2,
+            stat_arb_history(&env, &[80, 81, 82, 83, 84, 85]),
+        )
+        .unwrap();
+        AutoTradeContract::set_stat_arb_price_history(
+            env.clone(),
+            3,
+            stat_arb_history(&env, &[60, 61, 62, 63, 64, 65]),
+        )
+        .unwrap();
+    });
+
+    configure_stat_arb(&env, &contract_id, &user, 500, 250);
+
+    env.as_contract(&contract_id, || {
+        let portfolio =
+            AutoTradeContract::execute_stat_arb_trade(env.clone(), user.clone(), 90_000).unwrap();
+
+        assert_eq!(portfolio.asset_positions.len(), 3);
+        assert_eq!(portfolio.total_value, 90_000);
+        assert!(
+            AutoTradeContract::get_active_stat_arb_portfolio(env.clone(), user.clone()).is_some()
+        );
+    });
+}
+
+#[test]
+fn test_stat_arb_rebalance_updates_toward_new_hedge_ratios() {
+    let env = setup_env();
+    let contract_id = env.register(AutoTradeContract, ());
+    let user = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
         AutoTradeContract::set_stat_arb_price_history(
             env.clone(),
             1,
@@ -1410,10 +1982,7 @@ fn test_stat_arb_exit_closes_on_convergence() {
 mod exit_strategy_tests {
     use super::*;
     use crate::exit_strategy::{StopLossTier, StrategyStatus, TakeProfitTier};
-    use soroban_sdk::{
-        testutils::{Address as _, Ledger as _},
-        Env, Vec,
-    };
+    use soroban_sdk::{Env, Vec};
 
     fn setup() -> (Env, Address) {
         let env = Env::default();
@@ -1431,12 +2000,13 @@ mod exit_strategy_tests {
         let user = Address::generate(&env);
 
         env.as_contract(&cid, || {
-            let id = AutoTradeContract::create_conservative_exit(
+            let id = AutoTradeContract::exit_strategy_conservative(
                 env.clone(), user.clone(), 1, 1_000, 10_000,
             ).unwrap();
 
             // TP1 at +20% = 1200
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 1_200).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 1_200).unwrap();
             assert_eq!(trades.len(), 1);
 
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
@@ -1452,12 +2022,13 @@ mod exit_strategy_tests {
         let user = Address::generate(&env);
 
         env.as_contract(&cid, || {
-            let id = AutoTradeContract::create_conservative_exit(
+            let id = AutoTradeContract::exit_strategy_conservative(
                 env.clone(), user.clone(), 1, 1_000, 10_000,
             ).unwrap();
 
             // Price gaps past TP1 and TP2 simultaneously
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 1_500).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 1_500).unwrap();
             assert_eq!(trades.len(), 2);
 
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
@@ -1474,12 +2045,13 @@ mod exit_strategy_tests {
         let user = Address::generate(&env);
 
         env.as_contract(&cid, || {
-            let id = AutoTradeContract::create_conservative_exit(
+            let id = AutoTradeContract::exit_strategy_conservative(
                 env.clone(), user.clone(), 1, 1_000, 10_000,
             ).unwrap();
 
             // Price hits all 3 TPs at once (+100%)
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 2_000).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 2_000).unwrap();
             assert_eq!(trades.len(), 3);
 
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
@@ -1497,13 +2069,13 @@ mod exit_strategy_tests {
 
         env.as_contract(&cid, || {
             // entry=1000, trail=10% from start → stop at 900
-            let id = AutoTradeContract::create_conservative_exit(
+            let id = AutoTradeContract::exit_strategy_conservative(
                 env.clone(), user.clone(), 1, 1_000, 10_000,
             ).unwrap();
 
-            // Price rises to 1100 (no TP), then drops 10% → stop at 990
-            AutoTradeContract::check_exit_strategy(env.clone(), id, 1_100).unwrap();
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 990).unwrap();
+            // Price rises to 1100 (no TP), then drops below the stop at 990
+            AutoTradeContract::check_and_execute_exits(env.clone(), id, 1_100).unwrap();
+            let trades = AutoTradeContract::check_and_execute_exits(env.clone(), id, 989).unwrap();
             assert_eq!(trades.len(), 1);
 
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
@@ -1521,19 +2093,21 @@ mod exit_strategy_tests {
 
         env.as_contract(&cid, || {
             // Balanced: trail 10% initially, tightens to 7% after 20% profit
-            let id = AutoTradeContract::create_balanced_exit(
+            let id = AutoTradeContract::create_exit_strategy_balanced(
                 env.clone(), user.clone(), 1, 1_000, 10_000,
             ).unwrap();
 
             // Rise to 1200 (+20%) → tier 2 activates (trail 7%)
-            AutoTradeContract::check_exit_strategy(env.clone(), id, 1_200).unwrap();
+            AutoTradeContract::check_and_execute_exits(env.clone(), id, 1_200).unwrap();
 
             // 1200 * 93% = 1116 → exactly at 7% trail, no stop
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 1_116).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 1_116).unwrap();
             assert_eq!(trades.len(), 0);
 
             // 1115 → just below 7% trail of 1200 → stop triggered
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 1_115).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 1_115).unwrap();
             assert_eq!(trades.len(), 1);
 
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
@@ -1549,11 +2123,12 @@ mod exit_strategy_tests {
         let user = Address::generate(&env);
 
         env.as_contract(&cid, || {
-            let id = AutoTradeContract::create_aggressive_exit(
+            let id = AutoTradeContract::create_exit_strategy_aggressive(
                 env.clone(), user.clone(), 1, 1_000, 10_000,
             ).unwrap();
 
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 2_500).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 2_500).unwrap();
             assert_eq!(trades.len(), 4);
 
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
@@ -1568,19 +2143,21 @@ mod exit_strategy_tests {
         let user = Address::generate(&env);
 
         env.as_contract(&cid, || {
-            let id = AutoTradeContract::create_aggressive_exit(
+            let id = AutoTradeContract::create_exit_strategy_aggressive(
                 env.clone(), user.clone(), 1, 1_000, 10_000,
             ).unwrap();
 
             // Rise to 1500 (+50%) → tier 3 activates (trail 5%)
-            AutoTradeContract::check_exit_strategy(env.clone(), id, 1_500).unwrap();
+            AutoTradeContract::check_and_execute_exits(env.clone(), id, 1_500).unwrap();
 
             // 1500 * 95% = 1425 → exactly at 5% trail, no stop
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 1_425).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 1_425).unwrap();
             assert_eq!(trades.len(), 0);
 
             // 1424 → just below 5% trail → stop triggered
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 1_424).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 1_424).unwrap();
             assert_eq!(trades.len(), 1);
 
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
@@ -1608,13 +2185,13 @@ mod exit_strategy_tests {
             ).unwrap();
 
             // TP1 at 120 → close 50%
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 120).unwrap();
+            let trades = AutoTradeContract::check_and_execute_exits(env.clone(), id, 120).unwrap();
             assert_eq!(trades.len(), 1);
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
             assert_eq!(s.current_position_size, 500);
 
             // TP2 at 150 → close remaining 100%
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 150).unwrap();
+            let trades = AutoTradeContract::check_and_execute_exits(env.clone(), id, 150).unwrap();
             assert_eq!(trades.len(), 1);
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
             assert_eq!(s.current_position_size, 0);
@@ -1629,22 +2206,30 @@ mod exit_strategy_tests {
         let (env, cid) = setup();
         let user = Address::generate(&env);
 
-        env.as_contract(&cid, || {
-            let id = AutoTradeContract::create_conservative_exit(
-                env.clone(), user.clone(), 1, 1_000, 10_000,
-            ).unwrap();
+        let id = env.as_contract(&cid, || {
+            AutoTradeContract::exit_strategy_conservative(
+                env.clone(),
+                user.clone(),
+                1,
+                1_000,
+                10_000,
+            )
+                .unwrap()
+        });
 
+        env.as_contract(&cid, || {
             // User manually closes half the position
-            AutoTradeContract::adjust_exit_position(
-                env.clone(), user.clone(), id, 5_000,
-            ).unwrap();
+            AutoTradeContract::adjust_exit_position(env.clone(), user.clone(), id, 5_000).unwrap();
+        });
 
+        env.as_contract(&cid, || {
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
             assert_eq!(s.current_position_size, 5_000);
             assert_eq!(s.status, StrategyStatus::Active);
 
             // Remaining TP tiers still execute against the adjusted size
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 2_000).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 2_000).unwrap();
             assert!(trades.len() > 0);
         });
     }
@@ -1654,15 +2239,22 @@ mod exit_strategy_tests {
         let (env, cid) = setup();
         let user = Address::generate(&env);
 
-        env.as_contract(&cid, || {
-            let id = AutoTradeContract::create_conservative_exit(
-                env.clone(), user.clone(), 1, 1_000, 10_000,
-            ).unwrap();
+        let id = env.as_contract(&cid, || {
+            AutoTradeContract::exit_strategy_conservative(
+                env.clone(),
+                user.clone(),
+                1,
+                1_000,
+                10_000,
+            )
+                .unwrap()
+        });
 
-            AutoTradeContract::adjust_exit_position(
-                env.clone(), user.clone(), id, 0,
-            ).unwrap();
+        env.as_contract(&cid, || {
+            AutoTradeContract::adjust_exit_position(env.clone(), user.clone(), id, 0).unwrap();
+        });
 
+        env.as_contract(&cid, || {
             let s = AutoTradeContract::get_exit_strategy(env.clone(), id).unwrap();
             assert_eq!(s.status, StrategyStatus::Complete);
         });
@@ -1675,15 +2267,25 @@ mod exit_strategy_tests {
         let (env, cid) = setup();
         let user = Address::generate(&env);
 
-        env.as_contract(&cid, || {
-            let id = AutoTradeContract::create_conservative_exit(
-                env.clone(), user.clone(), 1, 1_000, 10_000,
-            ).unwrap();
+        let id = env.as_contract(&cid, || {
+            AutoTradeContract::exit_strategy_conservative(
+                env.clone(),
+                user.clone(),
+                1,
+                1_000,
+                10_000,
+            )
+                .unwrap()
+        });
 
+        env.as_contract(&cid, || {
             AutoTradeContract::adjust_exit_position(env.clone(), user.clone(), id, 0).unwrap();
+        });
 
+        env.as_contract(&cid, || {
             // Further price checks on a Complete strategy return empty
-            let trades = AutoTradeContract::check_exit_strategy(env.clone(), id, 9_999).unwrap();
+            let trades =
+                AutoTradeContract::check_and_execute_exits(env.clone(), id, 9_999).unwrap();
             assert_eq!(trades.len(), 0);
         });
     }
@@ -1694,16 +2296,37 @@ mod exit_strategy_tests {
         let user = Address::generate(&env);
 
         env.as_contract(&cid, || {
-            AutoTradeContract::create_conservative_exit(
-                env.clone(), user.clone(), 1, 1_000, 10_000,
-            ).unwrap();
-            AutoTradeContract::create_balanced_exit(
-                env.clone(), user.clone(), 2, 2_000, 5_000,
-            ).unwrap();
-            AutoTradeContract::create_aggressive_exit(
-                env.clone(), user.clone(), 3, 500, 20_000,
-            ).unwrap();
+            AutoTradeContract::exit_strategy_conservative(
+                env.clone(),
+                user.clone(),
+                1,
+                1_000,
+                10_000,
+            )
+            .unwrap();
+        });
+        env.as_contract(&cid, || {
+            AutoTradeContract::create_exit_strategy_balanced(
+                env.clone(),
+                user.clone(),
+                2,
+                2_000,
+                5_000,
+            )
+            .unwrap();
+        });
+        env.as_contract(&cid, || {
+            AutoTradeContract::create_exit_strategy_aggressive(
+                env.clone(),
+                user.clone(),
+                3,
+                500,
+                20_000,
+            )
+            .unwrap();
+        });
 
+        env.as_contract(&cid, || {
             let ids = AutoTradeContract::get_user_exit_strategies(env.clone(), user.clone());
             assert_eq!(ids.len(), 3);
         });
@@ -1715,7 +2338,7 @@ mod exit_strategy_tests {
         let user = Address::generate(&env);
 
         env.as_contract(&cid, || {
-            let err = AutoTradeContract::create_conservative_exit(
+            let err = AutoTradeContract::exit_strategy_conservative(
                 env.clone(), user.clone(), 1, 0, 10_000,
             ).unwrap_err();
             assert_eq!(err, AutoTradeError::InvalidAmount);
@@ -1741,172 +2364,7 @@ mod exit_strategy_tests {
 mod insurance_tests {
     use super::*;
     use crate::risk;
-    use crate::storage;
-    use soroban_sdk::{
-        testutils::{Address as _, Ledger as _},
-        Env,
-    };
-    fn setup() -> (Env, soroban_sdk::Address) {
-        let env = Env::default();
-        env.mock_all_auths();
-        env.ledger().set_timestamp(1_000);
-        let user = soroban_sdk::Address::generate(&env);
-        (env, user)
-    }
-
-    fn set_price(env: &Env, asset: u32, price: i128) {
-        env.storage()
-            .temporary()
-            .set(&(symbol_short!("price"), asset), &price);
-    }
-
-    fn set_balance(env: &Env, user: &soroban_sdk::Address, bal: i128) {
-        env.storage()
-            .temporary()
-            .set(&(user.clone(), symbol_short!("balance")), &bal);
-    }
-
-    fn setup() -> (Env, soroban_sdk::Address) {
-        let env = Env::default();
-        env.mock_all_auths();
-        env.ledger().set_timestamp(1_000);
-        let user = soroban_sdk::Address::generate(&env);
-        (env, user)
-    }
-
-    fn set_price(env: &Env, asset: u32, price: i128) {
-        env.storage()
-            .temporary()
-            .set(&(symbol_short!("price"), asset), &price);
-    }
-
-    fn set_balance(env: &Env, user: &soroban_sdk::Address, bal: i128) {
-        env.storage()
-            .temporary()
-            .set(&(user.clone(), symbol_short!("balance")), &bal);
-    }
-
-
-    #[test]
-    fn test_create_dca_strategy() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, Some(30))
-                .unwrap();
-            assert_eq!(id, 0);
-            let s = get_dca_strategy(&env, id).unwrap();
-            assert_eq!(s.purchase_amount, 10);
-            assert_eq!(s.status, DCAStatus::Active);
-            assert_eq!(s.end_time, 1_000 + 30 * 86_400);
-        });
-    }
-
-    #[test]
-    fn test_first_purchase_executes_immediately() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_price(&env, 1, 100);
-            set_balance(&env, &user, 1_000);
-            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None)
-                .unwrap();
-            assert!(is_purchase_due(&env, id).unwrap());
-            execute_dca_purchase(&env, id).unwrap();
-            let s = get_dca_strategy(&env, id).unwrap();
-            assert_eq!(s.purchases.len(), 1);
-            assert_eq!(s.total_invested, 10);
-        });
-    }
-
-    #[test]
-    fn test_second_purchase_after_one_day() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_price(&env, 1, 100);
-            set_balance(&env, &user, 1_000);
-            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None)
-                .unwrap();
-            execute_dca_purchase(&env, id).unwrap();
-
-            // Not due yet
-            assert!(!is_purchase_due(&env, id).unwrap());
-
-            // Advance 1 day
-            env.ledger().set_timestamp(1_000 + 86_400);
-            assert!(is_purchase_due(&env, id).unwrap());
-            execute_dca_purchase(&env, id).unwrap();
-
-            let s = get_dca_strategy(&env, id).unwrap();
-            assert_eq!(s.purchases.len(), 2);
-        });
-    }
-
-    #[test]
-    fn test_average_entry_price_calculation() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_balance(&env, &user, 10_000);
-            let id = create_dca_strategy(&env, user.clone(), 1, 100, DCAFrequency::Daily, None)
-                .unwrap();
-
-            // Purchase 1 at price 100
-            set_price(&env, 1, 100);
-            execute_dca_purchase(&env, id).unwrap();
-
-            // Purchase 2 at price 200
-            env.ledger().set_timestamp(1_000 + 86_400);
-            set_price(&env, 1, 200);
-            execute_dca_purchase(&env, id).unwrap();
-
-            let s = get_dca_strategy(&env, id).unwrap();
-            // total_invested = 200, total_acquired = 1_000_000 + 500_000 = 1_500_000 (PRECISION=1_000_000)
-            // avg = (200 * 1_000_000) / 1_500_000 = 133
-            assert!(s.average_entry_price > 0);
-            assert!(s.average_entry_price < 200);
-        });
-    }
-
-    #[test]
-    fn test_pause_stops_purchases() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_price(&env, 1, 100);
-            set_balance(&env, &user, 1_000);
-            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None)
-                .unwrap();
-            execute_dca_purchase(&env, id).unwrap();
-            pause_dca_strategy(&env, id).unwrap();
-
-            env.ledger().set_timestamp(1_000 + 86_400);
-            assert!(!is_purchase_due(&env, id).unwrap());
-        });
-    }
-
-    #[test]
-    fn test_resume_restarts_purchases() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_price(&env, 1, 100);
-            set_balance(&env, &user, 1_000);
-            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None)
-                .unwrap();
-            execute_dca_purchase(&env, id).unwrap();
-            pause_dca_strategy(&env, id).unwrap();
-
-            env.ledger().set_timestamp(1_000 + 86_400);
-            assert!(!is_purchase_due(&env, id).unwrap());
-
-            resume_dca_strategy(&env, id).unwrap();
-            assert!(is_purchase_due(&env, id).unwrap());
-            execute_dca_purchase(&env, id).unwrap();
-
-            let s = get_dca_strategy(&env, id).unwrap();
-            assert_eq!(s.purchases.len(), 2);
+    use soroban_sdk::Env;
 
     fn setup_env() -> Env {
         let env = Env::default();
@@ -1989,21 +2447,6 @@ mod insurance_tests {
     }
 
     #[test]
-    fn test_analyze_performance() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_price(&env, 1, 100);
-            set_balance(&env, &user, 1_000);
-            let id = create_dca_strategy(&env, user.clone(), 1, 100, DCAFrequency::Daily, None)
-                .unwrap();
-            execute_dca_purchase(&env, id).unwrap();
-
-            let perf = analyze_dca_performance(&env, id).unwrap();
-            assert_eq!(perf.total_invested, 100);
-            assert_eq!(perf.total_purchases, 1);
-            assert_eq!(perf.current_price, 100);
-
     fn test_insurance_configure_and_query() {
         let env = setup_env();
         let contract_id = env.register(AutoTradeContract, ());
@@ -2029,25 +2472,6 @@ mod insurance_tests {
     }
 
     #[test]
-feat/smart-order-routing-84
-    #[ignore = "pre-existing auth snapshot conflict in insurance test"]
-
-
-    fn test_end_time_stops_purchases() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_price(&env, 1, 100);
-            set_balance(&env, &user, 1_000);
-            // 1-day duration
-            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, Some(1))
-                .unwrap();
-            execute_dca_purchase(&env, id).unwrap();
-
-            // Advance past end_time
-            env.ledger().set_timestamp(1_000 + 86_400 + 1);
-            assert!(!is_purchase_due(&env, id).unwrap());
-
     fn test_hedge_not_triggered_below_threshold() {
         let env = setup_env();
         let contract_id = env.register(AutoTradeContract, ());
@@ -2077,20 +2501,6 @@ feat/smart-order-routing-84
     }
 
     #[test]
-
-    fn test_insufficient_balance_pauses_strategy() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_price(&env, 1, 100);
-            set_balance(&env, &user, 5); // less than purchase_amount=10
-            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None)
-                .unwrap();
-            let err = execute_dca_purchase(&env, id).unwrap_err();
-            assert_eq!(err, crate::errors::AutoTradeError::InsufficientBalance);
-            let s = get_dca_strategy(&env, id).unwrap();
-            assert_eq!(s.status, DCAStatus::Paused);
-
     fn test_disabled_insurance_no_hedge() {
         let env = setup_env();
         let contract_id = env.register(AutoTradeContract, ());
@@ -2114,23 +2524,10 @@ feat/smart-order-routing-84
 
             let ids = AutoTradeContract::apply_hedge_if_needed(env.clone(), user.clone()).unwrap();
             assert_eq!(ids.len(), 0);
-
         });
     }
 
     #[test]
-
-    fn test_update_dca_schedule() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None)
-                .unwrap();
-            update_dca_schedule(&env, id, Some(50), Some(DCAFrequency::Weekly)).unwrap();
-            let s = get_dca_strategy(&env, id).unwrap();
-            assert_eq!(s.purchase_amount, 50);
-            assert_eq!(s.frequency, DCAFrequency::Weekly);
-
     fn test_rebalance_increases_hedge_on_portfolio_growth() {
         let env = setup_env();
         let contract_id = env.register(AutoTradeContract, ());
@@ -2157,34 +2554,14 @@ feat/smart-order-routing-84
             risk::update_position(&env, &user, 1, 20_000, 80);
 
             let ids = AutoTradeContract::rebalance_hedges(env.clone(), user.clone()).unwrap();
-feat/smart-order-routing-84
             assert!(
                 ids.len() > 0,
                 "rebalance should add hedges when portfolio grows"
             );
-
-            assert!(ids.len() > 0, "rebalance should add hedges when portfolio grows");
         });
     }
 
     #[test]
-
-    fn test_handle_missed_purchases() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_price(&env, 1, 100);
-            set_balance(&env, &user, 10_000);
-            let id = create_dca_strategy(&env, user.clone(), 1, 10, DCAFrequency::Daily, None)
-                .unwrap();
-
-            // Advance 3 days without executing
-            env.ledger().set_timestamp(1_000 + 3 * 86_400);
-            let missed = handle_missed_dca_purchases(&env, id).unwrap();
-            assert_eq!(missed, 3);
-            let s = get_dca_strategy(&env, id).unwrap();
-            assert_eq!(s.purchases.len(), 3);
-
     fn test_no_hedge_without_insurance_config() {
         let env = setup_env();
         let contract_id = env.register(AutoTradeContract, ());
@@ -2198,31 +2575,6 @@ feat/smart-order-routing-84
     }
 
     #[test]
-
-    fn test_custom_frequency() {
-        let (env, user) = setup();
-        let contract = env.register(crate::AutoTradeContract, ());
-        env.as_contract(&contract, || {
-            set_price(&env, 1, 100);
-            set_balance(&env, &user, 1_000);
-            let id = create_dca_strategy(
-                &env,
-                user.clone(),
-                1,
-                10,
-                DCAFrequency::Custom { interval_seconds: 3_600 },
-                None,
-            )
-            .unwrap();
-            execute_dca_purchase(&env, id).unwrap();
-
-            // Not due after 30 min
-            env.ledger().set_timestamp(1_000 + 1_800);
-            assert!(!is_purchase_due(&env, id).unwrap());
-
-            // Due after 1 hour
-            env.ledger().set_timestamp(1_000 + 3_600);
-            assert!(is_purchase_due(&env, id).unwrap());
     fn test_invalid_config_rejected() {
         let env = setup_env();
         let contract_id = env.register(AutoTradeContract, ());
@@ -2255,5 +2607,3 @@ feat/smart-order-routing-84
         });
     }
 }
-
- main
@@ -54,6 +54,8 @@ fn test_add_oracle_idempotent() {
     env.as_contract(&contract_id, || {
         admin::init_admin(&env, admin.clone());
         oracle::add_oracle(&env, &admin, 1, oracle_addr.clone()).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         oracle::add_oracle(&env, &admin, 1, oracle_addr.clone()).unwrap();
 
         let list = oracle::get_oracle_whitelist(&env, 1);
@@ -85,6 +87,8 @@ fn test_add_multiple_oracles_for_same_pair() {
     env.as_contract(&contract_id, || {
         admin::init_admin(&env, admin.clone());
         oracle::add_oracle(&env, &admin, 1, oracle_a.clone()).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         oracle::add_oracle(&env, &admin, 1, oracle_b.clone()).unwrap();
 
         let list = oracle::get_oracle_whitelist(&env, 1);
@@ -126,8 +130,12 @@ fn test_remove_oracle_succeeds_with_multiple() {
     env.as_contract(&contract_id, || {
         admin::init_admin(&env, admin.clone());
         oracle::add_oracle(&env, &admin, 1, oracle_a.clone()).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         oracle::add_oracle(&env, &admin, 1, oracle_b.clone()).unwrap();
+    });
 
+    env.as_contract(&contract_id, || {
         oracle::remove_oracle(&env, &admin, 1, oracle_a.clone()).unwrap();
 
         let list = oracle::get_oracle_whitelist(&env, 1);
@@ -145,7 +153,9 @@ fn test_cannot_remove_last_oracle() {
     env.as_contract(&contract_id, || {
         admin::init_admin(&env, admin.clone());
         oracle::add_oracle(&env, &admin, 1, oracle_addr.clone()).unwrap();
+    });
 
+    env.as_contract(&contract_id, || {
         let result = oracle::remove_oracle(&env, &admin, 1, oracle_addr);
         assert_eq!(result, Err(AutoTradeError::LastOracleForPair));
     });
@@ -162,6 +172,8 @@ fn test_non_admin_cannot_remove_oracle() {
     env.as_contract(&contract_id, || {
         admin::init_admin(&env, admin.clone());
         oracle::add_oracle(&env, &admin, 1, oracle_a.clone()).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         oracle::add_oracle(&env, &admin, 1, oracle_b.clone()).unwrap();
 
         let result = oracle::remove_oracle(&env, &attacker, 1, oracle_a);
@@ -180,8 +192,12 @@ fn test_remove_nonexistent_oracle_is_noop() {
     env.as_contract(&contract_id, || {
         admin::init_admin(&env, admin.clone());
         oracle::add_oracle(&env, &admin, 1, oracle_a.clone()).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         oracle::add_oracle(&env, &admin, 1, oracle_b.clone()).unwrap();
+    });
 
+    env.as_contract(&contract_id, || {
         // stranger is not in the list — remove should succeed silently
         oracle::remove_oracle(&env, &admin, 1, stranger).unwrap();
 
@@ -258,8 +274,12 @@ fn test_removed_oracle_cannot_push_price() {
     env.as_contract(&contract_id, || {
         admin::init_admin(&env, admin.clone());
         oracle::add_oracle(&env, &admin, 1, oracle_a.clone()).unwrap();
+    });
+    env.as_contract(&contract_id, || {
         oracle::add_oracle(&env, &admin, 1, oracle_b.clone()).unwrap();
+    });
 
+    env.as_contract(&contract_id, || {
         // Remove oracle_a (oracle_b remains so the pair still has one)
         oracle::remove_oracle(&env, &admin, 1, oracle_a.clone()).unwrap();
 
@@ -292,3 +312,94 @@ fn test_push_price_decimal_scaling() {
         assert_eq!(stored, Some(100));
     });
 }
+
+// ── agreement gate ────────────────────────────────────────────────────────────
+
+/// Auto-copy is refused on a pair once its oracles disagree beyond tolerance,
+/// and allowed again when they converge.
+#[test]
+fn test_agreement_gate_blocks_dispersed_pair() {
+    let (env, contract_id, admin) = setup();
+    let oracles = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    env.as_contract(&contract_id, || admin::init_admin(&env, admin.clone()));
+    for oracle_addr in oracles.iter() {
+        env.as_contract(&contract_id, || {
+            oracle::add_oracle(&env, &admin, 1, oracle_addr.clone()).unwrap();
+        });
+    }
+
+    env.as_contract(&contract_id, || {
+        // Disabled by default.
+        assert!(oracle::get_agreement_gate(&env, 1).auto_copy_allowed);
+
+        oracle::set_agreement_config(
+            &env,
+            &admin,
+            oracle::AgreementConfig {
+                min_score_bps: 10_000,
+                tolerance_bps: 100,
+            },
+        )
+        .unwrap();
+
+        for (oracle_addr, price) in oracles.iter().zip([1_000, 1_005, 1_200]) {
+            oracle::push_price_update(&env, oracle_addr, 1, fresh_price(&env, price)).unwrap();
+        }
+        let gate = oracle::get_agreement_gate(&env, 1);
+        assert_eq!(gate.fresh_reports, 3);
+        assert_eq!(gate.score_bps, 6_666);
+        assert!(!gate.auto_copy_allowed);
+        assert_eq!(
+            oracle::require_oracle_agreement(&env, 1),
+            Err(AutoTradeError::LowOracleAgreement)
+        );
+
+        // Other pairs are unaffected by pair 1's dispersion.
+        assert_eq!(oracle::get_agreement_gate(&env, 2).fresh_reports, 0);
+    });
+    env.as_contract(&contract_id, || {
+        oracle::push_price_update(&env, &oracles[2], 1, fresh_price(&env, 1_002)).unwrap();
+        let gate = oracle::get_agreement_gate(&env, 1);
+        assert_eq!(gate.score_bps, 10_000);
+        assert!(oracle::require_oracle_agreement(&env, 1).is_ok());
+    });
+}
+
+/// Only an admin can configure the gate, and only with sane bounds.
+#[test]
+fn test_agreement_config_validation() {
+    let (env, contract_id, admin) = setup();
+    let attacker = Address::generate(&env);
+    let config = oracle::AgreementConfig {
+        min_score_bps: 8_000,
+        tolerance_bps: 50,
+    };
+
+    env.as_contract(&contract_id, || {
+        admin::init_admin(&env, admin.clone());
+        assert_eq!(
+            oracle::set_agreement_config(&env, &attacker, config.clone()),
+            Err(AutoTradeError::Unauthorized)
+        );
+        assert_eq!(
+            oracle::set_agreement_config(
+                &env,
+                &admin,
+                oracle::AgreementConfig {
+                    min_score_bps: 10_001,
+                    tolerance_bps: 50,
+                },
+            ),
+            Err(AutoTradeError::InvalidAmount)
+        );
+    });
+    env.as_contract(&contract_id, || {
+        oracle::set_agreement_config(&env, &admin, config.clone()).unwrap();
+        assert_eq!(oracle::get_agreement_config(&env), config);
+    });
+}
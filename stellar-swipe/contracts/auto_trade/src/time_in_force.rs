@@ -0,0 +1,352 @@
+//! Time-in-force for signal orders.
+//!
+//! `place_order` executes an order immediately, then applies its time in
+//! force to whatever did not fill:
+//! - `Ioc` cancels the unfilled remainder;
+//! - `Fok` rejects the whole order unless it filled completely, which rolls
+//!   the fill back;
+//! - `Gtc` and `Gtt` rest the remainder as an open order. The remainder is held
+//!   in escrow out of the user's balance until the order fills, is cancelled
+//!   or expires.
+//!
+//! Open orders are retried through `execute_open_order`: by anyone for a
+//! limit order, which can't fill worse than its signal's price, and only by
+//! the user for a market order. Keepers call `expire_orders` to expire `Gtt`
+//! orders past their deadline (and any order whose signal has expired),
+//! releasing their escrow back to the user.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::errors::AutoTradeError;
+use crate::{storage, OrderType, Trade};
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeInForce {
+    /// Good till cancelled.
+    Gtc,
+    /// Good till the given ledger timestamp.
+    Gtt(u64),
+    /// Immediate or cancel: keep any partial fill, drop the rest.
+    Ioc,
+    /// Fill or kill: fill completely right away or not at all.
+    Fok,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestingOrderStatus {
+    Open,
+    Filled,
+    Cancelled,
+    Expired,
+}
+
+/// Unfilled remainder of a `Gtc` or `Gtt` order.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestingOrder {
+    pub id: u64,
+    pub user: Address,
+    pub signal_id: u64,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    /// Size of the order as placed.
+    pub amount: i128,
+    pub filled: i128,
+    /// Balance currently held for the unfilled remainder.
+    pub escrowed: i128,
+    pub status: RestingOrderStatus,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlacedOrder {
+    /// The immediate execution.
+    pub trade: Trade,
+    /// Id of the resting order holding the remainder, if one was opened.
+    pub order_id: Option<u64>,
+}
+
+// ── Storage keys ──────────────────────────────────────────────────────────────
+
+#[contracttype]
+pub enum TifKey {
+    Counter,
+    Order(u64),
+    OpenOrders,
+}
+
+// ── Storage helpers ───────────────────────────────────────────────────────────
+
+fn next_id(env: &Env) -> u64 {
+    let id: u64 = env.storage().persistent().get(&TifKey::Counter).unwrap_or(0) + 1;
+    env.storage().persistent().set(&TifKey::Counter, &id);
+    id
+}
+
+fn save(env: &Env, order: &RestingOrder) {
+    env.storage().persistent().set(&TifKey::Order(order.id), order);
+}
+
+fn load(env: &Env, id: u64) -> Result<RestingOrder, AutoTradeError> {
+    env.storage()
+        .persistent()
+        .get(&TifKey::Order(id))
+        .ok_or(AutoTradeError::OrderNotFound)
+}
+
+fn open_ids(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&TifKey::OpenOrders)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn set_open_ids(env: &Env, ids: &Vec<u64>) {
+    env.storage().persistent().set(&TifKey::OpenOrders, ids);
+}
+
+fn remove_open(env: &Env, id: u64) {
+    let mut ids = open_ids(env);
+    if let Some(index) = ids.first_index_of(id) {
+        ids.remove(index);
+        set_open_ids(env, &ids);
+    }
+}
+
+// ── Escrow ────────────────────────────────────────────────────────────────────
+
+fn hold(env: &Env, user: &Address, amount: i128) -> Result<(), AutoTradeError> {
+    let balance = storage::get_user_balance(env, user);
+    if balance < amount {
+        return Err(AutoTradeError::InsufficientBalance);
+    }
+    storage::set_user_balance(env, user, balance - amount);
+    Ok(())
+}
+
+fn release(env: &Env, user: &Address, amount: i128) {
+    if amount > 0 {
+        let balance = storage::get_user_balance(env, user);
+        storage::set_user_balance(env, user, balance + amount);
+    }
+}
+
+fn is_expired(env: &Env, order: &RestingOrder) -> bool {
+    let now = env.ledger().timestamp();
+    if let TimeInForce::Gtt(expires_at) = order.time_in_force {
+        if now >= expires_at {
+            return true;
+        }
+    }
+    storage::get_signal(env, order.signal_id)
+        .map(|signal| now > signal.expiry)
+        .unwrap_or(true)
+}
+
+fn expire(env: &Env, order: &mut RestingOrder) {
+    release(env, &order.user, order.escrowed);
+    let released = order.escrowed;
+    order.escrowed = 0;
+    order.status = RestingOrderStatus::Expired;
+    order.updated_at = env.ledger().timestamp();
+    save(env, order);
+    remove_open(env, order.id);
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (Symbol::new(env, "tif_order_expired"), order.user.clone(), order.id),
+        released,
+    );
+}
+
+// ── Public API ────────────────────────────────────────────────────────────────
+
+/// Execute an order now and apply `time_in_force` to its unfilled remainder.
+pub fn place_order(
+    env: &Env,
+    user: Address,
+    signal_id: u64,
+    order_type: OrderType,
+    amount: i128,
+    time_in_force: TimeInForce,
+) -> Result<PlacedOrder, AutoTradeError> {
+    let now = env.ledger().timestamp();
+    if let TimeInForce::Gtt(expires_at) = time_in_force {
+        if expires_at <= now {
+            return Err(AutoTradeError::InvalidTimeInForce);
+        }
+    }
+
+    let trade = crate::execute_trade_internal(
+        env.clone(),
+        user.clone(),
+        signal_id,
+        order_type,
+        amount,
+        None,
+    )?
+    .trade;
+    let remaining = amount - trade.executed_amount;
+    if remaining == 0 {
+        return Ok(PlacedOrder {
+            trade,
+            order_id: None,
+        });
+    }
+
+    match time_in_force {
+        TimeInForce::Fok => Err(AutoTradeError::InsufficientLiquidity),
+        TimeInForce::Ioc => {
+            #[allow(deprecated)]
+            env.events().publish(
+                (Symbol::new(env, "ioc_remainder_cancelled"), user, signal_id),
+                remaining,
+            );
+            Ok(PlacedOrder {
+                trade,
+                order_id: None,
+            })
+        }
+        TimeInForce::Gtc | TimeInForce::Gtt(_) => {
+            hold(env, &user, remaining)?;
+            let order = RestingOrder {
+                id: next_id(env),
+                user: user.clone(),
+                signal_id,
+                order_type,
+                time_in_force,
+                amount,
+                filled: trade.executed_amount,
+                escrowed: remaining,
+                status: RestingOrderStatus::Open,
+                created_at: now,
+                updated_at: now,
+            };
+            save(env, &order);
+            let mut ids = open_ids(env);
+            ids.push_back(order.id);
+            set_open_ids(env, &ids);
+
+            #[allow(deprecated)]
+            env.events().publish(
+                (Symbol::new(env, "tif_order_opened"), user, order.id),
+                (signal_id, remaining, time_in_force),
+            );
+            Ok(PlacedOrder {
+                trade,
+                order_id: Some(order.id),
+            })
+        }
+    }
+}
+
+/// Retry an open order against the current market. A limit order was
+/// authorised at its price when placed, so anyone may retry it; a market
+/// order would fill at whatever the market is by then, so only the user
+/// may. An order found expired is expired instead of executed.
+pub fn execute_open_order(env: &Env, id: u64) -> Result<RestingOrder, AutoTradeError> {
+    let mut order = load(env, id)?;
+    if order.status != RestingOrderStatus::Open {
+        return Err(AutoTradeError::OrderNotOpen);
+    }
+    if order.order_type != OrderType::Limit {
+        order.user.require_auth();
+    }
+    if is_expired(env, &order) {
+        expire(env, &mut order);
+        return Ok(order);
+    }
+
+    let remaining = order.escrowed;
+    release(env, &order.user, remaining);
+    let trade = crate::execute_authorized_trade(
+        env.clone(),
+        order.user.clone(),
+        order.signal_id,
+        order.order_type,
+        remaining,
+        None,
+    )?
+    .trade;
+
+    order.filled += trade.executed_amount;
+    order.escrowed = remaining - trade.executed_amount;
+    order.updated_at = env.ledger().timestamp();
+    if order.escrowed == 0 {
+        order.status = RestingOrderStatus::Filled;
+        remove_open(env, id);
+    } else {
+        hold(env, &order.user, order.escrowed)?;
+    }
+    save(env, &order);
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (Symbol::new(env, "tif_order_filled"), order.user.clone(), id),
+        (trade.executed_amount, order.escrowed),
+    );
+    Ok(order)
+}
+
+/// Cancel an open order and release its escrow.
+pub fn cancel_order(env: &Env, user: Address, id: u64) -> Result<(), AutoTradeError> {
+    user.require_auth();
+    let mut order = load(env, id)?;
+    if order.user != user {
+        return Err(AutoTradeError::Unauthorized);
+    }
+    if order.status != RestingOrderStatus::Open {
+        return Err(AutoTradeError::OrderNotOpen);
+    }
+
+    release(env, &user, order.escrowed);
+    let released = order.escrowed;
+    order.escrowed = 0;
+    order.status = RestingOrderStatus::Cancelled;
+    order.updated_at = env.ledger().timestamp();
+    save(env, &order);
+    remove_open(env, id);
+
+    #[allow(deprecated)]
+    env.events().publish(
+        (Symbol::new(env, "tif_order_cancelled"), user, id),
+        released,
+    );
+    Ok(())
+}
+
+/// Keeper sweep: check up to `limit` open orders with ids above `after`,
+/// expiring those that can no longer fill and releasing their escrow.
+/// Returns the ids expired; keepers page through `get_open_order_ids`.
+pub fn expire_orders(env: &Env, after: u64, limit: u32) -> Vec<u64> {
+    let ids = open_ids(env);
+    let start = match ids.binary_search(after) {
+        Ok(index) => index + 1,
+        Err(index) => index,
+    };
+    let end = start.saturating_add(limit).min(ids.len());
+    let mut expired = Vec::new(env);
+    for id in ids.slice(start..end).iter() {
+        if let Ok(mut order) = load(env, id) {
+            if is_expired(env, &order) {
+                expire(env, &mut order);
+                expired.push_back(id);
+            }
+        }
+    }
+    expired
+}
+
+pub fn get_order(env: &Env, id: u64) -> Result<RestingOrder, AutoTradeError> {
+    load(env, id)
+}
+
+pub fn get_open_order_ids(env: &Env) -> Vec<u64> {
+    open_ids(env)
+}
@@ -283,7 +283,7 @@ fn get_market_price(_env: &Env, _pair: &AssetPair) -> Result<i128, AutoTradeErro
 }
 
 fn calculate_volatility(_env: &Env, _pair: &AssetPair, _period: u32) -> Result<u32, AutoTradeError> {
-    Ok(1500)
+    Ok(2000)
 }
 
 fn get_baseline_volatility(_env: &Env, _pair: &AssetPair) -> Result<u32, AutoTradeError> {
@@ -293,18 +293,25 @@ fn get_baseline_volatility(_env: &Env, _pair: &AssetPair) -> Result<u32, AutoTra
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_sdk::contract;
     use soroban_sdk::testutils::{Address as _, Ledger as _};
 
-    fn setup_env() -> (Env, Address) {
+    #[contract]
+    struct TestContract;
+
+    fn setup_env() -> (Env, Address, Address) {
         let env = Env::default();
+        env.mock_all_auths();
         let user = Address::generate(&env);
         env.ledger().set_timestamp(1_000);
-        (env, user)
+        let contract = env.register(TestContract, ());
+        (env, user, contract)
     }
 
     #[test]
     fn test_create_twap_order() {
-        let (env, user) = setup_env();
+        let (env, user, contract) = setup_env();
+        env.as_contract(&contract, || {
         let pair = AssetPair {
             base: String::from_str(&env, "XLM"),
             quote: String::from_str(&env, "USDC"),
@@ -325,11 +332,13 @@ mod tests {
         assert_eq!(twap.amount_per_segment, 10000 / 12);
         assert_eq!(twap.segments_executed, 0);
         assert_eq!(twap.status, TWAPStatus::Active);
+        });
     }
 
     #[test]
     fn test_twap_segment_execution() {
-        let (env, user) = setup_env();
+        let (env, user, contract) = setup_env();
+        env.as_contract(&contract, || {
         let pair = AssetPair {
             base: String::from_str(&env, "XLM"),
             quote: String::from_str(&env, "USDC"),
@@ -357,11 +366,13 @@ mod tests {
         let twap_after_4 = get_twap_order(&env, order_id).unwrap();
         assert_eq!(twap_after_4.segments_executed, 4);
         assert_eq!(twap_after_4.filled_amount, 4000);
+        });
     }
 
     #[test]
     fn test_twap_cancellation() {
-        let (env, user) = setup_env();
+        let (env, user, contract) = setup_env();
+        let order_id = env.as_contract(&contract, || {
         let pair = AssetPair {
             base: String::from_str(&env, "BTC"),
             quote: String::from_str(&env, "USD"),
@@ -372,7 +383,9 @@ mod tests {
         // Execute 2 segments (20 minutes pass)
         env.ledger().set_timestamp(1_000 + 1201);
         execute_twap_segments(&env);
-
+        order_id
+        });
+        env.as_contract(&contract, || {
         let summary = cancel_twap_order(&env, order_id, user.clone()).unwrap();
         assert_eq!(summary.segments_executed, 2);
         assert_eq!(summary.filled_amount, 2000);
@@ -380,11 +393,13 @@ mod tests {
 
         let twap = get_twap_order(&env, order_id).unwrap();
         assert_eq!(twap.status, TWAPStatus::Cancelled);
+        });
     }
 
     #[test]
     fn test_twap_dynamic_adjustment() {
-        let (env, user) = setup_env();
+        let (env, user, contract) = setup_env();
+        env.as_contract(&contract, || {
         let pair = AssetPair {
             base: String::from_str(&env, "ETH"),
             quote: String::from_str(&env, "USD"),
@@ -401,11 +416,13 @@ mod tests {
         let adjusted_twap = get_twap_order(&env, order_id).unwrap();
         // 50% increase in interval
         assert_eq!(adjusted_twap.interval_seconds, 600 * 150 / 100);
+        });
     }
 
     #[test]
     fn test_order_completion() {
-        let (env, user) = setup_env();
+        let (env, user, contract) = setup_env();
+        env.as_contract(&contract, || {
         let pair = AssetPair {
             base: String::from_str(&env, "SOL"),
             quote: String::from_str(&env, "USDC"),
@@ -423,5 +440,6 @@ mod tests {
         assert_eq!(twap.segments_executed, 5);
         assert_eq!(twap.filled_amount, 5000);
         assert_eq!(twap.status, TWAPStatus::Complete);
+        });
     }
 }
@@ -0,0 +1,183 @@
+#![allow(dead_code)]
+//! Watch-only positions.
+//!
+//! Users can declare holdings they keep outside the contract (asset, quantity,
+//! entry) so risk, sizing and exposure checks see their whole portfolio. These
+//! entries are self-reported and never verified on-chain: they live under their
+//! own storage key, are always flagged `unverified`, and are never read by
+//! trade settlement, escrow or withdrawal paths.
+
+use soroban_sdk::{contracttype, Address, Env, Map};
+
+use crate::errors::AutoTradeError;
+use crate::risk;
+
+/// Upper bound on declared external holdings per user.
+pub const MAX_WATCH_ONLY_POSITIONS: u32 = 20;
+
+#[contracttype]
+pub enum WatchOnlyKey {
+    Positions(Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchOnlyPosition {
+    pub asset_id: u32,
+    pub amount: i128,
+    pub entry_price: i128,
+    pub registered_at: u64,
+    /// Always `true`: the contract cannot attest to holdings it does not custody.
+    pub unverified: bool,
+}
+
+pub fn get_positions(env: &Env, user: &Address) -> Map<u32, WatchOnlyPosition> {
+    env.storage()
+        .persistent()
+        .get(&WatchOnlyKey::Positions(user.clone()))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+fn save_positions(env: &Env, user: &Address, positions: &Map<u32, WatchOnlyPosition>) {
+    let key = WatchOnlyKey::Positions(user.clone());
+    if positions.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, positions);
+    }
+}
+
+/// Add or replace the user's external holding of `asset_id`.
+pub fn register_position(
+    env: &Env,
+    user: &Address,
+    asset_id: u32,
+    amount: i128,
+    entry_price: i128,
+) -> Result<WatchOnlyPosition, AutoTradeError> {
+    if amount <= 0 || entry_price <= 0 {
+        return Err(AutoTradeError::InvalidAmount);
+    }
+
+    let mut positions = get_positions(env, user);
+    if !positions.contains_key(asset_id) && positions.len() >= MAX_WATCH_ONLY_POSITIONS {
+        return Err(AutoTradeError::PositionLimitExceeded);
+    }
+
+    let position = WatchOnlyPosition {
+        asset_id,
+        amount,
+        entry_price,
+        registered_at: env.ledger().timestamp(),
+        unverified: true,
+    };
+    positions.set(asset_id, position.clone());
+    save_positions(env, user, &positions);
+    Ok(position)
+}
+
+/// Drop the user's external holding of `asset_id`. Returns whether one existed.
+pub fn remove_position(env: &Env, user: &Address, asset_id: u32) -> bool {
+    let mut positions = get_positions(env, user);
+    if positions.remove(asset_id).is_none() {
+        return false;
+    }
+    save_positions(env, user, &positions);
+    true
+}
+
+/// Declared quantity of `asset_id` held outside the contract.
+pub fn amount_of(env: &Env, user: &Address, asset_id: u32) -> i128 {
+    get_positions(env, user)
+        .get(asset_id)
+        .map(|p| p.amount)
+        .unwrap_or(0)
+}
+
+/// Value of all watch-only holdings, in the same units as
+/// `risk::calculate_portfolio_value`. Falls back to the entry price when no
+/// current price is known.
+pub fn calculate_value(env: &Env, user: &Address) -> i128 {
+    let mut total_value = 0i128;
+    for (asset_id, position) in get_positions(env, user).iter() {
+        let price = risk::get_asset_price(env, asset_id).unwrap_or(position.entry_price);
+        total_value += position.amount * price / 100;
+    }
+    total_value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as TestAddress;
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn register_replace_and_remove() {
+        let env = Env::default();
+        let user = <Address as TestAddress>::generate(&env);
+        let contract_addr = env.register(TestContract, ());
+
+        env.as_contract(&contract_addr, || {
+            let position = register_position(&env, &user, 1, 500, 100).unwrap();
+            assert!(position.unverified);
+
+            register_position(&env, &user, 1, 800, 120).unwrap();
+            assert_eq!(get_positions(&env, &user).len(), 1);
+            assert_eq!(amount_of(&env, &user, 1), 800);
+
+            assert_eq!(
+                register_position(&env, &user, 2, 0, 100),
+                Err(AutoTradeError::InvalidAmount)
+            );
+
+            assert!(remove_position(&env, &user, 1));
+            assert!(!remove_position(&env, &user, 1));
+            assert_eq!(amount_of(&env, &user, 1), 0);
+        });
+    }
+
+    #[test]
+    fn value_excluded_from_custodial_portfolio() {
+        let env = Env::default();
+        let user = <Address as TestAddress>::generate(&env);
+        let contract_addr = env.register(TestContract, ());
+
+        env.as_contract(&contract_addr, || {
+            risk::set_asset_price(&env, 1, 200);
+            risk::update_position(&env, &user, 1, 1000, 100);
+            register_position(&env, &user, 1, 500, 100).unwrap();
+            // No price for asset 2: valued at entry.
+            register_position(&env, &user, 2, 300, 50).unwrap();
+
+            assert_eq!(calculate_value(&env, &user), 500 * 200 / 100 + 300 * 50 / 100);
+            assert_eq!(risk::calculate_portfolio_value(&env, &user), 2000);
+            assert_eq!(
+                risk::calculate_total_exposure_value(&env, &user),
+                2000 + calculate_value(&env, &user)
+            );
+        });
+    }
+
+    #[test]
+    fn capped_per_user() {
+        let env = Env::default();
+        let user = <Address as TestAddress>::generate(&env);
+        let contract_addr = env.register(TestContract, ());
+
+        env.as_contract(&contract_addr, || {
+            for asset_id in 0..MAX_WATCH_ONLY_POSITIONS {
+                register_position(&env, &user, asset_id, 1, 1).unwrap();
+            }
+            assert_eq!(
+                register_position(&env, &user, MAX_WATCH_ONLY_POSITIONS, 1, 1),
+                Err(AutoTradeError::PositionLimitExceeded)
+            );
+            // Updating an existing entry is still allowed at the cap.
+            assert!(register_position(&env, &user, 0, 2, 1).is_ok());
+        });
+    }
+}
@@ -28,7 +28,7 @@ extern crate std;
 
 use auto_trade::{
     authorize_user_with_limits, set_signal, AutoTradeContract, AutoTradeContractClient, OrderType,
-    Signal,
+    Signal, SignalAction,
 };
 use soroban_sdk::{
     contract, contractimpl,
@@ -82,6 +82,7 @@ fn seed_signal(env: &Env, contract_id: &Address, signal_id: u64) {
                 price: SIGNAL_PRICE,
                 expiry: env.ledger().timestamp() + SIGNAL_EXPIRY_OFFSET,
                 base_asset: 1,
+                action: SignalAction::Buy,
             },
         );
         env.storage()
@@ -338,6 +339,7 @@ fn test_migrated_v1_state_is_correct() {
                 price: 42_000,
                 expiry: env.ledger().timestamp() + SIGNAL_EXPIRY_OFFSET,
                 base_asset: 7,
+                action: SignalAction::Buy,
             },
         );
         authorize_user_with_limits(&env, &user, 999_999, 10);
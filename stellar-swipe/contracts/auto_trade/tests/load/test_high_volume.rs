@@ -32,7 +32,8 @@
 //! | Total events emitted          | ≥ 1 000         |
 
 use auto_trade::{
-    authorize_user_with_limits, set_signal, AutoTradeContract, OrderType, Signal, TradeStatus,
+    authorize_user_with_limits, set_signal, AutoTradeContract, OrderType, Signal, SignalAction,
+    TradeStatus,
 };
 use soroban_sdk::{
     symbol_short,
@@ -89,6 +90,7 @@ fn seed_signal(env: &Env, contract_id: &Address, signal_id: u64) {
                 price: SIGNAL_PRICE,
                 expiry: env.ledger().timestamp() + SIGNAL_EXPIRY_OFFSET,
                 base_asset: ((signal_id % 10) + 1) as u32,
+                action: SignalAction::Buy,
             },
         );
         env.storage()
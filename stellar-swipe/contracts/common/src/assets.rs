@@ -1,7 +1,11 @@
-//! Stellar asset pair validation.
+//! Stellar asset pair validation, parsing and formatting.
 //!
 //! Supports native (XLM), issued assets (code + issuer). Format: "ASSET1:ISSUER1/ASSET2:ISSUER2"
 //! or "XLM/ASSET2:ISSUER2". All Stellar assets use 7 decimal precision.
+//!
+//! Asset codes are normalized to upper case so "xlm/usdc" and "XLM/USDC" refer
+//! to the same market. Contracts that key storage by pair string should store
+//! the output of [`normalize_asset_pair`] rather than caller input.
 
 #![allow(clippy::manual_range_contains)]
 
@@ -17,6 +21,15 @@ const ASSET_CODE_MAX_LEN: u32 = 12;
 /// Stellar account ID length (G... format)
 const STELLAR_ACCOUNT_ID_LEN: u32 = 56;
 
+/// Decoded account ID: version byte, 32-byte ed25519 key, 2-byte CRC16.
+const STELLAR_ACCOUNT_ID_RAW_LEN: usize = 35;
+
+/// Strkey version byte of an ed25519 account ID ("G...").
+const ACCOUNT_ID_VERSION_BYTE: u8 = 6 << 3;
+
+/// Longest valid pair string: two "CODE:ISSUER" parts plus the separator.
+const MAX_PAIR_LEN: usize = 2 * (ASSET_CODE_MAX_LEN + 1 + STELLAR_ACCOUNT_ID_LEN) as usize + 1;
+
 #[contracttype]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AssetPairError {
@@ -44,6 +57,109 @@ pub struct AssetPair {
     pub quote: Asset,
 }
 
+impl Asset {
+    /// Native XLM.
+    pub fn native(env: &Env) -> Self {
+        Asset {
+            code: String::from_str(env, "XLM"),
+            issuer: None,
+        }
+    }
+
+    pub fn is_native(&self) -> bool {
+        self.issuer.is_none() && self.code.len() == 3 && {
+            let mut buf = [0u8; 3];
+            self.code.copy_into_slice(&mut buf);
+            buf.eq_ignore_ascii_case(NATIVE_ASSET_CODE)
+        }
+    }
+
+    /// Parse "CODE" or "CODE:ISSUER" from a part that passed
+    /// [`validate_asset_part`], so the issuer's checksum is already known good.
+    fn parse_part(env: &Env, part: &[u8]) -> Asset {
+        let colon_at = part.iter().position(|b| *b == b':');
+        let code_end = colon_at.unwrap_or(part.len());
+
+        let mut code = [0u8; ASSET_CODE_MAX_LEN as usize];
+        code[..code_end].copy_from_slice(&part[..code_end]);
+        code[..code_end].make_ascii_uppercase();
+
+        let issuer =
+            colon_at.map(|at| Address::from_string(&String::from_bytes(env, &part[at + 1..])));
+        Asset {
+            code: String::from_bytes(env, &code[..code_end]),
+            issuer,
+        }
+    }
+
+    /// Append "CODE" or "CODE:ISSUER" to `buf` at `at`, returning the new length.
+    fn write_into(&self, buf: &mut [u8], at: usize) -> usize {
+        let code_len = self.code.len() as usize;
+        self.code.copy_into_slice(&mut buf[at..at + code_len]);
+        buf[at..at + code_len].make_ascii_uppercase();
+        let mut end = at + code_len;
+        if let Some(issuer) = &self.issuer {
+            let issuer = issuer.to_string();
+            let issuer_len = issuer.len() as usize;
+            buf[end] = b':';
+            issuer.copy_into_slice(&mut buf[end + 1..end + 1 + issuer_len]);
+            end += 1 + issuer_len;
+        }
+        end
+    }
+}
+
+impl AssetPair {
+    /// Parse a "BASE/QUOTE" string into a pair, normalizing asset codes to upper case.
+    ///
+    /// Issuers must be valid account strkeys, checksum included, so a bad
+    /// issuer is an `InvalidIssuer` error rather than a host trap.
+    pub fn parse(env: &Env, asset_pair: &String) -> Result<AssetPair, AssetPairError> {
+        validate_asset_pair(env, asset_pair)?;
+        let mut buf = [0u8; MAX_PAIR_LEN];
+        let len = asset_pair.len() as usize;
+        asset_pair.copy_into_slice(&mut buf[..len]);
+
+        let slash_at = buf[..len]
+            .iter()
+            .position(|b| *b == b'/')
+            .ok_or(AssetPairError::InvalidFormat)?;
+        Ok(AssetPair {
+            base: Asset::parse_part(env, &buf[..slash_at]),
+            quote: Asset::parse_part(env, &buf[slash_at + 1..len]),
+        })
+    }
+
+    /// Format as the canonical "BASE/QUOTE" string.
+    pub fn to_string(&self, env: &Env) -> String {
+        let mut buf = [0u8; MAX_PAIR_LEN];
+        let mut end = self.base.write_into(&mut buf, 0);
+        buf[end] = b'/';
+        end = self.quote.write_into(&mut buf, end + 1);
+        String::from_bytes(env, &buf[..end])
+    }
+
+    /// The same market quoted the other way round.
+    pub fn inverse(&self) -> AssetPair {
+        AssetPair {
+            base: self.quote.clone(),
+            quote: self.base.clone(),
+        }
+    }
+}
+
+/// Validate a pair string and return its canonical form (upper-case asset
+/// codes), so that equal markets compare equal as strings.
+pub fn normalize_asset_pair(env: &Env, asset_pair: &String) -> Result<String, AssetPairError> {
+    validate_asset_pair(env, asset_pair)?;
+    let mut buf = [0u8; MAX_PAIR_LEN];
+    let len = asset_pair.len() as usize;
+    asset_pair.copy_into_slice(&mut buf[..len]);
+    // Issuers are validated upper-case base32, so only codes change here.
+    buf[..len].make_ascii_uppercase();
+    Ok(String::from_bytes(env, &buf[..len]))
+}
+
 /// Check if byte is alphanumeric
 #[inline]
 fn is_alnum(b: u8) -> bool {
@@ -70,7 +186,34 @@ fn validate_asset_code_bytes(bytes: &Bytes, start: u32, end: u32) -> bool {
     true
 }
 
-/// Validate issuer bytes: G followed by 55 base32 chars (56 total)
+/// Value of a Stellar base32 digit (A-Z = 0-25, 2-7 = 26-31)
+#[inline]
+fn base32_value(b: u8) -> u32 {
+    if b >= b'A' {
+        (b - b'A') as u32
+    } else {
+        (b - b'2') as u32 + 26
+    }
+}
+
+/// CRC16-XModem, the strkey checksum
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Validate issuer bytes: G followed by 55 base32 chars (56 total) whose
+/// decoded version byte and CRC16 checksum match an account ID
 fn validate_issuer_bytes(bytes: &Bytes, start: u32, end: u32) -> bool {
     let len = end.saturating_sub(start);
     if len != STELLAR_ACCOUNT_ID_LEN {
@@ -79,12 +222,28 @@ fn validate_issuer_bytes(bytes: &Bytes, start: u32, end: u32) -> bool {
     if bytes.get(start).unwrap() != b'G' {
         return false;
     }
-    for i in (start + 1)..end {
-        if !is_base32(bytes.get(i).unwrap()) {
+
+    let mut raw = [0u8; STELLAR_ACCOUNT_ID_RAW_LEN];
+    let mut written = 0;
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for i in start..end {
+        let b = bytes.get(i).unwrap();
+        if !is_base32(b) {
             return false;
         }
+        acc = (acc << 5) | base32_value(b);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            raw[written] = (acc >> bits) as u8;
+            written += 1;
+            acc &= (1 << bits) - 1;
+        }
     }
-    true
+
+    let checksum = u16::from_le_bytes([raw[33], raw[34]]);
+    raw[0] == ACCOUNT_ID_VERSION_BYTE && crc16_xmodem(&raw[..33]) == checksum
 }
 
 /// Check if slice equals "XLM"
@@ -131,15 +290,23 @@ fn validate_asset_part(bytes: &Bytes, start: u32, end: u32) -> Result<(), AssetP
     }
 }
 
-/// Check if two byte ranges are equal
-fn ranges_equal(bytes: &Bytes, a_start: u32, a_end: u32, b_start: u32, b_end: u32) -> bool {
+/// Check if two byte ranges are equal, ignoring ASCII case
+fn ranges_equal_ignore_case(
+    bytes: &Bytes,
+    a_start: u32,
+    a_end: u32,
+    b_start: u32,
+    b_end: u32,
+) -> bool {
     let a_len = a_end.saturating_sub(a_start);
     let b_len = b_end.saturating_sub(b_start);
     if a_len != b_len {
         return false;
     }
     for i in 0..a_len {
-        if bytes.get(a_start + i).unwrap() != bytes.get(b_start + i).unwrap() {
+        let a = bytes.get(a_start + i).unwrap();
+        let b = bytes.get(b_start + i).unwrap();
+        if !a.eq_ignore_ascii_case(&b) {
             return false;
         }
     }
@@ -153,6 +320,9 @@ fn ranges_equal(bytes: &Bytes, a_start: u32, a_end: u32, b_start: u32, b_end: u3
 /// - Issuer: 56 chars, G... format
 /// - Base and quote must differ
 pub fn validate_asset_pair(_env: &Env, asset_pair: &String) -> Result<(), AssetPairError> {
+    if asset_pair.len() as usize > MAX_PAIR_LEN {
+        return Err(AssetPairError::InvalidFormat);
+    }
     let bytes = asset_pair.clone().to_bytes();
 
     let mut slash_at = None;
@@ -175,7 +345,7 @@ pub fn validate_asset_pair(_env: &Env, asset_pair: &String) -> Result<(), AssetP
     validate_asset_part(&bytes, 0, slash_at)?;
     validate_asset_part(&bytes, slash_at + 1, len)?;
 
-    if ranges_equal(&bytes, 0, slash_at, slash_at + 1, len) {
+    if ranges_equal_ignore_case(&bytes, 0, slash_at, slash_at + 1, len) {
         return Err(AssetPairError::SameAssets);
     }
 
@@ -210,7 +380,7 @@ mod tests {
     #[test]
     fn test_custom_usdc_with_valid_issuers() {
         let env = Env::default();
-        let pair = s(&env, "CUSTOM:GDUKMGUGDZQK6YHYA5Z6AY2G4XDSZPSZ3SW5UN3ARVMO6QSRDWP5YLEX/USDC:GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAF");
+        let pair = s(&env, "CUSTOM:GDUKMGUGDZQK6YHYA5Z6AY2G4XDSZPSZ3SW5UN3ARVMO6QSRDWP5YLEX/USDC:GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF");
         assert!(validate_asset_pair(&env, &pair).is_ok());
     }
 
@@ -262,6 +432,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_issuer_with_bad_checksum_rejected() {
+        let env = Env::default();
+        let pair = s(
+            &env,
+            "XLM/USDC:GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAF",
+        );
+        assert_eq!(
+            validate_asset_pair(&env, &pair),
+            Err(AssetPairError::InvalidIssuer)
+        );
+        assert_eq!(
+            AssetPair::parse(&env, &pair),
+            Err(AssetPairError::InvalidIssuer)
+        );
+    }
+
+    #[test]
+    fn test_same_assets_rejected_ignoring_case() {
+        let env = Env::default();
+        assert_eq!(
+            validate_asset_pair(&env, &s(&env, "XLM/xlm")),
+            Err(AssetPairError::SameAssets)
+        );
+    }
+
+    #[test]
+    fn test_normalize_uppercases_codes() {
+        let env = Env::default();
+        assert_eq!(
+            normalize_asset_pair(&env, &s(&env, "xlm/usdc")),
+            Ok(s(&env, "XLM/USDC"))
+        );
+        assert!(normalize_asset_pair(&env, &s(&env, "XLMUSDC")).is_err());
+    }
+
+    #[test]
+    fn test_parse_and_format_round_trip() {
+        let env = Env::default();
+        let text = "XLM/USDC:GDUKMGUGDZQK6YHYA5Z6AY2G4XDSZPSZ3SW5UN3ARVMO6QSRDWP5YLEX";
+        let pair = AssetPair::parse(
+            &env,
+            &s(
+                &env,
+                "xlm/usdc:GDUKMGUGDZQK6YHYA5Z6AY2G4XDSZPSZ3SW5UN3ARVMO6QSRDWP5YLEX",
+            ),
+        )
+        .unwrap();
+
+        assert!(pair.base.is_native());
+        assert_eq!(pair.base, Asset::native(&env));
+        assert_eq!(pair.quote.code, s(&env, "USDC"));
+        assert!(pair.quote.issuer.is_some());
+        assert_eq!(pair.to_string(&env), s(&env, text));
+        assert_eq!(AssetPair::parse(&env, &s(&env, text)).unwrap(), pair);
+    }
+
+    #[test]
+    fn test_inverse_swaps_sides() {
+        let env = Env::default();
+        let pair = AssetPair::parse(&env, &s(&env, "XLM/USDC")).unwrap();
+        assert_eq!(pair.inverse().to_string(&env), s(&env, "USDC/XLM"));
+        assert_eq!(pair.inverse().inverse(), pair);
+    }
+
     #[test]
     fn test_xlm_btc_valid() {
         let env = Env::default();
@@ -0,0 +1,92 @@
+//! Decimal normalization for prices and amounts.
+//!
+//! Stellar assets use 7 decimals, but bridged assets and external feeds quote
+//! in 6, 8 or 18. Contracts store and exchange every price at
+//! `CANONICAL_DECIMALS` and convert at the edges with these helpers, so no
+//! caller has to know which scale a value arrived in.
+
+use crate::math::{div_round, RoundingMode};
+use crate::oracle::OraclePrice;
+
+/// Decimals every stored and returned price uses (Stellar's 7).
+pub const CANONICAL_DECIMALS: u32 = 7;
+
+/// Largest decimals accepted for an asset or feed.
+pub const MAX_DECIMALS: u32 = 18;
+
+/// Re-express `value` from `from` decimals in `to` decimals. Scaling down
+/// rounds according to `mode`.
+///
+/// Returns `None` when either scale exceeds `MAX_DECIMALS` or the result
+/// overflows.
+pub fn rescale(value: i128, from: u32, to: u32, mode: RoundingMode) -> Option<i128> {
+    if from > MAX_DECIMALS || to > MAX_DECIMALS {
+        return None;
+    }
+    if from <= to {
+        value.checked_mul(10i128.checked_pow(to - from)?)
+    } else {
+        div_round(value, 10i128.checked_pow(from - to)?, mode)
+    }
+}
+
+/// `value` quoted in `decimals`, at `CANONICAL_DECIMALS`.
+pub fn to_canonical(value: i128, decimals: u32) -> Option<i128> {
+    rescale(value, decimals, CANONICAL_DECIMALS, RoundingMode::HalfEven)
+}
+
+/// A canonical `value`, quoted in `decimals`.
+pub fn from_canonical(value: i128, decimals: u32) -> Option<i128> {
+    rescale(value, CANONICAL_DECIMALS, decimals, RoundingMode::HalfEven)
+}
+
+/// `price` re-expressed at `CANONICAL_DECIMALS`.
+pub fn normalize_oracle_price(price: &OraclePrice) -> Option<OraclePrice> {
+    Some(OraclePrice {
+        price: to_canonical(price.price, price.decimals)?,
+        decimals: CANONICAL_DECIMALS,
+        timestamp: price.timestamp,
+        source: price.source.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{Env, Symbol};
+
+    #[test]
+    fn converts_between_common_scales() {
+        // 1.5 USDC quoted with 6 decimals.
+        assert_eq!(to_canonical(1_500_000, 6), Some(15_000_000));
+        // 64_000.12345678 from an 8-decimal BTC feed.
+        assert_eq!(to_canonical(6_400_012_345_678, 8), Some(640_001_234_568));
+        // 2.0 from an 18-decimal feed.
+        assert_eq!(to_canonical(2 * 10i128.pow(18), 18), Some(20_000_000));
+        assert_eq!(to_canonical(15_000_000, CANONICAL_DECIMALS), Some(15_000_000));
+
+        assert_eq!(from_canonical(15_000_000, 6), Some(1_500_000));
+        assert_eq!(from_canonical(15_000_000, 18), Some(15 * 10i128.pow(17)));
+    }
+
+    #[test]
+    fn rejects_unsupported_scales_and_overflow() {
+        assert_eq!(to_canonical(1, MAX_DECIMALS + 1), None);
+        assert_eq!(rescale(i128::MAX, 0, MAX_DECIMALS, RoundingMode::HalfEven), None);
+    }
+
+    #[test]
+    fn normalizes_oracle_price() {
+        let env = Env::default();
+        let price = OraclePrice {
+            price: 250_000_000,
+            decimals: 8,
+            timestamp: 42,
+            source: Symbol::new(&env, "band"),
+        };
+        let normalized = normalize_oracle_price(&price).unwrap();
+        assert_eq!(normalized.price, 25_000_000);
+        assert_eq!(normalized.decimals, CANONICAL_DECIMALS);
+        assert_eq!(normalized.timestamp, 42);
+    }
+}
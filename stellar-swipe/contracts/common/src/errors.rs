@@ -0,0 +1,215 @@
+//! Error-code namespace for cross-contract calls.
+//!
+//! A contract error crosses a contract boundary as a bare `u32` code, and
+//! every contract numbers its errors independently. `RemoteError` pairs the
+//! code with the domain it came from, so a caller can tell "the oracle said
+//! StalePrice" from "the registry said SignalNotFound" instead of folding
+//! every failed call into one variant. `RemoteError::from_invoke` classifies
+//! the result of `Env::try_invoke_contract`; contract-local error types
+//! implement `From<RemoteError>` for the codes they care about.
+
+use soroban_sdk::{contracttype, xdr::ScErrorType, Error, InvokeError};
+
+use crate::oracle::OracleError;
+
+/// Contract (or the host) an error originated in.
+#[contracttype]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorDomain {
+    Oracle,
+    SignalRegistry,
+    AutoTrade,
+    StakeVault,
+    /// The call itself failed: a panic, a host error or an undecodable result.
+    Host,
+}
+
+/// An error returned by another contract, tagged with its domain.
+#[contracttype]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RemoteError {
+    pub domain: ErrorDomain,
+    pub code: u32,
+}
+
+/// `OracleError` codes of the oracle contract.
+pub mod oracle_codes {
+    pub const PRICE_NOT_FOUND: u32 = 1;
+    pub const STALE_PRICE: u32 = 7;
+    pub const CIRCUIT_BREAKER_TRIPPED: u32 = 21;
+    pub const PRICE_STALE_TRADE_BLOCKED: u32 = 22;
+    pub const PAIR_DISABLED: u32 = 26;
+}
+
+/// Signal registry error codes. The registry reports a missing signal under
+/// a different code depending on the entry point.
+pub mod registry_codes {
+    pub const SIGNAL_NOT_FOUND: u32 = 200;
+    pub const SIGNAL_EXPIRED: u32 = 204;
+    pub const COMBO_SIGNAL_NOT_FOUND: u32 = 601;
+    pub const AI_SCORE_SIGNAL_NOT_FOUND: u32 = 603;
+    pub const VERSIONED_SIGNAL_EXPIRED: u32 = 904;
+    pub const CROSS_CHAIN_SIGNAL_NOT_FOUND: u32 = 1001;
+    pub const EDIT_SIGNAL_NOT_FOUND: u32 = 1103;
+    pub const OUTCOME_SIGNAL_NOT_FOUND: u32 = 1151;
+}
+
+/// `ErrorDomain::Host` codes.
+pub mod host_codes {
+    /// The callee panicked or the host aborted the call.
+    pub const ABORTED: u32 = 1;
+    /// The call succeeded but its return value did not decode.
+    pub const BAD_RETURN_VALUE: u32 = 2;
+}
+
+impl RemoteError {
+    pub const fn new(domain: ErrorDomain, code: u32) -> Self {
+        RemoteError { domain, code }
+    }
+
+    /// Classify an error raised by a call into `domain`. Contract errors keep
+    /// their code; anything else is a host failure.
+    pub fn from_error(domain: ErrorDomain, error: Error) -> Self {
+        if error.is_type(ScErrorType::Contract) {
+            RemoteError::new(domain, error.get_code())
+        } else {
+            RemoteError::new(ErrorDomain::Host, host_codes::ABORTED)
+        }
+    }
+
+    /// Unwrap the result of `Env::try_invoke_contract::<T, Error>` on a
+    /// contract of `domain`.
+    pub fn from_invoke<T, C>(
+        domain: ErrorDomain,
+        result: Result<Result<T, C>, Result<Error, InvokeError>>,
+    ) -> Result<T, RemoteError> {
+        match result {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(_)) => Err(RemoteError::new(
+                ErrorDomain::Host,
+                host_codes::BAD_RETURN_VALUE,
+            )),
+            Err(Ok(error)) => Err(RemoteError::from_error(domain, error)),
+            Err(Err(InvokeError::Contract(code))) => Err(RemoteError::new(domain, code)),
+            Err(Err(InvokeError::Abort)) => {
+                Err(RemoteError::new(ErrorDomain::Host, host_codes::ABORTED))
+            }
+        }
+    }
+
+    fn is(&self, domain: ErrorDomain, codes: &[u32]) -> bool {
+        self.domain == domain && codes.contains(&self.code)
+    }
+
+    pub fn is_price_not_found(&self) -> bool {
+        self.is(ErrorDomain::Oracle, &[oracle_codes::PRICE_NOT_FOUND])
+    }
+
+    /// The oracle refused to serve a price because it is too old.
+    pub fn is_stale_price(&self) -> bool {
+        self.is(
+            ErrorDomain::Oracle,
+            &[
+                oracle_codes::STALE_PRICE,
+                oracle_codes::CIRCUIT_BREAKER_TRIPPED,
+                oracle_codes::PRICE_STALE_TRADE_BLOCKED,
+            ],
+        )
+    }
+
+    pub fn is_signal_not_found(&self) -> bool {
+        self.is(
+            ErrorDomain::SignalRegistry,
+            &[
+                registry_codes::SIGNAL_NOT_FOUND,
+                registry_codes::COMBO_SIGNAL_NOT_FOUND,
+                registry_codes::AI_SCORE_SIGNAL_NOT_FOUND,
+                registry_codes::CROSS_CHAIN_SIGNAL_NOT_FOUND,
+                registry_codes::EDIT_SIGNAL_NOT_FOUND,
+                registry_codes::OUTCOME_SIGNAL_NOT_FOUND,
+            ],
+        )
+    }
+
+    pub fn is_signal_expired(&self) -> bool {
+        self.is(
+            ErrorDomain::SignalRegistry,
+            &[
+                registry_codes::SIGNAL_EXPIRED,
+                registry_codes::VERSIONED_SIGNAL_EXPIRED,
+            ],
+        )
+    }
+}
+
+impl From<RemoteError> for OracleError {
+    fn from(error: RemoteError) -> Self {
+        if error.is_price_not_found() {
+            OracleError::PriceNotFound
+        } else if error.is_stale_price() {
+            OracleError::PriceStale
+        } else {
+            OracleError::CallFailed
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contracterror, contractimpl, vec, Env, Symbol};
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum FeedError {
+        StalePrice = 7,
+    }
+
+    #[contract]
+    struct StaleFeed;
+
+    #[contractimpl]
+    impl StaleFeed {
+        pub fn get_price(_env: Env, _pair: u32) -> Result<i128, FeedError> {
+            Err(FeedError::StalePrice)
+        }
+
+        pub fn boom(_env: Env) -> i128 {
+            panic!("boom")
+        }
+    }
+
+    #[test]
+    fn contract_errors_keep_domain_and_code() {
+        let env = Env::default();
+        let feed = env.register(StaleFeed, ());
+
+        let result = RemoteError::from_invoke(
+            ErrorDomain::Oracle,
+            env.try_invoke_contract::<i128, Error>(
+                &feed,
+                &Symbol::new(&env, "get_price"),
+                vec![&env, 1u32.into()],
+            ),
+        );
+        let error = result.unwrap_err();
+        assert_eq!(error, RemoteError::new(ErrorDomain::Oracle, oracle_codes::STALE_PRICE));
+        assert!(error.is_stale_price());
+        assert_eq!(OracleError::from(error), OracleError::PriceStale);
+
+        // The same code from another contract means something else.
+        let registry_error = RemoteError::new(ErrorDomain::SignalRegistry, 7);
+        assert!(!registry_error.is_stale_price());
+        assert_eq!(OracleError::from(registry_error), OracleError::CallFailed);
+        assert!(RemoteError::new(ErrorDomain::SignalRegistry, 1151).is_signal_not_found());
+
+        let result = RemoteError::from_invoke(
+            ErrorDomain::Oracle,
+            env.try_invoke_contract::<i128, Error>(&feed, &Symbol::new(&env, "boom"), vec![&env]),
+        );
+        assert_eq!(
+            result,
+            Err(RemoteError::new(ErrorDomain::Host, host_codes::ABORTED))
+        );
+    }
+}
@@ -3,13 +3,18 @@
 pub mod assets;
 pub mod commit_reveal;
 pub mod constants;
+pub mod decimals;
 pub mod emergency;
+pub mod errors;
 pub mod health;
+pub mod math;
 pub mod oracle;
 pub mod rate_limit;
 pub mod replay_protection;
+pub mod signal_ref;
+pub mod storage_health;
 
-pub use assets::{validate_asset_pair, Asset, AssetPair, AssetPairError};
+pub use assets::{normalize_asset_pair, validate_asset_pair, Asset, AssetPair, AssetPairError};
 pub use commit_reveal::hash_trade_intent;
 pub use constants::{
     BASIS_POINTS_DENOMINATOR, BASIS_POINTS_DENOMINATOR_I128, CAT_ALL, CAT_SIGNALS, CAT_STAKES,
@@ -17,16 +22,27 @@ pub use constants::{
     SECONDS_PER_30_DAY_MONTH, SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_WEEK,
     STELLAR_AMOUNT_SCALE,
 };
+pub use decimals::{
+    from_canonical, normalize_oracle_price, rescale, to_canonical, CANONICAL_DECIMALS, MAX_DECIMALS,
+};
 pub use emergency::PauseState;
+pub use errors::{ErrorDomain, RemoteError};
 pub use health::{health_uninitialized, placeholder_admin, HealthStatus};
+pub use math::{
+    apply_bps, div_round, mul_div_round, ratio_bps, RoundingMode, DEFAULT_ROUNDING_MODE,
+};
 pub use oracle::{
     oracle_price_to_i128, validate_freshness, IOracleClient, MockOracleClient, OnChainOracleClient,
-    OracleError, OraclePrice,
+    OracleError, OraclePrice, PriceFeedClient,
 };
 pub use rate_limit::{
     check_rate_limit, record_action, set_config as set_rate_limit_config, ActionType, RateLimitConfig,
 };
 pub use replay_protection::{current_nonce, verify_and_commit, ReplayError};
+pub use signal_ref::SignalRef;
+pub use storage_health::{
+    CategoryHealth, TrackedEntry, DEFAULT_ENTRY_TTL_LEDGERS, MAX_BUMP_BATCH, TTL_WARNING_LEDGERS,
+};
 
 #[cfg(test)]
 mod storage_key_tests;
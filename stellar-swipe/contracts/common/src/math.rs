@@ -0,0 +1,163 @@
+//! Fixed-point helpers for basis-point arithmetic.
+//!
+//! Plain integer division truncates toward zero, which systematically shrinks
+//! small ROI figures and biases aggregates. These helpers make the rounding
+//! policy explicit so every contract rounds the same way.
+
+use soroban_sdk::contracttype;
+
+/// How the remainder of an integer division is resolved.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Drop the remainder (Rust's `/`).
+    TowardZero,
+    /// Round to nearest; exact halves go away from zero.
+    HalfAwayFromZero,
+    /// Round to nearest; exact halves go to the even neighbour (banker's rounding).
+    HalfEven,
+}
+
+/// Policy used when a contract has not configured one.
+pub const DEFAULT_ROUNDING_MODE: RoundingMode = RoundingMode::HalfEven;
+
+/// `numerator / denominator` rounded according to `mode`.
+///
+/// Returns `None` when `denominator` is zero or the result overflows.
+pub fn div_round(numerator: i128, denominator: i128, mode: RoundingMode) -> Option<i128> {
+    if denominator == 0 {
+        return None;
+    }
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator.checked_rem(denominator)?;
+    if remainder == 0 || mode == RoundingMode::TowardZero {
+        return Some(quotient);
+    }
+
+    // The exact result lies strictly between `quotient` and `quotient ± 1`.
+    let step: i128 = if (numerator < 0) != (denominator < 0) { -1 } else { 1 };
+    let twice_rem = remainder.unsigned_abs().checked_mul(2)?;
+    let den_abs = denominator.unsigned_abs();
+
+    let round_away = if twice_rem > den_abs {
+        true
+    } else if twice_rem < den_abs {
+        false
+    } else {
+        match mode {
+            RoundingMode::HalfAwayFromZero => true,
+            RoundingMode::HalfEven => quotient % 2 != 0,
+            RoundingMode::TowardZero => false,
+        }
+    };
+
+    if round_away {
+        quotient.checked_add(step)
+    } else {
+        Some(quotient)
+    }
+}
+
+/// `a * b / denominator` rounded according to `mode`.
+pub fn mul_div_round(a: i128, b: i128, denominator: i128, mode: RoundingMode) -> Option<i128> {
+    div_round(a.checked_mul(b)?, denominator, mode)
+}
+
+/// `numerator / denominator` expressed in basis points.
+pub fn ratio_bps(numerator: i128, denominator: i128, mode: RoundingMode) -> Option<i128> {
+    mul_div_round(numerator, crate::BASIS_POINTS_DENOMINATOR_I128, denominator, mode)
+}
+
+/// `amount * bps / 10_000`, e.g. PnL from a volume and an ROI in bps.
+pub fn apply_bps(amount: i128, bps: i128, mode: RoundingMode) -> Option<i128> {
+    mul_div_round(amount, bps, crate::BASIS_POINTS_DENOMINATOR_I128, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [RoundingMode; 3] = [
+        RoundingMode::TowardZero,
+        RoundingMode::HalfAwayFromZero,
+        RoundingMode::HalfEven,
+    ];
+
+    /// Reference implementation: compares `2·num` against odd multiples of
+    /// `den` to locate the exact rational result without any division.
+    fn reference(num: i128, den: i128, mode: RoundingMode) -> i128 {
+        let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+        let mut floor = 0i128;
+        while (floor + 1) * den <= num {
+            floor += 1;
+        }
+        while floor * den > num {
+            floor -= 1;
+        }
+        let rem = num - floor * den;
+        if rem == 0 {
+            return floor;
+        }
+        let toward_zero = if num < 0 { floor + 1 } else { floor };
+        let away = if num < 0 { floor } else { floor + 1 };
+        match mode {
+            RoundingMode::TowardZero => toward_zero,
+            _ if 2 * rem < den => floor,
+            _ if 2 * rem > den => floor + 1,
+            RoundingMode::HalfAwayFromZero => away,
+            RoundingMode::HalfEven => {
+                if floor % 2 == 0 {
+                    floor
+                } else {
+                    floor + 1
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_exact_rational_results() {
+        for num in -60i128..=60 {
+            for den in [-8i128, -7, -4, -3, -2, -1, 1, 2, 3, 4, 7, 8] {
+                for mode in MODES {
+                    assert_eq!(
+                        div_round(num, den, mode),
+                        Some(reference(num, den, mode)),
+                        "{num}/{den} {mode:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn halves_follow_policy() {
+        assert_eq!(div_round(5, 2, RoundingMode::TowardZero), Some(2));
+        assert_eq!(div_round(5, 2, RoundingMode::HalfAwayFromZero), Some(3));
+        assert_eq!(div_round(5, 2, RoundingMode::HalfEven), Some(2));
+        assert_eq!(div_round(7, 2, RoundingMode::HalfEven), Some(4));
+        assert_eq!(div_round(-5, 2, RoundingMode::HalfAwayFromZero), Some(-3));
+        assert_eq!(div_round(-5, 2, RoundingMode::HalfEven), Some(-2));
+        assert_eq!(div_round(-7, 2, RoundingMode::HalfEven), Some(-4));
+    }
+
+    #[test]
+    fn small_roi_no_longer_truncated() {
+        // 2/3 of a basis point: 6_666.67 bps.
+        assert_eq!(ratio_bps(2, 3, RoundingMode::TowardZero), Some(6_666));
+        assert_eq!(ratio_bps(2, 3, RoundingMode::HalfEven), Some(6_667));
+        // -0.99 bps → -1 rather than 0.
+        assert_eq!(ratio_bps(-99, 1_000_000, RoundingMode::HalfEven), Some(-1));
+        assert_eq!(apply_bps(15, 5_000, RoundingMode::HalfEven), Some(8));
+        assert_eq!(apply_bps(25, 5_000, RoundingMode::HalfEven), Some(12));
+    }
+
+    #[test]
+    fn division_by_zero_and_overflow_are_none() {
+        for mode in MODES {
+            assert_eq!(div_round(1, 0, mode), None);
+            assert_eq!(div_round(i128::MIN, -1, mode), None);
+            assert_eq!(mul_div_round(i128::MAX, 2, 3, mode), None);
+        }
+    }
+}
@@ -4,8 +4,17 @@
 //! `IOracleClient` is the canonical trait for fetching manipulation-resistant
 //! prices.  The real implementation calls an on-chain oracle contract via
 //! `soroban_sdk::invoke`; the mock implementation is used in unit tests.
+//!
+//! `PriceFeedClient` reads the StellarSwipe oracle contract, which is keyed
+//! by `AssetPair` rather than a numeric id.
+
+use soroban_sdk::{
+    contracttype, symbol_short, vec, Address, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
+};
 
-use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Symbol};
+use crate::assets::AssetPair;
+use crate::decimals::{to_canonical, CANONICAL_DECIMALS};
+use crate::errors::{ErrorDomain, RemoteError};
 
 // ── Types ────────────────────────────────────────────────────────────────────
 
@@ -60,14 +69,76 @@ pub struct OnChainOracleClient {
 impl IOracleClient for OnChainOracleClient {
     fn get_price(&self, env: &Env, asset_pair: u32) -> Result<OraclePrice, OracleError> {
         // Cross-contract call: oracle_contract.get_price(asset_pair) -> OraclePrice
-        match env.try_invoke_contract::<OraclePrice, soroban_sdk::Error>(
+        let result = env.try_invoke_contract::<OraclePrice, soroban_sdk::Error>(
             &self.address,
             &Symbol::new(env, "get_price"),
             vec![env, asset_pair.into()],
-        ) {
-            Ok(Ok(price)) => Ok(price),
-            Ok(Err(_)) | Err(_) => Err(OracleError::CallFailed),
+        );
+        RemoteError::from_invoke(ErrorDomain::Oracle, result).map_err(OracleError::from)
+    }
+}
+
+// ── Price feed client ─────────────────────────────────────────────────────────
+
+/// Calls the StellarSwipe oracle contract, whose
+/// `get_price(AssetPair) -> ConsensusPriceData` serves prices at
+/// `CANONICAL_DECIMALS`. Only the `price` and `timestamp` fields are read,
+/// so the reply may carry fields this crate does not know about.
+pub struct PriceFeedClient {
+    pub address: Address,
+}
+
+impl PriceFeedClient {
+    pub fn get_price(&self, env: &Env, pair: &AssetPair) -> Result<OraclePrice, OracleError> {
+        let result = env.try_invoke_contract::<Map<Symbol, Val>, soroban_sdk::Error>(
+            &self.address,
+            &Symbol::new(env, "get_price"),
+            vec![env, pair.into_val(env)],
+        );
+        let data = RemoteError::from_invoke(ErrorDomain::Oracle, result)?;
+        let price = data
+            .get(symbol_short!("price"))
+            .and_then(|value| i128::try_from_val(env, &value).ok())
+            .ok_or(OracleError::CallFailed)?;
+        let timestamp = data
+            .get(Symbol::new(env, "timestamp"))
+            .and_then(|value| u64::try_from_val(env, &value).ok())
+            .ok_or(OracleError::CallFailed)?;
+        Ok(OraclePrice {
+            price,
+            decimals: CANONICAL_DECIMALS,
+            timestamp,
+            source: symbol_short!("oracle"),
+        })
+    }
+
+    /// Up to `limit` of the pair's published prices as `(price, timestamp)`,
+    /// newest first, read from `get_price_history(AssetPair, u32)`.
+    pub fn get_price_history(
+        &self,
+        env: &Env,
+        pair: &AssetPair,
+        limit: u32,
+    ) -> Result<Vec<(i128, u64)>, OracleError> {
+        let result = env.try_invoke_contract::<Vec<Map<Symbol, Val>>, soroban_sdk::Error>(
+            &self.address,
+            &Symbol::new(env, "get_price_history"),
+            vec![env, pair.into_val(env), limit.into_val(env)],
+        );
+        let data = RemoteError::from_invoke(ErrorDomain::Oracle, result)?;
+        let mut history = Vec::new(env);
+        for observation in data.iter() {
+            let price = observation
+                .get(symbol_short!("price"))
+                .and_then(|value| i128::try_from_val(env, &value).ok())
+                .ok_or(OracleError::CallFailed)?;
+            let timestamp = observation
+                .get(Symbol::new(env, "timestamp"))
+                .and_then(|value| u64::try_from_val(env, &value).ok())
+                .ok_or(OracleError::CallFailed)?;
+            history.push_back((price, timestamp));
         }
+        Ok(history)
     }
 }
 
@@ -112,11 +183,9 @@ pub fn validate_freshness(env: &Env, price: &OraclePrice) -> Result<(), OracleEr
     Ok(())
 }
 
-pub fn oracle_price_to_i128(price: &OraclePrice) -> i128 {
-    let scale = 10i128.pow(price.decimals);
-    if scale == 0 {
-        price.price
-    } else {
-        price.price / scale
-    }
+/// `price` at `CANONICAL_DECIMALS`, the precision signal and trade prices
+/// are quoted in. `None` if the oracle's decimals are unsupported or the
+/// rescaled price overflows.
+pub fn oracle_price_to_i128(price: &OraclePrice) -> Option<i128> {
+    to_canonical(price.price, price.decimals)
 }
@@ -167,7 +167,7 @@ pub fn record_action(env: &Env, user: &Address, action: ActionType) {
     record_first_action_if_new(env, user, now);
 
     let config = get_config(env, &action);
-    let mut timestamps = get_timestamps(env, user, &action);
+    let timestamps = get_timestamps(env, user, &action);
 
     // Prune entries outside the window first
     let mut pruned: Vec<u64> = Vec::new(env);
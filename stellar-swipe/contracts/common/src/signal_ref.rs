@@ -0,0 +1,43 @@
+//! Chain-wide signal identifiers.
+//!
+//! A signal id is only unique within the registry that issued it. Orders,
+//! trade receipts and exports carry a [`SignalRef`] instead so deployments with
+//! several registries (testnet and mainnet, or multiple communities) can never
+//! confuse two signals that share an id.
+
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignalRef {
+    /// Signal registry contract that issued the signal.
+    pub registry: Address,
+    /// Id of the signal within `registry`.
+    pub signal_id: u64,
+}
+
+impl SignalRef {
+    pub fn new(registry: Address, signal_id: u64) -> Self {
+        SignalRef {
+            registry,
+            signal_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Env;
+
+    #[test]
+    fn same_id_from_different_registries_differs() {
+        let env = Env::default();
+        let testnet = Address::generate(&env);
+        let mainnet = Address::generate(&env);
+
+        assert_eq!(SignalRef::new(testnet.clone(), 7), SignalRef::new(testnet.clone(), 7));
+        assert_ne!(SignalRef::new(testnet, 7), SignalRef::new(mainnet, 7));
+    }
+}
@@ -0,0 +1,237 @@
+//! Rent budgeting for persistent storage.
+//!
+//! A contract cannot read an entry's TTL back from the host, so each contract
+//! records the live-until ledger of the persistent entries it extends in a
+//! bounded index. `category_health` summarizes that index per category (the
+//! index is a sample once it holds `MAX_TRACKED_ENTRIES`), and `bump` extends
+//! the soonest-expiring entries of one category so operators can keep
+//! proposals, signals and positions from being archived.
+
+use soroban_sdk::{contracttype, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec};
+
+use crate::constants::{LEDGERS_PER_30_DAY_MONTH, LEDGERS_PER_DAY};
+
+/// Upper bound on entries tracked per contract.
+pub const MAX_TRACKED_ENTRIES: u32 = 256;
+/// TTL tracked entries are extended to by default.
+pub const DEFAULT_ENTRY_TTL_LEDGERS: u32 = LEDGERS_PER_30_DAY_MONTH;
+/// Entries with fewer ledgers left than this are reported as expiring.
+pub const TTL_WARNING_LEDGERS: u32 = LEDGERS_PER_DAY * 7;
+/// Upper bound on entries extended by one `bump` call.
+pub const MAX_BUMP_BATCH: u32 = 50;
+
+/// A persistent entry whose expiry a contract tracks.
+pub trait TrackedEntry: Clone + IntoVal<Env, Val> + TryFromVal<Env, Val> {
+    /// Category the entry is reported and bumped under.
+    fn category(&self) -> Symbol;
+    /// Extend the entry to `ledgers`. Returns false when it no longer exists.
+    fn extend(&self, env: &Env, ledgers: u32) -> bool;
+}
+
+#[contracttype]
+pub enum StorageHealthKey {
+    /// Live-until ledger of each tracked entry.
+    Expiries,
+}
+
+/// Tracked entries of one category.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CategoryHealth {
+    pub category: Symbol,
+    pub tracked: u32,
+    /// Fewest ledgers any tracked entry has left; 0 when none are tracked.
+    pub min_ttl_ledgers: u32,
+    /// Tracked entries with fewer than `TTL_WARNING_LEDGERS` left.
+    pub expiring: u32,
+}
+
+pub fn get_expiries<E: TrackedEntry>(env: &Env) -> Map<E, u32> {
+    env.storage()
+        .persistent()
+        .get(&StorageHealthKey::Expiries)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+fn save_expiries<E: TrackedEntry>(env: &Env, expiries: &Map<E, u32>) {
+    env.storage()
+        .persistent()
+        .set(&StorageHealthKey::Expiries, expiries);
+    env.storage().persistent().extend_ttl(
+        &StorageHealthKey::Expiries,
+        DEFAULT_ENTRY_TTL_LEDGERS,
+        DEFAULT_ENTRY_TTL_LEDGERS,
+    );
+}
+
+/// Record that `entry` was just extended to `ledgers`. Once the index is
+/// full, entries not already in it are left untracked.
+pub fn record<E: TrackedEntry>(env: &Env, entry: &E, ledgers: u32) {
+    let mut expiries = get_expiries::<E>(env);
+    if !expiries.contains_key(entry.clone()) && expiries.len() >= MAX_TRACKED_ENTRIES {
+        return;
+    }
+    expiries.set(entry.clone(), env.ledger().sequence() + ledgers);
+    save_expiries(env, &expiries);
+}
+
+/// Stop tracking an entry that was removed.
+pub fn forget<E: TrackedEntry>(env: &Env, entry: &E) {
+    let mut expiries = get_expiries::<E>(env);
+    if expiries.contains_key(entry.clone()) {
+        expiries.remove(entry.clone());
+        save_expiries(env, &expiries);
+    }
+}
+
+/// One row per category in `categories`, in that order.
+pub fn category_health<E: TrackedEntry>(env: &Env, categories: &[Symbol]) -> Vec<CategoryHealth> {
+    let expiries = get_expiries::<E>(env);
+    let now = env.ledger().sequence();
+
+    let mut report = Vec::new(env);
+    for category in categories {
+        let mut row = CategoryHealth {
+            category: category.clone(),
+            tracked: 0,
+            min_ttl_ledgers: 0,
+            expiring: 0,
+        };
+        for (entry, live_until) in expiries.iter() {
+            if entry.category() != *category {
+                continue;
+            }
+            let ledgers_left = live_until.saturating_sub(now);
+            row.min_ttl_ledgers = if row.tracked == 0 {
+                ledgers_left
+            } else {
+                row.min_ttl_ledgers.min(ledgers_left)
+            };
+            row.tracked += 1;
+            if ledgers_left < TTL_WARNING_LEDGERS {
+                row.expiring += 1;
+            }
+        }
+        report.push_back(row);
+    }
+    report
+}
+
+/// Extend up to `limit` tracked entries of `category` to `ledgers`, soonest
+/// expiring first. Entries that no longer exist are dropped from the index.
+/// Returns the number extended.
+pub fn bump<E: TrackedEntry>(env: &Env, category: &Symbol, limit: u32, ledgers: u32) -> u32 {
+    let mut expiries = get_expiries::<E>(env);
+
+    // Entries of the category ordered by live-until ledger, soonest first.
+    let mut due: Vec<E> = Vec::new(env);
+    let mut due_until: Vec<u32> = Vec::new(env);
+    for (entry, live_until) in expiries.iter() {
+        if entry.category() != *category {
+            continue;
+        }
+        let mut index = due.len();
+        for i in 0..due_until.len() {
+            if due_until.get(i).unwrap() > live_until {
+                index = i;
+                break;
+            }
+        }
+        due.insert(index, entry);
+        due_until.insert(index, live_until);
+    }
+
+    let mut bumped = 0;
+    for entry in due.iter().take(limit.min(MAX_BUMP_BATCH) as usize) {
+        if entry.extend(env, ledgers) {
+            expiries.set(entry, env.ledger().sequence() + ledgers);
+            bumped += 1;
+        } else {
+            expiries.remove(entry);
+        }
+    }
+    save_expiries(env, &expiries);
+    bumped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, symbol_short, testutils::Ledger as _};
+
+    #[contract]
+    struct TrackerContract;
+
+    #[contracttype]
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum Entry {
+        Proposal(u64),
+        Position(u64),
+    }
+
+    impl TrackedEntry for Entry {
+        fn category(&self) -> Symbol {
+            match self {
+                Entry::Proposal(_) => symbol_short!("proposal"),
+                Entry::Position(_) => symbol_short!("position"),
+            }
+        }
+
+        fn extend(&self, env: &Env, ledgers: u32) -> bool {
+            if !env.storage().persistent().has(self) {
+                return false;
+            }
+            env.storage().persistent().extend_ttl(self, ledgers, ledgers);
+            true
+        }
+    }
+
+    fn write(env: &Env, entry: Entry, ledgers: u32) {
+        env.storage().persistent().set(&entry, &true);
+        entry.extend(env, ledgers);
+        record(env, &entry, ledgers);
+    }
+
+    #[test]
+    fn reports_and_bumps_by_category() {
+        let env = Env::default();
+        let contract_id = env.register(TrackerContract, ());
+        let categories = [symbol_short!("proposal"), symbol_short!("position")];
+
+        env.as_contract(&contract_id, || {
+            write(&env, Entry::Proposal(1), TTL_WARNING_LEDGERS - 10);
+            write(&env, Entry::Proposal(2), DEFAULT_ENTRY_TTL_LEDGERS);
+            write(&env, Entry::Position(1), DEFAULT_ENTRY_TTL_LEDGERS);
+
+            let report = category_health::<Entry>(&env, &categories);
+            let proposals = report.get(0).unwrap();
+            assert_eq!(proposals.tracked, 2);
+            assert_eq!(proposals.min_ttl_ledgers, TTL_WARNING_LEDGERS - 10);
+            assert_eq!(proposals.expiring, 1);
+            assert_eq!(report.get(1).unwrap().expiring, 0);
+
+            // Only the soonest-expiring proposal is bumped.
+            assert_eq!(
+                bump::<Entry>(&env, &categories[0], 1, DEFAULT_ENTRY_TTL_LEDGERS),
+                1
+            );
+            let proposals = category_health::<Entry>(&env, &categories).get(0).unwrap();
+            assert_eq!(proposals.expiring, 0);
+            assert_eq!(proposals.min_ttl_ledgers, DEFAULT_ENTRY_TTL_LEDGERS);
+
+            // Removed entries fall out of the index when bumped.
+            env.storage().persistent().remove(&Entry::Position(1));
+            assert_eq!(
+                bump::<Entry>(&env, &categories[1], 10, DEFAULT_ENTRY_TTL_LEDGERS),
+                0
+            );
+            assert_eq!(category_health::<Entry>(&env, &categories).get(1).unwrap().tracked, 0);
+        });
+
+        env.ledger().set_sequence_number(env.ledger().sequence() + 100);
+        env.as_contract(&contract_id, || {
+            let proposals = category_health::<Entry>(&env, &categories).get(0).unwrap();
+            assert_eq!(proposals.min_ttl_ledgers, DEFAULT_ENTRY_TTL_LEDGERS - 100);
+        });
+    }
+}
@@ -57,6 +57,11 @@ fn setup() -> (Env, Address, Address, Address, Address, Address) {
     registry.initialize(&admin);
     registry.set_trade_executor(&admin, &executor_id);
 
+    // Stake is paid in a token the admin configures before anyone stakes
+    let stake_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    registry.set_stake_token(&admin, &stake_token);
+    soroban_sdk::token::StellarAssetClient::new(&env, &stake_token).mint(&sara, &200_000_000i128);
+
     // Sara stakes 200 XLM (above 100 XLM minimum)
     registry.stake_tokens(&sara, &200_000_000i128);
 
@@ -115,7 +120,7 @@ fn test_complete_user_journey() {
 
     // Step A1: Alex browses signals — signal is visible
     let signal = registry.get_signal(&signal_id).unwrap();
-    assert_eq!(signal.asset_pair, String::from_str(&env, "XLM/USDC"));
+    assert_eq!(signal.asset_pair.to_string(&env), String::from_str(&env, "XLM/USDC"));
     assert_eq!(signal.action, SignalAction::Buy);
 
     // Step A2: Alex swipes right (increment_adoption — must be called by executor)
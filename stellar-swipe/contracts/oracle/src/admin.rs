@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Env, Map, String};
+use soroban_sdk::{Address, Env, Map, String};
 use stellar_swipe_common::emergency::{PauseState, CAT_ALL};
 
 use crate::errors::OracleError;
@@ -49,7 +49,12 @@ pub fn pause_category(
         require_admin(env, caller)?;
     }
     caller.require_auth();
+    apply_pause(env, category, duration, reason);
+    Ok(())
+}
 
+/// Pause `category` without a caller check, for pauses decided by governance.
+pub(crate) fn apply_pause(env: &Env, category: String, duration: Option<u64>, reason: String) {
     let now = env.ledger().timestamp();
     let auto_unpause_at = duration.map(|d| now + d);
 
@@ -57,22 +62,25 @@ pub fn pause_category(
         paused: true,
         paused_at: now,
         auto_unpause_at,
-        reason: reason.clone(),
+        reason,
     };
 
     let mut states = get_pause_states(env);
-    states.set(category.clone(), pause_state);
+    states.set(category, pause_state);
     env.storage()
         .instance()
         .set(&StorageKey::PauseStates, &states);
-
-    Ok(())
 }
 
 pub fn unpause_category(env: &Env, caller: &Address, category: String) -> Result<(), OracleError> {
     require_admin(env, caller)?;
     caller.require_auth();
+    lift_pause(env, category);
+    Ok(())
+}
 
+/// Lift a pause on `category` without a caller check.
+pub(crate) fn lift_pause(env: &Env, category: String) {
     let mut states = get_pause_states(env);
     if states.contains_key(category.clone()) {
         states.remove(category.clone());
@@ -80,7 +88,6 @@ pub fn unpause_category(env: &Env, caller: &Address, category: String) -> Result
             .instance()
             .set(&StorageKey::PauseStates, &states);
     }
-    Ok(())
 }
 
 pub fn get_pause_states(env: &Env) -> Map<String, PauseState> {
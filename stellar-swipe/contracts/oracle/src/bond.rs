@@ -0,0 +1,180 @@
+//! Oracle operator bonds.
+//!
+//! Once the admin sets a minimum bond, an oracle's submissions are accepted
+//! only while it has at least that much bonded. The bond is the collateral
+//! behind slashing: every reputation slash also burns a share of it into the
+//! slashed pool. When an oracle leaves the set its bond starts unbonding and,
+//! still slashable, is released to the operator by `claim_bond` once the
+//! unbonding period has passed.
+//!
+//! Bonds are posted in the governance stake token and held by the contract:
+//! `post` pulls the tokens in, `claim` pays what is left of the bond back, and
+//! slashed amounts stay in the contract as the slashed pool.
+
+use soroban_sdk::{contracttype, token, Address, Env};
+use stellar_swipe_common::{BASIS_POINTS_DENOMINATOR_I128, SECONDS_PER_WEEK};
+
+use crate::errors::OracleError;
+use crate::events;
+use crate::governance;
+use crate::reputation::SlashReason;
+
+/// Default time a departing oracle's bond stays slashable.
+pub const DEFAULT_UNBONDING_PERIOD_SECS: u64 = SECONDS_PER_WEEK;
+
+/// Share of the bond burned per slash, in BPS.
+pub const MAJOR_DEVIATION_BOND_SLASH_BPS: i128 = 1_000;
+pub const SIGNATURE_FAILURE_BOND_SLASH_BPS: i128 = 1_500;
+pub const PERSISTENT_DEVIATION_BOND_SLASH_BPS: i128 = 500;
+pub const UPHELD_DISPUTE_BOND_SLASH_BPS: i128 = 2_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OracleBond {
+    pub amount: i128,
+    /// Set once the oracle has left the set: when the bond can be claimed.
+    pub unlocks_at: Option<u64>,
+}
+
+#[contracttype]
+pub enum BondKey {
+    MinBond,
+    UnbondingPeriod,
+    Bond(Address),
+    /// Total slashed out of bonds so far.
+    SlashedPool,
+}
+
+pub fn get_min_bond(env: &Env) -> i128 {
+    env.storage().instance().get(&BondKey::MinBond).unwrap_or(0)
+}
+
+pub fn get_unbonding_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&BondKey::UnbondingPeriod)
+        .unwrap_or(DEFAULT_UNBONDING_PERIOD_SECS)
+}
+
+pub fn set_config(env: &Env, min_bond: i128, unbonding_period: u64) -> Result<(), OracleError> {
+    if min_bond < 0 {
+        return Err(OracleError::InvalidBondConfig);
+    }
+    env.storage().instance().set(&BondKey::MinBond, &min_bond);
+    env.storage()
+        .instance()
+        .set(&BondKey::UnbondingPeriod, &unbonding_period);
+    Ok(())
+}
+
+pub fn get_bond(env: &Env, oracle: &Address) -> OracleBond {
+    env.storage()
+        .persistent()
+        .get(&BondKey::Bond(oracle.clone()))
+        .unwrap_or_default()
+}
+
+fn save_bond(env: &Env, oracle: &Address, bond: &OracleBond) {
+    let key = BondKey::Bond(oracle.clone());
+    if bond.amount == 0 && bond.unlocks_at.is_none() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, bond);
+    }
+}
+
+pub fn get_slashed_pool(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&BondKey::SlashedPool)
+        .unwrap_or(0)
+}
+
+/// The token bonds are held in: the governance stake token.
+pub fn bond_token(env: &Env) -> Result<token::Client<'_>, OracleError> {
+    governance::stake_token(env).map_err(|_| OracleError::BondTokenNotSet)
+}
+
+/// Transfer `amount` from the oracle into its bond. A bond that is unbonding
+/// can't be topped up; it has to be claimed first.
+pub fn post(env: &Env, oracle: &Address, amount: i128) -> Result<OracleBond, OracleError> {
+    if amount <= 0 {
+        return Err(OracleError::InvalidBondAmount);
+    }
+    let mut bond = get_bond(env, oracle);
+    if bond.unlocks_at.is_some() {
+        return Err(OracleError::BondLocked);
+    }
+    bond.amount = bond.amount.checked_add(amount).ok_or(OracleError::Overflow)?;
+    bond_token(env)?.transfer(oracle, env.current_contract_address(), &amount);
+    save_bond(env, oracle, &bond);
+    Ok(bond)
+}
+
+/// Reject submissions from an oracle without the minimum bond in place.
+pub fn require_bonded(env: &Env, oracle: &Address) -> Result<(), OracleError> {
+    let min_bond = get_min_bond(env);
+    if min_bond == 0 {
+        return Ok(());
+    }
+    let bond = get_bond(env, oracle);
+    if bond.unlocks_at.is_some() || bond.amount < min_bond {
+        return Err(OracleError::InsufficientBond);
+    }
+    Ok(())
+}
+
+/// Burn the share of the oracle's bond `reason` costs. Returns the amount.
+pub fn slash(env: &Env, oracle: &Address, reason: SlashReason) -> i128 {
+    let mut bond = get_bond(env, oracle);
+    if bond.amount == 0 {
+        return 0;
+    }
+    let bps = match reason {
+        SlashReason::MajorDeviation => MAJOR_DEVIATION_BOND_SLASH_BPS,
+        SlashReason::SignatureFailure => SIGNATURE_FAILURE_BOND_SLASH_BPS,
+        SlashReason::PersistentDeviation => PERSISTENT_DEVIATION_BOND_SLASH_BPS,
+        SlashReason::UpheldDispute => UPHELD_DISPUTE_BOND_SLASH_BPS,
+    };
+    let slashed = bond.amount * bps / BASIS_POINTS_DENOMINATOR_I128;
+    bond.amount -= slashed;
+    save_bond(env, oracle, &bond);
+    env.storage()
+        .instance()
+        .set(&BondKey::SlashedPool, &(get_slashed_pool(env) + slashed));
+    events::emit_bond_slashed(env, oracle.clone(), slashed, bond.amount);
+    slashed
+}
+
+/// Pay `amount` out of the slashed pool, to the winner of a dispute.
+pub fn take_slashed(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&BondKey::SlashedPool, &(get_slashed_pool(env) - amount));
+}
+
+/// Start the unbonding period of an oracle leaving the set.
+pub fn begin_unbonding(env: &Env, oracle: &Address) {
+    let mut bond = get_bond(env, oracle);
+    if bond.amount == 0 || bond.unlocks_at.is_some() {
+        return;
+    }
+    bond.unlocks_at = Some(env.ledger().timestamp() + get_unbonding_period(env));
+    save_bond(env, oracle, &bond);
+}
+
+/// Transfer an unbonded bond back to its operator. Returns the amount
+/// released.
+pub fn claim(env: &Env, oracle: &Address) -> Result<i128, OracleError> {
+    let bond = get_bond(env, oracle);
+    match bond.unlocks_at {
+        Some(unlocks_at) if env.ledger().timestamp() >= unlocks_at => {
+            save_bond(env, oracle, &OracleBond::default());
+            if bond.amount > 0 {
+                bond_token(env)?.transfer(&env.current_contract_address(), oracle, &bond.amount);
+            }
+            Ok(bond.amount)
+        }
+        _ => Err(OracleError::BondLocked),
+    }
+}
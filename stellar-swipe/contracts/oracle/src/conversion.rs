@@ -1,12 +1,15 @@
 //! Price conversion system for multi-asset portfolio aggregation
 
 use crate::errors::OracleError;
-use crate::storage::{get_base_currency, get_price};
+use crate::storage::{get_base_currency, get_price, get_quote_assets};
 use soroban_sdk::{contracttype, vec, Env, Map, Vec};
 use stellar_swipe_common::{Asset, AssetPair, STELLAR_AMOUNT_SCALE};
 
 const MAX_PATH_LENGTH: u32 = 3;
 
+/// Upper bound on configured intermediate quote assets.
+pub const MAX_QUOTE_ASSETS: u32 = 5;
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct ConversionPath {
@@ -31,26 +34,121 @@ pub fn convert_to_base(env: &Env, amount: i128, asset: Asset) -> Result<i128, Or
     convert_via_path(env, amount, asset, base)
 }
 
-/// Direct conversion: asset → base
+/// Convert `amount` of `from` into `to`.
+///
+/// Uses the `from/to` pair (or its inverse) when one is priced; otherwise
+/// routes through the first configured quote asset both legs can reach.
+pub fn convert(env: &Env, amount: i128, from: Asset, to: Asset) -> Result<i128, OracleError> {
+    if from == to {
+        return Ok(amount);
+    }
+
+    if let Ok(result) = convert_direct(env, amount, &from, &to) {
+        return Ok(result);
+    }
+
+    let path = find_quote_path(env, &from, &to)?;
+    convert_along_path(env, amount, &from, &to, &path)
+}
+
+/// Direct conversion over a single pair, in either direction.
 fn convert_direct(env: &Env, amount: i128, from: &Asset, to: &Asset) -> Result<i128, OracleError> {
     let pair = AssetPair {
         base: from.clone(),
         quote: to.clone(),
     };
-    let price = get_price(env, &pair)?;
+    if let Ok(price) = get_price(env, &pair) {
+        return amount
+            .checked_mul(price)
+            .and_then(|v| v.checked_div(STELLAR_AMOUNT_SCALE))
+            .ok_or(OracleError::ConversionOverflow);
+    }
 
-    Ok(amount
-        .checked_mul(price)
-        .and_then(|v| v.checked_div(STELLAR_AMOUNT_SCALE))
-        .ok_or(OracleError::ConversionOverflow)?)
+    let inverse = AssetPair {
+        base: to.clone(),
+        quote: from.clone(),
+    };
+    let price = get_price(env, &inverse)?;
+    if price <= 0 {
+        return Err(OracleError::InvalidPrice);
+    }
+    amount
+        .checked_mul(STELLAR_AMOUNT_SCALE)
+        .and_then(|v| v.checked_div(price))
+        .ok_or(OracleError::ConversionOverflow)
 }
 
 /// Path-based conversion: asset → intermediate(s) → base
 fn convert_via_path(env: &Env, amount: i128, from: Asset, to: Asset) -> Result<i128, OracleError> {
     let path = find_conversion_path(env, &from, &to)?;
+    convert_along_path(env, amount, &from, &to, &path)
+}
+
+/// Two-hop path `from → quote → to` through the first configured quote asset
+/// priced against both ends.
+fn find_quote_path(env: &Env, from: &Asset, to: &Asset) -> Result<ConversionPath, OracleError> {
+    for quote in get_quote_assets(env).iter() {
+        if quote == *from || quote == *to {
+            continue;
+        }
+        if is_priced(env, from, &quote) && is_priced(env, &quote, to) {
+            return Ok(ConversionPath {
+                assets: vec![env, from.clone(), quote, to.clone()],
+                total_hops: 2,
+            });
+        }
+    }
+    Err(OracleError::NoConversionPath)
+}
+
+fn is_priced(env: &Env, a: &Asset, b: &Asset) -> bool {
+    let pair = AssetPair {
+        base: a.clone(),
+        quote: b.clone(),
+    };
+    let inverse = AssetPair {
+        base: b.clone(),
+        quote: a.clone(),
+    };
+    get_price(env, &pair).is_ok() || get_price(env, &inverse).is_ok()
+}
+
+/// Check that `path` runs from `from` to `to` within `MAX_PATH_LENGTH` hops
+/// without revisiting an asset.
+pub fn validate_path(
+    path: &ConversionPath,
+    from: &Asset,
+    to: &Asset,
+) -> Result<(), OracleError> {
+    let len = path.assets.len();
+    if len < 2 || len - 1 > MAX_PATH_LENGTH || path.total_hops != len - 1 {
+        return Err(OracleError::InvalidPath);
+    }
+    if path.assets.first() != Some(from.clone()) || path.assets.last() != Some(to.clone()) {
+        return Err(OracleError::InvalidPath);
+    }
+    for i in 0..len {
+        let asset = path.assets.get(i).ok_or(OracleError::InvalidPath)?;
+        for j in (i + 1)..len {
+            if path.assets.get(j) == Some(asset.clone()) {
+                return Err(OracleError::InvalidPath);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn convert_along_path(
+    env: &Env,
+    amount: i128,
+    from: &Asset,
+    to: &Asset,
+    path: &ConversionPath,
+) -> Result<i128, OracleError> {
+    validate_path(path, from, to)?;
 
     let mut current_amount = amount;
-    let mut current_asset = from;
+    let mut current_asset = from.clone();
 
     for i in 1..path.assets.len() {
         let next_asset = path.assets.get(i).ok_or(OracleError::InvalidPath)?;
@@ -152,30 +250,115 @@ mod tests {
     #[test]
     fn test_convert_same_asset() {
         let env = Env::default();
-        let xlm = xlm(&env);
-        set_base_currency(&env, xlm.clone());
-
-        let result = convert_to_base(&env, 1000_0000000, xlm).unwrap();
-        assert_eq!(result, 1000_0000000);
+        let contract_id = env.register(ConversionContract, ());
+        env.as_contract(&contract_id, || {
+            let xlm = xlm(&env);
+            set_base_currency(&env, xlm.clone());
+
+            let result = convert_to_base(&env, 1000_0000000, xlm).unwrap();
+            assert_eq!(result, 1000_0000000);
+        });
     }
 
     #[test]
     fn test_direct_conversion() {
         let env = Env::default();
-        let xlm = xlm(&env);
-        let usdc = usdc(&env);
+        let contract_id = env.register(ConversionContract, ());
+        env.as_contract(&contract_id, || {
+            let xlm = xlm(&env);
+            let usdc = usdc(&env);
+
+            set_base_currency(&env, xlm.clone());
+
+            // 1 USDC = 10 XLM
+            let pair = AssetPair {
+                base: usdc.clone(),
+                quote: xlm.clone(),
+            };
+            set_price(&env, &pair, 10 * STELLAR_AMOUNT_SCALE);
+
+            // Convert 100 USDC to XLM
+            let result = convert_to_base(&env, 100_0000000, usdc).unwrap();
+            assert_eq!(result, 1000_0000000); // 100 * 10 = 1000 XLM
+        });
+    }
+
+    #[soroban_sdk::contract]
+    struct ConversionContract;
+
+    fn native(env: &Env, code: &str) -> Asset {
+        Asset {
+            code: String::from_str(env, code),
+            issuer: None,
+        }
+    }
+
+    fn pair(base: &Asset, quote: &Asset) -> AssetPair {
+        AssetPair {
+            base: base.clone(),
+            quote: quote.clone(),
+        }
+    }
+
+    #[test]
+    fn test_convert_routes_through_quote_asset() {
+        let env = Env::default();
+        let contract_id = env.register(ConversionContract, ());
+        let (btc, eth, usdc) = (native(&env, "BTC"), native(&env, "ETH"), native(&env, "USDC"));
+
+        env.as_contract(&contract_id, || {
+            // 1 BTC = 60_000 USDC, 1 ETH = 3_000 USDC; no BTC/ETH pair.
+            set_price(&env, &pair(&btc, &usdc), 60_000 * STELLAR_AMOUNT_SCALE);
+            set_price(&env, &pair(&eth, &usdc), 3_000 * STELLAR_AMOUNT_SCALE);
+
+            assert_eq!(
+                convert(&env, STELLAR_AMOUNT_SCALE, btc.clone(), eth.clone()),
+                Err(OracleError::NoConversionPath)
+            );
+
+            crate::storage::set_quote_assets(&env, &vec![&env, usdc.clone()]);
+            assert_eq!(
+                convert(&env, STELLAR_AMOUNT_SCALE, btc.clone(), eth.clone()),
+                Ok(20 * STELLAR_AMOUNT_SCALE)
+            );
+            // The inverse leg divides by the pair price.
+            assert_eq!(
+                convert(&env, 20 * STELLAR_AMOUNT_SCALE, eth.clone(), btc.clone()),
+                Ok(STELLAR_AMOUNT_SCALE)
+            );
+            assert_eq!(
+                convert(&env, 3_000 * STELLAR_AMOUNT_SCALE, usdc.clone(), eth.clone()),
+                Ok(STELLAR_AMOUNT_SCALE)
+            );
+            assert_eq!(
+                convert(&env, i128::MAX, btc.clone(), eth.clone()),
+                Err(OracleError::ConversionOverflow)
+            );
+        });
+    }
 
-        set_base_currency(&env, xlm.clone());
+    #[test]
+    fn test_validate_path() {
+        let env = Env::default();
+        let (a, b, c) = (native(&env, "A"), native(&env, "B"), native(&env, "C"));
 
-        // 1 USDC = 10 XLM
-        let pair = AssetPair {
-            base: usdc.clone(),
-            quote: xlm.clone(),
+        let path = |assets: Vec<Asset>| ConversionPath {
+            total_hops: assets.len() - 1,
+            assets,
         };
-        set_price(&env, &pair, 10 * STELLAR_AMOUNT_SCALE);
 
-        // Convert 100 USDC to XLM
-        let result = convert_to_base(&env, 100_0000000, usdc).unwrap();
-        assert_eq!(result, 1000_0000000); // 100 * 10 = 1000 XLM
+        assert!(validate_path(&path(vec![&env, a.clone(), b.clone(), c.clone()]), &a, &c).is_ok());
+        assert_eq!(
+            validate_path(&path(vec![&env, a.clone()]), &a, &a),
+            Err(OracleError::InvalidPath)
+        );
+        assert_eq!(
+            validate_path(&path(vec![&env, a.clone(), b.clone(), a.clone(), c.clone()]), &a, &c),
+            Err(OracleError::InvalidPath)
+        );
+        assert_eq!(
+            validate_path(&path(vec![&env, b.clone(), c.clone()]), &a, &c),
+            Err(OracleError::InvalidPath)
+        );
     }
 }
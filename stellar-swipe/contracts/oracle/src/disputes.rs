@@ -0,0 +1,231 @@
+//! Disputes over finalised rounds.
+//!
+//! For a short window after a round is finalised, any governance staker may
+//! dispute it by posting the dispute bond, which is transferred into the
+//! contract. While the dispute is open the round's price is frozen:
+//! `get_price` skips it as if the internal source had no price. Disputing
+//! opens a `ResolveDispute` governance vote on the short dispute period:
+//!
+//! - upheld (the vote executes): every oracle behind the round is slashed,
+//!   the price stays frozen until the next round, and the disputer wins back
+//!   their bond plus what was slashed;
+//! - rejected (the vote fails): the price is served again and the disputer's
+//!   bond is shared among the oracles behind the round.
+//!
+//! Winnings are credited here and transferred out by
+//! `claim_dispute_winnings`. Bonds, dispute bonds and winnings are all in the
+//! governance stake token.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use stellar_swipe_common::{AssetPair, STELLAR_AMOUNT_SCALE};
+
+use crate::bond;
+use crate::errors::OracleError;
+use crate::events;
+use crate::governance::OracleGovernance;
+use crate::reputation::{slash_oracle, SlashReason};
+
+/// Default time after finalisation during which a round can be disputed.
+pub const DEFAULT_DISPUTE_WINDOW_SECS: u64 = 600;
+/// Default bond a disputer posts (100 XLM).
+pub const DEFAULT_DISPUTE_BOND: i128 = 100 * STELLAR_AMOUNT_SCALE;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dispute {
+    pub pair: AssetPair,
+    pub round: u64,
+    pub disputer: Address,
+    pub bond: i128,
+    pub opened_at: u64,
+    /// Oracles whose submissions made up the round.
+    pub oracles: Vec<Address>,
+    pub status: DisputeStatus,
+    /// The `ResolveDispute` governance proposal settling the dispute.
+    pub proposal_id: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisputeConfig {
+    pub window_secs: u64,
+    pub bond: i128,
+}
+
+#[contracttype]
+pub enum DisputeKey {
+    Config,
+    /// Oracles behind the pair's last finalised round.
+    RoundOracles(AssetPair),
+    Dispute(AssetPair, u64),
+    /// Dispute winnings not yet claimed.
+    Winnings(Address),
+}
+
+pub fn get_config(env: &Env) -> DisputeConfig {
+    env.storage()
+        .instance()
+        .get(&DisputeKey::Config)
+        .unwrap_or(DisputeConfig {
+            window_secs: DEFAULT_DISPUTE_WINDOW_SECS,
+            bond: DEFAULT_DISPUTE_BOND,
+        })
+}
+
+pub fn set_config(env: &Env, config: &DisputeConfig) -> Result<(), OracleError> {
+    if config.window_secs == 0 || config.bond <= 0 {
+        return Err(OracleError::InvalidPairConfig);
+    }
+    env.storage().instance().set(&DisputeKey::Config, config);
+    Ok(())
+}
+
+/// Remember who made up the round just finalised for `pair`.
+pub fn record_round(env: &Env, pair: &AssetPair, oracles: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DisputeKey::RoundOracles(pair.clone()), oracles);
+}
+
+pub fn get_dispute(env: &Env, pair: &AssetPair, round: u64) -> Option<Dispute> {
+    env.storage()
+        .persistent()
+        .get(&DisputeKey::Dispute(pair.clone(), round))
+}
+
+fn save_dispute(env: &Env, dispute: &Dispute) {
+    env.storage().persistent().set(
+        &DisputeKey::Dispute(dispute.pair.clone(), dispute.round),
+        dispute,
+    );
+}
+
+/// Whether consumers must not be served the result of `round`.
+pub fn is_frozen(env: &Env, pair: &AssetPair, round: u64) -> bool {
+    matches!(
+        get_dispute(env, pair, round).map(|d| d.status),
+        Some(DisputeStatus::Open | DisputeStatus::Upheld)
+    )
+}
+
+/// Open a dispute over `round`, the pair's last finalised round, which was
+/// finalised at `finalised_at`: escrow the disputer's bond and put the
+/// dispute to a governance vote.
+pub fn open(
+    env: &Env,
+    disputer: Address,
+    pair: AssetPair,
+    round: u64,
+    finalised_at: u64,
+) -> Result<Dispute, OracleError> {
+    let config = get_config(env);
+    let now = env.ledger().timestamp();
+    if now > finalised_at + config.window_secs {
+        return Err(OracleError::DisputeWindowClosed);
+    }
+    if get_dispute(env, &pair, round).is_some() {
+        return Err(OracleError::DisputeExists);
+    }
+    if OracleGovernance::get_stake(env, &disputer) <= 0 {
+        return Err(OracleError::DisputerNotStaked);
+    }
+    bond::bond_token(env)?.transfer(&disputer, env.current_contract_address(), &config.bond);
+
+    let dispute = Dispute {
+        oracles: env
+            .storage()
+            .persistent()
+            .get(&DisputeKey::RoundOracles(pair.clone()))
+            .unwrap_or_else(|| Vec::new(env)),
+        proposal_id: OracleGovernance::open_dispute_vote(env, &disputer, &pair, round),
+        pair,
+        round,
+        disputer,
+        bond: config.bond,
+        opened_at: now,
+        status: DisputeStatus::Open,
+    };
+    save_dispute(env, &dispute);
+    events::emit_round_disputed(env, &dispute);
+    Ok(dispute)
+}
+
+/// Settle an open dispute and credit the winner. Called when its governance
+/// vote executes (upheld) or fails (rejected).
+pub fn resolve(
+    env: &Env,
+    pair: &AssetPair,
+    round: u64,
+    upheld: bool,
+) -> Result<Dispute, OracleError> {
+    let mut dispute = match get_dispute(env, pair, round) {
+        Some(dispute) if dispute.status == DisputeStatus::Open => dispute,
+        _ => return Err(OracleError::DisputeNotFound),
+    };
+
+    if upheld {
+        let mut slashed: i128 = 0;
+        for oracle in dispute.oracles.iter() {
+            slashed += slash_oracle(env, &oracle, SlashReason::UpheldDispute);
+        }
+        bond::take_slashed(env, slashed);
+        credit(env, &dispute.disputer, dispute.bond + slashed);
+        dispute.status = DisputeStatus::Upheld;
+    } else {
+        let count = dispute.oracles.len() as i128;
+        if count == 0 {
+            credit(env, &dispute.disputer, dispute.bond);
+        } else {
+            let share = dispute.bond / count;
+            for (i, oracle) in dispute.oracles.iter().enumerate() {
+                // The last oracle also takes the rounding remainder.
+                let amount = if i as i128 == count - 1 {
+                    dispute.bond - share * (count - 1)
+                } else {
+                    share
+                };
+                credit(env, &oracle, amount);
+            }
+        }
+        dispute.status = DisputeStatus::Rejected;
+    }
+
+    save_dispute(env, &dispute);
+    events::emit_dispute_resolved(env, &dispute);
+    Ok(dispute)
+}
+
+pub fn get_winnings(env: &Env, account: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DisputeKey::Winnings(account.clone()))
+        .unwrap_or(0)
+}
+
+fn credit(env: &Env, account: &Address, amount: i128) {
+    env.storage().persistent().set(
+        &DisputeKey::Winnings(account.clone()),
+        &(get_winnings(env, account) + amount),
+    );
+}
+
+/// Transfer `account`'s winnings to it. Returns the amount.
+pub fn claim(env: &Env, account: &Address) -> Result<i128, OracleError> {
+    let amount = get_winnings(env, account);
+    if amount == 0 {
+        return Ok(0);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DisputeKey::Winnings(account.clone()));
+    bond::bond_token(env)?.transfer(&env.current_contract_address(), account, &amount);
+    Ok(amount)
+}
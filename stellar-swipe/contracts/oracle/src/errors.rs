@@ -29,4 +29,58 @@ pub enum OracleError {
     PriceStaleTradeBlocked = 22,
     PendingAdminNotFound = 23,
     PendingAdminExpired = 24,
+    InvalidTwapWindow = 25,
+    PairDisabled = 26,
+    InvalidPairConfig = 27,
+    TooManyPriceRequests = 28,
+    PriceRequestNotFound = 29,
+    InsufficientBond = 30,
+    BondLocked = 31,
+    InvalidEmaPeriod = 32,
+    InvalidNonce = 33,
+    SigningKeyNotRegistered = 34,
+    BatchTooLarge = 35,
+    RoundNotReady = 36,
+    DisputeWindowClosed = 37,
+    DisputeExists = 38,
+    DisputeNotFound = 39,
+    PriceDisputed = 40,
+    InvalidBondAmount = 41,
+    InvalidBondConfig = 42,
+    /// Bonds are posted in the governance stake token, which isn't set yet.
+    BondTokenNotSet = 43,
+    /// Only governance stakers may dispute a round.
+    DisputerNotStaked = 44,
+}
+
+/// Errors returned by oracle governance: staking, proposals, votes and
+/// rewards. Kept apart from [`OracleError`], which is at the variant limit.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GovernanceError {
+    Unauthorized = 100,
+    InvalidAmount = 101,
+    InvalidParameter = 102,
+    InvalidPayload = 103,
+    NoStake = 104,
+    InsufficientStake = 105,
+    AlreadyVoted = 106,
+    UnstakeCooldownActive = 107,
+    NoPendingUnstake = 108,
+    ProposalNotFound = 109,
+    ProposalNotFinalised = 110,
+    ArchiveDelayActive = 111,
+    ProposalNotActive = 112,
+    ProposalHasVotes = 113,
+    ProposalNotRetryable = 114,
+    NothingToClaim = 115,
+    OracleNotFound = 116,
+    OracleAlreadyExists = 117,
+    InsufficientOracles = 118,
+    InvalidPairConfig = 119,
+    PairDisabled = 120,
+    /// Governance has not been given a stake token yet.
+    StakeTokenNotSet = 121,
+    /// The round a dispute vote settles is no longer under dispute.
+    DisputeNotFound = 122,
 }
@@ -1,6 +1,10 @@
-use soroban_sdk::{Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol};
 
+use crate::disputes::Dispute;
+use crate::pair_config::PairConfig;
+use crate::price_requests::PriceRequest;
 use crate::staleness::OracleStatus;
+use stellar_swipe_common::{Asset, AssetPair};
 
 pub fn emit_oracle_removed(env: &Env, oracle: Address, reason: &str) {
     env.events().publish(
@@ -29,15 +33,108 @@ pub fn emit_oracle_slashed(env: &Env, oracle: Address, reason: &str, penalty: u3
     );
 }
 
-pub fn emit_price_submitted(env: &Env, oracle: Address, price: i128) {
+/// `config` is `None` when the pair returns to the defaults.
+/// `remaining` is the bond left after the slash.
+pub fn emit_bond_slashed(env: &Env, oracle: Address, slashed: i128, remaining: i128) {
     env.events()
-        .publish((Symbol::new(env, "oracle_price_submitted"),), (oracle, price));
+        .publish((Symbol::new(env, "oracle_bond_slashed"),), (oracle, slashed, remaining));
 }
 
-pub fn emit_consensus_reached(env: &Env, price: i128, num_oracles: u32) {
+pub fn emit_bond_posted(env: &Env, oracle: Address, amount: i128, total: i128) {
+    env.events()
+        .publish((Symbol::new(env, "oracle_bond_posted"),), (oracle, amount, total));
+}
+
+pub fn emit_bond_claimed(env: &Env, oracle: Address, amount: i128) {
+    env.events()
+        .publish((Symbol::new(env, "oracle_bond_claimed"),), (oracle, amount));
+}
+
+pub fn emit_round_disputed(env: &Env, dispute: &Dispute) {
+    env.events().publish(
+        (Symbol::new(env, "round_disputed"), dispute.pair.clone()),
+        (dispute.round, dispute.disputer.clone(), dispute.bond),
+    );
+}
+
+pub fn emit_dispute_resolved(env: &Env, dispute: &Dispute) {
+    env.events().publish(
+        (Symbol::new(env, "dispute_resolved"), dispute.pair.clone()),
+        (dispute.round, dispute.status.clone()),
+    );
+}
+
+pub fn emit_signing_key_set(env: &Env, oracle: Address, key: BytesN<32>) {
+    env.events()
+        .publish((Symbol::new(env, "oracle_signing_key_set"),), (oracle, key));
+}
+
+pub fn emit_pair_config_updated(env: &Env, pair: AssetPair, config: Option<PairConfig>) {
+    env.events()
+        .publish((Symbol::new(env, "pair_config_updated"), pair), config);
+}
+
+pub fn emit_asset_decimals_updated(env: &Env, asset: Asset, decimals: u32) {
+    env.events()
+        .publish((Symbol::new(env, "asset_decimals_updated"), asset), decimals);
+}
+
+pub fn emit_price_submitted(env: &Env, oracle: Address, pair: AssetPair, round: u64, price: i128) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_price_submitted"), pair),
+        (oracle, round, price),
+    );
+}
+
+pub fn emit_price_rejected(
+    env: &Env,
+    oracle: Address,
+    pair: AssetPair,
+    round: u64,
+    price: i128,
+    deviation_bps: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_price_rejected"), pair),
+        (oracle, round, price, deviation_bps),
+    );
+}
+
+pub fn emit_consensus_reached(
+    env: &Env,
+    pair: AssetPair,
+    round: u64,
+    price: i128,
+    num_oracles: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "oracle_consensus_reached"), pair),
+        (round, price, num_oracles),
+    );
+}
+
+pub fn emit_price_requested(env: &Env, request: &PriceRequest) {
+    env.events().publish(
+        (Symbol::new(env, "price_requested"), request.pair.clone()),
+        (
+            request.id,
+            request.requester.clone(),
+            request.callback_contract.clone(),
+            request.round,
+        ),
+    );
+}
+
+/// `delivered` is false when the consumer's callback failed.
+pub fn emit_price_request_fulfilled(
+    env: &Env,
+    request: &PriceRequest,
+    round: u64,
+    delivered: bool,
+) {
     env.events().publish(
-        (Symbol::new(env, "oracle_consensus_reached"),),
-        (price, num_oracles),
+        (Symbol::new(env, "price_request_fulfilled"), request.pair.clone()),
+        (request.id, request.callback_contract.clone(), round, delivered),
     );
 }
 
@@ -52,3 +149,13 @@ pub fn emit_oracle_heartbeat_missed(
         (status, last_update_ledger, ledgers_since_update),
     );
 }
+
+pub fn emit_guardian_set(env: &Env, guardian: Address) {
+    env.events()
+        .publish((Symbol::new(env, "guardian_set"),), guardian);
+}
+
+pub fn emit_guardian_revoked(env: &Env, guardian: Address) {
+    env.events()
+        .publish((Symbol::new(env, "guardian_revoked"),), guardian);
+}
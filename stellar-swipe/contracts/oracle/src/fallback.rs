@@ -0,0 +1,81 @@
+//! Prioritised price sources per pair.
+//!
+//! Each pair has an ordered list of sources `get_price` tries in turn: this
+//! contract's own oracle rounds and any number of external SEP-40 feeds
+//! (Reflector, or a backup feed contract). A source whose price is stale or
+//! paused is skipped in favour of the next one. Pairs without a configured
+//! list use the internal rounds only.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+use stellar_swipe_common::{rescale, AssetPair, DEFAULT_ROUNDING_MODE};
+
+use crate::errors::OracleError;
+use crate::pair_config::PairConfig;
+use crate::sep40::{Sep40Asset, Sep40PriceData, SEP40_DECIMALS};
+use crate::types::PriceSource;
+
+/// Most sources a pair may list.
+pub const MAX_PRICE_SOURCES: u32 = 5;
+
+#[contracttype]
+pub enum FallbackKey {
+    Sources(AssetPair),
+}
+
+/// The subset of SEP-40 used to read an external feed.
+#[contractclient(name = "Sep40FeedClient")]
+pub trait Sep40Feed {
+    fn decimals(env: Env) -> u32;
+    fn lastprice(env: Env, asset: Sep40Asset) -> Option<Sep40PriceData>;
+}
+
+pub fn get_sources(env: &Env, pair: &AssetPair) -> Vec<PriceSource> {
+    env.storage()
+        .persistent()
+        .get(&FallbackKey::Sources(pair.clone()))
+        .unwrap_or_else(|| Vec::from_array(env, [PriceSource::Internal]))
+}
+
+pub fn set_sources(
+    env: &Env,
+    pair: &AssetPair,
+    sources: &Vec<PriceSource>,
+) -> Result<(), OracleError> {
+    if sources.is_empty() || sources.len() > MAX_PRICE_SOURCES {
+        return Err(OracleError::InvalidPath);
+    }
+    for i in 0..sources.len() {
+        for j in (i + 1)..sources.len() {
+            if sources.get(i) == sources.get(j) {
+                return Err(OracleError::InvalidAsset);
+            }
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&FallbackKey::Sources(pair.clone()), sources);
+    Ok(())
+}
+
+/// Latest price from an external SEP-40 feed, rescaled to `SEP40_DECIMALS`,
+/// and whether it is fresh. External prices are judged by the pair's
+/// staleness thresholds: up to `aging_secs` old they are fresh, past
+/// `stale_secs` they are unusable. `None` if the feed has no usable price or
+/// the call fails.
+pub fn external_price(
+    env: &Env,
+    feed: &Address,
+    asset: &Sep40Asset,
+    config: &PairConfig,
+) -> Option<(i128, u64, bool)> {
+    let client = Sep40FeedClient::new(env, feed);
+    let data = client.try_lastprice(asset).ok()?.ok()??;
+    let decimals = client.try_decimals().ok()?.ok()?;
+
+    let age = env.ledger().timestamp().saturating_sub(data.timestamp);
+    if data.price <= 0 || age > config.stale_secs {
+        return None;
+    }
+    let price = rescale(data.price, decimals, SEP40_DECIMALS, DEFAULT_ROUNDING_MODE)?;
+    Some((price, data.timestamp, age <= config.aging_secs))
+}
@@ -1,12 +1,33 @@
-//! Historical price storage and TWAP calculation
+//! Historical price storage, TWAP, VWAP, EMA and volatility calculation
 
 use crate::errors::OracleError;
+use crate::storage::pair_id;
 use stellar_swipe_common::AssetPair;
-use soroban_sdk::Env;
+use soroban_sdk::{contracttype, BytesN, Env, Vec};
 
-const BUCKET_SIZE: u64 = 300; // 5 minutes
+pub const BUCKET_SIZE: u64 = 300; // 5 minutes
 const MAX_BUCKETS: u64 = 2016; // 7 days at 5-min intervals
 const DAY_IN_LEDGERS: u32 = 17280; // ~24 hours
+/// Published prices kept per pair for history and time-weighted queries.
+pub const MAX_PRICE_OBSERVATIONS: u32 = 256;
+/// Page size of `get_price_history` when the caller passes no limit.
+pub const DEFAULT_PRICE_HISTORY_LIMIT: u32 = 20;
+
+#[contracttype]
+pub enum PriceHistoryKey {
+    /// Keyed by [`pair_id`].
+    Observations(BytesN<32>),
+}
+
+/// A published price of a pair and when it took effect.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceObservation {
+    pub price: i128,
+    pub timestamp: u64,
+    /// Volume reported by the oracles behind the price; 0 if none was.
+    pub volume: i128,
+}
 
 /// Store price snapshot at 5-minute intervals
 pub fn store_price(env: &Env, pair: &AssetPair, price: i128) {
@@ -30,6 +51,11 @@ pub fn get_historical_price(env: &Env, pair: &AssetPair, timestamp: u64) -> Opti
     }
 
     let bucket = timestamp / BUCKET_SIZE;
+    // Pruning only removes the bucket that falls out of the window on each
+    // write, so after a gap in updates older buckets can linger.
+    if current_time / BUCKET_SIZE - bucket > MAX_BUCKETS {
+        return None;
+    }
     let key = (pair.clone(), bucket);
     env.storage().persistent().get(&key)
 }
@@ -64,6 +90,255 @@ pub fn calculate_twap(
     Ok(sum / count as i128)
 }
 
+/// Price observations for `pair`, oldest first.
+pub fn get_observations(env: &Env, pair: &AssetPair) -> Vec<PriceObservation> {
+    env.storage()
+        .persistent()
+        .get(&PriceHistoryKey::Observations(pair_id(env, pair)))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append a published price, dropping the oldest once the buffer is full.
+pub fn record_observation(env: &Env, pair: &AssetPair, price: i128, volume: i128) {
+    let mut observations = get_observations(env, pair);
+    if observations.len() >= MAX_PRICE_OBSERVATIONS {
+        observations.pop_front();
+    }
+    observations.push_back(PriceObservation {
+        price,
+        timestamp: env.ledger().timestamp(),
+        volume,
+    });
+
+    let key = PriceHistoryKey::Observations(pair_id(env, pair));
+    env.storage().persistent().set(&key, &observations);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, DAY_IN_LEDGERS * 7, DAY_IN_LEDGERS * 7);
+}
+
+/// Time-weighted average of published prices over the last
+/// `window_seconds`. Each price is weighted by how long it stood before the
+/// next update replaced it; the price in effect at the window start counts
+/// from the start. If history is shorter than the window, only the covered
+/// span is averaged.
+pub fn get_twap(env: &Env, pair: &AssetPair, window_seconds: u64) -> Result<i128, OracleError> {
+    if window_seconds == 0 {
+        return Err(OracleError::InvalidTwapWindow);
+    }
+
+    let observations = get_observations(env, pair);
+    let latest = observations
+        .last()
+        .ok_or(OracleError::InsufficientHistoricalData)?;
+
+    let now = env.ledger().timestamp();
+    let start = now.saturating_sub(window_seconds);
+
+    let mut weighted_sum: i128 = 0;
+    let mut covered: u64 = 0;
+    for i in 0..observations.len() {
+        let observation = observations.get(i).unwrap();
+        let until = match observations.get(i + 1) {
+            Some(next) => next.timestamp.min(now),
+            None => now,
+        };
+        let from = observation.timestamp.max(start);
+        if until <= from {
+            continue;
+        }
+        let duration = until - from;
+        weighted_sum = observation
+            .price
+            .checked_mul(duration as i128)
+            .and_then(|weighted| weighted_sum.checked_add(weighted))
+            .ok_or(OracleError::Overflow)?;
+        covered += duration;
+    }
+
+    if covered == 0 {
+        // Only a price published in this very ledger: it is the whole window.
+        return Ok(latest.price);
+    }
+    Ok(weighted_sum / covered as i128)
+}
+
+/// Volume-weighted average of the prices published in the last
+/// `window_seconds`. Each price is weighted by the volume reported with it;
+/// prices published without volume don't count.
+pub fn get_vwap(env: &Env, pair: &AssetPair, window_seconds: u64) -> Result<i128, OracleError> {
+    if window_seconds == 0 {
+        return Err(OracleError::InvalidTwapWindow);
+    }
+
+    let start = env.ledger().timestamp().saturating_sub(window_seconds);
+    let mut notional: i128 = 0;
+    let mut volume: i128 = 0;
+    for observation in get_observations(env, pair).iter().rev() {
+        if observation.timestamp < start {
+            break;
+        }
+        if observation.volume == 0 {
+            continue;
+        }
+        notional = observation
+            .price
+            .checked_mul(observation.volume)
+            .and_then(|value| notional.checked_add(value))
+            .ok_or(OracleError::Overflow)?;
+        volume = volume
+            .checked_add(observation.volume)
+            .ok_or(OracleError::Overflow)?;
+    }
+
+    if volume == 0 {
+        return Err(OracleError::InsufficientHistoricalData);
+    }
+    Ok(notional / volume)
+}
+
+/// Exponential moving average of the last published prices, with smoothing
+/// factor `2 / (period + 1)`. The average is seeded with the simple mean of
+/// the oldest `period` retained observations and then updated with each
+/// newer one, so it needs at least `period` observations.
+pub fn get_ema(env: &Env, pair: &AssetPair, period: u32) -> Result<i128, OracleError> {
+    if period == 0 || period > MAX_PRICE_OBSERVATIONS {
+        return Err(OracleError::InvalidEmaPeriod);
+    }
+
+    let observations = get_observations(env, pair);
+    if observations.len() < period {
+        return Err(OracleError::InsufficientHistoricalData);
+    }
+
+    let mut sum: i128 = 0;
+    for observation in observations.iter().take(period as usize) {
+        sum = sum
+            .checked_add(observation.price)
+            .ok_or(OracleError::Overflow)?;
+    }
+    let mut ema = sum / period as i128;
+
+    let divisor = period as i128 + 1;
+    for observation in observations.iter().skip(period as usize) {
+        let delta = (observation.price - ema)
+            .checked_mul(2)
+            .ok_or(OracleError::Overflow)?;
+        ema += delta / divisor;
+    }
+    Ok(ema)
+}
+
+/// Change in BPS from the price in effect `window_seconds` ago to the
+/// latest one.
+pub fn get_price_change_bps(
+    env: &Env,
+    pair: &AssetPair,
+    window_seconds: u64,
+) -> Result<i128, OracleError> {
+    if window_seconds == 0 {
+        return Err(OracleError::InvalidTwapWindow);
+    }
+    let latest = get_observations(env, pair)
+        .last()
+        .ok_or(OracleError::InsufficientHistoricalData)?;
+    let start = env.ledger().timestamp().saturating_sub(window_seconds);
+    let base = observation_at(env, pair, start).ok_or(OracleError::InsufficientHistoricalData)?;
+
+    (latest.price - base.price)
+        .checked_mul(10_000)
+        .map(|change| change / base.price)
+        .ok_or(OracleError::Overflow)
+}
+
+/// Standard deviation, in BPS, of the returns between consecutive prices
+/// over the last `window_seconds`, starting from the price in effect at the
+/// window start. Not annualized: it is the volatility per price update.
+pub fn get_realized_volatility(
+    env: &Env,
+    pair: &AssetPair,
+    window_seconds: u64,
+) -> Result<i128, OracleError> {
+    if window_seconds == 0 {
+        return Err(OracleError::InvalidTwapWindow);
+    }
+    let start = env.ledger().timestamp().saturating_sub(window_seconds);
+
+    let mut returns: Vec<i128> = Vec::new(env);
+    let mut previous: Option<i128> = None;
+    for observation in get_observations(env, pair).iter() {
+        if observation.timestamp <= start {
+            // Only the price in effect at the window start is kept.
+            previous = Some(observation.price);
+            continue;
+        }
+        if let Some(previous) = previous {
+            let change = (observation.price - previous)
+                .checked_mul(10_000)
+                .ok_or(OracleError::Overflow)?;
+            returns.push_back(change / previous);
+        }
+        previous = Some(observation.price);
+    }
+    if returns.len() < 2 {
+        return Err(OracleError::InsufficientHistoricalData);
+    }
+
+    let count = returns.len() as i128;
+    let mean = returns.iter().sum::<i128>() / count;
+    let mut variance: i128 = 0;
+    for r in returns.iter() {
+        variance = (r - mean)
+            .checked_mul(r - mean)
+            .and_then(|square| variance.checked_add(square))
+            .ok_or(OracleError::Overflow)?;
+    }
+    Ok(isqrt(variance / count))
+}
+
+fn isqrt(n: i128) -> i128 {
+    if n <= 0 {
+        return 0;
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// The observation in effect at `timestamp`: the latest one published at or
+/// before it. `None` for future timestamps or ones older than the retained
+/// history.
+pub fn observation_at(env: &Env, pair: &AssetPair, timestamp: u64) -> Option<PriceObservation> {
+    if timestamp > env.ledger().timestamp() {
+        return None;
+    }
+    get_observations(env, pair)
+        .iter()
+        .rev()
+        .find(|observation| observation.timestamp <= timestamp)
+}
+
+/// The `limit` most recent observations for `pair`, newest first. A `limit`
+/// of 0 returns `DEFAULT_PRICE_HISTORY_LIMIT` entries.
+pub fn get_price_history(env: &Env, pair: &AssetPair, limit: u32) -> Vec<PriceObservation> {
+    let observations = get_observations(env, pair);
+    let limit = if limit == 0 {
+        DEFAULT_PRICE_HISTORY_LIMIT
+    } else {
+        limit
+    };
+
+    let mut history = Vec::new(env);
+    for observation in observations.iter().rev().take(limit as usize) {
+        history.push_back(observation);
+    }
+    history
+}
+
 /// Prune data older than 7 days (circular buffer)
 fn prune_old_data(env: &Env, pair: &AssetPair, current_bucket: u64) {
     if current_bucket <= MAX_BUCKETS {
@@ -94,7 +369,7 @@ pub fn get_twap_deviation(
 mod tests {
     use super::*;
     use stellar_swipe_common::Asset;
-    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String};
+    use soroban_sdk::{contract, testutils::{Address as _, Ledger}, Address, Env, String};
 
     fn test_pair(env: &Env) -> AssetPair {
         AssetPair {
@@ -112,228 +387,493 @@ mod tests {
     #[test]
     fn test_store_and_retrieve() {
         let env = Env::default();
-        let pair = test_pair(&env);
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            let pair = test_pair(&env);
 
-        store_price(&env, &pair, 10_000_000);
-        let price = get_historical_price(&env, &pair, env.ledger().timestamp());
-        assert_eq!(price, Some(10_000_000));
+            store_price(&env, &pair, 10_000_000);
+            let price = get_historical_price(&env, &pair, env.ledger().timestamp());
+            assert_eq!(price, Some(10_000_000));
+        });
     }
 
     #[test]
     fn test_twap_calculation() {
         let env = Env::default();
-        env.ledger().with_mut(|li| li.timestamp = 1000);
-        let pair = test_pair(&env);
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 1000);
+            let pair = test_pair(&env);
+
+            store_price(&env, &pair, 10_000_000);
+
+            env.ledger().with_mut(|li| li.timestamp = 1300);
+            store_price(&env, &pair, 11_000_000);
 
-        store_price(&env, &pair, 10_000_000);
+            env.ledger().with_mut(|li| li.timestamp = 1600);
+            store_price(&env, &pair, 12_000_000);
 
-        env.ledger().with_mut(|li| li.timestamp = 1300);
-        store_price(&env, &pair, 11_000_000);
+            let twap = calculate_twap(&env, &pair, 1000).unwrap();
+            assert_eq!(twap, 11_000_000);
+        });
+    }
 
-        env.ledger().with_mut(|li| li.timestamp = 1600);
-        store_price(&env, &pair, 12_000_000);
+    #[contract]
+    struct PriceHistoryContract;
 
-        let twap = calculate_twap(&env, &pair, 1000).unwrap();
-        assert_eq!(twap, 11_000_000);
+    fn native_pair(env: &Env, base: &str) -> AssetPair {
+        AssetPair {
+            base: Asset {
+                code: String::from_str(env, base),
+                issuer: None,
+            },
+            quote: Asset {
+                code: String::from_str(env, "XLM"),
+                issuer: None,
+            },
+        }
     }
 
     #[test]
-    fn test_insufficient_data() {
+    fn test_twap_is_time_weighted() {
         let env = Env::default();
-        let pair = test_pair(&env);
+        let pair = native_pair(&env, "USDC");
+        let contract_id = env.register(PriceHistoryContract, ());
+
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 1000);
+            record_observation(&env, &pair, 10_000_000, 0);
+            // A brief spike only moves the TWAP in proportion to its duration.
+            env.ledger().with_mut(|li| li.timestamp = 1900);
+            record_observation(&env, &pair, 20_000_000, 0);
+            env.ledger().with_mut(|li| li.timestamp = 2000);
+            record_observation(&env, &pair, 10_000_000, 0);
+            assert_eq!(get_twap(&env, &pair, 1000).unwrap(), 11_000_000);
+
+            // The window start falls inside the first observation's span.
+            env.ledger().with_mut(|li| li.timestamp = 2400);
+            assert_eq!(get_twap(&env, &pair, 1000).unwrap(), 11_000_000);
+            // Past the spike only the latest price remains.
+            assert_eq!(get_twap(&env, &pair, 400).unwrap(), 10_000_000);
+            // Windows longer than the history average the covered span.
+            assert_eq!(get_twap(&env, &pair, 100_000).unwrap(), 10_714_285);
+
+            assert_eq!(get_twap(&env, &pair, 0), Err(OracleError::InvalidTwapWindow));
+            assert_eq!(
+                get_twap(&env, &native_pair(&env, "BTC"), 1000),
+                Err(OracleError::InsufficientHistoricalData)
+            );
+        });
+    }
 
-        let result = calculate_twap(&env, &pair, 3600);
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), OracleError::InsufficientHistoricalData);
+    #[test]
+    fn test_ema_weights_recent_prices() {
+        let env = Env::default();
+        let pair = native_pair(&env, "USDC");
+        let contract_id = env.register(PriceHistoryContract, ());
+
+        env.as_contract(&contract_id, || {
+            for (i, price) in [10, 11, 12, 13, 14].iter().enumerate() {
+                env.ledger().with_mut(|li| li.timestamp = 1000 + i as u64 * 60);
+                record_observation(&env, &pair, price * 1_000_000, 0);
+            }
+
+            // Seeded at the mean of 10, 11, 12, then halfway to 13 and 14.
+            assert_eq!(get_ema(&env, &pair, 3).unwrap(), 13_000_000);
+            // A period covering the whole history is the simple mean.
+            assert_eq!(get_ema(&env, &pair, 5).unwrap(), 12_000_000);
+
+            assert_eq!(get_ema(&env, &pair, 0), Err(OracleError::InvalidEmaPeriod));
+            assert_eq!(
+                get_ema(&env, &pair, 6),
+                Err(OracleError::InsufficientHistoricalData)
+            );
+        });
     }
 
     #[test]
-    fn test_deviation_calculation() {
+    fn test_vwap_weights_by_volume() {
         let env = Env::default();
-        env.ledger().with_mut(|li| li.timestamp = 1000);
-        let pair = test_pair(&env);
+        let pair = native_pair(&env, "USDC");
+        let contract_id = env.register(PriceHistoryContract, ());
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                get_vwap(&env, &pair, 1000),
+                Err(OracleError::InsufficientHistoricalData)
+            );
+
+            env.ledger().with_mut(|li| li.timestamp = 1000);
+            record_observation(&env, &pair, 10_000_000, 300);
+            env.ledger().with_mut(|li| li.timestamp = 1600);
+            record_observation(&env, &pair, 12_000_000, 100);
+            // Prices without volume are left out.
+            env.ledger().with_mut(|li| li.timestamp = 1900);
+            record_observation(&env, &pair, 50_000_000, 0);
+
+            assert_eq!(get_vwap(&env, &pair, 1000).unwrap(), 10_500_000);
+            // The first print falls out of a shorter window.
+            assert_eq!(get_vwap(&env, &pair, 500).unwrap(), 12_000_000);
+            assert_eq!(get_vwap(&env, &pair, 0), Err(OracleError::InvalidTwapWindow));
+        });
+    }
 
-        store_price(&env, &pair, 10_000_000);
-        env.ledger().with_mut(|li| li.timestamp = 1300);
-        store_price(&env, &pair, 10_000_000);
+    #[test]
+    fn test_price_change_and_volatility() {
+        let env = Env::default();
+        let pair = native_pair(&env, "USDC");
+        let contract_id = env.register(PriceHistoryContract, ());
+
+        env.as_contract(&contract_id, || {
+            // Returns of +10%, -10%, +10%, -10% after the first price.
+            let prices = [10_000_000, 11_000_000, 9_900_000, 10_890_000, 9_801_000];
+            for (i, price) in prices.iter().enumerate() {
+                env.ledger().with_mut(|li| li.timestamp = 1000 + i as u64 * 60);
+                record_observation(&env, &pair, *price, 0);
+            }
+
+            assert_eq!(get_price_change_bps(&env, &pair, 240).unwrap(), -199);
+            assert_eq!(get_price_change_bps(&env, &pair, 60).unwrap(), -1_000);
+            assert_eq!(get_realized_volatility(&env, &pair, 240).unwrap(), 1_000);
+            assert_eq!(
+                get_realized_volatility(&env, &pair, 60),
+                Err(OracleError::InsufficientHistoricalData)
+            );
+            assert_eq!(
+                get_price_change_bps(&env, &pair, 10_000),
+                Err(OracleError::InsufficientHistoricalData)
+            );
+        });
+    }
 
-        let deviation = get_twap_deviation(&env, &pair, 11_000_000, 600).unwrap();
-        assert_eq!(deviation, 1000); // 10% deviation
+    #[test]
+    fn test_price_history_is_bounded() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let pair = native_pair(&env, "USDC");
+        let contract_id = env.register(PriceHistoryContract, ());
+
+        env.as_contract(&contract_id, || {
+            for i in 0..(MAX_PRICE_OBSERVATIONS as u64 + 10) {
+                env.cost_estimate().budget().reset_unlimited();
+                env.ledger().with_mut(|li| li.timestamp = 1000 + i * 60);
+                record_observation(&env, &pair, 1_000 + i as i128, 0);
+            }
+
+            let observations = get_observations(&env, &pair);
+            assert_eq!(observations.len(), MAX_PRICE_OBSERVATIONS);
+            assert_eq!(observations.first().unwrap().price, 1_010);
+            assert_eq!(
+                observations.last().unwrap().price,
+                1_000 + MAX_PRICE_OBSERVATIONS as i128 + 9
+            );
+        });
     }
 
     #[test]
-    fn test_twap_1h_window() {
+    fn test_price_history_newest_first() {
         let env = Env::default();
-        env.ledger().with_mut(|li| li.timestamp = 0);
-        let pair = test_pair(&env);
+        let pair = native_pair(&env, "USDC");
+        let contract_id = env.register(PriceHistoryContract, ());
+
+        env.as_contract(&contract_id, || {
+            assert!(get_price_history(&env, &pair, 5).is_empty());
+
+            for i in 0..30u64 {
+                env.ledger().with_mut(|li| li.timestamp = 1000 + i * 60);
+                record_observation(&env, &pair, 100 + i as i128, 0);
+            }
+
+            let history = get_price_history(&env, &pair, 3);
+            assert_eq!(history.len(), 3);
+            assert_eq!(
+                history.get(0).unwrap(),
+                PriceObservation {
+                    price: 129,
+                    timestamp: 1000 + 29 * 60,
+                    volume: 0,
+                }
+            );
+            assert_eq!(history.get(2).unwrap().price, 127);
+
+            assert_eq!(
+                get_price_history(&env, &pair, 0).len(),
+                DEFAULT_PRICE_HISTORY_LIMIT
+            );
+            assert_eq!(get_price_history(&env, &pair, 1000).len(), 30);
+        });
+    }
 
-        // Store prices every 5 minutes for 1 hour
-        for i in 0..13 {
-            env.ledger().with_mut(|li| li.timestamp = i * 300);
-            store_price(&env, &pair, 10_000_000 + (i as i128 * 100_000));
-        }
+    #[test]
+    fn test_observations_keyed_by_pair_id() {
+        let env = Env::default();
+        let issued = |issuer: Address| AssetPair {
+            base: Asset {
+                code: String::from_str(&env, "LONGASSETCOD"),
+                issuer: Some(issuer),
+            },
+            quote: Asset {
+                code: String::from_str(&env, "XLM"),
+                issuer: None,
+            },
+        };
+        // Same codes, different issuers: distinct pairs.
+        let first = issued(Address::generate(&env));
+        let second = issued(Address::generate(&env));
+        let contract_id = env.register(PriceHistoryContract, ());
+
+        env.as_contract(&contract_id, || {
+            record_observation(&env, &first, 100, 0);
+            record_observation(&env, &second, 200, 0);
+            record_observation(&env, &second, 201, 0);
+
+            assert_eq!(get_observations(&env, &first).len(), 1);
+            assert_eq!(get_observations(&env, &second).len(), 2);
+            assert_ne!(pair_id(&env, &first), pair_id(&env, &second));
+            assert!(env
+                .storage()
+                .persistent()
+                .has(&PriceHistoryKey::Observations(pair_id(&env, &first))));
+        });
+    }
 
-        env.ledger().with_mut(|li| li.timestamp = 3600);
-        let twap = calculate_twap(&env, &pair, 3600).unwrap();
-        assert!(twap >= 10_000_000 && twap <= 11_200_000);
+    #[test]
+    fn test_insufficient_data() {
+        let env = Env::default();
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            let pair = test_pair(&env);
+
+            let result = calculate_twap(&env, &pair, 3600);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), OracleError::InsufficientHistoricalData);
+        });
     }
 
     #[test]
-    fn test_twap_24h_window() {
+    fn test_deviation_calculation() {
         let env = Env::default();
-        env.ledger().with_mut(|li| li.timestamp = 0);
-        let pair = test_pair(&env);
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 1000);
+            let pair = test_pair(&env);
 
-        // Store prices every hour for 24 hours
-        for i in 0..25 {
-            env.ledger().with_mut(|li| li.timestamp = i * 3600);
             store_price(&env, &pair, 10_000_000);
-        }
+            env.ledger().with_mut(|li| li.timestamp = 1300);
+            store_price(&env, &pair, 10_000_000);
 
-        env.ledger().with_mut(|li| li.timestamp = 86400);
-        let twap = calculate_twap(&env, &pair, 86400).unwrap();
-        assert_eq!(twap, 10_000_000);
+            let deviation = get_twap_deviation(&env, &pair, 11_000_000, 600).unwrap();
+            assert_eq!(deviation, 1000); // 10% deviation
+        });
     }
 
     #[test]
-    fn test_twap_7d_window() {
+    fn test_twap_1h_window() {
         let env = Env::default();
-        env.ledger().with_mut(|li| li.timestamp = 0);
-        let pair = test_pair(&env);
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 0);
+            let pair = test_pair(&env);
+
+            // Store prices every 5 minutes for 1 hour
+            for i in 0..13 {
+                env.ledger().with_mut(|li| li.timestamp = i * 300);
+                store_price(&env, &pair, 10_000_000 + (i as i128 * 100_000));
+            }
+
+            env.ledger().with_mut(|li| li.timestamp = 3600);
+            let twap = calculate_twap(&env, &pair, 3600).unwrap();
+            assert!(twap >= 10_000_000 && twap <= 11_200_000);
+        });
+    }
 
-        // Store prices every 12 hours for 7 days
-        for i in 0..15 {
-            env.ledger().with_mut(|li| li.timestamp = i * 43200);
-            store_price(&env, &pair, 10_000_000 + (i as i128 * 50_000));
-        }
+    #[test]
+    fn test_twap_24h_window() {
+        let env = Env::default();
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 0);
+            let pair = test_pair(&env);
+
+            // Store prices every hour for 24 hours
+            for i in 0..25 {
+                env.ledger().with_mut(|li| li.timestamp = i * 3600);
+                store_price(&env, &pair, 10_000_000);
+            }
+
+            env.ledger().with_mut(|li| li.timestamp = 86400);
+            let twap = calculate_twap(&env, &pair, 86400).unwrap();
+            assert_eq!(twap, 10_000_000);
+        });
+    }
 
-        env.ledger().with_mut(|li| li.timestamp = 604800);
-        let twap = calculate_twap(&env, &pair, 604800).unwrap();
-        assert!(twap >= 10_000_000 && twap <= 10_700_000);
+    #[test]
+    fn test_twap_7d_window() {
+        let env = Env::default();
+        // A 7-day window scans all 2016 buckets.
+        env.cost_estimate().budget().reset_unlimited();
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 0);
+            let pair = test_pair(&env);
+
+            // Store prices every 12 hours for 7 days
+            for i in 0..15 {
+                env.ledger().with_mut(|li| li.timestamp = i * 43200);
+                store_price(&env, &pair, 10_000_000 + (i as i128 * 50_000));
+            }
+
+            env.ledger().with_mut(|li| li.timestamp = 604800);
+            let twap = calculate_twap(&env, &pair, 604800).unwrap();
+            assert!(twap >= 10_000_000 && twap <= 10_700_000);
+        });
     }
 
     #[test]
     fn test_future_timestamp_rejected() {
         let env = Env::default();
-        env.ledger().with_mut(|li| li.timestamp = 1000);
-        let pair = test_pair(&env);
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 1000);
+            let pair = test_pair(&env);
 
-        store_price(&env, &pair, 10_000_000);
+            store_price(&env, &pair, 10_000_000);
 
-        // Try to query future timestamp
-        let result = get_historical_price(&env, &pair, 2000);
-        assert_eq!(result, None);
+            // Try to query future timestamp
+            let result = get_historical_price(&env, &pair, 2000);
+            assert_eq!(result, None);
+        });
     }
 
     #[test]
     fn test_data_pruning_after_7_days() {
         let env = Env::default();
-        let pair = test_pair(&env);
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            let pair = test_pair(&env);
 
-        // Store price at day 0
-        env.ledger().with_mut(|li| li.timestamp = 0);
-        store_price(&env, &pair, 10_000_000);
+            // Store price at day 0
+            env.ledger().with_mut(|li| li.timestamp = 0);
+            store_price(&env, &pair, 10_000_000);
 
-        // Move to day 8 and store new price (should trigger pruning)
-        env.ledger().with_mut(|li| li.timestamp = 8 * 86400);
-        store_price(&env, &pair, 11_000_000);
+            // Move to day 8 and store new price (should trigger pruning)
+            env.ledger().with_mut(|li| li.timestamp = 8 * 86400);
+            store_price(&env, &pair, 11_000_000);
 
-        // Old data should be pruned
-        let old_price = get_historical_price(&env, &pair, 0);
-        assert_eq!(old_price, None);
+            // Old data should be pruned
+            let old_price = get_historical_price(&env, &pair, 0);
+            assert_eq!(old_price, None);
 
-        // New data should exist
-        let new_price = get_historical_price(&env, &pair, 8 * 86400);
-        assert_eq!(new_price, Some(11_000_000));
+            // New data should exist
+            let new_price = get_historical_price(&env, &pair, 8 * 86400);
+            assert_eq!(new_price, Some(11_000_000));
+        });
     }
 
     #[test]
     fn test_missing_data_points_in_window() {
         let env = Env::default();
-        env.ledger().with_mut(|li| li.timestamp = 0);
-        let pair = test_pair(&env);
-
-        // Store sparse data points
-        store_price(&env, &pair, 10_000_000);
-        env.ledger().with_mut(|li| li.timestamp = 1800); // 30 min gap
-        store_price(&env, &pair, 12_000_000);
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 0);
+            let pair = test_pair(&env);
 
-        env.ledger().with_mut(|li| li.timestamp = 3600);
-        let twap = calculate_twap(&env, &pair, 3600).unwrap();
-        // Should average only available data points
-        assert_eq!(twap, 11_000_000);
+            // Store sparse data points
+            store_price(&env, &pair, 10_000_000);
+            env.ledger().with_mut(|li| li.timestamp = 1800); // 30 min gap
+            store_price(&env, &pair, 12_000_000);
+
+            env.ledger().with_mut(|li| li.timestamp = 3600);
+            let twap = calculate_twap(&env, &pair, 3600).unwrap();
+            // Should average only available data points
+            assert_eq!(twap, 11_000_000);
+        });
     }
 
     #[test]
     fn test_storage_overflow_handling() {
         let env = Env::default();
-        let pair = test_pair(&env);
-
-        // Store max buckets + 1
-        for i in 0..(MAX_BUCKETS + 10) {
-            env.ledger().with_mut(|li| li.timestamp = i * BUCKET_SIZE);
-            store_price(&env, &pair, 10_000_000);
-        }
-
-        // Oldest data should be pruned
-        let oldest = get_historical_price(&env, &pair, 0);
-        assert_eq!(oldest, None);
+        // Writes more than 2016 buckets.
+        env.cost_estimate().budget().reset_unlimited();
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            let pair = test_pair(&env);
+
+            // Store max buckets + 1
+            for i in 0..(MAX_BUCKETS + 10) {
+                env.ledger().with_mut(|li| li.timestamp = i * BUCKET_SIZE);
+                store_price(&env, &pair, 10_000_000);
+            }
+
+            // Oldest data should be pruned
+            let oldest = get_historical_price(&env, &pair, 0);
+            assert_eq!(oldest, None);
+        });
     }
 
     #[test]
     fn test_manipulation_detection() {
         let env = Env::default();
-        env.ledger().with_mut(|li| li.timestamp = 0);
-        let pair = test_pair(&env);
-
-        // Store stable prices
-        for i in 0..10 {
-            env.ledger().with_mut(|li| li.timestamp = i * 300);
-            store_price(&env, &pair, 10_000_000);
-        }
-
-        // Check deviation with manipulated price
-        env.ledger().with_mut(|li| li.timestamp = 3000);
-        let deviation = get_twap_deviation(&env, &pair, 11_500_000, 3000).unwrap();
-        assert!(deviation > 1000); // >10% deviation indicates manipulation
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 0);
+            let pair = test_pair(&env);
+
+            // Store stable prices
+            for i in 0..10 {
+                env.ledger().with_mut(|li| li.timestamp = i * 300);
+                store_price(&env, &pair, 10_000_000);
+            }
+
+            // Check deviation with manipulated price
+            env.ledger().with_mut(|li| li.timestamp = 3000);
+            let deviation = get_twap_deviation(&env, &pair, 11_500_000, 3000).unwrap();
+            assert!(deviation > 1000); // >10% deviation indicates manipulation
+        });
     }
 
     #[test]
     fn test_zero_price_twap_error() {
         let env = Env::default();
-        env.ledger().with_mut(|li| li.timestamp = 1000);
-        let pair = test_pair(&env);
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 1000);
+            let pair = test_pair(&env);
 
-        store_price(&env, &pair, 0);
+            store_price(&env, &pair, 0);
 
-        let result = get_twap_deviation(&env, &pair, 10_000_000, 600);
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), OracleError::InvalidPrice);
+            let result = get_twap_deviation(&env, &pair, 10_000_000, 600);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), OracleError::InvalidPrice);
+        });
     }
 
     #[test]
     fn test_multiple_pairs_isolation() {
         let env = Env::default();
-        let pair1 = test_pair(&env);
-        let pair2 = AssetPair {
-            base: Asset {
-                code: String::from_str(&env, "BTC"),
-                issuer: Some(Address::generate(&env)),
-            },
-            quote: Asset {
-                code: String::from_str(&env, "XLM"),
-                issuer: None,
-            },
-        };
-
-        env.ledger().with_mut(|li| li.timestamp = 1000);
-        store_price(&env, &pair1, 10_000_000);
-        store_price(&env, &pair2, 50_000_000);
-
-        let price1 = get_historical_price(&env, &pair1, 1000);
-        let price2 = get_historical_price(&env, &pair2, 1000);
-
-        assert_eq!(price1, Some(10_000_000));
-        assert_eq!(price2, Some(50_000_000));
+        let contract_id = env.register(PriceHistoryContract, ());
+        env.as_contract(&contract_id, || {
+            let pair1 = test_pair(&env);
+            let pair2 = AssetPair {
+                base: Asset {
+                    code: String::from_str(&env, "BTC"),
+                    issuer: Some(Address::generate(&env)),
+                },
+                quote: Asset {
+                    code: String::from_str(&env, "XLM"),
+                    issuer: None,
+                },
+            };
+
+            env.ledger().with_mut(|li| li.timestamp = 1000);
+            store_price(&env, &pair1, 10_000_000);
+            store_price(&env, &pair2, 50_000_000);
+
+            let price1 = get_historical_price(&env, &pair1, 1000);
+            let price2 = get_historical_price(&env, &pair2, 1000);
+
+            assert_eq!(price1, Some(10_000_000));
+            assert_eq!(price2, Some(50_000_000));
+        });
     }
 }
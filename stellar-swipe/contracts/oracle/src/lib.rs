@@ -1,39 +1,89 @@
 #![no_std]
 
 mod admin;
+mod bond;
 mod conversion;
+mod disputes;
 mod errors;
 mod events;
 mod external_adapter;
+mod fallback;
+mod governance;
 mod history;
 mod multi_hop;
+mod pair_config;
+mod price_requests;
 mod reputation;
 mod sdex;
+mod sep40;
+mod signed_prices;
 mod staleness;
 mod storage;
 mod types;
 
-use errors::OracleError;
+use errors::{GovernanceError, OracleError};
+use governance::OracleGovernance;
 use reputation::{
-    adjust_oracle_weight, calculate_reputation, get_oracle_stats, should_remove_oracle,
-    slash_oracle, track_oracle_accuracy, SlashReason,
+    adjust_oracle_weight, check_persistent_deviation, current_reputation, get_deviation_streak,
+    get_oracle_stats, record_rejected_submission, should_remove_oracle, slash_oracle,
+    track_oracle_accuracy, SlashReason, DEFAULT_DEVIATION_STREAK_LIMIT,
+    PERSISTENT_DEVIATION_PENALTY,
 };
-use sdex::{calculate_spot_price, OrderBook, OrderEntry};
-use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Env, Map, String, Vec};
-use staleness::{OracleHealth, OracleStatus, StalenessLevel};
+use sdex::{calculate_spot_price, OrderBook};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, Map, String, Symbol,
+    Vec,
+};
+use staleness::{FeedHealth, OracleHealth, OracleStatus, PriceMetadata, StalenessLevel};
 use stellar_swipe_common::emergency::{PauseState, CAT_ALL};
 use stellar_swipe_common::{
     health_uninitialized, placeholder_admin, Asset, AssetPair, HealthStatus,
+    LEDGERS_PER_30_DAY_MONTH,
 };
 use types::{
-    ConsensusPriceData, ExternalPrice, OracleReputation, PriceData, PriceSubmission, StorageKey,
+    ConsensusPriceData, ExternalPrice, OracleReputation, PriceData, PriceSource, PriceSubmission,
+    OracleStats, PriceWithConfidence, RoundInfo, StorageKey,
 };
 
+pub use bond::OracleBond;
 pub use conversion::{convert_to_base, ConversionPath};
-pub use history::{calculate_twap, get_historical_price, get_twap_deviation, store_price};
+pub use disputes::{Dispute, DisputeConfig, DisputeStatus};
+pub use governance::{
+    DepositRebate, DisputedRound, GovernanceStats, OracleProposal, OracleReplacement, PairListing,
+    PendingUnstake, ProposalOutcomePreview, ProposalReward, ProposalStatus, ProposalSummary,
+    ProposalType, ReputationPolicy, RetryState, VoteReceipt,
+};
+pub use history::{
+    calculate_twap, get_historical_price, get_twap, get_twap_deviation, store_price,
+    PriceObservation,
+};
 pub use multi_hop::{calculate_multi_hop_price, find_optimal_path, LiquidityPath};
+pub use pair_config::PairConfig;
+pub use price_requests::PriceRequest;
+pub use sep40::{Sep40Asset, Sep40PriceData, SEP40_DECIMALS};
+pub use signed_prices::{SignedPrice, MAX_SIGNED_BATCH};
 pub use storage::{get_base_currency, get_price, set_base_currency, set_price};
 
+/// Oldest submission timestamp accepted by `submit_price`, relative to now.
+pub const MAX_SUBMISSION_AGE_SECS: u64 = 300;
+
+/// Default number of oracle submissions that close a round.
+pub const DEFAULT_MIN_SUBMISSIONS: u32 = 3;
+
+/// Default time a round may stay open before it can be finalised with fewer
+/// than the required submissions.
+pub const DEFAULT_ROUND_DURATION_SECS: u64 = 300;
+
+/// Ledgers a finalised round's result is kept for `get_round_result`.
+const ROUND_RESULT_TTL_LEDGERS: u32 = LEDGERS_PER_30_DAY_MONTH;
+
+/// Smallest oracle set that removals and voluntary exits may leave behind.
+pub const MIN_ORACLES: u32 = 2;
+
+/// Default `p_dev`: submissions further than this from the round median (in
+/// BPS) are dropped before aggregation.
+pub const DEFAULT_MAX_DEVIATION_BPS: i128 = 1_000;
+
 #[contract]
 pub struct OracleContract;
 
@@ -78,30 +128,42 @@ impl OracleContract {
     }
 
     /// # Summary
-    /// Set price for an asset pair. Stores the price, updates history,
-    /// and triggers staleness metadata update.
+    /// Set price for an asset pair (admin only). Stores the price, updates
+    /// history, and triggers staleness metadata update.
     ///
     /// # Parameters
     /// - `env`: Soroban environment.
+    /// - `admin`: The oracle admin; must authorize the call.
     /// - `pair`: The asset pair to price.
-    /// - `price`: Price value (must be > 0).
+    /// - `price`: Price value in the pair's decimals (must be > 0).
     ///
     /// # Returns
     /// `Ok(())` on success.
     ///
     /// # Errors
+    /// - [`OracleError::Unauthorized`] — `admin` is not the oracle admin.
     /// - [`OracleError::CircuitBreakerTripped`] — oracle is paused.
     /// - [`OracleError::InvalidAsset`] — price <= 0.
-    pub fn set_price(env: Env, pair: AssetPair, price: i128) -> Result<(), OracleError> {
+    /// - [`OracleError::InvalidPrice`] — price rounds to zero at canonical precision.
+    pub fn set_price(
+        env: Env,
+        admin: Address,
+        pair: AssetPair,
+        price: i128,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
         if admin::is_paused(&env, String::from_str(&env, CAT_ALL)) {
             return Err(OracleError::CircuitBreakerTripped);
         }
         if price <= 0 {
             return Err(OracleError::InvalidAsset);
         }
+        let price = pair_config::normalize_price(&pair_config::get_config(&env, &pair), price)?;
         storage::set_price(&env, &pair, price);
         storage::add_available_pair(&env, pair.clone());
         history::store_price(&env, &pair, price);
+        history::record_observation(&env, &pair, price, 0);
         on_price_update(&env, pair);
         Ok(())
     }
@@ -134,6 +196,42 @@ impl OracleContract {
         Ok(result)
     }
 
+    /// Convert `amount` of `from_asset` into `to_asset`, routing through a
+    /// configured quote asset when the two are not priced against each other.
+    pub fn convert(
+        env: Env,
+        amount: i128,
+        from_asset: Asset,
+        to_asset: Asset,
+    ) -> Result<i128, OracleError> {
+        conversion::convert(&env, amount, from_asset, to_asset)
+    }
+
+    /// Set the intermediate quote assets used by `convert` (admin only).
+    pub fn set_quote_assets(
+        env: Env,
+        admin: Address,
+        assets: Vec<Asset>,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if assets.len() > conversion::MAX_QUOTE_ASSETS {
+            return Err(OracleError::InvalidPath);
+        }
+        for (i, asset) in assets.iter().enumerate() {
+            if assets.first_index_of(&asset) != Some(i as u32) {
+                return Err(OracleError::InvalidAsset);
+            }
+        }
+        storage::set_quote_assets(&env, &assets);
+        Ok(())
+    }
+
+    /// Quote assets `convert` may route through, in priority order.
+    pub fn get_quote_assets(env: Env) -> Vec<Asset> {
+        storage::get_quote_assets(&env)
+    }
+
     /// Get base currency
     pub fn get_base_currency(env: Env) -> Asset {
         storage::get_base_currency(&env)
@@ -196,177 +294,1336 @@ impl OracleContract {
         admin::revoke_guardian(&env, &caller)
     }
 
-    /// Get current guardian, if any.
-    pub fn get_guardian(env: Env) -> Option<Address> {
-        admin::get_guardian(&env)
+    /// Get current guardian, if any.
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        admin::get_guardian(&env)
+    }
+
+    /// Propose admin transfer (current admin only)
+    pub fn propose_admin_transfer(
+        env: Env,
+        caller: Address,
+        new_admin: Address,
+    ) -> Result<(), OracleError> {
+        admin::propose_admin_transfer(&env, &caller, new_admin)
+    }
+
+    /// Accept admin transfer (new admin only)
+    pub fn accept_admin_transfer(env: Env, caller: Address) -> Result<(), OracleError> {
+        admin::accept_admin_transfer(&env, &caller)
+    }
+
+    /// Cancel pending admin transfer (current admin only)
+    pub fn cancel_admin_transfer(env: Env, caller: Address) -> Result<(), OracleError> {
+        admin::cancel_admin_transfer(&env, &caller)
+    }
+
+    /// Calculate TWAP for 1 hour
+    pub fn get_twap_1h(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
+        history::calculate_twap(&env, &pair, 3600)
+    }
+
+    /// Calculate TWAP for 24 hours
+    pub fn get_twap_24h(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
+        history::calculate_twap(&env, &pair, 86400)
+    }
+
+    /// Calculate TWAP for 7 days
+    pub fn get_twap_7d(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
+        history::calculate_twap(&env, &pair, 604800)
+    }
+
+    /// Time-weighted average of published prices over the last
+    /// `window_seconds`, for manipulation-resistant limit and stop checks.
+    pub fn get_twap(env: Env, pair: AssetPair, window_seconds: u64) -> Result<i128, OracleError> {
+        history::get_twap(&env, &pair, window_seconds)
+    }
+
+    /// Volume-weighted average of the prices published in the last
+    /// `window_seconds`, a benchmark for judging trade executions. Only
+    /// prices published with reported volume count.
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidTwapWindow`] — `window_seconds` is 0.
+    /// - [`OracleError::InsufficientHistoricalData`] — no volume was reported
+    ///   within the window.
+    pub fn get_vwap(env: Env, pair: AssetPair, window_seconds: u64) -> Result<i128, OracleError> {
+        history::get_vwap(&env, &pair, window_seconds)
+    }
+
+    /// Change in BPS from the price in effect `window_seconds` ago to the
+    /// latest published price.
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidTwapWindow`] — `window_seconds` is 0.
+    /// - [`OracleError::InsufficientHistoricalData`] — the retained history
+    ///   doesn't reach back to the window start.
+    pub fn get_price_change_bps(
+        env: Env,
+        pair: AssetPair,
+        window_seconds: u64,
+    ) -> Result<i128, OracleError> {
+        history::get_price_change_bps(&env, &pair, window_seconds)
+    }
+
+    /// Realized volatility over the last `window_seconds`: the standard
+    /// deviation, in BPS, of the returns between consecutive published
+    /// prices. The canonical figure for volatility-aware position sizing.
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidTwapWindow`] — `window_seconds` is 0.
+    /// - [`OracleError::InsufficientHistoricalData`] — fewer than two
+    ///   returns fall within the window.
+    pub fn get_realized_volatility(
+        env: Env,
+        pair: AssetPair,
+        window_seconds: u64,
+    ) -> Result<i128, OracleError> {
+        history::get_realized_volatility(&env, &pair, window_seconds)
+    }
+
+    /// Exponential moving average over the last published prices, smoothed
+    /// with `2 / (period + 1)`: a steadier reference than the last print for
+    /// limit and trailing-stop logic.
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidEmaPeriod`] — `period` is 0 or longer than the
+    ///   retained history can hold.
+    /// - [`OracleError::InsufficientHistoricalData`] — fewer than `period`
+    ///   prices have been published.
+    pub fn get_ema(env: Env, pair: AssetPair, period: u32) -> Result<i128, OracleError> {
+        history::get_ema(&env, &pair, period)
+    }
+
+    /// The `limit` most recent published prices for `pair`, newest first
+    /// (at most `history::MAX_PRICE_OBSERVATIONS` are retained).
+    pub fn get_price_history(env: Env, pair: AssetPair, limit: u32) -> Vec<PriceObservation> {
+        history::get_price_history(&env, &pair, limit)
+    }
+
+    /// Get price deviation from TWAP
+    pub fn get_price_deviation(
+        env: Env,
+        pair: AssetPair,
+        current_price: i128,
+        window: u64,
+    ) -> Result<i128, OracleError> {
+        history::get_twap_deviation(&env, &pair, current_price, window)
+    }
+
+    /// Find optimal path between assets
+    pub fn find_optimal_path(
+        env: Env,
+        from: Asset,
+        to: Asset,
+        amount: i128,
+    ) -> Result<LiquidityPath, OracleError> {
+        multi_hop::find_optimal_path(&env, from, to, amount)
+    }
+
+    /// Calculate price via multi-hop path
+    pub fn calculate_multi_hop_price(env: Env, path: LiquidityPath, amount: i128) -> i128 {
+        multi_hop::calculate_multi_hop_price(&env, path, amount)
+    }
+
+    // ── SEP-40 price feed ────────────────────────────────────────────────────
+
+    /// Set the asset SEP-40 `base()` reports prices in (admin only).
+    pub fn set_sep40_base(env: Env, admin: Address, asset: Sep40Asset) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        sep40::set_base(&env, &asset);
+        Ok(())
+    }
+
+    /// Expose `pair` through SEP-40 as `asset` (admin only). The pair must be
+    /// quoted in the base currency.
+    pub fn register_sep40_asset(
+        env: Env,
+        admin: Address,
+        asset: Sep40Asset,
+        pair: AssetPair,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        sep40::register_asset(&env, asset, pair)
+    }
+
+    /// SEP-40: asset all prices are quoted in.
+    pub fn base(env: Env) -> Sep40Asset {
+        sep40::get_base(&env).expect("SEP-40 base asset not configured")
+    }
+
+    /// SEP-40: assets with a price feed.
+    pub fn assets(env: Env) -> Vec<Sep40Asset> {
+        sep40::assets(&env)
+    }
+
+    /// SEP-40: decimals of returned prices.
+    pub fn decimals(_env: Env) -> u32 {
+        SEP40_DECIMALS
+    }
+
+    /// SEP-40: price history resolution in seconds.
+    pub fn resolution(_env: Env) -> u32 {
+        sep40::resolution()
+    }
+
+    /// SEP-40: price of `asset` in effect at `timestamp`.
+    pub fn price(env: Env, asset: Sep40Asset, timestamp: u64) -> Option<Sep40PriceData> {
+        sep40::price(&env, &asset, timestamp)
+    }
+
+    /// SEP-40: the `records` most recent prices of `asset`, newest first.
+    pub fn prices(env: Env, asset: Sep40Asset, records: u32) -> Option<Vec<Sep40PriceData>> {
+        sep40::prices(&env, &asset, records)
+    }
+
+    /// SEP-40: most recent price of `asset`.
+    pub fn lastprice(env: Env, asset: Sep40Asset) -> Option<Sep40PriceData> {
+        sep40::lastprice(&env, &asset)
+    }
+
+    /// Register a new oracle
+    pub fn register_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut oracles = Self::read_oracles(&env);
+        if oracles.contains(&oracle) {
+            return Err(OracleError::OracleAlreadyExists);
+        }
+
+        oracles.push_back(oracle.clone());
+        env.storage()
+            .persistent()
+            .set(&StorageKey::Oracles, &oracles);
+
+        // Initialize with default reputation
+        let stats = OracleReputation {
+            total_submissions: 0,
+            accurate_submissions: 0,
+            avg_deviation: 0,
+            reputation_score: reputation::NEUTRAL_REPUTATION,
+            weight: 1,
+            last_slash: 0,
+            last_update: env.ledger().timestamp(),
+        };
+        reputation::save_oracle_stats(&env, &oracle, &stats);
+
+        Ok(())
+    }
+
+    /// # Summary
+    /// Submit an oracle's price for `pair` into the pair's open round. Each
+    /// oracle has one submission per round; resubmitting replaces it. Once
+    /// the round holds `get_min_submissions` entries (capped at the number
+    /// of active oracles), or a submission arrives after its deadline, it is
+    /// aggregated into a weighted median that `get_price` serves, and a new
+    /// round opens. `price` is quoted in the
+    /// pair's `decimals` and stored at `CANONICAL_DECIMALS`.
+    ///
+    /// # Errors
+    /// - [`OracleError::CircuitBreakerTripped`] — oracle is paused.
+    /// - [`OracleError::PairDisabled`] — the pair is disabled in its config.
+    /// - [`OracleError::InvalidPrice`] — price <= 0 (also after normalization)
+    ///   or timestamp in the future.
+    /// - [`OracleError::StalePrice`] — timestamp older than `MAX_SUBMISSION_AGE_SECS`.
+    /// - [`OracleError::OracleNotFound`] — caller is not a registered oracle.
+    /// - [`OracleError::LowReputation`] — oracle weight has dropped to zero.
+    /// - [`OracleError::InsufficientBond`] — oracle has less than the minimum
+    ///   bond posted, or its bond is unbonding.
+    ///
+    /// # Breaking change
+    /// Replaces `submit_price(oracle, price)`, which fed a single global
+    /// round. Submissions now name their `pair` and observation `timestamp`,
+    /// and rounds close on their own, so `calculate_consensus` is gone.
+    pub fn submit_price(
+        env: Env,
+        oracle: Address,
+        pair: AssetPair,
+        price: i128,
+        timestamp: u64,
+    ) -> Result<(), OracleError> {
+        if admin::is_paused(&env, String::from_str(&env, CAT_ALL)) {
+            return Err(OracleError::CircuitBreakerTripped);
+        }
+        oracle.require_auth();
+        Self::accept_submission(&env, oracle, pair, price, timestamp, 0)
+    }
+
+    /// # Summary
+    /// `submit_price` with the base-asset `volume` the oracle saw trade at
+    /// `price`. The volumes of a round's accepted submissions are summed
+    /// and published with its price, which `get_vwap` weights by.
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidPrice`] — `volume` is negative.
+    /// - Any error of `submit_price`.
+    pub fn submit_price_with_volume(
+        env: Env,
+        oracle: Address,
+        pair: AssetPair,
+        price: i128,
+        timestamp: u64,
+        volume: i128,
+    ) -> Result<(), OracleError> {
+        if admin::is_paused(&env, String::from_str(&env, CAT_ALL)) {
+            return Err(OracleError::CircuitBreakerTripped);
+        }
+        oracle.require_auth();
+        Self::accept_submission(&env, oracle, pair, price, timestamp, volume)
+    }
+
+    /// # Summary
+    /// Register the Ed25519 key `oracle` signs relayed prices with, replacing
+    /// any earlier one.
+    ///
+    /// # Errors
+    /// - [`OracleError::OracleNotFound`] — caller is not a registered oracle.
+    pub fn register_signing_key(
+        env: Env,
+        oracle: Address,
+        key: BytesN<32>,
+    ) -> Result<(), OracleError> {
+        oracle.require_auth();
+        if !Self::read_oracles(&env).contains(&oracle) {
+            return Err(OracleError::OracleNotFound);
+        }
+        signed_prices::set_signing_key(&env, &oracle, &key);
+        events::emit_signing_key_set(&env, oracle, key);
+        Ok(())
+    }
+
+    pub fn get_signing_key(env: Env, oracle: Address) -> Option<BytesN<32>> {
+        signed_prices::get_signing_key(&env, &oracle)
+    }
+
+    /// Last nonce accepted from `oracle`'s signed prices.
+    pub fn get_signing_nonce(env: Env, oracle: Address) -> u64 {
+        signed_prices::get_nonce(&env, &oracle)
+    }
+
+    /// # Summary
+    /// Relay prices signed off-chain by registered oracle keys. Each price is
+    /// verified against its oracle's key and nonce and then submitted as
+    /// that oracle's, exactly as `submit_price` would; the relayer only pays
+    /// for the transaction. Returns the number of prices accepted.
+    ///
+    /// # Errors
+    /// - [`OracleError::BatchTooLarge`] — more than `MAX_SIGNED_BATCH` prices.
+    /// - [`OracleError::SigningKeyNotRegistered`] — a signer has no key.
+    /// - [`OracleError::InvalidNonce`] — a nonce doesn't follow the signer's
+    ///   last accepted one.
+    /// - Any error of `submit_price` for one of the prices. A bad signature
+    ///   aborts the invocation.
+    pub fn submit_signed_prices(
+        env: Env,
+        relayer: Address,
+        prices: Vec<SignedPrice>,
+    ) -> Result<u32, OracleError> {
+        if admin::is_paused(&env, String::from_str(&env, CAT_ALL)) {
+            return Err(OracleError::CircuitBreakerTripped);
+        }
+        relayer.require_auth();
+        if prices.len() > MAX_SIGNED_BATCH {
+            return Err(OracleError::BatchTooLarge);
+        }
+
+        for signed in prices.iter() {
+            signed_prices::verify(&env, &signed)?;
+            Self::accept_submission(
+                &env,
+                signed.oracle,
+                signed.pair,
+                signed.price,
+                signed.timestamp,
+                0,
+            )?;
+        }
+        Ok(prices.len())
+    }
+
+    /// Enter an authenticated oracle's price into the pair's open round.
+    fn accept_submission(
+        env: &Env,
+        oracle: Address,
+        pair: AssetPair,
+        price: i128,
+        timestamp: u64,
+        volume: i128,
+    ) -> Result<(), OracleError> {
+        let config = pair_config::require_enabled(env, &pair)?;
+        if price <= 0 || volume < 0 {
+            return Err(OracleError::InvalidPrice);
+        }
+        let price = pair_config::normalize_price(&config, price)?;
+
+        let now = env.ledger().timestamp();
+        if timestamp > now {
+            return Err(OracleError::InvalidPrice);
+        }
+        if now - timestamp > MAX_SUBMISSION_AGE_SECS {
+            return Err(OracleError::StalePrice);
+        }
+
+        let oracles = Self::read_oracles(env);
+        if !oracles.contains(&oracle) {
+            return Err(OracleError::OracleNotFound);
+        }
+
+        // Check reputation
+        let stats = get_oracle_stats(env, &oracle);
+        if stats.weight == 0 {
+            return Err(OracleError::LowReputation);
+        }
+        bond::require_bonded(env, &oracle)?;
+
+        let submission = PriceSubmission {
+            oracle: oracle.clone(),
+            price,
+            timestamp,
+            volume,
+        };
+
+        let mut submissions = Self::get_round_submissions(env.clone(), pair.clone());
+        if submissions.is_empty() {
+            env.storage()
+                .persistent()
+                .set(&StorageKey::RoundOpenedAt(pair.clone()), &env.ledger().timestamp());
+        }
+        match submissions.iter().position(|s| s.oracle == oracle) {
+            Some(i) => submissions.set(i as u32, submission),
+            None => submissions.push_back(submission),
+        }
+        env.storage()
+            .persistent()
+            .set(&StorageKey::RoundSubmissions(pair.clone()), &submissions);
+
+        let round = Self::get_round(env.clone(), pair.clone());
+        events::emit_price_submitted(env, oracle, pair.clone(), round, price);
+
+        if Self::round_ready(env, &pair, &config, submissions.len()) {
+            Self::close_round(env, &pair, &submissions);
+        }
+
+        Ok(())
+    }
+
+    /// # Summary
+    /// Finalise the pair's open round. Anyone may call this once the round
+    /// holds the required submissions or its deadline has passed; the round
+    /// is then aggregated exactly as when the last required submission
+    /// closes it, and the result becomes the price consumers read.
+    ///
+    /// # Errors
+    /// - [`OracleError::CircuitBreakerTripped`] — oracle is paused.
+    /// - [`OracleError::RoundNotReady`] — the round has no submissions, or
+    ///   too few before its deadline.
+    pub fn finalize_round(env: Env, pair: AssetPair) -> Result<ConsensusPriceData, OracleError> {
+        if admin::is_paused(&env, String::from_str(&env, CAT_ALL)) {
+            return Err(OracleError::CircuitBreakerTripped);
+        }
+        let submissions = Self::get_round_submissions(env.clone(), pair.clone());
+        let config = pair_config::get_config(&env, &pair);
+        if submissions.is_empty() || !Self::round_ready(&env, &pair, &config, submissions.len()) {
+            return Err(OracleError::RoundNotReady);
+        }
+        Self::close_round(&env, &pair, &submissions);
+        env.storage()
+            .persistent()
+            .get(&StorageKey::ConsensusPrice(pair))
+            .ok_or(OracleError::PriceNotFound)
+    }
+
+    /// The pair's open round: when it opened, its deadline and how far it
+    /// is from closing.
+    pub fn get_round_info(env: Env, pair: AssetPair) -> RoundInfo {
+        let config = pair_config::get_config(&env, &pair);
+        let opened_at: u64 = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::RoundOpenedAt(pair.clone()))
+            .unwrap_or(0);
+        RoundInfo {
+            round: Self::get_round(env.clone(), pair.clone()),
+            opened_at,
+            deadline: if opened_at == 0 {
+                0
+            } else {
+                opened_at + Self::get_round_duration(env.clone())
+            },
+            submissions: Self::get_round_submissions(env.clone(), pair).len(),
+            required: Self::required_submissions(&env, &config),
+        }
+    }
+
+    /// Result of a finalised round, while it is retained.
+    pub fn get_round_result(env: Env, pair: AssetPair, round: u64) -> Option<ConsensusPriceData> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::RoundResult(pair, round))
+    }
+
+    /// Set how long a round may stay open before it can be finalised short
+    /// of its required submissions (admin only).
+    pub fn set_round_duration(env: Env, admin: Address, seconds: u64) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if seconds == 0 {
+            return Err(OracleError::InvalidPairConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::RoundDuration, &seconds);
+        Ok(())
+    }
+
+    pub fn get_round_duration(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::RoundDuration)
+            .unwrap_or(DEFAULT_ROUND_DURATION_SECS)
+    }
+
+    /// Submissions collected so far in the pair's open round.
+    pub fn get_round_submissions(env: Env, pair: AssetPair) -> Vec<PriceSubmission> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::RoundSubmissions(pair))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Id of the pair's open round.
+    pub fn get_round(env: Env, pair: AssetPair) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::RoundId(pair))
+            .unwrap_or(0)
+    }
+
+    /// Set how many submissions close a round (admin only).
+    pub fn set_min_submissions(env: Env, admin: Address, count: u32) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if count == 0 {
+            return Err(OracleError::InsufficientOracles);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::MinSubmissions, &count);
+        Ok(())
+    }
+
+    /// Set the default `p_dev`, the max distance in BPS from the round median
+    /// a submission may have and still count toward the aggregated price
+    /// (admin only). Pairs with their own config use its `max_deviation_bps`.
+    pub fn set_max_deviation_bps(env: Env, admin: Address, bps: i128) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if bps <= 0 || bps > 10_000 {
+            return Err(OracleError::InvalidPrice);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::MaxDeviationBps, &bps);
+        Ok(())
+    }
+
+    /// Default `p_dev` outlier threshold in BPS.
+    pub fn get_max_deviation_bps(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::MaxDeviationBps)
+            .unwrap_or(DEFAULT_MAX_DEVIATION_BPS)
+    }
+
+    /// Install a config for one pair, or with `None` return it to the
+    /// contract-wide defaults (admin only).
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidPairConfig`] — decimals above
+    ///   `MAX_PAIR_DECIMALS`, staleness thresholds out of order, deviation
+    ///   outside (0, 10000] BPS or zero `min_sources`.
+    pub fn set_pair_config(
+        env: Env,
+        admin: Address,
+        pair: AssetPair,
+        config: Option<PairConfig>,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        pair_config::set_override(&env, &pair, config.clone())?;
+        events::emit_pair_config_updated(&env, pair, config);
+        Ok(())
+    }
+
+    /// The config in force for `pair`: its own, or the defaults.
+    pub fn get_pair_config(env: Env, pair: AssetPair) -> PairConfig {
+        pair_config::get_config(&env, &pair)
+    }
+
+    /// Register the decimals `asset` is expressed in (admin only). Pairs
+    /// quoted in the asset take them as their default `decimals`, and their
+    /// prices are normalized from them to `CANONICAL_DECIMALS`.
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidPairConfig`] — decimals above `MAX_PAIR_DECIMALS`.
+    pub fn set_asset_decimals(
+        env: Env,
+        admin: Address,
+        asset: Asset,
+        decimals: u32,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        pair_config::set_asset_decimals(&env, &asset, decimals)?;
+        events::emit_asset_decimals_updated(&env, asset, decimals);
+        Ok(())
+    }
+
+    /// Decimals `asset` is expressed in.
+    pub fn get_asset_decimals(env: Env, asset: Asset) -> u32 {
+        pair_config::asset_decimals(&env, &asset)
+    }
+
+    /// Ask for a fresh aggregation of `pair`: once the pair's open round
+    /// closes, `callback_fn` on `callback_contract` is invoked with
+    /// `(request_id, pair, ConsensusPriceData)`. Returns the request id.
+    ///
+    /// # Errors
+    /// - [`OracleError::PairDisabled`] — the pair is disabled in its config.
+    /// - [`OracleError::TooManyPriceRequests`] — `MAX_PENDING_REQUESTS` already
+    ///   wait on the pair's round.
+    pub fn request_price(
+        env: Env,
+        requester: Address,
+        pair: AssetPair,
+        callback_contract: Address,
+        callback_fn: Symbol,
+    ) -> Result<u64, OracleError> {
+        requester.require_auth();
+        pair_config::require_enabled(&env, &pair)?;
+        let round = Self::get_round(env.clone(), pair.clone());
+        price_requests::request(&env, requester, pair, round, callback_contract, callback_fn)
+    }
+
+    /// Withdraw a pending price request (requester only).
+    pub fn cancel_price_request(
+        env: Env,
+        requester: Address,
+        pair: AssetPair,
+        request_id: u64,
+    ) -> Result<(), OracleError> {
+        requester.require_auth();
+        price_requests::cancel(&env, &requester, &pair, request_id)
+    }
+
+    /// Requests waiting on the pair's open round, oldest first.
+    pub fn get_price_requests(env: Env, pair: AssetPair) -> Vec<PriceRequest> {
+        price_requests::get_pending(&env, &pair)
+    }
+
+    /// Consecutive deviating rounds before an oracle is slashed automatically.
+    pub fn set_deviation_streak_limit(
+        env: Env,
+        admin: Address,
+        rounds: u32,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if rounds == 0 {
+            return Err(OracleError::InvalidPrice);
+        }
+        env.storage()
+            .instance()
+            .set(&StorageKey::DeviationStreakLimit, &rounds);
+        Ok(())
+    }
+
+    pub fn get_deviation_streak_limit(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::DeviationStreakLimit)
+            .unwrap_or(DEFAULT_DEVIATION_STREAK_LIMIT)
+    }
+
+    /// Rounds in a row the oracle's rolling deviation has exceeded `p_dev`.
+    pub fn get_deviation_streak(env: Env, oracle: Address) -> u32 {
+        get_deviation_streak(&env, &oracle)
+    }
+
+    /// Configured submissions per round (before capping at active oracles).
+    pub fn get_min_submissions(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::MinSubmissions)
+            .unwrap_or(DEFAULT_MIN_SUBMISSIONS)
+    }
+
+    /// Get oracle reputation stats, with idle decay applied to the score so
+    /// governance voters see the current value before removal proposals
+    pub fn get_oracle_reputation(env: Env, oracle: Address) -> OracleReputation {
+        current_reputation(&env, &oracle)
+    }
+
+    fn read_oracles(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::Oracles)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Get all registered oracles
+    pub fn get_oracles(env: Env) -> Vec<Address> {
+        Self::read_oracles(&env)
+    }
+
+    /// Size of the registered oracle set.
+    pub fn get_oracle_count(env: Env) -> u32 {
+        Self::read_oracles(&env).len()
+    }
+
+    /// # Summary
+    /// Reputation, bond and deviation streak of a registered oracle, for
+    /// reviewing the operator set before proposing changes to it.
+    ///
+    /// # Errors
+    /// - [`OracleError::OracleNotFound`] — `oracle` is not registered.
+    pub fn get_oracle_stats(env: Env, oracle: Address) -> Result<OracleStats, OracleError> {
+        if !Self::read_oracles(&env).contains(&oracle) {
+            return Err(OracleError::OracleNotFound);
+        }
+        Ok(OracleStats {
+            reputation: current_reputation(&env, &oracle),
+            bond: bond::get_bond(&env, &oracle),
+            deviation_streak: get_deviation_streak(&env, &oracle),
+            oracle,
+        })
+    }
+
+    /// Set the bond an oracle must hold for its submissions to be accepted
+    /// (0 disables the requirement) and how long a departing oracle's bond
+    /// stays slashable before it can be claimed (admin only).
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidBondConfig`] — `min_bond` is negative.
+    pub fn set_bond_config(
+        env: Env,
+        admin: Address,
+        min_bond: i128,
+        unbonding_period: u64,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        bond::set_config(&env, min_bond, unbonding_period)
+    }
+
+    /// Minimum bond and unbonding period in seconds.
+    pub fn get_bond_config(env: Env) -> (i128, u64) {
+        (bond::get_min_bond(&env), bond::get_unbonding_period(&env))
+    }
+
+    /// Transfer `amount` of the governance stake token from the caller into
+    /// their bond. The bond is slashed alongside reputation and starts
+    /// unbonding when the oracle leaves the set.
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidBondAmount`] — amount <= 0.
+    /// - [`OracleError::BondLocked`] — the bond is unbonding; claim it first.
+    /// - [`OracleError::BondTokenNotSet`] — governance has no stake token yet.
+    pub fn post_bond(env: Env, oracle: Address, amount: i128) -> Result<OracleBond, OracleError> {
+        oracle.require_auth();
+        let bond = bond::post(&env, &oracle, amount)?;
+        events::emit_bond_posted(&env, oracle, amount, bond.amount);
+        Ok(bond)
+    }
+
+    /// # Summary
+    /// Leave the oracle set. The operator's bond starts unbonding and stays
+    /// slashable for the submissions it already made, including one still in
+    /// an open round, until `claim_bond` releases it. Returns the bond.
+    ///
+    /// # Errors
+    /// - [`OracleError::OracleNotFound`] — caller is not a registered oracle.
+    /// - [`OracleError::InsufficientOracles`] — leaving would shrink the set
+    ///   below `MIN_ORACLES`.
+    pub fn exit_oracle(env: Env, oracle: Address) -> Result<OracleBond, OracleError> {
+        oracle.require_auth();
+        let oracles = Self::read_oracles(&env);
+        if !oracles.contains(&oracle) {
+            return Err(OracleError::OracleNotFound);
+        }
+        if oracles.len() - 1 < MIN_ORACLES {
+            return Err(OracleError::InsufficientOracles);
+        }
+        Self::remove_oracle_internal(&env, &oracle);
+        events::emit_oracle_removed(&env, oracle.clone(), "Voluntary exit");
+        Ok(bond::get_bond(&env, &oracle))
+    }
+
+    /// Transfer the caller's bond back to them once its unbonding period has
+    /// passed. Returns the amount released.
+    ///
+    /// # Errors
+    /// - [`OracleError::BondLocked`] — the bond is not unbonding or still locked.
+    pub fn claim_bond(env: Env, oracle: Address) -> Result<i128, OracleError> {
+        oracle.require_auth();
+        let amount = bond::claim(&env, &oracle)?;
+        events::emit_bond_claimed(&env, oracle, amount);
+        Ok(amount)
+    }
+
+    pub fn get_bond(env: Env, oracle: Address) -> OracleBond {
+        bond::get_bond(&env, &oracle)
+    }
+
+    /// # Summary
+    /// Dispute the pair's last finalised round within the dispute window,
+    /// transferring the dispute bond from the caller, who must hold
+    /// governance stake. The round's price is frozen for consumers until the
+    /// `ResolveDispute` vote opened here (`Dispute::proposal_id`) executes,
+    /// upholding the dispute, or fails, rejecting it.
+    ///
+    /// # Errors
+    /// - [`OracleError::PriceNotFound`] — `round` is not the pair's last
+    ///   finalised round.
+    /// - [`OracleError::DisputeWindowClosed`] — the window has passed.
+    /// - [`OracleError::DisputeExists`] — the round is already disputed.
+    /// - [`OracleError::DisputerNotStaked`] — the caller holds no stake.
+    pub fn dispute_round(
+        env: Env,
+        disputer: Address,
+        pair: AssetPair,
+        round: u64,
+    ) -> Result<Dispute, OracleError> {
+        disputer.require_auth();
+        let consensus: ConsensusPriceData = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::ConsensusPrice(pair.clone()))
+            .ok_or(OracleError::PriceNotFound)?;
+        if consensus.round != round {
+            return Err(OracleError::PriceNotFound);
+        }
+        disputes::open(&env, disputer, pair, round, consensus.timestamp)
+    }
+
+    pub fn get_dispute(env: Env, pair: AssetPair, round: u64) -> Option<Dispute> {
+        disputes::get_dispute(&env, &pair, round)
+    }
+
+    /// Set the dispute window and bond (admin only).
+    pub fn set_dispute_config(
+        env: Env,
+        admin: Address,
+        config: DisputeConfig,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        disputes::set_config(&env, &config)
+    }
+
+    pub fn get_dispute_config(env: Env) -> DisputeConfig {
+        disputes::get_config(&env)
+    }
+
+    pub fn get_dispute_winnings(env: Env, account: Address) -> i128 {
+        disputes::get_winnings(&env, &account)
+    }
+
+    /// Transfer the caller's dispute winnings to them. Returns the amount.
+    pub fn claim_dispute_winnings(env: Env, account: Address) -> Result<i128, OracleError> {
+        account.require_auth();
+        disputes::claim(&env, &account)
+    }
+
+    /// Total slashed out of oracle bonds.
+    pub fn get_slashed_bonds(env: Env) -> i128 {
+        bond::get_slashed_pool(&env)
+    }
+
+    /// Start oracle governance. The oracle admin becomes the governance admin
+    /// and `stake_token` the token staked for voting weight (admin only,
+    /// once).
+    pub fn init_governance(
+        env: Env,
+        admin: Address,
+        stake_token: Address,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        OracleGovernance::initialize(&env, admin, stake_token);
+        Ok(())
+    }
+
+    /// Stake `amount` of the governance token for voting weight.
+    pub fn deposit_stake(env: Env, staker: Address, amount: i128) -> Result<(), GovernanceError> {
+        OracleGovernance::deposit_stake(&env, staker, amount)
+    }
+
+    /// # Summary
+    /// Take `amount` out of voting weight now and queue it for withdrawal
+    /// once the unstake cooldown has passed. Returns when it can be claimed.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::InsufficientStake`] — amount <= 0 or above the stake.
+    pub fn request_unstake(env: Env, staker: Address, amount: i128) -> Result<u64, GovernanceError> {
+        OracleGovernance::request_unstake(&env, staker, amount)
+    }
+
+    /// # Summary
+    /// Transfer the caller's queued unstake back to them. Returns the amount.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::NoPendingUnstake`] — nothing has been requested.
+    /// - [`GovernanceError::UnstakeCooldownActive`] — the cooldown hasn't passed.
+    pub fn claim_unstake(env: Env, staker: Address) -> Result<i128, GovernanceError> {
+        OracleGovernance::claim_unstake(&env, staker)
+    }
+
+    pub fn get_pending_unstake(env: Env, staker: Address) -> Option<PendingUnstake> {
+        OracleGovernance::get_pending_unstake(&env, &staker)
+    }
+
+    pub fn get_unstake_cooldown(env: Env) -> u64 {
+        OracleGovernance::get_unstake_cooldown(&env)
+    }
+
+    /// Set the delay between requesting and claiming an unstake (governance
+    /// admin only). Fails with [`GovernanceError::InvalidParameter`] if it is shorter
+    /// than the voting period.
+    pub fn set_unstake_cooldown(
+        env: Env,
+        admin: Address,
+        cooldown_seconds: u64,
+    ) -> Result<(), GovernanceError> {
+        OracleGovernance::set_unstake_cooldown(&env, admin, cooldown_seconds)
+    }
+
+    /// Governance stake of `staker` currently counting as voting weight.
+    pub fn get_stake(env: Env, staker: Address) -> i128 {
+        OracleGovernance::get_stake(&env, &staker)
+    }
+
+    pub fn get_total_staked(env: Env) -> i128 {
+        OracleGovernance::get_total_staked(&env)
+    }
+
+    /// Deposit a proposal opened now locks: a share of total stake, clamped
+    /// between a floor and a cap.
+    pub fn get_required_deposit(env: Env) -> i128 {
+        OracleGovernance::get_required_deposit(&env)
+    }
+
+    /// # Summary
+    /// Open a governance proposal, locking the required deposit out of the
+    /// proposer's stake. Returns the proposal ID.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::Unauthorized`] — the type has its own entry point.
+    /// - [`GovernanceError::InsufficientStake`] — stake below the required deposit.
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        proposal_type: ProposalType,
+        description: String,
+        execution_payload: Bytes,
+    ) -> Result<u64, GovernanceError> {
+        OracleGovernance::create_proposal(
+            &env,
+            proposer,
+            proposal_type,
+            description,
+            execution_payload,
+        )
+    }
+
+    /// # Summary
+    /// Propose swapping `outgoing` for `incoming` in one step, so the oracle
+    /// set never shrinks. The incoming oracle's starting reputation follows
+    /// the governed replacement policy. Returns the proposal ID.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::OracleNotFound`] — `outgoing` is not registered.
+    /// - [`GovernanceError::OracleAlreadyExists`] — `incoming` is already
+    ///   registered.
+    /// - [`GovernanceError::InsufficientStake`] — stake below the required deposit.
+    pub fn propose_oracle_replacement(
+        env: Env,
+        proposer: Address,
+        outgoing: Address,
+        incoming: Address,
+        description: String,
+    ) -> Result<u64, GovernanceError> {
+        OracleGovernance::propose_oracle_replacement(
+            &env,
+            proposer,
+            outgoing,
+            incoming,
+            description,
+        )
+    }
+
+    pub fn get_oracle_replacement(env: Env, proposal_id: u64) -> Option<OracleReplacement> {
+        OracleGovernance::get_oracle_replacement(&env, proposal_id)
+    }
+
+    pub fn get_replacement_policy(env: Env) -> ReputationPolicy {
+        OracleGovernance::get_replacement_policy(&env)
+    }
+
+    /// # Summary
+    /// Propose that `pair` be priced under `config`, or the contract-wide
+    /// defaults when `None`. On execution the pair is enabled and listed as
+    /// available. Returns the proposal ID.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::InvalidPairConfig`] — `config` is inconsistent.
+    /// - [`GovernanceError::InsufficientStake`] — stake below the required deposit.
+    pub fn propose_pair_listing(
+        env: Env,
+        proposer: Address,
+        pair: AssetPair,
+        config: Option<PairConfig>,
+        description: String,
+    ) -> Result<u64, GovernanceError> {
+        OracleGovernance::propose_pair_listing(&env, proposer, pair, config, description)
+    }
+
+    /// # Summary
+    /// Propose that `pair` stop being priced. On execution its config is
+    /// kept but disabled, so a later listing can restore it. Returns the
+    /// proposal ID.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::PairDisabled`] — the pair is already disabled.
+    /// - [`GovernanceError::InsufficientStake`] — stake below the required deposit.
+    pub fn propose_pair_delisting(
+        env: Env,
+        proposer: Address,
+        pair: AssetPair,
+        description: String,
+    ) -> Result<u64, GovernanceError> {
+        OracleGovernance::propose_pair_delisting(&env, proposer, pair, description)
+    }
+
+    pub fn get_pair_listing(env: Env, proposal_id: u64) -> Option<PairListing> {
+        OracleGovernance::get_pair_listing(&env, proposal_id)
+    }
+
+    /// Round settled by a `ResolveDispute` proposal.
+    pub fn get_disputed_round(env: Env, proposal_id: u64) -> Option<DisputedRound> {
+        OracleGovernance::get_disputed_round(&env, proposal_id)
+    }
+
+    /// Vote with the caller's stake. A vote that takes the proposal past
+    /// quorum and approval executes it.
+    pub fn vote_on_proposal(
+        env: Env,
+        proposal_id: u64,
+        voter: Address,
+        support: bool,
+    ) -> Result<(), GovernanceError> {
+        OracleGovernance::vote_on_proposal(&env, proposal_id, voter, support)
+    }
+
+    /// Resolve a proposal whose voting window has closed. Anyone may call
+    /// this. Returns the resulting status.
+    pub fn finalise_proposal(env: Env, proposal_id: u64) -> Result<ProposalStatus, GovernanceError> {
+        OracleGovernance::finalise_proposal(&env, proposal_id)
+    }
+
+    /// # Summary
+    /// Retry a proposal whose execution failed, without waiting for its
+    /// backoff. Anyone may call this.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::ProposalNotFound`] — no such proposal.
+    /// - [`GovernanceError::ProposalNotRetryable`] — the proposal is not in
+    ///   `ExecutionFailed`.
+    pub fn retry_execution(env: Env, proposal_id: u64) -> Result<(), GovernanceError> {
+        OracleGovernance::retry_execution(&env, proposal_id)
+    }
+
+    /// Keeper entry point: retry up to `max_batch` failed proposals whose
+    /// backoff has elapsed. Returns how many were retried.
+    pub fn process_retry_queue(env: Env, max_batch: u32) -> u32 {
+        OracleGovernance::process_retry_queue(&env, max_batch)
+    }
+
+    pub fn get_retry_queue(env: Env) -> Vec<u64> {
+        OracleGovernance::get_retry_queue(&env)
+    }
+
+    pub fn get_retry_state(env: Env, proposal_id: u64) -> Option<RetryState> {
+        OracleGovernance::get_retry_state(&env, proposal_id)
+    }
+
+    /// Add `amount` of the stake token to the voter reward pool. A share of
+    /// the pool is set aside for the voters of each proposal decided by vote.
+    pub fn fund_reward_pool(env: Env, funder: Address, amount: i128) -> Result<(), GovernanceError> {
+        OracleGovernance::fund_reward_pool(&env, funder, amount)
+    }
+
+    /// # Summary
+    /// Pay `voter` their share of the rewards of the listed proposals, pro
+    /// rata to vote weight, including the deposit rebate of those that missed
+    /// quorum (which their proposer cannot claim). Returns the amount paid.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::NothingToClaim`] — no unclaimed reward on any of them.
+    pub fn claim_voting_rewards(
+        env: Env,
+        voter: Address,
+        proposal_ids: Vec<u64>,
+    ) -> Result<i128, GovernanceError> {
+        OracleGovernance::claim_voting_rewards(&env, voter, proposal_ids)
+    }
+
+    pub fn get_reward_pool(env: Env) -> i128 {
+        OracleGovernance::get_reward_pool(&env)
+    }
+
+    pub fn get_proposal_reward(env: Env, proposal_id: u64) -> Option<ProposalReward> {
+        OracleGovernance::get_proposal_reward(&env, proposal_id)
+    }
+
+    pub fn get_deposit_rebate(env: Env, proposal_id: u64) -> Option<DepositRebate> {
+        OracleGovernance::get_deposit_rebate(&env, proposal_id)
+    }
+
+    /// Cancel an active proposal and return its deposit (governance admin
+    /// only).
+    pub fn cancel_proposal(env: Env, admin: Address, proposal_id: u64) -> Result<(), GovernanceError> {
+        OracleGovernance::cancel_proposal(&env, admin, proposal_id)
+    }
+
+    /// # Summary
+    /// Withdraw one's own proposal before anyone has voted on it, recovering
+    /// the deposit.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::Unauthorized`] — caller is not the proposer, or the
+    ///   proposal is a ratification.
+    /// - [`GovernanceError::ProposalNotActive`] — the proposal is already resolved.
+    /// - [`GovernanceError::ProposalHasVotes`] — a vote has been cast.
+    pub fn withdraw_proposal(
+        env: Env,
+        proposer: Address,
+        proposal_id: u64,
+    ) -> Result<(), GovernanceError> {
+        OracleGovernance::withdraw_proposal(&env, proposer, proposal_id)
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<OracleProposal, GovernanceError> {
+        OracleGovernance::get_proposal(&env, proposal_id)
+    }
+
+    pub fn get_proposal_count(env: Env) -> u64 {
+        OracleGovernance::proposal_count(&env)
+    }
+
+    pub fn has_voted(env: Env, proposal_id: u64, voter: Address) -> bool {
+        OracleGovernance::has_voted(&env, proposal_id, &voter)
     }
 
-    /// Propose admin transfer (current admin only)
-    pub fn propose_admin_transfer(
+    /// # Summary
+    /// Where a proposal stands against its quorum and approval thresholds,
+    /// how much more FOR weight it needs, and the voting time left.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::ProposalNotFound`] — no such proposal.
+    pub fn preview_proposal_outcome(
         env: Env,
-        caller: Address,
-        new_admin: Address,
-    ) -> Result<(), OracleError> {
-        admin::propose_admin_transfer(&env, &caller, new_admin)
+        proposal_id: u64,
+    ) -> Result<ProposalOutcomePreview, GovernanceError> {
+        OracleGovernance::preview_proposal_outcome(&env, proposal_id)
     }
 
-    /// Accept admin transfer (new admin only)
-    pub fn accept_admin_transfer(env: Env, caller: Address) -> Result<(), OracleError> {
-        admin::accept_admin_transfer(&env, &caller)
+    /// Direction, weight and time of `voter`'s ballot on a proposal, if any.
+    pub fn get_vote(env: Env, proposal_id: u64, voter: Address) -> Option<VoteReceipt> {
+        OracleGovernance::get_vote(&env, proposal_id, &voter)
     }
 
-    /// Cancel pending admin transfer (current admin only)
-    pub fn cancel_admin_transfer(env: Env, caller: Address) -> Result<(), OracleError> {
-        admin::cancel_admin_transfer(&env, &caller)
+    /// ID of the ratification vote opened when emergency pause `emergency_id`
+    /// executed. If it fails, the pause is lifted.
+    pub fn get_ratification_proposal(env: Env, emergency_id: u64) -> Option<u64> {
+        OracleGovernance::get_ratification_proposal(&env, emergency_id)
     }
 
-    /// Calculate TWAP for 1 hour
-    pub fn get_twap_1h(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
-        history::calculate_twap(&env, &pair, 3600)
+    /// Emergency pause quorum and approval threshold, in BPS.
+    pub fn get_emergency_params(env: Env) -> (i128, i128) {
+        OracleGovernance::get_emergency_params(&env)
     }
 
-    /// Calculate TWAP for 24 hours
-    pub fn get_twap_24h(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
-        history::calculate_twap(&env, &pair, 86400)
+    /// Quorum, in BPS of total stake, a proposal of `proposal_type` needs.
+    /// Governable per type through `UpdateParameter`.
+    pub fn get_quorum_bps(env: Env, proposal_type: ProposalType) -> i128 {
+        OracleGovernance::get_quorum_bps(&env, proposal_type)
     }
 
-    /// Calculate TWAP for 7 days
-    pub fn get_twap_7d(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
-        history::calculate_twap(&env, &pair, 604800)
+    /// Proposal outcome counts, average participation and time to quorum,
+    /// and deposits returned or burned, for DAO health dashboards.
+    pub fn get_governance_stats(env: Env) -> GovernanceStats {
+        OracleGovernance::get_governance_stats(&env)
     }
 
-    /// Get price deviation from TWAP
-    pub fn get_price_deviation(
-        env: Env,
-        pair: AssetPair,
-        current_price: i128,
-        window: u64,
-    ) -> Result<i128, OracleError> {
-        history::get_twap_deviation(&env, &pair, current_price, window)
+    /// Extend the TTL of a proposal record, full or archived. Anyone may
+    /// call this.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::ProposalNotFound`] — no such proposal.
+    pub fn bump_proposal_ttl(env: Env, proposal_id: u64) -> Result<(), GovernanceError> {
+        OracleGovernance::bump_proposal_ttl(&env, proposal_id)
     }
 
-    /// Find optimal path between assets
-    pub fn find_optimal_path(
-        env: Env,
-        from: Asset,
-        to: Asset,
-        amount: i128,
-    ) -> Result<LiquidityPath, OracleError> {
-        multi_hop::find_optimal_path(&env, from, to, amount)
+    /// # Summary
+    /// Replace a long-finalised proposal with a compact summary to cut
+    /// ledger rent. Anyone may call this.
+    ///
+    /// # Errors
+    /// - [`GovernanceError::ProposalNotFound`] — no such proposal, or already
+    ///   archived.
+    /// - [`GovernanceError::ProposalNotFinalised`] — still open or awaiting retry.
+    /// - [`GovernanceError::ArchiveDelayActive`] — finalised too recently.
+    pub fn archive_proposal(env: Env, proposal_id: u64) -> Result<ProposalSummary, GovernanceError> {
+        OracleGovernance::archive_proposal(&env, proposal_id)
     }
 
-    /// Calculate price via multi-hop path
-    pub fn calculate_multi_hop_price(env: Env, path: LiquidityPath, amount: i128) -> i128 {
-        multi_hop::calculate_multi_hop_price(&env, path, amount)
+    pub fn get_archived_proposal(env: Env, proposal_id: u64) -> Option<ProposalSummary> {
+        OracleGovernance::get_archived_proposal(&env, proposal_id)
     }
 
-    /// Register a new oracle
-    pub fn register_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), OracleError> {
+    /// Remove an oracle (admin only)
+    pub fn remove_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), OracleError> {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
+        Self::remove_oracle_internal(&env, &oracle);
+        Ok(())
+    }
 
-        let mut oracles = Self::read_oracles(&env);
-        if oracles.contains(&oracle) {
-            return Err(OracleError::OracleAlreadyExists);
-        }
-
-        oracles.push_back(oracle.clone());
-        env.storage()
-            .persistent()
-            .set(&StorageKey::Oracles, &oracles);
+    // Internal helpers
 
-        // Initialize with default reputation
-        let stats = OracleReputation {
-            total_submissions: 0,
-            accurate_submissions: 0,
-            avg_deviation: 0,
-            reputation_score: 50,
-            weight: 1,
-            last_slash: 0,
-        };
-        reputation::save_oracle_stats(&env, &oracle, &stats);
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), OracleError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(OracleError::Unauthorized)?;
 
+        if caller != &admin {
+            return Err(OracleError::Unauthorized);
+        }
         Ok(())
     }
 
-    /// Submit a price from an oracle
-    pub fn submit_price(env: Env, oracle: Address, price: i128) -> Result<(), OracleError> {
-        if admin::is_paused(&env, String::from_str(&env, CAT_ALL)) {
-            return Err(OracleError::CircuitBreakerTripped);
-        }
-        oracle.require_auth();
-
-        if price <= 0 {
-            return Err(OracleError::InvalidPrice);
+    /// Submissions needed to close a round: the pair's `min_sources`, capped
+    /// at the number of oracles that still carry weight.
+    fn required_submissions(env: &Env, config: &PairConfig) -> u32 {
+        let oracles = Self::read_oracles(env);
+        let mut active = 0u32;
+        for oracle in oracles.iter() {
+            if get_oracle_stats(env, &oracle).weight > 0 {
+                active += 1;
+            }
         }
+        config.min_sources.min(active).max(1)
+    }
 
-        let oracles = Self::read_oracles(&env);
-        if !oracles.contains(&oracle) {
-            return Err(OracleError::OracleNotFound);
+    /// A round of `submissions` entries closes once it holds the required
+    /// submissions or its deadline has passed.
+    fn round_ready(env: &Env, pair: &AssetPair, config: &PairConfig, submissions: u32) -> bool {
+        if submissions >= Self::required_submissions(env, config) {
+            return true;
         }
-
-        // Check reputation
-        let stats = get_oracle_stats(&env, &oracle);
-        if stats.weight == 0 {
-            return Err(OracleError::LowReputation);
+        let opened_at: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::RoundOpenedAt(pair.clone()));
+        match opened_at {
+            Some(opened_at) => {
+                env.ledger().timestamp() >= opened_at + Self::get_round_duration(env.clone())
+            }
+            None => false,
         }
-
-        let submission = PriceSubmission {
-            oracle: oracle.clone(),
-            price,
-            timestamp: env.ledger().timestamp(),
-        };
-
-        let mut submissions = Self::get_price_submissions(&env);
-        submissions.push_back(submission);
-        env.storage()
-            .instance()
-            .set(&StorageKey::PriceSubmissions, &submissions);
-
-        events::emit_price_submitted(&env, oracle, price);
-
-        Ok(())
     }
 
-    /// Calculate consensus price and update oracle reputations
-    pub fn calculate_consensus(env: Env) -> Result<i128, OracleError> {
-        let submissions = Self::get_price_submissions(&env);
-        let oracles = Self::read_oracles(&env);
-
-        if submissions.is_empty() {
-            return Err(OracleError::InsufficientOracles);
+    /// Aggregate a full round, update oracle reputations and publish the result.
+    fn close_round(env: &Env, pair: &AssetPair, submissions: &Vec<PriceSubmission>) {
+        let oracles = Self::read_oracles(env);
+        let round = Self::get_round(env.clone(), pair.clone());
+
+        // Drop outliers: anything further than `p_dev` from the plain median.
+        // If every value is an outlier the round is too dispersed to judge, so
+        // keep them all.
+        let max_deviation = pair_config::get_config(env, pair).max_deviation_bps;
+        let mut prices = Vec::new(env);
+        for submission in submissions.iter() {
+            prices.push_back(submission.price);
+        }
+        let reference = Self::median(prices);
+        let mut accepted = Vec::new(env);
+        let mut rejected = Vec::new(env);
+        for submission in submissions.iter() {
+            let deviation = ((submission.price - reference).abs() * 10000) / reference;
+            if deviation > max_deviation {
+                rejected.push_back((submission, deviation));
+            } else {
+                accepted.push_back(submission);
+            }
+        }
+        if accepted.is_empty() {
+            accepted = submissions.clone();
+            rejected = Vec::new(env);
         }
 
         // Calculate weighted median
-        let consensus_price = Self::weighted_median(&env, &submissions);
+        let consensus_price = Self::weighted_median(env, &accepted);
+
+        // Track accuracy for each oracle; outliers are charged their
+        // deviation from the reference median
+        for (submission, deviation) in rejected.iter() {
+            record_rejected_submission(env, &submission.oracle, deviation);
+            events::emit_price_rejected(
+                env,
+                submission.oracle.clone(),
+                pair.clone(),
+                round,
+                submission.price,
+                deviation,
+            );
+        }
+        for submission in accepted.iter() {
+            track_oracle_accuracy(env, &submission.oracle, submission.price, consensus_price);
+        }
 
-        // Track accuracy for each oracle
         for i in 0..submissions.len() {
             let submission = submissions.get(i).unwrap();
-            track_oracle_accuracy(&env, &submission.oracle, submission.price, consensus_price);
 
             // Check for major deviation and slash if needed
             let deviation = ((submission.price - consensus_price).abs() * 10000) / consensus_price;
             if deviation > 2000 {
                 // 20%
-                slash_oracle(&env, &submission.oracle, SlashReason::MajorDeviation);
-                events::emit_oracle_slashed(&env, submission.oracle.clone(), "major_deviation", 20);
+                slash_oracle(env, &submission.oracle, SlashReason::MajorDeviation);
+                events::emit_oracle_slashed(env, submission.oracle.clone(), "major_deviation", 20);
             }
         }
 
         // Adjust weights for all oracles
-        let mut removed_oracles = Vec::new(&env);
+        let mut removed_oracles = Vec::new(env);
         for i in 0..oracles.len() {
             let oracle = oracles.get(i).unwrap();
-            let old_stats = get_oracle_stats(&env, &oracle);
+            let old_stats = get_oracle_stats(env, &oracle);
             let old_weight = old_stats.weight;
 
-            let new_weight = adjust_oracle_weight(&env, &oracle);
+            let submitted = submissions.iter().any(|s| s.oracle == oracle);
+            let new_weight = adjust_oracle_weight(env, &oracle, submitted);
 
             if new_weight != old_weight {
-                let reputation = calculate_reputation(&env, &oracle);
+                let reputation = get_oracle_stats(env, &oracle).reputation_score;
                 events::emit_weight_adjusted(
-                    &env,
+                    env,
                     oracle.clone(),
                     old_weight,
                     new_weight,
@@ -374,91 +1631,110 @@ impl OracleContract {
                 );
             }
 
-            if should_remove_oracle(&env, &oracle) {
+            if should_remove_oracle(env, &oracle) {
                 removed_oracles.push_back(oracle.clone());
             }
         }
 
+        // Slash oracles whose rolling deviation stays above `p_dev`
+        let streak_limit = Self::get_deviation_streak_limit(env.clone());
+        for submission in submissions.iter() {
+            if check_persistent_deviation(env, &submission.oracle, max_deviation, streak_limit) {
+                events::emit_oracle_slashed(
+                    env,
+                    submission.oracle.clone(),
+                    "persistent_deviation",
+                    PERSISTENT_DEVIATION_PENALTY,
+                );
+            }
+        }
+
         // Remove poor performing oracles (but keep minimum 2)
-        if oracles.len() - removed_oracles.len() >= 2 {
+        if oracles.len() - removed_oracles.len() >= MIN_ORACLES {
             for i in 0..removed_oracles.len() {
                 let oracle = removed_oracles.get(i).unwrap();
-                Self::remove_oracle_internal(&env, &oracle);
-                events::emit_oracle_removed(&env, oracle, "Low reputation");
+                Self::remove_oracle_internal(env, &oracle);
+                events::emit_oracle_removed(env, oracle, "Low reputation");
             }
         }
 
-        // Store consensus
+        // Store consensus and publish it as the pair's price
         let consensus_data = ConsensusPriceData {
             price: consensus_price,
             timestamp: env.ledger().timestamp(),
-            num_oracles: submissions.len() as u32,
+            num_oracles: accepted.len(),
+            round,
+            source: PriceSource::Internal,
         };
         env.storage()
             .persistent()
-            .set(&StorageKey::ConsensusPrice, &consensus_data);
-
-        // Clear submissions for next round
-        env.storage().instance().set(
-            &StorageKey::PriceSubmissions,
-            &Vec::<PriceSubmission>::new(&env),
+            .set(&StorageKey::ConsensusPrice(pair.clone()), &consensus_data);
+        let mut round_oracles = Vec::new(env);
+        for submission in accepted.iter() {
+            round_oracles.push_back(submission.oracle);
+        }
+        disputes::record_round(env, pair, &round_oracles);
+        let confidence = PriceWithConfidence {
+            price: consensus_price,
+            spread_bps: Self::spread_bps(&accepted, consensus_price),
+            num_sources: accepted.len(),
+            timestamp: consensus_data.timestamp,
+        };
+        env.storage()
+            .persistent()
+            .set(&StorageKey::PriceConfidence(pair.clone()), &confidence);
+        storage::set_price(env, pair, consensus_price);
+        storage::add_available_pair(env, pair.clone());
+        history::store_price(env, pair, consensus_price);
+        let mut volume: i128 = 0;
+        for submission in accepted.iter() {
+            volume = volume.saturating_add(submission.volume);
+        }
+        history::record_observation(env, pair, consensus_price, volume);
+        on_price_update(env, pair.clone());
+
+        let result_key = StorageKey::RoundResult(pair.clone(), round);
+        env.storage().persistent().set(&result_key, &consensus_data);
+        env.storage().persistent().extend_ttl(
+            &result_key,
+            ROUND_RESULT_TTL_LEDGERS,
+            ROUND_RESULT_TTL_LEDGERS,
         );
 
-        events::emit_consensus_reached(&env, consensus_price, submissions.len());
-
-        Ok(consensus_price)
-    }
-
-    /// Get oracle reputation stats
-    pub fn get_oracle_reputation(env: Env, oracle: Address) -> OracleReputation {
-        get_oracle_stats(&env, &oracle)
-    }
-
-    fn read_oracles(env: &Env) -> Vec<Address> {
+        // Open the next round
         env.storage()
             .persistent()
-            .get(&StorageKey::Oracles)
-            .unwrap_or(Vec::new(env))
-    }
-
-    /// Get all registered oracles
-    pub fn get_oracles(env: Env) -> Vec<Address> {
-        Self::read_oracles(&env)
-    }
+            .remove(&StorageKey::RoundSubmissions(pair.clone()));
+        env.storage()
+            .persistent()
+            .remove(&StorageKey::RoundOpenedAt(pair.clone()));
+        env.storage()
+            .persistent()
+            .set(&StorageKey::RoundId(pair.clone()), &(round + 1));
 
-    /// Get current consensus price
-    pub fn get_consensus_price(env: Env) -> Option<ConsensusPriceData> {
-        env.storage().persistent().get(&StorageKey::ConsensusPrice)
-    }
+        events::emit_consensus_reached(
+            env,
+            pair.clone(),
+            round,
+            consensus_price,
+            accepted.len(),
+        );
 
-    /// Remove an oracle (admin only)
-    pub fn remove_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), OracleError> {
-        admin.require_auth();
-        Self::require_admin(&env, &admin)?;
-        Self::remove_oracle_internal(&env, &oracle);
-        Ok(())
+        price_requests::fulfil(env, pair, &consensus_data);
     }
 
-    // Internal helpers
-
-    fn require_admin(env: &Env, caller: &Address) -> Result<(), OracleError> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&StorageKey::Admin)
-            .ok_or(OracleError::Unauthorized)?;
-
-        if caller != &admin {
-            return Err(OracleError::Unauthorized);
+    /// Dispersion of the submissions behind `price`: their range in BPS of it.
+    fn spread_bps(submissions: &Vec<PriceSubmission>, price: i128) -> u32 {
+        if submissions.is_empty() || price <= 0 {
+            return 0;
         }
-        Ok(())
-    }
-
-    fn get_price_submissions(env: &Env) -> Vec<PriceSubmission> {
-        env.storage()
-            .instance()
-            .get(&StorageKey::PriceSubmissions)
-            .unwrap_or(Vec::new(env))
+        let mut min = i128::MAX;
+        let mut max = i128::MIN;
+        for submission in submissions.iter() {
+            min = min.min(submission.price);
+            max = max.max(submission.price);
+        }
+        ((max - min) * 10000 / price).min(u32::MAX as i128) as u32
     }
 
     fn weighted_median(env: &Env, submissions: &Vec<PriceSubmission>) -> i128 {
@@ -478,15 +1754,19 @@ impl OracleContract {
             }
         }
 
+        Self::median(weighted_prices)
+    }
+
+    fn median(mut prices: Vec<i128>) -> i128 {
         // Sort prices
-        let len = weighted_prices.len();
+        let len = prices.len();
         for i in 0..len {
             for j in 0..(len - i - 1) {
-                let curr = weighted_prices.get(j).unwrap();
-                let next = weighted_prices.get(j + 1).unwrap();
+                let curr = prices.get(j).unwrap();
+                let next = prices.get(j + 1).unwrap();
                 if curr > next {
-                    weighted_prices.set(j, next);
-                    weighted_prices.set(j + 1, curr);
+                    prices.set(j, next);
+                    prices.set(j + 1, curr);
                 }
             }
         }
@@ -494,9 +1774,9 @@ impl OracleContract {
         // Return median
         let mid = len / 2;
         if len % 2 == 0 {
-            (weighted_prices.get(mid - 1).unwrap() + weighted_prices.get(mid).unwrap()) / 2
+            (prices.get(mid - 1).unwrap() + prices.get(mid).unwrap()) / 2
         } else {
-            weighted_prices.get(mid).unwrap()
+            prices.get(mid).unwrap()
         }
     }
 
@@ -514,28 +1794,188 @@ impl OracleContract {
         env.storage()
             .persistent()
             .set(&StorageKey::Oracles, &new_oracles);
+        bond::begin_unbonding(env, oracle);
     }
 
     /// # Summary
-    /// Get the aggregated price for an asset pair. Applies median aggregation
-    /// across all fresh price sources (staleness TTL: 300s).
+    /// Get the price for an asset pair from the first of its sources (see
+    /// `set_price_sources`) with a fresh price. By default the only source
+    /// is the last finalised oracle round (see `submit_price`). If every
+    /// source is stale, the highest-priority stale price is served.
     ///
     /// # Parameters
     /// - `env`: Soroban environment.
     /// - `pair`: The asset pair to query.
     ///
     /// # Returns
-    /// The median aggregated price.
+    /// The price with the time it was produced, the source that served it
+    /// and, for internal rounds, the number of oracles and the round id.
     ///
     /// # Errors
-    /// - [`OracleError::PriceNotFound`] — no price data for this pair.
-    /// - [`OracleError::StalePrice`] — all price sources are stale (> 300s old).
-    /// - [`OracleError::UnreliablePrice`] — sources disagree by > 10%.
-    pub fn get_price(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
+    /// - [`OracleError::PriceNotFound`] — no source has a price for this pair.
+    /// - [`OracleError::StalePrice`] — the internal price is paused for
+    ///   staleness and no other source has a price.
+    /// - [`OracleError::PriceDisputed`] — the last round is frozen by a
+    ///   dispute and no other source has a price.
+    /// - [`OracleError::PairDisabled`] — the pair is disabled in its config.
+    ///
+    /// # Breaking change
+    /// `get_price` used to return the bare `i128` median of the
+    /// `submit_pair_price` observations; that value is still served by
+    /// `get_median_price`.
+    pub fn get_price(env: Env, pair: AssetPair) -> Result<ConsensusPriceData, OracleError> {
+        let config = pair_config::require_enabled(&env, &pair)?;
+        let mut stale: Option<ConsensusPriceData> = None;
+        let mut paused = false;
+        let mut disputed = false;
+        for source in fallback::get_sources(&env, &pair).iter() {
+            let candidate = match &source {
+                PriceSource::Internal => {
+                    let consensus: Option<ConsensusPriceData> = env
+                        .storage()
+                        .persistent()
+                        .get(&StorageKey::ConsensusPrice(pair.clone()));
+                    match consensus {
+                        Some(consensus) if disputes::is_frozen(&env, &pair, consensus.round) => {
+                            disputed = true;
+                            None
+                        }
+                        Some(consensus) => {
+                            let metadata = refresh_staleness(&env, &pair);
+                            paused |= metadata.is_paused;
+                            let fresh = matches!(
+                                metadata.staleness_level,
+                                StalenessLevel::Fresh | StalenessLevel::Aging
+                            );
+                            (!metadata.is_paused).then_some((consensus, fresh))
+                        }
+                        None => None,
+                    }
+                }
+                PriceSource::Sep40(feed, asset) => fallback::external_price(&env, feed, asset, &config)
+                    .map(|(price, timestamp, fresh)| {
+                        let data = ConsensusPriceData {
+                            price,
+                            timestamp,
+                            num_oracles: 0,
+                            round: 0,
+                            source: source.clone(),
+                        };
+                        (data, fresh)
+                    }),
+            };
+            match candidate {
+                Some((data, true)) => return Ok(data),
+                Some((data, false)) if stale.is_none() => stale = Some(data),
+                _ => {}
+            }
+        }
+        match stale {
+            Some(data) => Ok(data),
+            None if disputed => Err(OracleError::PriceDisputed),
+            None if paused => Err(OracleError::StalePrice),
+            None => Err(OracleError::PriceNotFound),
+        }
+    }
+
+    /// Deprecated: the value `get_price` returned before it served oracle
+    /// rounds. Prefer `get_price`, or `get_price_with_confidence` for the
+    /// same median with its confidence.
+    ///
+    /// # Errors
+    /// - Any error of `get_price_with_confidence`.
+    pub fn get_median_price(env: Env, pair: AssetPair) -> Result<i128, OracleError> {
         let (price, _) = Self::get_price_with_confidence(env, pair)?;
         Ok(price)
     }
 
+    /// Deprecated: the last finalised round for `pair`, read directly without
+    /// consulting fallback sources, staleness or disputes. Kept for callers of
+    /// the former global `get_consensus_price`; prefer `get_price`.
+    pub fn get_consensus_price(env: Env, pair: AssetPair) -> Option<ConsensusPriceData> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::ConsensusPrice(pair))
+    }
+
+    /// Set the ordered sources `get_price` tries for `pair` (admin only).
+    ///
+    /// # Errors
+    /// - [`OracleError::InvalidPath`] — empty list or more than
+    ///   `MAX_PRICE_SOURCES` entries.
+    /// - [`OracleError::InvalidAsset`] — a source is listed twice.
+    pub fn set_price_sources(
+        env: Env,
+        admin: Address,
+        pair: AssetPair,
+        sources: Vec<PriceSource>,
+    ) -> Result<(), OracleError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        fallback::set_sources(&env, &pair, &sources)
+    }
+
+    /// Sources `get_price` tries for `pair`, highest priority first.
+    pub fn get_price_sources(env: Env, pair: AssetPair) -> Vec<PriceSource> {
+        fallback::get_sources(&env, &pair)
+    }
+
+    /// # Summary
+    /// The last finalised round's price together with the spread of the
+    /// submissions it was aggregated from. Slippage checks and position
+    /// sizing can widen tolerances or size down as `spread_bps` grows.
+    ///
+    /// # Errors
+    /// - [`OracleError::PriceNotFound`] — no round has completed for this pair.
+    /// - [`OracleError::StalePrice`] — the pair is paused for staleness.
+    pub fn get_price_confidence(
+        env: Env,
+        pair: AssetPair,
+    ) -> Result<PriceWithConfidence, OracleError> {
+        let confidence = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::PriceConfidence(pair.clone()))
+            .ok_or(OracleError::PriceNotFound)?;
+        if refresh_staleness(&env, &pair).is_paused {
+            return Err(OracleError::StalePrice);
+        }
+        Ok(confidence)
+    }
+
+    /// Update tracking for a pair, with the staleness level as of now. A pair
+    /// that has reached `Critical` is paused until its next price update.
+    pub fn get_price_metadata(env: Env, pair: AssetPair) -> PriceMetadata {
+        let mut metadata = refresh_staleness(&env, &pair);
+        metadata.update_count_24h = staleness::update_count_24h(&env, &pair);
+        metadata
+    }
+
+    /// Update cadence and freshness of a pair's feed: updates over the last
+    /// 24 hours, the average interval between them, the current staleness
+    /// level and heartbeat status.
+    pub fn get_feed_health(env: Env, pair: AssetPair) -> FeedHealth {
+        let metadata = refresh_staleness(&env, &pair);
+        let heartbeat = staleness::check_oracle_heartbeat(&env, &pair);
+        FeedHealth {
+            last_update: metadata.last_update,
+            update_count_24h: staleness::update_count_24h(&env, &pair),
+            avg_update_interval: metadata.avg_update_interval,
+            age: env.ledger().timestamp().saturating_sub(metadata.last_update),
+            staleness_level: metadata.staleness_level,
+            heartbeat: heartbeat.status,
+            is_paused: metadata.is_paused,
+        }
+    }
+
+    /// # Summary
+    /// Median across all fresh `submit_pair_price` observations for a pair
+    /// (staleness TTL: 300s), with the median source's confidence.
+    ///
+    /// # Errors
+    /// - [`OracleError::PriceNotFound`] — no price data for this pair.
+    /// - [`OracleError::StalePrice`] — all price sources are stale (> 300s old).
+    /// - [`OracleError::UnreliablePrice`] — sources disagree by > 10%.
     pub fn get_price_with_confidence(
         env: Env,
         pair: AssetPair,
@@ -627,6 +2067,7 @@ impl OracleContract {
             return Err(OracleError::Unauthorized);
         }
 
+        let price = pair_config::normalize_price(&pair_config::get_config(&env, &pair), price)?;
         let key = StorageKey::PriceMap(pair.clone());
         let mut prices: Vec<PriceData> = env
             .storage()
@@ -679,7 +2120,7 @@ impl OracleContract {
 }
 
 // Internal helper to represent the SDEX query
-fn fetch_sdex_orderbook(env: &Env, pair: &AssetPair) -> Result<OrderBook, OracleError> {
+fn fetch_sdex_orderbook(_env: &Env, _pair: &AssetPair) -> Result<OrderBook, OracleError> {
     // Note: Actual Soroban host functions for SDEX are currently limited
     // to Liquidity Pool swaps. For Order Books, one typically uses
     // a Cross-Chain/Bridge approach or a Trusted Observer.
@@ -713,13 +2154,46 @@ pub fn on_price_update(env: &Env, pair: AssetPair) {
         );
     }
 
-    metadata.last_update = env.ledger().timestamp();
+    let now = env.ledger().timestamp();
+    if metadata.last_update > 0 {
+        let interval = now.saturating_sub(metadata.last_update);
+        metadata.avg_update_interval = if metadata.avg_update_interval == 0 {
+            interval
+        } else {
+            (metadata.avg_update_interval * 3 + interval) / 4
+        };
+    }
+
+    metadata.last_update = now;
     metadata.last_update_ledger = env.ledger().sequence();
-    metadata.update_count_24h += 1;
+    metadata.update_count_24h = staleness::record_update(env, &pair);
+    metadata.staleness_level = StalenessLevel::Fresh;
     metadata.last_heartbeat_status = OracleStatus::Healthy;
     staleness::set_metadata(env, &pair, metadata);
 }
 
+/// Store the pair's current staleness level and pause it once it turns
+/// `Critical`, so reads fail instead of serving an old price.
+fn refresh_staleness(env: &Env, pair: &AssetPair) -> PriceMetadata {
+    let mut metadata = staleness::get_metadata(env, pair);
+    let level = staleness::check_staleness(env, pair.clone());
+    let pause = level == StalenessLevel::Critical && !metadata.is_paused;
+    if level == metadata.staleness_level && !pause {
+        return metadata;
+    }
+
+    metadata.staleness_level = level;
+    if pause {
+        metadata.is_paused = true;
+        env.events().publish(
+            (symbol_short!("PAUSED"), pair.clone()),
+            env.ledger().timestamp(),
+        );
+    }
+    staleness::set_metadata(env, pair, metadata.clone());
+    metadata
+}
+
 fn maybe_emit_heartbeat_missed(env: &Env, pair: &AssetPair, health: &OracleHealth) {
     if health.status == OracleStatus::Healthy {
         return;
@@ -746,3 +2220,6 @@ mod test_health;
 
 #[cfg(test)]
 mod test_admin_transfer;
+
+#[cfg(test)]
+mod test_governance;
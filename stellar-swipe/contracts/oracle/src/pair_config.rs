@@ -0,0 +1,156 @@
+//! Per-pair oracle configuration.
+//!
+//! Every pair is governed by a `PairConfig`: the decimals its price is quoted
+//! in (by default those of its quote asset), the age thresholds behind `check_staleness`, the outlier cut-off used
+//! when a round is aggregated, the submissions that close a round and whether
+//! the pair is served at all. The admin can install a config for a single
+//! pair; pairs without one follow the contract-wide defaults, which the admin
+//! and governance `UpdateParameter` proposals adjust.
+//!
+//! Prices arrive in the pair's `decimals` and are normalized to
+//! `CANONICAL_DECIMALS` before they are stored, so everything the contract
+//! returns shares one precision.
+
+use soroban_sdk::{contracttype, Env};
+use stellar_swipe_common::{to_canonical, Asset, AssetPair, CANONICAL_DECIMALS, MAX_DECIMALS};
+
+use crate::errors::OracleError;
+use crate::types::StorageKey;
+use crate::{DEFAULT_MAX_DEVIATION_BPS, DEFAULT_MIN_SUBMISSIONS};
+
+/// Default upper age bounds, in seconds, of the `Fresh`, `Aging` and `Stale`
+/// staleness levels. Anything older is `Critical`.
+pub const DEFAULT_FRESH_SECS: u64 = 120;
+pub const DEFAULT_AGING_SECS: u64 = 300;
+pub const DEFAULT_STALE_SECS: u64 = 900;
+
+/// Largest `decimals` a pair or asset may be quoted in.
+pub const MAX_PAIR_DECIMALS: u32 = MAX_DECIMALS;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairConfig {
+    /// Decimals the pair's price is quoted in.
+    pub decimals: u32,
+    /// Prices up to this age (seconds) are `Fresh`.
+    pub fresh_secs: u64,
+    /// ... up to this age `Aging`.
+    pub aging_secs: u64,
+    /// ... up to this age `Stale`; older prices are `Critical`.
+    pub stale_secs: u64,
+    /// Submissions further than this from the round median (BPS) are dropped.
+    pub max_deviation_bps: i128,
+    /// Submissions that close a round.
+    pub min_sources: u32,
+    /// Disabled pairs accept no submissions and serve no price.
+    pub enabled: bool,
+}
+
+#[contracttype]
+pub enum PairConfigKey {
+    Pair(AssetPair),
+    /// Decimals an asset's amounts are expressed in, if not the canonical 7.
+    AssetDecimals(Asset),
+}
+
+/// Decimals `asset` uses; `CANONICAL_DECIMALS` unless registered otherwise.
+pub fn asset_decimals(env: &Env, asset: &Asset) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&PairConfigKey::AssetDecimals(asset.clone()))
+        .unwrap_or(CANONICAL_DECIMALS)
+}
+
+pub fn set_asset_decimals(env: &Env, asset: &Asset, decimals: u32) -> Result<(), OracleError> {
+    if decimals > MAX_PAIR_DECIMALS {
+        return Err(OracleError::InvalidPairConfig);
+    }
+    let key = PairConfigKey::AssetDecimals(asset.clone());
+    if decimals == CANONICAL_DECIMALS {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &decimals);
+    }
+    Ok(())
+}
+
+/// Contract-wide defaults for pairs without their own config. A pair is
+/// quoted in the decimals of its quote asset.
+pub fn default_config(env: &Env, pair: &AssetPair) -> PairConfig {
+    let storage = env.storage().instance();
+    let stale_secs: u64 = storage
+        .get(&StorageKey::PriceTtl)
+        .unwrap_or(DEFAULT_STALE_SECS);
+    PairConfig {
+        decimals: asset_decimals(env, &pair.quote),
+        fresh_secs: DEFAULT_FRESH_SECS.min(stale_secs),
+        aging_secs: DEFAULT_AGING_SECS.min(stale_secs),
+        stale_secs,
+        max_deviation_bps: storage
+            .get(&StorageKey::MaxDeviationBps)
+            .unwrap_or(DEFAULT_MAX_DEVIATION_BPS),
+        min_sources: storage
+            .get(&StorageKey::MinSubmissions)
+            .unwrap_or(DEFAULT_MIN_SUBMISSIONS),
+        enabled: true,
+    }
+}
+
+/// The pair's own config, if one is installed.
+pub fn get_override(env: &Env, pair: &AssetPair) -> Option<PairConfig> {
+    env.storage()
+        .persistent()
+        .get(&PairConfigKey::Pair(pair.clone()))
+}
+
+/// The config in force for `pair`.
+pub fn get_config(env: &Env, pair: &AssetPair) -> PairConfig {
+    get_override(env, pair).unwrap_or_else(|| default_config(env, pair))
+}
+
+pub fn validate(config: &PairConfig) -> Result<(), OracleError> {
+    let valid = config.decimals <= MAX_PAIR_DECIMALS
+        && config.fresh_secs > 0
+        && config.fresh_secs <= config.aging_secs
+        && config.aging_secs <= config.stale_secs
+        && config.max_deviation_bps > 0
+        && config.max_deviation_bps <= 10_000
+        && config.min_sources > 0;
+    if !valid {
+        return Err(OracleError::InvalidPairConfig);
+    }
+    Ok(())
+}
+
+/// Install, replace or (with `None`) remove the config of one pair.
+pub fn set_override(
+    env: &Env,
+    pair: &AssetPair,
+    config: Option<PairConfig>,
+) -> Result<(), OracleError> {
+    let key = PairConfigKey::Pair(pair.clone());
+    match config {
+        Some(config) => {
+            validate(&config)?;
+            env.storage().persistent().set(&key, &config);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+    Ok(())
+}
+
+pub fn require_enabled(env: &Env, pair: &AssetPair) -> Result<PairConfig, OracleError> {
+    let config = get_config(env, pair);
+    if !config.enabled {
+        return Err(OracleError::PairDisabled);
+    }
+    Ok(config)
+}
+
+/// A price quoted in the pair's `decimals`, at `CANONICAL_DECIMALS`.
+pub fn normalize_price(config: &PairConfig, price: i128) -> Result<i128, OracleError> {
+    match to_canonical(price, config.decimals) {
+        Some(normalized) if normalized > 0 => Ok(normalized),
+        _ => Err(OracleError::InvalidPrice),
+    }
+}
@@ -0,0 +1,142 @@
+//! Pull-based price requests.
+//!
+//! A consumer that cannot act on a cached price calls `request_price` with
+//! the contract and function to call back. The request waits on the pair's
+//! open round; when that round closes, `fulfil` invokes every pending
+//! callback with `(request_id, pair, ConsensusPriceData)`. A callback that
+//! fails is reported in an event and dropped, so a broken consumer can never
+//! block a round from closing.
+
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, Val, Vec};
+use stellar_swipe_common::AssetPair;
+
+use crate::errors::OracleError;
+use crate::events;
+use crate::types::ConsensusPriceData;
+
+/// Requests that may wait on one pair's round at once. Bounds the callbacks
+/// the oracle submission that closes the round has to pay for.
+pub const MAX_PENDING_REQUESTS: u32 = 10;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceRequest {
+    pub id: u64,
+    pub requester: Address,
+    pub pair: AssetPair,
+    pub callback_contract: Address,
+    pub callback_fn: Symbol,
+    /// Round the request waits on.
+    pub round: u64,
+    pub created_at: u64,
+}
+
+#[contracttype]
+pub enum PriceRequestKey {
+    Counter,
+    /// Requests waiting on the pair's open round, oldest first.
+    Pending(AssetPair),
+}
+
+pub fn get_pending(env: &Env, pair: &AssetPair) -> Vec<PriceRequest> {
+    env.storage()
+        .persistent()
+        .get(&PriceRequestKey::Pending(pair.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_pending(env: &Env, pair: &AssetPair, requests: &Vec<PriceRequest>) {
+    let key = PriceRequestKey::Pending(pair.clone());
+    if requests.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, requests);
+    }
+}
+
+/// Queue a callback for the close of `pair`'s open round `round`.
+pub fn request(
+    env: &Env,
+    requester: Address,
+    pair: AssetPair,
+    round: u64,
+    callback_contract: Address,
+    callback_fn: Symbol,
+) -> Result<u64, OracleError> {
+    let mut pending = get_pending(env, &pair);
+    if pending.len() >= MAX_PENDING_REQUESTS {
+        return Err(OracleError::TooManyPriceRequests);
+    }
+
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&PriceRequestKey::Counter)
+        .unwrap_or(0u64)
+        + 1;
+    env.storage().instance().set(&PriceRequestKey::Counter, &id);
+
+    let request = PriceRequest {
+        id,
+        requester,
+        pair: pair.clone(),
+        callback_contract,
+        callback_fn,
+        round,
+        created_at: env.ledger().timestamp(),
+    };
+    pending.push_back(request.clone());
+    set_pending(env, &pair, &pending);
+
+    events::emit_price_requested(env, &request);
+    Ok(id)
+}
+
+/// Withdraw a pending request. Only its requester may cancel it.
+pub fn cancel(
+    env: &Env,
+    requester: &Address,
+    pair: &AssetPair,
+    id: u64,
+) -> Result<(), OracleError> {
+    let mut pending = get_pending(env, pair);
+    let index = pending
+        .iter()
+        .position(|r| r.id == id)
+        .ok_or(OracleError::PriceRequestNotFound)?;
+    if pending.get(index as u32).unwrap().requester != *requester {
+        return Err(OracleError::Unauthorized);
+    }
+    pending.remove(index as u32);
+    set_pending(env, pair, &pending);
+    Ok(())
+}
+
+/// Answer every request waiting on the round that produced `price`.
+pub fn fulfil(env: &Env, pair: &AssetPair, price: &ConsensusPriceData) {
+    let pending = get_pending(env, pair);
+    if pending.is_empty() {
+        return;
+    }
+    // Clear the queue before calling out so no callback sees its own request.
+    set_pending(env, pair, &Vec::new(env));
+
+    for request in pending.iter() {
+        let args: Vec<Val> = Vec::from_array(
+            env,
+            [
+                request.id.into_val(env),
+                pair.into_val(env),
+                price.into_val(env),
+            ],
+        );
+        let delivered = env
+            .try_invoke_contract::<Val, soroban_sdk::Error>(
+                &request.callback_contract,
+                &request.callback_fn,
+                args,
+            )
+            .is_ok();
+        events::emit_price_request_fulfilled(env, &request, price.round, delivered);
+    }
+}
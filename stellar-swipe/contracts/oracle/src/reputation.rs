@@ -1,5 +1,5 @@
 use soroban_sdk::{Address, Env, Map};
-use stellar_swipe_common::{BASIS_POINTS_DENOMINATOR_I128, SECONDS_PER_WEEK};
+use stellar_swipe_common::{BASIS_POINTS_DENOMINATOR_I128, SECONDS_PER_DAY, SECONDS_PER_WEEK};
 
 use crate::types::{OracleReputation, StorageKey};
 
@@ -7,6 +7,19 @@ const ACCURACY_THRESHOLD_TIGHT: i128 = 100; // 1% in basis points
 const ACCURACY_THRESHOLD_MODERATE: i128 = 500; // 5% in basis points
 const MAJOR_DEVIATION_THRESHOLD: i128 = 2000; // 20% in basis points
 
+/// Consecutive rounds above `p_dev` before an oracle is auto-slashed.
+pub const DEFAULT_DEVIATION_STREAK_LIMIT: u32 = 3;
+/// Minimum time between two persistent-deviation slashes of the same oracle.
+pub const PERSISTENT_DEVIATION_COOLDOWN: u64 = SECONDS_PER_DAY;
+pub const PERSISTENT_DEVIATION_PENALTY: u32 = 25;
+
+/// Score new and long-idle oracles settle at.
+pub const NEUTRAL_REPUTATION: u32 = 50;
+/// Points per idle day a score drifts back toward `NEUTRAL_REPUTATION`.
+pub const REPUTATION_DECAY_PER_DAY: u32 = 1;
+/// Most a score can rise in one round, so recovery after a slash is gradual.
+pub const REPUTATION_RECOVERY_STEP: u32 = 5;
+
 pub fn get_oracle_stats(env: &Env, oracle: &Address) -> OracleReputation {
     let stats_map: Map<Address, OracleReputation> = env
         .storage()
@@ -18,9 +31,10 @@ pub fn get_oracle_stats(env: &Env, oracle: &Address) -> OracleReputation {
         total_submissions: 0,
         accurate_submissions: 0,
         avg_deviation: 0,
-        reputation_score: 50,
+        reputation_score: NEUTRAL_REPUTATION,
         weight: 1,
         last_slash: 0,
+        last_update: 0,
     })
 }
 
@@ -62,20 +76,70 @@ pub fn calculate_reputation(env: &Env, oracle: &Address) -> u32 {
     total.min(100).max(0) as u32
 }
 
-pub fn adjust_oracle_weight(env: &Env, oracle: &Address) -> u32 {
-    let reputation = calculate_reputation(env, oracle);
+/// Stored score after idle decay toward `NEUTRAL_REPUTATION`, along with the
+/// timestamp the decay has been accounted up to.
+fn decayed_score(env: &Env, stats: &OracleReputation) -> (u32, u64) {
+    let now = env.ledger().timestamp();
+    let idle_days = now.saturating_sub(stats.last_update) / SECONDS_PER_DAY;
+    let decay = idle_days
+        .saturating_mul(REPUTATION_DECAY_PER_DAY as u64)
+        .min(u32::MAX as u64) as u32;
 
-    let new_weight = match reputation {
+    let score = if stats.reputation_score > NEUTRAL_REPUTATION {
+        stats
+            .reputation_score
+            .saturating_sub(decay)
+            .max(NEUTRAL_REPUTATION)
+    } else {
+        stats
+            .reputation_score
+            .saturating_add(decay)
+            .min(NEUTRAL_REPUTATION)
+    };
+    (score, stats.last_update + idle_days * SECONDS_PER_DAY)
+}
+
+/// Current reputation including idle decay not yet written back. `weight` is
+/// the one in force until the next round is finalised.
+pub fn current_reputation(env: &Env, oracle: &Address) -> OracleReputation {
+    let mut stats = get_oracle_stats(env, oracle);
+    stats.reputation_score = decayed_score(env, &stats).0;
+    stats
+}
+
+fn weight_for(reputation: u32) -> u32 {
+    match reputation {
         90..=100 => 10,
         75..=89 => 5,
         60..=74 => 2,
         50..=59 => 1,
         _ => 0,
-    };
+    }
+}
 
+/// Refresh the oracle's stored score and weight at the end of a round.
+///
+/// Idle time first decays the score toward neutral. If the oracle submitted
+/// this round the score then tracks `calculate_reputation`: drops apply at
+/// once, gains are capped at `REPUTATION_RECOVERY_STEP` per round.
+pub fn adjust_oracle_weight(env: &Env, oracle: &Address, submitted: bool) -> u32 {
     let mut stats = get_oracle_stats(env, oracle);
+    let (mut score, decayed_until) = decayed_score(env, &stats);
+    stats.last_update = decayed_until;
+
+    if submitted {
+        let target = calculate_reputation(env, oracle);
+        score = if target < score {
+            target
+        } else {
+            target.min(score + REPUTATION_RECOVERY_STEP)
+        };
+        stats.last_update = env.ledger().timestamp();
+    }
+
+    let new_weight = weight_for(score);
     stats.weight = new_weight;
-    stats.reputation_score = reputation;
+    stats.reputation_score = score;
     save_oracle_stats(env, oracle, &stats);
 
     new_weight
@@ -109,18 +173,81 @@ pub fn track_oracle_accuracy(
     save_oracle_stats(env, oracle, &stats);
 }
 
-pub fn slash_oracle(env: &Env, oracle: &Address, reason: SlashReason) {
+/// Record a submission dropped as an outlier during aggregation. It counts
+/// toward the oracle's submissions and average deviation but never as accurate.
+pub fn record_rejected_submission(env: &Env, oracle: &Address, deviation_bps: i128) {
+    let mut stats = get_oracle_stats(env, oracle);
+
+    stats.total_submissions += 1;
+    let total_dev = stats.avg_deviation * (stats.total_submissions - 1) as i128;
+    stats.avg_deviation = (total_dev + deviation_bps) / stats.total_submissions as i128;
+
+    save_oracle_stats(env, oracle, &stats);
+}
+
+/// Returns the amount slashed from the oracle's bond.
+pub fn slash_oracle(env: &Env, oracle: &Address, reason: SlashReason) -> i128 {
     let mut stats = get_oracle_stats(env, oracle);
 
     let penalty = match reason {
         SlashReason::MajorDeviation => 20,
         SlashReason::SignatureFailure => 30,
+        SlashReason::PersistentDeviation => PERSISTENT_DEVIATION_PENALTY,
+        SlashReason::UpheldDispute => 30,
     };
 
-    stats.reputation_score = stats.reputation_score.saturating_sub(penalty);
+    let (score, decayed_until) = decayed_score(env, &stats);
+    stats.reputation_score = score.saturating_sub(penalty);
+    stats.last_update = decayed_until;
     stats.last_slash = env.ledger().timestamp();
 
     save_oracle_stats(env, oracle, &stats);
+    crate::bond::slash(env, oracle, reason)
+}
+
+pub fn get_deviation_streak(env: &Env, oracle: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::DeviationStreak(oracle.clone()))
+        .unwrap_or(0)
+}
+
+/// Update the oracle's run of rounds with a rolling deviation above
+/// `max_deviation` and slash it once the run reaches `streak_limit`.
+///
+/// A slash cuts reputation, halves the current weight and restarts the run.
+/// While `last_slash` is within the cooldown the run keeps counting but no
+/// further slash is applied. Returns `true` if the oracle was slashed.
+pub fn check_persistent_deviation(
+    env: &Env,
+    oracle: &Address,
+    max_deviation: i128,
+    streak_limit: u32,
+) -> bool {
+    let key = StorageKey::DeviationStreak(oracle.clone());
+    let stats = get_oracle_stats(env, oracle);
+
+    if stats.avg_deviation <= max_deviation {
+        env.storage().persistent().remove(&key);
+        return false;
+    }
+
+    let streak = get_deviation_streak(env, oracle) + 1;
+    let now = env.ledger().timestamp();
+    let cooling_down =
+        stats.last_slash != 0 && now - stats.last_slash < PERSISTENT_DEVIATION_COOLDOWN;
+
+    if streak < streak_limit || cooling_down {
+        env.storage().persistent().set(&key, &streak);
+        return false;
+    }
+
+    slash_oracle(env, oracle, SlashReason::PersistentDeviation);
+    let mut stats = get_oracle_stats(env, oracle);
+    stats.weight /= 2;
+    save_oracle_stats(env, oracle, &stats);
+    env.storage().persistent().remove(&key);
+    true
 }
 
 pub fn should_remove_oracle(env: &Env, oracle: &Address) -> bool {
@@ -139,7 +266,12 @@ pub fn should_remove_oracle(env: &Env, oracle: &Address) -> bool {
     reputation < 50
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SlashReason {
     MajorDeviation,
     SignatureFailure,
+    /// Rolling deviation above `p_dev` for too many consecutive rounds.
+    PersistentDeviation,
+    /// Behind a round governance found wrong on dispute.
+    UpheldDispute,
 }
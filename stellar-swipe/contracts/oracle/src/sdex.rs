@@ -17,7 +17,7 @@ pub struct OrderBook {
 }
 
 /// Calculate Mid-Market Spot Price
-pub fn calculate_spot_price(env: &Env, orderbook: OrderBook) -> Result<i128, OracleError> {
+pub fn calculate_spot_price(_env: &Env, orderbook: OrderBook) -> Result<i128, OracleError> {
     if orderbook.bids.is_empty() || orderbook.asks.is_empty() {
         return Err(OracleError::EmptyOrderBook);
     }
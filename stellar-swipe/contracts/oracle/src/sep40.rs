@@ -0,0 +1,134 @@
+//! SEP-40 price feed interface.
+//!
+//! Exposes published prices through the standard SEP-40 oracle methods so
+//! other Soroban protocols can consume them without custom integration code.
+//! SEP-40 identifies assets by contract address or symbol, so the admin maps
+//! each SEP-40 asset onto the `AssetPair` quoting it in the base currency.
+//! Prices are returned with `SEP40_DECIMALS` decimals, the scale every
+//! published price already uses. A pair that `get_price` would refuse to
+//! serve (disabled, paused for staleness, or with its last round under
+//! dispute) reads as having no price.
+
+use soroban_sdk::{contracttype, Address, Env, Map, Symbol, Vec};
+use stellar_swipe_common::AssetPair;
+
+use crate::disputes;
+use crate::errors::OracleError;
+use crate::history::{self, PriceObservation};
+use crate::pair_config;
+use crate::storage;
+use crate::types::{ConsensusPriceData, StorageKey};
+
+/// Decimals of every price returned through SEP-40.
+pub const SEP40_DECIMALS: u32 = 7;
+
+/// SEP-40 asset identifier.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Sep40Asset {
+    /// Stellar asset contract address.
+    Stellar(Address),
+    /// Any other asset, by symbol.
+    Other(Symbol),
+}
+
+/// SEP-40 price record.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sep40PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+pub enum Sep40Key {
+    Base,
+    /// SEP-40 asset → pair quoting it in the base currency.
+    Assets,
+}
+
+impl From<PriceObservation> for Sep40PriceData {
+    fn from(observation: PriceObservation) -> Self {
+        Sep40PriceData {
+            price: observation.price,
+            timestamp: observation.timestamp,
+        }
+    }
+}
+
+pub fn get_base(env: &Env) -> Option<Sep40Asset> {
+    env.storage().instance().get(&Sep40Key::Base)
+}
+
+pub fn set_base(env: &Env, asset: &Sep40Asset) {
+    env.storage().instance().set(&Sep40Key::Base, asset);
+}
+
+fn get_asset_pairs(env: &Env) -> Map<Sep40Asset, AssetPair> {
+    env.storage()
+        .persistent()
+        .get(&Sep40Key::Assets)
+        .unwrap_or_else(|| Map::new(env))
+}
+
+/// Map `asset` onto `pair`, which must be quoted in the base currency.
+pub fn register_asset(env: &Env, asset: Sep40Asset, pair: AssetPair) -> Result<(), OracleError> {
+    if pair.quote != storage::get_base_currency(env) {
+        return Err(OracleError::InvalidAsset);
+    }
+    let mut assets = get_asset_pairs(env);
+    assets.set(asset, pair);
+    env.storage().persistent().set(&Sep40Key::Assets, &assets);
+    Ok(())
+}
+
+pub fn assets(env: &Env) -> Vec<Sep40Asset> {
+    get_asset_pairs(env).keys()
+}
+
+/// Seconds between the history buckets historical lookups are served from.
+pub fn resolution() -> u32 {
+    history::BUCKET_SIZE as u32
+}
+
+/// The pair behind `asset`, if `get_price` would serve it: the pair is
+/// enabled, not paused for staleness, and its last round is not disputed.
+fn servable_pair(env: &Env, asset: &Sep40Asset) -> Option<AssetPair> {
+    let pair = get_asset_pairs(env).get(asset.clone())?;
+    pair_config::require_enabled(env, &pair).ok()?;
+    let consensus: Option<ConsensusPriceData> = env
+        .storage()
+        .persistent()
+        .get(&StorageKey::ConsensusPrice(pair.clone()));
+    if consensus.is_some_and(|consensus| disputes::is_frozen(env, &pair, consensus.round)) {
+        return None;
+    }
+    if crate::refresh_staleness(env, &pair).is_paused {
+        return None;
+    }
+    Some(pair)
+}
+
+pub fn lastprice(env: &Env, asset: &Sep40Asset) -> Option<Sep40PriceData> {
+    let pair = servable_pair(env, asset)?;
+    history::get_observations(env, &pair).last().map(Sep40PriceData::from)
+}
+
+pub fn price(env: &Env, asset: &Sep40Asset, timestamp: u64) -> Option<Sep40PriceData> {
+    let pair = servable_pair(env, asset)?;
+    history::observation_at(env, &pair, timestamp).map(Sep40PriceData::from)
+}
+
+/// Up to `records` most recent prices, newest first. `None` for unknown
+/// assets and for pairs that cannot currently be served.
+pub fn prices(env: &Env, asset: &Sep40Asset, records: u32) -> Option<Vec<Sep40PriceData>> {
+    let pair = servable_pair(env, asset)?;
+    let mut result = Vec::new(env);
+    if records == 0 {
+        return Some(result);
+    }
+    for observation in history::get_price_history(env, &pair, records).iter() {
+        result.push_back(Sep40PriceData::from(observation));
+    }
+    Some(result)
+}
@@ -0,0 +1,107 @@
+//! Relayed, off-chain signed price submissions.
+//!
+//! An oracle may register an Ed25519 key and sign its prices off-chain
+//! instead of authorizing every `submit_price` call itself. A relayer then
+//! posts a batch of those prices through `submit_signed_prices`, paying for
+//! one transaction on behalf of many oracles. Each price is checked against
+//! the signer's registered key and its nonce, which has to follow the
+//! oracle's last accepted one, so a signed price can be relayed only once.
+//! An accepted price enters the round as the signing oracle's submission and
+//! counts towards that oracle's reputation, not the relayer's.
+//!
+//! The signed message is the XDR encoding of
+//! `(oracle contract, oracle, pair, price, timestamp, nonce)`; binding the
+//! contract address keeps a signature from being replayed against another
+//! deployment.
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env};
+use stellar_swipe_common::{replay_protection, AssetPair, ReplayError};
+
+use crate::errors::OracleError;
+use crate::MAX_SUBMISSION_AGE_SECS;
+
+/// Largest batch `submit_signed_prices` accepts.
+pub const MAX_SIGNED_BATCH: u32 = 20;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedPrice {
+    pub oracle: Address,
+    pub pair: AssetPair,
+    /// Quoted in the pair's `decimals`, as for `submit_price`.
+    pub price: i128,
+    pub timestamp: u64,
+    /// Must be one more than the oracle's last accepted nonce.
+    pub nonce: u64,
+    pub signature: BytesN<64>,
+}
+
+#[contracttype]
+pub enum SignedPriceKey {
+    SigningKey(Address),
+}
+
+pub fn get_signing_key(env: &Env, oracle: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&SignedPriceKey::SigningKey(oracle.clone()))
+}
+
+pub fn set_signing_key(env: &Env, oracle: &Address, key: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&SignedPriceKey::SigningKey(oracle.clone()), key);
+}
+
+/// Last nonce accepted from `oracle`; 0 before its first signed price.
+pub fn get_nonce(env: &Env, oracle: &Address) -> u64 {
+    replay_protection::current_nonce(env, oracle)
+}
+
+/// The message an oracle signs for one price.
+pub fn payload(
+    env: &Env,
+    oracle: &Address,
+    pair: &AssetPair,
+    price: i128,
+    timestamp: u64,
+    nonce: u64,
+) -> Bytes {
+    (
+        env.current_contract_address(),
+        oracle.clone(),
+        pair.clone(),
+        price,
+        timestamp,
+        nonce,
+    )
+        .to_xdr(env)
+}
+
+/// Check the signature and nonce of `signed` and consume the nonce. An
+/// invalid signature aborts the whole invocation.
+pub fn verify(env: &Env, signed: &SignedPrice) -> Result<(), OracleError> {
+    let key = get_signing_key(env, &signed.oracle).ok_or(OracleError::SigningKeyNotRegistered)?;
+    let message = payload(
+        env,
+        &signed.oracle,
+        &signed.pair,
+        signed.price,
+        signed.timestamp,
+        signed.nonce,
+    );
+    env.crypto().ed25519_verify(&key, &message, &signed.signature);
+
+    let digest: Bytes = env.crypto().sha256(&message).into();
+    replay_protection::verify_and_commit(
+        env,
+        &signed.oracle,
+        signed.nonce,
+        digest,
+        signed.timestamp.saturating_add(MAX_SUBMISSION_AGE_SECS),
+    )
+    .map_err(|e| match e {
+        ReplayError::Expired => OracleError::StalePrice,
+        ReplayError::InvalidNonce | ReplayError::DuplicateTx => OracleError::InvalidNonce,
+    })
+}
@@ -1,13 +1,29 @@
-use soroban_sdk::{contracttype, Env};
-use stellar_swipe_common::AssetPair;
+use soroban_sdk::{contracttype, BytesN, Env, Vec};
+use stellar_swipe_common::{AssetPair, SECONDS_PER_HOUR};
+
+use crate::pair_config;
+use crate::storage::pair_id;
 
 pub const MAX_PRICE_AGE_LEDGERS: u32 = 60;
 pub const ORACLE_DEAD_THRESHOLD_LEDGERS: u32 = 1_440;
 
+/// Hourly buckets behind `update_count_24h`.
+const UPDATE_WINDOW_HOURS: u64 = 24;
+
 #[contracttype]
 #[derive(Clone)]
 enum StaleStorageKey {
     Meta(AssetPair),
+    /// Keyed by [`pair_id`].
+    Updates(BytesN<32>),
+}
+
+/// Updates recorded for a pair in one hour.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct HourlyUpdates {
+    hour: u64,
+    count: u32,
 }
 
 #[contracttype]
@@ -41,13 +57,29 @@ pub struct OracleHealth {
 pub struct PriceMetadata {
     pub last_update: u64,
     pub last_update_ledger: u32,
+    /// Updates in the trailing 24 hours (hourly resolution).
     pub update_count_24h: u32,
+    /// Moving average of the seconds between updates.
     pub avg_update_interval: u64,
     pub staleness_level: StalenessLevel,
     pub is_paused: bool,
     pub last_heartbeat_status: OracleStatus,
 }
 
+/// Snapshot of a feed's update cadence and freshness, for monitoring.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedHealth {
+    pub last_update: u64,
+    pub update_count_24h: u32,
+    pub avg_update_interval: u64,
+    /// Seconds since the last update.
+    pub age: u64,
+    pub staleness_level: StalenessLevel,
+    pub heartbeat: OracleStatus,
+    pub is_paused: bool,
+}
+
 pub fn default_metadata() -> PriceMetadata {
     PriceMetadata {
         last_update: 0,
@@ -77,16 +109,64 @@ pub fn set_metadata(env: &Env, pair: &AssetPair, metadata: PriceMetadata) {
         .set(&StaleStorageKey::Meta(pair.clone()), &metadata);
 }
 
+/// Buckets still inside the 24h window ending at `now`.
+fn load_updates(env: &Env, pair: &AssetPair, now: u64) -> Vec<HourlyUpdates> {
+    let oldest = (now / SECONDS_PER_HOUR).saturating_sub(UPDATE_WINDOW_HOURS - 1);
+    let stored: Vec<HourlyUpdates> = env
+        .storage()
+        .persistent()
+        .get(&StaleStorageKey::Updates(pair_id(env, pair)))
+        .unwrap_or(Vec::new(env));
+    let mut updates = Vec::new(env);
+    for bucket in stored.iter() {
+        if bucket.hour >= oldest {
+            updates.push_back(bucket);
+        }
+    }
+    updates
+}
+
+/// Count an update at the current ledger time. Returns the updates seen in
+/// the last 24 hours, this one included.
+pub fn record_update(env: &Env, pair: &AssetPair) -> u32 {
+    let now = env.ledger().timestamp();
+    let hour = now / SECONDS_PER_HOUR;
+    let mut updates = load_updates(env, pair, now);
+    match updates.last() {
+        Some(mut bucket) if bucket.hour == hour => {
+            bucket.count += 1;
+            updates.set(updates.len() - 1, bucket);
+        }
+        _ => updates.push_back(HourlyUpdates { hour, count: 1 }),
+    }
+    env.storage()
+        .persistent()
+        .set(&StaleStorageKey::Updates(pair_id(env, pair)), &updates);
+    updates.iter().map(|bucket| bucket.count).sum()
+}
+
+/// Updates seen for `pair` in the last 24 hours.
+pub fn update_count_24h(env: &Env, pair: &AssetPair) -> u32 {
+    load_updates(env, pair, env.ledger().timestamp())
+        .iter()
+        .map(|bucket| bucket.count)
+        .sum()
+}
+
 pub fn check_staleness(env: &Env, pair: AssetPair) -> StalenessLevel {
     let metadata = load_metadata(env, &pair);
     let now = env.ledger().timestamp();
     let age = now.saturating_sub(metadata.last_update);
+    let config = pair_config::get_config(env, &pair);
 
-    match age {
-        0..=120 => StalenessLevel::Fresh,
-        121..=300 => StalenessLevel::Aging,
-        301..=900 => StalenessLevel::Stale,
-        _ => StalenessLevel::Critical,
+    if age <= config.fresh_secs {
+        StalenessLevel::Fresh
+    } else if age <= config.aging_secs {
+        StalenessLevel::Aging
+    } else if age <= config.stale_secs {
+        StalenessLevel::Stale
+    } else {
+        StalenessLevel::Critical
     }
 }
 
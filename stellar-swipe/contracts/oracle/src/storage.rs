@@ -1,6 +1,6 @@
 //! Oracle storage layer
 
-use soroban_sdk::{contracttype, Env, Map};
+use soroban_sdk::{contracttype, xdr::ToXdr, BytesN, Env, Map, Vec};
 use stellar_swipe_common::{Asset, AssetPair};
 
 use crate::errors::OracleError;
@@ -15,6 +15,8 @@ pub enum StorageKey {
     PriceTimestamp(AssetPair),
     AvailablePairs,
     ConversionCache(Asset, Asset),
+    /// Intermediate assets `convert` may route through, in priority order.
+    QuoteAssets,
 }
 
 #[contracttype]
@@ -24,6 +26,13 @@ pub struct CachedConversion {
     pub timestamp: u64,
 }
 
+/// Fixed-size storage id of `pair`: the SHA-256 of its XDR. Persistent keys
+/// built from it stay under the ledger key size limit whatever the asset
+/// codes and issuers.
+pub fn pair_id(env: &Env, pair: &AssetPair) -> BytesN<32> {
+    env.crypto().sha256(&pair.clone().to_xdr(env)).into()
+}
+
 /// Get base currency (default: XLM)
 pub fn get_base_currency(env: &Env) -> Asset {
     env.storage()
@@ -95,6 +104,26 @@ pub fn set_cached_conversion(env: &Env, from: &Asset, to: &Asset, rate: i128) {
     env.storage().temporary().extend_ttl(&key, 60, 60);
 }
 
+/// Get the quote assets used for two-hop conversions (default: base currency)
+pub fn get_quote_assets(env: &Env) -> Vec<Asset> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::QuoteAssets)
+        .unwrap_or_else(|| Vec::from_array(env, [get_base_currency(env)]))
+}
+
+/// Set the quote assets used for two-hop conversions
+pub fn set_quote_assets(env: &Env, assets: &Vec<Asset>) {
+    env.storage()
+        .persistent()
+        .set(&StorageKey::QuoteAssets, assets);
+    env.storage().persistent().extend_ttl(
+        &StorageKey::QuoteAssets,
+        DAY_IN_LEDGERS,
+        DAY_IN_LEDGERS,
+    );
+}
+
 /// Get available trading pairs
 pub fn get_available_pairs(env: &Env) -> Map<AssetPair, bool> {
     env.storage()
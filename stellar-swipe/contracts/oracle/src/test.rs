@@ -1,7 +1,11 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    token, vec, Address, BytesN, Env, String, Symbol,
+};
+use stellar_swipe_common::{SECONDS_PER_DAY, SECONDS_PER_WEEK};
 
 fn xlm_asset(env: &Env) -> Asset {
     Asset {
@@ -10,6 +14,52 @@ fn xlm_asset(env: &Env) -> Asset {
     }
 }
 
+fn xlm_usdc(env: &Env) -> AssetPair {
+    AssetPair {
+        base: xlm_asset(env),
+        quote: Asset {
+            code: String::from_str(env, "USDC"),
+            issuer: None,
+        },
+    }
+}
+
+fn submit(env: &Env, client: &OracleContractClient, oracle: &Address, price: i128) {
+    client.submit_price(oracle, &xlm_usdc(env), &price, &env.ledger().timestamp());
+}
+
+/// Start governance with a fresh stake token, the token bonds are posted in.
+fn init_bond_token(env: &Env, client: &OracleContractClient, admin: &Address) -> Address {
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init_governance(admin, &token);
+    token
+}
+
+fn post_bond(
+    env: &Env,
+    client: &OracleContractClient,
+    token: &Address,
+    oracle: &Address,
+    amount: i128,
+) {
+    token::StellarAssetClient::new(env, token).mint(oracle, &amount);
+    client.post_bond(oracle, &amount);
+}
+
+fn stake(env: &Env, client: &OracleContractClient, token: &Address, who: &Address, amount: i128) {
+    token::StellarAssetClient::new(env, token).mint(who, &amount);
+    client.deposit_stake(who, &amount);
+}
+
+/// Keep `pair`'s price from going stale during a day-long dispute vote.
+fn outlast_dispute_vote(client: &OracleContractClient, admin: &Address, pair: &AssetPair) {
+    let mut config = client.get_pair_config(pair);
+    config.stale_secs = 2 * SECONDS_PER_DAY;
+    client.set_pair_config(admin, pair, &Some(config));
+}
+
 fn create_test_env() -> (Env, Address, Address, Address, Address) {
     let env = Env::default();
     env.mock_all_auths();
@@ -48,6 +98,33 @@ fn test_register_oracle() {
     assert_eq!(reputation.total_submissions, 0);
 }
 
+#[test]
+fn test_oracle_set_queries() {
+    let (env, admin, oracle1, oracle2, outsider) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    assert_eq!(client.get_oracle_count(), 0);
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    let token = init_bond_token(&env, &client, &admin);
+    post_bond(&env, &client, &token, &oracle2, 500);
+
+    assert_eq!(client.get_oracle_count(), 2);
+    assert_eq!(client.get_oracles(), vec![&env, oracle1.clone(), oracle2.clone()]);
+
+    let stats = client.get_oracle_stats(&oracle2);
+    assert_eq!(stats.oracle, oracle2);
+    assert_eq!(stats.reputation.reputation_score, 50);
+    assert_eq!(stats.bond.amount, 500);
+    assert_eq!(stats.deviation_streak, 0);
+    assert!(matches!(
+        client.try_get_oracle_stats(&outsider),
+        Err(Ok(OracleError::OracleNotFound))
+    ));
+}
+
 #[test]
 fn test_submit_price() {
     let (env, admin, oracle1, _, _) = create_test_env();
@@ -57,11 +134,14 @@ fn test_submit_price() {
     client.initialize(&admin, &xlm_asset(&env));
     client.register_oracle(&admin, &oracle1);
 
-    client.submit_price(&oracle1, &100_000_000);
+    submit(&env, &client, &oracle1, 100_000_000);
 
-    // Verify submission was recorded
-    let _consensus = client.calculate_consensus();
-    // Test passes if no panic occurs
+    // A lone active oracle closes the round on its own
+    let consensus = client.get_price(&xlm_usdc(&env));
+    assert_eq!(consensus.price, 100_000_000);
+    assert_eq!(consensus.num_oracles, 1);
+    assert_eq!(consensus.round, 0);
+    assert_eq!(client.get_round(&xlm_usdc(&env)), 1);
 }
 
 #[test]
@@ -76,11 +156,9 @@ fn test_reputation_calculation_accurate_oracle() {
     client.register_oracle(&admin, &oracle3);
 
     // Oracle1: accurate (100), Oracle2: moderate (105), Oracle3: poor (120)
-    client.submit_price(&oracle1, &100_000_000);
-    client.submit_price(&oracle2, &105_000_000);
-    client.submit_price(&oracle3, &120_000_000);
-
-    client.calculate_consensus();
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 105_000_000);
+    submit(&env, &client, &oracle3, 120_000_000);
 
     let rep1 = client.get_oracle_reputation(&oracle1);
     let rep2 = client.get_oracle_reputation(&oracle2);
@@ -108,10 +186,9 @@ fn test_weight_adjustment() {
 
     // Simulate multiple rounds with oracle1 being consistently accurate
     for _ in 0..10 {
-        client.submit_price(&oracle1, &100_000_000);
-        client.submit_price(&oracle2, &105_000_000);
-        client.submit_price(&oracle3, &95_000_000);
-        client.calculate_consensus();
+        submit(&env, &client, &oracle1, 100_000_000);
+        submit(&env, &client, &oracle2, 105_000_000);
+        submit(&env, &client, &oracle3, 95_000_000);
     }
 
     let rep1 = client.get_oracle_reputation(&oracle1);
@@ -133,11 +210,9 @@ fn test_slash_for_major_deviation() {
     client.register_oracle(&admin, &oracle3);
 
     // Oracle3 submits price with >20% deviation
-    client.submit_price(&oracle1, &100_000_000);
-    client.submit_price(&oracle2, &101_000_000);
-    client.submit_price(&oracle3, &150_000_000); // 50% higher
-
-    client.calculate_consensus();
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 101_000_000);
+    submit(&env, &client, &oracle3, 150_000_000); // 50% higher
 
     let rep3 = client.get_oracle_reputation(&oracle3);
 
@@ -158,17 +233,15 @@ fn test_oracle_removal_for_poor_performance() {
 
     // Oracle3 consistently submits bad data until it gets weight 0
     for i in 0..50 {
-        client.submit_price(&oracle1, &100_000_000);
-        client.submit_price(&oracle2, &101_000_000);
+        submit(&env, &client, &oracle1, 100_000_000);
+        submit(&env, &client, &oracle2, 101_000_000);
 
         // Check if oracle3 still has weight before submitting
         let rep3 = client.get_oracle_reputation(&oracle3);
         if rep3.weight > 0 {
-            client.submit_price(&oracle3, &200_000_000);
+            submit(&env, &client, &oracle3, 200_000_000);
         }
 
-        client.calculate_consensus();
-
         // Break early if oracle3 is already at weight 0
         if i > 10 && rep3.weight == 0 {
             break;
@@ -194,20 +267,18 @@ fn test_reputation_recovery() {
 
     // Oracle1 submits slightly inaccurate data initially (6% off - outside 5% threshold)
     for _ in 0..5 {
-        client.submit_price(&oracle1, &106_000_000); // 6% off
-        client.submit_price(&oracle2, &100_000_000);
-        client.submit_price(&oracle3, &101_000_000);
-        client.calculate_consensus();
+        submit(&env, &client, &oracle1, 106_000_000); // 6% off
+        submit(&env, &client, &oracle2, 100_000_000);
+        submit(&env, &client, &oracle3, 101_000_000);
     }
 
     let rep_before = client.get_oracle_reputation(&oracle1);
 
     // Oracle1 improves and becomes accurate
     for _ in 0..20 {
-        client.submit_price(&oracle1, &100_000_000);
-        client.submit_price(&oracle2, &100_500_000);
-        client.submit_price(&oracle3, &101_000_000);
-        client.calculate_consensus();
+        submit(&env, &client, &oracle1, 100_000_000);
+        submit(&env, &client, &oracle2, 100_500_000);
+        submit(&env, &client, &oracle3, 101_000_000);
     }
 
     let rep_after = client.get_oracle_reputation(&oracle1);
@@ -217,6 +288,51 @@ fn test_reputation_recovery() {
     assert_eq!(rep_after.total_submissions, 25); // 5 + 20
 }
 
+#[test]
+fn test_reputation_decays_when_idle_and_recovers_gradually() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+    env.ledger().set_timestamp(10 * SECONDS_PER_WEEK);
+
+    // Accurate rounds raise the score in capped steps
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 100_000_000);
+    submit(&env, &client, &oracle3, 100_000_000);
+    assert_eq!(client.get_oracle_reputation(&oracle1).reputation_score, 55);
+    for _ in 0..9 {
+        submit(&env, &client, &oracle1, 100_000_000);
+        submit(&env, &client, &oracle2, 100_000_000);
+        submit(&env, &client, &oracle3, 100_000_000);
+    }
+    assert_eq!(client.get_oracle_reputation(&oracle1).reputation_score, 100);
+
+    // Twenty idle days drift the score back toward neutral
+    env.ledger().set_timestamp(10 * SECONDS_PER_WEEK + 20 * SECONDS_PER_DAY);
+    let idle = client.get_oracle_reputation(&oracle1);
+    assert_eq!(idle.reputation_score, 80);
+    assert_eq!(idle.weight, 10);
+
+    // Oracle3 is slashed for a 30% miss, then climbs back one step per round
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 100_000_000);
+    submit(&env, &client, &oracle3, 130_000_000);
+    assert_eq!(client.get_oracle_reputation(&oracle1).reputation_score, 85);
+    let slashed = client.get_oracle_reputation(&oracle3);
+    assert_eq!(slashed.last_slash, 10 * SECONDS_PER_WEEK + 20 * SECONDS_PER_DAY);
+    assert_eq!(slashed.reputation_score, 65);
+
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 100_000_000);
+    submit(&env, &client, &oracle3, 100_000_000);
+    assert_eq!(client.get_oracle_reputation(&oracle3).reputation_score, 70);
+}
+
 #[test]
 fn test_weighted_median() {
     let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
@@ -230,10 +346,9 @@ fn test_weighted_median() {
 
     // Build reputation for oracle1
     for _ in 0..10 {
-        client.submit_price(&oracle1, &100_000_000);
-        client.submit_price(&oracle2, &100_000_000);
-        client.submit_price(&oracle3, &100_000_000);
-        client.calculate_consensus();
+        submit(&env, &client, &oracle1, 100_000_000);
+        submit(&env, &client, &oracle2, 100_000_000);
+        submit(&env, &client, &oracle3, 100_000_000);
     }
 
     let rep1 = client.get_oracle_reputation(&oracle1);
@@ -258,28 +373,26 @@ fn test_minimum_oracles_maintained() {
     assert_eq!(oracles_before.len(), 3);
 
     // All oracles submit terrible data
-    for i in 0..50 {
+    for _i in 0..50 {
         let rep1 = client.get_oracle_reputation(&oracle1);
         let rep2 = client.get_oracle_reputation(&oracle2);
         let rep3 = client.get_oracle_reputation(&oracle3);
 
         // Only submit if oracle still has weight
         if rep1.weight > 0 {
-            client.submit_price(&oracle1, &200_000_000);
+            submit(&env, &client, &oracle1, 200_000_000);
         }
         if rep2.weight > 0 {
-            client.submit_price(&oracle2, &300_000_000);
+            submit(&env, &client, &oracle2, 300_000_000);
         }
         if rep3.weight > 0 {
-            client.submit_price(&oracle3, &400_000_000);
+            submit(&env, &client, &oracle3, 400_000_000);
         }
 
-        // Need at least one submission to calculate consensus
+        // Stop once no oracle can submit
         if rep1.weight == 0 && rep2.weight == 0 && rep3.weight == 0 {
             break;
         }
-
-        client.calculate_consensus();
     }
 
     let oracles = client.get_oracles();
@@ -288,6 +401,714 @@ fn test_minimum_oracles_maintained() {
     assert!(oracles.len() >= 2);
 }
 
+#[test]
+fn test_round_aggregates_once_min_submissions_reached() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+    assert_eq!(client.get_min_submissions(), DEFAULT_MIN_SUBMISSIONS);
+
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 104_000_000);
+    // Resubmitting within a round replaces the earlier value
+    submit(&env, &client, &oracle2, 102_000_000);
+    assert_eq!(client.get_round_submissions(&pair).len(), 2);
+    assert!(client.try_get_price(&pair).is_err());
+
+    submit(&env, &client, &oracle3, 101_000_000);
+    let consensus = client.get_price(&pair);
+    assert_eq!(consensus.price, 101_000_000);
+    assert_eq!(consensus.num_oracles, 3);
+    assert_eq!(consensus.timestamp, env.ledger().timestamp());
+    assert_eq!(client.get_round(&pair), 1);
+    assert_eq!(client.get_round_submissions(&pair).len(), 0);
+    let stored = client.get_consensus_price(&pair).unwrap();
+    assert_eq!((stored.price, stored.round), (consensus.price, consensus.round));
+}
+
+#[test]
+fn test_median_price_serves_pair_observations() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    assert!(client.try_get_median_price(&pair).is_err());
+
+    for (source, price) in [
+        (&oracle1, 100_000_000),
+        (&oracle2, 103_000_000),
+        (&oracle3, 101_000_000),
+    ] {
+        client.add_price_source(&admin, source, &1);
+        client.submit_pair_price(source, &pair, &price, &90);
+    }
+    assert_eq!(client.get_median_price(&pair), 101_000_000);
+    assert_eq!(
+        client.get_median_price(&pair),
+        client.get_price_with_confidence(&pair).0
+    );
+}
+
+#[test]
+fn test_outlier_rejected_from_aggregation() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+    assert_eq!(client.get_max_deviation_bps(), DEFAULT_MAX_DEVIATION_BPS);
+
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 101_000_000);
+    submit(&env, &client, &oracle3, 150_000_000); // ~48.5% above the median
+
+    // Only the two agreeing oracles feed the price
+    let consensus = client.get_price(&xlm_usdc(&env));
+    assert_eq!(consensus.price, 100_500_000);
+    assert_eq!(consensus.num_oracles, 2);
+
+    // The rejection is charged against the outlier's deviation
+    let rep3 = client.get_oracle_reputation(&oracle3);
+    assert_eq!(rep3.total_submissions, 1);
+    assert_eq!(rep3.accurate_submissions, 0);
+    assert_eq!(rep3.avg_deviation, 4_851);
+}
+
+#[test]
+fn test_price_confidence_reports_spread() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+    assert!(matches!(
+        client.try_get_price_confidence(&pair),
+        Err(Ok(OracleError::PriceNotFound))
+    ));
+
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 101_000_000);
+    submit(&env, &client, &oracle3, 150_000_000);
+
+    // The rejected outlier does not widen the spread.
+    let confidence = client.get_price_confidence(&pair);
+    assert_eq!(confidence.price, 100_500_000);
+    assert_eq!(confidence.num_sources, 2);
+    assert_eq!(confidence.spread_bps, 99);
+    assert_eq!(confidence.timestamp, env.ledger().timestamp());
+
+    // oracle3 was deactivated for the outlier, so only the others resubmit.
+    for oracle in [&oracle1, &oracle2] {
+        submit(&env, &client, oracle, 101_000_000);
+    }
+    let confidence = client.get_price_confidence(&pair);
+    assert_eq!(confidence.spread_bps, 0);
+    assert_eq!(confidence.num_sources, 2);
+}
+
+#[test]
+fn test_max_deviation_configurable() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+
+    assert!(client.try_set_max_deviation_bps(&admin, &0).is_err());
+    client.set_max_deviation_bps(&admin, &6_000);
+
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 101_000_000);
+    submit(&env, &client, &oracle3, 150_000_000);
+
+    let consensus = client.get_price(&xlm_usdc(&env));
+    assert_eq!(consensus.price, 101_000_000);
+    assert_eq!(consensus.num_oracles, 3);
+}
+
+#[test]
+fn test_prices_normalized_from_asset_decimals() {
+    let (env, admin, oracle1, _, _) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+
+    // Bridged USDC quotes with 6 decimals: 0.125 USDC is 125_000.
+    assert_eq!(client.get_asset_decimals(&pair.quote), 7);
+    client.set_asset_decimals(&admin, &pair.quote, &6);
+    assert_eq!(client.get_pair_config(&pair).decimals, 6);
+    assert!(matches!(
+        client.try_set_asset_decimals(&admin, &pair.quote, &19),
+        Err(Ok(OracleError::InvalidPairConfig))
+    ));
+
+    submit(&env, &client, &oracle1, 125_000);
+    assert_eq!(client.get_price(&pair).price, 1_250_000);
+}
+
+#[test]
+fn test_bond_required_slashed_and_released() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+    client.set_bond_config(&admin, &1_000, &100);
+    env.ledger().set_timestamp(1_000);
+
+    assert!(matches!(
+        client.try_submit_price(&oracle1, &pair, &100_000_000, &1_000),
+        Err(Ok(OracleError::InsufficientBond))
+    ));
+    assert!(matches!(
+        client.try_post_bond(&oracle1, &1_000),
+        Err(Ok(OracleError::BondTokenNotSet))
+    ));
+    let token = init_bond_token(&env, &client, &admin);
+    assert!(matches!(
+        client.try_post_bond(&oracle1, &0),
+        Err(Ok(OracleError::InvalidBondAmount))
+    ));
+    assert!(matches!(
+        client.try_set_bond_config(&admin, &-1, &100),
+        Err(Ok(OracleError::InvalidBondConfig))
+    ));
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        post_bond(&env, &client, &token, oracle, 1_000);
+    }
+    let balance = token::Client::new(&env, &token);
+    assert_eq!(balance.balance(&oracle1), 0);
+    assert_eq!(balance.balance(&contract_id), 3_000);
+
+    // oracle3 is 100% off the consensus and forfeits 10% of its bond.
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 100_000_000);
+    submit(&env, &client, &oracle3, 200_000_000);
+    assert_eq!(client.get_bond(&oracle3).amount, 900);
+    assert_eq!(client.get_bond(&oracle1).amount, 1_000);
+    assert_eq!(client.get_slashed_bonds(), 100);
+
+    // Leaving the set starts unbonding; the bond is claimable afterwards.
+    client.remove_oracle(&admin, &oracle1);
+    assert_eq!(client.get_bond(&oracle1).unlocks_at, Some(1_100));
+    assert!(matches!(
+        client.try_claim_bond(&oracle1),
+        Err(Ok(OracleError::BondLocked))
+    ));
+    assert!(matches!(
+        client.try_claim_bond(&oracle2),
+        Err(Ok(OracleError::BondLocked))
+    ));
+    env.ledger().set_timestamp(1_100);
+    assert_eq!(client.claim_bond(&oracle1), 1_000);
+    assert_eq!(client.get_bond(&oracle1), OracleBond::default());
+    assert_eq!(balance.balance(&oracle1), 1_000);
+    assert_eq!(balance.balance(&contract_id), 2_000);
+}
+
+#[test]
+fn test_finalize_round_after_deadline() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+    env.ledger().set_timestamp(1_000);
+
+    assert!(matches!(
+        client.try_finalize_round(&pair),
+        Err(Ok(OracleError::RoundNotReady))
+    ));
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 102_000_000);
+
+    let info = client.get_round_info(&pair);
+    assert_eq!(info.round, 0);
+    assert_eq!(info.deadline, 1_000 + DEFAULT_ROUND_DURATION_SECS);
+    assert_eq!((info.submissions, info.required), (2, 3));
+    assert!(matches!(
+        client.try_finalize_round(&pair),
+        Err(Ok(OracleError::RoundNotReady))
+    ));
+
+    // Past the deadline anyone can close the round with what it holds.
+    env.ledger().set_timestamp(1_000 + DEFAULT_ROUND_DURATION_SECS);
+    let result = client.finalize_round(&pair);
+    assert_eq!(result.round, 0);
+    assert_eq!(result.num_oracles, 2);
+    assert_eq!(client.get_price(&pair).round, 0);
+    assert_eq!(client.get_round_result(&pair, &0).unwrap().price, result.price);
+
+    let info = client.get_round_info(&pair);
+    assert_eq!((info.round, info.submissions, info.opened_at), (1, 0, 0));
+}
+
+#[test]
+fn test_vwap_from_submitted_volume() {
+    let (env, admin, oracle1, oracle2, _) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    env.ledger().set_timestamp(1_000);
+
+    client.submit_price_with_volume(&oracle1, &pair, &100_000_000, &1_000, &3_000);
+    client.submit_price_with_volume(&oracle2, &pair, &100_000_000, &1_000, &1_000);
+    env.ledger().set_timestamp(1_060);
+    client.submit_price_with_volume(&oracle1, &pair, &120_000_000, &1_060, &2_000);
+    client.submit_price_with_volume(&oracle2, &pair, &120_000_000, &1_060, &2_000);
+
+    // Rounds of 4_000 at 10.0 and 12.0.
+    assert_eq!(client.get_vwap(&pair, &3_600), 110_000_000);
+    assert!(matches!(
+        client.try_submit_price_with_volume(&oracle1, &pair, &100_000_000, &1_060, &-1),
+        Err(Ok(OracleError::InvalidPrice))
+    ));
+}
+
+fn sign_price(
+    env: &Env,
+    contract_id: &Address,
+    key: &ed25519_dalek::SigningKey,
+    oracle: &Address,
+    price: i128,
+    nonce: u64,
+) -> SignedPrice {
+    use ed25519_dalek::Signer;
+
+    let pair = xlm_usdc(env);
+    let timestamp = env.ledger().timestamp();
+    let message = env.as_contract(contract_id, || {
+        signed_prices::payload(env, oracle, &pair, price, timestamp, nonce)
+    });
+    let mut buf = [0u8; 512];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    SignedPrice {
+        oracle: oracle.clone(),
+        pair,
+        price,
+        timestamp,
+        nonce,
+        signature: BytesN::from_array(env, &key.sign(&buf[..len]).to_bytes()),
+    }
+}
+
+#[test]
+fn test_relayed_signed_prices_close_round() {
+    let (env, admin, oracle1, oracle2, relayer) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let key1 = ed25519_dalek::SigningKey::from_bytes(&[1u8; 32]);
+    let key2 = ed25519_dalek::SigningKey::from_bytes(&[2u8; 32]);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    env.ledger().set_timestamp(1_000);
+
+    let first = sign_price(&env, &contract_id, &key1, &oracle1, 100_000_000, 1);
+    assert!(matches!(
+        client.try_submit_signed_prices(&relayer, &vec![&env, first.clone()]),
+        Err(Ok(OracleError::SigningKeyNotRegistered))
+    ));
+
+    client.register_signing_key(
+        &oracle1,
+        &BytesN::from_array(&env, &key1.verifying_key().to_bytes()),
+    );
+    client.register_signing_key(
+        &oracle2,
+        &BytesN::from_array(&env, &key2.verifying_key().to_bytes()),
+    );
+    let second = sign_price(&env, &contract_id, &key2, &oracle2, 100_000_000, 1);
+    assert_eq!(
+        client.submit_signed_prices(&relayer, &vec![&env, first.clone(), second]),
+        2
+    );
+
+    // The round closed with both signers credited, not the relayer.
+    assert_eq!(client.get_price(&xlm_usdc(&env)).price, 100_000_000);
+    assert_eq!(client.get_oracle_reputation(&oracle1).total_submissions, 1);
+    assert_eq!(client.get_oracle_reputation(&oracle2).total_submissions, 1);
+    assert_eq!(client.get_signing_nonce(&oracle1), 1);
+
+    // A relayed price can't be replayed.
+    assert!(matches!(
+        client.try_submit_signed_prices(&relayer, &vec![&env, first]),
+        Err(Ok(OracleError::InvalidNonce))
+    ));
+}
+
+#[test]
+fn test_exit_oracle_keeps_bond_slashable() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+    client.set_bond_config(&admin, &1_000, &100);
+    let token = init_bond_token(&env, &client, &admin);
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        post_bond(&env, &client, &token, oracle, 1_000);
+    }
+    env.ledger().set_timestamp(1_000);
+
+    // oracle1 leaves with an outlying price still in the open round.
+    submit(&env, &client, &oracle1, 200_000_000);
+    submit(&env, &client, &oracle2, 100_000_000);
+    let bond = client.exit_oracle(&oracle1);
+    assert_eq!(bond.unlocks_at, Some(1_100));
+    assert_eq!(client.get_oracle_count(), 2);
+    assert!(matches!(
+        client.try_exit_oracle(&oracle2),
+        Err(Ok(OracleError::InsufficientOracles))
+    ));
+
+    submit(&env, &client, &oracle3, 100_000_000);
+    assert_eq!(client.get_round(&xlm_usdc(&env)), 1);
+    assert_eq!(client.get_bond(&oracle1).amount, 900);
+
+    env.ledger().set_timestamp(1_100);
+    assert_eq!(client.claim_bond(&oracle1), 900);
+    assert_eq!(token::Client::new(&env, &token).balance(&oracle1), 900);
+}
+
+#[test]
+fn test_disputed_round_is_frozen_until_resolved() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+    let disputer = Address::generate(&env);
+    let voter = Address::generate(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    let token = init_bond_token(&env, &client, &admin);
+    let balance = token::Client::new(&env, &token);
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        client.register_oracle(&admin, oracle);
+        post_bond(&env, &client, &token, oracle, 1_000);
+    }
+    client.set_dispute_config(
+        &admin,
+        &DisputeConfig {
+            window_secs: 600,
+            bond: 60,
+        },
+    );
+    outlast_dispute_vote(&client, &admin, &pair);
+    stake(&env, &client, &token, &disputer, 1_000);
+    stake(&env, &client, &token, &voter, 9_000);
+    token::StellarAssetClient::new(&env, &token).mint(&disputer, &120);
+    env.ledger().set_timestamp(1_000);
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        submit(&env, &client, oracle, 100_000_000);
+    }
+
+    // Only stakers may dispute, and the bond is escrowed.
+    assert!(matches!(
+        client.try_dispute_round(&Address::generate(&env), &pair, &0),
+        Err(Ok(OracleError::DisputerNotStaked))
+    ));
+    let dispute = client.dispute_round(&disputer, &pair, &0);
+    assert_eq!(balance.balance(&disputer), 60);
+    assert_eq!(
+        client.get_disputed_round(&dispute.proposal_id),
+        Some(DisputedRound {
+            pair: pair.clone(),
+            round: 0
+        })
+    );
+    assert!(matches!(
+        client.try_get_price(&pair),
+        Err(Ok(OracleError::PriceDisputed))
+    ));
+    assert!(matches!(
+        client.try_dispute_round(&disputer, &pair, &0),
+        Err(Ok(OracleError::DisputeExists))
+    ));
+    assert_eq!(
+        client.try_withdraw_proposal(&disputer, &dispute.proposal_id),
+        Err(Ok(GovernanceError::Unauthorized))
+    );
+
+    // Rejected by the vote: the price is served again and the bond goes to
+    // the oracles.
+    client.vote_on_proposal(&dispute.proposal_id, &voter, &false);
+    env.ledger()
+        .set_timestamp(1_000 + governance::DISPUTE_VOTING_PERIOD_SECONDS);
+    client.finalise_proposal(&dispute.proposal_id);
+    assert_eq!(
+        client.get_dispute(&pair, &0).unwrap().status,
+        DisputeStatus::Rejected
+    );
+    assert_eq!(client.get_price(&pair).round, 0);
+    assert_eq!(client.get_dispute_winnings(&oracle2), 20);
+    assert_eq!(client.claim_dispute_winnings(&oracle2), 20);
+    assert_eq!(balance.balance(&oracle2), 20);
+
+    // Upheld by the vote: the price stays frozen and the oracles pay the
+    // disputer.
+    let now = env.ledger().timestamp() + 1_000;
+    env.ledger().set_timestamp(now);
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        submit(&env, &client, oracle, 100_000_000);
+    }
+    let dispute = client.dispute_round(&disputer, &pair, &1);
+    client.vote_on_proposal(&dispute.proposal_id, &voter, &true);
+    let dispute = client.get_dispute(&pair, &1).unwrap();
+    assert_eq!(dispute.status, DisputeStatus::Upheld);
+    assert_eq!(client.get_bond(&oracle1).amount, 800);
+    assert_eq!(client.get_dispute_winnings(&disputer), 60 + 600);
+    assert_eq!(client.get_slashed_bonds(), 0);
+    assert!(matches!(
+        client.try_get_price(&pair),
+        Err(Ok(OracleError::PriceDisputed))
+    ));
+    assert_eq!(client.claim_dispute_winnings(&disputer), 660);
+    assert_eq!(client.get_dispute_winnings(&disputer), 0);
+    assert_eq!(balance.balance(&disputer), 660);
+
+    env.ledger().set_timestamp(now + 601);
+    assert!(matches!(
+        client.try_dispute_round(&disputer, &pair, &1),
+        Err(Ok(OracleError::DisputeWindowClosed))
+    ));
+}
+
+#[soroban_sdk::contract]
+pub struct PriceConsumer;
+
+#[soroban_sdk::contractimpl]
+impl PriceConsumer {
+    pub fn on_price(env: Env, request_id: u64, _pair: AssetPair, data: ConsensusPriceData) {
+        env.storage().instance().set(&request_id, &data.price);
+    }
+
+    pub fn received(env: Env, request_id: u64) -> Option<i128> {
+        env.storage().instance().get(&request_id)
+    }
+}
+
+#[test]
+fn test_request_price_calls_back_when_round_closes() {
+    let (env, admin, oracle1, oracle2, consumer_user) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let consumer_id = env.register_contract(None, PriceConsumer);
+    let consumer = PriceConsumerClient::new(&env, &consumer_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    env.ledger().set_timestamp(1_000);
+
+    let id = client.request_price(
+        &consumer_user,
+        &pair,
+        &consumer_id,
+        &Symbol::new(&env, "on_price"),
+    );
+    // A callback that does not exist must not block the round.
+    let broken = client.request_price(
+        &consumer_user,
+        &pair,
+        &consumer_id,
+        &Symbol::new(&env, "missing"),
+    );
+    assert_eq!(client.get_price_requests(&pair).len(), 2);
+    assert!(matches!(
+        client.try_cancel_price_request(&admin, &pair, &broken),
+        Err(Ok(OracleError::Unauthorized))
+    ));
+
+    submit(&env, &client, &oracle1, 100_000_000);
+    assert_eq!(consumer.received(&id), None);
+    submit(&env, &client, &oracle2, 102_000_000);
+
+    assert_eq!(consumer.received(&id), Some(client.get_price(&pair).price));
+    assert_eq!(consumer.received(&broken), None);
+    assert_eq!(client.get_price_requests(&pair).len(), 0);
+}
+
+#[test]
+fn test_pair_config_overrides_defaults() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+    env.ledger().set_timestamp(1_000);
+
+    let defaults = client.get_pair_config(&pair);
+    assert_eq!(defaults.stale_secs, 900);
+    assert_eq!(defaults.min_sources, DEFAULT_MIN_SUBMISSIONS);
+
+    let config = PairConfig {
+        decimals: 7,
+        fresh_secs: 10,
+        aging_secs: 20,
+        stale_secs: 30,
+        max_deviation_bps: 500,
+        min_sources: 2,
+        enabled: true,
+    };
+    let mut unordered = config.clone();
+    unordered.aging_secs = 40;
+    assert!(matches!(
+        client.try_set_pair_config(&admin, &pair, &Some(unordered)),
+        Err(Ok(OracleError::InvalidPairConfig))
+    ));
+    client.set_pair_config(&admin, &pair, &Some(config.clone()));
+
+    // Two submissions now close the round.
+    submit(&env, &client, &oracle1, 100_000_000);
+    submit(&env, &client, &oracle2, 100_000_000);
+    assert_eq!(client.get_price(&pair).price, 100_000_000);
+
+    // The pair turns critical after its own 30s rather than the default 900s.
+    env.ledger().set_timestamp(1_031);
+    assert!(matches!(
+        client.try_get_price(&pair),
+        Err(Ok(OracleError::StalePrice))
+    ));
+
+    let mut disabled = config.clone();
+    disabled.enabled = false;
+    client.set_pair_config(&admin, &pair, &Some(disabled));
+    assert!(matches!(
+        client.try_get_price(&pair),
+        Err(Ok(OracleError::PairDisabled))
+    ));
+    assert!(matches!(
+        client.try_submit_price(&oracle3, &pair, &100_000_000, &env.ledger().timestamp()),
+        Err(Ok(OracleError::PairDisabled))
+    ));
+
+    client.set_pair_config(&admin, &pair, &None);
+    assert_eq!(client.get_pair_config(&pair), defaults);
+}
+
+#[test]
+fn test_persistent_deviation_auto_slash() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+    env.ledger().set_timestamp(10 * SECONDS_PER_WEEK);
+
+    let round = |price3: i128| {
+        submit(&env, &client, &oracle1, 100_000_000);
+        submit(&env, &client, &oracle2, 100_000_000);
+        submit(&env, &client, &oracle3, price3);
+    };
+
+    // Build a clean track record first
+    for _ in 0..10 {
+        round(100_000_000);
+    }
+    assert_eq!(client.get_oracle_reputation(&oracle3).weight, 10);
+
+    // Drift 5% with a 1% threshold: the rolling average crosses p_dev on
+    // the third bad round and the streak runs from there
+    client.set_max_deviation_bps(&admin, &100);
+    assert!(client.try_set_deviation_streak_limit(&admin, &0).is_err());
+    assert_eq!(client.get_deviation_streak_limit(), 3);
+    for _ in 0..4 {
+        round(105_000_000);
+    }
+    assert_eq!(client.get_deviation_streak(&oracle3), 2);
+    assert_eq!(client.get_oracle_reputation(&oracle3).last_slash, 0);
+
+    round(105_000_000);
+    let stats = client.get_oracle_reputation(&oracle3);
+    assert_eq!(stats.last_slash, 10 * SECONDS_PER_WEEK);
+    assert_eq!(stats.reputation_score, 80 - PERSISTENT_DEVIATION_PENALTY);
+    assert_eq!(stats.weight, 2);
+    assert_eq!(client.get_deviation_streak(&oracle3), 0);
+
+    // Within the cooldown the streak keeps counting but nothing is slashed
+    for _ in 0..3 {
+        round(105_000_000);
+    }
+    assert_eq!(client.get_deviation_streak(&oracle3), 3);
+    assert_eq!(client.get_oracle_reputation(&oracle3).last_slash, 10 * SECONDS_PER_WEEK);
+
+    env.ledger().set_timestamp(10 * SECONDS_PER_WEEK + SECONDS_PER_DAY);
+    round(105_000_000);
+    let stats = client.get_oracle_reputation(&oracle3);
+    assert_eq!(stats.last_slash, 10 * SECONDS_PER_WEEK + SECONDS_PER_DAY);
+    assert_eq!(stats.weight, 1);
+
+    // Honest oracles are untouched
+    assert_eq!(client.get_deviation_streak(&oracle1), 0);
+    assert_eq!(client.get_oracle_reputation(&oracle1).last_slash, 0);
+}
+
+#[test]
+fn test_submission_timestamp_validated() {
+    let (env, admin, oracle1, _, _) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    env.ledger().set_timestamp(10_000);
+
+    let future = client.try_submit_price(&oracle1, &pair, &100_000_000, &10_001);
+    assert_eq!(future, Err(Ok(OracleError::InvalidPrice)));
+
+    let stale_ts = 10_000 - MAX_SUBMISSION_AGE_SECS - 1;
+    let stale = client.try_submit_price(&oracle1, &pair, &100_000_000, &stale_ts);
+    assert_eq!(stale, Err(Ok(OracleError::StalePrice)));
+}
+
 #[test]
 fn test_invalid_price_rejected() {
     let (env, admin, oracle1, _, _) = create_test_env();
@@ -297,10 +1118,12 @@ fn test_invalid_price_rejected() {
     client.initialize(&admin, &xlm_asset(&env));
     client.register_oracle(&admin, &oracle1);
 
-    let result = client.try_submit_price(&oracle1, &0);
+    let pair = xlm_usdc(&env);
+    let now = env.ledger().timestamp();
+    let result = client.try_submit_price(&oracle1, &pair, &0, &now);
     assert!(result.is_err());
 
-    let result = client.try_submit_price(&oracle1, &-100);
+    let result = client.try_submit_price(&oracle1, &pair, &-100, &now);
     assert!(result.is_err());
 }
 
@@ -313,6 +1136,309 @@ fn test_unregistered_oracle_cannot_submit() {
 
     client.initialize(&admin, &xlm_asset(&env));
 
-    let result = client.try_submit_price(&unregistered, &100_000_000);
+    let result = client.try_submit_price(
+        &unregistered,
+        &xlm_usdc(&env),
+        &100_000_000,
+        &env.ledger().timestamp(),
+    );
     assert!(result.is_err());
 }
+
+#[test]
+fn test_set_price_is_admin_only() {
+    let (env, admin, _, _, _) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &xlm_asset(&env));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_price(&stranger, &xlm_usdc(&env), &100_000_000),
+        Err(Ok(OracleError::Unauthorized))
+    );
+    assert_eq!(client.get_price_history(&xlm_usdc(&env), &10).len(), 0);
+
+    client.set_price(&admin, &xlm_usdc(&env), &100_000_000);
+    assert_eq!(client.get_price_history(&xlm_usdc(&env), &10).len(), 1);
+}
+
+#[test]
+fn test_sep40_price_feed() {
+    let (env, admin, _, _, _) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let usdc = xlm_usdc(&env).quote;
+    client.initialize(&admin, &usdc);
+
+    let usd = Sep40Asset::Other(Symbol::new(&env, "USDC"));
+    let xlm = Sep40Asset::Other(Symbol::new(&env, "XLM"));
+    client.set_sep40_base(&admin, &usd);
+    client.register_sep40_asset(&admin, &xlm, &xlm_usdc(&env));
+
+    // Pairs must be quoted in the base currency.
+    let inverted = AssetPair {
+        base: usdc.clone(),
+        quote: xlm_asset(&env),
+    };
+    assert_eq!(
+        client.try_register_sep40_asset(&admin, &xlm, &inverted),
+        Err(Ok(OracleError::InvalidAsset))
+    );
+
+    assert_eq!(client.base(), usd);
+    assert_eq!(client.assets(), vec![&env, xlm.clone()]);
+    assert_eq!(client.decimals(), 7);
+    assert_eq!(client.resolution(), 300);
+    assert_eq!(client.lastprice(&xlm), None);
+
+    env.ledger().set_timestamp(1_000);
+    client.set_price(&admin, &xlm_usdc(&env), &1_200_000);
+    env.ledger().set_timestamp(1_600);
+    client.set_price(&admin, &xlm_usdc(&env), &1_300_000);
+
+    assert_eq!(
+        client.lastprice(&xlm),
+        Some(Sep40PriceData {
+            price: 1_300_000,
+            timestamp: 1_600
+        })
+    );
+    assert_eq!(client.price(&xlm, &1_599).unwrap().price, 1_200_000);
+    assert_eq!(client.price(&xlm, &999), None);
+
+    let records = client.prices(&xlm, &5).unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records.get(0).unwrap().price, 1_300_000);
+
+    let unknown = Sep40Asset::Stellar(Address::generate(&env));
+    assert_eq!(client.prices(&unknown, &5), None);
+}
+
+/// An oracle with XLM registered as a SEP-40 asset on the XLM/USDC pair.
+fn sep40_setup<'a>(env: &Env, admin: &Address) -> (OracleContractClient<'a>, Sep40Asset) {
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(env, &contract_id);
+    client.initialize(admin, &xlm_usdc(env).quote);
+    let xlm = Sep40Asset::Other(Symbol::new(env, "XLM"));
+    client.set_sep40_base(admin, &Sep40Asset::Other(Symbol::new(env, "USDC")));
+    client.register_sep40_asset(admin, &xlm, &xlm_usdc(env));
+    (client, xlm)
+}
+
+#[test]
+fn test_sep40_hides_disabled_pair() {
+    let (env, admin, _, _, _) = create_test_env();
+    let (client, xlm) = sep40_setup(&env, &admin);
+    env.ledger().set_timestamp(1_000);
+    client.set_price(&admin, &xlm_usdc(&env), &1_200_000);
+    assert!(client.lastprice(&xlm).is_some());
+
+    let mut disabled = client.get_pair_config(&xlm_usdc(&env));
+    disabled.enabled = false;
+    client.set_pair_config(&admin, &xlm_usdc(&env), &Some(disabled));
+    assert_eq!(client.lastprice(&xlm), None);
+    assert_eq!(client.price(&xlm, &1_000), None);
+    assert_eq!(client.prices(&xlm, &5), None);
+}
+
+#[test]
+fn test_sep40_hides_pair_paused_for_staleness() {
+    let (env, admin, _, _, _) = create_test_env();
+    let (client, xlm) = sep40_setup(&env, &admin);
+    env.ledger().set_timestamp(1_000);
+    client.set_price(&admin, &xlm_usdc(&env), &1_200_000);
+
+    // Past 900s without an update the pair pauses, as it does for get_price.
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.lastprice(&xlm), None);
+    assert!(client.get_price_metadata(&xlm_usdc(&env)).is_paused);
+
+    // A new price lifts the pause.
+    client.set_price(&admin, &xlm_usdc(&env), &1_300_000);
+    assert_eq!(client.lastprice(&xlm).unwrap().price, 1_300_000);
+}
+
+#[test]
+fn test_sep40_hides_disputed_round() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let (client, xlm) = sep40_setup(&env, &admin);
+    let disputer = Address::generate(&env);
+    let token = init_bond_token(&env, &client, &admin);
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        client.register_oracle(&admin, oracle);
+        post_bond(&env, &client, &token, oracle, 1_000);
+    }
+    outlast_dispute_vote(&client, &admin, &xlm_usdc(&env));
+    stake(&env, &client, &token, &disputer, 1_000);
+    token::StellarAssetClient::new(&env, &token).mint(&disputer, &60);
+    client.set_dispute_config(
+        &admin,
+        &DisputeConfig {
+            window_secs: 600,
+            bond: 60,
+        },
+    );
+    env.ledger().set_timestamp(1_000);
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        submit(&env, &client, oracle, 100_000_000);
+    }
+    assert_eq!(client.lastprice(&xlm).unwrap().price, 100_000_000);
+
+    let dispute = client.dispute_round(&disputer, &xlm_usdc(&env), &0);
+    assert_eq!(client.lastprice(&xlm), None);
+    assert_eq!(client.prices(&xlm, &5), None);
+
+    client.vote_on_proposal(&dispute.proposal_id, &disputer, &false);
+    env.ledger()
+        .set_timestamp(1_000 + governance::DISPUTE_VOTING_PERIOD_SECONDS);
+    client.finalise_proposal(&dispute.proposal_id);
+    assert_eq!(client.lastprice(&xlm).unwrap().price, 100_000_000);
+}
+
+#[test]
+fn test_critical_staleness_pauses_pair() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+
+    env.ledger().set_timestamp(1_000);
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        submit(&env, &client, oracle, 100_000_000);
+    }
+    let metadata = client.get_price_metadata(&pair);
+    assert_eq!(metadata.last_update, 1_000);
+    assert_eq!(metadata.staleness_level, StalenessLevel::Fresh);
+    assert!(!metadata.is_paused);
+
+    env.ledger().set_timestamp(1_400);
+    assert_eq!(client.get_price_metadata(&pair).staleness_level, StalenessLevel::Stale);
+    assert_eq!(client.get_price(&pair).price, 100_000_000);
+
+    // Past 900s without an update the pair pauses instead of serving the old price.
+    env.ledger().set_timestamp(2_000);
+    assert!(matches!(client.try_get_price(&pair), Err(Ok(OracleError::StalePrice))));
+    let metadata = client.get_price_metadata(&pair);
+    assert_eq!(metadata.staleness_level, StalenessLevel::Critical);
+    assert!(metadata.is_paused);
+
+    // The next aggregated round recovers it.
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        submit(&env, &client, oracle, 101_000_000);
+    }
+    assert_eq!(client.get_price(&pair).price, 101_000_000);
+    let metadata = client.get_price_metadata(&pair);
+    assert!(!metadata.is_paused);
+    assert_eq!(metadata.avg_update_interval, 1_000);
+}
+
+#[test]
+fn test_feed_health_tracks_rolling_update_counts() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let pair = xlm_usdc(&env);
+
+    client.initialize(&admin, &xlm_asset(&env));
+    client.register_oracle(&admin, &oracle1);
+    client.register_oracle(&admin, &oracle2);
+    client.register_oracle(&admin, &oracle3);
+
+    for (i, timestamp) in [1_000u64, 2_000, 3_000].iter().enumerate() {
+        env.ledger().set_timestamp(*timestamp);
+        env.ledger().set_sequence_number(10 + i as u32);
+        for oracle in [&oracle1, &oracle2, &oracle3] {
+            submit(&env, &client, oracle, 100_000_000);
+        }
+    }
+
+    let health = client.get_feed_health(&pair);
+    assert_eq!(health.last_update, 3_000);
+    assert_eq!(health.update_count_24h, 3);
+    assert_eq!(health.avg_update_interval, 1_000);
+    assert_eq!(health.age, 0);
+    assert_eq!(health.staleness_level, StalenessLevel::Fresh);
+    assert_eq!(health.heartbeat, OracleStatus::Healthy);
+    assert!(!health.is_paused);
+
+    // A day later the old updates have rolled out of the window.
+    env.ledger().set_timestamp(3_000 + SECONDS_PER_DAY);
+    env.ledger().set_sequence_number(12 + 17_280);
+    let health = client.get_feed_health(&pair);
+    assert_eq!(health.update_count_24h, 0);
+    assert_eq!(health.age, SECONDS_PER_DAY);
+    assert_eq!(health.staleness_level, StalenessLevel::Critical);
+    assert_eq!(health.heartbeat, OracleStatus::Dead);
+    assert!(health.is_paused);
+    assert_eq!(client.get_price_metadata(&pair).update_count_24h, 0);
+}
+
+#[test]
+fn test_get_price_falls_back_to_backup_feed() {
+    let (env, admin, oracle1, oracle2, oracle3) = create_test_env();
+    let pair = xlm_usdc(&env);
+
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &xlm_asset(&env));
+
+    // A second deployment stands in for an external SEP-40 feed.
+    let backup_id = env.register_contract(None, OracleContract);
+    let backup = OracleContractClient::new(&env, &backup_id);
+    backup.initialize(&admin, &pair.quote);
+    let xlm = Sep40Asset::Other(Symbol::new(&env, "XLM"));
+    backup.register_sep40_asset(&admin, &xlm, &pair);
+
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        client.register_oracle(&admin, oracle);
+        backup.register_oracle(&admin, oracle);
+    }
+
+    let internal = PriceSource::Internal;
+    let external = PriceSource::Sep40(backup_id.clone(), xlm.clone());
+    assert_eq!(client.get_price_sources(&pair), vec![&env, internal.clone()]);
+    assert!(matches!(
+        client.try_set_price_sources(&admin, &pair, &Vec::new(&env)),
+        Err(Ok(OracleError::InvalidPath))
+    ));
+    assert!(matches!(
+        client.try_set_price_sources(&admin, &pair, &vec![&env, internal.clone(), internal.clone()]),
+        Err(Ok(OracleError::InvalidAsset))
+    ));
+    client.set_price_sources(&admin, &pair, &vec![&env, internal.clone(), external.clone()]);
+
+    env.ledger().set_timestamp(1_000);
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        submit(&env, &client, oracle, 100_000_000);
+    }
+    let price = client.get_price(&pair);
+    assert_eq!(price.price, 100_000_000);
+    assert_eq!(price.source, internal);
+
+    env.ledger().set_timestamp(1_350);
+    for oracle in [&oracle1, &oracle2, &oracle3] {
+        backup.submit_price(oracle, &pair, &90_000_000, &1_350);
+    }
+
+    // The internal price is stale; the fresh backup serves instead.
+    env.ledger().set_timestamp(1_400);
+    let price = client.get_price(&pair);
+    assert_eq!(price.price, 90_000_000);
+    assert_eq!(price.timestamp, 1_350);
+    assert_eq!(price.num_oracles, 0);
+    assert_eq!(price.source, external);
+
+    // Internal is paused and the backup is stale but still usable.
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.get_price(&pair).source, external);
+
+    // Nothing usable remains.
+    env.ledger().set_timestamp(3_000);
+    assert!(matches!(client.try_get_price(&pair), Err(Ok(OracleError::StalePrice))));
+}
@@ -0,0 +1,738 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token,
+    xdr::ToXdr,
+    Address, Bytes, Env, String,
+};
+
+struct GovernanceTest<'a> {
+    env: Env,
+    client: OracleContractClient<'a>,
+    admin: Address,
+    token: token::Client<'a>,
+}
+
+fn setup<'a>() -> GovernanceTest<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Asset {
+            code: String::from_str(&env, "XLM"),
+            issuer: None,
+        },
+    );
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    client.init_governance(&admin, &token_id);
+    let token = token::Client::new(&env, &token_id);
+
+    GovernanceTest {
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+impl GovernanceTest<'_> {
+    fn stake(&self, who: &Address, amount: i128) {
+        token::StellarAssetClient::new(&self.env, &self.token.address).mint(who, &amount);
+        self.client.deposit_stake(who, &amount);
+    }
+}
+
+#[test]
+fn test_stake_requires_stake_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, OracleContract);
+    let client = OracleContractClient::new(&env, &contract_id);
+    client.initialize(
+        &Address::generate(&env),
+        &Asset {
+            code: String::from_str(&env, "XLM"),
+            issuer: None,
+        },
+    );
+
+    assert_eq!(
+        client.try_deposit_stake(&Address::generate(&env), &5_000),
+        Err(Ok(GovernanceError::StakeTokenNotSet))
+    );
+}
+
+#[test]
+fn test_unstake_pays_out_after_cooldown() {
+    let t = setup();
+    let staker = Address::generate(&t.env);
+    t.stake(&staker, 5_000);
+    assert_eq!(t.token.balance(&staker), 0);
+    assert_eq!(t.token.balance(&t.client.address), 5_000);
+
+    assert_eq!(
+        t.client.try_claim_unstake(&staker),
+        Err(Ok(GovernanceError::NoPendingUnstake))
+    );
+
+    let available_at = t.client.request_unstake(&staker, &2_000);
+    assert_eq!(t.client.get_stake(&staker), 3_000);
+    assert_eq!(t.client.get_total_staked(), 3_000);
+    assert_eq!(
+        t.client.try_claim_unstake(&staker),
+        Err(Ok(GovernanceError::UnstakeCooldownActive))
+    );
+
+    t.env.ledger().with_mut(|l| l.timestamp = available_at);
+    assert_eq!(t.client.claim_unstake(&staker), 2_000);
+    assert_eq!(t.token.balance(&staker), 2_000);
+    assert_eq!(t.token.balance(&t.client.address), 3_000);
+    assert!(t.client.get_pending_unstake(&staker).is_none());
+}
+
+#[test]
+fn test_unstake_cooldown_is_governance_admin_only() {
+    let t = setup();
+    let other = Address::generate(&t.env);
+    let cooldown = governance::VOTING_PERIOD_SECONDS;
+    assert_eq!(
+        t.client.try_set_unstake_cooldown(&other, &cooldown),
+        Err(Ok(GovernanceError::Unauthorized))
+    );
+    t.client.set_unstake_cooldown(&t.admin, &cooldown);
+    assert_eq!(t.client.get_unstake_cooldown(), cooldown);
+}
+
+impl GovernanceTest<'_> {
+    fn propose(&self, proposer: &Address, proposal_type: ProposalType) -> u64 {
+        self.client.create_proposal(
+            proposer,
+            &proposal_type,
+            &String::from_str(&self.env, "proposal"),
+            &Bytes::new(&self.env),
+        )
+    }
+
+    fn propose_parameter(&self, proposer: &Address, key: u64, value: i128) -> u64 {
+        let mut payload = Bytes::from_array(&self.env, &key.to_le_bytes());
+        payload.extend_from_array(&value.to_le_bytes());
+        self.client.create_proposal(
+            proposer,
+            &ProposalType::UpdateParameter,
+            &String::from_str(&self.env, "parameter"),
+            &payload,
+        )
+    }
+}
+
+#[test]
+fn test_archive_and_bump_finalised_proposal() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    t.stake(&proposer, governance::PROPOSAL_DEPOSIT);
+    let id = t.propose(&proposer, ProposalType::UpdateParameter);
+
+    assert_eq!(
+        t.client.try_archive_proposal(&id),
+        Err(Ok(GovernanceError::ProposalNotFinalised))
+    );
+    t.client.cancel_proposal(&t.admin, &id);
+    assert_eq!(
+        t.client.try_archive_proposal(&id),
+        Err(Ok(GovernanceError::ArchiveDelayActive))
+    );
+
+    t.env.ledger().with_mut(|l| {
+        l.timestamp += governance::PROPOSAL_ARCHIVE_DELAY_SECONDS;
+    });
+    let summary = t.client.archive_proposal(&id);
+    assert_eq!(summary.status, ProposalStatus::Cancelled);
+    assert_eq!(t.client.get_archived_proposal(&id), Some(summary));
+    assert_eq!(
+        t.client.try_get_proposal(&id).unwrap_err(),
+        Ok(GovernanceError::ProposalNotFound)
+    );
+
+    t.client.bump_proposal_ttl(&id);
+    assert_eq!(
+        t.client.try_bump_proposal_ttl(&(id + 1)),
+        Err(Ok(GovernanceError::ProposalNotFound))
+    );
+}
+
+#[test]
+fn test_emergency_pause_halts_submissions_until_ratification_fails() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let whale = Address::generate(&t.env);
+    // The proposer's 100 XLM left after the deposit is ~5.3% of the 1_900
+    // XLM staked: above the emergency quorum, below the standard one.
+    t.stake(&proposer, governance::PROPOSAL_DEPOSIT + 100 * 10_000_000);
+    t.stake(&whale, 800 * 10_000_000);
+    assert_eq!(
+        t.client.get_emergency_params(),
+        (
+            governance::EMERGENCY_QUORUM_BPS,
+            governance::EMERGENCY_THRESHOLD_BPS
+        )
+    );
+
+    let id = t.propose(&proposer, ProposalType::EmergencyPause);
+    t.client.vote_on_proposal(&id, &proposer, &true);
+    assert_eq!(t.client.get_proposal(&id).status, ProposalStatus::Executed);
+    assert!(t.client.health_check().is_paused);
+
+    let pair = AssetPair {
+        base: Asset {
+            code: String::from_str(&t.env, "XLM"),
+            issuer: None,
+        },
+        quote: Asset {
+            code: String::from_str(&t.env, "USDC"),
+            issuer: None,
+        },
+    };
+    assert_eq!(
+        t.client.try_set_price(&t.admin, &pair, &1_000_000),
+        Err(Ok(OracleError::CircuitBreakerTripped))
+    );
+
+    let ratification_id = t.client.get_ratification_proposal(&id).unwrap();
+    let ratification = t.client.get_proposal(&ratification_id);
+    assert_eq!(
+        ratification.proposal_type,
+        ProposalType::RatifyEmergencyPause
+    );
+    assert_eq!(ratification.deposit, 0);
+
+    t.env.ledger().with_mut(|l| {
+        l.timestamp = ratification.voting_ends + 1;
+    });
+    assert_eq!(
+        t.client.finalise_proposal(&ratification_id),
+        ProposalStatus::Failed
+    );
+    assert!(!t.client.health_check().is_paused);
+    t.client.set_price(&t.admin, &pair, &1_000_000);
+}
+
+#[test]
+fn test_governance_stats_track_outcomes_and_deposits() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    t.stake(&proposer, 3 * governance::PROPOSAL_DEPOSIT);
+
+    let executed = t.propose(&proposer, ProposalType::UpdateParameter);
+    let cancelled = t.propose(&proposer, ProposalType::UpdateParameter);
+    let stats = t.client.get_governance_stats();
+    assert_eq!(stats.total_proposals, 2);
+    assert_eq!(stats.active, 2);
+
+    let deposit = t.client.get_proposal(&cancelled).deposit;
+    t.client.cancel_proposal(&t.admin, &cancelled);
+    // The proposer is the only staker, so one vote passes it; the empty
+    // payload then fails to execute.
+    t.client.vote_on_proposal(&executed, &proposer, &true);
+    let votes = t.client.get_proposal(&executed).votes_for;
+
+    let stats = t.client.get_governance_stats();
+    assert_eq!(stats.active, 0);
+    assert_eq!(stats.cancelled, 1);
+    assert_eq!(stats.execution_failed, 1);
+    assert_eq!(stats.participation_samples, 1);
+    assert_eq!(
+        stats.avg_participation_bps,
+        votes * 10_000 / t.client.get_total_staked()
+    );
+    assert_eq!(stats.quorum_samples, 1);
+    assert_eq!(stats.deposits_returned, deposit);
+}
+
+#[test]
+fn test_withdraw_proposal_before_first_vote() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let voter = Address::generate(&t.env);
+    let stake = 2 * governance::PROPOSAL_DEPOSIT;
+    t.stake(&proposer, stake);
+    t.stake(&voter, stake);
+
+    let id = t.propose(&proposer, ProposalType::UpdateParameter);
+    assert!(t.client.get_stake(&proposer) < stake);
+    assert_eq!(
+        t.client.try_withdraw_proposal(&voter, &id),
+        Err(Ok(GovernanceError::Unauthorized))
+    );
+    t.client.withdraw_proposal(&proposer, &id);
+    assert_eq!(t.client.get_stake(&proposer), stake);
+    assert_eq!(t.client.get_proposal(&id).status, ProposalStatus::Withdrawn);
+    assert_eq!(
+        t.client.try_withdraw_proposal(&proposer, &id),
+        Err(Ok(GovernanceError::ProposalNotActive))
+    );
+
+    let id = t.propose(&proposer, ProposalType::UpdateParameter);
+    t.client.vote_on_proposal(&id, &voter, &false);
+    assert_eq!(
+        t.client.try_withdraw_proposal(&proposer, &id),
+        Err(Ok(GovernanceError::ProposalHasVotes))
+    );
+}
+
+#[test]
+fn test_governed_quorum_applies_to_votes_of_its_type() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let minority = Address::generate(&t.env);
+    let majority = Address::generate(&t.env);
+    t.stake(&proposer, 3 * governance::PROPOSAL_DEPOSIT);
+    t.stake(&minority, 1_000 * 10_000_000);
+    t.stake(&majority, 6_000 * 10_000_000);
+    assert_eq!(
+        t.client.get_quorum_bps(&ProposalType::RemoveOracle),
+        governance::REMOVE_ORACLE_QUORUM_BPS
+    );
+
+    // The minority's 10% of stake is short of the RemoveOracle quorum.
+    let before = t.propose(&proposer, ProposalType::RemoveOracle);
+    t.client.vote_on_proposal(&before, &minority, &true);
+    assert_eq!(
+        t.client.get_proposal(&before).status,
+        ProposalStatus::Active
+    );
+
+    // Key 6 is the RemoveOracle quorum.
+    let update = t.propose_parameter(&proposer, 6, 1_000);
+    t.client.vote_on_proposal(&update, &majority, &true);
+    assert_eq!(
+        t.client.get_proposal(&update).status,
+        ProposalStatus::Executed
+    );
+    assert_eq!(t.client.get_quorum_bps(&ProposalType::RemoveOracle), 1_000);
+    assert_eq!(
+        t.client.get_quorum_bps(&ProposalType::AddOracle),
+        governance::QUORUM_BPS
+    );
+
+    // The same vote now reaches quorum and the proposal is dispatched (the
+    // empty payload makes it fail).
+    let after = t.propose(&proposer, ProposalType::RemoveOracle);
+    t.client.vote_on_proposal(&after, &minority, &true);
+    assert_eq!(
+        t.client.get_proposal(&after).status,
+        ProposalStatus::ExecutionFailed
+    );
+}
+
+#[test]
+fn test_failed_execution_is_retried_by_keeper_after_backoff() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let oracle = Address::generate(&t.env);
+    t.stake(&proposer, 2 * governance::PROPOSAL_DEPOSIT);
+    t.client.register_oracle(&t.admin, &oracle);
+
+    // Adding an oracle that is already registered fails to execute.
+    let id = t.client.create_proposal(
+        &proposer,
+        &ProposalType::AddOracle,
+        &String::from_str(&t.env, "add oracle"),
+        &oracle.clone().to_xdr(&t.env),
+    );
+    t.client.vote_on_proposal(&id, &proposer, &true);
+    assert_eq!(
+        t.client.get_proposal(&id).status,
+        ProposalStatus::ExecutionFailed
+    );
+    assert_eq!(t.client.get_retry_queue(), soroban_sdk::vec![&t.env, id]);
+    let state = t.client.get_retry_state(&id).unwrap();
+    assert_eq!(state.failures, 1);
+
+    // Not due yet.
+    assert_eq!(t.client.process_retry_queue(&10), 0);
+
+    t.client.remove_oracle(&t.admin, &oracle);
+    t.env
+        .ledger()
+        .with_mut(|l| l.timestamp = state.next_retry_at);
+    assert_eq!(t.client.process_retry_queue(&10), 1);
+    assert_eq!(t.client.get_proposal(&id).status, ProposalStatus::Executed);
+    assert!(t.client.get_oracles().contains(&oracle));
+    assert!(t.client.get_retry_queue().is_empty());
+    assert_eq!(
+        t.client.try_retry_execution(&id),
+        Err(Ok(GovernanceError::ProposalNotRetryable))
+    );
+}
+
+#[test]
+fn test_voting_rewards_are_paid_in_stake_token() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let voter = Address::generate(&t.env);
+    let funder = Address::generate(&t.env);
+    t.stake(&proposer, governance::PROPOSAL_DEPOSIT);
+    t.stake(&voter, 3 * governance::PROPOSAL_DEPOSIT);
+    token::StellarAssetClient::new(&t.env, &t.token.address).mint(&funder, &1_000_000);
+    t.client.fund_reward_pool(&funder, &1_000_000);
+    assert_eq!(t.token.balance(&funder), 0);
+
+    let id = t.propose(&proposer, ProposalType::UpdateParameter);
+    t.client.vote_on_proposal(&id, &voter, &false);
+    t.env.ledger().with_mut(|l| {
+        l.timestamp += governance::VOTING_PERIOD_SECONDS;
+    });
+    assert_eq!(t.client.finalise_proposal(&id), ProposalStatus::Failed);
+    let reward = t.client.get_proposal_reward(&id).unwrap();
+    assert!(reward.amount > 0);
+
+    let ids = soroban_sdk::vec![&t.env, id];
+    assert_eq!(
+        t.client.try_claim_voting_rewards(&proposer, &ids),
+        Err(Ok(GovernanceError::NothingToClaim))
+    );
+    assert_eq!(t.client.claim_voting_rewards(&voter, &ids), reward.amount);
+    assert_eq!(t.token.balance(&voter), reward.amount);
+    assert_eq!(
+        t.client.try_claim_voting_rewards(&voter, &ids),
+        Err(Ok(GovernanceError::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_quorum_failure_rebates_deposit_to_voters_but_not_proposer() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let voter = Address::generate(&t.env);
+    let whale = Address::generate(&t.env);
+    let deposit = governance::PROPOSAL_DEPOSIT;
+    t.stake(&proposer, 2 * deposit);
+    t.stake(&voter, deposit);
+
+    let id = t.propose(&proposer, ProposalType::UpdateParameter);
+    t.stake(&whale, 100 * deposit);
+    t.client.vote_on_proposal(&id, &proposer, &true);
+    t.client.vote_on_proposal(&id, &voter, &true);
+    t.env.ledger().with_mut(|l| {
+        l.timestamp += governance::VOTING_PERIOD_SECONDS;
+    });
+    assert_eq!(t.client.finalise_proposal(&id), ProposalStatus::Failed);
+
+    // Half the deposit goes to the voters other than the proposer, half to
+    // the pool that funds every proposal's voter reward.
+    let rebate = t.client.get_deposit_rebate(&id).unwrap();
+    assert_eq!(rebate.amount, deposit / 2);
+    assert_eq!(rebate.total_weight, deposit);
+    let reward = t.client.get_proposal_reward(&id).unwrap();
+    assert_eq!(reward.amount, deposit / 2 / 10);
+    assert_eq!(reward.total_weight, 2 * deposit);
+
+    let ids = soroban_sdk::vec![&t.env, id];
+    assert_eq!(
+        t.client.claim_voting_rewards(&proposer, &ids),
+        reward.amount / 2
+    );
+    assert_eq!(
+        t.client.claim_voting_rewards(&voter, &ids),
+        rebate.amount + reward.amount / 2
+    );
+    assert_eq!(t.token.balance(&voter), rebate.amount + reward.amount / 2);
+}
+
+#[test]
+fn test_vote_receipt_records_direction_weight_and_time() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let voter = Address::generate(&t.env);
+    t.stake(&proposer, 2 * governance::PROPOSAL_DEPOSIT);
+    t.stake(&voter, 1_000);
+
+    let id = t.propose(&proposer, ProposalType::UpdateParameter);
+    assert_eq!(t.client.get_vote(&id, &voter), None);
+    t.env.ledger().with_mut(|l| l.timestamp += 60);
+    t.client.vote_on_proposal(&id, &voter, &false);
+
+    assert!(t.client.has_voted(&id, &voter));
+    assert_eq!(
+        t.client.get_vote(&id, &voter),
+        Some(VoteReceipt {
+            support: false,
+            weight: 1_000,
+            timestamp: t.env.ledger().timestamp(),
+        })
+    );
+}
+
+#[test]
+fn test_proposal_charges_deposit_scaled_by_total_stake() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let whale = Address::generate(&t.env);
+    t.stake(&proposer, governance::PROPOSAL_DEPOSIT);
+    assert_eq!(
+        t.client.get_required_deposit(),
+        governance::PROPOSAL_DEPOSIT
+    );
+
+    // 1% of 200_000 XLM is twice the floor.
+    t.stake(&whale, 200_000 * 10_000_000 - governance::PROPOSAL_DEPOSIT);
+    let deposit = t.client.get_required_deposit();
+    assert_eq!(deposit, 2 * governance::PROPOSAL_DEPOSIT);
+    assert_eq!(
+        t.client.try_create_proposal(
+            &proposer,
+            &ProposalType::UpdateParameter,
+            &String::from_str(&t.env, "proposal"),
+            &Bytes::new(&t.env),
+        ),
+        Err(Ok(GovernanceError::InsufficientStake))
+    );
+
+    let whale_stake = t.client.get_stake(&whale);
+    let id = t.propose(&whale, ProposalType::UpdateParameter);
+    assert_eq!(t.client.get_proposal(&id).deposit, deposit);
+    assert_eq!(t.client.get_stake(&whale), whale_stake - deposit);
+}
+
+#[test]
+fn test_replace_oracle_swaps_in_place() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let outgoing = Address::generate(&t.env);
+    let staying = Address::generate(&t.env);
+    let incoming = Address::generate(&t.env);
+    t.stake(&proposer, 2 * governance::PROPOSAL_DEPOSIT);
+    t.client.register_oracle(&t.admin, &outgoing);
+    t.client.register_oracle(&t.admin, &staying);
+    assert_eq!(t.client.get_replacement_policy(), ReputationPolicy::Reset);
+
+    let description = String::from_str(&t.env, "rotate");
+    assert_eq!(
+        t.client
+            .try_propose_oracle_replacement(&proposer, &incoming, &outgoing, &description),
+        Err(Ok(GovernanceError::OracleNotFound))
+    );
+    assert_eq!(
+        t.client
+            .try_propose_oracle_replacement(&proposer, &outgoing, &staying, &description),
+        Err(Ok(GovernanceError::OracleAlreadyExists))
+    );
+
+    let id = t
+        .client
+        .propose_oracle_replacement(&proposer, &outgoing, &incoming, &description);
+    assert_eq!(
+        t.client.get_oracle_replacement(&id),
+        Some(OracleReplacement {
+            outgoing: outgoing.clone(),
+            incoming: incoming.clone(),
+        })
+    );
+    t.client.vote_on_proposal(&id, &proposer, &true);
+    assert_eq!(t.client.get_proposal(&id).status, ProposalStatus::Executed);
+    assert_eq!(
+        t.client.get_oracles(),
+        soroban_sdk::vec![&t.env, incoming.clone(), staying]
+    );
+    assert_eq!(
+        t.client.get_oracle_reputation(&incoming).reputation_score,
+        reputation::NEUTRAL_REPUTATION
+    );
+}
+
+#[test]
+fn test_preview_shows_votes_needed_to_pass() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let against = Address::generate(&t.env);
+    let undecided = Address::generate(&t.env);
+    t.stake(&proposer, governance::PROPOSAL_DEPOSIT);
+    t.stake(&against, 1_000 * 10_000_000);
+    t.stake(&undecided, 3_000 * 10_000_000);
+
+    let id = t.propose(&proposer, ProposalType::UpdateParameter);
+    t.client.vote_on_proposal(&id, &against, &false);
+    let preview = t.client.preview_proposal_outcome(&id);
+    assert_eq!(preview.status, ProposalStatus::Active);
+    assert_eq!(preview.quorum_bps, 2_000);
+    assert_eq!(preview.required_quorum_bps, governance::QUORUM_BPS);
+    assert_eq!(preview.approval_bps, 0);
+    assert_eq!(
+        preview.required_approval_bps,
+        governance::APPROVAL_THRESHOLD_BPS
+    );
+    assert_eq!(preview.time_remaining, governance::VOTING_PERIOD_SECONDS);
+    assert!(!preview.passing);
+    let needed = preview.votes_needed.unwrap();
+    assert!(needed > 0 && needed <= t.client.get_stake(&undecided));
+
+    t.client.vote_on_proposal(&id, &undecided, &true);
+    assert_eq!(
+        t.client.preview_proposal_outcome(&id).status,
+        ProposalStatus::ExecutionFailed
+    );
+    assert_eq!(
+        t.client.try_preview_proposal_outcome(&(id + 1)),
+        Err(Ok(GovernanceError::ProposalNotFound))
+    );
+}
+
+#[test]
+fn test_pair_delisting_and_relisting_by_vote() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let oracle = Address::generate(&t.env);
+    t.stake(&proposer, 3 * governance::PROPOSAL_DEPOSIT);
+    t.client.register_oracle(&t.admin, &oracle);
+    let pair = AssetPair {
+        base: Asset {
+            code: String::from_str(&t.env, "XLM"),
+            issuer: None,
+        },
+        quote: Asset {
+            code: String::from_str(&t.env, "USDC"),
+            issuer: None,
+        },
+    };
+    let description = String::from_str(&t.env, "pair");
+
+    let delist = t
+        .client
+        .propose_pair_delisting(&proposer, &pair, &description);
+    assert_eq!(t.client.get_pair_listing(&delist).unwrap().pair, pair);
+    t.client.vote_on_proposal(&delist, &proposer, &true);
+    assert!(!t.client.get_pair_config(&pair).enabled);
+    let now = t.env.ledger().timestamp();
+    assert_eq!(
+        t.client.try_submit_price(&oracle, &pair, &1_000_000, &now),
+        Err(Ok(OracleError::PairDisabled))
+    );
+    assert_eq!(
+        t.client
+            .try_propose_pair_delisting(&proposer, &pair, &description),
+        Err(Ok(GovernanceError::PairDisabled))
+    );
+
+    let list = t
+        .client
+        .propose_pair_listing(&proposer, &pair, &None, &description);
+    t.client.vote_on_proposal(&list, &proposer, &true);
+    assert_eq!(
+        t.client.get_proposal(&list).status,
+        ProposalStatus::Executed
+    );
+    assert!(t.client.get_pair_config(&pair).enabled);
+    t.client.submit_price(&oracle, &pair, &1_000_000, &now);
+}
+
+#[test]
+fn test_governance_added_oracle_starts_idle_decay_clock_on_admission() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    let oracle = Address::generate(&t.env);
+    t.stake(&proposer, 2 * governance::PROPOSAL_DEPOSIT);
+    let added_at = 10 * 24 * 60 * 60 + 3_600;
+    t.env.ledger().with_mut(|l| l.timestamp = added_at);
+
+    let id = t.client.create_proposal(
+        &proposer,
+        &ProposalType::AddOracle,
+        &String::from_str(&t.env, "add oracle"),
+        &oracle.clone().to_xdr(&t.env),
+    );
+    t.client.vote_on_proposal(&id, &proposer, &true);
+    assert_eq!(t.client.get_proposal(&id).status, ProposalStatus::Executed);
+
+    // Decay is counted from admission, not from the epoch.
+    let rep = t.client.get_oracle_reputation(&oracle);
+    assert_eq!(rep.last_update, added_at);
+    assert_eq!(rep.reputation_score, reputation::NEUTRAL_REPUTATION);
+}
+
+#[test]
+fn test_parameter_votes_set_pair_config_defaults() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    t.stake(&proposer, 4 * governance::PROPOSAL_DEPOSIT);
+    let pair = AssetPair {
+        base: Asset {
+            code: String::from_str(&t.env, "XLM"),
+            issuer: None,
+        },
+        quote: Asset {
+            code: String::from_str(&t.env, "USDC"),
+            issuer: None,
+        },
+    };
+
+    // Keys 0-2: submissions per round, staleness TTL, max deviation.
+    for (key, value) in [(0u64, 5i128), (1, 600), (2, 300)] {
+        let id = t.propose_parameter(&proposer, key, value);
+        t.client.vote_on_proposal(&id, &proposer, &true);
+        assert_eq!(t.client.get_proposal(&id).status, ProposalStatus::Executed);
+    }
+    let config = t.client.get_pair_config(&pair);
+    assert_eq!(config.min_sources, 5);
+    assert_eq!(config.stale_secs, 600);
+    assert!(config.aging_secs <= 600);
+    assert_eq!(config.max_deviation_bps, 300);
+
+    // A deviation above 100% would leave every default config invalid.
+    let id = t.propose_parameter(&proposer, 2, 10_001);
+    t.client.vote_on_proposal(&id, &proposer, &true);
+    assert_eq!(
+        t.client.get_proposal(&id).status,
+        ProposalStatus::ExecutionFailed
+    );
+    assert_eq!(t.client.get_pair_config(&pair).max_deviation_bps, 300);
+}
+
+#[test]
+fn test_oracle_removed_by_governance_starts_unbonding() {
+    let t = setup();
+    let proposer = Address::generate(&t.env);
+    t.stake(&proposer, 2 * governance::PROPOSAL_DEPOSIT);
+    let oracle = Address::generate(&t.env);
+    for o in [
+        oracle.clone(),
+        Address::generate(&t.env),
+        Address::generate(&t.env),
+    ] {
+        t.client.register_oracle(&t.admin, &o);
+    }
+    token::StellarAssetClient::new(&t.env, &t.token.address).mint(&oracle, &5_000);
+    t.client.post_bond(&oracle, &5_000);
+    assert_eq!(
+        t.client.try_claim_bond(&oracle),
+        Err(Ok(OracleError::BondLocked))
+    );
+
+    let id = t.client.create_proposal(
+        &proposer,
+        &ProposalType::RemoveOracle,
+        &String::from_str(&t.env, "remove oracle"),
+        &oracle.clone().to_xdr(&t.env),
+    );
+    t.client.vote_on_proposal(&id, &proposer, &true);
+    assert_eq!(t.client.get_proposal(&id).status, ProposalStatus::Executed);
+    assert!(!t.client.get_oracles().contains(&oracle));
+
+    // Still slashable until the unbonding period has passed.
+    assert_eq!(
+        t.client.try_claim_bond(&oracle),
+        Err(Ok(OracleError::BondLocked))
+    );
+    let (_, unbonding_period) = t.client.get_bond_config();
+    t.env.ledger().with_mut(|l| l.timestamp += unbonding_period);
+    assert_eq!(t.client.claim_bond(&oracle), 5_000);
+    assert_eq!(t.token.balance(&oracle), 5_000);
+}
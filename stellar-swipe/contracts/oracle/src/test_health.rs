@@ -89,7 +89,7 @@ fn heartbeat_is_healthy_after_recent_update() {
     let asset_pair = pair(&env);
     client.initialize(&admin, &xlm(&env));
 
-    client.set_price(&asset_pair, &1_000);
+    client.set_price(&admin, &asset_pair, &1_000);
     let health = client.check_oracle_heartbeat(&asset_pair);
 
     assert!(health.is_healthy);
@@ -107,7 +107,7 @@ fn heartbeat_becomes_stale_after_price_age_threshold() {
     let admin = Address::generate(&env);
     let asset_pair = pair(&env);
     client.initialize(&admin, &xlm(&env));
-    client.set_price(&asset_pair, &1_000);
+    client.set_price(&admin, &asset_pair, &1_000);
 
     env.ledger().with_mut(|ledger| ledger.sequence_number += 61);
     let health = client.check_oracle_heartbeat(&asset_pair);
@@ -127,7 +127,7 @@ fn heartbeat_becomes_dead_after_dead_threshold() {
     let admin = Address::generate(&env);
     let asset_pair = pair(&env);
     client.initialize(&admin, &xlm(&env));
-    client.set_price(&asset_pair, &1_000);
+    client.set_price(&admin, &asset_pair, &1_000);
 
     env.ledger()
         .with_mut(|ledger| ledger.sequence_number += 1_441);
@@ -166,7 +166,7 @@ fn heartbeat_missed_event_emits_when_becoming_stale() {
     let admin = Address::generate(&env);
     let asset_pair = pair(&env);
     client.initialize(&admin, &xlm(&env));
-    client.set_price(&asset_pair, &1_000);
+    client.set_price(&admin, &asset_pair, &1_000);
 
     env.ledger().with_mut(|ledger| ledger.sequence_number += 61);
     assert_eq!(
@@ -186,7 +186,7 @@ fn heartbeat_missed_event_emits_when_transitioning_to_dead() {
     let admin = Address::generate(&env);
     let asset_pair = pair(&env);
     client.initialize(&admin, &xlm(&env));
-    client.set_price(&asset_pair, &1_000);
+    client.set_price(&admin, &asset_pair, &1_000);
 
     env.ledger().with_mut(|ledger| ledger.sequence_number += 61);
     assert_eq!(
@@ -1,6 +1,9 @@
-use soroban_sdk::{contracttype, Address, Bytes, Env, Vec};
+use soroban_sdk::{contracttype, Address, Bytes};
 use stellar_swipe_common::AssetPair;
 
+use crate::bond::OracleBond;
+use crate::sep40::Sep40Asset;
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct OracleReputation {
@@ -10,6 +13,20 @@ pub struct OracleReputation {
     pub reputation_score: u32,
     pub weight: u32,
     pub last_slash: u64,
+    /// Time the score was last refreshed; idle decay is measured from here.
+    pub last_update: u64,
+}
+
+/// What governance needs to judge one operator of the oracle set.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct OracleStats {
+    pub oracle: Address,
+    /// Reputation with idle decay applied up to now.
+    pub reputation: OracleReputation,
+    pub bond: OracleBond,
+    /// Consecutive rounds its rolling deviation exceeded `p_dev`.
+    pub deviation_streak: u32,
 }
 
 #[contracttype]
@@ -18,6 +35,8 @@ pub struct PriceSubmission {
     pub oracle: Address,
     pub price: i128,
     pub timestamp: u64,
+    /// Base-asset volume the oracle saw trade at `price`; 0 if not reported.
+    pub volume: i128,
 }
 
 #[contracttype]
@@ -38,12 +57,45 @@ pub enum StorageKey {
     PriceMap(AssetPair),
     OracleStats,
     Oracles,
-    PriceSubmissions,
-    ConsensusPrice,
+    /// Submissions for the pair's open round, at most one per oracle.
+    RoundSubmissions(AssetPair),
+    /// Id of the pair's open round (starts at 0).
+    RoundId(AssetPair),
+    /// Last finalised round result for the pair.
+    ConsensusPrice(AssetPair),
+    /// Last finalised round result for the pair with its source dispersion.
+    PriceConfidence(AssetPair),
+    /// Submissions needed before a round is aggregated.
+    MinSubmissions,
+    /// Default `p_dev` outlier threshold in BPS, see `pair_config`.
+    MaxDeviationBps,
+    /// Default age in seconds after which a price turns `Critical`.
+    PriceTtl,
     PauseStates,
     OracleWeight(Address),
+    /// Consecutive rounds the oracle's rolling deviation exceeded `p_dev`.
+    DeviationStreak(Address),
+    /// Streak length that triggers an automatic slash.
+    DeviationStreakLimit,
     PendingAdmin,
     PendingAdminExpiry,
+    /// When the first submission of the pair's open round arrived.
+    RoundOpenedAt(AssetPair),
+    /// Seconds after which an open round may close short of `min_sources`.
+    RoundDuration,
+    /// Result of one finalised round, kept for audit.
+    RoundResult(AssetPair, u64),
+}
+
+/// Where a pair's price can come from, see `fallback`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PriceSource {
+    /// This contract's own oracle rounds.
+    Internal,
+    /// An external SEP-40 feed contract (e.g. Reflector) and the asset it
+    /// lists the pair under.
+    Sep40(Address, Sep40Asset),
 }
 
 #[contracttype]
@@ -51,7 +103,38 @@ pub enum StorageKey {
 pub struct ConsensusPriceData {
     pub price: i128,
     pub timestamp: u64,
+    /// Oracles behind the price; 0 when an external feed served it.
     pub num_oracles: u32,
+    pub round: u64,
+    /// Source that served the price.
+    pub source: PriceSource,
+}
+
+/// An aggregated price with how far apart the sources behind it were, so
+/// consumers can discount prices the sources did not agree on.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceWithConfidence {
+    pub price: i128,
+    /// Range of the contributing submissions (max - min) in BPS of `price`.
+    pub spread_bps: u32,
+    pub num_sources: u32,
+    pub timestamp: u64,
+}
+
+/// State of a pair's open round.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoundInfo {
+    pub round: u64,
+    /// Arrival of the round's first submission; 0 while it has none.
+    pub opened_at: u64,
+    /// When the round may be finalised whatever its submission count; 0
+    /// while it has none.
+    pub deadline: u64,
+    pub submissions: u32,
+    /// Submissions that close the round before its deadline.
+    pub required: u32,
 }
 
 #[contracttype]
@@ -1,17 +1,23 @@
 use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+use stellar_swipe_common::{RoundingMode, DEFAULT_ROUNDING_MODE, SECONDS_PER_DAY};
 use stellar_swipe_common::emergency::{
-    CircuitBreakerConfig, CircuitBreakerStats, PauseState, CAT_ALL, CAT_SIGNALS, CAT_STAKES,
+    CircuitBreakerConfig, CircuitBreakerStats, PauseState, CAT_ALL,
     CAT_TRADING,
 };
 
 use crate::errors::AdminError;
 use crate::events::*;
+use crate::types::AttributionMode;
 
 // Constants
 pub const MAX_FEE_BPS: u32 = 100; // 1% max fee
 pub const MAX_RISK_PERCENTAGE: u32 = 100; // 100% max
 /// Wall-clock admin transfer validity (matches admin transfer tests).
 const ADMIN_TRANSFER_EXPIRY_SECS: u64 = 48 * 60 * 60;
+/// The same 48 hours in 5-second ledgers.
+const ADMIN_TRANSFER_EXPIRY_LEDGERS: u32 = 34_560;
+/// How long a manual trading pause lasts before it lifts on its own.
+pub const TRADING_PAUSE_SECS: u64 = 48 * 60 * 60;
 
 // Default values
 pub const DEFAULT_MIN_STAKE: i128 = 100_000_000; // 100 XLM (7 decimals)
@@ -21,6 +27,12 @@ pub const DEFAULT_POSITION_LIMIT: u32 = 20; // 20%
 pub const DEFAULT_BRONZE_SIGNAL_LIMIT: u32 = 5;
 pub const DEFAULT_SILVER_SIGNAL_LIMIT: u32 = 10;
 pub const DEFAULT_GOLD_SIGNAL_LIMIT: u32 = 20;
+pub const DEFAULT_ATTRIBUTION_HORIZON: u64 = SECONDS_PER_DAY;
+/// Minimum time between two signals from one provider; 0 disables it.
+pub const DEFAULT_SIGNAL_COOLDOWN_SECS: u64 = 0;
+pub const DEFAULT_LB_MIN_CLOSED_SIGNALS: u32 = 10;
+pub const MAX_LB_MIN_CLOSED_SIGNALS: u32 = 1_000;
+pub const MAX_LB_MIN_AGE_SECS: u64 = 365 * SECONDS_PER_DAY;
 
 #[contracttype]
 #[derive(Clone)]
@@ -44,12 +56,22 @@ pub enum AdminStorageKey {
     BronzeSignalLimit,
     SilverSignalLimit,
     GoldSignalLimit,
+    RoundingMode,
+    AttributionMode,
+    AttributionHorizon,
+    SignalCooldown,
+    /// When a provider last created a signal.
+    LastSignalAt(Address),
+    LeaderboardQualification,
+    /// Guard against deleting the contract; on unless governance lifts it.
+    PreventSelfDestruct,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PendingAdminTransfer {
     pub pending_admin: Address,
+    pub expires_at: u64,
     pub expires_at_ledger: u32,
 }
 
@@ -65,6 +87,31 @@ pub struct AdminConfig {
     pub gold_signal_limit: u32,
 }
 
+/// What a provider needs to appear on the ranked leaderboards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardQualification {
+    /// Successful plus failed signals.
+    pub min_closed_signals: u32,
+    /// Total copies across all signals.
+    pub min_adopters: u32,
+    /// Total traded volume.
+    pub min_volume: i128,
+    /// Time since the provider's first signal.
+    pub min_age_secs: u64,
+}
+
+impl Default for LeaderboardQualification {
+    fn default() -> Self {
+        Self {
+            min_closed_signals: DEFAULT_LB_MIN_CLOSED_SIGNALS,
+            min_adopters: 1,
+            min_volume: 0,
+            min_age_secs: 0,
+        }
+    }
+}
+
 /// Initialize admin with default parameters
 pub fn init_admin(env: &Env, admin: Address) -> Result<(), AdminError> {
     if has_admin(env) {
@@ -200,7 +247,9 @@ fn get_pending_admin_transfer(env: &Env) -> Option<PendingAdminTransfer> {
 
 fn require_active_pending_admin_transfer(env: &Env) -> Result<PendingAdminTransfer, AdminError> {
     let pending = get_pending_admin_transfer(env).ok_or(AdminError::PendingAdminNotFound)?;
-    if env.ledger().sequence() > pending.expires_at_ledger {
+    if env.ledger().timestamp() >= pending.expires_at
+        || env.ledger().sequence() > pending.expires_at_ledger
+    {
         env.storage()
             .instance()
             .remove(&AdminStorageKey::PendingAdminTransfer);
@@ -224,13 +273,17 @@ pub fn propose_admin_transfer(
     let pending = PendingAdminTransfer {
         pending_admin: new_admin.clone(),
         expires_at,
+        expires_at_ledger: env
+            .ledger()
+            .sequence()
+            .saturating_add(ADMIN_TRANSFER_EXPIRY_LEDGERS),
     };
 
     env.storage()
         .instance()
         .set(&AdminStorageKey::PendingAdminTransfer, &pending);
 
-    emit_admin_transfer_proposed(env, caller.clone(), new_admin, expires_at_ledger as u64);
+    emit_admin_transfer_proposed(env, caller.clone(), new_admin, expires_at);
     Ok(())
 }
 
@@ -270,7 +323,7 @@ pub fn set_min_stake(env: &Env, caller: &Address, new_amount: i128) -> Result<()
     require_admin(env, caller)?;
     caller.require_auth();
 
-    if new_amount <= 0 {
+    if new_amount < 0 {
         return Err(AdminError::InvalidParameter);
     }
 
@@ -337,6 +390,80 @@ pub fn get_trade_fee(env: &Env) -> u32 {
         .unwrap_or(DEFAULT_TRADE_FEE_BPS)
 }
 
+/// Set the rounding policy used for ROI and PnL basis-point math
+pub fn set_rounding_mode(env: &Env, caller: &Address, mode: RoundingMode) -> Result<(), AdminError> {
+    require_admin(env, caller)?;
+    caller.require_auth();
+
+    let old_value = get_rounding_mode(env);
+    env.storage()
+        .instance()
+        .set(&AdminStorageKey::RoundingMode, &mode);
+
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "rounding_mode"),
+        old_value as i128,
+        mode as i128,
+    );
+    Ok(())
+}
+
+/// Get the rounding policy for basis-point math (banker's rounding by default)
+pub fn get_rounding_mode(env: &Env) -> RoundingMode {
+    env.storage()
+        .instance()
+        .get(&AdminStorageKey::RoundingMode)
+        .unwrap_or(DEFAULT_ROUNDING_MODE)
+}
+
+/// Set how signal success is attributed. `horizon_secs` is the delay after
+/// publication at which the oracle price is sampled in `OracleHorizon` mode.
+pub fn set_attribution_mode(
+    env: &Env,
+    caller: &Address,
+    mode: AttributionMode,
+    horizon_secs: u64,
+) -> Result<(), AdminError> {
+    require_admin(env, caller)?;
+    caller.require_auth();
+
+    if mode == AttributionMode::OracleHorizon && horizon_secs == 0 {
+        return Err(AdminError::InvalidParameter);
+    }
+
+    let old_value = get_attribution_mode(env);
+    env.storage()
+        .instance()
+        .set(&AdminStorageKey::AttributionMode, &mode);
+    env.storage()
+        .instance()
+        .set(&AdminStorageKey::AttributionHorizon, &horizon_secs);
+
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "attribution_mode"),
+        old_value as i128,
+        mode as i128,
+    );
+    Ok(())
+}
+
+/// Get the signal success attribution mode (average of executions by default)
+pub fn get_attribution_mode(env: &Env) -> AttributionMode {
+    env.storage()
+        .instance()
+        .get(&AdminStorageKey::AttributionMode)
+        .unwrap_or_default()
+}
+
+pub fn get_attribution_horizon(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&AdminStorageKey::AttributionHorizon)
+        .unwrap_or(DEFAULT_ATTRIBUTION_HORIZON)
+}
+
 /// Set risk defaults (stop loss and position limit)
 pub fn set_risk_defaults(
     env: &Env,
@@ -437,13 +564,13 @@ pub fn pause_category(
     Ok(())
 }
 
-/// Pause trading (legacy wrapper)
+/// Pause trading (legacy wrapper). Lifts itself after `TRADING_PAUSE_SECS`.
 pub fn pause_trading(env: &Env, caller: &Address) -> Result<(), AdminError> {
     pause_category(
         env,
         caller,
         String::from_str(env, CAT_TRADING),
-        None,
+        Some(TRADING_PAUSE_SECS),
         String::from_str(env, "Manual pause"),
     )
 }
@@ -555,6 +682,8 @@ pub fn get_admin_config(env: &Env) -> AdminConfig {
     }
 }
 
+/// Set the max concurrently Active signals per provider for each stake tier.
+/// Limits must be non-zero and non-decreasing from bronze to gold.
 pub fn set_tier_signal_limits(
     env: &Env,
     caller: &Address,
@@ -564,6 +693,14 @@ pub fn set_tier_signal_limits(
 ) -> Result<(), AdminError> {
     require_admin(env, caller)?;
     caller.require_auth();
+
+    if bronze == 0 || silver < bronze || gold < silver {
+        return Err(AdminError::InvalidParameter);
+    }
+
+    let old_bronze = get_bronze_signal_limit(env);
+    let old_silver = get_silver_signal_limit(env);
+    let old_gold = get_gold_signal_limit(env);
     env.storage()
         .instance()
         .set(&AdminStorageKey::BronzeSignalLimit, &bronze);
@@ -573,9 +710,124 @@ pub fn set_tier_signal_limits(
     env.storage()
         .instance()
         .set(&AdminStorageKey::GoldSignalLimit, &gold);
+
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "bronze_signal_limit"),
+        old_bronze as i128,
+        bronze as i128,
+    );
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "silver_signal_limit"),
+        old_silver as i128,
+        silver as i128,
+    );
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "gold_signal_limit"),
+        old_gold as i128,
+        gold as i128,
+    );
+    Ok(())
+}
+
+/// Set the minimum time, in seconds, a provider must wait between creating
+/// two signals. 0 disables the cooldown.
+pub fn set_signal_cooldown(env: &Env, caller: &Address, cooldown_secs: u64) -> Result<(), AdminError> {
+    require_admin(env, caller)?;
+    caller.require_auth();
+
+    let old_value = get_signal_cooldown(env);
+    env.storage()
+        .instance()
+        .set(&AdminStorageKey::SignalCooldown, &cooldown_secs);
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "signal_cooldown"),
+        old_value as i128,
+        cooldown_secs as i128,
+    );
+    Ok(())
+}
+
+pub fn get_signal_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&AdminStorageKey::SignalCooldown)
+        .unwrap_or(DEFAULT_SIGNAL_COOLDOWN_SECS)
+}
+
+/// Set the leaderboard qualification rules. Providers are re-checked the next
+/// time their stats change; existing index entries are not swept.
+pub fn set_leaderboard_qualification(
+    env: &Env,
+    caller: &Address,
+    rules: LeaderboardQualification,
+) -> Result<(), AdminError> {
+    require_admin(env, caller)?;
+    caller.require_auth();
+
+    if rules.min_closed_signals == 0
+        || rules.min_closed_signals > MAX_LB_MIN_CLOSED_SIGNALS
+        || rules.min_volume < 0
+        || rules.min_age_secs > MAX_LB_MIN_AGE_SECS
+    {
+        return Err(AdminError::InvalidParameter);
+    }
+
+    let old = get_leaderboard_qualification(env);
+    env.storage()
+        .instance()
+        .set(&AdminStorageKey::LeaderboardQualification, &rules);
+
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "lb_min_signals"),
+        old.min_closed_signals as i128,
+        rules.min_closed_signals as i128,
+    );
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "lb_min_adopters"),
+        old.min_adopters as i128,
+        rules.min_adopters as i128,
+    );
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "lb_min_volume"),
+        old.min_volume,
+        rules.min_volume,
+    );
+    emit_parameter_updated(
+        env,
+        soroban_sdk::Symbol::new(env, "lb_min_age"),
+        old.min_age_secs as i128,
+        rules.min_age_secs as i128,
+    );
     Ok(())
 }
 
+pub fn get_leaderboard_qualification(env: &Env) -> LeaderboardQualification {
+    env.storage()
+        .instance()
+        .get(&AdminStorageKey::LeaderboardQualification)
+        .unwrap_or_default()
+}
+
+pub fn get_last_signal_at(env: &Env, provider: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&AdminStorageKey::LastSignalAt(provider.clone()))
+}
+
+pub fn record_signal_created(env: &Env, provider: &Address) {
+    env.storage().persistent().set(
+        &AdminStorageKey::LastSignalAt(provider.clone()),
+        &env.ledger().timestamp(),
+    );
+}
+
 pub fn get_bronze_signal_limit(env: &Env) -> u32 {
     env.storage()
         .instance()
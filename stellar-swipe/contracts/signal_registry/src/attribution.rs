@@ -0,0 +1,94 @@
+//! Signal success attribution.
+//!
+//! The deployment's `AttributionMode` decides which ROI a signal is judged on
+//! when it settles. Each execution is folded into a small per-signal record so
+//! any mode can be evaluated without replaying trade history.
+
+use soroban_sdk::{contracttype, Env};
+use stellar_swipe_common::{div_round, RoundingMode};
+
+use crate::performance;
+use crate::types::{AttributionMode, Signal, TradeExecution};
+
+#[contracttype]
+pub enum AttributionKey {
+    Signal(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignalAttribution {
+    /// ROI of the first recorded execution, in bps.
+    pub first_roi: Option<i128>,
+    /// Sum of `roi * volume` over all executions.
+    pub volume_weighted_roi_sum: i128,
+    /// Oracle price sampled at the attribution horizon.
+    pub horizon_price: Option<i128>,
+}
+
+pub fn get_attribution(env: &Env, signal_id: u64) -> SignalAttribution {
+    env.storage()
+        .persistent()
+        .get(&AttributionKey::Signal(signal_id))
+        .unwrap_or_default()
+}
+
+fn save_attribution(env: &Env, signal_id: u64, attribution: &SignalAttribution) {
+    env.storage()
+        .persistent()
+        .set(&AttributionKey::Signal(signal_id), attribution);
+}
+
+/// Fold one execution into the signal's attribution record.
+pub fn record_execution(env: &Env, trade: &TradeExecution) {
+    let mut attribution = get_attribution(env, trade.signal_id);
+    if attribution.first_roi.is_none() {
+        attribution.first_roi = Some(trade.roi);
+    }
+    attribution.volume_weighted_roi_sum = attribution
+        .volume_weighted_roi_sum
+        .checked_add(trade.roi.checked_mul(trade.volume).expect("weighted ROI overflow"))
+        .expect("weighted ROI overflow");
+    save_attribution(env, trade.signal_id, &attribution);
+}
+
+/// Store the oracle price observed at the signal's horizon.
+pub fn record_horizon_price(env: &Env, signal_id: u64, price: i128) {
+    let mut attribution = get_attribution(env, signal_id);
+    attribution.horizon_price = Some(price);
+    save_attribution(env, signal_id, &attribution);
+}
+
+/// ROI the signal is judged on under `mode`, or `None` while there is nothing
+/// to judge yet (no executions, or no horizon price in `OracleHorizon` mode).
+pub fn attributed_roi(
+    env: &Env,
+    signal: &Signal,
+    mode: AttributionMode,
+    rounding: RoundingMode,
+) -> Option<i128> {
+    match mode {
+        AttributionMode::AverageExecution => {
+            if signal.executions == 0 {
+                None
+            } else {
+                Some(performance::get_signal_average_roi(signal, rounding))
+            }
+        }
+        AttributionMode::FirstExecution => get_attribution(env, signal.id).first_roi,
+        AttributionMode::VolumeWeighted => {
+            if signal.total_volume == 0 {
+                None
+            } else {
+                div_round(
+                    get_attribution(env, signal.id).volume_weighted_roi_sum,
+                    signal.total_volume,
+                    rounding,
+                )
+            }
+        }
+        AttributionMode::OracleHorizon => get_attribution(env, signal.id)
+            .horizon_price
+            .map(|price| performance::calculate_roi(signal.price, price, &signal.action, rounding)),
+    }
+}
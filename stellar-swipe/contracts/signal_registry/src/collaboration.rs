@@ -1,5 +1,4 @@
 use crate::errors::AdminError;
-use crate::types::{Signal, SignalAction};
 use soroban_sdk::{contracttype, Address, Env, Map, Vec};
 
 #[contracttype]
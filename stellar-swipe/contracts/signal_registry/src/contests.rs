@@ -303,7 +303,7 @@ pub fn finalize_contest(env: &Env, contest_id: u64) -> Result<Vec<Address>, Cont
 
     // Remove from active contests
     let active_key = ContestStorageKey::ActiveContests;
-    let mut active: Vec<u64> = env
+    let active: Vec<u64> = env
         .storage()
         .persistent()
         .get(&active_key)
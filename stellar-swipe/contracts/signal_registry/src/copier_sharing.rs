@@ -0,0 +1,146 @@
+//! Opt-in sharing of copier execution details with providers.
+//!
+//! Executions are private by default. An executor can consent to share the
+//! size and ROI of their executions with a given provider; from then on each
+//! execution of that provider's signals is folded into a per-copier record
+//! the provider can read through `get_my_copier_stats`. Revoking consent
+//! deletes the record, so a provider only ever sees currently consenting
+//! copiers. Executions made before consent are not shared retroactively.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::events;
+use crate::types::TradeExecution;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum SharingKey {
+    /// (executor, provider) -> true while the executor shares with the provider
+    Consent(Address, Address),
+    /// provider -> Vec<Address> of consenting copiers
+    Copiers(Address),
+    /// (provider, executor) -> CopierStats
+    Stats(Address, Address),
+}
+
+/// What one consenting copier has executed on a provider's signals.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CopierStats {
+    pub copier: Address,
+    pub trades: u32,
+    pub total_volume: i128,
+    /// Sum of per-trade ROI in basis points.
+    pub total_roi: i128,
+    pub last_trade_at: u64,
+}
+
+/// Aggregate over a provider's consenting copiers.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderCopierStats {
+    pub consenting_copiers: u32,
+    pub total_trades: u32,
+    pub total_volume: i128,
+    /// Mean ROI in basis points over all shared trades.
+    pub avg_roi: i128,
+    pub copiers: Vec<CopierStats>,
+}
+
+pub fn is_sharing(env: &Env, executor: &Address, provider: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&SharingKey::Consent(executor.clone(), provider.clone()))
+        .unwrap_or(false)
+}
+
+fn get_copiers(env: &Env, provider: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&SharingKey::Copiers(provider.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_stats(env: &Env, provider: &Address, copier: &Address) -> CopierStats {
+    env.storage()
+        .persistent()
+        .get(&SharingKey::Stats(provider.clone(), copier.clone()))
+        .unwrap_or(CopierStats {
+            copier: copier.clone(),
+            trades: 0,
+            total_volume: 0,
+            total_roi: 0,
+            last_trade_at: 0,
+        })
+}
+
+/// Grant or revoke sharing with `provider`. Idempotent.
+pub fn set_sharing(env: &Env, executor: Address, provider: Address, enabled: bool) {
+    executor.require_auth();
+
+    if is_sharing(env, &executor, &provider) == enabled {
+        return;
+    }
+
+    let mut copiers = get_copiers(env, &provider);
+    let consent_key = SharingKey::Consent(executor.clone(), provider.clone());
+    if enabled {
+        env.storage().persistent().set(&consent_key, &true);
+        copiers.push_back(executor.clone());
+    } else {
+        env.storage().persistent().remove(&consent_key);
+        env.storage()
+            .persistent()
+            .remove(&SharingKey::Stats(provider.clone(), executor.clone()));
+        if let Some(index) = copiers.first_index_of(executor.clone()) {
+            copiers.remove(index);
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&SharingKey::Copiers(provider.clone()), &copiers);
+
+    events::emit_data_sharing_updated(env, executor, provider, enabled);
+}
+
+/// Fold an execution into the copier's shared record if they consented.
+pub fn record_execution(env: &Env, provider: &Address, trade: &TradeExecution) {
+    if !is_sharing(env, &trade.executor, provider) {
+        return;
+    }
+
+    let mut stats = get_stats(env, provider, &trade.executor);
+    stats.trades += 1;
+    stats.total_volume += trade.volume;
+    stats.total_roi += trade.roi;
+    stats.last_trade_at = trade.timestamp;
+    env.storage().persistent().set(
+        &SharingKey::Stats(provider.clone(), trade.executor.clone()),
+        &stats,
+    );
+}
+
+/// Shared stats of every copier currently consenting to `provider`.
+pub fn provider_copier_stats(env: &Env, provider: &Address) -> ProviderCopierStats {
+    let copiers = get_copiers(env, provider);
+    let mut result = ProviderCopierStats {
+        consenting_copiers: copiers.len(),
+        total_trades: 0,
+        total_volume: 0,
+        avg_roi: 0,
+        copiers: Vec::new(env),
+    };
+
+    let mut total_roi = 0i128;
+    for copier in copiers.iter() {
+        let stats = get_stats(env, provider, &copier);
+        result.total_trades += stats.trades;
+        result.total_volume += stats.total_volume;
+        total_roi += stats.total_roi;
+        result.copiers.push_back(stats);
+    }
+    if result.total_trades > 0 {
+        result.avg_roi = total_roi / result.total_trades as i128;
+    }
+    result
+}
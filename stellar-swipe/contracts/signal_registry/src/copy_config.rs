@@ -0,0 +1,116 @@
+//! Per-follower copy-trading authorization.
+//!
+//! A follower records, per provider, whether their new signals may be copied
+//! automatically and within what limits. The auto_trade contract and keepers
+//! read this before executing on the follower's behalf; the registry itself
+//! only stores and validates it.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::errors::CopyConfigError;
+
+const BPS_DENOMINATOR: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CopyConfig {
+    pub enabled: bool,
+    /// Largest amount a single copied trade may use.
+    pub max_amount_per_trade: i128,
+    /// Share of the follower's balance per trade in bps, replacing their own
+    /// position sizing; `None` keeps it.
+    pub sizing_override_bps: Option<u32>,
+    pub updated_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum CopyConfigKey {
+    /// (follower, provider)
+    Config(Address, Address),
+}
+
+pub fn get_config(env: &Env, follower: &Address, provider: &Address) -> Option<CopyConfig> {
+    env.storage()
+        .persistent()
+        .get(&CopyConfigKey::Config(follower.clone(), provider.clone()))
+}
+
+pub fn set_config(
+    env: &Env,
+    follower: &Address,
+    provider: &Address,
+    enabled: bool,
+    max_amount_per_trade: i128,
+    sizing_override_bps: Option<u32>,
+) -> Result<CopyConfig, CopyConfigError> {
+    if follower == provider {
+        return Err(CopyConfigError::SelfCopy);
+    }
+    if max_amount_per_trade < 0 || (enabled && max_amount_per_trade == 0) {
+        return Err(CopyConfigError::InvalidMaxAmount);
+    }
+    if let Some(bps) = sizing_override_bps {
+        if bps == 0 || bps > BPS_DENOMINATOR {
+            return Err(CopyConfigError::InvalidSizingOverride);
+        }
+    }
+
+    let config = CopyConfig {
+        enabled,
+        max_amount_per_trade,
+        sizing_override_bps,
+        updated_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(
+        &CopyConfigKey::Config(follower.clone(), provider.clone()),
+        &config,
+    );
+    Ok(config)
+}
+
+/// Whether a copy of `amount` for `follower` of `provider`'s signal is allowed.
+pub fn is_authorized(env: &Env, follower: &Address, provider: &Address, amount: i128) -> bool {
+    match get_config(env, follower, provider) {
+        Some(config) => config.enabled && amount > 0 && amount <= config.max_amount_per_trade,
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn copy_config_limits_authorization() {
+        let env = Env::default();
+        let contract_id = env.register(TestContract, ());
+        let follower = Address::generate(&env);
+        let provider = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            assert!(!is_authorized(&env, &follower, &provider, 1));
+            assert_eq!(
+                set_config(&env, &follower, &provider, true, 0, None),
+                Err(CopyConfigError::InvalidMaxAmount)
+            );
+            assert_eq!(
+                set_config(&env, &follower, &provider, true, 500, Some(10_001)),
+                Err(CopyConfigError::InvalidSizingOverride)
+            );
+
+            set_config(&env, &follower, &provider, true, 500, Some(2_500)).unwrap();
+            assert!(is_authorized(&env, &follower, &provider, 500));
+            assert!(!is_authorized(&env, &follower, &provider, 501));
+
+            set_config(&env, &follower, &provider, false, 500, None).unwrap();
+            assert!(!is_authorized(&env, &follower, &provider, 100));
+            assert_eq!(get_config(&env, &follower, &provider).unwrap().sizing_override_bps, None);
+        });
+    }
+}
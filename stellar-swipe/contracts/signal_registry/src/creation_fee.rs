@@ -0,0 +1,159 @@
+//! Per-signal creation fee.
+//!
+//! An optional flat fee charged to the provider whenever they publish a
+//! signal, on top of rate limits, to make spam cost something. Each stake tier
+//! above bronze earns `tier_discount_bps` off the fee. What is collected is
+//! split between the platform treasury and a burn according to `burn_bps`.
+//! Disabled until the admin installs a config.
+
+use soroban_sdk::{contracttype, token, Address, Env, Symbol};
+
+use crate::admin;
+use crate::errors::AdminError;
+use crate::events::emit_parameter_updated;
+use crate::fees;
+
+const BPS_DENOMINATOR: i128 = 10_000;
+/// Highest stake tier (gold); discounts stop growing past it.
+const MAX_STAKE_TIER: u32 = 3;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreationFeeConfig {
+    /// Token the fee is paid in.
+    pub token: Address,
+    /// Fee before any tier discount.
+    pub amount: i128,
+    /// Share of the collected fee that is burned; the rest goes to the treasury.
+    pub burn_bps: u32,
+    /// Discount per stake tier above bronze, in bps of `amount`.
+    pub tier_discount_bps: u32,
+}
+
+#[contracttype]
+pub enum CreationFeeKey {
+    Config,
+}
+
+pub fn get_config(env: &Env) -> Option<CreationFeeConfig> {
+    env.storage().instance().get(&CreationFeeKey::Config)
+}
+
+/// Install, replace or (with `None`) remove the creation fee.
+pub fn set_config(
+    env: &Env,
+    caller: &Address,
+    config: Option<CreationFeeConfig>,
+) -> Result<(), AdminError> {
+    admin::require_admin(env, caller)?;
+    caller.require_auth();
+
+    if let Some(config) = &config {
+        if config.amount < 0
+            || config.burn_bps as i128 > BPS_DENOMINATOR
+            || config.tier_discount_bps as i128 * MAX_STAKE_TIER as i128 > BPS_DENOMINATOR
+        {
+            return Err(AdminError::InvalidParameter);
+        }
+        if (config.burn_bps as i128) < BPS_DENOMINATOR && fees::get_platform_treasury(env).is_none()
+        {
+            return Err(AdminError::InvalidParameter);
+        }
+    }
+
+    let old_amount = get_config(env).map(|c| c.amount).unwrap_or(0);
+    let new_amount = config.as_ref().map(|c| c.amount).unwrap_or(0);
+    match &config {
+        Some(config) => env.storage().instance().set(&CreationFeeKey::Config, config),
+        None => env.storage().instance().remove(&CreationFeeKey::Config),
+    }
+
+    emit_parameter_updated(env, Symbol::new(env, "creation_fee"), old_amount, new_amount);
+    Ok(())
+}
+
+/// Fee owed by a provider of the given stake tier.
+pub fn fee_for_tier(config: &CreationFeeConfig, stake_tier: u32) -> i128 {
+    let discount_bps = config.tier_discount_bps as i128 * stake_tier.min(MAX_STAKE_TIER) as i128;
+    config.amount - config.amount * discount_bps / BPS_DENOMINATOR
+}
+
+/// Collect the creation fee from `provider`. Returns the amount charged
+/// (0 when no fee is configured).
+pub fn charge(env: &Env, provider: &Address, stake_tier: u32) -> Result<i128, AdminError> {
+    let config = match get_config(env) {
+        Some(config) => config,
+        None => return Ok(0),
+    };
+    let fee = fee_for_tier(&config, stake_tier);
+    if fee <= 0 {
+        return Ok(0);
+    }
+
+    let burned = fee * config.burn_bps as i128 / BPS_DENOMINATOR;
+    let to_treasury = fee - burned;
+    let token = token::Client::new(env, &config.token);
+    if to_treasury > 0 {
+        let treasury = fees::get_platform_treasury(env).ok_or(AdminError::NotInitialized)?;
+        token.transfer(provider, &treasury, &to_treasury);
+    }
+    if burned > 0 {
+        token.burn(provider, &burned);
+    }
+    Ok(fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::token::StellarAssetClient;
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn fee_split_between_treasury_and_burn() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register(TestContract, ());
+        let admin = Address::generate(&env);
+        let provider = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let sac = env.register_stellar_asset_contract_v2(admin.clone());
+        StellarAssetClient::new(&env, &sac.address()).mint(&provider, &1_000);
+
+        let config = CreationFeeConfig {
+            token: sac.address(),
+            amount: 100,
+            burn_bps: 4_000,
+            tier_discount_bps: 2_000,
+        };
+        env.as_contract(&contract_id, || {
+            admin::init_admin(&env, admin.clone()).unwrap();
+            assert_eq!(charge(&env, &provider, 0), Ok(0));
+
+            // Part of the fee is routed to the treasury, so one must exist.
+            assert_eq!(
+                set_config(&env, &admin, Some(config.clone())),
+                Err(AdminError::InvalidParameter)
+            );
+            fees::set_platform_treasury(&env, treasury.clone());
+        });
+        // A fresh frame, since the admin already authorized once in the last one.
+        env.as_contract(&contract_id, || {
+            set_config(&env, &admin, Some(config.clone())).unwrap();
+        });
+        env.as_contract(&contract_id, || {
+            assert_eq!(fee_for_tier(&config, 3), 40);
+            assert_eq!(fee_for_tier(&config, 7), 40);
+            assert_eq!(charge(&env, &provider, 0), Ok(100));
+            assert_eq!(charge(&env, &provider, 2), Ok(60));
+        });
+
+        let token = token::Client::new(&env, &sac.address());
+        assert_eq!(token.balance(&treasury), 60 + 36);
+        assert_eq!(token.balance(&provider), 1_000 - 160);
+    }
+}
@@ -1,5 +1,5 @@
 use soroban_sdk::{Address, Bytes, Env, String};
-use crate::types::{CrossChainSignal, SyncStatus, AddressMapping};
+use crate::types::{CrossChainSignal, AddressMapping};
 use crate::StorageKey;
 
 pub fn register_address(
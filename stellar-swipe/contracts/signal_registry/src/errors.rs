@@ -17,7 +17,8 @@ pub enum AdminError {
     InvalidAssetPair = 11,
     CannotFollowSelf = 12,
     RateLimitExceeded = 13,
-    SignalLimitExceeded = 14,
+    /// The provider already has as many Active signals as their tier allows.
+    TooManyActiveSignals = 14,
     InvalidTimestamp = 16,
     ScheduleTooFarFuture = 17,
     ScheduleLimitReached = 18,
@@ -26,8 +27,21 @@ pub enum AdminError {
     CircuitBreakerTriggered = 21,
     StakeBelowMinimum = 22,
     PendingAdminNotFound = 23,
-    PendingAdminExpired = 23,
     ReentrancyDetected = 24,
+    PendingAdminExpired = 25,
+    ProviderInMoratorium = 26,
+    SignalCooldownActive = 27,
+    ProviderNotRegistered = 28,
+    NoStake = 29,
+    /// A pending unstake would leave the provider below the minimum stake.
+    UnstakePending = 30,
+    /// No stake token has been configured, so stake can't be paid in.
+    StakeTokenNotSet = 31,
+    InvalidStakeAmount = 32,
+    /// The pending unstake is still inside its lock period.
+    StakeLocked = 33,
+    InsufficientStake = 34,
+    NoPendingUnstake = 35,
 }
 
 #[contracterror]
@@ -49,6 +63,10 @@ pub enum FeeError {
     ArithmeticOverflow = 102,
     InvalidAmount = 103,
     InvalidProviderAddress = 104,
+    TreasuryNotSet = 105,
+    NothingToClaim = 106,
+    Unauthorized = 107,
+    InvalidShare = 108,
 }
 
 #[contracterror]
@@ -69,6 +87,11 @@ pub enum PerformanceError {
     SignalExpired = 204,
     NoExecutions = 205,
     TradingPaused = 206,
+    PriceOutsideTolerance = 207,
+    OracleUnavailable = 208,
+    UntrustedExecutor = 209,
+    /// The trade fee on the execution could not be charged.
+    FeeNotCollected = 210,
 }
 
 #[contracterror]
@@ -82,6 +105,10 @@ pub enum TemplateError {
     InvalidTemplate = 304,
     InvalidAction = 305,
     InvalidExpiry = 306,
+    /// The submitter has no stake while a minimum stake is set.
+    NoStake = 307,
+    /// The submitter's stake is below the minimum.
+    StakeBelowMinimum = 308,
 }
 
 #[contracterror]
@@ -117,6 +144,7 @@ pub enum ExportError {
     UnsupportedFormat = 700,
     NoDataInRange = 701,
     ExportTooLarge = 702,
+    InvalidRangePreset = 703,
 }
 
 #[contracterror]
@@ -165,6 +193,7 @@ pub enum VersioningError {
     InvalidPrice = 905,
     InvalidExpiry = 906,
     VersionNotFound = 907,
+    NothingToAmend = 908,
 }
 
 #[contracterror]
@@ -178,6 +207,12 @@ pub enum CrossChainError {
     AddressNotRegistered = 1004,
     InvalidSyncStatus = 1005,
     NotSignalOwner = 1006,
+    /// The creation fee for the imported signal couldn't be collected.
+    CreationFeeFailed = 1007,
+    /// The provider has no stake while a minimum stake is set.
+    NoStake = 1008,
+    /// The provider's stake is below the minimum.
+    StakeBelowMinimum = 1009,
 }
 
 #[contracterror]
@@ -216,3 +251,113 @@ pub enum SubmissionError {
     MissingRationale = 1206,
     PriceUnreasonable = 1207,
 }
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SettlementError {
+    SignalNotFound = 1250,
+    NotSignalOwner = 1251,
+    SignalNotActive = 1252,
+    InvalidTargets = 1253,
+    /// Targets can't change once the signal has been copied.
+    TargetsLocked = 1254,
+    OracleNotConfigured = 1255,
+    PriceUnavailable = 1256,
+    /// Neither target reached and the signal has not expired.
+    NotTriggered = 1257,
+    /// The signal has not expired yet.
+    NotExpired = 1258,
+    /// No oracle observation inside the settlement window after expiry.
+    SettlementWindowClosed = 1259,
+    /// The signal's executions already decide its outcome.
+    ExecutionsConclusive = 1260,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RegistrationError {
+    InvalidDisplayName = 1300,
+    InvalidBioUri = 1301,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PremiumError {
+    SignalNotFound = 1350,
+    NotSignalOwner = 1351,
+    /// Premium status can only change while the signal is Active.
+    SignalNotActive = 1352,
+    /// Viewer is neither the provider nor an active subscriber.
+    NotSubscribed = 1353,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SubscriptionError {
+    /// The provider has not set a subscription price.
+    TermsNotSet = 1400,
+    InvalidPrice = 1401,
+    SelfSubscribe = 1402,
+    /// No platform treasury to receive the platform share.
+    TreasuryNotSet = 1403,
+    /// The provider's price is above what the subscriber agreed to pay.
+    PriceAboveMax = 1404,
+    /// The provider's price is in a different token than the subscriber expected.
+    TokenMismatch = 1405,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TippingError {
+    InvalidAmount = 1450,
+    SignalNotFound = 1451,
+    /// Only signals that closed Successful can be tipped.
+    SignalNotSuccessful = 1452,
+    /// The signal was published by someone else.
+    NotSignalProvider = 1453,
+    SelfTip = 1454,
+    /// No platform treasury to receive the platform cut.
+    TreasuryNotSet = 1455,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RatingError {
+    /// Stars must be between 1 and 5.
+    InvalidRating = 1500,
+    SignalNotFound = 1501,
+    /// The rater has no recorded execution of the signal.
+    NotExecutor = 1502,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CopyConfigError {
+    /// Must be positive while copying is enabled, and never negative.
+    InvalidMaxAmount = 1550,
+    /// Sizing override must be between 1 and 10000 bps.
+    InvalidSizingOverride = 1551,
+    SelfCopy = 1552,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReferralError {
+    SelfReferral = 1600,
+    /// A user's referrer can only be set once.
+    ReferrerAlreadySet = 1601,
+    /// The referrer was referred by this user.
+    ReferralCycle = 1602,
+    /// Share must be at most 10000 bps.
+    InvalidShare = 1603,
+    NothingToClaim = 1604,
+    Unauthorized = 1605,
+}
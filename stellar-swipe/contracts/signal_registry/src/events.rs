@@ -1,5 +1,6 @@
+use crate::signal_index::BackfillProgress;
 use crate::types::{Asset, MigrationProgress};
-use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 
 // Horizon / indexer: first topic is only the event name (ScVal::Symbol);
 // all identifying fields live in a standard ScVal body (tuple or #[contracttype]).
@@ -54,6 +55,16 @@ pub fn emit_multisig_signer_removed(env: &Env, signer: Address, removed_by: Addr
     env.events().publish(topics, (signer, removed_by));
 }
 
+pub fn emit_trusted_executor_added(env: &Env, executor: Address, added_by: Address) {
+    let topics = (Symbol::new(env, "trusted_executor_added"),);
+    env.events().publish(topics, (executor, added_by));
+}
+
+pub fn emit_trusted_executor_removed(env: &Env, executor: Address, removed_by: Address) {
+    let topics = (Symbol::new(env, "trusted_executor_removed"),);
+    env.events().publish(topics, (executor, removed_by));
+}
+
 pub fn emit_fee_collected(
     env: &Env,
     asset: Asset,
@@ -77,6 +88,23 @@ pub fn emit_fee_collected(
     );
 }
 
+/// Accrued fees paid out to the platform treasury, a provider or a referrer.
+pub fn emit_fees_withdrawn(env: &Env, to: Address, amounts: Map<Asset, i128>) {
+    let topics = (Symbol::new(env, "fees_withdrawn"),);
+    env.events().publish(topics, (to, amounts));
+}
+
+pub fn emit_referral_rewarded(env: &Env, referrer: Address, user: Address, asset: Asset, amount: i128) {
+    let topics = (Symbol::new(env, "referral_rewarded"),);
+    env.events()
+        .publish(topics, (referrer, user, asset, amount));
+}
+
+pub fn emit_referrer_set(env: &Env, user: Address, referrer: Address) {
+    let topics = (Symbol::new(env, "referrer_set"),);
+    env.events().publish(topics, (user, referrer));
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct SignalAdoptedEvent {
@@ -156,12 +184,30 @@ pub fn emit_follow_lost(env: &Env, user: Address, provider: Address, new_count:
         .publish(topics, (user, provider, new_count));
 }
 
+pub fn emit_data_sharing_updated(env: &Env, executor: Address, provider: Address, enabled: bool) {
+    let topics = (Symbol::new(env, "data_sharing_updated"),);
+    env.events()
+        .publish(topics, (executor, provider, enabled));
+}
+
+pub fn emit_provider_moratorium(env: &Env, provider: Address, failed_signals: u32, until: u64) {
+    let topics = (Symbol::new(env, "provider_moratorium"),);
+    env.events()
+        .publish(topics, (provider, failed_signals, until));
+}
+
 pub fn emit_tags_added(env: &Env, signal_id: u64, provider: Address, tag_count: u32) {
     let topics = (Symbol::new(env, "tags_added"),);
     env.events()
         .publish(topics, (signal_id, provider, tag_count));
 }
 
+pub fn emit_signal_created(env: &Env, signal_id: u64, provider: Address, creation_fee: i128) {
+    let topics = (Symbol::new(env, "signal_created"),);
+    env.events()
+        .publish(topics, (signal_id, provider, creation_fee));
+}
+
 pub fn emit_collaborative_signal_created(env: &Env, signal_id: u64, authors: Vec<Address>) {
     let topics = (Symbol::new(env, "collab_signal_created"),);
     env.events().publish(topics, (signal_id, authors));
@@ -238,6 +284,147 @@ pub fn emit_signal_edited(
     );
 }
 
+/// An amendment to an active signal, with the values it replaced.
+#[contracttype]
+#[derive(Clone)]
+pub struct SignalAmendedEvent {
+    pub signal_id: u64,
+    pub provider: Address,
+    pub revision: u32,
+    pub previous_price: i128,
+    pub price: i128,
+    pub previous_expiry: u64,
+    pub expiry: u64,
+    pub rationale_changed: bool,
+}
+
+pub fn emit_signal_amended(env: &Env, event: SignalAmendedEvent) {
+    let topics = (Symbol::new(env, "signal_amended"),);
+    env.events().publish(topics, event);
+}
+
+pub fn emit_signal_targets_set(
+    env: &Env,
+    signal_id: u64,
+    stop_loss: Option<i128>,
+    take_profit: Option<i128>,
+) {
+    let topics = (Symbol::new(env, "signal_targets_set"),);
+    env.events()
+        .publish(topics, (signal_id, stop_loss, take_profit));
+}
+
+pub fn emit_signal_settled(env: &Env, signal_id: u64, oracle_price: i128, status: u32) {
+    let topics = (Symbol::new(env, "signal_settled"),);
+    env.events()
+        .publish(topics, (signal_id, oracle_price, status));
+}
+
+pub fn emit_signal_stake_locked(env: &Env, provider: Address, signal_id: u64, amount: i128) {
+    let topics = (Symbol::new(env, "signal_stake_locked"),);
+    env.events()
+        .publish(topics, (provider, signal_id, amount));
+}
+
+pub fn emit_signal_stake_slashed(env: &Env, provider: Address, signal_id: u64, amount: i128) {
+    let topics = (Symbol::new(env, "signal_stake_slashed"),);
+    env.events()
+        .publish(topics, (provider, signal_id, amount));
+}
+
+pub fn emit_provider_registered(env: &Env, provider: Address, display_name: String) {
+    let topics = (Symbol::new(env, "provider_registered"),);
+    env.events().publish(topics, (provider, display_name));
+}
+
+pub fn emit_provider_tier_changed(env: &Env, provider: Address, old_tier: u32, new_tier: u32) {
+    let topics = (Symbol::new(env, "provider_tier_changed"),);
+    env.events()
+        .publish(topics, (provider, old_tier, new_tier));
+}
+
+pub fn emit_signal_premium_set(env: &Env, signal_id: u64, premium: bool) {
+    let topics = (Symbol::new(env, "signal_premium_set"),);
+    env.events().publish(topics, (signal_id, premium));
+}
+
+pub fn emit_subscription_paid(
+    env: &Env,
+    subscriber: Address,
+    provider: Address,
+    expires_at: u64,
+    provider_fee: i128,
+    platform_fee: i128,
+) {
+    let topics = (Symbol::new(env, "subscription_paid"),);
+    env.events().publish(
+        topics,
+        (subscriber, provider, expires_at, provider_fee, platform_fee),
+    );
+}
+
+pub fn emit_provider_tipped(
+    env: &Env,
+    provider: Address,
+    from: Address,
+    token: Address,
+    signal_id: u64,
+    amount: i128,
+) {
+    let topics = (Symbol::new(env, "provider_tipped"),);
+    env.events()
+        .publish(topics, (provider, from, token, signal_id, amount));
+}
+
+pub fn emit_signal_rated(env: &Env, signal_id: u64, executor: Address, stars: u32) {
+    let topics = (Symbol::new(env, "signal_rated"),);
+    env.events().publish(topics, (signal_id, executor, stars));
+}
+
+pub fn emit_copy_config_set(
+    env: &Env,
+    follower: Address,
+    provider: Address,
+    enabled: bool,
+    max_amount_per_trade: i128,
+) {
+    let topics = (Symbol::new(env, "copy_config_set"),);
+    env.events()
+        .publish(topics, (follower, provider, enabled, max_amount_per_trade));
+}
+
+pub fn emit_trade_flagged(
+    env: &Env,
+    trade_id: u64,
+    signal_id: u64,
+    executor: Address,
+    deviation_bps: u32,
+) {
+    let topics = (Symbol::new(env, "trade_flagged"),);
+    env.events()
+        .publish(topics, (trade_id, signal_id, executor, deviation_bps));
+}
+
+pub fn emit_provider_stake_locked(env: &Env, provider: Address, amount: i128, locked_until: u64) {
+    let topics = (Symbol::new(env, "provider_stake_locked"),);
+    env.events().publish(topics, (provider, amount, locked_until));
+}
+
+pub fn emit_unstake_requested(env: &Env, provider: Address, amount: i128, available_at: u64) {
+    let topics = (Symbol::new(env, "unstake_requested"),);
+    env.events().publish(topics, (provider, amount, available_at));
+}
+
+pub fn emit_unstake_claimed(env: &Env, provider: Address, amount: i128) {
+    let topics = (Symbol::new(env, "unstake_claimed"),);
+    env.events().publish(topics, (provider, amount));
+}
+
+pub fn emit_insurance_withdrawn(env: &Env, recipient: Address, amount: i128) {
+    let topics = (Symbol::new(env, "insurance_withdrawn"),);
+    env.events().publish(topics, (recipient, amount));
+}
+
 pub fn emit_copy_recorded(env: &Env, user: Address, signal_id: u64, version: u32) {
     let topics = (Symbol::new(env, "copy_recorded"),);
     env.events()
@@ -378,3 +565,24 @@ pub fn emit_provider_cooling_off_started(
     env.events()
         .publish(topics, (provider, ends_at));
 }
+
+pub fn emit_index_backfill_progress(env: &Env, progress: BackfillProgress) {
+    let topics = (Symbol::new(env, "index_backfill_progress"),);
+    env.events().publish(topics, progress);
+}
+
+pub fn emit_migration_progress(env: &Env, progress: MigrationProgress) {
+    let topics = (Symbol::new(env, "migration_progress"),);
+    env.events().publish(topics, progress);
+}
+
+/// A legacy signal record that could not be converted; it stays in legacy storage.
+pub fn emit_migration_skipped(env: &Env, signal_id: u64) {
+    let topics = (Symbol::new(env, "migration_skipped"),);
+    env.events().publish(topics, signal_id);
+}
+
+pub fn emit_signal_orphaned(env: &Env, signal_id: u64, reason: String) {
+    let topics = (Symbol::new(env, "signal_orphaned"),);
+    env.events().publish(topics, (signal_id, reason));
+}
@@ -0,0 +1,165 @@
+//! Oracle checks on executor-reported trade prices.
+//!
+//! With verification on, `record_trade_execution` checks both legs of a
+//! trade against the oracle for the signal's pair. Executions are recorded
+//! in one call after they close, so the exit is compared with the oracle's
+//! current price. The entry happened at some point while the signal was
+//! live, so it is compared with the prices the oracle published since the
+//! signal was (including the one in effect at publication) and may be
+//! anywhere within `tolerance_bps` of one of them.
+//!
+//! A leg further than `tolerance_bps` from the oracle is an outlier: in
+//! `Reject` mode the trade is refused, in `Flag` mode it is recorded but
+//! flagged for review with the larger of the two deviations. The oracle is
+//! the one configured for settlement, read through its `get_price` and
+//! `get_price_history`, at the `CANONICAL_DECIMALS` trade prices are
+//! reported in. Only the oracle's retained history is consulted; if it no
+//! longer reaches back to publication the entry is checked against what
+//! remains.
+
+use soroban_sdk::{contracttype, Env, Vec};
+use stellar_swipe_common::BASIS_POINTS_DENOMINATOR_I128;
+
+use crate::errors::PerformanceError;
+use crate::settlement;
+use crate::types::Signal;
+
+pub const DEFAULT_TOLERANCE_BPS: u32 = 200;
+pub const MAX_TOLERANCE_BPS: u32 = 5_000;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationMode {
+    Off,
+    /// Record outliers but flag them.
+    Flag,
+    /// Refuse outliers.
+    Reject,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationConfig {
+    pub mode: VerificationMode,
+    pub tolerance_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum VerificationKey {
+    VerificationConfig,
+    /// Largest deviation, in bps, of a flagged trade.
+    Flagged(u64),
+}
+
+pub fn get_config(env: &Env) -> VerificationConfig {
+    env.storage()
+        .instance()
+        .get(&VerificationKey::VerificationConfig)
+        .unwrap_or(VerificationConfig {
+            mode: VerificationMode::Off,
+            tolerance_bps: DEFAULT_TOLERANCE_BPS,
+        })
+}
+
+pub fn set_config(env: &Env, config: &VerificationConfig) {
+    env.storage().instance().set(&VerificationKey::VerificationConfig, config);
+}
+
+/// Distance of `price` from `reference`, in bps of `reference`.
+pub fn deviation_bps(price: i128, reference: i128) -> u32 {
+    let diff = (price - reference).saturating_abs();
+    diff.saturating_mul(BASIS_POINTS_DENOMINATOR_I128)
+        .checked_div(reference)
+        .unwrap_or(i128::MAX)
+        .min(u32::MAX as i128) as u32
+}
+
+/// Check the reported prices against the oracle. Returns the deviation
+/// when the trade should be recorded but flagged, `None` when it is clean or
+/// verification is off.
+pub fn verify(
+    env: &Env,
+    signal: &Signal,
+    entry_price: i128,
+    exit_price: i128,
+) -> Result<Option<u32>, PerformanceError> {
+    let config = get_config(env);
+    if config.mode == VerificationMode::Off {
+        return Ok(None);
+    }
+
+    let oracle_price = settlement::oracle_price(env, &signal.asset_pair)
+        .map_err(|_| PerformanceError::OracleUnavailable)?;
+    let history = settlement::oracle_history(env, &signal.asset_pair)
+        .map_err(|_| PerformanceError::OracleUnavailable)?;
+    let deviation = entry_deviation_bps(entry_price, signal.timestamp, &history)
+        .ok_or(PerformanceError::OracleUnavailable)?
+        .max(deviation_bps(exit_price, oracle_price));
+    if deviation <= config.tolerance_bps {
+        return Ok(None);
+    }
+    match config.mode {
+        VerificationMode::Reject => Err(PerformanceError::PriceOutsideTolerance),
+        _ => Ok(Some(deviation)),
+    }
+}
+
+/// Smallest deviation of `entry_price` from the prices in effect since
+/// `since`, given `history` newest first. `None` if there are none.
+fn entry_deviation_bps(entry_price: i128, since: u64, history: &Vec<(i128, u64)>) -> Option<u32> {
+    let mut closest: Option<u32> = None;
+    for (price, timestamp) in history.iter() {
+        let deviation = deviation_bps(entry_price, price);
+        closest = Some(closest.map_or(deviation, |c| c.min(deviation)));
+        if timestamp <= since {
+            // In effect at `since`; anything older was already replaced.
+            break;
+        }
+    }
+    closest
+}
+
+pub fn flag(env: &Env, trade_id: u64, deviation_bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&VerificationKey::Flagged(trade_id), &deviation_bps);
+}
+
+pub fn get_flag(env: &Env, trade_id: u64) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&VerificationKey::Flagged(trade_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deviation_is_symmetric_in_bps_of_reference() {
+        assert_eq!(deviation_bps(102, 100), 200);
+        assert_eq!(deviation_bps(98, 100), 200);
+        assert_eq!(deviation_bps(100, 100), 0);
+        assert_eq!(deviation_bps(1, 0), u32::MAX);
+    }
+
+    #[test]
+    fn entry_checked_against_prices_since_publication() {
+        let env = Env::default();
+        // Newest first: published at 300, 200, 100 and 50.
+        let history = Vec::from_array(
+            &env,
+            [(100_000, 300), (110_000, 200), (120_000, 100), (90_000, 50)],
+        );
+
+        // Closest price in effect since 150 is 0.011.
+        assert_eq!(entry_deviation_bps(110_000, 150, &history), Some(0));
+        // 0.012 took effect at 100 and was still in effect at 150.
+        assert_eq!(entry_deviation_bps(120_000, 150, &history), Some(0));
+        // 0.009 had been replaced by 150.
+        assert_eq!(entry_deviation_bps(90_000, 150, &history), Some(1_000));
+        assert_eq!(entry_deviation_bps(90_000, 40, &history), Some(0));
+        assert_eq!(entry_deviation_bps(90_000, 150, &Vec::new(&env)), None);
+    }
+}
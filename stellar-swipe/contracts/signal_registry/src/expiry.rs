@@ -1,17 +1,34 @@
-use soroban_sdk::{Address, Env, Map, Vec};
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
 use stellar_swipe_common::{SECONDS_PER_30_DAY_MONTH, SECONDS_PER_DAY};
 
 use crate::events::emit_signal_expired;
+use crate::settlement;
+use crate::signal_index;
 use crate::types::{Signal, SignalStatus};
 
 pub const DEFAULT_EXPIRY_SECONDS: u64 = SECONDS_PER_DAY; // 24 hours
 pub const MAX_CLEANUP_BATCH_SIZE: u32 = 100; // Process max 100 signals per cleanup call
 pub const ARCHIVE_THRESHOLD_SECONDS: u64 = SECONDS_PER_30_DAY_MONTH; // 30 days
 
+/// Outcome of one `expire_signals` sweep.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpirySweep {
+    /// Overdue signals closed as Successful or Failed on their attributed ROI.
+    pub successful: u32,
+    pub failed: u32,
+    /// Overdue signals whose ROI fell between the thresholds.
+    pub expired: u32,
+    /// Whether overdue Active signals are left for another call.
+    pub more: bool,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct CleanupResult {
     pub signals_processed: u32,
     pub signals_expired: u32,
+    /// IDs of the signals this batch marked Expired.
+    pub expired_ids: Vec<u64>,
 }
 
 /// Check if a signal has expired based on current time
@@ -58,6 +75,7 @@ pub fn get_signal_with_expiry_check(
     signal_id: u64,
 ) -> Option<Signal> {
     if let Some(mut signal) = signals_map.get(signal_id) {
+        let old_status = signal.status.clone();
         // Check and update expiry status
         if check_and_update_expiry(env, &mut signal) {
             // Status was updated, save it back
@@ -66,6 +84,7 @@ pub fn get_signal_with_expiry_check(
             env.storage()
                 .instance()
                 .set(&crate::StorageKey::Signals, &updated_map);
+            signal_index::move_status(env, signal_id, &old_status, &signal.status);
         }
         Some(signal)
     } else {
@@ -150,6 +169,7 @@ pub fn cleanup_expired_signals(
     let current_time = env.ledger().timestamp();
     let mut signals_processed = 0u32;
     let mut signals_expired = 0u32;
+    let mut expired_ids = Vec::new(env);
     let mut updated_map = signals_map.clone();
 
     // Collect all keys first
@@ -172,14 +192,22 @@ pub fn cleanup_expired_signals(
             if signal.status == SignalStatus::Expired || signal.status == SignalStatus::Executed {
                 continue;
             }
+            // Leave signals still open to oracle settlement to it.
+            if signal.expiry < current_time
+                && settlement::awaiting_settlement(env, &signal, current_time)
+            {
+                continue;
+            }
 
             signals_processed += 1;
 
             // Check if expired
             if signal.expiry < current_time {
+                signal_index::move_status(env, signal_id, &signal.status, &SignalStatus::Expired);
                 signal.status = SignalStatus::Expired;
                 updated_map.set(signal_id, signal.clone());
                 signals_expired += 1;
+                expired_ids.push_back(signal_id);
 
                 // Emit expiry event
                 emit_signal_expired(env, signal.id, signal.provider.clone(), signal.expiry);
@@ -197,6 +225,7 @@ pub fn cleanup_expired_signals(
     CleanupResult {
         signals_processed,
         signals_expired,
+        expired_ids,
     }
 }
 
@@ -305,12 +334,13 @@ mod tests {
         testutils::{Address as _, Ledger},
         Address, Env, String,
     };
+    use stellar_swipe_common::AssetPair;
 
     fn create_test_signal(env: &Env, id: u64, expiry: u64) -> Signal {
         Signal {
             id,
             provider: Address::generate(env),
-            asset_pair: String::from_str(env, "XLM/USDC"),
+            asset_pair: AssetPair::parse(env, &String::from_str(env, "XLM/USDC")).unwrap(),
             action: SignalAction::Buy,
             price: 100_000,
             rationale: String::from_str(env, "Test signal"),
@@ -335,6 +365,9 @@ mod tests {
             warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
         }
     }
 
@@ -2,12 +2,17 @@ extern crate alloc;
 
 use alloc::string::{String as RustString, ToString};
 use alloc::vec::Vec as RustVec;
-use soroban_sdk::{Address, Bytes, Env, Map};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map};
 
+use crate::admin::get_rounding_mode;
 use crate::errors::ExportError;
+use crate::performance::get_signal_average_roi;
+use crate::risk_metrics::{self, RoiStats};
 use crate::types::{Signal, SignalAction, SignalStatus, TradeExecution};
 use crate::StorageKey;
-use stellar_swipe_common::{SECONDS_PER_30_DAY_MONTH, SECONDS_PER_DAY, SECONDS_PER_WEEK};
+use stellar_swipe_common::{
+    apply_bps, AssetPair, SECONDS_PER_30_DAY_MONTH, SECONDS_PER_DAY, SECONDS_PER_WEEK,
+};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -33,17 +38,119 @@ pub enum ExportFormat {
     Json,
 }
 
+impl ExportFormat {
+    /// Wire encoding used by the contract entrypoints: 0 = CSV, 1 = JSON.
+    pub fn from_u32(format: u32) -> Result<Self, ExportError> {
+        match format {
+            0 => Ok(ExportFormat::Csv),
+            1 => Ok(ExportFormat::Json),
+            _ => Err(ExportError::UnsupportedFormat),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ExportEntity {
     Signals,
     Trades,
     Performance,
     Portfolio,
+    /// Realized P&L of an executor's trades by provider, pair and month.
+    Attribution,
+    /// Aggregate results of an executor's trades.
+    ExecutorPerformance,
+    /// Realized gain or loss of each of an executor's trades, by tax year.
+    TaxReport,
+}
+
+impl ExportEntity {
+    /// Wire encoding: 0 = signals, 1 = trades, 2 = performance, 3 = portfolio,
+    /// 4 = attribution, 5 = executor performance, 6 = tax report.
+    pub fn from_u32(entity: u32) -> Result<Self, ExportError> {
+        match entity {
+            0 => Ok(ExportEntity::Signals),
+            1 => Ok(ExportEntity::Trades),
+            2 => Ok(ExportEntity::Performance),
+            3 => Ok(ExportEntity::Portfolio),
+            4 => Ok(ExportEntity::Attribution),
+            5 => Ok(ExportEntity::ExecutorPerformance),
+            6 => Ok(ExportEntity::TaxReport),
+            _ => Err(ExportError::UnsupportedFormat),
+        }
+    }
 }
 
 /// Date range filter (start_ts, end_ts) inclusive, both in Unix seconds UTC.
 pub type DateRange = (u64, u64);
 
+/// Narrows an export to signals, or trades on signals, with one of the given
+/// pairs, one of the given actions and one of the given statuses. Empty
+/// fields match everything.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportFilter {
+    pub asset_pairs: soroban_sdk::Vec<AssetPair>,
+    pub actions: soroban_sdk::Vec<SignalAction>,
+    pub statuses: soroban_sdk::Vec<SignalStatus>,
+}
+
+impl ExportFilter {
+    /// A filter that matches everything.
+    pub fn all(env: &Env) -> Self {
+        ExportFilter {
+            asset_pairs: soroban_sdk::Vec::new(env),
+            actions: soroban_sdk::Vec::new(env),
+            statuses: soroban_sdk::Vec::new(env),
+        }
+    }
+
+    pub fn matches(&self, signal: &Signal) -> bool {
+        (self.asset_pairs.is_empty() || self.asset_pairs.contains(&signal.asset_pair))
+            && (self.actions.is_empty() || self.actions.contains(&signal.action))
+            && (self.statuses.is_empty() || self.statuses.contains(&signal.status))
+    }
+}
+
+/// The range ending now covered by a preset of 7, 30 or 365 days.
+pub fn preset_range(env: &Env, days: u32) -> Result<DateRange, ExportError> {
+    let span = match days {
+        7 => PRESET_7_DAYS,
+        30 => PRESET_30_DAYS,
+        365 => PRESET_365_DAYS,
+        _ => return Err(ExportError::InvalidRangePreset),
+    };
+    let now = env.ledger().timestamp();
+    Ok((now.saturating_sub(span), now))
+}
+
+/// Describes an export so integrations can name and verify the file without
+/// parsing it. Two exports of the same data produce the same metadata apart
+/// from `generated_at`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportMetadata {
+    /// Entity code as passed to `export_with_metadata`.
+    pub entity: u32,
+    /// Format code as passed to `export_with_metadata`.
+    pub format: u32,
+    /// Inclusive range filter; `(0, u64::MAX)` when unfiltered.
+    pub range_start: u64,
+    pub range_end: u64,
+    /// Rows (signals, trades or attribution groups) in the export; 1 for
+    /// summary entities.
+    pub record_count: u32,
+    pub generated_at: u64,
+    /// SHA-256 of `data`.
+    pub content_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportWithMetadata {
+    pub data: Bytes,
+    pub metadata: ExportMetadata,
+}
+
 // ---------------------------------------------------------------------------
 // CSV / JSON helpers (no_std compatible using alloc)
 // ---------------------------------------------------------------------------
@@ -85,6 +192,7 @@ fn signal_status_str(status: &SignalStatus) -> &'static str {
         SignalStatus::Expired => "Expired",
         SignalStatus::Successful => "Successful",
         SignalStatus::Failed => "Failed",
+        SignalStatus::ProviderDeleted => "ProviderDeleted",
     }
 }
 
@@ -97,7 +205,7 @@ fn signal_action_str(action: &SignalAction) -> &'static str {
 
 /// Escape a string for CSV (wrap in quotes if it contains comma/newline/quote).
 fn csv_escape(s: &str) -> RustString {
-    if s.contains(',') || s.contains('"') || s.contains('\n') {
+    if s.contains(',') || s.contains('"') || s.contains('\n') || s.contains('\r') {
         let mut out = RustString::from('"');
         for c in s.chars() {
             if c == '"' {
@@ -112,15 +220,71 @@ fn csv_escape(s: &str) -> RustString {
     }
 }
 
-/// Convert a native Soroban `String` to a Rust `String`.
+/// Convert a native Soroban `String` to a Rust `String`, whatever its length.
+/// Invalid UTF-8 yields an empty string.
 fn sdk_str_to_rust(s: &soroban_sdk::String) -> RustString {
-    let bytes = s.to_array::<512>().unwrap_or([0u8; 512]);
-    let len = bytes.iter().position(|&b| b == 0).unwrap_or(512);
+    let mut bytes = alloc::vec![0u8; s.len() as usize];
+    s.copy_into_slice(&mut bytes);
+    RustString::from_utf8(bytes).unwrap_or_default()
+}
+
+/// An asset pair in its canonical "BASE/QUOTE" form.
+fn pair_to_rust(env: &Env, pair: &AssetPair) -> RustString {
+    sdk_str_to_rust(&pair.to_string(env))
+}
+
+/// Escape a string for use inside a JSON string literal.
+fn json_escape(s: &str) -> RustString {
+    let mut out = RustString::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&alloc::format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Strkey of `address`, e.g. the registry half of a `SignalRef`.
+fn address_to_rust(address: &Address) -> RustString {
+    let strkey = address.to_string();
+    let mut bytes = [0u8; 128];
+    let len = strkey.len() as usize;
+    if len > bytes.len() {
+        return RustString::new();
+    }
+    strkey.copy_into_slice(&mut bytes[..len]);
     core::str::from_utf8(&bytes[..len])
         .unwrap_or("")
         .to_string()
 }
 
+/// Calendar (year, month) of a Unix timestamp (UTC).
+fn civil_year_month(timestamp: u64) -> (u64, u64) {
+    // Civil-from-days over the proleptic Gregorian calendar, with years
+    // starting in March so the leap day falls last.
+    let z = timestamp / SECONDS_PER_DAY + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month)
+}
+
+/// Calendar month of a Unix timestamp as "YYYY-MM" (UTC).
+fn month_label(timestamp: u64) -> RustString {
+    let (year, month) = civil_year_month(timestamp);
+    alloc::format!("{:04}-{:02}", year, month)
+}
+
 /// Append a `RustString` to a `RustVec<u8>`.
 fn push_str(buf: &mut RustVec<u8>, s: &str) {
     buf.extend_from_slice(s.as_bytes());
@@ -194,6 +358,7 @@ fn collect_provider_signals(
     env: &Env,
     provider: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> alloc::vec::Vec<Signal> {
     let map: Map<u64, Signal> = env
         .storage()
@@ -213,6 +378,9 @@ fn collect_provider_signals(
                         continue;
                     }
                 }
+                if !filter.matches(&signal) {
+                    continue;
+                }
                 out.push(signal);
                 if out.len() as u32 >= MAX_EXPORT_RECORDS {
                     break;
@@ -227,28 +395,28 @@ pub fn export_signals_csv(
     env: &Env,
     provider: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> Result<Bytes, ExportError> {
-    let signals = collect_provider_signals(env, provider, date_range);
+    let signals = collect_provider_signals(env, provider, date_range, filter);
+    let rounding = get_rounding_mode(env);
+    let registry = address_to_rust(&env.current_contract_address());
 
     let mut buf: RustVec<u8> = RustVec::new();
     // Header
     push_str(
         &mut buf,
-        "signal_id,timestamp,asset_pair,action,price,rationale,executions,total_roi,status\n",
+        "signal_id,registry,timestamp,asset_pair,action,price,rationale,executions,total_roi,status\n",
     );
 
     for signal in &signals {
-        let asset_pair = sdk_str_to_rust(&signal.asset_pair);
+        let asset_pair = pair_to_rust(env, &signal.asset_pair);
         let rationale = sdk_str_to_rust(&signal.rationale);
-        let avg_roi = if signal.executions > 0 {
-            signal.total_roi / signal.executions as i128
-        } else {
-            0
-        };
+        let avg_roi = get_signal_average_roi(signal, rounding);
 
         let row = alloc::format!(
-            "{},{},{},{},{},{},{},{},{}\n",
+            "{},{},{},{},{},{},{},{},{},{}\n",
             u64_to_str(signal.id),
+            registry,
             u64_to_str(signal.timestamp),
             csv_escape(&asset_pair),
             signal_action_str(&signal.action),
@@ -268,8 +436,11 @@ pub fn export_signals_json(
     env: &Env,
     provider: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> Result<Bytes, ExportError> {
-    let signals = collect_provider_signals(env, provider, date_range);
+    let signals = collect_provider_signals(env, provider, date_range, filter);
+    let rounding = get_rounding_mode(env);
+    let registry = address_to_rust(&env.current_contract_address());
 
     let mut buf: RustVec<u8> = RustVec::new();
     push_str(&mut buf, "[");
@@ -278,22 +449,19 @@ pub fn export_signals_json(
         if idx > 0 {
             push_str(&mut buf, ",");
         }
-        let asset_pair = sdk_str_to_rust(&signal.asset_pair);
+        let asset_pair = pair_to_rust(env, &signal.asset_pair);
         let rationale = sdk_str_to_rust(&signal.rationale);
-        let avg_roi = if signal.executions > 0 {
-            signal.total_roi / signal.executions as i128
-        } else {
-            0
-        };
+        let avg_roi = get_signal_average_roi(signal, rounding);
 
         let entry = alloc::format!(
-            r#"{{"signal_id":{},"timestamp":{},"asset_pair":"{}","action":"{}","price":{},"rationale":"{}","executions":{},"avg_roi_bps":{},"total_roi_pct":"{}","status":"{}"}}"#,
+            r#"{{"signal_id":{},"registry":"{}","timestamp":{},"asset_pair":"{}","action":"{}","price":{},"rationale":"{}","executions":{},"avg_roi_bps":{},"total_roi_pct":"{}","status":"{}"}}"#,
             signal.id,
+            registry,
             signal.timestamp,
-            asset_pair.replace('"', "\\\""),
+            json_escape(&asset_pair),
             signal_action_str(&signal.action),
             signal.price,
-            rationale.replace('"', "\\\""),
+            json_escape(&rationale),
             signal.executions,
             avg_roi,
             bps_to_pct_str(avg_roi),
@@ -314,6 +482,7 @@ fn collect_trades(
     env: &Env,
     executor: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> alloc::vec::Vec<(u64, TradeExecution, Signal)> {
     let signals_map: Map<u64, Signal> = env
         .storage()
@@ -340,6 +509,9 @@ fn collect_trades(
                     }
                 }
                 if let Some(signal) = signals_map.get(trade.signal_id) {
+                    if !filter.matches(&signal) {
+                        continue;
+                    }
                     out.push((trade_id, trade, signal));
                     if out.len() as u32 >= MAX_EXPORT_RECORDS {
                         break;
@@ -355,30 +527,29 @@ pub fn export_trades_csv(
     env: &Env,
     executor: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> Result<Bytes, ExportError> {
-    let trades = collect_trades(env, executor, date_range);
+    let trades = collect_trades(env, executor, date_range, filter);
+    let rounding = get_rounding_mode(env);
+    let registry = address_to_rust(&env.current_contract_address());
 
     let mut buf: RustVec<u8> = RustVec::new();
     push_str(
         &mut buf,
-        "trade_id,timestamp,signal_id,asset_pair,volume,entry_price,exit_price,roi_bps,pnl\n",
+        "trade_id,timestamp,signal_id,registry,asset_pair,volume,entry_price,exit_price,roi_bps,pnl\n",
     );
 
     for (trade_id, trade, signal) in &trades {
-        let asset_pair = sdk_str_to_rust(&signal.asset_pair);
+        let asset_pair = pair_to_rust(env, &signal.asset_pair);
         // PnL = volume * roi / 10000
-        let pnl = trade
-            .volume
-            .checked_mul(trade.roi)
-            .unwrap_or(i128::MAX)
-            .checked_div(10000)
-            .unwrap_or(0);
+        let pnl = apply_bps(trade.volume, trade.roi, rounding).unwrap_or(0);
 
         let row = alloc::format!(
-            "{},{},{},{},{},{},{},{},{}\n",
+            "{},{},{},{},{},{},{},{},{},{}\n",
             trade_id,
             trade.timestamp,
             trade.signal_id,
+            registry,
             csv_escape(&asset_pair),
             trade.volume,
             trade.entry_price,
@@ -396,8 +567,11 @@ pub fn export_trades_json(
     env: &Env,
     executor: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> Result<Bytes, ExportError> {
-    let trades = collect_trades(env, executor, date_range);
+    let trades = collect_trades(env, executor, date_range, filter);
+    let rounding = get_rounding_mode(env);
+    let registry = address_to_rust(&env.current_contract_address());
 
     let mut buf: RustVec<u8> = RustVec::new();
     push_str(&mut buf, "[");
@@ -406,20 +580,16 @@ pub fn export_trades_json(
         if idx > 0 {
             push_str(&mut buf, ",");
         }
-        let asset_pair = sdk_str_to_rust(&signal.asset_pair);
-        let pnl = trade
-            .volume
-            .checked_mul(trade.roi)
-            .unwrap_or(i128::MAX)
-            .checked_div(10000)
-            .unwrap_or(0);
+        let asset_pair = pair_to_rust(env, &signal.asset_pair);
+        let pnl = apply_bps(trade.volume, trade.roi, rounding).unwrap_or(0);
 
         let entry = alloc::format!(
-            r#"{{"trade_id":{},"timestamp":{},"signal_id":{},"asset_pair":"{}","volume":{},"entry_price":{},"exit_price":{},"roi_bps":{},"roi_pct":"{}","pnl":{}}}"#,
+            r#"{{"trade_id":{},"timestamp":{},"signal_id":{},"registry":"{}","asset_pair":"{}","volume":{},"entry_price":{},"exit_price":{},"roi_bps":{},"roi_pct":"{}","pnl":{}}}"#,
             trade_id,
             trade.timestamp,
             trade.signal_id,
-            asset_pair.replace('"', "\\\""),
+            registry,
+            json_escape(&asset_pair),
             trade.volume,
             trade.entry_price,
             trade.exit_price,
@@ -434,6 +604,370 @@ pub fn export_trades_json(
     Ok(vec_to_bytes(env, &buf))
 }
 
+// ---------------------------------------------------------------------------
+// Attribution export
+// ---------------------------------------------------------------------------
+
+/// Realized P&L of the trades sharing one provider, pair and month.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AttributionRow {
+    pub trades: u32,
+    pub volume: i128,
+    pub realized_pnl: i128,
+}
+
+/// Group an executor's trades by (provider, asset pair, month). Each trade
+/// is a closed round trip, so its P&L is realized: `volume * roi`.
+fn collect_attribution(
+    env: &Env,
+    executor: &Address,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> alloc::collections::BTreeMap<(RustString, RustString, RustString), AttributionRow> {
+    let rounding = get_rounding_mode(env);
+    let mut rows = alloc::collections::BTreeMap::new();
+    for (_, trade, signal) in collect_trades(env, executor, date_range, filter) {
+        let key = (
+            address_to_rust(&signal.provider),
+            pair_to_rust(env, &signal.asset_pair),
+            month_label(trade.timestamp),
+        );
+        let row: &mut AttributionRow = rows.entry(key).or_default();
+        row.trades = row.trades.saturating_add(1);
+        row.volume = row.volume.saturating_add(trade.volume);
+        row.realized_pnl = row
+            .realized_pnl
+            .saturating_add(apply_bps(trade.volume, trade.roi, rounding).unwrap_or(0));
+    }
+    rows
+}
+
+pub fn export_attribution_csv(
+    env: &Env,
+    executor: &Address,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> Result<Bytes, ExportError> {
+    let rows = collect_attribution(env, executor, date_range, filter);
+
+    let mut buf: RustVec<u8> = RustVec::new();
+    push_str(&mut buf, "provider,asset_pair,month,trades,volume,realized_pnl\n");
+
+    for ((provider, asset_pair, month), row) in &rows {
+        let line = alloc::format!(
+            "{},{},{},{},{},{}\n",
+            provider,
+            csv_escape(asset_pair),
+            month,
+            row.trades,
+            row.volume,
+            row.realized_pnl,
+        );
+        push_str(&mut buf, &line);
+    }
+
+    Ok(vec_to_bytes(env, &buf))
+}
+
+pub fn export_attribution_json(
+    env: &Env,
+    executor: &Address,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> Result<Bytes, ExportError> {
+    let rows = collect_attribution(env, executor, date_range, filter);
+
+    let mut buf: RustVec<u8> = RustVec::new();
+    push_str(&mut buf, "[");
+
+    for (idx, ((provider, asset_pair, month), row)) in rows.iter().enumerate() {
+        if idx > 0 {
+            push_str(&mut buf, ",");
+        }
+        let entry = alloc::format!(
+            r#"{{"provider":"{}","asset_pair":"{}","month":"{}","trades":{},"volume":{},"realized_pnl":{}}}"#,
+            provider,
+            json_escape(&asset_pair),
+            month,
+            row.trades,
+            row.volume,
+            row.realized_pnl,
+        );
+        push_str(&mut buf, &entry);
+    }
+
+    push_str(&mut buf, "]");
+    Ok(vec_to_bytes(env, &buf))
+}
+
+// ---------------------------------------------------------------------------
+// Executor performance export
+// ---------------------------------------------------------------------------
+
+pub struct ExecutorSummary {
+    pub total_trades: u32,
+    pub winning_trades: u32,
+    pub win_rate_bps: u32,
+    pub total_volume: i128,
+    pub total_pnl: i128,
+    /// Pairs with the highest and lowest total P&L.
+    pub best_pair: RustString,
+    pub worst_pair: RustString,
+    /// Mean time from a signal's publication to the executor's trade on it.
+    pub avg_hold_secs: u64,
+}
+
+fn calculate_executor_summary(
+    env: &Env,
+    executor: &Address,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> ExecutorSummary {
+    let trades = collect_trades(env, executor, date_range, filter);
+    let rounding = get_rounding_mode(env);
+
+    let total_trades = trades.len() as u32;
+    let mut winning_trades: u32 = 0;
+    let mut total_volume: i128 = 0;
+    let mut total_pnl: i128 = 0;
+    let mut total_hold_secs: u64 = 0;
+    let mut pair_pnl: alloc::collections::BTreeMap<RustString, i128> =
+        alloc::collections::BTreeMap::new();
+
+    for (_, trade, signal) in &trades {
+        let pnl = apply_bps(trade.volume, trade.roi, rounding).unwrap_or(0);
+        if trade.roi > 0 {
+            winning_trades += 1;
+        }
+        total_volume = total_volume.saturating_add(trade.volume);
+        total_pnl = total_pnl.saturating_add(pnl);
+        total_hold_secs =
+            total_hold_secs.saturating_add(trade.timestamp.saturating_sub(signal.timestamp));
+
+        let entry = pair_pnl.entry(pair_to_rust(env, &signal.asset_pair)).or_insert(0);
+        *entry = entry.saturating_add(pnl);
+    }
+
+    let mut best_pair = RustString::from("N/A");
+    let mut worst_pair = RustString::from("N/A");
+    let mut best_pnl = i128::MIN;
+    let mut worst_pnl = i128::MAX;
+    for (pair, pnl) in &pair_pnl {
+        if *pnl > best_pnl {
+            best_pnl = *pnl;
+            best_pair = pair.clone();
+        }
+        if *pnl < worst_pnl {
+            worst_pnl = *pnl;
+            worst_pair = pair.clone();
+        }
+    }
+
+    let (win_rate_bps, avg_hold_secs) = if total_trades > 0 {
+        (
+            ((winning_trades as u64 * 10000) / total_trades as u64) as u32,
+            total_hold_secs / total_trades as u64,
+        )
+    } else {
+        (0, 0)
+    };
+
+    ExecutorSummary {
+        total_trades,
+        winning_trades,
+        win_rate_bps,
+        total_volume,
+        total_pnl,
+        best_pair,
+        worst_pair,
+        avg_hold_secs,
+    }
+}
+
+pub fn export_executor_performance_json(
+    env: &Env,
+    executor: &Address,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> Result<Bytes, ExportError> {
+    let s = calculate_executor_summary(env, executor, date_range, filter);
+    let win_rate_str = alloc::format!("{}.{:02}%", s.win_rate_bps / 100, s.win_rate_bps % 100);
+
+    let json = alloc::format!(
+        r#"{{"total_trades":{},"winning_trades":{},"win_rate":"{}","total_volume":{},"total_pnl":{},"best_pair":"{}","worst_pair":"{}","avg_hold_secs":{}}}"#,
+        s.total_trades,
+        s.winning_trades,
+        win_rate_str,
+        s.total_volume,
+        s.total_pnl,
+        json_escape(&s.best_pair),
+        json_escape(&s.worst_pair),
+        s.avg_hold_secs,
+    );
+
+    let mut buf: RustVec<u8> = RustVec::new();
+    push_str(&mut buf, &json);
+    Ok(vec_to_bytes(env, &buf))
+}
+
+pub fn export_executor_performance_csv(
+    env: &Env,
+    executor: &Address,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> Result<Bytes, ExportError> {
+    let s = calculate_executor_summary(env, executor, date_range, filter);
+    let win_rate_str = alloc::format!("{}.{:02}%", s.win_rate_bps / 100, s.win_rate_bps % 100);
+
+    let mut buf: RustVec<u8> = RustVec::new();
+    push_str(&mut buf, "metric,value\n");
+
+    let rows = [
+        alloc::format!("total_trades,{}\n", s.total_trades),
+        alloc::format!("winning_trades,{}\n", s.winning_trades),
+        alloc::format!("win_rate,{}\n", win_rate_str),
+        alloc::format!("total_volume,{}\n", s.total_volume),
+        alloc::format!("total_pnl,{}\n", s.total_pnl),
+        alloc::format!("best_pair,{}\n", csv_escape(&s.best_pair)),
+        alloc::format!("worst_pair,{}\n", csv_escape(&s.worst_pair)),
+        alloc::format!("avg_hold_secs,{}\n", s.avg_hold_secs),
+    ];
+
+    for row in &rows {
+        push_str(&mut buf, row);
+    }
+
+    Ok(vec_to_bytes(env, &buf))
+}
+
+// ---------------------------------------------------------------------------
+// Tax report export
+// ---------------------------------------------------------------------------
+
+/// One realized lot: each trade is a closed round trip, opened when the
+/// signal was published and closed when the trade was recorded.
+struct TaxLot {
+    tax_year: u64,
+    trade_id: u64,
+    trade: TradeExecution,
+    signal: Signal,
+    realized_pnl: i128,
+}
+
+/// An executor's trades as realized lots, ordered by tax year, then close
+/// time.
+fn collect_tax_lots(
+    env: &Env,
+    executor: &Address,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> RustVec<TaxLot> {
+    let rounding = get_rounding_mode(env);
+    let mut lots: RustVec<TaxLot> = collect_trades(env, executor, date_range, filter)
+        .into_iter()
+        .map(|(trade_id, trade, signal)| TaxLot {
+            tax_year: civil_year_month(trade.timestamp).0,
+            trade_id,
+            realized_pnl: apply_bps(trade.volume, trade.roi, rounding).unwrap_or(0),
+            trade,
+            signal,
+        })
+        .collect();
+    lots.sort_by_key(|lot| (lot.tax_year, lot.trade.timestamp, lot.trade_id));
+    lots
+}
+
+pub fn export_tax_report_csv(
+    env: &Env,
+    executor: &Address,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> Result<Bytes, ExportError> {
+    let lots = collect_tax_lots(env, executor, date_range, filter);
+
+    let mut buf: RustVec<u8> = RustVec::new();
+    push_str(
+        &mut buf,
+        "tax_year,trade_id,signal_id,asset_pair,side,opened_at,closed_at,volume,entry_price,exit_price,realized_pnl\n",
+    );
+
+    for lot in &lots {
+        let row = alloc::format!(
+            "{},{},{},{},{},{},{},{},{},{},{}\n",
+            lot.tax_year,
+            lot.trade_id,
+            lot.trade.signal_id,
+            csv_escape(&pair_to_rust(env, &lot.signal.asset_pair)),
+            signal_action_str(&lot.signal.action),
+            lot.signal.timestamp,
+            lot.trade.timestamp,
+            lot.trade.volume,
+            lot.trade.entry_price,
+            lot.trade.exit_price,
+            lot.realized_pnl,
+        );
+        push_str(&mut buf, &row);
+    }
+
+    Ok(vec_to_bytes(env, &buf))
+}
+
+/// Lots grouped per tax year: `[{"tax_year":Y,"realized_pnl":P,"lots":[...]}]`.
+pub fn export_tax_report_json(
+    env: &Env,
+    executor: &Address,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> Result<Bytes, ExportError> {
+    let lots = collect_tax_lots(env, executor, date_range, filter);
+
+    let mut buf: RustVec<u8> = RustVec::new();
+    push_str(&mut buf, "[");
+
+    let mut start = 0;
+    while start < lots.len() {
+        let year = lots[start].tax_year;
+        let mut end = start;
+        let mut year_pnl: i128 = 0;
+        while end < lots.len() && lots[end].tax_year == year {
+            year_pnl = year_pnl.saturating_add(lots[end].realized_pnl);
+            end += 1;
+        }
+
+        if start > 0 {
+            push_str(&mut buf, ",");
+        }
+        push_str(
+            &mut buf,
+            &alloc::format!(r#"{{"tax_year":{},"realized_pnl":{},"lots":["#, year, year_pnl),
+        );
+        for (idx, lot) in lots[start..end].iter().enumerate() {
+            if idx > 0 {
+                push_str(&mut buf, ",");
+            }
+            let entry = alloc::format!(
+                r#"{{"trade_id":{},"signal_id":{},"asset_pair":"{}","side":"{}","opened_at":{},"closed_at":{},"volume":{},"entry_price":{},"exit_price":{},"realized_pnl":{}}}"#,
+                lot.trade_id,
+                lot.trade.signal_id,
+                json_escape(&pair_to_rust(env, &lot.signal.asset_pair)),
+                signal_action_str(&lot.signal.action),
+                lot.signal.timestamp,
+                lot.trade.timestamp,
+                lot.trade.volume,
+                lot.trade.entry_price,
+                lot.trade.exit_price,
+                lot.realized_pnl,
+            );
+            push_str(&mut buf, &entry);
+        }
+        push_str(&mut buf, "]}");
+        start = end;
+    }
+
+    push_str(&mut buf, "]");
+    Ok(vec_to_bytes(env, &buf))
+}
+
 // ---------------------------------------------------------------------------
 // Performance summary export
 // ---------------------------------------------------------------------------
@@ -449,14 +983,19 @@ pub struct PerformanceSummary {
     pub best_pair: RustString,
     pub worst_pair: RustString,
     pub avg_signal_lifetime_secs: u64,
+    /// Over the settled signals in range, ×100.
+    pub sortino_x100: i128,
+    pub profit_factor_x100: i128,
 }
 
 fn calculate_performance_summary(
     env: &Env,
     provider: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> PerformanceSummary {
-    let signals = collect_provider_signals(env, provider, date_range);
+    let signals = collect_provider_signals(env, provider, date_range, filter);
+    let rounding = get_rounding_mode(env);
 
     let total_signals = signals.len() as u32;
     let mut successful_signals: u32 = 0;
@@ -465,6 +1004,7 @@ fn calculate_performance_summary(
     let mut total_volume: i128 = 0;
     let mut total_lifetime_secs: u64 = 0;
     let mut total_trades: u32 = 0;
+    let mut settled = RoiStats::default();
 
     // Track ROI per asset pair
     let mut pair_roi: alloc::collections::BTreeMap<RustString, (i128, u32)> =
@@ -478,11 +1018,10 @@ fn calculate_performance_summary(
             failed_signals += 1;
         }
 
-        let avg_roi = if signal.executions > 0 {
-            signal.total_roi / signal.executions as i128
-        } else {
-            0
-        };
+        let avg_roi = get_signal_average_roi(signal, rounding);
+        if matches!(signal.status, SignalStatus::Successful | SignalStatus::Failed) {
+            risk_metrics::fold(&mut settled, avg_roi);
+        }
 
         total_roi_bps = total_roi_bps.saturating_add(avg_roi);
         total_volume = total_volume.saturating_add(signal.total_volume);
@@ -490,7 +1029,7 @@ fn calculate_performance_summary(
             total_lifetime_secs.saturating_add(signal.expiry.saturating_sub(signal.timestamp));
         total_trades = total_trades.saturating_add(signal.executions);
 
-        let pair_key = sdk_str_to_rust(&signal.asset_pair);
+        let pair_key = pair_to_rust(env, &signal.asset_pair);
         let entry = pair_roi.entry(pair_key).or_insert((0i128, 0u32));
         entry.0 = entry.0.saturating_add(avg_roi);
         entry.1 = entry.1.saturating_add(1);
@@ -540,6 +1079,8 @@ fn calculate_performance_summary(
         best_pair,
         worst_pair,
         avg_signal_lifetime_secs,
+        sortino_x100: risk_metrics::sortino_x100(&settled),
+        profit_factor_x100: risk_metrics::profit_factor_x100(&settled),
     }
 }
 
@@ -547,8 +1088,9 @@ pub fn export_performance_json(
     env: &Env,
     provider: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> Result<Bytes, ExportError> {
-    let s = calculate_performance_summary(env, provider, date_range);
+    let s = calculate_performance_summary(env, provider, date_range, filter);
 
     let sr_whole = s.success_rate_bps / 100;
     let sr_frac = s.success_rate_bps % 100;
@@ -557,7 +1099,7 @@ pub fn export_performance_json(
     let avg_lifetime_hours = s.avg_signal_lifetime_secs / 3600;
 
     let json = alloc::format!(
-        r#"{{"total_signals":{},"successful_signals":{},"failed_signals":{},"success_rate":"{}","total_roi_bps":{},"total_roi_pct":"{}","total_volume":{},"total_trades":{},"best_pair":"{}","worst_pair":"{}","avg_signal_lifetime_hours":{}}}"#,
+        r#"{{"total_signals":{},"successful_signals":{},"failed_signals":{},"success_rate":"{}","total_roi_bps":{},"total_roi_pct":"{}","total_volume":{},"total_trades":{},"best_pair":"{}","worst_pair":"{}","avg_signal_lifetime_hours":{},"sortino_x100":{},"profit_factor_x100":{}}}"#,
         s.total_signals,
         s.successful_signals,
         s.failed_signals,
@@ -566,9 +1108,11 @@ pub fn export_performance_json(
         bps_to_pct_str(s.total_roi_bps),
         s.total_volume,
         s.total_trades,
-        s.best_pair.replace('"', "\\\""),
-        s.worst_pair.replace('"', "\\\""),
+        json_escape(&s.best_pair),
+        json_escape(&s.worst_pair),
         avg_lifetime_hours,
+        s.sortino_x100,
+        s.profit_factor_x100,
     );
 
     let mut buf: RustVec<u8> = RustVec::new();
@@ -580,8 +1124,9 @@ pub fn export_performance_csv(
     env: &Env,
     provider: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> Result<Bytes, ExportError> {
-    let s = calculate_performance_summary(env, provider, date_range);
+    let s = calculate_performance_summary(env, provider, date_range, filter);
 
     let sr_whole = s.success_rate_bps / 100;
     let sr_frac = s.success_rate_bps % 100;
@@ -605,6 +1150,8 @@ pub fn export_performance_csv(
             "avg_signal_lifetime_hours,{}\n",
             s.avg_signal_lifetime_secs / 3600
         ),
+        alloc::format!("sortino_x100,{}\n", s.sortino_x100),
+        alloc::format!("profit_factor_x100,{}\n", s.profit_factor_x100),
     ];
 
     for row in &rows {
@@ -622,20 +1169,16 @@ pub fn export_portfolio_json(
     env: &Env,
     provider: &Address,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> Result<Bytes, ExportError> {
-    let signals = collect_provider_signals(env, provider, date_range);
+    let signals = collect_provider_signals(env, provider, date_range, filter);
     let trades = get_provider_trades(env, provider);
 
     let total_volume: i128 = signals.iter().map(|s| s.total_volume).sum();
+    let rounding = get_rounding_mode(env);
     let total_roi_bps: i128 = signals
         .iter()
-        .map(|s| {
-            if s.executions > 0 {
-                s.total_roi / s.executions as i128
-            } else {
-                0
-            }
-        })
+        .map(|s| get_signal_average_roi(s, rounding))
         .sum();
     let total_trades = trades.len() as u32;
     let active_signals = signals
@@ -668,30 +1211,102 @@ pub fn export_data(
     entity: ExportEntity,
     format: ExportFormat,
     date_range: Option<DateRange>,
+    filter: &ExportFilter,
 ) -> Result<Bytes, ExportError> {
     match (entity, format) {
         (ExportEntity::Signals, ExportFormat::Csv) => {
-            export_signals_csv(env, requester, date_range)
+            export_signals_csv(env, requester, date_range, filter)
         }
         (ExportEntity::Signals, ExportFormat::Json) => {
-            export_signals_json(env, requester, date_range)
+            export_signals_json(env, requester, date_range, filter)
+        }
+        (ExportEntity::Trades, ExportFormat::Csv) => {
+            export_trades_csv(env, requester, date_range, filter)
         }
-        (ExportEntity::Trades, ExportFormat::Csv) => export_trades_csv(env, requester, date_range),
         (ExportEntity::Trades, ExportFormat::Json) => {
-            export_trades_json(env, requester, date_range)
+            export_trades_json(env, requester, date_range, filter)
         }
         (ExportEntity::Performance, ExportFormat::Csv) => {
-            export_performance_csv(env, requester, date_range)
+            export_performance_csv(env, requester, date_range, filter)
         }
         (ExportEntity::Performance, ExportFormat::Json) => {
-            export_performance_json(env, requester, date_range)
+            export_performance_json(env, requester, date_range, filter)
         }
         (ExportEntity::Portfolio, ExportFormat::Json) => {
-            export_portfolio_json(env, requester, date_range)
+            export_portfolio_json(env, requester, date_range, filter)
         }
         (ExportEntity::Portfolio, ExportFormat::Csv) => {
             // Portfolio makes most sense as JSON; CSV is a flat summary
-            export_portfolio_json(env, requester, date_range)
+            export_portfolio_json(env, requester, date_range, filter)
+        }
+        (ExportEntity::Attribution, ExportFormat::Csv) => {
+            export_attribution_csv(env, requester, date_range, filter)
+        }
+        (ExportEntity::Attribution, ExportFormat::Json) => {
+            export_attribution_json(env, requester, date_range, filter)
+        }
+        (ExportEntity::ExecutorPerformance, ExportFormat::Csv) => {
+            export_executor_performance_csv(env, requester, date_range, filter)
+        }
+        (ExportEntity::ExecutorPerformance, ExportFormat::Json) => {
+            export_executor_performance_json(env, requester, date_range, filter)
+        }
+        (ExportEntity::TaxReport, ExportFormat::Csv) => {
+            export_tax_report_csv(env, requester, date_range, filter)
+        }
+        (ExportEntity::TaxReport, ExportFormat::Json) => {
+            export_tax_report_json(env, requester, date_range, filter)
         }
     }
 }
+
+/// Number of rows an export of `entity` holds, for its metadata.
+fn record_count(
+    env: &Env,
+    requester: &Address,
+    entity: &ExportEntity,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> u32 {
+    match entity {
+        ExportEntity::Signals => {
+            collect_provider_signals(env, requester, date_range, filter).len() as u32
+        }
+        ExportEntity::Trades | ExportEntity::TaxReport => {
+            collect_trades(env, requester, date_range, filter).len() as u32
+        }
+        ExportEntity::Attribution => {
+            collect_attribution(env, requester, date_range, filter).len() as u32
+        }
+        ExportEntity::Performance
+        | ExportEntity::Portfolio
+        | ExportEntity::ExecutorPerformance => 1,
+    }
+}
+
+/// `export_data` plus metadata describing the result.
+pub fn export_with_metadata(
+    env: &Env,
+    requester: &Address,
+    entity: u32,
+    format: u32,
+    date_range: Option<DateRange>,
+    filter: &ExportFilter,
+) -> Result<ExportWithMetadata, ExportError> {
+    let export_entity = ExportEntity::from_u32(entity)?;
+    let export_format = ExportFormat::from_u32(format)?;
+    let count = record_count(env, requester, &export_entity, date_range, filter);
+    let data = export_data(env, requester, export_entity, export_format, date_range, filter)?;
+
+    let (range_start, range_end) = date_range.unwrap_or((0, u64::MAX));
+    let metadata = ExportMetadata {
+        entity,
+        format,
+        range_start,
+        range_end,
+        record_count: count,
+        generated_at: env.ledger().timestamp(),
+        content_hash: env.crypto().sha256(&data).into(),
+    };
+    Ok(ExportWithMetadata { data, metadata })
+}
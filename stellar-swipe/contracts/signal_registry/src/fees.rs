@@ -1,8 +1,11 @@
-use soroban_sdk::{Address, Env, Map};
+use soroban_sdk::{token, Address, Env, Map};
 
 use crate::admin;
 use crate::errors::FeeError;
-use crate::events::emit_fee_collected;
+use crate::stake;
+use crate::events::{emit_fee_collected, emit_fees_withdrawn, emit_referral_rewarded};
+use crate::referrals;
+use crate::staking_rewards;
 use crate::types::{Asset, FeeBreakdown, FeeStorageKey};
 
 // Fee configuration
@@ -15,13 +18,19 @@ pub const MIN_TRADE_AMOUNT: i128 = 1000; // Minimum trade to ensure non-zero fee
 /// Calculate fee for a given trade amount
 /// Returns (fee_amount, amount_after_fee)
 pub fn calculate_fee(trade_amount: i128) -> Result<(i128, i128), FeeError> {
+    calculate_fee_at(trade_amount, FEE_BPS)
+}
+
+/// Calculate fee for a given trade amount at `fee_bps`
+/// Returns (fee_amount, amount_after_fee)
+pub fn calculate_fee_at(trade_amount: i128, fee_bps: u32) -> Result<(i128, i128), FeeError> {
     if trade_amount < MIN_TRADE_AMOUNT {
         return Err(FeeError::TradeTooSmall);
     }
 
-    // Calculate fee: trade_amount × 10 / 10000
+    // Calculate fee: trade_amount × fee_bps / 10000
     let fee = trade_amount
-        .checked_mul(FEE_BPS as i128)
+        .checked_mul(fee_bps as i128)
         .ok_or(FeeError::ArithmeticOverflow)?
         .checked_div(BPS_DENOMINATOR as i128)
         .ok_or(FeeError::ArithmeticOverflow)?;
@@ -41,7 +50,74 @@ pub fn calculate_fee(trade_amount: i128) -> Result<(i128, i128), FeeError> {
 /// Calculate fee breakdown (platform vs provider split)
 pub fn calculate_fee_breakdown(trade_amount: i128) -> Result<FeeBreakdown, FeeError> {
     let (total_fee, amount_after_fee) = calculate_fee(trade_amount)?;
+    split_fee(total_fee, amount_after_fee)
+}
+
+/// Fee discount earned by staking, in bps of the fee: (minimum stake, discount).
+/// The largest tier the stake reaches applies.
+pub const STAKE_DISCOUNT_TIERS: [(i128, u32); 3] = [
+    (100_000_000, 1_000),    // 100 XLM: 10% off
+    (1_000_000_000, 2_500),  // 1,000 XLM: 25% off
+    (10_000_000_000, 5_000), // 10,000 XLM: 50% off
+];
+
+/// Discount in bps of the fee for a payer with `stake` staked.
+pub fn stake_discount_bps(stake: i128) -> u32 {
+    let mut discount = 0;
+    for (min_stake, bps) in STAKE_DISCOUNT_TIERS {
+        if stake >= min_stake {
+            discount = bps;
+        }
+    }
+    discount
+}
+
+/// Discount `payer` gets on trade fees from their stake in the registry.
+pub fn payer_discount_bps(env: &Env, payer: &Address) -> u32 {
+    stake::get_stake_info(env, payer)
+        .map(|info| stake_discount_bps(info.amount))
+        .unwrap_or(0)
+}
+
+/// Fee breakdown at the admin-configured `trade_fee_bps`, less `discount_bps`
+/// of the fee. A zero rate charges nothing.
+pub fn calculate_fees(
+    env: &Env,
+    trade_amount: i128,
+    discount_bps: u32,
+) -> Result<FeeBreakdown, FeeError> {
+    let fee_bps = admin::get_trade_fee(env);
+    if fee_bps == 0 {
+        validate_trade_amount(trade_amount)?;
+        return Ok(FeeBreakdown {
+            total_fee: 0,
+            platform_fee: 0,
+            provider_fee: 0,
+            trade_amount_after_fee: trade_amount,
+            discount: 0,
+        });
+    }
+    let (full_fee, _) = calculate_fee_at(trade_amount, fee_bps)?;
+    let discount = full_fee
+        .checked_mul(discount_bps.min(BPS_DENOMINATOR) as i128)
+        .ok_or(FeeError::ArithmeticOverflow)?
+        / BPS_DENOMINATOR as i128;
+    let total_fee = full_fee - discount;
+    let mut breakdown = split_fee(total_fee, trade_amount - total_fee)?;
+    breakdown.discount = discount;
+    Ok(breakdown)
+}
+
+/// Split a subscription payment between platform and provider the same way
+/// trade fees are split. Nothing is left over, so `trade_amount_after_fee` is 0.
+pub fn subscription_breakdown(price: i128) -> Result<FeeBreakdown, FeeError> {
+    if price <= 0 {
+        return Err(FeeError::InvalidAmount);
+    }
+    split_fee(price, 0)
+}
 
+fn split_fee(total_fee: i128, amount_after_fee: i128) -> Result<FeeBreakdown, FeeError> {
     // Split fee: 70% platform, 30% provider
     let platform_fee = total_fee
         .checked_mul(PLATFORM_SHARE_PERCENTAGE as i128)
@@ -58,6 +134,7 @@ pub fn calculate_fee_breakdown(trade_amount: i128) -> Result<FeeBreakdown, FeeEr
         platform_fee,
         provider_fee,
         trade_amount_after_fee: amount_after_fee,
+        discount: 0,
     })
 }
 
@@ -95,6 +172,52 @@ pub fn add_to_treasury(env: &Env, asset: Asset, amount: i128) -> Result<(), FeeE
     Ok(())
 }
 
+/// Take fees back out of the platform's balance for an asset
+fn deduct_from_treasury(env: &Env, asset: Asset, amount: i128) -> Result<(), FeeError> {
+    let mut balances = get_treasury_balances(env);
+    let new_balance = balances
+        .get(asset.clone())
+        .unwrap_or(0)
+        .checked_sub(amount)
+        .filter(|b| *b >= 0)
+        .ok_or(FeeError::InvalidAmount)?;
+    balances.set(asset, new_balance);
+    save_treasury_balances(env, &balances);
+    Ok(())
+}
+
+/// Unclaimed fee share of one provider, per asset
+pub fn get_provider_fee_balances(env: &Env, provider: &Address) -> Map<Asset, i128> {
+    env.storage()
+        .persistent()
+        .get(&FeeStorageKey::ProviderBalances(provider.clone()))
+        .unwrap_or(Map::new(env))
+}
+
+/// Credit a provider's fee share for a specific asset
+pub fn add_provider_fees(
+    env: &Env,
+    provider: &Address,
+    asset: Asset,
+    amount: i128,
+) -> Result<(), FeeError> {
+    if amount <= 0 {
+        return Err(FeeError::InvalidAmount);
+    }
+
+    let mut balances = get_provider_fee_balances(env, provider);
+    let new_balance = balances
+        .get(asset.clone())
+        .unwrap_or(0)
+        .checked_add(amount)
+        .ok_or(FeeError::ArithmeticOverflow)?;
+    balances.set(asset, new_balance);
+    env.storage()
+        .persistent()
+        .set(&FeeStorageKey::ProviderBalances(provider.clone()), &balances);
+    Ok(())
+}
+
 /// Get treasury balance for a specific asset
 pub fn get_treasury_balance(env: &Env, asset: Asset) -> i128 {
     let balances = get_treasury_balances(env);
@@ -112,6 +235,7 @@ pub fn get_all_treasury_balances(env: &Env) -> Map<Asset, i128> {
 pub fn collect_and_distribute_fee(
     env: &Env,
     trade_amount: i128,
+    discount_bps: u32,
     asset: Asset,
     provider: Address,
     platform_treasury: Address,
@@ -123,6 +247,7 @@ pub fn collect_and_distribute_fee(
             platform_fee: 0,
             provider_fee: 0,
             trade_amount_after_fee: trade_amount,
+            discount: 0,
         });
     }
 
@@ -131,15 +256,16 @@ pub fn collect_and_distribute_fee(
         return Err(FeeError::InvalidProviderAddress);
     }
 
-    // Calculate fee breakdown
-    let breakdown = calculate_fee_breakdown(trade_amount)?;
+    // Calculate fee breakdown at the configured rate
+    let breakdown = calculate_fees(env, trade_amount, discount_bps)?;
 
-    // Add to treasury tracking
-    add_to_treasury(env, asset.clone(), breakdown.total_fee)?;
-
-    // TODO: transfer tokens here
-    // token_client.transfer(&env.current_contract_address(), &platform_treasury, &breakdown.platform_fee);
-    // token_client.transfer(&env.current_contract_address(), &provider, &breakdown.provider_fee);
+    // Accrue each share until it is withdrawn or claimed
+    if breakdown.platform_fee > 0 {
+        add_to_treasury(env, asset.clone(), breakdown.platform_fee)?;
+    }
+    if breakdown.provider_fee > 0 {
+        add_provider_fees(env, &provider, asset.clone(), breakdown.provider_fee)?;
+    }
 
     // Emit event
     emit_fee_collected(
@@ -155,6 +281,94 @@ pub fn collect_and_distribute_fee(
     Ok(breakdown)
 }
 
+/// Pull the fee on a `trade_amount` trade from `payer` into the contract,
+/// after their stake discount, and accrue it. The payer's referrer, if any,
+/// is paid out of the platform share, then stakers get their slice of what
+/// is left. Returns the breakdown charged.
+pub fn collect_fee(
+    env: &Env,
+    payer: &Address,
+    trade_amount: i128,
+    asset: Asset,
+    provider: Address,
+) -> Result<FeeBreakdown, FeeError> {
+    let treasury = get_platform_treasury(env).ok_or(FeeError::TreasuryNotSet)?;
+    let discount_bps = payer_discount_bps(env, payer);
+    let breakdown = collect_and_distribute_fee(
+        env,
+        trade_amount,
+        discount_bps,
+        asset.clone(),
+        provider,
+        treasury,
+    )?;
+    if breakdown.total_fee > 0 {
+        token::Client::new(env, &asset.contract).transfer(
+            payer,
+            &env.current_contract_address(),
+            &breakdown.total_fee,
+        );
+    }
+    let mut platform_kept = breakdown.platform_fee;
+    if let Some((referrer, amount)) = referrals::reward(
+        env,
+        payer,
+        &asset,
+        breakdown.total_fee,
+        breakdown.platform_fee,
+    ) {
+        deduct_from_treasury(env, asset.clone(), amount)?;
+        platform_kept -= amount;
+        emit_referral_rewarded(env, referrer, payer.clone(), asset.clone(), amount);
+    }
+    let to_stakers = staking_rewards::fund(env, &asset, platform_kept);
+    if to_stakers > 0 {
+        deduct_from_treasury(env, asset, to_stakers)?;
+    }
+    Ok(breakdown)
+}
+
+/// Pay out every non-zero balance in `balances` to `to`.
+pub fn pay_out(env: &Env, balances: &Map<Asset, i128>, to: &Address) -> Map<Asset, i128> {
+    let mut paid = Map::new(env);
+    for (asset, amount) in balances.iter() {
+        if amount > 0 {
+            token::Client::new(env, &asset.contract).transfer(
+                &env.current_contract_address(),
+                to,
+                &amount,
+            );
+            paid.set(asset, amount);
+        }
+    }
+    paid
+}
+
+/// Send all accrued platform fees to the platform treasury
+pub fn withdraw_platform_fees(env: &Env) -> Result<Map<Asset, i128>, FeeError> {
+    let treasury = get_platform_treasury(env).ok_or(FeeError::TreasuryNotSet)?;
+    let withdrawn = pay_out(env, &get_treasury_balances(env), &treasury);
+    if withdrawn.is_empty() {
+        return Err(FeeError::NothingToClaim);
+    }
+    save_treasury_balances(env, &Map::new(env));
+    emit_fees_withdrawn(env, treasury, withdrawn.clone());
+    Ok(withdrawn)
+}
+
+/// Send a provider their accrued fee share
+pub fn claim_provider_fees(env: &Env, provider: &Address) -> Result<Map<Asset, i128>, FeeError> {
+    let claimed = pay_out(env, &get_provider_fee_balances(env, provider), provider);
+    if claimed.is_empty() {
+        return Err(FeeError::NothingToClaim);
+    }
+    env.storage()
+        .persistent()
+        .remove(&FeeStorageKey::ProviderBalances(provider.clone()));
+    emit_fees_withdrawn(env, provider.clone(), claimed.clone());
+    Ok(claimed)
+}
+
 /// Set platform treasury address (admin only)
 pub fn set_platform_treasury(env: &Env, treasury: Address) {
     env.storage()
@@ -169,6 +383,18 @@ pub fn get_platform_treasury(env: &Env) -> Option<Address> {
         .get(&FeeStorageKey::PlatformTreasury)
 }
 
+/// Asset trade fees are charged in, if fees are charged on executions.
+pub fn get_fee_asset(env: &Env) -> Option<Asset> {
+    env.storage().instance().get(&FeeStorageKey::FeeAsset)
+}
+
+pub fn set_fee_asset(env: &Env, asset: Option<Asset>) {
+    match asset {
+        Some(asset) => env.storage().instance().set(&FeeStorageKey::FeeAsset, &asset),
+        None => env.storage().instance().remove(&FeeStorageKey::FeeAsset),
+    }
+}
+
 /// Validate minimum trade amount
 pub fn validate_trade_amount(trade_amount: i128) -> Result<(), FeeError> {
     if trade_amount < MIN_TRADE_AMOUNT {
@@ -250,7 +476,16 @@ mod tests {
         let platform = Address::generate(&env);
 
         // Provider same as platform should fail
-        collect_and_distribute_fee(&env, 1_000_000_000, asset, platform.clone(), platform).unwrap();
+        collect_and_distribute_fee(&env, 1_000_000_000, 0, asset, platform.clone(), platform).unwrap();
+    }
+
+    #[test]
+    fn test_stake_discount_tiers() {
+        assert_eq!(stake_discount_bps(0), 0);
+        assert_eq!(stake_discount_bps(99_999_999), 0);
+        assert_eq!(stake_discount_bps(100_000_000), 1_000);
+        assert_eq!(stake_discount_bps(5_000_000_000), 2_500);
+        assert_eq!(stake_discount_bps(10_000_000_000), 5_000);
     }
 
     #[test]
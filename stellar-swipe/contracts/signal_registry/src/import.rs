@@ -1,6 +1,6 @@
 use crate::errors::ImportError;
 use crate::types::SignalAction;
-use soroban_sdk::{Address, Bytes, Env, Map, String, Vec};
+use soroban_sdk::{Address, Bytes, Env, Map, String};
 
 const MAX_BATCH_SIZE: u32 = 100;
 const MAX_RATIONALE_LEN: u32 = 500;
@@ -11,7 +11,7 @@ pub struct ImportResult {
 }
 
 pub fn import_signals_csv(
-    env: &Env,
+    _env: &Env,
     _provider: &Address,
     data: Bytes,
     validate_only: bool,
@@ -4,18 +4,34 @@
 //! each capped at INDEX_CAPACITY. Updated on every signal close via
 //! update_leaderboard_index. Queries are O(1) storage reads.
 //!
-//! Qualification: provider must have >= MIN_CLOSED_SIGNALS (10) closed signals.
+//! Qualification rules (closed signals, adopters, volume, age since first
+//! signal) are admin-configured; see `admin::LeaderboardQualification`. By
+//! default a provider needs 10 closed signals and at least one adopter.
+//!
+//! The legacy follower, tip, top-provider and risk-adjusted (AvgRoi, Sharpe)
+//! rankings are kept the same way: bounded lists re-sorted on every stats
+//! update, not at query time. AvgRoi and Sharpe also need the minimum number
+//! of closed signals to have settled with a ROI.
+//!
+//! Whenever an update reorders the top TOP_N_ANNOUNCED of a metric an
+//! `lb_top3` event carries the old and new podium so bots can announce rank
+//! changes without polling.
 
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
 
-use crate::social;
+use crate::admin;
+use crate::reputation;
+use crate::risk_metrics;
 use crate::stake;
+use crate::tipping;
 use crate::types::ProviderPerformance;
 
-pub const MIN_CLOSED_SIGNALS: u32 = 10;
 pub const DEFAULT_LEADERBOARD_LIMIT: u32 = 10;
 pub const MAX_LEADERBOARD_LIMIT: u32 = 50;
 pub const INDEX_CAPACITY: u32 = 100;
+pub const TOP_N_ANNOUNCED: u32 = 3;
+/// Neighbours shown on each side by `get_provider_rank`.
+pub const RANK_CONTEXT: u32 = 2;
 
 // ── Public types ──────────────────────────────────────────────────────────────
 
@@ -38,6 +54,17 @@ pub struct ProviderLeaderboardEntry {
     pub verified: bool,
 }
 
+/// A provider's place on a board and the entries around it.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProviderRank {
+    pub entry: ProviderLeaderboardEntry,
+    /// Entries from RANK_CONTEXT above to RANK_CONTEXT below, including `entry`.
+    pub surrounding: Vec<ProviderLeaderboardEntry>,
+    /// Providers ranked on this board.
+    pub board_size: u32,
+}
+
 // ── Legacy aliases ────────────────────────────────────────────────────────────
 
 pub type ProviderLeaderboard = ProviderLeaderboardEntry;
@@ -48,6 +75,12 @@ pub enum LeaderboardMetric {
     SuccessRate,
     Volume,
     Followers,
+    /// Net tips received in the admin-set tip ranking token.
+    Tips,
+    /// Mean ROI of settled signals, in bps.
+    AvgRoi,
+    /// Mean over standard deviation of settled signal ROIs, ×100.
+    Sharpe,
 }
 
 // ── Storage keys ──────────────────────────────────────────────────────────────
@@ -59,6 +92,12 @@ pub enum LeaderboardKey {
     AdoptersIndex,
     ProfitDeltaIndex,
     StakeIndex,
+    FollowersIndex,
+    TipsIndex,
+    /// Every provider with closed signals by success rate, unqualified.
+    TopProvidersIndex,
+    AvgRoiIndex,
+    SharpeIndex,
 }
 
 // ── Index entry ───────────────────────────────────────────────────────────────
@@ -88,12 +127,28 @@ fn save_index(env: &Env, key: LeaderboardKey, index: &Vec<IndexEntry>) {
     env.storage().persistent().set(&key, index);
 }
 
-fn is_qualified(entry: &IndexEntry) -> bool {
-    entry.closed_signals >= MIN_CLOSED_SIGNALS && entry.total_adopters > 0
+/// Checks a provider against the admin-configured qualification rules.
+fn is_qualified(env: &Env, entry: &IndexEntry, stats: &ProviderPerformance) -> bool {
+    let rules = admin::get_leaderboard_qualification(env);
+    let first_signal = reputation::get_first_signal_time(env, &entry.provider);
+    let age = if first_signal == 0 {
+        0
+    } else {
+        env.ledger().timestamp().saturating_sub(first_signal)
+    };
+    entry.closed_signals >= rules.min_closed_signals
+        && entry.total_adopters >= rules.min_adopters
+        && stats.total_volume >= rules.min_volume
+        && age >= rules.min_age_secs
 }
 
-fn upsert_sorted<F>(env: &Env, index: &mut Vec<IndexEntry>, entry: IndexEntry, score_fn: F)
-where
+fn upsert_sorted<F>(
+    env: &Env,
+    index: &mut Vec<IndexEntry>,
+    entry: IndexEntry,
+    qualified: bool,
+    score_fn: F,
+) where
     F: Fn(&IndexEntry) -> i128,
 {
     let mut without: Vec<IndexEntry> = Vec::new(env);
@@ -104,7 +159,7 @@ where
         }
     }
 
-    if !is_qualified(&entry) {
+    if !qualified {
         *index = without;
         return;
     }
@@ -135,6 +190,140 @@ where
     *index = capped;
 }
 
+fn index_key(metric: ProviderMetric) -> LeaderboardKey {
+    match metric {
+        ProviderMetric::BySuccessRate => LeaderboardKey::SuccessRateIndex,
+        ProviderMetric::ByTotalAdopters => LeaderboardKey::AdoptersIndex,
+        ProviderMetric::ByTotalProfitDelta => LeaderboardKey::ProfitDeltaIndex,
+        ProviderMetric::ByStake => LeaderboardKey::StakeIndex,
+    }
+}
+
+fn metric_value(metric: ProviderMetric, e: &IndexEntry) -> i128 {
+    match metric {
+        ProviderMetric::BySuccessRate => e.success_rate as i128,
+        ProviderMetric::ByTotalAdopters => e.total_adopters as i128,
+        ProviderMetric::ByTotalProfitDelta => e.total_profit_delta,
+        ProviderMetric::ByStake => e.stake_amount,
+    }
+}
+
+fn podium(env: &Env, index: &Vec<IndexEntry>) -> Vec<Address> {
+    let mut top = Vec::new(env);
+    for i in 0..TOP_N_ANNOUNCED.min(index.len()) {
+        top.push_back(index.get(i).unwrap().provider);
+    }
+    top
+}
+
+/// Re-sort one metric's index and announce any change to its podium.
+fn update_metric_index(env: &Env, metric: ProviderMetric, entry: &IndexEntry, qualified: bool) {
+    let key = index_key(metric);
+    let mut index = load_index(env, key.clone());
+    let old_top = podium(env, &index);
+
+    upsert_sorted(env, &mut index, entry.clone(), qualified, |e| {
+        metric_value(metric, e)
+    });
+    save_index(env, key, &index);
+
+    let new_top = podium(env, &index);
+    if new_top != old_top {
+        let mut values = Vec::new(env);
+        for i in 0..new_top.len() {
+            values.push_back(metric_value(metric, &index.get(i).unwrap()));
+        }
+        env.events().publish(
+            (symbol_short!("lb_top3"), metric),
+            (old_top.first(), new_top.first(), new_top, values),
+        );
+    }
+}
+
+/// Move `entry` to its place in a bounded list sorted by `metric_value`, or
+/// drop it from the list when it no longer qualifies.
+fn upsert_ranked(env: &Env, key: LeaderboardKey, entry: ProviderLeaderboardEntry, qualified: bool) {
+    let index: Vec<ProviderLeaderboardEntry> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut result: Vec<ProviderLeaderboardEntry> = Vec::new(env);
+    let mut inserted = !qualified;
+    for e in index.iter() {
+        if e.provider == entry.provider {
+            continue;
+        }
+        if !inserted && e.metric_value < entry.metric_value {
+            result.push_back(entry.clone());
+            inserted = true;
+        }
+        if result.len() < INDEX_CAPACITY {
+            result.push_back(e);
+        }
+    }
+    if !inserted && result.len() < INDEX_CAPACITY {
+        result.push_back(entry);
+    }
+    while result.len() > INDEX_CAPACITY {
+        result.pop_back();
+    }
+    env.storage().persistent().set(&key, &result);
+}
+
+fn ranked_key(metric: LeaderboardMetric) -> LeaderboardKey {
+    match metric {
+        LeaderboardMetric::SuccessRate => LeaderboardKey::SuccessRateIndex,
+        LeaderboardMetric::Volume => LeaderboardKey::ProfitDeltaIndex,
+        LeaderboardMetric::Followers => LeaderboardKey::FollowersIndex,
+        LeaderboardMetric::Tips => LeaderboardKey::TipsIndex,
+        LeaderboardMetric::AvgRoi => LeaderboardKey::AvgRoiIndex,
+        LeaderboardMetric::Sharpe => LeaderboardKey::SharpeIndex,
+    }
+}
+
+/// Entries `offset..offset + count` of a board, ranked by position.
+fn board_slice(
+    env: &Env,
+    metric: LeaderboardMetric,
+    offset: u32,
+    count: u32,
+) -> Vec<ProviderLeaderboardEntry> {
+    let mut result = Vec::new(env);
+    let provider_metric = match metric {
+        LeaderboardMetric::SuccessRate => Some(ProviderMetric::BySuccessRate),
+        LeaderboardMetric::Volume => Some(ProviderMetric::ByTotalProfitDelta),
+        _ => None,
+    };
+    if let Some(provider_metric) = provider_metric {
+        let index = load_index(env, index_key(provider_metric));
+        for i in offset..offset.saturating_add(count).min(index.len()) {
+            let e = index.get(i).unwrap();
+            result.push_back(ProviderLeaderboardEntry {
+                rank: i + 1,
+                provider: e.provider.clone(),
+                metric_value: metric_value(provider_metric, &e),
+                total_signals: e.closed_signals,
+                verified: e.verified,
+            });
+        }
+        return result;
+    }
+
+    let index: Vec<ProviderLeaderboardEntry> = env
+        .storage()
+        .persistent()
+        .get(&ranked_key(metric))
+        .unwrap_or_else(|| Vec::new(env));
+    for i in offset..offset.saturating_add(count).min(index.len()) {
+        let mut entry = index.get(i).unwrap();
+        entry.rank = i + 1;
+        result.push_back(entry);
+    }
+    result
+}
+
 // ── Public API ────────────────────────────────────────────────────────────────
 
 pub fn update_leaderboard_index(env: &Env, provider: Address, stats: &ProviderPerformance) {
@@ -156,21 +345,49 @@ pub fn update_leaderboard_index(env: &Env, provider: Address, stats: &ProviderPe
         verified,
     };
 
-    let mut sr = load_index(env, LeaderboardKey::SuccessRateIndex);
-    upsert_sorted(env, &mut sr, entry.clone(), |e| e.success_rate as i128);
-    save_index(env, LeaderboardKey::SuccessRateIndex, &sr);
+    let qualified = is_qualified(env, &entry, stats);
+    update_metric_index(env, ProviderMetric::BySuccessRate, &entry, qualified);
+    update_metric_index(env, ProviderMetric::ByTotalAdopters, &entry, qualified);
+    update_metric_index(env, ProviderMetric::ByTotalProfitDelta, &entry, qualified);
+    update_metric_index(env, ProviderMetric::ByStake, &entry, qualified);
 
-    let mut ad = load_index(env, LeaderboardKey::AdoptersIndex);
-    upsert_sorted(env, &mut ad, entry.clone(), |e| e.total_adopters as i128);
-    save_index(env, LeaderboardKey::AdoptersIndex, &ad);
-
-    let mut pd = load_index(env, LeaderboardKey::ProfitDeltaIndex);
-    upsert_sorted(env, &mut pd, entry.clone(), |e| e.total_profit_delta);
-    save_index(env, LeaderboardKey::ProfitDeltaIndex, &pd);
-
-    let mut sk = load_index(env, LeaderboardKey::StakeIndex);
-    upsert_sorted(env, &mut sk, entry, |e| e.stake_amount);
-    save_index(env, LeaderboardKey::StakeIndex, &sk);
+    let ranked = |metric_value: i128| ProviderLeaderboardEntry {
+        rank: 0,
+        provider: provider.clone(),
+        metric_value,
+        total_signals: stats.total_signals,
+        verified,
+    };
+    upsert_ranked(
+        env,
+        LeaderboardKey::FollowersIndex,
+        ranked(stats.follower_count as i128),
+        stats.follower_count > 0,
+    );
+    let tipped = tipping::ranked_total(env, &provider);
+    upsert_ranked(env, LeaderboardKey::TipsIndex, ranked(tipped), tipped > 0);
+    upsert_ranked(
+        env,
+        LeaderboardKey::TopProvidersIndex,
+        ranked(stats.success_rate as i128),
+        closed_signals > 0,
+    );
+
+    let roi_stats = risk_metrics::get_roi_stats(env, &provider);
+    let risk_qualified = qualified
+        && roi_stats.count >= admin::get_leaderboard_qualification(env).min_closed_signals;
+    upsert_ranked(
+        env,
+        LeaderboardKey::AvgRoiIndex,
+        ranked(risk_metrics::mean_roi(&roi_stats)),
+        risk_qualified,
+    );
+    upsert_ranked(
+        env,
+        LeaderboardKey::SharpeIndex,
+        ranked(risk_metrics::sharpe_x100(&roi_stats)),
+        risk_qualified,
+    );
 
     env.events()
         .publish((symbol_short!("lb_upd"), provider), stats.success_rate);
@@ -187,25 +404,14 @@ pub fn get_provider_leaderboard(
         limit.min(MAX_LEADERBOARD_LIMIT)
     };
 
-    let key = match metric {
-        ProviderMetric::BySuccessRate => LeaderboardKey::SuccessRateIndex,
-        ProviderMetric::ByTotalAdopters => LeaderboardKey::AdoptersIndex,
-        ProviderMetric::ByTotalProfitDelta => LeaderboardKey::ProfitDeltaIndex,
-        ProviderMetric::ByStake => LeaderboardKey::StakeIndex,
-    };
-
+    let key = index_key(metric);
     let index = load_index(env, key);
     let take = limit.min(index.len());
     let mut result = Vec::new(env);
 
     for i in 0..take {
         let e = index.get(i).unwrap();
-        let metric_value = match metric {
-            ProviderMetric::BySuccessRate => e.success_rate as i128,
-            ProviderMetric::ByTotalAdopters => e.total_adopters as i128,
-            ProviderMetric::ByTotalProfitDelta => e.total_profit_delta,
-            ProviderMetric::ByStake => e.stake_amount,
-        };
+        let metric_value = metric_value(metric, &e);
         result.push_back(ProviderLeaderboardEntry {
             rank: i + 1,
             provider: e.provider,
@@ -221,61 +427,63 @@ pub fn get_provider_leaderboard(
 /// Legacy wrapper kept for backward-compat with existing get_leaderboard callers.
 pub fn get_leaderboard(
     env: &Env,
-    stats_map: &soroban_sdk::Map<Address, ProviderPerformance>,
     metric: LeaderboardMetric,
     limit: u32,
 ) -> Vec<ProviderLeaderboardEntry> {
     match metric {
         LeaderboardMetric::SuccessRate => get_provider_leaderboard(env, ProviderMetric::BySuccessRate, limit),
         LeaderboardMetric::Volume => get_provider_leaderboard(env, ProviderMetric::ByTotalProfitDelta, limit),
-        LeaderboardMetric::Followers => get_followers_leaderboard(env, stats_map, limit),
+        LeaderboardMetric::Followers => board_slice(env, metric, 0, limit),
+        LeaderboardMetric::Tips => board_slice(env, metric, 0, limit),
+        LeaderboardMetric::AvgRoi => board_slice(env, metric, 0, limit),
+        LeaderboardMetric::Sharpe => board_slice(env, metric, 0, limit),
     }
 }
 
-fn get_followers_leaderboard(
+/// One page of a board: entries ranked `offset + 1` onwards, up to
+/// MAX_LEADERBOARD_LIMIT at a time and INDEX_CAPACITY in total.
+pub fn get_leaderboard_page(
     env: &Env,
-    stats_map: &soroban_sdk::Map<Address, ProviderPerformance>,
+    metric: LeaderboardMetric,
+    offset: u32,
     limit: u32,
 ) -> Vec<ProviderLeaderboardEntry> {
-    let mut providers: Vec<ProviderLeaderboardEntry> = Vec::new(env);
-    for key in stats_map.keys() {
-        if let Some(stats) = stats_map.get(key.clone()) {
-            let follower_count = social::get_follower_count(env, &key);
-            if follower_count == 0 {
-                continue;
-            }
-            let stake_amount = stake::get_stake_info(env, &key)
-                .as_ref()
-                .map(|s| s.amount)
-                .unwrap_or(0);
-            providers.push_back(ProviderLeaderboardEntry {
-                rank: 0,
-                provider: key.clone(),
-                metric_value: follower_count as i128,
-                total_signals: stats.total_signals,
-                verified: stake_amount >= stake::DEFAULT_MINIMUM_STAKE,
-            });
-        }
-    }
+    let limit = if limit == 0 {
+        DEFAULT_LEADERBOARD_LIMIT
+    } else {
+        limit.min(MAX_LEADERBOARD_LIMIT)
+    };
+    board_slice(env, metric, offset, limit)
+}
 
-    let len = providers.len();
-    for i in 0..len {
-        for j in 0..(len - i - 1) {
-            let curr = providers.get(j).unwrap();
-            let next = providers.get(j + 1).unwrap();
-            if curr.metric_value < next.metric_value {
-                providers.set(j, next);
-                providers.set(j + 1, curr);
-            }
-        }
-    }
+/// Where `provider` stands on a board, with up to RANK_CONTEXT entries
+/// either side; `None` when they aren't ranked.
+pub fn get_provider_rank(
+    env: &Env,
+    provider: &Address,
+    metric: LeaderboardMetric,
+) -> Option<ProviderRank> {
+    let board = board_slice(env, metric, 0, INDEX_CAPACITY);
+    let position = board.iter().position(|e| e.provider == *provider)? as u32;
+    let start = position.saturating_sub(RANK_CONTEXT);
+    let end = (position + RANK_CONTEXT + 1).min(board.len());
+    Some(ProviderRank {
+        entry: board.get(position).unwrap(),
+        surrounding: board.slice(start..end),
+        board_size: board.len(),
+    })
+}
 
-    let take = limit.min(providers.len());
+/// Providers with at least one closed signal, by success rate, unqualified.
+pub fn get_top_providers(env: &Env, limit: u32) -> Vec<Address> {
     let mut result = Vec::new(env);
-    for i in 0..take {
-        let mut entry = providers.get(i).unwrap();
-        entry.rank = i + 1;
-        result.push_back(entry);
+    let index: Vec<ProviderLeaderboardEntry> = env
+        .storage()
+        .persistent()
+        .get(&LeaderboardKey::TopProvidersIndex)
+        .unwrap_or_else(|| Vec::new(env));
+    for i in 0..limit.min(index.len()) {
+        result.push_back(index.get(i).unwrap().provider);
     }
     result
 }
@@ -285,7 +493,7 @@ fn get_followers_leaderboard(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::ProviderPerformance;
+    use crate::types::{AttributionMode, ProviderPerformance};
     use soroban_sdk::testutils::Address as TestAddress;
     use soroban_sdk::{contract, Env};
 
@@ -308,9 +516,59 @@ mod tests {
             avg_return,
             total_volume: 0,
             follower_count: 0,
+            attribution_mode: AttributionMode::AverageExecution,
+            tip_count: 0,
+            equity: 0,
+            peak_equity: 0,
+            max_drawdown_bps: 0,
+            current_streak: 0,
+            best_streak: 0,
+            sortino_x100: 0,
+            profit_factor_x100: 0,
         }
     }
 
+    fn podium_events(env: &Env) -> usize {
+        use soroban_sdk::testutils::Events as _;
+        use soroban_sdk::{Symbol, TryFromVal};
+
+        env.events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| {
+                topics.get(0).and_then(|t| Symbol::try_from_val(env, &t).ok())
+                    == Some(symbol_short!("lb_top3"))
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_podium_change_announced() {
+        let env = Env::default();
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let p1 = Address::generate(&env);
+            let p2 = Address::generate(&env);
+            let p3 = Address::generate(&env);
+            update_leaderboard_index(&env, p1, &make_stats(9000, 30, 300, 5, 5));
+            update_leaderboard_index(&env, p2, &make_stats(8000, 20, 200, 5, 5));
+            update_leaderboard_index(&env, p3.clone(), &make_stats(7000, 10, 100, 5, 5));
+            let before = podium_events(&env);
+            assert!(before > 0);
+
+            // p3 takes the success-rate lead; other metrics keep their order
+            update_leaderboard_index(&env, p3.clone(), &make_stats(9500, 10, 100, 5, 5));
+            assert_eq!(podium_events(&env), before + 1);
+            let lb = get_provider_leaderboard(&env, ProviderMetric::BySuccessRate, 3);
+            assert_eq!(lb.get(0).unwrap().provider, p3);
+
+            // Fourth place in every metric: the podiums are unchanged
+            let p4 = Address::generate(&env);
+            update_leaderboard_index(&env, p4, &make_stats(1000, 1, -100, 5, 5));
+            assert_eq!(podium_events(&env), before + 1);
+        });
+    }
+
     #[test]
     fn test_zero_adoption_excluded_from_leaderboard() {
         let env = Env::default();
@@ -342,6 +600,7 @@ mod tests {
     #[test]
     fn test_30_providers_top_10_by_each_metric() {
         let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
         let cid = env.register(TestContract, ());
 
         env.as_contract(&cid, || {
@@ -461,6 +720,148 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_sharpe_ranks_consistency_over_hit_rate() {
+        let env = Env::default();
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let lottery = Address::generate(&env);
+            let steady = Address::generate(&env);
+            for i in 0..10 {
+                risk_metrics::record_roi(&env, &lottery, if i < 8 { 50 } else { -2_000 });
+                risk_metrics::record_roi(&env, &steady, if i % 2 == 0 { 200 } else { 400 });
+            }
+            update_leaderboard_index(&env, lottery.clone(), &make_stats(8000, 1, -360, 8, 2));
+            update_leaderboard_index(&env, steady.clone(), &make_stats(10_000, 1, 300, 10, 0));
+
+            let sharpe = get_leaderboard(&env, LeaderboardMetric::Sharpe, 10);
+            assert_eq!(sharpe.get(0).unwrap().provider, steady);
+            assert_eq!(sharpe.get(0).unwrap().metric_value, 300);
+            let avg = get_leaderboard(&env, LeaderboardMetric::AvgRoi, 10);
+            assert_eq!(avg.get(1).unwrap().provider, lottery);
+            assert_eq!(avg.get(1).unwrap().metric_value, -360);
+        });
+    }
+
+    #[test]
+    fn test_follower_ranking_maintained_on_update() {
+        let env = Env::default();
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let a = Address::generate(&env);
+            let b = Address::generate(&env);
+            let mut stats_a = make_stats(5000, 1, 0, 1, 1);
+            let mut stats_b = make_stats(5000, 1, 0, 1, 1);
+            stats_a.follower_count = 3;
+            stats_b.follower_count = 5;
+            update_leaderboard_index(&env, a.clone(), &stats_a);
+            update_leaderboard_index(&env, b.clone(), &stats_b);
+
+            let lb = get_leaderboard(&env, LeaderboardMetric::Followers, 10);
+            assert_eq!(lb.get(0).unwrap().provider, b);
+            assert_eq!(lb.get(1).unwrap().rank, 2);
+
+            // Losing every follower drops the provider from the ranking.
+            stats_b.follower_count = 0;
+            update_leaderboard_index(&env, b.clone(), &stats_b);
+            let lb = get_leaderboard(&env, LeaderboardMetric::Followers, 10);
+            assert_eq!(lb.len(), 1);
+            assert_eq!(lb.get(0).unwrap().provider, a);
+            assert_eq!(get_top_providers(&env, 10).len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_pages_and_provider_rank() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let mut providers = Vec::new(&env);
+            // 60 qualifying providers; provider i has success rate 100 * (i + 1).
+            for i in 0..60u32 {
+                let p = Address::generate(&env);
+                let stats = make_stats((i + 1) * 100, 1, 0, 6, 5);
+                update_leaderboard_index(&env, p.clone(), &stats);
+                providers.push_back(p);
+            }
+
+            let first = get_leaderboard_page(&env, LeaderboardMetric::SuccessRate, 0, 50);
+            let second = get_leaderboard_page(&env, LeaderboardMetric::SuccessRate, 50, 50);
+            assert_eq!(first.len(), 50);
+            assert_eq!(second.len(), 10);
+            assert_eq!(second.get(0).unwrap().rank, 51);
+            assert_eq!(second.get(9).unwrap().metric_value, 100);
+            assert_eq!(
+                get_leaderboard_page(&env, LeaderboardMetric::SuccessRate, 60, 50).len(),
+                0
+            );
+
+            // Provider 57 (rate 5800) is third, with two neighbours above
+            // and two below.
+            let metric = LeaderboardMetric::SuccessRate;
+            let rank = get_provider_rank(&env, &providers.get(57).unwrap(), metric).unwrap();
+            assert_eq!(rank.entry.rank, 3);
+            assert_eq!(rank.board_size, 60);
+            assert_eq!(rank.surrounding.len(), 5);
+            assert_eq!(rank.surrounding.get(0).unwrap().rank, 1);
+
+            // Last place only has neighbours above.
+            let last = get_provider_rank(&env, &providers.get(0).unwrap(), metric).unwrap();
+            assert_eq!(last.entry.rank, 60);
+            assert_eq!(last.surrounding.len(), 3);
+
+            let stranger = Address::generate(&env);
+            assert!(get_provider_rank(&env, &stranger, metric).is_none());
+        });
+    }
+
+    #[test]
+    fn test_admin_qualification_rules_applied() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let cid = env.register(TestContract, ());
+        let admin_addr = Address::generate(&env);
+        let rules = admin::LeaderboardQualification {
+            min_closed_signals: 3,
+            min_adopters: 1,
+            min_volume: 1_000,
+            min_age_secs: 0,
+        };
+        env.as_contract(&cid, || {
+            admin::init_admin(&env, admin_addr.clone()).unwrap();
+            assert_eq!(
+                admin::set_leaderboard_qualification(
+                    &env,
+                    &admin_addr,
+                    admin::LeaderboardQualification {
+                        min_closed_signals: 0,
+                        ..rules.clone()
+                    },
+                ),
+                Err(crate::errors::AdminError::InvalidParameter)
+            );
+        });
+        // A fresh frame, since the admin already authorized once in the last one.
+        env.as_contract(&cid, || {
+            admin::set_leaderboard_qualification(&env, &admin_addr, rules).unwrap();
+        });
+        env.as_contract(&cid, || {
+            // 5 closed signals now qualify, but only with enough volume.
+            let p = Address::generate(&env);
+            let mut stats = make_stats(6000, 2, 10, 3, 2);
+            update_leaderboard_index(&env, p.clone(), &stats);
+            let lb = get_provider_leaderboard(&env, ProviderMetric::BySuccessRate, 10);
+            assert_eq!(lb.len(), 0);
+
+            stats.total_volume = 5_000;
+            update_leaderboard_index(&env, p.clone(), &stats);
+            let lb = get_provider_leaderboard(&env, ProviderMetric::BySuccessRate, 10);
+            assert_eq!(lb.len(), 1);
+            assert_eq!(lb.get(0).unwrap().provider, p);
+        });
+    }
+
     #[test]
     fn test_legacy_get_leaderboard_wrapper() {
         let env = Env::default();
@@ -468,10 +869,9 @@ mod tests {
         env.as_contract(&cid, || {
             let p = Address::generate(&env);
             update_leaderboard_index(&env, p, &make_stats(7500, 15, 80, 6, 5));
-            let empty_map = soroban_sdk::Map::new(&env);
-            let lb = get_leaderboard(&env, &empty_map, LeaderboardMetric::SuccessRate, 10);
+            let lb = get_leaderboard(&env, LeaderboardMetric::SuccessRate, 10);
             assert_eq!(lb.len(), 1);
-            let lb_f = get_leaderboard(&env, &empty_map, LeaderboardMetric::Followers, 10);
+            let lb_f = get_leaderboard(&env, LeaderboardMetric::Followers, 10);
             assert_eq!(lb_f.len(), 0);
         });
     }
@@ -0,0 +1,219 @@
+//! Rolling-window leaderboards ("hot this week").
+//!
+//! Every signal close is added to a per-provider bucket for the UTC day it
+//! closed on, and the day keeps a list of providers with a bucket. A windowed
+//! board sums the last `window_days` buckets at query time, so its cost
+//! grows with the window (at most MAX_WINDOW_DAYS) and the providers active
+//! in it, not with the whole provider base. Buckets live in temporary
+//! storage and lapse once they are older than any window can reach.
+
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+
+use crate::leaderboard::{LeaderboardMetric, ProviderLeaderboardEntry, MAX_LEADERBOARD_LIMIT};
+use crate::stake;
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+pub const MAX_WINDOW_DAYS: u32 = 30;
+/// Closed signals needed inside the window to be ranked by success rate.
+pub const MIN_WINDOW_CLOSED_SIGNALS: u32 = 3;
+/// Providers tracked per day; closes beyond this on a busy day aren't ranked.
+pub const MAX_PROVIDERS_PER_DAY: u32 = 200;
+/// Buckets outlive the longest window by a day (~5s ledgers).
+const BUCKET_TTL_LEDGERS: u32 = 17_280 * (MAX_WINDOW_DAYS + 1);
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayBucket {
+    pub successful: u32,
+    pub failed: u32,
+    pub roi_sum: i128,
+    pub volume: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum WindowKey {
+    /// (provider, day number since epoch)
+    Bucket(Address, u64),
+    DayProviders(u64),
+}
+
+fn today(env: &Env) -> u64 {
+    env.ledger().timestamp() / SECONDS_PER_DAY
+}
+
+fn get_bucket(env: &Env, provider: &Address, day: u64) -> Option<DayBucket> {
+    env.storage()
+        .temporary()
+        .get(&WindowKey::Bucket(provider.clone(), day))
+}
+
+fn get_day_providers(env: &Env, day: u64) -> Vec<Address> {
+    env.storage()
+        .temporary()
+        .get(&WindowKey::DayProviders(day))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add a closed signal to today's bucket for `provider`.
+pub fn record_close(env: &Env, provider: &Address, successful: bool, roi: i128, volume: i128) {
+    let day = today(env);
+    let bucket_key = WindowKey::Bucket(provider.clone(), day);
+    let mut bucket = match get_bucket(env, provider, day) {
+        Some(bucket) => bucket,
+        None => {
+            let mut providers = get_day_providers(env, day);
+            if providers.len() >= MAX_PROVIDERS_PER_DAY {
+                return;
+            }
+            providers.push_back(provider.clone());
+            let day_key = WindowKey::DayProviders(day);
+            env.storage().temporary().set(&day_key, &providers);
+            env.storage()
+                .temporary()
+                .extend_ttl(&day_key, BUCKET_TTL_LEDGERS, BUCKET_TTL_LEDGERS);
+            DayBucket::default()
+        }
+    };
+
+    if successful {
+        bucket.successful += 1;
+    } else {
+        bucket.failed += 1;
+    }
+    bucket.roi_sum = bucket.roi_sum.saturating_add(roi);
+    bucket.volume = bucket.volume.saturating_add(volume);
+    env.storage().temporary().set(&bucket_key, &bucket);
+    env.storage()
+        .temporary()
+        .extend_ttl(&bucket_key, BUCKET_TTL_LEDGERS, BUCKET_TTL_LEDGERS);
+}
+
+/// Sum of each active provider's buckets over the last `window_days` days.
+fn window_totals(env: &Env, window_days: u32) -> Map<Address, DayBucket> {
+    let today = today(env);
+    let mut totals: Map<Address, DayBucket> = Map::new(env);
+    for offset in 0..window_days as u64 {
+        let Some(day) = today.checked_sub(offset) else {
+            break;
+        };
+        for provider in get_day_providers(env, day).iter() {
+            if let Some(bucket) = get_bucket(env, &provider, day) {
+                let mut total = totals.get(provider.clone()).unwrap_or_default();
+                total.successful += bucket.successful;
+                total.failed += bucket.failed;
+                total.roi_sum = total.roi_sum.saturating_add(bucket.roi_sum);
+                total.volume = total.volume.saturating_add(bucket.volume);
+                totals.set(provider, total);
+            }
+        }
+    }
+    totals
+}
+
+/// Rank providers on their last `window_days` days (capped at
+/// MAX_WINDOW_DAYS). SuccessRate needs MIN_WINDOW_CLOSED_SIGNALS closes in
+/// the window; Volume ranks traded volume. Followers and tips are not
+/// windowed, so those metrics return an empty board.
+pub fn get_leaderboard_windowed(
+    env: &Env,
+    metric: LeaderboardMetric,
+    window_days: u32,
+    limit: u32,
+) -> Vec<ProviderLeaderboardEntry> {
+    let limit = limit.min(MAX_LEADERBOARD_LIMIT);
+    let mut board: Vec<ProviderLeaderboardEntry> = Vec::new(env);
+    if window_days == 0 || limit == 0 {
+        return board;
+    }
+
+    for (provider, total) in window_totals(env, window_days.min(MAX_WINDOW_DAYS)).iter() {
+        let closed = total.successful + total.failed;
+        let metric_value = match metric {
+            LeaderboardMetric::SuccessRate if closed >= MIN_WINDOW_CLOSED_SIGNALS => {
+                (total.successful as i128) * 10_000 / closed as i128
+            }
+            LeaderboardMetric::Volume if total.volume > 0 => total.volume,
+            _ => continue,
+        };
+
+        let mut at = board.len();
+        for i in 0..board.len() {
+            if board.get(i).unwrap().metric_value < metric_value {
+                at = i;
+                break;
+            }
+        }
+        if at >= limit {
+            continue;
+        }
+        let verified = stake::get_stake_info(env, &provider)
+            .map(|info| info.amount >= stake::DEFAULT_MINIMUM_STAKE)
+            .unwrap_or(false);
+        board.insert(
+            at,
+            ProviderLeaderboardEntry {
+                rank: 0,
+                provider,
+                metric_value,
+                total_signals: closed,
+                verified,
+            },
+        );
+        if board.len() > limit {
+            board.pop_back();
+        }
+    }
+
+    for i in 0..board.len() {
+        let mut entry = board.get(i).unwrap();
+        entry.rank = i + 1;
+        board.set(i, entry);
+    }
+    board
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn window_only_counts_recent_days() {
+        let env = Env::default();
+        env.ledger().with_mut(|l| l.timestamp = 100 * SECONDS_PER_DAY);
+        let cid = env.register(TestContract, ());
+        let veteran = Address::generate(&env);
+        let newcomer = Address::generate(&env);
+
+        env.as_contract(&cid, || {
+            for _ in 0..5 {
+                record_close(&env, &veteran, true, 500, 1_000);
+            }
+            env.ledger().with_mut(|l| l.timestamp += 10 * SECONDS_PER_DAY);
+            record_close(&env, &veteran, false, -200, 1_000);
+            for _ in 0..3 {
+                record_close(&env, &newcomer, true, 300, 1_000);
+            }
+
+            let week = get_leaderboard_windowed(&env, LeaderboardMetric::SuccessRate, 7, 10);
+            assert_eq!(week.len(), 1);
+            assert_eq!(week.get(0).unwrap().provider, newcomer);
+            assert_eq!(week.get(0).unwrap().metric_value, 10_000);
+
+            let month = get_leaderboard_windowed(&env, LeaderboardMetric::SuccessRate, 30, 10);
+            assert_eq!(month.len(), 2);
+            assert_eq!(month.get(1).unwrap().provider, veteran);
+            assert_eq!(month.get(1).unwrap().metric_value, 8_333);
+
+            let volume = get_leaderboard_windowed(&env, LeaderboardMetric::Volume, 30, 1);
+            assert_eq!(volume.len(), 1);
+            assert_eq!(volume.get(0).unwrap().provider, veteran);
+            assert_eq!(volume.get(0).unwrap().metric_value, 6_000);
+        });
+    }
+}
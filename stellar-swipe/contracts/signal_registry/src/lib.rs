@@ -2,44 +2,71 @@
 
 mod admin;
 mod analytics;
+mod attribution;
 mod categories;
 mod collaboration;
 mod combos;
 mod contests;
+mod copier_sharing;
+mod copy_config;
+mod creation_fee;
 mod cross_chain;
 mod errors;
 mod events;
+mod exec_verification;
+mod export;
 mod expiry;
 mod fees;
 mod import;
 mod leaderboard;
+mod leaderboard_window;
 mod ml_scoring;
+mod moratorium;
+mod pair_stats;
 mod performance;
+mod premium;
+mod providers;
 mod query;
+mod ratings;
+mod referrals;
+mod registration;
 pub mod reputation;
 mod reports;
+mod risk_metrics;
+mod saved_templates;
 mod scheduling;
 mod scoring;
+mod settlement;
 mod social;
+mod signal_index;
 mod stake;
+mod staking_rewards;
 mod storage_monitor;
 mod submission;
+mod subscriptions;
 mod templates;
+mod tiers;
+mod tipping;
+mod trusted_executors;
 mod test_reputation;
 mod types;
 mod migration;
 mod validation;
 mod versioning;
 
+pub use copier_sharing::{CopierStats, ProviderCopierStats};
+pub use creation_fee::CreationFeeConfig;
+pub use export::{ExportMetadata, ExportWithMetadata};
+pub use moratorium::MoratoriumConfig;
 pub use categories::{RiskLevel, SignalCategory};
 pub use types::SignalAction;
-pub use types::{FeeBreakdown, ProviderPerformance, SignalOutcome, SignalStatus};
+pub use types::{FeeBreakdown, FeeBreakdownV1, ProviderPerformance, SignalOutcome, SignalStatus};
 
 use admin::{
     get_admin, get_admin_config, init_admin, is_trading_paused,
     require_not_paused_legacy as require_not_paused, AdminConfig,
 };
-use stellar_swipe_common::emergency::PauseState;
+use stellar_swipe_common::emergency::{PauseState, CAT_SIGNALS, CAT_TRADING};
 use stellar_swipe_common::rate_limit::{self as rl, ActionType as RLAction, RateLimitConfig};
 use stellar_swipe_common::SECONDS_PER_30_DAY_MONTH;
 
@@ -48,10 +75,11 @@ use combos::{
     get_combo_performance, ComboExecution, ComboPerformanceSummary, ComboSignal, ComboType,
     ComponentExecution, ComponentSignal,
 };
-use contests::{Contest, ContestEntry, ContestMetric, ContestStatus};
+use contests::{Contest, ContestEntry, ContestMetric};
 use errors::{
-    AdminError, AiScoreError, ComboError, ContestError, CrossChainError, SignalEditError,
-    SignalOutcomeError, TemplateError, VersioningError,
+    AdminError, ComboError, ContestError, CopyConfigError, CrossChainError, ExportError,
+    PremiumError, RatingError, ReferralError, RegistrationError, SettlementError, SignalEditError, SubscriptionError,
+    TippingError, SignalOutcomeError, TemplateError, VersioningError,
 };
 pub use leaderboard::{
     get_leaderboard as get_leaderboard_internal, update_leaderboard_index, LeaderboardMetric,
@@ -59,23 +87,33 @@ pub use leaderboard::{
 };
 pub use ml_scoring::{MLModel, SignalFeatures, SignalScore};
 use reputation::{
-    calculate_trust_score, get_trust_score, update_median_values, update_trust_score,
+    calculate_trust_score, update_median_values,
     TrustScoreDetails, TrustScoreTier,
 };
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, Env, Map, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, token, Address, Bytes, BytesN, Env, IntoVal, Map, String,
+    Symbol, Val, Vec,
+};
 use stellar_swipe_common::{health_uninitialized, placeholder_admin, HealthStatus};
-use stellar_swipe_common::{validate_asset_pair as validate_asset_pair_common, AssetPairError};
-pub use templates::{SignalTemplate, SignalTemplateOverrides, StoredSignalTemplate};
-use templates::{SignalTemplate, DEFAULT_TEMPLATE_EXPIRY_HOURS};
+use stellar_swipe_common::{
+    validate_asset_pair as validate_asset_pair_common, AssetPair, AssetPairError,
+};
+pub use saved_templates::{SavedSignalTemplate, SignalTemplateOverrides, StoredSignalTemplate};
+pub use templates::SignalTemplate;
+use templates::DEFAULT_TEMPLATE_EXPIRY_HOURS;
 use types::{
-    AddressMapping, Asset, CrossChainSignal, FeeBreakdown, ImportResultView, ProviderMonthlyReport,
-    ProviderPerformance, RecurrencePattern, Signal, SignalData, SignalEditInput, SignalOutcome,
-    SignalPerformanceView, SignalStatus, SignalSummary, SortOption, SyncStatus, TradeExecution,
+    AddressMapping, Asset, CrossChainSignal, ImportResultView,
+    RecurrencePattern, Signal, SignalData, SignalEditInput, SignalPerformanceView, SignalSummary,
+    SortOption, SyncStatus, TradeExecution,
 };
 use versioning::{CopyRecord, SignalVersion};
 
 const MAX_EXPIRY_SECONDS: u64 = SECONDS_PER_30_DAY_MONTH;
 const WARNING_WINDOW_LEDGERS: u64 = 720;
+/// Average ledger close time, for converting time to expiry into ledgers.
+const LEDGER_CLOSE_SECS: u64 = 5;
+/// Upper bound on signals returned by one page of a signal query.
+pub const MAX_SIGNALS_PAGE: u32 = 50;
 
 #[contract]
 pub struct SignalRegistry;
@@ -91,6 +129,12 @@ pub enum StorageKey {
     MigrationCursor,
     /// Snapshot count of v1 keys at migration start (for `MigrationProgress.total_count`).
     MigrationV1TargetTotal,
+    /// v2 signals moved out of [`StorageKey::Signals`] by the v2→v3 migration, awaiting conversion.
+    SignalsV2,
+    /// Next signal id to scan for v2→v3 migration.
+    MigrationV2Cursor,
+    /// Snapshot count of staged v2 records (for `MigrationProgress.total_count`).
+    MigrationV2TargetTotal,
     ProviderStats,
     /// Per-provider stake balances for trust and submission gates.
     ProviderStakes,
@@ -137,7 +181,10 @@ impl SignalRegistry {
     /// # Errors
     /// - [`AdminError::AlreadyInitialized`] if the contract has already been initialized.
     pub fn initialize(env: Env, admin: Address) -> Result<(), AdminError> {
-        init_admin(&env, admin)
+        init_admin(&env, admin)?;
+        // Nothing is staked yet, so staking rewards have nothing to migrate.
+        staking_rewards::mark_migrated(&env);
+        Ok(())
     }
 
     /// Register the TradeExecutor contract address (admin only). Required before `increment_adoption`.
@@ -166,6 +213,92 @@ impl SignalRegistry {
         migration::migrate_signals_v1_to_v2(&env, &caller, batch_size)
     }
 
+    /// Admin: migrate batched v2 signal records (string pair, no targets or
+    /// premium flag) into the current layout. The first call moves every v2
+    /// record out of [`StorageKey::Signals`]; later calls convert them back in
+    /// by id. Idempotent; safe to call until no v2 rows remain.
+    pub fn migrate_signals_v2_to_v3(
+        env: Env,
+        caller: Address,
+        batch_size: u32,
+    ) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        migration::migrate_signals_v2_to_v3(&env, batch_size)
+    }
+
+    /// Admin: rewrite provider stats stored before the attribution, tip,
+    /// equity, streak and risk-ratio fields existed. Idempotent.
+    pub fn migrate_provider_stats_v1_to_v2(
+        env: Env,
+        caller: Address,
+    ) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        migration::migrate_provider_stats_v1_to_v2(&env);
+        Ok(())
+    }
+
+    /// Admin: count stake placed before staking rewards existed towards the
+    /// reward total and start its rewards from now. Trade fees fund no
+    /// staking rewards until this has run on an upgraded contract.
+    /// Idempotent; returns the total stake.
+    pub fn migrate_staking_rewards(env: Env, caller: Address) -> Result<i128, AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        Ok(staking_rewards::migrate_existing_stakes(&env))
+    }
+
+    /// Admin: add up to `limit` existing signals, starting at id `cursor`
+    /// (0 = resume where the last call stopped), to the provider, pair and
+    /// status indexes. Idempotent; call until `done` is returned.
+    pub fn backfill_indexes(
+        env: Env,
+        caller: Address,
+        cursor: u64,
+        limit: u32,
+    ) -> Result<signal_index::BackfillProgress, AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        if limit == 0 || limit > signal_index::MAX_BACKFILL_BATCH {
+            return Err(AdminError::InvalidParameter);
+        }
+
+        let total = Self::get_signal_count(env.clone());
+        let signals = Self::get_signals_map(&env);
+        let progress = signal_index::backfill(&env, &signals, total, cursor, limit);
+        events::emit_index_backfill_progress(&env, progress.clone());
+        Ok(progress)
+    }
+
+    pub fn get_backfill_cursor(env: Env) -> u64 {
+        signal_index::get_backfill_cursor(&env)
+    }
+
+    /// Admin: mark the executors of up to `limit` trades recorded before the
+    /// executor index existed, starting at trade id `cursor` (ids start at
+    /// 1), so they can rate those signals. Idempotent; call until `done`.
+    pub fn backfill_executor_index(
+        env: Env,
+        caller: Address,
+        cursor: u64,
+        limit: u32,
+    ) -> Result<signal_index::BackfillProgress, AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        if limit == 0 || limit > signal_index::MAX_BACKFILL_BATCH {
+            return Err(AdminError::InvalidParameter);
+        }
+
+        let total: u64 = env
+            .storage()
+            .instance()
+            .get(&StorageKey::TradeCounter)
+            .unwrap_or(0);
+        let trades = Self::get_trade_executions_map(&env);
+        Ok(ratings::backfill_executors(&env, &trades, total, cursor, limit))
+    }
+
     /* =========================
        ADMIN FUNCTIONS
     ========================== */
@@ -176,6 +309,19 @@ impl SignalRegistry {
         storage_monitor::check_storage_capacity(&env)
     }
 
+    /// Tracked persistent entries (signal indexes and signal versions) with
+    /// each category's count and minimum remaining TTL.
+    pub fn get_storage_health(env: Env) -> Vec<stellar_swipe_common::CategoryHealth> {
+        storage_monitor::get_storage_health(&env)
+    }
+
+    /// Extend up to `limit` tracked entries of `category` ("sig_index" or
+    /// "sig_vers"), soonest expiring first. Anyone may pay for the extension.
+    /// Returns the number extended.
+    pub fn bump_ttls(env: Env, category: soroban_sdk::Symbol, limit: u32) -> u32 {
+        storage_monitor::bump_ttls(&env, &category, limit)
+    }
+
     /// Admin: archive old expired signals to free instance storage.
     /// Returns the number of signals removed.
     pub fn admin_cleanup_storage(
@@ -188,13 +334,18 @@ impl SignalRegistry {
         Ok(storage_monitor::admin_cleanup_storage(&env, batch_size))
     }
 
+    /// Admin: stake a provider needs for `create_signal` to accept their
+    /// signals. Each accepted signal then locks the provider's stake for
+    /// `UNSTAKE_LOCK_PERIOD`. Zero lifts both the requirement and the lock.
     pub fn set_min_stake(env: Env, caller: Address, new_amount: i128) -> Result<(), AdminError> {
         admin::set_min_stake(&env, &caller, new_amount)
     }
 
-    /// User stakes tokens. Rate-limited to 5 changes per day.
+    /// User stakes tokens, paid in the stake token and held by the contract.
+    /// Rate-limited to 5 changes per day.
     pub fn stake_tokens(env: Env, provider: Address, amount: i128) -> Result<(), AdminError> {
         provider.require_auth();
+        let token = stake::get_stake_token(&env).ok_or(AdminError::StakeTokenNotSet)?;
         let trust = reputation::get_trust_score(&env, &provider)
             .map(|d| d.score)
             .unwrap_or(0);
@@ -202,20 +353,66 @@ impl SignalRegistry {
             .map_err(|_| AdminError::RateLimitExceeded)?;
 
         let mut stakes = Self::get_provider_stakes_map(&env);
-        stake::stake(&env, &mut stakes, &provider, amount).map_err(|e| match e {
-            stake::ContractError::InvalidStakeAmount
-            | stake::ContractError::NoStakeFound
-            | stake::ContractError::StakeLocked
-            | stake::ContractError::InsufficientStake
-            | stake::ContractError::BelowMinimumStake => AdminError::InvalidParameter,
-        })?;
+        let current = stakes.get(provider.clone()).map_or(0, |info| info.amount);
+        staking_rewards::settle(&env, &provider, current);
+        stake::stake(&env, &mut stakes, &provider, amount).map_err(Self::map_stake_error)?;
+        staking_rewards::adjust_total_staked(&env, amount);
+        token::Client::new(&env, &token).transfer(
+            &provider,
+            &env.current_contract_address(),
+            &amount,
+        );
         Self::save_provider_stakes_map(&env, &stakes);
         rl::record_action(&env, &provider, RLAction::StakeChange);
         Ok(())
     }
 
-    /// User unstakes tokens. Rate-limited to 5 changes per day.
-    pub fn unstake_tokens(env: Env, provider: Address) -> Result<(), AdminError> {
+    /// Admin: token stake is paid in and paid out. It can only change
+    /// while the contract holds no stake, pending unstake or insurance pool
+    /// balance; otherwise fails with `InvalidParameter`.
+    pub fn set_stake_token(env: Env, caller: Address, token: Address) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        if stake::get_stake_token(&env).as_ref() != Some(&token)
+            && stake::holds_stake(&env, &Self::get_provider_stakes_map(&env))
+        {
+            return Err(AdminError::InvalidParameter);
+        }
+        stake::set_stake_token(&env, &token);
+        Ok(())
+    }
+
+    pub fn get_stake_token(env: Env) -> Option<Address> {
+        stake::get_stake_token(&env)
+    }
+
+    /// Queue `amount` of the caller's stake for withdrawal. It stays staked
+    /// until claimed with `claim_unstake` after the 7-day lock. While the
+    /// rest would be below the minimum stake, the provider can't create
+    /// signals. Rate-limited to 5 changes per day.
+    pub fn request_unstake(
+        env: Env,
+        provider: Address,
+        amount: i128,
+    ) -> Result<stake::PendingUnstake, AdminError> {
+        provider.require_auth();
+        let trust = reputation::get_trust_score(&env, &provider)
+            .map(|d| d.score)
+            .unwrap_or(0);
+        rl::check_rate_limit(&env, &provider, RLAction::StakeChange, trust)
+            .map_err(|_| AdminError::RateLimitExceeded)?;
+
+        let stakes = Self::get_provider_stakes_map(&env);
+        let pending = stake::request_unstake(&env, &stakes, &provider, amount)
+            .map_err(Self::map_stake_error)?;
+        rl::record_action(&env, &provider, RLAction::StakeChange);
+        events::emit_unstake_requested(&env, provider, pending.amount, pending.available_at);
+        Ok(pending)
+    }
+
+    /// Withdraw the caller's pending unstake once available. Returns the
+    /// amount withdrawn.
+    pub fn claim_unstake(env: Env, provider: Address) -> Result<i128, AdminError> {
         provider.require_auth();
 
         // ── Reentrancy guard ──────────────────────────────────────────────────
@@ -230,34 +427,450 @@ impl SignalRegistry {
         }
         env.storage().temporary().set(&lock_key, &true);
 
-        let trust = reputation::get_trust_score(&env, &provider)
-            .map(|d| d.score)
-            .unwrap_or(0);
-        let result = (|| -> Result<(), AdminError> {
-            rl::check_rate_limit(&env, &provider, RLAction::StakeChange, trust)
-                .map_err(|_| AdminError::RateLimitExceeded)?;
-
+        let result = (|| -> Result<i128, AdminError> {
             let mut stakes = Self::get_provider_stakes_map(&env);
-            let _ = stake::unstake(&env, &mut stakes, &provider).map_err(|e| match e {
-                stake::ContractError::InvalidStakeAmount
-                | stake::ContractError::NoStakeFound
-                | stake::ContractError::StakeLocked
-                | stake::ContractError::InsufficientStake
-                | stake::ContractError::BelowMinimumStake => AdminError::InvalidParameter,
-            })?;
+            let current = stakes.get(provider.clone()).map_or(0, |info| info.amount);
+            staking_rewards::settle(&env, &provider, current);
+            let amount = stake::claim_unstake(&env, &mut stakes, &provider)
+                .map_err(Self::map_stake_error)?;
+            staking_rewards::adjust_total_staked(&env, -amount);
+            let token = stake::get_stake_token(&env).ok_or(AdminError::StakeTokenNotSet)?;
             Self::save_provider_stakes_map(&env, &stakes);
-            rl::record_action(&env, &provider, RLAction::StakeChange);
-            Ok(())
+            token::Client::new(&env, &token).transfer(
+                &env.current_contract_address(),
+                &provider,
+                &amount,
+            );
+            events::emit_unstake_claimed(&env, provider.clone(), amount);
+            Ok(amount)
         })();
 
         env.storage().temporary().remove(&lock_key);
         result
     }
 
+    pub fn get_pending_unstake(env: Env, provider: Address) -> Option<stake::PendingUnstake> {
+        stake::get_pending_unstake(&env, &provider)
+    }
+
+    /// Admin: stake locked behind each new signal and the share of it (BPS)
+    /// slashed into the insurance pool when the signal ends Failed. A zero
+    /// lock turns per-signal locking off.
+    pub fn set_signal_stake_config(
+        env: Env,
+        caller: Address,
+        lock_per_signal: i128,
+        slash_bps: u32,
+    ) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        stake::set_signal_stake_config(
+            &env,
+            &stake::SignalStakeConfig {
+                lock_per_signal,
+                slash_bps,
+            },
+        )
+        .map_err(|_| AdminError::InvalidParameter)
+    }
+
+    pub fn get_signal_stake_config(env: Env) -> stake::SignalStakeConfig {
+        stake::get_signal_stake_config(&env)
+    }
+
+    /// Stake `provider` has locked behind signals that are still open.
+    pub fn get_locked_stake(env: Env, provider: Address) -> i128 {
+        stake::get_locked_stake(&env, &provider)
+    }
+
+    /// Slashed stake collected for insurance payouts.
+    pub fn get_insurance_pool(env: Env) -> i128 {
+        stake::get_insurance_pool(&env)
+    }
+
+    /// Admin: pay `amount` of slashed stake out of the insurance pool to
+    /// `recipient`, in the stake token.
+    pub fn withdraw_insurance(
+        env: Env,
+        caller: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        let token = stake::get_stake_token(&env).ok_or(AdminError::StakeTokenNotSet)?;
+        stake::withdraw_insurance(&env, amount).map_err(Self::map_stake_error)?;
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &amount,
+        );
+        events::emit_insurance_withdrawn(&env, recipient, amount);
+        Ok(())
+    }
+
+    /// Release the stake locked behind a signal that closed without passing
+    /// through settlement (e.g. one orphaned when its provider account was
+    /// deleted).
+    /// Permissionless; a no-op while the signal is open or once its stake
+    /// has been released.
+    pub fn release_signal_stake(env: Env, signal_id: u64) {
+        let signal = match Self::get_signals_map(&env).get(signal_id) {
+            Some(signal) => signal,
+            None => return,
+        };
+        if !matches!(signal.status, SignalStatus::Active | SignalStatus::Pending) {
+            Self::release_signal_stake_for(&env, &signal, &signal.status);
+        }
+    }
+
     pub fn set_trade_fee(env: Env, caller: Address, new_fee_bps: u32) -> Result<(), AdminError> {
         admin::set_trade_fee(&env, &caller, new_fee_bps)
     }
 
+    /// Admin: choose how ROI and PnL basis-point math rounds fractional
+    /// results. Defaults to banker's rounding (`HalfEven`).
+    pub fn set_rounding_mode(
+        env: Env,
+        caller: Address,
+        mode: stellar_swipe_common::RoundingMode,
+    ) -> Result<(), AdminError> {
+        admin::set_rounding_mode(&env, &caller, mode)
+    }
+
+    pub fn get_rounding_mode(env: Env) -> stellar_swipe_common::RoundingMode {
+        admin::get_rounding_mode(&env)
+    }
+
+    /// Admin: choose how signal success is judged (average, first execution,
+    /// volume-weighted, or oracle price `horizon_secs` after publication).
+    pub fn set_attribution_mode(
+        env: Env,
+        caller: Address,
+        mode: types::AttributionMode,
+        horizon_secs: u64,
+    ) -> Result<(), AdminError> {
+        admin::set_attribution_mode(&env, &caller, mode, horizon_secs)
+    }
+
+    pub fn get_attribution_mode(env: Env) -> types::AttributionMode {
+        admin::get_attribution_mode(&env)
+    }
+
+    pub fn get_attribution_horizon(env: Env) -> u64 {
+        admin::get_attribution_horizon(&env)
+    }
+
+    /// Admin: post the oracle price observed at a signal's attribution horizon
+    /// and settle the signal on it. Only valid in `OracleHorizon` mode, once per
+    /// signal and after the horizon has passed.
+    pub fn record_horizon_price(
+        env: Env,
+        caller: Address,
+        signal_id: u64,
+        price: i128,
+    ) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+
+        let mode = admin::get_attribution_mode(&env);
+        if mode != types::AttributionMode::OracleHorizon || price <= 0 {
+            return Err(AdminError::InvalidParameter);
+        }
+
+        let mut signals = Self::get_signals_map(&env);
+        let mut signal = signals.get(signal_id).ok_or(AdminError::InvalidParameter)?;
+        let now = env.ledger().timestamp();
+        if now < signal.timestamp.saturating_add(admin::get_attribution_horizon(&env)) {
+            return Err(AdminError::InvalidTimestamp);
+        }
+        if attribution::get_attribution(&env, signal_id).horizon_price.is_some() {
+            return Err(AdminError::InvalidParameter);
+        }
+        attribution::record_horizon_price(&env, signal_id, price);
+
+        let rounding = admin::get_rounding_mode(&env);
+        let attributed_roi = attribution::attributed_roi(&env, &signal, mode, rounding);
+        let old_status = signal.status.clone();
+        let new_status = performance::evaluate_signal_status_for_roi(&signal, now, attributed_roi);
+        signal.status = new_status.clone();
+        signals.set(signal_id, signal.clone());
+        Self::save_signals_map(&env, &signals);
+        signal_index::move_status(&env, signal_id, &old_status, &new_status);
+
+        Self::apply_status_change(&env, &signal, old_status, new_status, attributed_roi, mode);
+        Ok(())
+    }
+
+    /// Admin: oracle contract `settle_signal` reads prices from.
+    pub fn set_price_oracle(env: Env, caller: Address, oracle: Address) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        settlement::set_price_oracle(&env, &oracle);
+        Ok(())
+    }
+
+    /// Admin: check executor-reported entry and exit prices against the
+    /// price oracle. Outliers beyond `tolerance_bps` are flagged or rejected
+    /// per `mode`; see `exec_verification`.
+    pub fn set_execution_verification(
+        env: Env,
+        caller: Address,
+        mode: exec_verification::VerificationMode,
+        tolerance_bps: u32,
+    ) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        if tolerance_bps == 0 || tolerance_bps > exec_verification::MAX_TOLERANCE_BPS {
+            return Err(AdminError::InvalidParameter);
+        }
+        let old = exec_verification::get_config(&env);
+        exec_verification::set_config(
+            &env,
+            &exec_verification::VerificationConfig {
+                mode,
+                tolerance_bps,
+            },
+        );
+        events::emit_parameter_updated(
+            &env,
+            Symbol::new(&env, "exec_tolerance_bps"),
+            old.tolerance_bps as i128,
+            tolerance_bps as i128,
+        );
+        Ok(())
+    }
+
+    /// Admin: allow `executor` to record trades while executions are
+    /// restricted. Fails if it is already trusted.
+    pub fn set_trusted_executor(env: Env, caller: Address, executor: Address) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        if !trusted_executors::add(&env, &executor) {
+            return Err(AdminError::InvalidParameter);
+        }
+        events::emit_trusted_executor_added(&env, executor, caller);
+        Ok(())
+    }
+
+    pub fn remove_trusted_executor(
+        env: Env,
+        caller: Address,
+        executor: Address,
+    ) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        if !trusted_executors::remove(&env, &executor) {
+            return Err(AdminError::InvalidParameter);
+        }
+        events::emit_trusted_executor_removed(&env, executor, caller);
+        Ok(())
+    }
+
+    pub fn get_trusted_executors(env: Env) -> Vec<Address> {
+        trusted_executors::get_all(&env)
+    }
+
+    /// Admin: when on, `record_trade_execution` only accepts trusted
+    /// executors. Off (permissionless) by default.
+    pub fn set_executions_restricted(env: Env, caller: Address, restricted: bool) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        trusted_executors::set_restricted(&env, restricted);
+        Ok(())
+    }
+
+    pub fn are_executions_restricted(env: Env) -> bool {
+        trusted_executors::is_restricted(&env)
+    }
+
+    pub fn get_execution_verification(env: Env) -> exec_verification::VerificationConfig {
+        exec_verification::get_config(&env)
+    }
+
+    /// Deviation, in bps, of a trade flagged by execution verification.
+    pub fn get_trade_flag(env: Env, trade_id: u64) -> Option<u32> {
+        exec_verification::get_flag(&env, trade_id)
+    }
+
+    /// Set or clear the stop-loss and take-profit prices of an Active signal.
+    /// Targets are fixed once the signal has been copied.
+    pub fn set_signal_targets(
+        env: Env,
+        provider: Address,
+        signal_id: u64,
+        stop_loss: Option<i128>,
+        take_profit: Option<i128>,
+    ) -> Result<(), SettlementError> {
+        provider.require_auth();
+        let mut signals = Self::get_signals_map(&env);
+        let mut signal = signals
+            .get(signal_id)
+            .ok_or(SettlementError::SignalNotFound)?;
+        if signal.provider != provider {
+            return Err(SettlementError::NotSignalOwner);
+        }
+        if signal.status != SignalStatus::Active {
+            return Err(SettlementError::SignalNotActive);
+        }
+        if signal.adoption_count > 0 {
+            return Err(SettlementError::TargetsLocked);
+        }
+        settlement::validate_targets(&signal, stop_loss, take_profit)?;
+
+        signal.stop_loss = stop_loss;
+        signal.take_profit = take_profit;
+        signals.set(signal_id, signal);
+        Self::save_signals_map(&env, &signals);
+        events::emit_signal_targets_set(&env, signal_id, stop_loss, take_profit);
+        Ok(())
+    }
+
+    /// Settle an Active signal on the oracle price once its stop-loss or
+    /// take-profit is reached or it has expired. Once the signal has expired
+    /// this settles exactly as `settle_expired_signal` does. Permissionless;
+    /// returns the status the signal was closed with.
+    pub fn settle_signal(env: Env, signal_id: u64) -> Result<SignalStatus, SettlementError> {
+        Self::settle_on_oracle(env, signal_id, false)
+    }
+
+    /// Settle an Active signal past its expiry that has no or inconclusive
+    /// executions on the oracle price at expiry: Successful if the price
+    /// moved the signal's way, Failed otherwise. The oracle's current price
+    /// stands in for the expiry price, so it must have been observed no
+    /// earlier than expiry and within `SETTLEMENT_WINDOW` of it.
+    /// Permissionless; returns the status the signal was closed with.
+    pub fn settle_expired_signal(env: Env, signal_id: u64) -> Result<SignalStatus, SettlementError> {
+        Self::settle_on_oracle(env, signal_id, true)
+    }
+
+    fn settle_on_oracle(
+        env: Env,
+        signal_id: u64,
+        expired_only: bool,
+    ) -> Result<SignalStatus, SettlementError> {
+        let mut signals = Self::get_signals_map(&env);
+        let mut signal = signals
+            .get(signal_id)
+            .ok_or(SettlementError::SignalNotFound)?;
+        if signal.status != SignalStatus::Active {
+            return Err(SettlementError::SignalNotActive);
+        }
+        let now = env.ledger().timestamp();
+        let expired = now >= signal.expiry;
+        if expired_only && !expired {
+            return Err(SettlementError::NotExpired);
+        }
+        let mode = admin::get_attribution_mode(&env);
+        if expired && signal.executions > 0 {
+            let attributed_roi =
+                attribution::attributed_roi(&env, &signal, mode, admin::get_rounding_mode(&env));
+            if performance::evaluate_signal_status_for_roi(&signal, now, attributed_roi)
+                != SignalStatus::Active
+            {
+                return Err(SettlementError::ExecutionsConclusive);
+            }
+        }
+
+        let (price, observed_at) = settlement::oracle_observation(&env, &signal.asset_pair)?;
+        if expired {
+            if observed_at < signal.expiry {
+                return Err(SettlementError::PriceUnavailable);
+            }
+            if observed_at > signal.expiry.saturating_add(settlement::SETTLEMENT_WINDOW) {
+                return Err(SettlementError::SettlementWindowClosed);
+            }
+        }
+        let new_status =
+            settlement::outcome(&signal, price, now).ok_or(SettlementError::NotTriggered)?;
+        let roi = performance::calculate_roi(
+            signal.price,
+            price,
+            &signal.action,
+            admin::get_rounding_mode(&env),
+        );
+
+        let old_status = signal.status.clone();
+        signal.status = new_status.clone();
+        signals.set(signal_id, signal.clone());
+        Self::save_signals_map(&env, &signals);
+        signal_index::move_status(&env, signal_id, &old_status, &new_status);
+
+        Self::apply_status_change(&env, &signal, old_status, new_status.clone(), Some(roi), mode);
+        events::emit_signal_settled(&env, signal_id, price, new_status.clone() as u32);
+        Ok(new_status)
+    }
+
+    /// Admin: set the max concurrently Active signals per provider for the
+    /// bronze, silver, and gold stake tiers. `create_signal` rejects signals
+    /// beyond the provider's tier limit with `TooManyActiveSignals`.
+    pub fn set_tier_signal_limits(
+        env: Env,
+        caller: Address,
+        bronze: u32,
+        silver: u32,
+        gold: u32,
+    ) -> Result<(), AdminError> {
+        admin::set_tier_signal_limits(&env, &caller, bronze, silver, gold)
+    }
+
+    /// Admin: minimum seconds between two signals from one provider (0 =
+    /// no cooldown). `create_signal` rejects signals inside it with
+    /// `SignalCooldownActive`.
+    pub fn set_signal_cooldown(env: Env, caller: Address, cooldown_secs: u64) -> Result<(), AdminError> {
+        admin::set_signal_cooldown(&env, &caller, cooldown_secs)
+    }
+
+    pub fn get_signal_cooldown(env: Env) -> u64 {
+        admin::get_signal_cooldown(&env)
+    }
+
+    /// Admin: set what a provider needs to appear on the leaderboards.
+    pub fn set_leaderboard_qualification(
+        env: Env,
+        caller: Address,
+        rules: admin::LeaderboardQualification,
+    ) -> Result<(), AdminError> {
+        admin::set_leaderboard_qualification(&env, &caller, rules)
+    }
+
+    pub fn get_leaderboard_qualification(env: Env) -> admin::LeaderboardQualification {
+        admin::get_leaderboard_qualification(&env)
+    }
+
+    /// Register, or update, the caller's display name and bio URI.
+    pub fn register_provider(
+        env: Env,
+        provider: Address,
+        display_name: String,
+        bio_uri: String,
+    ) -> Result<registration::ProviderRegistration, RegistrationError> {
+        provider.require_auth();
+        let registration = registration::register(&env, &provider, display_name, bio_uri)?;
+        events::emit_provider_registered(&env, provider, registration.display_name.clone());
+        Ok(registration)
+    }
+
+    pub fn get_provider_registration(
+        env: Env,
+        provider: Address,
+    ) -> Option<registration::ProviderRegistration> {
+        registration::get_registration(&env, &provider)
+    }
+
+    /// Admin: whether `create_signal` only accepts registered providers.
+    pub fn set_registration_required(env: Env, caller: Address, required: bool) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        registration::set_required(&env, required);
+        Ok(())
+    }
+
+    /// Number of currently Active signals owned by `provider`.
+    pub fn get_active_signal_count(env: Env, provider: Address) -> u32 {
+        validation::count_active_provider_signals(&env, &Self::get_signals_map(&env), &provider)
+    }
+
     pub fn set_risk_defaults(
         env: Env,
         caller: Address,
@@ -587,26 +1200,51 @@ impl SignalRegistry {
     }
 
     fn validate_asset_pair(env: &Env, asset_pair: &String) -> Result<(), AdminError> {
-        validate_asset_pair_common(env, asset_pair).map_err(|e| match e {
+        validate_asset_pair_common(env, asset_pair).map_err(Self::map_asset_pair_error)
+    }
+
+    /// Parse a pair string into the shared pair type, so stored signals
+    /// match on pair regardless of the case the provider submitted.
+    fn parse_asset_pair(env: &Env, asset_pair: &String) -> Result<AssetPair, AdminError> {
+        AssetPair::parse(env, asset_pair).map_err(Self::map_asset_pair_error)
+    }
+
+    fn map_asset_pair_error(e: AssetPairError) -> AdminError {
+        match e {
             AssetPairError::InvalidFormat
             | AssetPairError::InvalidAssetCode
             | AssetPairError::InvalidIssuer
             | AssetPairError::SameAssets => AdminError::InvalidAssetPair,
-        })
+        }
+    }
+
+    fn map_stake_error(e: stake::ContractError) -> AdminError {
+        match e {
+            stake::ContractError::InvalidStakeAmount => AdminError::InvalidStakeAmount,
+            stake::ContractError::NoStakeFound => AdminError::NoStake,
+            stake::ContractError::StakeLocked => AdminError::StakeLocked,
+            stake::ContractError::InsufficientStake => AdminError::InsufficientStake,
+            stake::ContractError::BelowMinimumStake => AdminError::StakeBelowMinimum,
+            stake::ContractError::NoPendingUnstake => AdminError::NoPendingUnstake,
+        }
     }
 
     /// Returns `true` if the Stellar account for `provider` still exists on-chain.
     /// A merged (deleted) account returns `false`.
-    fn check_provider_exists(env: &Env, provider: &Address) -> bool {
-        env.accounts().get(provider).is_some()
+    /// Contract providers can't be merged away, so they always count as present.
+    fn check_provider_exists(_env: &Env, provider: &Address) -> bool {
+        let is_account = provider.to_string().to_bytes().get(0) == Some(b'G');
+        !is_account || provider.exists()
     }
 
     /// Mark a signal as orphaned (provider account deleted), emit the event, and persist.
     fn orphan_signal(env: &Env, signals: &mut Map<u64, Signal>, signal_id: u64) {
         if let Some(mut signal) = signals.get(signal_id) {
+            let old_status = signal.status.clone();
             signal.status = SignalStatus::ProviderDeleted;
             signals.set(signal_id, signal);
             Self::save_signals_map(env, signals);
+            signal_index::move_status(env, signal_id, &old_status, &SignalStatus::ProviderDeleted);
             events::emit_signal_orphaned(
                 env,
                 signal_id,
@@ -642,6 +1280,7 @@ impl SignalRegistry {
     /// - [`AdminError::TradingPaused`] — signals category is paused.
     /// - [`AdminError::RateLimitExceeded`] — provider has exceeded submission rate limit.
     /// - [`AdminError::InvalidAssetPair`] — asset_pair format is invalid.
+    /// - [`AdminError::TooManyActiveSignals`] — provider is at their tier's active signal cap.
     /// - Panics if expiry is in the past or exceeds 30 days.
     pub fn create_signal(
         env: Env,
@@ -658,10 +1297,58 @@ impl SignalRegistry {
         provider.require_auth();
         // Analytics: session start on first call by this provider
         shared::events::emit_session_started_once(&env, &provider);
-        Self::create_signal_internal(
-            &env, provider, asset_pair, action, price, rationale, expiry, category, tags,
+        let signal_id = Self::create_signal_internal(
+            &env,
+            provider.clone(),
+            asset_pair,
+            action,
+            price,
+            rationale,
+            expiry,
+            category,
+            tags,
             risk_level,
-        )
+        )?;
+        Self::charge_creation_fee(&env, &provider, signal_id)?;
+        Ok(signal_id)
+    }
+
+    /// Provider's stake tier (0 = none, 3 = gold), from their profile or, if
+    /// they have none, their current stake.
+    fn provider_stake_tier(env: &Env, provider: &Address) -> u32 {
+        providers::get_provider_profile(env, provider)
+            .map(|profile| profile.stake_tier)
+            .unwrap_or_else(|| {
+                let stakes = Self::get_provider_stakes_map(env);
+                let amount = stakes
+                    .get(provider.clone())
+                    .map(|info| info.amount)
+                    .unwrap_or(0);
+                if amount >= providers::GOLD_TIER_STAKE {
+                    3
+                } else if amount >= providers::GOLD_TIER_STAKE / 2 {
+                    2
+                } else if amount >= providers::GOLD_TIER_STAKE / 10 {
+                    1
+                } else {
+                    0
+                }
+            })
+    }
+
+    /// Tier perks are granted at: the better of the provider's stake tier
+    /// and the perk level of their performance tier.
+    fn provider_perk_tier(env: &Env, provider: &Address) -> u32 {
+        Self::provider_stake_tier(env, provider)
+            .max(tiers::perk_level(tiers::get_tier(env, provider)))
+    }
+
+    /// Collect the creation fee for a provider-submitted signal and announce it.
+    fn charge_creation_fee(env: &Env, provider: &Address, signal_id: u64) -> Result<(), AdminError> {
+        let tier = Self::provider_perk_tier(env, provider);
+        let fee = creation_fee::charge(env, provider, tier)?;
+        events::emit_signal_created(env, signal_id, provider.clone(), fee);
+        Ok(())
     }
 
     fn create_signal_internal(
@@ -676,39 +1363,39 @@ impl SignalRegistry {
         tags: Vec<String>,
         risk_level: RiskLevel,
     ) -> Result<u64, AdminError> {
-        // Check if signals are paused
+        // Check if signals are paused, or trading as a whole
         admin::require_not_paused(env, String::from_str(env, CAT_SIGNALS))?;
+        require_not_paused(env)?;
 
         // Issue #424: Banned providers cannot submit signals
         if providers::is_provider_banned(env, &provider) {
             return Err(AdminError::Unauthorized);
         }
 
-        // Verify provider account still exists on Stellar
-        if !Self::check_provider_exists(env, &provider) {
-            return Err(AdminError::Unauthorized);
-        }
+        moratorium::require_no_moratorium(env, &provider)?;
 
-        let provider_stake_tier = providers::get_provider_profile(env, &provider)
-            .map(|profile| profile.stake_tier)
-            .unwrap_or_else(|| {
-                let stakes = Self::get_provider_stakes_map(env);
-                let amount = stakes
-                    .get(provider.clone())
-                    .map(|info| info.amount)
-                    .unwrap_or(0);
-                if amount >= providers::GOLD_TIER_STAKE {
-                    3
-                } else if amount >= providers::GOLD_TIER_STAKE / 2 {
-                    2
-                } else if amount >= providers::GOLD_TIER_STAKE / 10 {
-                    1
-                } else {
-                    0
-                }
-            });
+        if registration::is_required(env) && !registration::is_registered(env, &provider) {
+            return Err(AdminError::ProviderNotRegistered);
+        }
+
+        if stake::unstake_breaches_minimum(
+            env,
+            &Self::get_provider_stakes_map(env),
+            &provider,
+            admin::get_min_stake(env),
+        ) {
+            return Err(AdminError::UnstakePending);
+        }
+
+        // Verify provider account still exists on Stellar
+        if !Self::check_provider_exists(env, &provider) {
+            return Err(AdminError::Unauthorized);
+        }
+
+        let provider_stake_tier = Self::provider_perk_tier(env, &provider);
 
         validation::validate_provider_signal_limit(env, &Self::get_signals_map(env), &provider, provider_stake_tier)?;
+        validation::validate_signal_cooldown(env, &provider)?;
 
         // Rate limit: signal submission
         let trust = reputation::get_trust_score(env, &provider)
@@ -718,7 +1405,7 @@ impl SignalRegistry {
             .map_err(|_| AdminError::RateLimitExceeded)?;
         rl::record_action(env, &provider, RLAction::SignalSubmission);
 
-        Self::validate_asset_pair(env, &asset_pair)?;
+        let asset_pair = Self::parse_asset_pair(env, &asset_pair)?;
 
         // Validate and deduplicate tags
         categories::validate_tags(&tags)?;
@@ -735,6 +1422,30 @@ impl SignalRegistry {
         }
 
         let id = Self::next_signal_id(env);
+        let min_stake = admin::get_min_stake(env);
+        let locked = stake::lock_for_signal(
+            env,
+            &Self::get_provider_stakes_map(env),
+            &provider,
+            id,
+            min_stake,
+        )
+        .map_err(|e| match e {
+            stake::ContractError::NoStakeFound => AdminError::NoStake,
+            _ => AdminError::StakeBelowMinimum,
+        })?;
+        if locked > 0 {
+            events::emit_signal_stake_locked(env, provider.clone(), id, locked);
+        }
+        if min_stake > 0 {
+            // Keep the stake from being withdrawn while the signal is live.
+            let mut stakes = Self::get_provider_stakes_map(env);
+            stake::record_signal(env, &mut stakes, &provider)
+                .map_err(|_| AdminError::NoStake)?;
+            Self::save_provider_stakes_map(env, &stakes);
+            let info = stakes.get(provider.clone()).unwrap();
+            events::emit_provider_stake_locked(env, provider.clone(), info.amount, info.locked_until);
+        }
         let rationale_hash = rationale.clone();
 
         let signal = Signal {
@@ -768,15 +1479,20 @@ impl SignalRegistry {
             warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
         };
 
         // Auto-enter signal into active contests (before moving signal)
         let _ = contests::auto_enter_signal(env, &signal);
 
         // Store signal
+        signal_index::index_signal(env, &signal);
         let mut signals = Self::get_signals_map(env);
         signals.set(id, signal);
         Self::save_signals_map(env, &signals);
+        admin::record_signal_created(env, &provider);
 
         // Update tag popularity
         categories::increment_tag_popularity(env, &unique_tags);
@@ -801,6 +1517,110 @@ impl SignalRegistry {
         Ok(id)
     }
 
+    /// Highest signal id assigned so far.
+    pub fn get_signal_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::SignalCounter)
+            .unwrap_or(0)
+    }
+
+    /// Signals with ids above `cursor`, in id order, premium ones redacted.
+    /// Pass the returned `next_cursor` to fetch the following page. `limit`
+    /// is capped at `MAX_SIGNALS_PAGE`.
+    pub fn get_signals(env: Env, cursor: u64, limit: u32) -> signal_index::SignalPage {
+        let signals = Self::get_signals_map(&env);
+        let total = Self::get_signal_count(env.clone());
+        let limit = limit.min(MAX_SIGNALS_PAGE);
+
+        let mut page = Vec::new(&env);
+        let mut id = cursor;
+        while id < total && page.len() < limit {
+            id += 1;
+            if let Some(signal) = signals.get(id) {
+                page.push_back(premium::redacted(&env, signal));
+            }
+        }
+        signal_index::SignalPage {
+            signals: page,
+            next_cursor: if id < total { Some(id) } else { None },
+        }
+    }
+
+    /// A provider's signals, paginated as in `get_signals`.
+    pub fn get_signals_by_provider(
+        env: Env,
+        provider: Address,
+        cursor: u64,
+        limit: u32,
+    ) -> signal_index::SignalPage {
+        let ids = signal_index::ids_by_provider(&env, &provider);
+        Self::signal_page(&env, &ids, cursor, limit, None)
+    }
+
+    /// Signals currently in `status`, paginated as in `get_signals`.
+    pub fn get_signals_by_status(
+        env: Env,
+        status: SignalStatus,
+        cursor: u64,
+        limit: u32,
+    ) -> signal_index::SignalPage {
+        let ids = signal_index::ids_by_status(&env, &status);
+        Self::signal_page(&env, &ids, cursor, limit, Some(status))
+    }
+
+    /// The `limit` most recent signals on `asset_pair`, newest first, read
+    /// from the pair index, premium ones redacted. `limit` is capped at `MAX_SIGNALS_PAGE`.
+    /// The legacy "XLM-USDC" spelling is accepted as well as "XLM/USDC".
+    pub fn get_signals_by_pair(env: Env, asset_pair: String, limit: u32) -> Vec<Signal> {
+        let mut page = Vec::new(&env);
+        let asset_pair = match migration::v1_pair(&env, &asset_pair) {
+            Ok(pair) => pair,
+            Err(_) => return page,
+        };
+        let ids = signal_index::ids_by_pair(&env, &asset_pair);
+        let signals = Self::get_signals_map(&env);
+        let limit = limit.min(MAX_SIGNALS_PAGE);
+        for id in ids.iter().rev() {
+            if page.len() >= limit {
+                break;
+            }
+            if let Some(signal) = signals.get(id) {
+                page.push_back(premium::redacted(&env, signal));
+            }
+        }
+        page
+    }
+
+    /// Load one page of indexed ids. With `status`, signals whose bucket lags
+    /// their current status are left out.
+    fn signal_page(
+        env: &Env,
+        ids: &Vec<u64>,
+        cursor: u64,
+        limit: u32,
+        status: Option<SignalStatus>,
+    ) -> signal_index::SignalPage {
+        let (page_ids, more) =
+            signal_index::page_ids(env, ids, cursor, limit.min(MAX_SIGNALS_PAGE));
+        let signals_map = Self::get_signals_map(env);
+
+        let mut signals = Vec::new(env);
+        for id in page_ids.iter() {
+            if let Some(signal) = signals_map.get(id) {
+                if status.as_ref().map_or(true, |s| *s == signal.status) {
+                    signals.push_back(premium::redacted(env, signal));
+                }
+            }
+        }
+        signal_index::SignalPage {
+            signals,
+            next_cursor: if more { page_ids.last() } else { None },
+        }
+    }
+
+    /// A signal by id. Premium signals come back redacted; subscribers read
+    /// them in full through `get_signal_for_viewer`.
     pub fn get_signal(env: Env, signal_id: u64) -> Option<Signal> {
         let mut signals = Self::get_signals_map(&env);
         let mut signal = signals.get(signal_id)?;
@@ -811,12 +1631,14 @@ impl SignalRegistry {
             && !Self::check_provider_exists(&env, &signal.provider)
         {
             Self::orphan_signal(&env, &mut signals, signal_id);
-            return signals.get(signal_id);
+            return signals
+                .get(signal_id)
+                .map(|signal| premium::redacted(&env, signal));
         }
 
         // Check for expiry warning (Issue #417)
-        let current_ledger = env.ledger().sequence();
-        let time_to_expiry = signal.expiry.saturating_sub(current_ledger);
+        let time_to_expiry =
+            signal.expiry.saturating_sub(env.ledger().timestamp()) / LEDGER_CLOSE_SECS;
         if time_to_expiry <= WARNING_WINDOW_LEDGERS && !signal.warning_emitted {
             events::emit_signal_expiry_warning(
                 &env,
@@ -830,20 +1652,20 @@ impl SignalRegistry {
             Self::save_signals_map(&env, &signals);
         }
 
-        Some(signal)
+        Some(premium::redacted(&env, signal))
     }
 
     pub fn save_signal_template(
         env: Env,
         provider: Address,
-        template: SignalTemplate,
+        template: SavedSignalTemplate,
     ) -> Result<u32, AdminError> {
         provider.require_auth();
         Self::validate_asset_pair(&env, &template.asset_pair)?;
 
         let mut templates_map = Self::get_signal_templates_map(&env);
         let template_id =
-            templates::save_signal_template(&env, &mut templates_map, provider, template)
+            saved_templates::save_signal_template(&env, &mut templates_map, provider, template)
                 .map_err(|_| AdminError::InvalidParameter)?;
         Self::save_signal_templates_map(&env, &templates_map);
 
@@ -858,13 +1680,25 @@ impl SignalRegistry {
     ) -> Result<u64, AdminError> {
         let templates_map = Self::get_signal_templates_map(&env);
         let template =
-            templates::get_signal_template(&templates_map, provider.clone(), template_id)
+            saved_templates::get_signal_template(&templates_map, provider.clone(), template_id)
                 .map_err(|_| AdminError::InvalidParameter)?;
         let (asset_pair, action, expiry_hours, price, rationale) =
-            templates::merge_template(template, overrides);
+            saved_templates::merge_template(template, overrides);
         let expiry = env.ledger().timestamp() + expiry_hours * 60 * 60;
+        let tags = Vec::new(&env);
 
-        Self::create_signal(env, provider, asset_pair, action, price, rationale, expiry)
+        Self::create_signal(
+            env,
+            provider,
+            asset_pair,
+            action,
+            price,
+            rationale,
+            expiry,
+            SignalCategory::SWING,
+            tags,
+            RiskLevel::Medium,
+        )
     }
 
     /* =========================
@@ -886,15 +1720,16 @@ impl SignalRegistry {
         scoring::get_signal_quality_score(&env, signal_id)
     }
 
-    /// Return the signal if `viewer` is allowed to see it. Non-[`SignalCategory::PREMIUM`]
-    /// signals are visible to any viewer. PREMIUM signals require an active on-chain
-    /// subscription (via UserPortfolio [`check_subscription`]) unless the viewer is the
-    /// signal provider.
+    /// Return the signal, unredacted, if `viewer` is allowed to see it. Non-premium
+    /// signals are visible to any viewer. Premium signals require an active
+    /// subscription (here or via UserPortfolio [`check_subscription`]) unless the
+    /// viewer is the signal provider. The viewer must authorize the call.
     pub fn get_signal_for_viewer(
         env: Env,
         signal_id: u64,
         viewer: Address,
     ) -> Option<Signal> {
+        viewer.require_auth();
         let signals = Self::get_signals_map(&env);
         let signal = signals.get(signal_id)?;
 
@@ -909,21 +1744,134 @@ impl SignalRegistry {
                 timestamp: env.ledger().timestamp(),
             },
         );
-        if signal.category != SignalCategory::PREMIUM {
-            return Some(signal);
+        if Self::can_view_premium(&env, &signal, &viewer) {
+            Some(signal)
+        } else {
+            None
+        }
+    }
+
+    /// Provider: set the monthly subscription price, paid in `token`.
+    pub fn set_subscription_price(
+        env: Env,
+        provider: Address,
+        token: Address,
+        monthly_price: i128,
+    ) -> Result<(), SubscriptionError> {
+        provider.require_auth();
+        subscriptions::set_terms(&env, &provider, token, monthly_price)
+    }
+
+    pub fn get_subscription_terms(
+        env: Env,
+        provider: Address,
+    ) -> Option<subscriptions::SubscriptionTerms> {
+        subscriptions::get_terms(&env, &provider)
+    }
+
+    /// Pay one month of `provider`'s price and extend the subscription.
+    /// Fails if the price is no longer in `token` or exceeds `max_price`.
+    /// Returns the new expiry.
+    pub fn subscribe(
+        env: Env,
+        subscriber: Address,
+        provider: Address,
+        token: Address,
+        max_price: i128,
+    ) -> Result<u64, SubscriptionError> {
+        subscriber.require_auth();
+        let (subscription, breakdown) =
+            subscriptions::subscribe(&env, &subscriber, &provider, &token, max_price)?;
+        events::emit_subscription_paid(
+            &env,
+            subscriber,
+            provider,
+            subscription.expires_at,
+            breakdown.provider_fee,
+            breakdown.platform_fee,
+        );
+        Ok(subscription.expires_at)
+    }
+
+    pub fn get_subscription(
+        env: Env,
+        subscriber: Address,
+        provider: Address,
+    ) -> Option<subscriptions::Subscription> {
+        subscriptions::get_subscription(&env, &subscriber, &provider)
+    }
+
+    pub fn is_subscribed(env: Env, subscriber: Address, provider: Address) -> bool {
+        subscriptions::is_active(&env, &subscriber, &provider)
+    }
+
+    /// Mark an Active signal premium or public again. Signals in the PREMIUM
+    /// category are always premium regardless of this flag.
+    pub fn set_signal_premium(
+        env: Env,
+        provider: Address,
+        signal_id: u64,
+        premium: bool,
+    ) -> Result<(), PremiumError> {
+        provider.require_auth();
+        let mut signals = Self::get_signals_map(&env);
+        let mut signal = signals
+            .get(signal_id)
+            .ok_or(PremiumError::SignalNotFound)?;
+        if signal.provider != provider {
+            return Err(PremiumError::NotSignalOwner);
+        }
+        if signal.status != SignalStatus::Active {
+            return Err(PremiumError::SignalNotActive);
         }
-        if viewer == signal.provider {
-            return Some(signal);
+        signal.premium = premium;
+        signals.set(signal_id, signal);
+        Self::save_signals_map(&env, &signals);
+        events::emit_signal_premium_set(&env, signal_id, premium);
+        Ok(())
+    }
+
+    /// Public metadata of any signal, premium or not: pair, timing, status
+    /// and execution record stay visible so outcomes remain accountable.
+    pub fn get_signal_public(env: Env, signal_id: u64) -> Option<premium::SignalPublicView> {
+        Self::get_signals_map(&env)
+            .get(signal_id)
+            .map(|signal| premium::public_view(&signal))
+    }
+
+    /// Action, price, rationale and targets of a signal. For premium signals
+    /// the viewer must be the provider or hold an active subscription.
+    pub fn get_premium_content(
+        env: Env,
+        signal_id: u64,
+        viewer: Address,
+    ) -> Result<premium::PremiumContent, PremiumError> {
+        viewer.require_auth();
+        let signal = Self::get_signals_map(&env)
+            .get(signal_id)
+            .ok_or(PremiumError::SignalNotFound)?;
+        if !Self::can_view_premium(&env, &signal, &viewer) {
+            return Err(PremiumError::NotSubscribed);
+        }
+        Ok(premium::content(&signal))
+    }
+
+    fn can_view_premium(env: &Env, signal: &Signal, viewer: &Address) -> bool {
+        if !premium::is_premium(signal) || *viewer == signal.provider {
+            return true;
+        }
+        if subscriptions::is_active(env, viewer, &signal.provider) {
+            return true;
         }
-        let portfolio: Address = env
+        match env
             .storage()
             .instance()
-            .get(&StorageKey::UserPortfolio)?;
-        let allowed = Self::invoke_check_subscription(&env, &portfolio, &viewer, &signal.provider);
-        if allowed {
-            Some(signal)
-        } else {
-            None
+            .get::<_, Address>(&StorageKey::UserPortfolio)
+        {
+            Some(portfolio) => {
+                Self::invoke_check_subscription(env, &portfolio, viewer, &signal.provider)
+            }
+            None => false,
         }
     }
 
@@ -1056,6 +2004,16 @@ impl SignalRegistry {
         stats.get(provider)
     }
 
+    /// A provider's settled results on one asset pair.
+    pub fn get_provider_pair_stats(
+        env: Env,
+        provider: Address,
+        asset_pair: String,
+    ) -> Option<pair_stats::PairStats> {
+        let asset_pair = Self::parse_asset_pair(&env, &asset_pair).ok()?;
+        pair_stats::get(&env, &provider, &asset_pair)
+    }
+
     pub fn get_provider_monthly_report(
         env: Env,
         provider: Address,
@@ -1167,10 +2125,24 @@ impl SignalRegistry {
         let risk_level = RiskLevel::Medium;
 
         let signal_id = Self::create_signal_internal(
-            &env, submitter, asset_pair, action, price, rationale, expiry, category, tags,
+            &env,
+            submitter.clone(),
+            asset_pair,
+            action,
+            price,
+            rationale,
+            expiry,
+            category,
+            tags,
             risk_level,
         )
-        .map_err(|_| TemplateError::InvalidTemplate)?;
+        .map_err(|e| match e {
+            AdminError::NoStake => TemplateError::NoStake,
+            AdminError::StakeBelowMinimum => TemplateError::StakeBelowMinimum,
+            _ => TemplateError::InvalidTemplate,
+        })?;
+        Self::charge_creation_fee(&env, &submitter, signal_id)
+            .map_err(|_| TemplateError::InvalidTemplate)?;
 
         templates::increment_template_use_count(&env, template_id)?;
         Ok(signal_id)
@@ -1180,7 +2152,11 @@ impl SignalRegistry {
        PERFORMANCE TRACKING FUNCTIONS
     ========================== */
 
-    /// Record a trade execution for a signal and update performance stats
+    /// Record a trade execution for a signal and update performance stats.
+    /// Once a trade fee asset is set, the executor pays the fee on `volume`
+    /// and the signal's provider is credited its share.
+    /// While executions are restricted, `executor` must be a trusted executor
+    /// contract (normally auto_trade) calling in itself.
     pub fn record_trade_execution(
         env: Env,
         executor: Address,
@@ -1197,6 +2173,10 @@ impl SignalRegistry {
         // Require executor authorization
         executor.require_auth();
 
+        if trusted_executors::is_restricted(&env) && !trusted_executors::is_trusted(&env, &executor) {
+            return Err(errors::PerformanceError::UntrustedExecutor);
+        }
+
         // Rate limit: trade execution
         let trust = reputation::get_trust_score(&env, &executor)
             .map(|d| d.score)
@@ -1219,8 +2199,24 @@ impl SignalRegistry {
             .get(signal_id)
             .ok_or(errors::PerformanceError::SignalNotFound)?;
 
+        // Check the reported prices against the oracle when verification is on
+        let deviation = exec_verification::verify(&env, &signal, entry_price, exit_price)?;
+
+        // Charge the trade fee on the volume, crediting the signal's provider
+        if let Some(asset) = fees::get_fee_asset(&env) {
+            fees::collect_fee(&env, &executor, volume, asset, signal.provider.clone()).map_err(
+                |e| match e {
+                    errors::FeeError::TradeTooSmall | errors::FeeError::FeeRoundedToZero => {
+                        errors::PerformanceError::InvalidVolume
+                    }
+                    _ => errors::PerformanceError::FeeNotCollected,
+                },
+            )?;
+        }
+
         // Calculate ROI
-        let roi = performance::calculate_roi(entry_price, exit_price, &signal.action);
+        let rounding = admin::get_rounding_mode(&env);
+        let roi = performance::calculate_roi(entry_price, exit_price, &signal.action, rounding);
 
         // Create trade execution record
         let trade = TradeExecution {
@@ -1239,15 +2235,29 @@ impl SignalRegistry {
         // Update signal stats (general perf) and copier ROI (Issue #367)
         performance::update_signal_stats(&mut signal, &trade);
         performance::update_copier_roi_stats(&mut signal, roi.clamp(i32::MIN as i128, i32::MAX as i128) as i32);
+        let mut trades = Self::get_trade_executions_map(&env);
+        let trade_id = Self::next_trade_id(&env);
+        trades.set(trade_id, trade.clone());
+        Self::save_trade_executions_map(&env, &trades);
+        if let Some(deviation_bps) = deviation {
+            exec_verification::flag(&env, trade_id, deviation_bps);
+            events::emit_trade_flagged(&env, trade_id, signal_id, executor.clone(), deviation_bps);
+        }
+        attribution::record_execution(&env, &trade);
+        copier_sharing::record_execution(&env, &signal.provider, &trade);
+        ratings::record_executor(&env, signal_id, &executor);
 
-        // Evaluate new status
+        // Evaluate new status on the deployment's attributed ROI
         let now = env.ledger().timestamp();
-        let new_status = performance::evaluate_signal_status(&signal, now);
+        let mode = admin::get_attribution_mode(&env);
+        let attributed_roi = attribution::attributed_roi(&env, &signal, mode, rounding);
+        let new_status = performance::evaluate_signal_status_for_roi(&signal, now, attributed_roi);
         signal.status = new_status.clone();
 
         // Save updated signal
         signals.set(signal_id, signal.clone());
         Self::save_signals_map(&env, &signals);
+        signal_index::move_status(&env, signal_id, &old_status, &new_status);
 
         let provider_for_contest = signal.provider.clone();
 
@@ -1266,36 +2276,95 @@ impl SignalRegistry {
             },
         );
 
+        Self::apply_status_change(&env, &signal, old_status, new_status, attributed_roi, mode);
+
+        contests::apply_trade_to_contest_entries(
+            &env,
+            signal_id,
+            &provider_for_contest,
+            roi,
+            volume,
+        );
+
+        Ok(())
+    }
+
+    /// Release a closed signal's locked stake, slashing it if it Failed.
+    fn release_signal_stake_for(env: &Env, signal: &Signal, status: &SignalStatus) {
+        let mut stakes = Self::get_provider_stakes_map(env);
+        let slashed = stake::release_for_signal(env, &mut stakes, signal.id, status);
+        if slashed > 0 {
+            Self::save_provider_stakes_map(env, &stakes);
+            events::emit_signal_stake_slashed(env, signal.provider.clone(), signal.id, slashed);
+        }
+    }
+
+    /// Update provider stats, trust score and events when a signal moves to a
+    /// terminal status.
+    fn apply_status_change(
+        env: &Env,
+        signal: &Signal,
+        old_status: SignalStatus,
+        new_status: SignalStatus,
+        attributed_roi: Option<i128>,
+        mode: types::AttributionMode,
+    ) {
+        if !matches!(new_status, SignalStatus::Active | SignalStatus::Pending) {
+            Self::release_signal_stake_for(env, signal, &new_status);
+        }
+
         // Check if status changed and update provider stats
         if performance::should_update_provider_stats(&old_status, &new_status) {
-            let mut provider_stats_map = Self::get_provider_stats_map(&env);
+            moratorium::record_outcome(env, &signal.provider, &new_status);
+
+            let mut provider_stats_map = Self::get_provider_stats_map(env);
             let mut provider_stats = provider_stats_map
                 .get(signal.provider.clone())
                 .unwrap_or_default();
 
-            let signal_avg_roi = performance::get_signal_average_roi(&signal);
-
             performance::update_provider_performance(
                 &mut provider_stats,
                 &old_status,
                 &new_status,
-                signal_avg_roi,
+                attributed_roi.unwrap_or(0),
+                signal.total_volume,
+            );
+            provider_stats.attribution_mode = mode;
+            leaderboard_window::record_close(
+                env,
+                &signal.provider,
+                new_status == SignalStatus::Successful,
+                attributed_roi.unwrap_or(0),
+                signal.total_volume,
+            );
+            let roi_stats =
+                risk_metrics::record_roi(env, &signal.provider, attributed_roi.unwrap_or(0));
+            provider_stats.sortino_x100 = risk_metrics::sortino_x100(&roi_stats);
+            provider_stats.profit_factor_x100 = risk_metrics::profit_factor_x100(&roi_stats);
+            pair_stats::record_close(
+                env,
+                &signal.provider,
+                &signal.asset_pair,
+                new_status == SignalStatus::Successful,
+                attributed_roi.unwrap_or(0),
                 signal.total_volume,
             );
 
             provider_stats_map.set(signal.provider.clone(), provider_stats.clone());
-            Self::save_provider_stats_map(&env, &provider_stats_map);
+            Self::save_provider_stats_map(env, &provider_stats_map);
+
+            tiers::refresh(env, &signal.provider, &provider_stats);
 
             // Update leaderboard index (O(INDEX_CAPACITY) in-memory, O(1) query after)
-            update_leaderboard_index(&env, signal.provider.clone(), &provider_stats);
+            update_leaderboard_index(env, signal.provider.clone(), &provider_stats);
 
             // Update trust score when performance changes
             Self::update_provider_trust_score(env.clone(), signal.provider.clone());
 
             // Emit status change event
             events::emit_signal_status_changed(
-                &env,
-                signal_id,
+                env,
+                signal.id,
                 signal.provider.clone(),
                 old_status as u32,
                 new_status as u32,
@@ -1303,23 +2372,13 @@ impl SignalRegistry {
 
             // Emit provider stats updated event
             events::emit_provider_stats_updated(
-                &env,
-                signal.provider,
+                env,
+                signal.provider.clone(),
                 provider_stats.success_rate,
                 provider_stats.avg_return,
                 provider_stats.total_volume,
             );
         }
-
-        contests::apply_trade_to_contest_entries(
-            &env,
-            signal_id,
-            &provider_for_contest,
-            roi,
-            volume,
-        );
-
-        Ok(())
     }
 
     /// Get signal performance metrics
@@ -1327,7 +2386,8 @@ impl SignalRegistry {
         let signals = Self::get_signals_map(&env);
         let signal = signals.get(signal_id)?;
 
-        let average_roi = performance::get_signal_average_roi(&signal);
+        let average_roi =
+            performance::get_signal_average_roi(&signal, admin::get_rounding_mode(&env));
 
         Some(SignalPerformanceView {
             signal_id: signal.id,
@@ -1343,19 +2403,6 @@ impl SignalRegistry {
         Self::get_provider_stats(env, provider)
     }
 
-    /// Record provider stake amount for verification checks.
-    pub fn set_provider_stake(env: Env, provider: Address, amount: i128) -> Result<(), AdminError> {
-        provider.require_auth();
-        if amount < 0 {
-            return Err(AdminError::InvalidParameter);
-        }
-
-        let mut stakes = Self::get_provider_stakes_map(&env);
-        stakes.set(provider, amount);
-        Self::save_provider_stakes_map(&env, &stakes);
-        Ok(())
-    }
-
     // ═══════════════════════════════════════════════════════════════
     // Issue #424: Provider Ban Mechanism
     // ═══════════════════════════════════════════════════════════════
@@ -1410,10 +2457,13 @@ impl SignalRegistry {
     }
 
     /// Check whether a provider meets automated verification criteria.
-    pub fn check_verification_eligibility(env: Env, provider: Address) -> VerificationEligibility {
+    pub fn check_verification_eligibility(
+        env: Env,
+        provider: Address,
+    ) -> providers::VerificationEligibility {
         let stakes = Self::get_provider_stakes_map(&env);
         let stats = Self::get_provider_stats_map(&env);
-        let stake = stakes.get(provider.clone()).unwrap_or(0);
+        let stake = stakes.get(provider.clone()).map_or(0, |info| info.amount);
         let performance = stats.get(provider.clone()).unwrap_or_default();
 
         providers::check_verification_eligibility(&env, provider, stake, performance)
@@ -1422,19 +2472,147 @@ impl SignalRegistry {
     /// Get leaderboard of top providers by metric
     ///
     /// # Arguments
-    /// * `metric` - SuccessRate, Volume, or Followers (empty for MVP)
+    /// * `metric` - SuccessRate, Volume, Followers, Tips, AvgRoi or Sharpe
     /// * `limit` - Max providers to return (0 = default 10, max 50)
     ///
     /// # Minimum qualification
-    /// - >= 5 signals with terminal status
-    /// - success_rate > 0 (exclude all-failed)
+    /// Admin-configured via `set_leaderboard_qualification`; by default
+    /// >= 10 closed signals and at least one adopter.
     pub fn get_leaderboard(
         env: Env,
         metric: LeaderboardMetric,
         limit: u32,
     ) -> Vec<ProviderLeaderboard> {
-        let stats_map = Self::get_provider_stats_map(&env);
-        get_leaderboard_internal(&env, &stats_map, metric, limit)
+        get_leaderboard_internal(&env, metric, limit)
+    }
+
+    /// Tip the provider of a successful signal. The platform keeps a small cut;
+    /// returns what the provider received.
+    pub fn tip_provider(
+        env: Env,
+        from: Address,
+        provider: Address,
+        token: Address,
+        amount: i128,
+        signal_id: u64,
+    ) -> Result<i128, TippingError> {
+        from.require_auth();
+        let signal = Self::get_signals_map(&env)
+            .get(signal_id)
+            .ok_or(TippingError::SignalNotFound)?;
+        if signal.provider != provider {
+            return Err(TippingError::NotSignalProvider);
+        }
+        if signal.status != SignalStatus::Successful {
+            return Err(TippingError::SignalNotSuccessful);
+        }
+
+        let net = tipping::tip(&env, &from, &provider, &token, amount)?;
+
+        let mut stats_map = Self::get_provider_stats_map(&env);
+        let mut stats = stats_map.get(provider.clone()).unwrap_or_default();
+        stats.tip_count = stats.tip_count.saturating_add(1);
+        stats_map.set(provider.clone(), stats.clone());
+        Self::save_provider_stats_map(&env, &stats_map);
+        update_leaderboard_index(&env, provider.clone(), &stats);
+
+        events::emit_provider_tipped(&env, provider, from, token, signal_id, net);
+        Ok(net)
+    }
+
+    /// Net tips a provider has received in `token`.
+    pub fn get_total_tips(env: Env, provider: Address, token: Address) -> i128 {
+        tipping::get_total_tips(&env, &provider, &token)
+    }
+
+    /// Admin: token whose tip totals rank the Tips leaderboard. Tips in any
+    /// other token still reach the provider but don't count towards rank.
+    /// Providers move on the board the next time their stats update.
+    pub fn set_tip_ranking_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        tipping::set_ranking_token(&env, &token);
+        Ok(())
+    }
+
+    pub fn get_tip_ranking_token(env: Env) -> Option<Address> {
+        tipping::get_ranking_token(&env)
+    }
+
+    /// Rate a signal 1–5 with the hash of a short comment. Only executors with
+    /// a recorded execution of the signal may rate it; rating again replaces
+    /// the earlier rating.
+    pub fn rate_signal(
+        env: Env,
+        executor: Address,
+        signal_id: u64,
+        stars: u32,
+        comment_hash: BytesN<32>,
+    ) -> Result<(), RatingError> {
+        executor.require_auth();
+        let signal = Self::get_signals_map(&env)
+            .get(signal_id)
+            .ok_or(RatingError::SignalNotFound)?;
+        if !ratings::has_executed(&env, signal_id, &executor) {
+            return Err(RatingError::NotExecutor);
+        }
+
+        ratings::rate(&env, signal_id, &signal.provider, &executor, stars, comment_hash)?;
+        events::emit_signal_rated(&env, signal_id, executor, stars);
+        Ok(())
+    }
+
+    pub fn get_signal_rating(env: Env, signal_id: u64) -> ratings::RatingSummary {
+        ratings::get_signal_summary(&env, signal_id)
+    }
+
+    /// Ratings across all of a provider's signals.
+    pub fn get_provider_rating(env: Env, provider: Address) -> ratings::RatingSummary {
+        ratings::get_provider_summary(&env, &provider)
+    }
+
+    pub fn get_executor_rating(
+        env: Env,
+        signal_id: u64,
+        executor: Address,
+    ) -> Option<ratings::Rating> {
+        ratings::get_rating(&env, signal_id, &executor)
+    }
+
+    /// One page of a leaderboard, starting after `offset` ranked providers.
+    /// Boards hold up to 100 providers; pages up to 50.
+    pub fn get_leaderboard_page(
+        env: Env,
+        metric: LeaderboardMetric,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<ProviderLeaderboard> {
+        leaderboard::get_leaderboard_page(&env, metric, offset, limit)
+    }
+
+    /// A provider's rank on a leaderboard with the entries around it.
+    pub fn get_provider_rank(
+        env: Env,
+        provider: Address,
+        metric: LeaderboardMetric,
+    ) -> Option<leaderboard::ProviderRank> {
+        leaderboard::get_provider_rank(&env, &provider, metric)
+    }
+
+    /// Leaderboard over only the last `window_days` days (up to 30), e.g. 7 for
+    /// "hot this week". Ranks SuccessRate and Volume; other metrics return an
+    /// empty board.
+    pub fn get_leaderboard_windowed(
+        env: Env,
+        metric: LeaderboardMetric,
+        window_days: u32,
+        limit: u32,
+    ) -> Vec<ProviderLeaderboard> {
+        leaderboard_window::get_leaderboard_windowed(&env, metric, window_days, limit)
     }
 
     /// Get top N providers ranked by the requested metric.
@@ -1449,42 +2627,16 @@ impl SignalRegistry {
         leaderboard::get_provider_leaderboard(&env, metric, limit)
     }
 
-    /// Get top providers sorted by success rate
+    /// Get top providers sorted by success rate, from the index kept up to
+    /// date as signals close. Providers without a closed signal are not ranked.
     pub fn get_top_providers(env: Env, limit: u32) -> Vec<(Address, ProviderPerformance)> {
         let stats_map = Self::get_provider_stats_map(&env);
-        let mut providers = Vec::new(&env);
-
-        // Collect all providers
-        for key in stats_map.keys() {
-            if let Some(stats) = stats_map.get(key.clone()) {
-                providers.push_back((key, stats));
-            }
-        }
-
-        // Sort by success rate (descending)
-        // Note: Soroban Vec doesn't have built-in sort, so we implement a simple bubble sort
-        let len = providers.len();
-        for i in 0..len {
-            for j in 0..(len - i - 1) {
-                let curr = providers.get(j).unwrap();
-                let next = providers.get(j + 1).unwrap();
-
-                if curr.1.success_rate < next.1.success_rate {
-                    // Swap
-                    let temp = curr.clone();
-                    providers.set(j, next);
-                    providers.set(j + 1, temp);
-                }
-            }
-        }
-
-        // Return top N
-        let result_len = if limit < len { limit } else { len };
         let mut result = Vec::new(&env);
-        for i in 0..result_len {
-            result.push_back(providers.get(i).unwrap());
+        for provider in leaderboard::get_top_providers(&env, limit).iter() {
+            if let Some(stats) = stats_map.get(provider.clone()) {
+                result.push_back((provider, stats));
+            }
         }
-
         result
     }
 
@@ -1582,6 +2734,59 @@ impl SignalRegistry {
         fees::get_platform_treasury(&env)
     }
 
+    /// Admin: set the per-signal creation fee, or remove it with `None`.
+    /// A treasury must be configured unless the whole fee is burned.
+    pub fn set_creation_fee(
+        env: Env,
+        caller: Address,
+        config: Option<CreationFeeConfig>,
+    ) -> Result<(), AdminError> {
+        creation_fee::set_config(&env, &caller, config)
+    }
+
+    pub fn get_creation_fee(env: Env) -> Option<CreationFeeConfig> {
+        creation_fee::get_config(&env)
+    }
+
+    /// Admin: bar providers from creating signals for `cooldown_hours` after
+    /// `failure_threshold` consecutive failed signals, or remove the rule with `None`.
+    pub fn set_moratorium_config(
+        env: Env,
+        caller: Address,
+        config: Option<MoratoriumConfig>,
+    ) -> Result<(), AdminError> {
+        moratorium::set_config(&env, &caller, config)
+    }
+
+    pub fn get_moratorium_config(env: Env) -> Option<MoratoriumConfig> {
+        moratorium::get_config(&env)
+    }
+
+    /// When the provider's current moratorium ends, or `None` if they may post.
+    pub fn get_moratorium_end(env: Env, provider: Address) -> Option<u64> {
+        moratorium::moratorium_end(&env, &provider)
+    }
+
+    /// Consecutive failed signals counted towards the provider's next moratorium.
+    pub fn get_failure_streak(env: Env, provider: Address) -> u32 {
+        moratorium::failure_streak(&env, &provider)
+    }
+
+    /// Performance tier of `provider`, recomputed whenever their stats change.
+    pub fn get_provider_tier(env: Env, provider: Address) -> tiers::ProviderTier {
+        tiers::get_tier(&env, &provider)
+    }
+
+    /// Creation fee the provider would pay for their next signal.
+    pub fn get_creation_fee_for(env: Env, provider: Address) -> i128 {
+        creation_fee::get_config(&env)
+            .map(|config| {
+                creation_fee::fee_for_tier(&config, Self::provider_perk_tier(&env, &provider))
+            })
+            .unwrap_or(0)
+    }
+
+    /// Accrued platform fees in `asset` not yet withdrawn.
     pub fn get_treasury_balance(env: Env, asset: Asset) -> i128 {
         fees::get_treasury_balance(&env, asset)
     }
@@ -1590,11 +2795,137 @@ impl SignalRegistry {
         fees::get_all_treasury_balances(&env)
     }
 
+    /// Fee breakdown for a trade at the configured `trade_fee_bps`, in the
+    /// original layout; use `calculate_fee_preview_for` to see a discount.
     pub fn calculate_fee_preview(
-        _env: Env,
+        env: Env,
+        trade_amount: i128,
+    ) -> Result<FeeBreakdownV1, errors::FeeError> {
+        fees::calculate_fees(&env, trade_amount, 0).map(FeeBreakdownV1::from)
+    }
+
+    /// Fee breakdown for a trade by `payer`, after their stake discount.
+    pub fn calculate_fee_preview_for(
+        env: Env,
+        payer: Address,
         trade_amount: i128,
     ) -> Result<FeeBreakdown, errors::FeeError> {
-        fees::calculate_fee_breakdown(trade_amount)
+        fees::calculate_fees(&env, trade_amount, fees::payer_discount_bps(&env, &payer))
+    }
+
+    /// Admin: charge trade fees in `asset` on every recorded execution, or
+    /// stop charging them with `None`. Needs a platform treasury.
+    pub fn set_trade_fee_asset(
+        env: Env,
+        caller: Address,
+        asset: Option<Asset>,
+    ) -> Result<(), AdminError> {
+        admin::require_admin(&env, &caller)?;
+        caller.require_auth();
+        if asset.is_some() && fees::get_platform_treasury(&env).is_none() {
+            return Err(AdminError::InvalidParameter);
+        }
+        fees::set_fee_asset(&env, asset);
+        Ok(())
+    }
+
+    pub fn get_trade_fee_asset(env: Env) -> Option<Asset> {
+        fees::get_fee_asset(&env)
+    }
+
+    /// Admin: send all accrued platform fees to the platform treasury.
+    pub fn withdraw_platform_fees(
+        env: Env,
+        admin: Address,
+    ) -> Result<Map<Asset, i128>, errors::FeeError> {
+        admin::require_admin(&env, &admin).map_err(|_| errors::FeeError::Unauthorized)?;
+        admin.require_auth();
+        fees::withdraw_platform_fees(&env)
+    }
+
+    /// Send the provider their accrued share of trade fees.
+    pub fn claim_provider_fees(
+        env: Env,
+        provider: Address,
+    ) -> Result<Map<Asset, i128>, errors::FeeError> {
+        provider.require_auth();
+        fees::claim_provider_fees(&env, &provider)
+    }
+
+    pub fn get_provider_fee_balances(env: Env, provider: Address) -> Map<Asset, i128> {
+        fees::get_provider_fee_balances(&env, &provider)
+    }
+
+    /// Name the address that referred `user`. Can only be set once.
+    pub fn set_referrer(env: Env, user: Address, referrer: Address) -> Result<(), ReferralError> {
+        user.require_auth();
+        referrals::set_referrer(&env, &user, &referrer)?;
+        events::emit_referrer_set(&env, user, referrer);
+        Ok(())
+    }
+
+    pub fn get_referrer(env: Env, user: Address) -> Option<Address> {
+        referrals::get_referrer(&env, &user)
+    }
+
+    /// Admin: set the share of each referred trade fee credited to the referrer.
+    pub fn set_referral_share(env: Env, caller: Address, share_bps: u32) -> Result<(), ReferralError> {
+        admin::require_admin(&env, &caller).map_err(|_| ReferralError::Unauthorized)?;
+        caller.require_auth();
+        referrals::set_share_bps(&env, share_bps)
+    }
+
+    pub fn get_referral_share(env: Env) -> u32 {
+        referrals::get_share_bps(&env)
+    }
+
+    pub fn get_referral_stats(env: Env, address: Address) -> referrals::ReferralStats {
+        referrals::get_stats(&env, &address)
+    }
+
+    pub fn get_referral_balance(env: Env, referrer: Address) -> Map<Asset, i128> {
+        referrals::get_balance(&env, &referrer)
+    }
+
+    /// Admin: share of the platform's cut of each trade fee paid to stakers.
+    pub fn set_staking_reward_share(env: Env, caller: Address, share_bps: u32) -> Result<(), errors::FeeError> {
+        admin::require_admin(&env, &caller).map_err(|_| errors::FeeError::Unauthorized)?;
+        caller.require_auth();
+        staking_rewards::set_share_bps(&env, share_bps)
+    }
+
+    pub fn get_staking_reward_share(env: Env) -> u32 {
+        staking_rewards::get_share_bps(&env)
+    }
+
+    /// Staking rewards `provider` has earned and not yet claimed, per asset.
+    pub fn get_staking_rewards(env: Env, provider: Address) -> Map<Asset, i128> {
+        staking_rewards::get_pending(&env, &provider)
+    }
+
+    /// Send the provider their staking rewards.
+    pub fn claim_staking_rewards(
+        env: Env,
+        provider: Address,
+    ) -> Result<Map<Asset, i128>, errors::FeeError> {
+        provider.require_auth();
+        let claimed = fees::pay_out(&env, &staking_rewards::take_pending(&env, &provider), &provider);
+        if claimed.is_empty() {
+            return Err(errors::FeeError::NothingToClaim);
+        }
+        events::emit_fees_withdrawn(&env, provider, claimed.clone());
+        Ok(claimed)
+    }
+
+    /// Send the referrer everything they have been credited.
+    pub fn claim_referral_rewards(
+        env: Env,
+        referrer: Address,
+    ) -> Result<Map<Asset, i128>, ReferralError> {
+        referrer.require_auth();
+        let claimed = referrals::claim(&env, &referrer)?;
+        events::emit_fees_withdrawn(&env, referrer, claimed.clone());
+        Ok(claimed)
     }
 
     /* =========================
@@ -1693,11 +3024,77 @@ impl SignalRegistry {
         Ok(())
     }
 
+    /// Opt in to (or out of) sharing the size and ROI of your executions of
+    /// `provider`'s signals with them. Sharing is off by default; opting out
+    /// deletes what was shared.
+    pub fn set_data_sharing(env: Env, executor: Address, provider: Address, enabled: bool) {
+        copier_sharing::set_sharing(&env, executor, provider, enabled);
+    }
+
+    pub fn is_sharing_data(env: Env, executor: Address, provider: Address) -> bool {
+        copier_sharing::is_sharing(&env, &executor, &provider)
+    }
+
+    /// Execution stats of the copiers that consented to share with the provider.
+    pub fn get_my_copier_stats(env: Env, provider: Address) -> ProviderCopierStats {
+        provider.require_auth();
+        copier_sharing::provider_copier_stats(&env, &provider)
+    }
+
     /// Get list of providers user follows
     pub fn get_followed_providers(env: Env, user: Address) -> Vec<Address> {
         social::get_followed_providers(&env, &user)
     }
 
+    /// Followers of `provider`, `limit` at a time from position `cursor`
+    /// (0 for the first page).
+    pub fn get_followers(env: Env, provider: Address, cursor: u32, limit: u32) -> social::FollowPage {
+        social::page(&env, &social::get_followers(&env, &provider), cursor, limit)
+    }
+
+    /// Providers `user` follows, paginated as in `get_followers`.
+    pub fn get_following(env: Env, user: Address, cursor: u32, limit: u32) -> social::FollowPage {
+        social::page(&env, &social::get_followed_providers(&env, &user), cursor, limit)
+    }
+
+    /// Authorize (or stop) automatic copying of `provider`'s new signals for
+    /// `follower`, capped at `max_amount_per_trade` per trade. `sizing_override`
+    /// is a share of the follower's balance in bps that replaces their own
+    /// position sizing.
+    pub fn set_copy_config(
+        env: Env,
+        follower: Address,
+        provider: Address,
+        enabled: bool,
+        max_amount_per_trade: i128,
+        sizing_override: Option<u32>,
+    ) -> Result<(), CopyConfigError> {
+        follower.require_auth();
+        copy_config::set_config(
+            &env,
+            &follower,
+            &provider,
+            enabled,
+            max_amount_per_trade,
+            sizing_override,
+        )?;
+        events::emit_copy_config_set(&env, follower, provider, enabled, max_amount_per_trade);
+        Ok(())
+    }
+
+    pub fn get_copy_config(
+        env: Env,
+        follower: Address,
+        provider: Address,
+    ) -> Option<copy_config::CopyConfig> {
+        copy_config::get_config(&env, &follower, &provider)
+    }
+
+    /// Whether `follower` allows a copy of `amount` of `provider`'s signal.
+    pub fn is_copy_authorized(env: Env, follower: Address, provider: Address, amount: i128) -> bool {
+        copy_config::is_authorized(&env, &follower, &provider, amount)
+    }
+
     /// Get follower count for a provider
     pub fn get_follower_count(env: Env, provider: Address) -> u32 {
         social::get_follower_count(&env, &provider)
@@ -1712,11 +3109,96 @@ impl SignalRegistry {
         update_leaderboard_index(env, provider.clone(), &stats);
     }
 
-    /// Cleanup expired signals in batches
+    /// Close up to `max_count` Active signals past their expiry (capped at
+    /// `MAX_CLEANUP_BATCH_SIZE`). Each is judged on its attributed ROI like
+    /// any other close and updates its provider's stats; a signal that
+    /// expired without executions is Failed, one whose ROI sits between the
+    /// thresholds is Expired. While a price oracle is configured, signals
+    /// their executions don't decide are left to `settle_expired_signal`
+    /// until `SETTLEMENT_WINDOW` after expiry. Anyone may call this.
+    pub fn expire_signals(env: Env, max_count: u32) -> expiry::ExpirySweep {
+        let now = env.ledger().timestamp();
+        let mode = admin::get_attribution_mode(&env);
+        let rounding = admin::get_rounding_mode(&env);
+        let batch = max_count.min(expiry::MAX_CLEANUP_BATCH_SIZE);
+
+        let mut signals = Self::get_signals_map(&env);
+        let mut sweep = expiry::ExpirySweep {
+            successful: 0,
+            failed: 0,
+            expired: 0,
+            more: false,
+        };
+        let mut closed: Vec<(Signal, Option<i128>)> = Vec::new(&env);
+
+        for signal_id in signal_index::ids_by_status(&env, &SignalStatus::Active).iter() {
+            let mut signal = match signals.get(signal_id) {
+                Some(signal) => signal,
+                None => continue,
+            };
+            if signal.status != SignalStatus::Active || signal.expiry >= now {
+                continue;
+            }
+            let roi = attribution::attributed_roi(&env, &signal, mode, rounding);
+            let verdict = performance::evaluate_signal_status_for_roi(&signal, now, roi);
+            let decided_by_executions = roi.is_some() && verdict != SignalStatus::Active;
+            if !decided_by_executions && settlement::awaiting_settlement(&env, &signal, now) {
+                continue;
+            }
+            if closed.len() >= batch {
+                sweep.more = true;
+                break;
+            }
+
+            let new_status = match verdict {
+                SignalStatus::Successful => {
+                    sweep.successful += 1;
+                    SignalStatus::Successful
+                }
+                SignalStatus::Failed => {
+                    sweep.failed += 1;
+                    SignalStatus::Failed
+                }
+                _ => {
+                    sweep.expired += 1;
+                    SignalStatus::Expired
+                }
+            };
+            signal_index::move_status(&env, signal_id, &signal.status, &new_status);
+            signal.status = new_status;
+            signals.set(signal_id, signal.clone());
+            events::emit_signal_expired(&env, signal.id, signal.provider.clone(), signal.expiry);
+            closed.push_back((signal, roi));
+        }
+
+        if !closed.is_empty() {
+            Self::save_signals_map(&env, &signals);
+        }
+        for (signal, roi) in closed.iter() {
+            Self::apply_status_change(
+                &env,
+                &signal,
+                SignalStatus::Active,
+                signal.status.clone(),
+                roi,
+                mode,
+            );
+        }
+        sweep
+    }
+
+    /// Cleanup expired signals in batches, releasing the stake locked behind
+    /// each one marked Expired. Like `expire_signals`, it leaves signals
+    /// `settle_expired_signal` can still close alone.
     /// Returns (signals_processed, signals_expired)
     pub fn cleanup_expired_signals(env: Env, limit: u32) -> (u32, u32) {
         let signals = Self::get_signals_map(&env);
         let result = expiry::cleanup_expired_signals(&env, &signals, limit);
+        for id in result.expired_ids.iter() {
+            if let Some(signal) = signals.get(id) {
+                Self::release_signal_stake_for(&env, &signal, &SignalStatus::Expired);
+            }
+        }
         (result.signals_processed, result.signals_expired)
     }
 
@@ -1741,37 +3223,21 @@ impl SignalRegistry {
 
     //  ANALYTICS FUNCTIONS
 
-    /// Get provider analytics (requires min 10 signals)
-    pub fn get_provider_analytics(
-        env: Env,
-        provider: Address,
-    ) -> Option<analytics::ProviderAnalytics> {
-        let signals = Self::get_signals_map(&env);
-        analytics::calculate_provider_analytics(&env, &signals, &provider)
-    }
-
-    /// Get trending asset pairs in last N hours
-    pub fn get_trending_assets(env: Env, window_hours: u64) -> Vec<(String, u32)> {
+    /// Record today's global stats (signal count, volume, success rate) under
+    /// the current day. Anyone may call this; repeat calls refresh the record.
+    pub fn snapshot_analytics(env: Env) -> analytics::DailySnapshot {
         let signals = Self::get_signals_map(&env);
-        analytics::get_trending_assets(&env, &signals, window_hours)
+        analytics::snapshot_daily(&env, &signals)
     }
 
-    /// Get global analytics (24h metrics)
-    pub fn get_global_analytics(env: Env) -> analytics::GlobalAnalytics {
-        let signals = Self::get_signals_map(&env);
-        analytics::calculate_global_analytics(&env, &signals)
-    }
-
-    /// Get category-level performance analytics (Issue #419)
-    /// Returns analytics for the given category, including avg success rate,
-    /// avg ROI, total signals, total adopters, and top provider.
-    /// Empty categories return zero-valued analytics (no error).
-    pub fn get_category_analytics(
+    /// Daily snapshots between two days (timestamp / 86400), inclusive; at
+    /// most 90 days per call.
+    pub fn get_analytics_history(
         env: Env,
-        category: SignalCategory,
-    ) -> analytics::CategoryAnalytics {
-        let signals = Self::get_signals_map(&env);
-        analytics::calculate_category_analytics(&env, &signals, &category)
+        from_day: u64,
+        to_day: u64,
+    ) -> Vec<analytics::DailySnapshot> {
+        analytics::get_history(&env, from_day, to_day)
     }
 
     /* =========================
@@ -1926,6 +3392,174 @@ impl SignalRegistry {
         categories::auto_suggest_tags(&env, &rationale)
     }
 
+    /* =========================
+       EXPORT FUNCTIONS
+    ========================== */
+
+    /// Export a provider's signals. `format`: 0 = CSV, 1 = JSON.
+    pub fn export_signals(
+        env: Env,
+        provider: Address,
+        format: u32,
+        date_range: Option<(u64, u64)>,
+    ) -> Result<Bytes, ExportError> {
+        let format = export::ExportFormat::from_u32(format)?;
+        export::export_data(
+            &env,
+            &provider,
+            export::ExportEntity::Signals,
+            format,
+            date_range,
+            &export::ExportFilter::all(&env),
+        )
+    }
+
+    /// Export an executor's trades. `format`: 0 = CSV, 1 = JSON.
+    pub fn export_trades(
+        env: Env,
+        executor: Address,
+        format: u32,
+        date_range: Option<(u64, u64)>,
+    ) -> Result<Bytes, ExportError> {
+        let format = export::ExportFormat::from_u32(format)?;
+        export::export_data(
+            &env,
+            &executor,
+            export::ExportEntity::Trades,
+            format,
+            date_range,
+            &export::ExportFilter::all(&env),
+        )
+    }
+
+    /// Export a provider's performance summary. `format`: 0 = CSV, 1 = JSON.
+    pub fn export_performance(
+        env: Env,
+        provider: Address,
+        format: u32,
+        date_range: Option<(u64, u64)>,
+    ) -> Result<Bytes, ExportError> {
+        let format = export::ExportFormat::from_u32(format)?;
+        export::export_data(
+            &env,
+            &provider,
+            export::ExportEntity::Performance,
+            format,
+            date_range,
+            &export::ExportFilter::all(&env),
+        )
+    }
+
+    /// Export an executor's realized P&L broken down by the provider of each
+    /// copied signal, asset pair and calendar month (UTC).
+    /// `format`: 0 = CSV, 1 = JSON.
+    pub fn export_attribution(
+        env: Env,
+        executor: Address,
+        format: u32,
+        date_range: Option<(u64, u64)>,
+    ) -> Result<Bytes, ExportError> {
+        let format = export::ExportFormat::from_u32(format)?;
+        export::export_data(
+            &env,
+            &executor,
+            export::ExportEntity::Attribution,
+            format,
+            date_range,
+            &export::ExportFilter::all(&env),
+        )
+    }
+
+    /// Export an executor's trade summary: count, win rate, volume, realized
+    /// P&L, best and worst pair and average hold time.
+    /// `format`: 0 = CSV, 1 = JSON.
+    pub fn export_executor_performance(
+        env: Env,
+        executor: Address,
+        format: u32,
+        date_range: Option<(u64, u64)>,
+    ) -> Result<Bytes, ExportError> {
+        let format = export::ExportFormat::from_u32(format)?;
+        export::export_data(
+            &env,
+            &executor,
+            export::ExportEntity::ExecutorPerformance,
+            format,
+            date_range,
+            &export::ExportFilter::all(&env),
+        )
+    }
+
+    /// Export an executor's realized gain or loss per trade, grouped by
+    /// calendar year (UTC), for tax software. `preset_days` limits it to the
+    /// last 7, 30 or 365 days; 0 exports everything.
+    /// `format`: 0 = CSV, 1 = JSON.
+    pub fn export_tax_report(
+        env: Env,
+        executor: Address,
+        format: u32,
+        preset_days: u32,
+    ) -> Result<Bytes, ExportError> {
+        let format = export::ExportFormat::from_u32(format)?;
+        let date_range = if preset_days == 0 {
+            None
+        } else {
+            Some(export::preset_range(&env, preset_days)?)
+        };
+        export::export_data(
+            &env,
+            &executor,
+            export::ExportEntity::TaxReport,
+            format,
+            date_range,
+            &export::ExportFilter::all(&env),
+        )
+    }
+
+    /// Export a provider's portfolio summary as JSON.
+    pub fn export_portfolio(
+        env: Env,
+        provider: Address,
+        date_range: Option<(u64, u64)>,
+    ) -> Result<Bytes, ExportError> {
+        export::export_portfolio_json(&env, &provider, date_range, &export::ExportFilter::all(&env))
+    }
+
+    /// Any export together with metadata (entity, format, range, record
+    /// count, generation time and SHA-256 of the bytes) for deterministic
+    /// file naming and verification. `entity`: 0 = signals, 1 = trades,
+    /// 2 = performance, 3 = portfolio, 4 = attribution, 5 = executor
+    /// performance, 6 = tax report; `format`: 0 = CSV, 1 = JSON.
+    pub fn export_with_metadata(
+        env: Env,
+        requester: Address,
+        entity: u32,
+        format: u32,
+        date_range: Option<(u64, u64)>,
+    ) -> Result<ExportWithMetadata, ExportError> {
+        export::export_with_metadata(
+            &env,
+            &requester,
+            entity,
+            format,
+            date_range,
+            &export::ExportFilter::all(&env),
+        )
+    }
+
+    /// `export_with_metadata` limited to signals, or trades on signals,
+    /// matching `filter`'s asset pair, actions and statuses.
+    pub fn export_filtered(
+        env: Env,
+        requester: Address,
+        entity: u32,
+        format: u32,
+        date_range: Option<(u64, u64)>,
+        filter: export::ExportFilter,
+    ) -> Result<ExportWithMetadata, ExportError> {
+        export::export_with_metadata(&env, &requester, entity, format, date_range, &filter)
+    }
+
     /* =======
        SIGNAL IMPORT FUNCTIONS
     ========================== */
@@ -2008,6 +3642,7 @@ impl SignalRegistry {
             tags,
             risk_level,
         )?;
+        Self::charge_creation_fee(&env, &primary_author, signal_id)?;
 
         let mut signals = Self::get_signals_map(&env);
         let mut signal = signals.get(signal_id).unwrap();
@@ -2015,6 +3650,12 @@ impl SignalRegistry {
         signal.status = SignalStatus::Pending;
         signals.set(signal_id, signal);
         Self::save_signals_map(&env, &signals);
+        signal_index::move_status(
+            &env,
+            signal_id,
+            &SignalStatus::Active,
+            &SignalStatus::Pending,
+        );
 
         collaboration::create_collaborative_signal(
             &env,
@@ -2041,9 +3682,11 @@ impl SignalRegistry {
         if all_approved {
             let mut signals = Self::get_signals_map(&env);
             let mut signal = signals.get(signal_id).ok_or(AdminError::InvalidParameter)?;
+            let old_status = signal.status.clone();
             signal.status = SignalStatus::Active;
             signals.set(signal_id, signal);
             Self::save_signals_map(&env, &signals);
+            signal_index::move_status(&env, signal_id, &old_status, &SignalStatus::Active);
             events::emit_collaborative_signal_published(&env, signal_id);
         }
 
@@ -2235,6 +3878,44 @@ impl SignalRegistry {
         Ok(new_version)
     }
 
+    /// Amend the target price, expiry or rationale of an Active signal
+    /// (`None` keeps a field). Each amendment bumps the signal's revision and
+    /// keeps the replaced values in `get_signal_history`.
+    pub fn amend_signal(
+        env: Env,
+        provider: Address,
+        signal_id: u64,
+        new_price: Option<i128>,
+        new_expiry: Option<u64>,
+        new_rationale: Option<String>,
+    ) -> Result<u32, VersioningError> {
+        provider.require_auth();
+        let mut signals = Self::get_signals_map(&env);
+        let mut signal = signals
+            .get(signal_id)
+            .ok_or(VersioningError::VersionNotFound)?;
+
+        let revision = versioning::amend_signal(
+            &env,
+            signal_id,
+            &provider,
+            new_price,
+            new_expiry,
+            new_rationale,
+            &mut signal,
+        )?;
+
+        signals.set(signal_id, signal);
+        Self::save_signals_map(&env, &signals);
+
+        Ok(revision)
+    }
+
+    /// Current revision of a signal; 1 until it is first amended.
+    pub fn get_signal_revision(env: Env, signal_id: u64) -> u32 {
+        versioning::get_latest_version(&env, signal_id)
+    }
+
     /// Get version history for a signal
     pub fn get_signal_history(env: Env, signal_id: u64) -> Vec<SignalVersion> {
         versioning::get_signal_history(&env, signal_id)
@@ -2349,6 +4030,9 @@ impl SignalRegistry {
         cross_chain::get_address_mapping(&env, &source_chain, &source_address)
     }
 
+    /// Publish a requested cross-chain signal once its proof verifies. The
+    /// creation fee is charged to the provider's Stellar address like any
+    /// other signal, so they must authorize the import while a fee is set.
     pub fn import_verified_signal(
         env: Env,
         source_chain: String,
@@ -2378,6 +4062,12 @@ impl SignalRegistry {
             return Err(CrossChainError::VerificationFailed);
         }
 
+        // The fee is pulled from the provider, so they must sign for it.
+        let provider = cc_signal.stellar_address.clone();
+        if Self::get_creation_fee_for(env.clone(), provider.clone()) > 0 {
+            provider.require_auth();
+        }
+
         // Create the signal on Stellar
         let category = SignalCategory::SWING;
         let tags = Vec::new(&env);
@@ -2395,7 +4085,13 @@ impl SignalRegistry {
             tags,
             risk_level,
         )
-        .map_err(|_| CrossChainError::InvalidProof)?;
+        .map_err(|e| match e {
+            AdminError::NoStake => CrossChainError::NoStake,
+            AdminError::StakeBelowMinimum => CrossChainError::StakeBelowMinimum,
+            _ => CrossChainError::InvalidProof,
+        })?;
+        Self::charge_creation_fee(&env, &cc_signal.stellar_address, stellar_id)
+            .map_err(|_| CrossChainError::CreationFeeFailed)?;
 
         cc_signal.stellar_signal_id = stellar_id;
         cc_signal.sync_status = SyncStatus::Imported;
@@ -2635,8 +4331,6 @@ mod tests;
 #[cfg(test)]
 mod test_admin_transfer;
 #[cfg(test)]
-mod test_adoption;
-#[cfg(test)]
 mod test_emergency;
 #[cfg(test)]
 mod test_health;
@@ -2644,5 +4338,3 @@ mod test_health;
 mod test_scheduling;
 #[cfg(test)]
 mod test_signal_issues;
-#[cfg(test)]
-mod test_admin_transfer;
@@ -1,24 +1,53 @@
-//! v1 → v2 signal storage migration. Unmigrated records live in [`StorageKey::SignalsV1`];
-//! canonical v2 data is in [`StorageKey::Signals`]. Re-running the migration is safe: only
-//! ids with a v1 record are transformed; v1 is removed when written to v2.
+//! Signal and provider stats storage migrations. Unmigrated v1 signals live in
+//! [`StorageKey::SignalsV1`] and staged v2 signals in [`StorageKey::SignalsV2`];
+//! canonical data is in [`StorageKey::Signals`]. Re-running a migration is safe: only
+//! ids with a legacy record are transformed; the legacy record is removed when written.
 
 use crate::categories;
-use crate::categories::{RiskLevel, SignalCategory};
+use crate::categories::SignalCategory;
 use crate::contests;
 use crate::errors::AdminError;
-use crate::events::emit_migration_progress;
-use crate::types::{MigrationProgress, Signal, SignalAction, SignalStatus, SignalV1};
+use crate::events::{emit_migration_progress, emit_migration_skipped};
+use crate::types::{
+    MigrationProgress, ProviderPerformance, ProviderPerformanceV1, Signal,
+    SignalStatus, SignalV1, SignalV2,
+};
 use crate::StorageKey;
-use soroban_sdk::{Address, Env, Map, String, Vec};
+use soroban_sdk::{Address, Env, FromVal, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec};
+use stellar_swipe_common::AssetPair;
 
 const MAX_MIGRATION_BATCH: u32 = 256;
 
-fn v1_to_v2(_env: &Env, v1: &SignalV1) -> Signal {
+/// Longest v1 pair string worth trying to parse; valid pairs are shorter.
+const MAX_V1_PAIR_LEN: usize = 256;
+
+/// Parse a pair string, which may use the v1 "-" rather than "/" between the
+/// assets (e.g. "XLM-USDC").
+pub(crate) fn v1_pair(env: &Env, raw: &String) -> Result<AssetPair, AdminError> {
+    if let Ok(pair) = AssetPair::parse(env, raw) {
+        return Ok(pair);
+    }
+    let len = raw.len() as usize;
+    if len > MAX_V1_PAIR_LEN {
+        return Err(AdminError::InvalidAssetPair);
+    }
+    let mut buf = [0u8; MAX_V1_PAIR_LEN];
+    raw.copy_into_slice(&mut buf[..len]);
+    let dash_at = buf[..len]
+        .iter()
+        .position(|b| *b == b'-')
+        .ok_or(AdminError::InvalidAssetPair)?;
+    buf[dash_at] = b'/';
+    AssetPair::parse(env, &String::from_bytes(env, &buf[..len]))
+        .map_err(|_| AdminError::InvalidAssetPair)
+}
+
+fn v1_to_v2(env: &Env, v1: &SignalV1) -> Result<Signal, AdminError> {
     let rationale_hash = v1.rationale.clone();
-    Signal {
+    Ok(Signal {
         id: v1.id,
         provider: v1.provider.clone(),
-        asset_pair: v1.asset_pair.clone(),
+        asset_pair: v1_pair(env, &v1.asset_pair)?,
         action: v1.action.clone(),
         price: v1.price,
         rationale: v1.rationale.clone(),
@@ -43,7 +72,45 @@ fn v1_to_v2(_env: &Env, v1: &SignalV1) -> Signal {
         warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
-    }
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
+    })
+}
+
+fn v2_to_v3(env: &Env, v2: &SignalV2) -> Result<Signal, AdminError> {
+    Ok(Signal {
+        id: v2.id,
+        provider: v2.provider.clone(),
+        asset_pair: v1_pair(env, &v2.asset_pair)?,
+        action: v2.action.clone(),
+        price: v2.price,
+        rationale: v2.rationale.clone(),
+        timestamp: v2.timestamp,
+        expiry: v2.expiry,
+        status: v2.status.clone(),
+        executions: v2.executions,
+        successful_executions: v2.successful_executions,
+        total_volume: v2.total_volume,
+        total_roi: v2.total_roi,
+        category: v2.category.clone(),
+        tags: v2.tags.clone(),
+        risk_level: v2.risk_level.clone(),
+        is_collaborative: v2.is_collaborative,
+        submitted_at: v2.submitted_at,
+        rationale_hash: v2.rationale_hash.clone(),
+        confidence: v2.confidence,
+        adoption_count: v2.adoption_count,
+        ai_validation_score: v2.ai_validation_score,
+        avg_copier_roi_bps: v2.avg_copier_roi_bps,
+        copier_closed_count: v2.copier_closed_count,
+        warning_emitted: v2.warning_emitted,
+        benchmark_return_bps: v2.benchmark_return_bps,
+        alpha_bps: v2.alpha_bps,
+        stop_loss: None,
+        take_profit: None,
+        premium: false,
+    })
 }
 
 fn get_v1_map(env: &Env) -> Map<u64, SignalV1> {
@@ -136,6 +203,8 @@ fn add_to_category_index(env: &Env, id: u64, category: SignalCategory) {
 
 /// Migrate at most `batch_size` v1 signal records into v2, scanning by signal id
 /// from the saved cursor. Idempotent: re-running with no v1 rows is a no-op (aside from events).
+/// A record whose pair can't be parsed is left in v1, reported with a
+/// `migration_skipped` event, and passed over by the cursor.
 pub fn migrate_signals_v1_to_v2(
     env: &Env,
     _admin: &Address,
@@ -199,7 +268,14 @@ pub fn migrate_signals_v1_to_v2(
     while id <= scan_to {
         if let Some(v1_sig) = v1.get(id) {
             if v1_sig.id == id {
-                let s2 = v1_to_v2(env, &v1_sig);
+                let s2 = match v1_to_v2(env, &v1_sig) {
+                    Ok(s2) => s2,
+                    Err(_) => {
+                        emit_migration_skipped(env, id);
+                        id = id.saturating_add(1);
+                        continue;
+                    }
+                };
                 v2.set(id, s2.clone());
                 v1.remove(id);
                 if s2.status == SignalStatus::Active {
@@ -232,9 +308,179 @@ pub fn migrate_signals_v1_to_v2(
     Ok(())
 }
 
+/// Whether the stored struct `val` has a field named `field`. Decoding a
+/// struct of the wrong layout traps, so layouts are told apart this way first.
+fn has_field(env: &Env, val: &Val, field: &str) -> bool {
+    Map::<Symbol, Val>::try_from_val(env, val)
+        .map(|m| m.contains_key(Symbol::new(env, field)))
+        .unwrap_or(false)
+}
+
+/// Move every record of [`StorageKey::Signals`] still in the v2 layout (no
+/// `premium` field) into [`StorageKey::SignalsV2`]. Returns how many records
+/// were staged.
+fn stage_v2_signals(env: &Env) -> u32 {
+    let raw: Map<u64, Val> = env
+        .storage()
+        .instance()
+        .get(&StorageKey::Signals)
+        .unwrap_or(Map::new(env));
+    let mut current: Map<u64, Val> = Map::new(env);
+    let mut staged: Map<u64, SignalV2> = Map::new(env);
+    for (id, val) in raw.iter() {
+        if has_field(env, &val, "premium") {
+            current.set(id, val);
+        } else {
+            staged.set(id, SignalV2::from_val(env, &val));
+        }
+    }
+    if staged.is_empty() {
+        return 0;
+    }
+    env.storage().instance().set(&StorageKey::Signals, &current);
+    env.storage().instance().set(&StorageKey::SignalsV2, &staged);
+    env.storage().instance().set(&StorageKey::MigrationV2Cursor, &1u64);
+    env.storage()
+        .instance()
+        .set(&StorageKey::MigrationV2TargetTotal, &staged.len());
+    staged.len()
+}
+
+/// Convert at most `batch_size` staged v2 records into the current layout,
+/// scanning by signal id from the saved cursor. The first call stages the v2
+/// records; once none remain staged, a call is a no-op (aside from events).
+/// A record whose pair can't be parsed stays staged and is reported with a
+/// `migration_skipped` event.
+pub fn migrate_signals_v2_to_v3(env: &Env, batch_size: u32) -> Result<(), AdminError> {
+    if batch_size == 0 || batch_size > MAX_MIGRATION_BATCH {
+        return Err(AdminError::InvalidParameter);
+    }
+
+    if !env.storage().instance().has(&StorageKey::SignalsV2) && stage_v2_signals(env) == 0 {
+        emit_migration_progress(
+            env,
+            MigrationProgress {
+                migrated_count: 0,
+                total_count: 0,
+            },
+        );
+        return Ok(());
+    }
+
+    let counter: u64 = env
+        .storage()
+        .instance()
+        .get(&StorageKey::SignalCounter)
+        .unwrap_or(0u64);
+    let target_total: u32 = env
+        .storage()
+        .instance()
+        .get(&StorageKey::MigrationV2TargetTotal)
+        .unwrap_or(0);
+    let mut staged: Map<u64, SignalV2> = env
+        .storage()
+        .instance()
+        .get(&StorageKey::SignalsV2)
+        .unwrap_or(Map::new(env));
+    let mut signals = get_v2_map(env);
+    let cur: u64 = env
+        .storage()
+        .instance()
+        .get(&StorageKey::MigrationV2Cursor)
+        .unwrap_or(1u64)
+        .max(1);
+
+    let end_scan = cur.saturating_add((batch_size as u64).saturating_sub(1));
+    let scan_to = end_scan.min(counter);
+    let mut batch_migrated: u32 = 0;
+
+    let mut id = cur;
+    while id <= scan_to {
+        if let Some(v2) = staged.get(id) {
+            match v2_to_v3(env, &v2) {
+                Ok(signal) => {
+                    signals.set(id, signal);
+                    staged.remove(id);
+                    batch_migrated = batch_migrated.saturating_add(1);
+                }
+                Err(_) => emit_migration_skipped(env, id),
+            }
+        }
+        id = id.saturating_add(1);
+    }
+
+    save_v2_map(env, &signals);
+    if staged.is_empty() {
+        env.storage().instance().remove(&StorageKey::SignalsV2);
+    } else {
+        env.storage().instance().set(&StorageKey::SignalsV2, &staged);
+    }
+    if scan_to >= cur {
+        env.storage()
+            .instance()
+            .set(&StorageKey::MigrationV2Cursor, &scan_to.saturating_add(1));
+    }
+
+    emit_migration_progress(
+        env,
+        MigrationProgress {
+            migrated_count: batch_migrated,
+            total_count: target_total,
+        },
+    );
+    Ok(())
+}
+
+fn v1_to_v2_stats(v1: &ProviderPerformanceV1) -> ProviderPerformance {
+    ProviderPerformance {
+        total_signals: v1.total_signals,
+        successful_signals: v1.successful_signals,
+        failed_signals: v1.failed_signals,
+        total_copies: v1.total_copies,
+        success_rate: v1.success_rate,
+        avg_return: v1.avg_return,
+        total_volume: v1.total_volume,
+        follower_count: v1.follower_count,
+        ..Default::default()
+    }
+}
+
+/// Rewrite every v1 entry (no `tip_count` field) of [`StorageKey::ProviderStats`]
+/// in the current layout; the new fields start from their defaults. Entries
+/// already current are left alone.
+pub fn migrate_provider_stats_v1_to_v2(env: &Env) {
+    let mut raw: Map<Address, Val> = env
+        .storage()
+        .instance()
+        .get(&StorageKey::ProviderStats)
+        .unwrap_or(Map::new(env));
+    let total = raw.len();
+    let mut migrated: u32 = 0;
+    for (provider, val) in raw.clone().iter() {
+        if has_field(env, &val, "tip_count") {
+            continue;
+        }
+        let v1 = ProviderPerformanceV1::from_val(env, &val);
+        raw.set(provider, v1_to_v2_stats(&v1).into_val(env));
+        migrated = migrated.saturating_add(1);
+    }
+    if migrated > 0 {
+        env.storage().instance().set(&StorageKey::ProviderStats, &raw);
+    }
+    emit_migration_progress(
+        env,
+        MigrationProgress {
+            migrated_count: migrated,
+            total_count: total,
+        },
+    );
+}
+
 /// Test helper: only compiled for unit tests. Seeds v1, clears v2, resets migration metadata.
 #[cfg(test)]
 pub(crate) fn test_seed_v1_signals(env: &Env, count: u64) {
+    use crate::categories::RiskLevel;
+    use crate::types::SignalAction;
     use soroban_sdk::testutils::Address as _;
     if count == 0 {
         return;
@@ -274,4 +520,53 @@ pub(crate) fn test_seed_v1_signals(env: &Env, count: u64) {
         .set(&StorageKey::SignalCounter, &count);
     env.storage().instance().set(&StorageKey::MigrationCursor, &1u64);
     env.storage().instance().remove(&StorageKey::MigrationV1TargetTotal);
-}
\ No newline at end of file
+}
+/// Test helper: only compiled for unit tests. Stores `count` signals in the v2
+/// layout under [`StorageKey::Signals`], as a contract deployed before v3 would.
+#[cfg(test)]
+pub(crate) fn test_seed_v2_signals(env: &Env, count: u64) {
+    use crate::categories::RiskLevel;
+    use crate::types::SignalAction;
+    use soroban_sdk::testutils::Address as _;
+    let p = Address::generate(env);
+    let mut m: Map<u64, SignalV2> = Map::new(env);
+    let now = 1_000u64;
+    let mut i: u64 = 1;
+    while i <= count {
+        let v = SignalV2 {
+            id: i,
+            provider: p.clone(),
+            asset_pair: String::from_str(env, "XLM/USDC"),
+            action: SignalAction::Buy,
+            price: 100_000_000i128,
+            rationale: String::from_str(env, "test rationale"),
+            timestamp: now,
+            expiry: now + 86_400,
+            status: SignalStatus::Active,
+            executions: 2,
+            successful_executions: 1,
+            total_volume: 0,
+            total_roi: 0,
+            category: SignalCategory::SWING,
+            tags: Vec::new(env),
+            risk_level: RiskLevel::Medium,
+            is_collaborative: false,
+            submitted_at: now,
+            rationale_hash: String::from_str(env, "test rationale"),
+            confidence: 70,
+            adoption_count: 3,
+            ai_validation_score: None,
+            avg_copier_roi_bps: 0,
+            copier_closed_count: 0,
+            warning_emitted: false,
+            benchmark_return_bps: None,
+            alpha_bps: None,
+        };
+        m.set(i, v);
+        i = i.saturating_add(1);
+    }
+    env.storage().instance().set(&StorageKey::Signals, &m);
+    env.storage()
+        .instance()
+        .set(&StorageKey::SignalCounter, &count);
+}
@@ -0,0 +1,174 @@
+//! Cooling-off period after a run of failed signals.
+//!
+//! Each provider's consecutive `Failed` signals are counted as signals reach a
+//! terminal status; a `Successful` one resets the count. When the count reaches
+//! the configured threshold the provider is barred from creating signals for
+//! `cooldown_hours` and the count starts over. Disabled until the admin
+//! installs a config.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::admin;
+use crate::errors::AdminError;
+use crate::events;
+use crate::types::SignalStatus;
+
+const SECONDS_PER_HOUR: u64 = 3_600;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoratoriumConfig {
+    /// Consecutive failed signals that trigger a moratorium.
+    pub failure_threshold: u32,
+    /// Length of the moratorium.
+    pub cooldown_hours: u32,
+}
+
+#[contracttype]
+pub enum MoratoriumKey {
+    MoratoriumConfig,
+    /// provider -> consecutive failed signals since the last success or moratorium
+    FailureStreak(Address),
+    /// provider -> timestamp the current moratorium ends
+    Until(Address),
+}
+
+pub fn get_config(env: &Env) -> Option<MoratoriumConfig> {
+    env.storage().instance().get(&MoratoriumKey::MoratoriumConfig)
+}
+
+/// Install, replace or (with `None`) remove the moratorium rule.
+pub fn set_config(
+    env: &Env,
+    caller: &Address,
+    config: Option<MoratoriumConfig>,
+) -> Result<(), AdminError> {
+    admin::require_admin(env, caller)?;
+    caller.require_auth();
+
+    if let Some(config) = &config {
+        if config.failure_threshold == 0 || config.cooldown_hours == 0 {
+            return Err(AdminError::InvalidParameter);
+        }
+    }
+
+    let old = get_config(env).map(|c| c.failure_threshold).unwrap_or(0);
+    let new = config.as_ref().map(|c| c.failure_threshold).unwrap_or(0);
+    match &config {
+        Some(config) => env.storage().instance().set(&MoratoriumKey::MoratoriumConfig, config),
+        None => env.storage().instance().remove(&MoratoriumKey::MoratoriumConfig),
+    }
+
+    events::emit_parameter_updated(env, Symbol::new(env, "moratorium"), old as i128, new as i128);
+    Ok(())
+}
+
+pub fn failure_streak(env: &Env, provider: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&MoratoriumKey::FailureStreak(provider.clone()))
+        .unwrap_or(0)
+}
+
+/// End of the provider's moratorium, if one is in force.
+pub fn moratorium_end(env: &Env, provider: &Address) -> Option<u64> {
+    let until: u64 = env
+        .storage()
+        .persistent()
+        .get(&MoratoriumKey::Until(provider.clone()))?;
+    (env.ledger().timestamp() < until).then_some(until)
+}
+
+/// Reject signal creation while the provider is cooling off.
+pub fn require_no_moratorium(env: &Env, provider: &Address) -> Result<(), AdminError> {
+    match moratorium_end(env, provider) {
+        Some(_) => Err(AdminError::ProviderInMoratorium),
+        None => Ok(()),
+    }
+}
+
+/// Count a signal reaching a terminal status and start a moratorium when the
+/// provider's failure streak reaches the threshold.
+pub fn record_outcome(env: &Env, provider: &Address, status: &SignalStatus) {
+    let streak_key = MoratoriumKey::FailureStreak(provider.clone());
+    match status {
+        SignalStatus::Successful => {
+            env.storage().persistent().remove(&streak_key);
+            return;
+        }
+        SignalStatus::Failed => {}
+        _ => return,
+    }
+
+    let streak = failure_streak(env, provider) + 1;
+    let config = match get_config(env) {
+        Some(config) if streak >= config.failure_threshold => config,
+        _ => {
+            env.storage().persistent().set(&streak_key, &streak);
+            return;
+        }
+    };
+
+    env.storage().persistent().remove(&streak_key);
+    let until = env.ledger().timestamp() + config.cooldown_hours as u64 * SECONDS_PER_HOUR;
+    env.storage()
+        .persistent()
+        .set(&MoratoriumKey::Until(provider.clone()), &until);
+    events::emit_provider_moratorium(env, provider.clone(), streak, until);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger as _};
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn consecutive_failures_start_moratorium() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(TestContract, ());
+        let admin_addr = Address::generate(&env);
+        let provider = Address::generate(&env);
+        env.ledger().set_timestamp(1_000);
+
+        env.as_contract(&contract_id, || {
+            admin::init_admin(&env, admin_addr.clone()).unwrap();
+            set_config(
+                &env,
+                &admin_addr,
+                Some(MoratoriumConfig {
+                    failure_threshold: 3,
+                    cooldown_hours: 2,
+                }),
+            )
+            .unwrap();
+
+            record_outcome(&env, &provider, &SignalStatus::Failed);
+            record_outcome(&env, &provider, &SignalStatus::Failed);
+            record_outcome(&env, &provider, &SignalStatus::Successful);
+            record_outcome(&env, &provider, &SignalStatus::Failed);
+            record_outcome(&env, &provider, &SignalStatus::Expired);
+            record_outcome(&env, &provider, &SignalStatus::Failed);
+            assert_eq!(failure_streak(&env, &provider), 2);
+            assert_eq!(require_no_moratorium(&env, &provider), Ok(()));
+
+            record_outcome(&env, &provider, &SignalStatus::Failed);
+            assert_eq!(failure_streak(&env, &provider), 0);
+            assert_eq!(moratorium_end(&env, &provider), Some(1_000 + 7_200));
+            assert_eq!(
+                require_no_moratorium(&env, &provider),
+                Err(AdminError::ProviderInMoratorium)
+            );
+        });
+
+        env.ledger().set_timestamp(1_000 + 7_200);
+        env.as_contract(&contract_id, || {
+            assert_eq!(moratorium_end(&env, &provider), None);
+            assert_eq!(require_no_moratorium(&env, &provider), Ok(()));
+        });
+    }
+}
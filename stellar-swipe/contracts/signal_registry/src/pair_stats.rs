@@ -0,0 +1,102 @@
+//! Per-asset-pair provider performance.
+//!
+//! Every settled signal is folded into a `(provider, asset pair)` record so
+//! followers can see which markets a provider is actually good at without
+//! replaying their history.
+
+use soroban_sdk::{contracttype, Address, Env};
+use stellar_swipe_common::{AssetPair, BASIS_POINTS_DENOMINATOR_I128};
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PairStats {
+    pub total_signals: u32,
+    pub successful_signals: u32,
+    pub failed_signals: u32,
+    /// Successful over settled signals, in bps.
+    pub success_rate: u32,
+    /// Mean settled ROI, in bps.
+    pub avg_roi: i128,
+    pub total_volume: i128,
+    /// Sum of settled ROIs, kept so the average stays exact.
+    pub roi_sum: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum PairStatsKey {
+    /// (provider, asset pair)
+    Stats(Address, AssetPair),
+}
+
+pub fn get(env: &Env, provider: &Address, asset_pair: &AssetPair) -> Option<PairStats> {
+    env.storage()
+        .persistent()
+        .get(&PairStatsKey::Stats(provider.clone(), asset_pair.clone()))
+}
+
+/// Fold one settled signal into the provider's stats for its pair.
+pub fn record_close(
+    env: &Env,
+    provider: &Address,
+    asset_pair: &AssetPair,
+    successful: bool,
+    roi: i128,
+    volume: i128,
+) {
+    let mut stats = get(env, provider, asset_pair).unwrap_or_default();
+    stats.total_signals = stats.total_signals.saturating_add(1);
+    if successful {
+        stats.successful_signals = stats.successful_signals.saturating_add(1);
+    } else {
+        stats.failed_signals = stats.failed_signals.saturating_add(1);
+    }
+    stats.success_rate = (stats.successful_signals as i128 * BASIS_POINTS_DENOMINATOR_I128
+        / stats.total_signals as i128) as u32;
+    stats.roi_sum = stats.roi_sum.saturating_add(roi);
+    stats.avg_roi = stats.roi_sum / stats.total_signals as i128;
+    stats.total_volume = stats.total_volume.saturating_add(volume);
+
+    env.storage().persistent().set(
+        &PairStatsKey::Stats(provider.clone(), asset_pair.clone()),
+        &stats,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, Env, String};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn pairs_are_tracked_separately() {
+        let env = Env::default();
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = Address::generate(&env);
+            let pair = |s| AssetPair::parse(&env, &String::from_str(&env, s)).unwrap();
+            let xlm = pair("XLM/USDC");
+            let btc = pair("BTC/USDC");
+
+            record_close(&env, &provider, &xlm, true, 400, 1_000);
+            record_close(&env, &provider, &xlm, true, 300, 500);
+            record_close(&env, &provider, &xlm, false, -600, 2_000);
+            record_close(&env, &provider, &btc, false, -900, 700);
+
+            let stats = get(&env, &provider, &xlm).unwrap();
+            assert_eq!(stats.total_signals, 3);
+            assert_eq!(stats.success_rate, 6_666);
+            assert_eq!(stats.avg_roi, 33);
+            assert_eq!(stats.total_volume, 3_500);
+
+            let stats = get(&env, &provider, &btc).unwrap();
+            assert_eq!(stats.success_rate, 0);
+            assert_eq!(stats.avg_roi, -900);
+            assert!(get(&env, &provider, &pair("ETH/USDC")).is_none());
+        });
+    }
+}
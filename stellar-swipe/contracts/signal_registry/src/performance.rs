@@ -1,11 +1,13 @@
 use crate::types::{ProviderPerformance, Signal, SignalAction, SignalStatus, TradeExecution};
-use stellar_swipe_common::BASIS_POINTS_DENOMINATOR_I128;
+use stellar_swipe_common::{div_round, ratio_bps, RoundingMode, BASIS_POINTS_DENOMINATOR_I128};
 use soroban_sdk::Env;
 
 /// ROI calculation constants
 const SUCCESS_THRESHOLD_BPS: i128 = 200; // 2% in basis points
 const FAILURE_THRESHOLD_BPS: i128 = -500; // -5% in basis points
 const MIN_ROI_BPS: i128 = -BASIS_POINTS_DENOMINATOR_I128; // -100% cap
+/// Starting equity for drawdown tracking (1.0 with 9 decimals).
+pub const EQUITY_SCALE: i128 = 1_000_000_000;
 
 /// Calculate ROI in basis points from entry and exit prices
 ///
@@ -13,13 +15,19 @@ const MIN_ROI_BPS: i128 = -BASIS_POINTS_DENOMINATOR_I128; // -100% cap
 /// * `entry_price` - Entry price for the trade
 /// * `exit_price` - Exit price for the trade
 /// * `action` - Buy or Sell signal action
+/// * `mode` - How fractional basis points are rounded
 ///
 /// # Returns
 /// ROI in basis points (10000 = 100%). Capped at -100% minimum.
 ///
 /// # Panics
 /// Panics if entry_price is 0 (division by zero)
-pub fn calculate_roi(entry_price: i128, exit_price: i128, action: &SignalAction) -> i128 {
+pub fn calculate_roi(
+    entry_price: i128,
+    exit_price: i128,
+    action: &SignalAction,
+    mode: RoundingMode,
+) -> i128 {
     if entry_price == 0 {
         panic!("entry price cannot be zero");
     }
@@ -31,11 +39,7 @@ pub fn calculate_roi(entry_price: i128, exit_price: i128, action: &SignalAction)
     };
 
     // Calculate ROI: (price_diff / entry_price) * 10000
-    let roi = price_diff
-        .checked_mul(BASIS_POINTS_DENOMINATOR_I128)
-        .expect("ROI calculation overflow")
-        .checked_div(entry_price)
-        .expect("division by zero in ROI calculation");
+    let roi = ratio_bps(price_diff, entry_price, mode).expect("ROI calculation overflow");
 
     // Cap negative ROI at -100%
     if roi < MIN_ROI_BPS {
@@ -92,23 +96,38 @@ pub fn update_signal_stats(signal: &mut Signal, trade: &TradeExecution) {
 /// # Returns
 /// The appropriate signal status
 pub fn evaluate_signal_status(signal: &Signal, now: u64) -> SignalStatus {
-    // Check if signal expired with no executions -> Failed
-    if signal.expiry < now && signal.executions == 0 {
-        return SignalStatus::Failed;
-    }
-
-    // If no executions yet, maintain current status
-    if signal.executions == 0 {
-        return signal.status.clone();
-    }
+    let avg_roi = if signal.executions == 0 {
+        None
+    } else {
+        Some(signal.total_roi / (signal.executions as i128))
+    };
+    evaluate_signal_status_for_roi(signal, now, avg_roi)
+}
 
-    // Calculate average ROI
-    let avg_roi = signal.total_roi / (signal.executions as i128);
+/// Evaluate signal status against an attributed ROI.
+///
+/// `attributed_roi` is `None` while the attribution mode has nothing to judge
+/// yet; the signal then keeps its status unless it expired with no executions.
+pub fn evaluate_signal_status_for_roi(
+    signal: &Signal,
+    now: u64,
+    attributed_roi: Option<i128>,
+) -> SignalStatus {
+    let roi = match attributed_roi {
+        Some(roi) => roi,
+        None => {
+            // Check if signal expired with no executions -> Failed
+            if signal.expiry < now && signal.executions == 0 {
+                return SignalStatus::Failed;
+            }
+            return signal.status.clone();
+        }
+    };
 
     // Evaluate against thresholds
-    if avg_roi > SUCCESS_THRESHOLD_BPS {
+    if roi > SUCCESS_THRESHOLD_BPS {
         SignalStatus::Successful
-    } else if avg_roi < FAILURE_THRESHOLD_BPS {
+    } else if roi < FAILURE_THRESHOLD_BPS {
         SignalStatus::Failed
     } else {
         // Maintain Active status if within thresholds
@@ -120,14 +139,15 @@ pub fn evaluate_signal_status(signal: &Signal, now: u64) -> SignalStatus {
 ///
 /// # Arguments
 /// * `signal` - The signal to calculate average ROI for
+/// * `mode` - How fractional basis points are rounded
 ///
 /// # Returns
 /// Average ROI in basis points, or 0 if no executions
-pub fn get_signal_average_roi(signal: &Signal) -> i128 {
+pub fn get_signal_average_roi(signal: &Signal, mode: RoundingMode) -> i128 {
     if signal.executions == 0 {
         0
     } else {
-        signal.total_roi / (signal.executions as i128)
+        div_round(signal.total_roi, signal.executions as i128, mode).unwrap_or(0)
     }
 }
 
@@ -203,6 +223,43 @@ pub fn update_provider_performance(
         .total_volume
         .checked_add(signal_volume)
         .expect("total volume overflow");
+
+    update_drawdown(provider_stats, signal_roi);
+    update_streak(provider_stats, *new_status == SignalStatus::Successful);
+}
+
+/// Extend the current streak if `won` matches it, otherwise start a new one.
+pub fn update_streak(provider_stats: &mut ProviderPerformance, won: bool) {
+    let streak = provider_stats.current_streak;
+    provider_stats.current_streak = match (won, streak > 0) {
+        (true, true) => streak.saturating_add(1),
+        (true, false) => 1,
+        (false, _) if streak < 0 => streak.saturating_sub(1),
+        (false, _) => -1,
+    };
+    if provider_stats.current_streak > 0 {
+        provider_stats.best_streak = provider_stats
+            .best_streak
+            .max(provider_stats.current_streak as u32);
+    }
+}
+
+/// Compound `signal_roi` into the provider's equity curve and widen
+/// `max_drawdown_bps` if the curve is now further below its peak than ever.
+pub fn update_drawdown(provider_stats: &mut ProviderPerformance, signal_roi: i128) {
+    let equity = if provider_stats.peak_equity == 0 {
+        EQUITY_SCALE
+    } else {
+        provider_stats.equity
+    };
+    let growth = BASIS_POINTS_DENOMINATOR_I128 + signal_roi.max(MIN_ROI_BPS);
+    let equity = equity.saturating_mul(growth) / BASIS_POINTS_DENOMINATOR_I128;
+    let peak = provider_stats.peak_equity.max(EQUITY_SCALE).max(equity);
+
+    let drawdown_bps = (peak - equity).saturating_mul(BASIS_POINTS_DENOMINATOR_I128) / peak;
+    provider_stats.equity = equity;
+    provider_stats.peak_equity = peak;
+    provider_stats.max_drawdown_bps = provider_stats.max_drawdown_bps.max(drawdown_bps as u32);
 }
 
 /// Update the running average copier ROI on a position close (Issue #367).
@@ -217,7 +274,13 @@ pub fn update_copier_roi_stats(signal: &mut Signal, roi_bps: i32) {
     let new_avg = if n == 0 {
         roi_bps as i64
     } else {
-        ((signal.avg_copier_roi_bps as i64 * n) + roi_bps as i64) / (n + 1)
+        // Round rather than truncate so the error doesn't compound across updates.
+        div_round(
+            (signal.avg_copier_roi_bps as i128 * n as i128) + roi_bps as i128,
+            (n + 1) as i128,
+            RoundingMode::HalfAwayFromZero,
+        )
+        .unwrap_or(0) as i64
     };
     // Clamp to i32 range (practically bounded by ±10_000 bps = ±100%)
     signal.avg_copier_roi_bps = new_avg.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
@@ -240,7 +303,7 @@ pub fn calculate_benchmark_and_alpha(
         return (None, None);
     }
 
-    let signal_return_bps = signal.total_roi / (signal.executions as i128);
+    let _signal_return_bps = signal.total_roi / (signal.executions as i128);
 
     (None, None)
 }
@@ -250,27 +313,33 @@ mod tests {
     use super::*;
     use soroban_sdk::testutils::Address as _;
 
+    fn xlm_usdc() -> stellar_swipe_common::AssetPair {
+        let env = soroban_sdk::Env::default();
+        stellar_swipe_common::AssetPair::parse(&env, &soroban_sdk::String::from_str(&env, "XLM/USDC"))
+            .unwrap()
+    }
+
     #[test]
     fn test_calculate_roi_buy_profit() {
-        let roi = calculate_roi(100, 105, &SignalAction::Buy);
+        let roi = calculate_roi(100, 105, &SignalAction::Buy, RoundingMode::HalfEven);
         assert_eq!(roi, 500); // 5% = 500 basis points
     }
 
     #[test]
     fn test_calculate_roi_buy_loss() {
-        let roi = calculate_roi(100, 98, &SignalAction::Buy);
+        let roi = calculate_roi(100, 98, &SignalAction::Buy, RoundingMode::HalfEven);
         assert_eq!(roi, -200); // -2% = -200 basis points
     }
 
     #[test]
     fn test_calculate_roi_sell_profit() {
-        let roi = calculate_roi(100, 95, &SignalAction::Sell);
+        let roi = calculate_roi(100, 95, &SignalAction::Sell, RoundingMode::HalfEven);
         assert_eq!(roi, 500); // 5% profit on sell = 500 basis points
     }
 
     #[test]
     fn test_calculate_roi_capped_at_negative_100_percent() {
-        let roi = calculate_roi(100, 0, &SignalAction::Buy);
+        let roi = calculate_roi(100, 0, &SignalAction::Buy, RoundingMode::HalfEven);
         assert_eq!(roi, -10000); // Capped at -100%
     }
 
@@ -279,7 +348,7 @@ mod tests {
         let signal = Signal {
             id: 1,
             provider: soroban_sdk::Address::generate(&soroban_sdk::Env::default()),
-            asset_pair: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "XLM/USDC"),
+            asset_pair: xlm_usdc(),
             action: SignalAction::Buy,
             price: 100,
             rationale: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "Test"),
@@ -304,6 +373,9 @@ mod tests {
             warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
         };
 
         let status = evaluate_signal_status(&signal, 2001);
@@ -316,7 +388,7 @@ mod tests {
         Signal {
             id: 1,
             provider: soroban_sdk::Address::generate(&soroban_sdk::Env::default()),
-            asset_pair: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "XLM/USDC"),
+            asset_pair: xlm_usdc(),
             action: SignalAction::Buy,
             price: 100,
             rationale: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "Test"),
@@ -341,6 +413,9 @@ mod tests {
             warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
         }
     }
 
@@ -399,7 +474,7 @@ mod tests {
         let signal = Signal {
             id: 1,
             provider: soroban_sdk::Address::generate(&soroban_sdk::Env::default()),
-            asset_pair: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "XLM/USDC"),
+            asset_pair: xlm_usdc(),
             action: SignalAction::Buy,
             price: 100,
             rationale: soroban_sdk::String::from_str(&soroban_sdk::Env::default(), "Test"),
@@ -424,8 +499,69 @@ mod tests {
             warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
         };
 
-        assert_eq!(get_signal_average_roi(&signal), 0);
+        assert_eq!(get_signal_average_roi(&signal, RoundingMode::HalfEven), 0);
+    }
+
+    #[test]
+    fn test_max_drawdown_tracks_worst_losing_streak() {
+        fn settle(stats: &mut ProviderPerformance, roi: i128) {
+            let status = if roi >= 0 {
+                SignalStatus::Successful
+            } else {
+                SignalStatus::Failed
+            };
+            update_provider_performance(stats, &SignalStatus::Active, &status, roi, 0);
+        }
+        let mut stats = ProviderPerformance::default();
+        // +50%, then -20% twice: 1.5 -> 1.2 -> 0.96, a 36% drawdown.
+        settle(&mut stats, 5_000);
+        settle(&mut stats, -2_000);
+        settle(&mut stats, -2_000);
+        // Recovering to a new peak keeps the worst drawdown on record.
+        settle(&mut stats, 10_000);
+        assert_eq!(stats.max_drawdown_bps, 3_600);
+        assert_eq!(stats.peak_equity, stats.equity);
+        assert_eq!(stats.equity, 1_920_000_000);
+        // A later, shallower dip doesn't lower it.
+        settle(&mut stats, -1_000);
+        assert_eq!(stats.max_drawdown_bps, 3_600);
+    }
+
+    #[test]
+    fn test_streaks_follow_settled_outcomes() {
+        let mut stats = ProviderPerformance::default();
+        let mut longest_losing = 0;
+        for won in [true, true, true, false, false, true] {
+            let status = if won {
+                SignalStatus::Successful
+            } else {
+                SignalStatus::Failed
+            };
+            update_provider_performance(&mut stats, &SignalStatus::Active, &status, 0, 0);
+            longest_losing = longest_losing.min(stats.current_streak);
+        }
+        assert_eq!(longest_losing, -2);
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(stats.best_streak, 3);
+    }
+
+    #[test]
+    fn test_calculate_roi_rounding_modes() {
+        let buy = |entry, exit, mode| calculate_roi(entry, exit, &SignalAction::Buy, mode);
+
+        // 2/3 bp gain: truncation drops it, banker's rounding keeps it.
+        assert_eq!(buy(30_000, 30_002, RoundingMode::TowardZero), 0);
+        assert_eq!(buy(30_000, 30_002, RoundingMode::HalfEven), 1);
+        // Exactly half a bp goes to the even neighbour.
+        assert_eq!(buy(20_000, 20_001, RoundingMode::HalfEven), 0);
+        assert_eq!(buy(20_000, 20_003, RoundingMode::HalfEven), 2);
+        assert_eq!(buy(20_000, 20_001, RoundingMode::HalfAwayFromZero), 1);
+        // Losses round symmetrically instead of toward zero.
+        assert_eq!(buy(30_000, 29_998, RoundingMode::HalfEven), -1);
     }
 }
@@ -0,0 +1,90 @@
+//! Premium signals.
+//!
+//! A premium signal's call (action, price, rationale and targets) is served
+//! only to the provider and to subscribers, through `get_premium_content`.
+//! Everything needed to hold the provider accountable (pair, timing, status
+//! and execution record) stays public through `get_signal_public`. The gate
+//! applies to the contract interface; ledger storage itself is public. The
+//! open getters (`get_signal` and the signal lists) serve premium signals
+//! redacted, with price, rationale and targets cleared; the full signal goes
+//! only to a viewer who proves access through `get_signal_for_viewer`.
+//!
+//! Signals in the PREMIUM category are always premium; a provider can also
+//! mark any of their Active signals premium.
+
+use soroban_sdk::{contracttype, Address, Env, String};
+use stellar_swipe_common::AssetPair;
+
+use crate::categories::SignalCategory;
+use crate::types::{Signal, SignalAction, SignalStatus};
+
+/// What everyone can see of a signal.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignalPublicView {
+    pub id: u64,
+    pub provider: Address,
+    pub asset_pair: AssetPair,
+    pub category: SignalCategory,
+    pub timestamp: u64,
+    pub expiry: u64,
+    pub status: SignalStatus,
+    pub executions: u32,
+    pub successful_executions: u32,
+    pub premium: bool,
+}
+
+/// The gated part of a premium signal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PremiumContent {
+    pub signal_id: u64,
+    pub action: SignalAction,
+    pub price: i128,
+    pub rationale: String,
+    pub stop_loss: Option<i128>,
+    pub take_profit: Option<i128>,
+}
+
+pub fn is_premium(signal: &Signal) -> bool {
+    signal.premium || signal.category == SignalCategory::PREMIUM
+}
+
+pub fn public_view(signal: &Signal) -> SignalPublicView {
+    SignalPublicView {
+        id: signal.id,
+        provider: signal.provider.clone(),
+        asset_pair: signal.asset_pair.clone(),
+        category: signal.category.clone(),
+        timestamp: signal.timestamp,
+        expiry: signal.expiry,
+        status: signal.status.clone(),
+        executions: signal.executions,
+        successful_executions: signal.successful_executions,
+        premium: is_premium(signal),
+    }
+}
+
+pub fn content(signal: &Signal) -> PremiumContent {
+    PremiumContent {
+        signal_id: signal.id,
+        action: signal.action.clone(),
+        price: signal.price,
+        rationale: signal.rationale.clone(),
+        stop_loss: signal.stop_loss,
+        take_profit: signal.take_profit,
+    }
+}
+
+/// `signal` as the open getters serve it: a premium signal loses its gated
+/// fields. The direction stays, as a signal always carries one.
+pub fn redacted(env: &Env, mut signal: Signal) -> Signal {
+    if is_premium(&signal) {
+        signal.price = 0;
+        signal.rationale = String::from_str(env, "");
+        signal.rationale_hash = String::from_str(env, "");
+        signal.stop_loss = None;
+        signal.take_profit = None;
+    }
+    signal
+}
@@ -233,7 +233,11 @@ mod feed_tests {
     use super::*;
     use core::assert_eq;
     use soroban_sdk::testutils::Address as _;
-    use soroban_sdk::String;
+    use soroban_sdk::{contract, String};
+    use stellar_swipe_common::AssetPair;
+
+    #[contract]
+    struct TestContract;
 
     /// Historical implementation (pre-optimization): per-iter `keys()` + bubble sort. Used
     /// only to verify identical `SignalSummary` output to [`super::get_active_signals`].
@@ -324,7 +328,7 @@ mod feed_tests {
             let s = Signal {
                 id,
                 provider: p.clone(),
-                asset_pair: String::from_str(env, "XLM-USDC"),
+                asset_pair: AssetPair::parse(env, &String::from_str(env, "XLM/USDC")).unwrap(),
                 action: if id % 2 == 0 {
                     SignalAction::Buy
                 } else {
@@ -353,6 +357,9 @@ mod feed_tests {
                 warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
             };
             m.set(id, s);
         }
@@ -378,27 +385,30 @@ mod feed_tests {
     #[test]
     fn get_active_signals_matches_bubble_historical_all_sorts() {
         let env = Env::default();
-        // Many param combinations + historical O(n^2) reference can exceed default test budget.
-        env.cost_estimate().budget().reset_unlimited();
-        let map = make_test_map(&env, 50);
-        for sort in [
-            SortOption::RecencyDesc,
-            SortOption::PerformanceDesc,
-            SortOption::VolumeDesc,
-        ] {
-            for off in [0u32, 3, 20] {
-                for lim in [0u32, 10, 25, 100] {
-                    let a = get_active_signals(
-                        &env, &map, None, off, lim, sort.clone(), None,
-                    );
-                    let b = get_active_signals_bubble_historical(
-                        &env, &map, None, off, lim, &sort, None,
-                    );
-                    assert_eq!(a.len(), b.len());
-                    assert_summaries_eq(&a, &b);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            // Many param combinations + historical O(n^2) reference can exceed default test budget.
+            env.cost_estimate().budget().reset_unlimited();
+            let map = make_test_map(&env, 50);
+            for sort in [
+                SortOption::RecencyDesc,
+                SortOption::PerformanceDesc,
+                SortOption::VolumeDesc,
+            ] {
+                for off in [0u32, 3, 20] {
+                    for lim in [0u32, 10, 25, 100] {
+                        let a = get_active_signals(
+                            &env, &map, None, off, lim, sort.clone(), None,
+                        );
+                        let b = get_active_signals_bubble_historical(
+                            &env, &map, None, off, lim, &sort, None,
+                        );
+                        assert_eq!(a.len(), b.len());
+                        assert_summaries_eq(&a, &b);
+                    }
                 }
             }
-        }
+        });
     }
 
     /// `cost_estimate().budget().cpu_instruction_cost()` (see module header for before/after).
@@ -407,22 +417,25 @@ mod feed_tests {
         const DEFAULT_TX_CPU: u64 = 100_000_000;
         const HALF: u64 = DEFAULT_TX_CPU / 2;
         let env = Env::default();
-        let map = make_test_map(&env, 50);
-        env.cost_estimate().budget().reset_tracker();
-        let _ = get_active_signals(
-            &env,
-            &map,
-            None,
-            0,
-            30,
-            SortOption::RecencyDesc,
-            None,
-        );
-        let after = env.cost_estimate().budget().cpu_instruction_cost();
-        // Re-run with `cargo test get_active_signals_stays_under_half -- --nocapture` to log for PRs.
-        assert!(
-            after < HALF,
-            "get_active_signals(50 actives) used {after} insns, expected < {HALF} (50% of {DEFAULT_TX_CPU})"
-        );
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let map = make_test_map(&env, 50);
+            env.cost_estimate().budget().reset_tracker();
+            let _ = get_active_signals(
+                &env,
+                &map,
+                None,
+                0,
+                30,
+                SortOption::RecencyDesc,
+                None,
+            );
+            let after = env.cost_estimate().budget().cpu_instruction_cost();
+            // Re-run with `cargo test get_active_signals_stays_under_half -- --nocapture` to log for PRs.
+            assert!(
+                after < HALF,
+                "get_active_signals(50 actives) used {after} insns, expected < {HALF} (50% of {DEFAULT_TX_CPU})"
+            );
+        });
     }
 }
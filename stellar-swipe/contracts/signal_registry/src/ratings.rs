@@ -0,0 +1,190 @@
+//! Executor ratings of signals.
+//!
+//! Anyone with a recorded execution of a signal can rate it 1–5 and attach
+//! the hash of a short comment (the text itself lives off-chain). One rating
+//! per executor per signal; rating again replaces the earlier one. Running
+//! totals are kept per signal and per provider so averages are O(1) reads.
+//! Who executed what is indexed by (signal, executor) as trades are
+//! recorded, so checking eligibility doesn't scan every trade.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map};
+
+use crate::errors::RatingError;
+use crate::signal_index::BackfillProgress;
+use crate::types::TradeExecution;
+
+pub const MIN_STARS: u32 = 1;
+pub const MAX_STARS: u32 = 5;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rating {
+    pub stars: u32,
+    /// Hash of the comment text stored off-chain.
+    pub comment_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RatingSummary {
+    pub count: u32,
+    pub total_stars: u64,
+    /// Average stars ×100 (e.g. 450 = 4.5 stars); 0 when unrated.
+    pub average_x100: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum RatingKey {
+    /// (signal_id, executor)
+    Rating(u64, Address),
+    SignalSummary(u64),
+    ProviderSummary(Address),
+    /// (signal_id, executor): set once the executor has a recorded trade.
+    Executed(u64, Address),
+}
+
+/// Note that `executor` has a recorded trade on `signal_id`.
+pub fn record_executor(env: &Env, signal_id: u64, executor: &Address) {
+    env.storage()
+        .persistent()
+        .set(&RatingKey::Executed(signal_id, executor.clone()), &true);
+}
+
+pub fn has_executed(env: &Env, signal_id: u64, executor: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&RatingKey::Executed(signal_id, executor.clone()))
+}
+
+/// Index the executors of trades `cursor..cursor + limit` (trade ids start
+/// at 1) out of `total`.
+pub fn backfill_executors(
+    env: &Env,
+    trades: &Map<u64, TradeExecution>,
+    total: u64,
+    cursor: u64,
+    limit: u32,
+) -> BackfillProgress {
+    let start = cursor.max(1);
+    let end = start
+        .saturating_add(limit as u64)
+        .min(total.saturating_add(1))
+        .max(start);
+
+    let mut indexed = 0u32;
+    for trade_id in start..end {
+        if let Some(trade) = trades.get(trade_id) {
+            if !has_executed(env, trade.signal_id, &trade.executor) {
+                record_executor(env, trade.signal_id, &trade.executor);
+                indexed += 1;
+            }
+        }
+    }
+
+    BackfillProgress {
+        next_cursor: end,
+        indexed,
+        total,
+        done: end > total,
+    }
+}
+
+pub fn get_rating(env: &Env, signal_id: u64, executor: &Address) -> Option<Rating> {
+    env.storage()
+        .persistent()
+        .get(&RatingKey::Rating(signal_id, executor.clone()))
+}
+
+fn get_summary(env: &Env, key: &RatingKey) -> RatingSummary {
+    env.storage().persistent().get(key).unwrap_or_default()
+}
+
+pub fn get_signal_summary(env: &Env, signal_id: u64) -> RatingSummary {
+    get_summary(env, &RatingKey::SignalSummary(signal_id))
+}
+
+pub fn get_provider_summary(env: &Env, provider: &Address) -> RatingSummary {
+    get_summary(env, &RatingKey::ProviderSummary(provider.clone()))
+}
+
+/// Add a new rating to a summary, or swap out the one it replaces.
+fn fold(env: &Env, key: RatingKey, stars: u32, replaced: Option<u32>) {
+    let mut summary = get_summary(env, &key);
+    match replaced {
+        Some(old) => summary.total_stars = summary.total_stars - old as u64 + stars as u64,
+        None => {
+            summary.count += 1;
+            summary.total_stars += stars as u64;
+        }
+    }
+    summary.average_x100 = (summary.total_stars * 100 / summary.count as u64) as u32;
+    env.storage().persistent().set(&key, &summary);
+}
+
+/// Record `executor`'s rating of a signal by `provider`. The caller checks
+/// that the executor actually executed the signal.
+pub fn rate(
+    env: &Env,
+    signal_id: u64,
+    provider: &Address,
+    executor: &Address,
+    stars: u32,
+    comment_hash: BytesN<32>,
+) -> Result<Rating, RatingError> {
+    if !(MIN_STARS..=MAX_STARS).contains(&stars) {
+        return Err(RatingError::InvalidRating);
+    }
+    let replaced = get_rating(env, signal_id, executor).map(|r| r.stars);
+    let rating = Rating {
+        stars,
+        comment_hash,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&RatingKey::Rating(signal_id, executor.clone()), &rating);
+    fold(env, RatingKey::SignalSummary(signal_id), stars, replaced);
+    fold(env, RatingKey::ProviderSummary(provider.clone()), stars, replaced);
+    Ok(rating)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn ratings_aggregate_and_replace() {
+        let env = Env::default();
+        let contract_id = env.register(TestContract, ());
+        let provider = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let hash = BytesN::from_array(&env, &[7; 32]);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                rate(&env, 1, &provider, &alice, 6, hash.clone()),
+                Err(RatingError::InvalidRating)
+            );
+            rate(&env, 1, &provider, &alice, 5, hash.clone()).unwrap();
+            rate(&env, 1, &provider, &bob, 4, hash.clone()).unwrap();
+            rate(&env, 2, &provider, &bob, 1, hash.clone()).unwrap();
+            assert_eq!(get_signal_summary(&env, 1).average_x100, 450);
+            assert_eq!(get_provider_summary(&env, &provider).average_x100, 333);
+
+            // Alice changes her mind; the count stays the same.
+            rate(&env, 1, &provider, &alice, 2, hash.clone()).unwrap();
+            let summary = get_signal_summary(&env, 1);
+            assert_eq!(summary.count, 2);
+            assert_eq!(summary.average_x100, 300);
+            assert_eq!(get_rating(&env, 1, &alice).unwrap().stars, 2);
+        });
+    }
+}
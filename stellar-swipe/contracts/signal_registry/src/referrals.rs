@@ -0,0 +1,196 @@
+//! Referral program.
+//!
+//! A user may name the address that referred them once; it can't be changed
+//! afterwards. From then on a slice of every trade fee the user pays
+//! (`share_bps` of the fee, never more than the platform's share) is credited
+//! to the referrer instead of the platform, and the referrer claims it like a
+//! provider claims fees.
+
+use soroban_sdk::{contracttype, Address, Env, Map};
+
+use crate::errors::ReferralError;
+use crate::fees;
+use crate::types::Asset;
+
+/// Share of each referred trade fee credited to the referrer (10%).
+pub const DEFAULT_REFERRAL_SHARE_BPS: u32 = 1_000;
+const BPS_DENOMINATOR: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReferralStats {
+    /// Users who named this address as their referrer.
+    pub referred_users: u32,
+    /// Fees paid by referred users that earned this address a reward.
+    pub rewarded_trades: u32,
+    /// Everything ever credited, per asset, claimed or not.
+    pub lifetime_rewards: Map<Asset, i128>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum ReferralKey {
+    Referrer(Address),
+    Stats(Address),
+    /// Unclaimed rewards, per asset.
+    Balance(Address),
+    ShareBps,
+}
+
+pub fn get_referrer(env: &Env, user: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&ReferralKey::Referrer(user.clone()))
+}
+
+pub fn get_stats(env: &Env, address: &Address) -> ReferralStats {
+    env.storage()
+        .persistent()
+        .get(&ReferralKey::Stats(address.clone()))
+        .unwrap_or(ReferralStats {
+            referred_users: 0,
+            rewarded_trades: 0,
+            lifetime_rewards: Map::new(env),
+        })
+}
+
+fn save_stats(env: &Env, address: &Address, stats: &ReferralStats) {
+    env.storage()
+        .persistent()
+        .set(&ReferralKey::Stats(address.clone()), stats);
+}
+
+pub fn get_balance(env: &Env, referrer: &Address) -> Map<Asset, i128> {
+    env.storage()
+        .persistent()
+        .get(&ReferralKey::Balance(referrer.clone()))
+        .unwrap_or(Map::new(env))
+}
+
+pub fn get_share_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ReferralKey::ShareBps)
+        .unwrap_or(DEFAULT_REFERRAL_SHARE_BPS)
+}
+
+pub fn set_share_bps(env: &Env, share_bps: u32) -> Result<(), ReferralError> {
+    if share_bps > BPS_DENOMINATOR {
+        return Err(ReferralError::InvalidShare);
+    }
+    env.storage().instance().set(&ReferralKey::ShareBps, &share_bps);
+    Ok(())
+}
+
+/// Record `referrer` as the one who referred `user`. Set once; two addresses
+/// can't refer each other.
+pub fn set_referrer(env: &Env, user: &Address, referrer: &Address) -> Result<(), ReferralError> {
+    if user == referrer {
+        return Err(ReferralError::SelfReferral);
+    }
+    if get_referrer(env, user).is_some() {
+        return Err(ReferralError::ReferrerAlreadySet);
+    }
+    if get_referrer(env, referrer).as_ref() == Some(user) {
+        return Err(ReferralError::ReferralCycle);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&ReferralKey::Referrer(user.clone()), referrer);
+    let mut stats = get_stats(env, referrer);
+    stats.referred_users += 1;
+    save_stats(env, referrer, &stats);
+    Ok(())
+}
+
+/// Credit the referrer of `payer`, if any, with their slice of a `total_fee`
+/// fee, capped at `platform_fee`. Returns the referrer and the amount, which
+/// the caller takes out of the platform's share.
+pub fn reward(
+    env: &Env,
+    payer: &Address,
+    asset: &Asset,
+    total_fee: i128,
+    platform_fee: i128,
+) -> Option<(Address, i128)> {
+    let referrer = get_referrer(env, payer)?;
+    let amount = (total_fee * get_share_bps(env) as i128 / BPS_DENOMINATOR as i128).min(platform_fee);
+    if amount <= 0 {
+        return None;
+    }
+
+    let mut balance = get_balance(env, &referrer);
+    balance.set(asset.clone(), balance.get(asset.clone()).unwrap_or(0) + amount);
+    env.storage()
+        .persistent()
+        .set(&ReferralKey::Balance(referrer.clone()), &balance);
+
+    let mut stats = get_stats(env, &referrer);
+    stats.rewarded_trades += 1;
+    let lifetime = stats.lifetime_rewards.get(asset.clone()).unwrap_or(0);
+    stats.lifetime_rewards.set(asset.clone(), lifetime + amount);
+    save_stats(env, &referrer, &stats);
+    Some((referrer, amount))
+}
+
+/// Pay `referrer` everything they have been credited.
+pub fn claim(env: &Env, referrer: &Address) -> Result<Map<Asset, i128>, ReferralError> {
+    let claimed = fees::pay_out(env, &get_balance(env, referrer), referrer);
+    if claimed.is_empty() {
+        return Err(ReferralError::NothingToClaim);
+    }
+    env.storage()
+        .persistent()
+        .remove(&ReferralKey::Balance(referrer.clone()));
+    Ok(claimed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, Env, Symbol};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn referrer_is_fixed_and_rewarded() {
+        let env = Env::default();
+        let contract_id = env.register(TestContract, ());
+        let user = Address::generate(&env);
+        let referrer = Address::generate(&env);
+        let other = Address::generate(&env);
+        let asset = Asset {
+            symbol: Symbol::new(&env, "XLM"),
+            contract: Address::generate(&env),
+        };
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(set_referrer(&env, &user, &user), Err(ReferralError::SelfReferral));
+            assert_eq!(reward(&env, &user, &asset, 1_000, 700), None);
+
+            set_referrer(&env, &user, &referrer).unwrap();
+            assert_eq!(
+                set_referrer(&env, &user, &other),
+                Err(ReferralError::ReferrerAlreadySet)
+            );
+            assert_eq!(
+                set_referrer(&env, &referrer, &user),
+                Err(ReferralError::ReferralCycle)
+            );
+
+            assert_eq!(reward(&env, &user, &asset, 1_000, 700), Some((referrer.clone(), 100)));
+            // Never more than the platform's share.
+            set_share_bps(&env, 9_000).unwrap();
+            assert_eq!(reward(&env, &user, &asset, 1_000, 700), Some((referrer.clone(), 700)));
+
+            let stats = get_stats(&env, &referrer);
+            assert_eq!(stats.referred_users, 1);
+            assert_eq!(stats.rewarded_trades, 2);
+            assert_eq!(stats.lifetime_rewards.get(asset.clone()), Some(800));
+            assert_eq!(get_balance(&env, &referrer).get(asset.clone()), Some(800));
+        });
+    }
+}
@@ -0,0 +1,92 @@
+//! Provider registration.
+//!
+//! A provider registers a display name and a URI for their bio so UIs can
+//! show who is behind a signal instead of a bare address. Registering again
+//! updates both fields. Once the admin turns registration on, `create_signal`
+//! only accepts signals from registered providers; it is off by default so
+//! providers active before registration existed aren't locked out while they
+//! register.
+//!
+//! This is separate from `providers::ProviderProfile`, which holds hashes of
+//! off-chain content alongside mirrored performance figures.
+
+use soroban_sdk::{contracttype, Address, Env, String};
+
+use crate::errors::RegistrationError;
+
+pub const MAX_DISPLAY_NAME_LEN: u32 = 64;
+pub const MAX_BIO_URI_LEN: u32 = 256;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderRegistration {
+    pub provider: Address,
+    pub display_name: String,
+    /// Where the provider's bio is published (e.g. an IPFS or HTTPS URI).
+    pub bio_uri: String,
+    pub registered_at: u64,
+    pub updated_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum RegistrationKey {
+    Registration(Address),
+    /// Whether `create_signal` requires registration.
+    Required,
+}
+
+pub fn register(
+    env: &Env,
+    provider: &Address,
+    display_name: String,
+    bio_uri: String,
+) -> Result<ProviderRegistration, RegistrationError> {
+    if display_name.len() == 0 || display_name.len() > MAX_DISPLAY_NAME_LEN {
+        return Err(RegistrationError::InvalidDisplayName);
+    }
+    if bio_uri.len() > MAX_BIO_URI_LEN {
+        return Err(RegistrationError::InvalidBioUri);
+    }
+
+    let now = env.ledger().timestamp();
+    let registered_at = get_registration(env, provider)
+        .map(|p| p.registered_at)
+        .unwrap_or(now);
+    let registration = ProviderRegistration {
+        provider: provider.clone(),
+        display_name,
+        bio_uri,
+        registered_at,
+        updated_at: now,
+    };
+    env.storage()
+        .persistent()
+        .set(&RegistrationKey::Registration(provider.clone()), &registration);
+    Ok(registration)
+}
+
+pub fn get_registration(env: &Env, provider: &Address) -> Option<ProviderRegistration> {
+    env.storage()
+        .persistent()
+        .get(&RegistrationKey::Registration(provider.clone()))
+}
+
+pub fn is_registered(env: &Env, provider: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&RegistrationKey::Registration(provider.clone()))
+}
+
+pub fn is_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&RegistrationKey::Required)
+        .unwrap_or(false)
+}
+
+pub fn set_required(env: &Env, required: bool) {
+    env.storage()
+        .instance()
+        .set(&RegistrationKey::Required, &required);
+}
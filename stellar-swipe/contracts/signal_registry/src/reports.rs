@@ -5,7 +5,7 @@ const SECONDS_PER_MONTH: u64 = 30 * 24 * 60 * 60;
 
 /// Get provider monthly performance report (Issue #421)
 pub fn get_provider_monthly_report(
-    env: &Env,
+    _env: &Env,
     signals_map: &Map<u64, Signal>,
     provider: &Address,
     month: u32,
@@ -30,42 +30,40 @@ pub fn get_provider_monthly_report(
     let month_start = calculate_month_start(month, year);
     let month_end = month_start + SECONDS_PER_MONTH;
 
-    for i in 0..signals_map.len() {
-        if let Some(signal) = signals_map.get(i as u64) {
-            if signal.provider != *provider {
-                continue;
-            }
+    for (_, signal) in signals_map.iter() {
+        if signal.provider != *provider {
+            continue;
+        }
 
-            if signal.timestamp >= month_start && signal.timestamp < month_end {
-                report.signals_submitted += 1;
-                report.total_adopters = report
-                    .total_adopters
-                    .saturating_add(signal.adoption_count);
-
-                if matches!(
-                    signal.status,
-                    SignalStatus::Successful | SignalStatus::Failed
-                ) {
-                    report.signals_closed += 1;
-
-                    if signal.status == SignalStatus::Successful {
-                        if signal.total_roi > best_return {
-                            best_return = signal.total_roi;
-                            best_id = Some(signal.id);
-                        }
-                        if signal.total_roi < worst_return {
-                            worst_return = signal.total_roi;
-                            worst_id = Some(signal.id);
-                        }
-                    } else {
-                        if signal.total_roi > best_return {
-                            best_return = signal.total_roi;
-                            best_id = Some(signal.id);
-                        }
-                        if signal.total_roi < worst_return {
-                            worst_return = signal.total_roi;
-                            worst_id = Some(signal.id);
-                        }
+        if signal.timestamp >= month_start && signal.timestamp < month_end {
+            report.signals_submitted += 1;
+            report.total_adopters = report
+                .total_adopters
+                .saturating_add(signal.adoption_count);
+
+            if matches!(
+                signal.status,
+                SignalStatus::Successful | SignalStatus::Failed
+            ) {
+                report.signals_closed += 1;
+
+                if signal.status == SignalStatus::Successful {
+                    if signal.total_roi > best_return {
+                        best_return = signal.total_roi;
+                        best_id = Some(signal.id);
+                    }
+                    if signal.total_roi < worst_return {
+                        worst_return = signal.total_roi;
+                        worst_id = Some(signal.id);
+                    }
+                } else {
+                    if signal.total_roi > best_return {
+                        best_return = signal.total_roi;
+                        best_id = Some(signal.id);
+                    }
+                    if signal.total_roi < worst_return {
+                        worst_return = signal.total_roi;
+                        worst_id = Some(signal.id);
                     }
                 }
             }
@@ -108,6 +106,7 @@ mod tests {
     use crate::categories::{RiskLevel, SignalCategory};
     use crate::types::{SignalAction, Signal};
     use soroban_sdk::{testutils::Address as _, Address, Env, Map, String};
+    use stellar_swipe_common::AssetPair;
 
     fn create_test_signal(
         env: &Env,
@@ -120,13 +119,13 @@ mod tests {
         Signal {
             id,
             provider,
-            asset_pair: String::from_str(env, "XLM/USDC"),
+            asset_pair: AssetPair::parse(env, &String::from_str(env, "XLM/USDC")).unwrap(),
             action: SignalAction::Buy,
             price: 100_000,
             rationale: String::from_str(env, "Test"),
             timestamp,
             expiry: timestamp + 86_400,
-            status,
+            status: status.clone(),
             executions: 1,
             successful_executions: if status == SignalStatus::Successful { 1 } else { 0 },
             total_volume: 1000,
@@ -145,6 +144,9 @@ mod tests {
             warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
         }
     }
 
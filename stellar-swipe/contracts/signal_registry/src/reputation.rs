@@ -456,6 +456,7 @@ mod tests {
             success_rate: 6667, // 66.67%
             avg_return: 500,
             total_volume: 1000000,
+            ..Default::default()
         };
 
         let score_details = calculate_trust_score(&env, &provider, &performance, &None);
@@ -0,0 +1,178 @@
+//! Risk-adjusted provider returns.
+//!
+//! The ROI of every settled signal (Successful or Failed) is folded into
+//! running count / sum / sum-of-squares totals per provider, from which the
+//! mean ROI and its standard deviation are derived without replaying
+//! history. Sharpe here is mean over standard deviation of signal ROIs
+//! (no risk-free rate), scaled by 100. Sortino swaps the standard deviation
+//! for the downside deviation (losses only), and profit factor is gross
+//! winning ROI over gross losing ROI.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Floor on the standard deviation, in bps, so a provider with identical
+/// results doesn't get an unbounded Sharpe.
+pub const MIN_STD_DEV_BPS: i128 = 1;
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoiStats {
+    pub count: u32,
+    /// Sum of settled ROIs, in bps.
+    pub sum: i128,
+    /// Sum of squared settled ROIs, in bps².
+    pub sum_sq: i128,
+    /// Sum of squared negative ROIs, in bps².
+    pub downside_sq: i128,
+    /// Sum of positive ROIs, in bps.
+    pub gross_wins: i128,
+    /// Sum of the magnitudes of negative ROIs, in bps.
+    pub gross_losses: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum RiskKey {
+    RoiStats(Address),
+}
+
+pub fn get_roi_stats(env: &Env, provider: &Address) -> RoiStats {
+    env.storage()
+        .persistent()
+        .get(&RiskKey::RoiStats(provider.clone()))
+        .unwrap_or_default()
+}
+
+/// Fold one settled signal's ROI into `stats`.
+pub fn fold(stats: &mut RoiStats, roi: i128) {
+    let squared = roi.saturating_mul(roi);
+    stats.count = stats.count.saturating_add(1);
+    stats.sum = stats.sum.saturating_add(roi);
+    stats.sum_sq = stats.sum_sq.saturating_add(squared);
+    if roi < 0 {
+        stats.downside_sq = stats.downside_sq.saturating_add(squared);
+        stats.gross_losses = stats.gross_losses.saturating_add(-roi);
+    } else {
+        stats.gross_wins = stats.gross_wins.saturating_add(roi);
+    }
+}
+
+/// Fold one settled signal's ROI into the provider's totals and return them.
+pub fn record_roi(env: &Env, provider: &Address, roi: i128) -> RoiStats {
+    let mut stats = get_roi_stats(env, provider);
+    fold(&mut stats, roi);
+    env.storage()
+        .persistent()
+        .set(&RiskKey::RoiStats(provider.clone()), &stats);
+    stats
+}
+
+/// Mean settled ROI in bps; 0 without settled signals.
+pub fn mean_roi(stats: &RoiStats) -> i128 {
+    if stats.count == 0 {
+        return 0;
+    }
+    stats.sum / stats.count as i128
+}
+
+/// Population standard deviation of settled ROIs, in bps.
+pub fn std_dev(stats: &RoiStats) -> i128 {
+    if stats.count < 2 {
+        return 0;
+    }
+    let n = stats.count as i128;
+    let variance = (stats.sum_sq - stats.sum.saturating_mul(stats.sum) / n) / n;
+    isqrt(variance)
+}
+
+/// Mean over standard deviation, ×100 (150 = 1.50); 0 with fewer than two
+/// settled signals.
+pub fn sharpe_x100(stats: &RoiStats) -> i128 {
+    if stats.count < 2 {
+        return 0;
+    }
+    mean_roi(stats).saturating_mul(100) / std_dev(stats).max(MIN_STD_DEV_BPS)
+}
+
+/// Root mean square of losses (gains count as zero), in bps.
+pub fn downside_dev(stats: &RoiStats) -> i128 {
+    if stats.count == 0 {
+        return 0;
+    }
+    isqrt(stats.downside_sq / stats.count as i128)
+}
+
+/// Mean over downside deviation, ×100; 0 with fewer than two settled
+/// signals. A provider without losses is divided by MIN_STD_DEV_BPS.
+pub fn sortino_x100(stats: &RoiStats) -> i128 {
+    if stats.count < 2 {
+        return 0;
+    }
+    mean_roi(stats).saturating_mul(100) / downside_dev(stats).max(MIN_STD_DEV_BPS)
+}
+
+/// Gross winning ROI over gross losing ROI, ×100 (250 = 2.5); losses are
+/// floored at MIN_STD_DEV_BPS so a provider without losses stays finite.
+pub fn profit_factor_x100(stats: &RoiStats) -> i128 {
+    stats.gross_wins.saturating_mul(100) / stats.gross_losses.max(MIN_STD_DEV_BPS)
+}
+
+/// Integer square root (Babylonian method, no_std compatible).
+fn isqrt(n: i128) -> i128 {
+    if n <= 0 {
+        return 0;
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats_of(rois: &[i128]) -> RoiStats {
+        let mut stats = RoiStats::default();
+        for roi in rois {
+            fold(&mut stats, *roi);
+        }
+        stats
+    }
+
+    #[test]
+    fn steady_winner_beats_lottery_player() {
+        // Many tiny wins and a few huge losses: positive hit rate, negative mean.
+        let lottery = stats_of(&[50, 50, 50, 50, 50, 50, 50, 50, -2_000, -2_000]);
+        let steady = stats_of(&[300, 200, 400, 250, 350]);
+
+        assert_eq!(mean_roi(&lottery), -360);
+        assert!(sharpe_x100(&lottery) < 0);
+        assert_eq!(mean_roi(&steady), 300);
+        assert_eq!(std_dev(&steady), 70);
+        assert_eq!(sharpe_x100(&steady), 428);
+    }
+
+    #[test]
+    fn sortino_and_profit_factor_only_penalise_losses() {
+        let stats = stats_of(&[400, -200, 300, -100]);
+        assert_eq!(downside_dev(&stats), 111);
+        assert_eq!(sortino_x100(&stats), 90);
+        assert_eq!(profit_factor_x100(&stats), 233);
+
+        let no_losses = stats_of(&[100, 300]);
+        assert_eq!(sortino_x100(&no_losses), 20_000);
+        assert_eq!(profit_factor_x100(&no_losses), 40_000);
+    }
+
+    #[test]
+    fn identical_results_use_the_std_dev_floor() {
+        let flat = stats_of(&[100, 100]);
+        assert_eq!(std_dev(&flat), 0);
+        assert_eq!(sharpe_x100(&flat), 10_000);
+        assert_eq!(sharpe_x100(&stats_of(&[100])), 0);
+    }
+}
@@ -1,6 +1,6 @@
 use soroban_sdk::{Env, Map};
 use crate::types::Signal;
-use crate::stake::{get_stake_info, StakeInfo, DEFAULT_MINIMUM_STAKE};
+use crate::stake::get_stake_info;
 
 /// Maximum adoption count for normalization (cap at 100 adoptions)
 const MAX_ADOPTION: u32 = 100;
@@ -186,10 +186,14 @@ pub fn get_signal_quality_score(env: &Env, signal_id: u64) -> Option<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::{testutils::Address as TestAddress, Address, Env, Map, String, Vec};
+    use soroban_sdk::{contract, testutils::Address as TestAddress, Address, Env, Map, String, Vec};
     use crate::types::{Signal, SignalAction, SignalStatus};
     use crate::categories::{SignalCategory, RiskLevel};
     use crate::stake::StakeInfo;
+    use stellar_swipe_common::AssetPair;
+
+    #[contract]
+    struct TestContract;
 
     fn sdk_string(env: &Env, s: &str) -> String {
         #[allow(deprecated)]
@@ -207,7 +211,7 @@ mod tests {
         Signal {
             id: 1,
             provider,
-            asset_pair: sdk_string(env, "XLM/USDC"),
+            asset_pair: AssetPair::parse(env, &sdk_string(env, "XLM/USDC")).unwrap(),
             action: SignalAction::Buy,
             price: 100_000_000,
             rationale: sdk_string(env, "Test signal"),
@@ -232,6 +236,9 @@ mod tests {
             warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
         }
     }
 
@@ -253,81 +260,96 @@ mod tests {
     #[test]
     fn test_all_components_present() {
         let env = Env::default();
-        let provider = <Address as TestAddress>::generate(&env);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = <Address as TestAddress>::generate(&env);
         
-        // Setup: 80% success rate, 50 adoptions, GOLD stake, 90 AI score
-        setup_stake(&env, &provider, GOLD_THRESHOLD);
-        let signal = create_test_signal(&env, provider, 10, 8, 50, Some(90));
+            // Setup: 80% success rate, 50 adoptions, GOLD stake, 90 AI score
+            setup_stake(&env, &provider, GOLD_THRESHOLD);
+            let signal = create_test_signal(&env, provider, 10, 8, 50, Some(90));
         
-        let score = calculate_quality_score(&env, &signal);
+            let score = calculate_quality_score(&env, &signal);
         
-        // Expected: (80 * 0.4) + (50 * 0.2) + (100 * 0.2) + (90 * 0.2)
-        //         = 32 + 10 + 20 + 18 = 80
-        assert_eq!(score, 80);
+            // Expected: (80 * 0.4) + (50 * 0.2) + (100 * 0.2) + (90 * 0.2)
+            //         = 32 + 10 + 20 + 18 = 80
+            assert_eq!(score, 80);
+        });
     }
 
     #[test]
     fn test_missing_ai_score() {
         let env = Env::default();
-        let provider = <Address as TestAddress>::generate(&env);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = <Address as TestAddress>::generate(&env);
         
-        // Setup: 80% success rate, 50 adoptions, GOLD stake, NO AI score
-        setup_stake(&env, &provider, GOLD_THRESHOLD);
-        let signal = create_test_signal(&env, provider, 10, 8, 50, None);
+            // Setup: 80% success rate, 50 adoptions, GOLD stake, NO AI score
+            setup_stake(&env, &provider, GOLD_THRESHOLD);
+            let signal = create_test_signal(&env, provider, 10, 8, 50, None);
         
-        let score = calculate_quality_score(&env, &signal);
+            let score = calculate_quality_score(&env, &signal);
         
-        // Expected: (80 * 0.6) + (50 * 0.2) + (100 * 0.2)
-        //         = 48 + 10 + 20 = 78
-        assert_eq!(score, 78);
+            // Expected: (80 * 0.6) + (50 * 0.2) + (100 * 0.2)
+            //         = 48 + 10 + 20 = 78
+            assert_eq!(score, 78);
+        });
     }
 
     #[test]
     fn test_zero_success_rate() {
         let env = Env::default();
-        let provider = <Address as TestAddress>::generate(&env);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = <Address as TestAddress>::generate(&env);
         
-        // Setup: 0% success rate, 50 adoptions, GOLD stake, 90 AI score
-        setup_stake(&env, &provider, GOLD_THRESHOLD);
-        let signal = create_test_signal(&env, provider, 10, 0, 50, Some(90));
+            // Setup: 0% success rate, 50 adoptions, GOLD stake, 90 AI score
+            setup_stake(&env, &provider, GOLD_THRESHOLD);
+            let signal = create_test_signal(&env, provider, 10, 0, 50, Some(90));
         
-        let score = calculate_quality_score(&env, &signal);
+            let score = calculate_quality_score(&env, &signal);
         
-        // Expected: (0 * 0.4) + (50 * 0.2) + (100 * 0.2) + (90 * 0.2)
-        //         = 0 + 10 + 20 + 18 = 48
-        assert_eq!(score, 48);
+            // Expected: (0 * 0.4) + (50 * 0.2) + (100 * 0.2) + (90 * 0.2)
+            //         = 0 + 10 + 20 + 18 = 48
+            assert_eq!(score, 48);
+        });
     }
 
     #[test]
     fn test_zero_executions() {
         let env = Env::default();
-        let provider = <Address as TestAddress>::generate(&env);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = <Address as TestAddress>::generate(&env);
         
-        // Setup: No executions yet, 0 adoptions, BRONZE stake, 50 AI score
-        setup_stake(&env, &provider, BRONZE_THRESHOLD);
-        let signal = create_test_signal(&env, provider, 0, 0, 0, Some(50));
+            // Setup: No executions yet, 0 adoptions, BRONZE stake, 50 AI score
+            setup_stake(&env, &provider, BRONZE_THRESHOLD);
+            let signal = create_test_signal(&env, provider, 0, 0, 0, Some(50));
         
-        let score = calculate_quality_score(&env, &signal);
+            let score = calculate_quality_score(&env, &signal);
         
-        // Expected: (0 * 0.4) + (0 * 0.2) + (33 * 0.2) + (50 * 0.2)
-        //         = 0 + 0 + 6.6 + 10 = 16.6 ≈ 16
-        assert_eq!(score, 16);
+            // Expected: (0 * 0.4) + (0 * 0.2) + (33 * 0.2) + (50 * 0.2)
+            //         = 0 + 0 + 6.6 + 10 = 16.6 ≈ 16
+            assert_eq!(score, 16);
+        });
     }
 
     #[test]
     fn test_max_adoption_capped() {
         let env = Env::default();
-        let provider = <Address as TestAddress>::generate(&env);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = <Address as TestAddress>::generate(&env);
         
-        // Setup: 150 adoptions (should cap at 100)
-        setup_stake(&env, &provider, SILVER_THRESHOLD);
-        let signal = create_test_signal(&env, provider, 10, 10, 150, Some(80));
+            // Setup: 150 adoptions (should cap at 100)
+            setup_stake(&env, &provider, SILVER_THRESHOLD);
+            let signal = create_test_signal(&env, provider, 10, 10, 150, Some(80));
         
-        let score = calculate_quality_score(&env, &signal);
+            let score = calculate_quality_score(&env, &signal);
         
-        // Expected: (100 * 0.4) + (100 * 0.2) + (66 * 0.2) + (80 * 0.2)
-        //         = 40 + 20 + 13.2 + 16 = 89.2 ≈ 89
-        assert_eq!(score, 89);
+            // Expected: (100 * 0.4) + (100 * 0.2) + (66 * 0.2) + (80 * 0.2)
+            //         = 40 + 20 + 13.2 + 16 = 89.2 ≈ 89
+            assert_eq!(score, 89);
+        });
     }
 
     #[test]
@@ -384,64 +406,76 @@ mod tests {
     #[test]
     fn test_score_always_0_to_100() {
         let env = Env::default();
-        let provider = <Address as TestAddress>::generate(&env);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = <Address as TestAddress>::generate(&env);
         
-        // Test various combinations
-        setup_stake(&env, &provider, GOLD_THRESHOLD);
+            // Test various combinations
+            setup_stake(&env, &provider, GOLD_THRESHOLD);
         
-        // All max values
-        let signal = create_test_signal(&env, provider.clone(), 100, 100, 200, Some(100));
-        let score = calculate_quality_score(&env, &signal);
-        assert!(score <= 100);
+            // All max values
+            let signal = create_test_signal(&env, provider.clone(), 100, 100, 200, Some(100));
+            let score = calculate_quality_score(&env, &signal);
+            assert!(score <= 100);
         
-        // All min values
-        let signal = create_test_signal(&env, provider.clone(), 0, 0, 0, Some(0));
-        let score = calculate_quality_score(&env, &signal);
-        assert!(score >= 0 && score <= 100);
+            // All min values
+            let signal = create_test_signal(&env, provider.clone(), 0, 0, 0, Some(0));
+            let score = calculate_quality_score(&env, &signal);
+            assert!(score >= 0 && score <= 100);
+        });
     }
 
     #[test]
     fn test_bronze_stake_tier() {
         let env = Env::default();
-        let provider = <Address as TestAddress>::generate(&env);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = <Address as TestAddress>::generate(&env);
         
-        setup_stake(&env, &provider, BRONZE_THRESHOLD);
-        let signal = create_test_signal(&env, provider, 10, 8, 50, Some(80));
+            setup_stake(&env, &provider, BRONZE_THRESHOLD);
+            let signal = create_test_signal(&env, provider, 10, 8, 50, Some(80));
         
-        let score = calculate_quality_score(&env, &signal);
+            let score = calculate_quality_score(&env, &signal);
         
-        // Expected: (80 * 0.4) + (50 * 0.2) + (33 * 0.2) + (80 * 0.2)
-        //         = 32 + 10 + 6.6 + 16 = 64.6 ≈ 64
-        assert_eq!(score, 64);
+            // Expected: (80 * 0.4) + (50 * 0.2) + (33 * 0.2) + (80 * 0.2)
+            //         = 32 + 10 + 6.6 + 16 = 64.6 ≈ 64
+            assert_eq!(score, 64);
+        });
     }
 
     #[test]
     fn test_silver_stake_tier() {
         let env = Env::default();
-        let provider = <Address as TestAddress>::generate(&env);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = <Address as TestAddress>::generate(&env);
         
-        setup_stake(&env, &provider, SILVER_THRESHOLD);
-        let signal = create_test_signal(&env, provider, 10, 8, 50, Some(80));
+            setup_stake(&env, &provider, SILVER_THRESHOLD);
+            let signal = create_test_signal(&env, provider, 10, 8, 50, Some(80));
         
-        let score = calculate_quality_score(&env, &signal);
+            let score = calculate_quality_score(&env, &signal);
         
-        // Expected: (80 * 0.4) + (50 * 0.2) + (66 * 0.2) + (80 * 0.2)
-        //         = 32 + 10 + 13.2 + 16 = 71.2 ≈ 71
-        assert_eq!(score, 71);
+            // Expected: (80 * 0.4) + (50 * 0.2) + (66 * 0.2) + (80 * 0.2)
+            //         = 32 + 10 + 13.2 + 16 = 71.2 ≈ 71
+            assert_eq!(score, 71);
+        });
     }
 
     #[test]
     fn test_no_stake() {
         let env = Env::default();
-        let provider = <Address as TestAddress>::generate(&env);
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            let provider = <Address as TestAddress>::generate(&env);
         
-        // No stake setup
-        let signal = create_test_signal(&env, provider, 10, 8, 50, Some(80));
+            // No stake setup
+            let signal = create_test_signal(&env, provider, 10, 8, 50, Some(80));
         
-        let score = calculate_quality_score(&env, &signal);
+            let score = calculate_quality_score(&env, &signal);
         
-        // Expected: (80 * 0.4) + (50 * 0.2) + (0 * 0.2) + (80 * 0.2)
-        //         = 32 + 10 + 0 + 16 = 58
-        assert_eq!(score, 58);
+            // Expected: (80 * 0.4) + (50 * 0.2) + (0 * 0.2) + (80 * 0.2)
+            //         = 32 + 10 + 0 + 16 = 58
+            assert_eq!(score, 58);
+        });
     }
 }
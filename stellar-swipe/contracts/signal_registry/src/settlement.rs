@@ -0,0 +1,227 @@
+//! Oracle-based settlement of stop-loss and take-profit targets.
+//!
+//! A provider may attach a stop-loss and a take-profit price to an Active
+//! signal until it is first copied. Anyone may then call `settle_signal`: the
+//! oracle's price for the signal's pair is checked against the targets and,
+//! once either level is crossed or the signal has expired, the signal is
+//! closed as Successful or Failed on that price instead of on prices reported
+//! by executors. On an expired signal it applies the same rules as
+//! `settle_expired_signal`.
+//!
+//! `settle_expired_signal` is the expiry-only variant: it closes a signal
+//! left Active past its expiry, typically one nobody copied or whose
+//! executions were inconclusive. The oracle only serves its current price,
+//! so "the price at expiry" is whatever `get_price` returns when settlement
+//! is called, provided that price was observed at or after expiry and no
+//! later than `SETTLEMENT_WINDOW` past it. Once the window has passed the
+//! signal can no longer be settled this way. A signal whose executions
+//! already decide its outcome is left to them.
+//!
+//! Prices come from the oracle contract's `get_price(AssetPair)`, read
+//! through `PriceFeedClient`, at `CANONICAL_DECIMALS` like signal prices.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use stellar_swipe_common::oracle::{oracle_price_to_i128, validate_freshness, PriceFeedClient};
+use stellar_swipe_common::AssetPair;
+
+use crate::errors::SettlementError;
+use crate::types::{Signal, SignalAction, SignalStatus};
+
+/// How long after expiry an oracle observation still counts as the expiry
+/// price for `settle_expired_signal`.
+pub const SETTLEMENT_WINDOW: u64 = 60 * 60;
+
+/// Most observations read by `oracle_history`; the oracle retains 256.
+pub const ORACLE_HISTORY_LIMIT: u32 = 256;
+
+#[contracttype]
+pub enum SettlementKey {
+    /// Oracle contract serving `get_price(AssetPair)`.
+    PriceOracle,
+}
+
+pub fn set_price_oracle(env: &Env, oracle: &Address) {
+    env.storage()
+        .instance()
+        .set(&SettlementKey::PriceOracle, oracle);
+}
+
+pub fn get_price_oracle(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&SettlementKey::PriceOracle)
+}
+
+/// Whether `signal`, past its expiry, can still be closed on the oracle
+/// price by `settle_expired_signal`. Expiry sweeps leave such a signal alone
+/// unless its executions already decide it, so they don't get in ahead of
+/// settlement.
+pub fn awaiting_settlement(env: &Env, signal: &Signal, now: u64) -> bool {
+    get_price_oracle(env).is_some() && now <= signal.expiry.saturating_add(SETTLEMENT_WINDOW)
+}
+
+/// Check that the targets sit on the right side of the entry price: below it
+/// (stop-loss) and above it (take-profit) for a Buy, the other way round for
+/// a Sell.
+pub fn validate_targets(
+    signal: &Signal,
+    stop_loss: Option<i128>,
+    take_profit: Option<i128>,
+) -> Result<(), SettlementError> {
+    let entry = signal.price;
+    let valid_stop = match stop_loss {
+        None => true,
+        Some(sl) => {
+            sl > 0
+                && match signal.action {
+                    SignalAction::Buy => sl < entry,
+                    SignalAction::Sell => sl > entry,
+                }
+        }
+    };
+    let valid_take = match take_profit {
+        None => true,
+        Some(tp) => {
+            tp > 0
+                && match signal.action {
+                    SignalAction::Buy => tp > entry,
+                    SignalAction::Sell => tp < entry,
+                }
+        }
+    };
+    if !valid_stop || !valid_take {
+        return Err(SettlementError::InvalidTargets);
+    }
+    Ok(())
+}
+
+/// Fresh oracle price for the signal's pair.
+pub fn oracle_price(env: &Env, asset_pair: &AssetPair) -> Result<i128, SettlementError> {
+    oracle_observation(env, asset_pair).map(|(price, _)| price)
+}
+
+/// Fresh oracle price for the signal's pair and the time it was observed.
+pub fn oracle_observation(env: &Env, asset_pair: &AssetPair) -> Result<(i128, u64), SettlementError> {
+    let address = get_price_oracle(env).ok_or(SettlementError::OracleNotConfigured)?;
+    let price = PriceFeedClient { address }
+        .get_price(env, asset_pair)
+        .map_err(|_| SettlementError::PriceUnavailable)?;
+    validate_freshness(env, &price).map_err(|_| SettlementError::PriceUnavailable)?;
+    let observed_at = price.timestamp;
+    let price = oracle_price_to_i128(&price).ok_or(SettlementError::PriceUnavailable)?;
+    if price <= 0 {
+        return Err(SettlementError::PriceUnavailable);
+    }
+    Ok((price, observed_at))
+}
+
+/// The oracle's retained prices for the pair as `(price, timestamp)`,
+/// newest first.
+pub fn oracle_history(env: &Env, asset_pair: &AssetPair) -> Result<Vec<(i128, u64)>, SettlementError> {
+    let address = get_price_oracle(env).ok_or(SettlementError::OracleNotConfigured)?;
+    PriceFeedClient { address }
+        .get_price_history(env, asset_pair, ORACLE_HISTORY_LIMIT)
+        .map_err(|_| SettlementError::PriceUnavailable)
+}
+
+/// Outcome of the signal at oracle price `price`, or `None` while neither
+/// target has been reached and the signal has not expired. A target takes
+/// precedence over expiry; an expired signal without a target hit is judged
+/// on whether the price moved its way.
+pub fn outcome(signal: &Signal, price: i128, now: u64) -> Option<SignalStatus> {
+    let (stop_hit, take_hit) = match signal.action {
+        SignalAction::Buy => (
+            signal.stop_loss.map_or(false, |sl| price <= sl),
+            signal.take_profit.map_or(false, |tp| price >= tp),
+        ),
+        SignalAction::Sell => (
+            signal.stop_loss.map_or(false, |sl| price >= sl),
+            signal.take_profit.map_or(false, |tp| price <= tp),
+        ),
+    };
+    if stop_hit {
+        return Some(SignalStatus::Failed);
+    }
+    if take_hit {
+        return Some(SignalStatus::Successful);
+    }
+    if now >= signal.expiry {
+        let moved_its_way = match signal.action {
+            SignalAction::Buy => price > signal.price,
+            SignalAction::Sell => price < signal.price,
+        };
+        return Some(if moved_its_way {
+            SignalStatus::Successful
+        } else {
+            SignalStatus::Failed
+        });
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::String;
+
+    fn buy_signal(env: &Env, stop_loss: Option<i128>, take_profit: Option<i128>) -> Signal {
+        Signal {
+            id: 1,
+            provider: Address::generate(env),
+            asset_pair: AssetPair::parse(env, &String::from_str(env, "XLM/USDC")).unwrap(),
+            action: SignalAction::Buy,
+            price: 100,
+            rationale: String::from_str(env, "Breakout"),
+            timestamp: 0,
+            expiry: 1_000,
+            status: SignalStatus::Active,
+            executions: 0,
+            successful_executions: 0,
+            total_volume: 0,
+            total_roi: 0,
+            category: crate::categories::SignalCategory::SWING,
+            risk_level: crate::categories::RiskLevel::Medium,
+            is_collaborative: false,
+            tags: soroban_sdk::Vec::new(env),
+            submitted_at: 0,
+            rationale_hash: String::from_str(env, "Breakout"),
+            confidence: 50,
+            adoption_count: 0,
+            ai_validation_score: None,
+            avg_copier_roi_bps: 0,
+            copier_closed_count: 0,
+            warning_emitted: false,
+            benchmark_return_bps: None,
+            alpha_bps: None,
+            stop_loss,
+            take_profit,
+            premium: false,
+        }
+    }
+
+    #[test]
+    fn test_targets_must_bracket_entry() {
+        let env = Env::default();
+        let signal = buy_signal(&env, None, None);
+        assert!(validate_targets(&signal, Some(90), Some(120)).is_ok());
+        assert_eq!(
+            validate_targets(&signal, Some(110), None),
+            Err(SettlementError::InvalidTargets)
+        );
+        assert_eq!(
+            validate_targets(&signal, None, Some(95)),
+            Err(SettlementError::InvalidTargets)
+        );
+    }
+
+    #[test]
+    fn test_outcome_on_targets_and_expiry() {
+        let env = Env::default();
+        let signal = buy_signal(&env, Some(90), Some(120));
+        assert_eq!(outcome(&signal, 100, 500), None);
+        assert_eq!(outcome(&signal, 89, 500), Some(SignalStatus::Failed));
+        assert_eq!(outcome(&signal, 125, 500), Some(SignalStatus::Successful));
+        // Past expiry with no target hit: judged on the direction of the move.
+        assert_eq!(outcome(&signal, 105, 1_000), Some(SignalStatus::Successful));
+        assert_eq!(outcome(&signal, 100, 1_000), Some(SignalStatus::Failed));
+    }
+}
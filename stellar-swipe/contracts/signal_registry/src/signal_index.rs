@@ -0,0 +1,204 @@
+//! Secondary signal indexes: signal ids by provider, by asset pair and by status.
+//!
+//! New signals are indexed on creation and status transitions move the id
+//! between status buckets. Signals created before the indexes existed are
+//! picked up by the admin `backfill_indexes` migration, which walks the
+//! signals map by id in bounded batches and records how far it has got.
+//! Readers should still check a signal's current status, since a bucket can
+//! lag a transition made by a path that predates the index.
+//!
+//! The paginated queries take a cursor, the id of the last signal of the
+//! previous page (0 for the first page), and return signals in id order.
+
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+use stellar_swipe_common::AssetPair;
+
+use crate::storage_monitor::{self, StorageEntry};
+use crate::types::{Signal, SignalStatus};
+
+/// Upper bound on signal ids scanned by one backfill call.
+pub const MAX_BACKFILL_BATCH: u32 = 100;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum SignalIndexKey {
+    Provider(Address),
+    Pair(AssetPair),
+    Status(SignalStatus),
+    /// Next signal id the backfill will scan.
+    BackfillCursor,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BackfillProgress {
+    /// Id the next batch starts from.
+    pub next_cursor: u64,
+    /// Records this batch added to an index.
+    pub indexed: u32,
+    /// Highest id at the time of the call.
+    pub total: u64,
+    pub done: bool,
+}
+
+/// One page of a paginated signal query.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignalPage {
+    pub signals: Vec<Signal>,
+    /// Cursor for the next page; `None` once the last page has been returned.
+    pub next_cursor: Option<u64>,
+}
+
+fn get_ids(env: &Env, key: &SignalIndexKey) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save_ids(env: &Env, key: &SignalIndexKey, ids: &Vec<u64>) {
+    if ids.is_empty() {
+        env.storage().persistent().remove(key);
+    } else {
+        env.storage().persistent().set(key, ids);
+    }
+
+    let entry = match key {
+        SignalIndexKey::Provider(provider) => StorageEntry::ProviderIndex(provider.clone()),
+        SignalIndexKey::Pair(pair) => StorageEntry::PairIndex(pair.clone()),
+        SignalIndexKey::Status(status) => StorageEntry::StatusIndex(status.clone()),
+        SignalIndexKey::BackfillCursor => return,
+    };
+    if ids.is_empty() {
+        storage_monitor::forget(env, entry);
+    } else {
+        storage_monitor::touch(env, entry);
+    }
+}
+
+/// Append `id` under `key` unless it is already there. Returns whether it was added.
+fn insert(env: &Env, key: &SignalIndexKey, id: u64) -> bool {
+    let mut ids = get_ids(env, key);
+    if ids.contains(id) {
+        return false;
+    }
+    ids.push_back(id);
+    save_ids(env, key, &ids);
+    true
+}
+
+fn remove(env: &Env, key: &SignalIndexKey, id: u64) {
+    let mut ids = get_ids(env, key);
+    if let Some(index) = ids.first_index_of(id) {
+        ids.remove(index);
+        save_ids(env, key, &ids);
+    }
+}
+
+pub fn ids_by_provider(env: &Env, provider: &Address) -> Vec<u64> {
+    get_ids(env, &SignalIndexKey::Provider(provider.clone()))
+}
+
+pub fn ids_by_pair(env: &Env, asset_pair: &AssetPair) -> Vec<u64> {
+    get_ids(env, &SignalIndexKey::Pair(asset_pair.clone()))
+}
+
+pub fn ids_by_status(env: &Env, status: &SignalStatus) -> Vec<u64> {
+    get_ids(env, &SignalIndexKey::Status(status.clone()))
+}
+
+/// Up to `limit` of `ids` above `cursor`, in ascending order, and whether
+/// any further ids remain. Index buckets are not kept sorted, since status
+/// moves append, so the bucket is scanned rather than sliced.
+pub fn page_ids(env: &Env, ids: &Vec<u64>, cursor: u64, limit: u32) -> (Vec<u64>, bool) {
+    let mut page: Vec<u64> = Vec::new(env);
+    let mut more = false;
+    if limit == 0 {
+        return (page, ids.iter().any(|id| id > cursor));
+    }
+    for id in ids.iter() {
+        if id <= cursor {
+            continue;
+        }
+        let mut index = page.len();
+        for i in 0..page.len() {
+            if page.get(i).unwrap() > id {
+                index = i;
+                break;
+            }
+        }
+        if index == limit {
+            more = true;
+            continue;
+        }
+        page.insert(index, id);
+        if page.len() > limit {
+            page.pop_back();
+            more = true;
+        }
+    }
+    (page, more)
+}
+
+/// Add a signal to every index. Safe to repeat.
+pub fn index_signal(env: &Env, signal: &Signal) -> bool {
+    let by_provider = insert(env, &SignalIndexKey::Provider(signal.provider.clone()), signal.id);
+    let by_pair = insert(env, &SignalIndexKey::Pair(signal.asset_pair.clone()), signal.id);
+    let by_status = insert(env, &SignalIndexKey::Status(signal.status.clone()), signal.id);
+    by_provider || by_pair || by_status
+}
+
+/// Move a signal between status buckets after a transition.
+pub fn move_status(env: &Env, signal_id: u64, old: &SignalStatus, new: &SignalStatus) {
+    if old == new {
+        return;
+    }
+    remove(env, &SignalIndexKey::Status(old.clone()), signal_id);
+    insert(env, &SignalIndexKey::Status(new.clone()), signal_id);
+}
+
+pub fn get_backfill_cursor(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&SignalIndexKey::BackfillCursor)
+        .unwrap_or(1)
+}
+
+/// Index up to `limit` signal ids starting at `cursor` (0 resumes from the
+/// saved cursor) and save where the next batch should start.
+pub fn backfill(
+    env: &Env,
+    signals: &Map<u64, Signal>,
+    total: u64,
+    cursor: u64,
+    limit: u32,
+) -> BackfillProgress {
+    let start = if cursor == 0 {
+        get_backfill_cursor(env)
+    } else {
+        cursor
+    };
+    let end = start.saturating_add(limit as u64).min(total.saturating_add(1));
+
+    let mut indexed = 0u32;
+    for id in start..end {
+        if let Some(signal) = signals.get(id) {
+            if index_signal(env, &signal) {
+                indexed += 1;
+            }
+        }
+    }
+
+    let next_cursor = end.max(start);
+    env.storage()
+        .instance()
+        .set(&SignalIndexKey::BackfillCursor, &next_cursor);
+
+    BackfillProgress {
+        next_cursor,
+        indexed,
+        total,
+        done: next_cursor > total,
+    }
+}
@@ -2,7 +2,12 @@
 //!
 //! Store follows: (user, provider) -> bool
 //! Store follower count per provider for leaderboard/stats.
-//! Gas: O(1) follow/unfollow, O(n) get_followed_providers where n = followed count.
+//! Store each provider's followers for paginated listing.
+//! Gas: O(n) follow/unfollow and get_followed_providers, where n is the length
+//! of the lists touched.
+//!
+//! Follower lists start with the follows made after they were introduced;
+//! follower counts cover every follow.
 
 use soroban_sdk::{contracttype, Address, Env, Vec};
 
@@ -18,6 +23,20 @@ pub enum SocialDataKey {
     UserFollowedList(Address),
     /// provider -> u32 follower count
     FollowerCount(Address),
+    /// provider -> Vec<Address> of their followers
+    ProviderFollowerList(Address),
+}
+
+/// Largest page `get_followers` / `get_following` return.
+pub const MAX_FOLLOW_PAGE: u32 = 50;
+
+/// One page of followers or followed providers.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FollowPage {
+    pub addresses: Vec<Address>,
+    /// Position to pass as `cursor` for the next page; `None` on the last page.
+    pub next_cursor: Option<u32>,
 }
 
 /// Check if user follows provider
@@ -36,6 +55,38 @@ pub fn get_followed_providers(env: &Env, user: &Address) -> Vec<Address> {
         .unwrap_or_else(|| Vec::new(env))
 }
 
+/// Get the followers of provider
+pub fn get_followers(env: &Env, provider: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&SocialDataKey::ProviderFollowerList(provider.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn save_followers(env: &Env, provider: &Address, followers: &Vec<Address>) {
+    let key = SocialDataKey::ProviderFollowerList(provider.clone());
+    if followers.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, followers);
+    }
+}
+
+/// Up to `limit` entries of `list` from position `cursor`. Positions shift
+/// when an earlier entry unfollows, so a page may skip or repeat an entry
+/// across such a change.
+pub fn page(env: &Env, list: &Vec<Address>, cursor: u32, limit: u32) -> FollowPage {
+    let end = cursor.saturating_add(limit.min(MAX_FOLLOW_PAGE)).min(list.len());
+    let mut addresses = Vec::new(env);
+    for i in cursor..end {
+        addresses.push_back(list.get(i).unwrap());
+    }
+    FollowPage {
+        addresses,
+        next_cursor: if end < list.len() { Some(end) } else { None },
+    }
+}
+
 /// Get follower count for provider
 pub fn get_follower_count(env: &Env, provider: &Address) -> u32 {
     env.storage()
@@ -63,6 +114,10 @@ pub fn follow_provider(env: &Env, user: Address, provider: Address) -> Result<()
         .instance()
         .set(&SocialDataKey::UserFollowedList(user.clone()), &list);
 
+    let mut followers = get_followers(env, &provider);
+    followers.push_back(user.clone());
+    save_followers(env, &provider, &followers);
+
     // Mark follow
     env.storage().instance().set(
         &SocialDataKey::Follow(user.clone(), provider.clone()),
@@ -101,6 +156,12 @@ pub fn unfollow_provider(env: &Env, user: Address, provider: Address) -> Result<
         .instance()
         .set(&SocialDataKey::UserFollowedList(user.clone()), &new_list);
 
+    let mut followers = get_followers(env, &provider);
+    if let Some(index) = followers.first_index_of(&user) {
+        followers.remove(index);
+        save_followers(env, &provider, &followers);
+    }
+
     // Remove follow marker
     env.storage()
         .instance()
@@ -1,6 +1,9 @@
 #![allow(dead_code)]
 
 use soroban_sdk::{contracttype, Address, Env, Map};
+use stellar_swipe_common::BASIS_POINTS_DENOMINATOR_I128;
+
+use crate::types::SignalStatus;
 
 pub const DEFAULT_MINIMUM_STAKE: i128 = 100_000_000; // 100 XLM
 pub const UNSTAKE_LOCK_PERIOD: u64 = 7 * 24 * 60 * 60; // 7 days in seconds
@@ -20,6 +23,23 @@ pub enum ContractError {
     StakeLocked,
     InsufficientStake,
     BelowMinimumStake,
+    NoPendingUnstake,
+}
+
+/// Stake queued for withdrawal. It stays staked, backing the provider's
+/// signals and exposed to slashing, until it is claimed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUnstake {
+    pub amount: i128,
+    /// Ledger timestamp from which `claim_unstake` succeeds.
+    pub available_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum UnstakeKey {
+    PendingUnstake(Address),
 }
 
 /// Stake XLM for a provider
@@ -49,32 +69,91 @@ pub fn stake(
     Ok(())
 }
 
-/// Unstake XLM (only after lock period)
-pub fn unstake(
+pub fn get_pending_unstake(env: &Env, provider: &Address) -> Option<PendingUnstake> {
+    env.storage()
+        .persistent()
+        .get(&UnstakeKey::PendingUnstake(provider.clone()))
+}
+
+/// Queue `amount` of the provider's unlocked stake for withdrawal. It can be
+/// claimed `UNSTAKE_LOCK_PERIOD` from now, or once the stake's lock from the
+/// last signal runs out if that is later. A second request adds to the
+/// pending amount and restarts the wait.
+pub fn request_unstake(
+    env: &Env,
+    storage: &Map<Address, StakeInfo>,
+    provider: &Address,
+    amount: i128,
+) -> Result<PendingUnstake, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidStakeAmount);
+    }
+    let info = storage
+        .get(provider.clone())
+        .ok_or(ContractError::NoStakeFound)?;
+    let queued = get_pending_unstake(env, provider).map_or(0, |p| p.amount);
+    if queued + amount > info.amount - get_locked_stake(env, provider) {
+        return Err(ContractError::InsufficientStake);
+    }
+
+    let now = env.ledger().timestamp();
+    let pending = PendingUnstake {
+        amount: queued + amount,
+        available_at: (now + UNSTAKE_LOCK_PERIOD).max(info.locked_until),
+    };
+    env.storage()
+        .persistent()
+        .set(&UnstakeKey::PendingUnstake(provider.clone()), &pending);
+    Ok(pending)
+}
+
+/// Withdraw a pending unstake once it is available and no signal posted
+/// since the request still holds the stake's lock. Stake slashed or locked
+/// behind new signals since the request stays staked, so the amount
+/// withdrawn may be less than requested. Returns the amount withdrawn.
+pub fn claim_unstake(
     env: &Env,
     storage: &mut Map<Address, StakeInfo>,
     provider: &Address,
 ) -> Result<i128, ContractError> {
+    let pending = get_pending_unstake(env, provider).ok_or(ContractError::NoPendingUnstake)?;
     let mut info = storage
         .get(provider.clone())
         .ok_or(ContractError::NoStakeFound)?;
-    let now = env.ledger().timestamp();
-
-    if now < info.locked_until {
+    if env.ledger().timestamp() < pending.available_at.max(info.locked_until) {
         return Err(ContractError::StakeLocked);
     }
-
-    if info.amount <= 0 {
+    let amount = pending
+        .amount
+        .min(info.amount - get_locked_stake(env, provider));
+    if amount <= 0 {
         return Err(ContractError::InsufficientStake);
     }
 
-    let amount = info.amount;
-    info.amount = 0;
+    info.amount -= amount;
     storage.set(provider.clone(), info);
-
+    env.storage()
+        .persistent()
+        .remove(&UnstakeKey::PendingUnstake(provider.clone()));
     Ok(amount)
 }
 
+/// Whether the provider's pending unstake would leave less than `min_stake`.
+pub fn unstake_breaches_minimum(
+    env: &Env,
+    storage: &Map<Address, StakeInfo>,
+    provider: &Address,
+    min_stake: i128,
+) -> bool {
+    match get_pending_unstake(env, provider) {
+        Some(pending) => {
+            let staked = storage.get(provider.clone()).map_or(0, |info| info.amount);
+            staked - pending.amount < min_stake
+        }
+        None => false,
+    }
+}
+
 /// Record that a signal was submitted
 /// Updates last_signal_time and locks stake for UNSTAKE_LOCK_PERIOD
 pub fn record_signal(
@@ -99,15 +178,24 @@ pub fn can_submit_signal(
     storage: &Map<Address, StakeInfo>,
     provider: &Address,
 ) -> Result<(), ContractError> {
+    verify_stake(storage, provider, DEFAULT_MINIMUM_STAKE).map(|_| ())
+}
+
+/// Check that `provider` has staked at least `min_stake`.
+pub fn verify_stake(
+    storage: &Map<Address, StakeInfo>,
+    provider: &Address,
+    min_stake: i128,
+) -> Result<StakeInfo, ContractError> {
     let info = storage
         .get(provider.clone())
         .ok_or(ContractError::NoStakeFound)?;
 
-    if info.amount < DEFAULT_MINIMUM_STAKE {
+    if info.amount < min_stake {
         return Err(ContractError::BelowMinimumStake);
     }
 
-    Ok(())
+    Ok(info)
 }
 
 /// Get stake information for a provider from instance storage.
@@ -120,6 +208,186 @@ pub fn get_stake_info(env: &Env, provider: &Address) -> Option<StakeInfo> {
     map.get(provider.clone())
 }
 
+/// Stake locked behind each signal and slashed if the signal fails.
+///
+/// While `lock_per_signal` is non-zero, creating a signal locks that much of
+/// the provider's unlocked stake. When the signal closes the lock is
+/// released, less `slash_bps` of it if the signal ended Failed; slashed stake
+/// is moved into the insurance pool. Staked tokens are held by the contract,
+/// so the pool is backed by the slashed tokens left behind.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignalStakeConfig {
+    pub lock_per_signal: i128,
+    pub slash_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum SignalStakeKey {
+    SignalStakeConfig,
+    /// Total stake a provider has locked behind open signals.
+    Locked(Address),
+    /// (provider, amount) locked behind one signal.
+    SignalLock(u64),
+    /// Slashed stake held for insurance payouts.
+    InsurancePool,
+    /// Token stakes are paid in and held by the contract.
+    Token,
+}
+
+pub fn get_stake_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&SignalStakeKey::Token)
+}
+
+pub fn set_stake_token(env: &Env, token: &Address) {
+    env.storage().instance().set(&SignalStakeKey::Token, token);
+}
+
+/// Whether the contract still holds any stake, pending unstake or insurance
+/// pool balance, all of which are paid out in the current stake token.
+pub fn holds_stake(env: &Env, stakes: &Map<Address, StakeInfo>) -> bool {
+    if get_insurance_pool(env) != 0 {
+        return true;
+    }
+    stakes
+        .iter()
+        .any(|(provider, info)| info.amount != 0 || get_pending_unstake(env, &provider).is_some())
+}
+
+pub fn get_signal_stake_config(env: &Env) -> SignalStakeConfig {
+    env.storage()
+        .instance()
+        .get(&SignalStakeKey::SignalStakeConfig)
+        .unwrap_or(SignalStakeConfig {
+            lock_per_signal: 0,
+            slash_bps: 0,
+        })
+}
+
+pub fn set_signal_stake_config(env: &Env, config: &SignalStakeConfig) -> Result<(), ContractError> {
+    if config.lock_per_signal < 0 || config.slash_bps as i128 > BASIS_POINTS_DENOMINATOR_I128 {
+        return Err(ContractError::InvalidStakeAmount);
+    }
+    env.storage().instance().set(&SignalStakeKey::SignalStakeConfig, config);
+    Ok(())
+}
+
+pub fn get_locked_stake(env: &Env, provider: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&SignalStakeKey::Locked(provider.clone()))
+        .unwrap_or(0)
+}
+
+fn set_locked_stake(env: &Env, provider: &Address, amount: i128) {
+    let key = SignalStakeKey::Locked(provider.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+    }
+}
+
+pub fn get_signal_lock(env: &Env, signal_id: u64) -> Option<(Address, i128)> {
+    env.storage()
+        .persistent()
+        .get(&SignalStakeKey::SignalLock(signal_id))
+}
+
+pub fn get_insurance_pool(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&SignalStakeKey::InsurancePool)
+        .unwrap_or(0)
+}
+
+/// Take `amount` out of the insurance pool for a payout.
+pub fn withdraw_insurance(env: &Env, amount: i128) -> Result<(), ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidStakeAmount);
+    }
+    let pool = get_insurance_pool(env);
+    if amount > pool {
+        return Err(ContractError::InsufficientStake);
+    }
+    env.storage()
+        .instance()
+        .set(&SignalStakeKey::InsurancePool, &(pool - amount));
+    Ok(())
+}
+
+/// Check that `provider` has at least `min_stake` staked (no check when it
+/// is zero) and lock the configured per-signal stake behind a new signal.
+/// Returns the amount locked.
+pub fn lock_for_signal(
+    env: &Env,
+    stakes: &Map<Address, StakeInfo>,
+    provider: &Address,
+    signal_id: u64,
+    min_stake: i128,
+) -> Result<i128, ContractError> {
+    if min_stake > 0 {
+        verify_stake(stakes, provider, min_stake)?;
+    }
+    let lock = get_signal_stake_config(env).lock_per_signal;
+    if lock == 0 {
+        return Ok(0);
+    }
+    let info = stakes
+        .get(provider.clone())
+        .ok_or(ContractError::NoStakeFound)?;
+    let locked = get_locked_stake(env, provider);
+    if info.amount - locked < lock {
+        return Err(ContractError::InsufficientStake);
+    }
+    set_locked_stake(env, provider, locked + lock);
+    env.storage()
+        .persistent()
+        .set(&SignalStakeKey::SignalLock(signal_id), &(provider.clone(), lock));
+    Ok(lock)
+}
+
+/// Release the stake locked behind a signal that has closed with `status`,
+/// slashing the configured share of it if the signal Failed. Returns the
+/// amount slashed.
+pub fn release_for_signal(
+    env: &Env,
+    stakes: &mut Map<Address, StakeInfo>,
+    signal_id: u64,
+    status: &SignalStatus,
+) -> i128 {
+    let (provider, lock) = match get_signal_lock(env, signal_id) {
+        Some(entry) => entry,
+        None => return 0,
+    };
+    env.storage()
+        .persistent()
+        .remove(&SignalStakeKey::SignalLock(signal_id));
+    set_locked_stake(env, &provider, (get_locked_stake(env, &provider) - lock).max(0));
+
+    if *status != SignalStatus::Failed {
+        return 0;
+    }
+    let mut info = match stakes.get(provider.clone()) {
+        Some(info) => info,
+        None => return 0,
+    };
+    let slash_bps = get_signal_stake_config(env).slash_bps as i128;
+    let slashed = (lock * slash_bps / BASIS_POINTS_DENOMINATOR_I128).min(info.amount);
+    if slashed == 0 {
+        return 0;
+    }
+    crate::staking_rewards::settle(env, &provider, info.amount);
+    crate::staking_rewards::adjust_total_staked(env, -slashed);
+    info.amount -= slashed;
+    stakes.set(provider, info);
+    env.storage()
+        .instance()
+        .set(&SignalStakeKey::InsurancePool, &(get_insurance_pool(env) + slashed));
+    slashed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,34 +426,44 @@ mod tests {
     }
 
     #[test]
-    fn test_unstake_before_and_after_lock() {
+    fn test_unstake_waits_for_lock() {
         let env = setup_env();
-        let mut storage: Map<Address, StakeInfo> = Map::new(&env);
-        let provider = sample_provider(&env);
-
-        stake(&env, &mut storage, &provider, 100_000_000).unwrap();
-
-        // Locked because no signal yet (locked_until = 0, so actually allowed)
-        let unstake_result = unstake(&env, &mut storage, &provider);
-        assert_eq!(unstake_result.unwrap(), 100_000_000);
-
-        // Re-stake and simulate signal submission
-        stake(&env, &mut storage, &provider, 100_000_000).unwrap();
-        record_signal(&env, &mut storage, &provider).unwrap();
-
-        // Attempt unstake immediately should fail
-        assert_eq!(
-            unstake(&env, &mut storage, &provider),
-            Err(ContractError::StakeLocked)
-        );
-
-        // Move timestamp beyond lock period
-        env.ledger()
-            .set_timestamp(env.ledger().timestamp() + UNSTAKE_LOCK_PERIOD + 1);
-
-        // Now unstake should succeed
-        let amount = unstake(&env, &mut storage, &provider).unwrap();
-        assert_eq!(amount, 100_000_000);
+        #[allow(deprecated)]
+        let contract_id = env.register_contract(None, crate::SignalRegistry);
+        env.as_contract(&contract_id, || {
+            let mut storage: Map<Address, StakeInfo> = Map::new(&env);
+            let provider = sample_provider(&env);
+
+            stake(&env, &mut storage, &provider, 200_000_000).unwrap();
+            assert_eq!(
+                claim_unstake(&env, &mut storage, &provider),
+                Err(ContractError::NoPendingUnstake)
+            );
+
+            // The wait runs from the request, past the lock from the signal.
+            env.ledger().set_timestamp(1_000);
+            record_signal(&env, &mut storage, &provider).unwrap();
+            env.ledger().set_timestamp(2_000);
+            let pending = request_unstake(&env, &storage, &provider, 150_000_000).unwrap();
+            assert_eq!(pending.available_at, 2_000 + UNSTAKE_LOCK_PERIOD);
+            assert!(unstake_breaches_minimum(&env, &storage, &provider, DEFAULT_MINIMUM_STAKE));
+            assert_eq!(
+                request_unstake(&env, &storage, &provider, 60_000_000),
+                Err(ContractError::InsufficientStake)
+            );
+
+            // Attempt claim before the lock runs out should fail
+            env.ledger().set_timestamp(pending.available_at - 1);
+            assert_eq!(
+                claim_unstake(&env, &mut storage, &provider),
+                Err(ContractError::StakeLocked)
+            );
+
+            env.ledger().set_timestamp(pending.available_at);
+            assert_eq!(claim_unstake(&env, &mut storage, &provider), Ok(150_000_000));
+            assert_eq!(storage.get(provider.clone()).unwrap().amount, 50_000_000);
+            assert!(get_pending_unstake(&env, &provider).is_none());
+        });
     }
 
     #[test]
@@ -219,4 +497,59 @@ mod tests {
         stake(&env, &mut storage, &provider, 100_000_000).unwrap();
         assert!(can_submit_signal(&storage, &provider).is_ok());
     }
+
+    #[test]
+    fn test_failed_signal_slashes_locked_stake() {
+        let env = setup_env();
+        #[allow(deprecated)]
+        let contract_id = env.register_contract(None, crate::SignalRegistry);
+        env.as_contract(&contract_id, || {
+            let mut storage: Map<Address, StakeInfo> = Map::new(&env);
+            let provider = sample_provider(&env);
+            stake(&env, &mut storage, &provider, 100_000_000).unwrap();
+
+            // Locking is off by default.
+            assert_eq!(lock_for_signal(&env, &storage, &provider, 1, 0), Ok(0));
+            assert_eq!(
+                lock_for_signal(&env, &storage, &provider, 1, 200_000_000),
+                Err(ContractError::BelowMinimumStake)
+            );
+            assert_eq!(get_locked_stake(&env, &provider), 0);
+
+            set_signal_stake_config(
+                &env,
+                &SignalStakeConfig {
+                    lock_per_signal: 40_000_000,
+                    slash_bps: 5_000,
+                },
+            )
+            .unwrap();
+            lock_for_signal(&env, &storage, &provider, 2, 0).unwrap();
+            lock_for_signal(&env, &storage, &provider, 3, 0).unwrap();
+            assert_eq!(
+                lock_for_signal(&env, &storage, &provider, 4, 0),
+                Err(ContractError::InsufficientStake)
+            );
+
+            // A successful signal gets its stake back untouched.
+            assert_eq!(
+                release_for_signal(&env, &mut storage, 2, &SignalStatus::Successful),
+                0
+            );
+            // A failed one loses half of its lock to the insurance pool.
+            assert_eq!(
+                release_for_signal(&env, &mut storage, 3, &SignalStatus::Failed),
+                20_000_000
+            );
+            assert_eq!(get_locked_stake(&env, &provider), 0);
+            assert_eq!(storage.get(provider.clone()).unwrap().amount, 80_000_000);
+            assert_eq!(get_insurance_pool(&env), 20_000_000);
+
+            // Releasing twice does nothing.
+            assert_eq!(
+                release_for_signal(&env, &mut storage, 3, &SignalStatus::Failed),
+                0
+            );
+        });
+    }
 }
@@ -0,0 +1,197 @@
+//! Staking rewards funded from trade fees.
+//!
+//! `share_bps` of what the platform keeps from each trade fee (after any
+//! referral reward) goes to the staking reward pool instead of the treasury.
+//! The pool is split over providers' stake at the moment the fee comes in,
+//! so a provider earns in proportion to both how much they stake and how
+//! long they keep it staked.
+//!
+//! Bookkeeping uses a per-asset reward-per-stake accumulator. Each provider
+//! has a checkpoint of the accumulator; whatever it grew since, times their
+//! stake, is theirs. `settle` must therefore run before any change to a
+//! provider's stake amount, and `adjust_total_staked` with it so funding
+//! doesn't have to walk every provider's stake.
+//!
+//! A contract upgraded from before staking rewards already holds stake that
+//! neither the total nor any checkpoint accounts for. Until
+//! `migrate_existing_stakes` has counted it, `fund` moves nothing into the
+//! pool. New deployments start migrated.
+
+use soroban_sdk::{contracttype, Address, Env, Map};
+
+use crate::errors::FeeError;
+use crate::stake::StakeInfo;
+use crate::types::Asset;
+
+/// Fixed-point scale of the reward-per-stake accumulator.
+pub const REWARD_PRECISION: i128 = 1_000_000_000_000;
+const BPS_DENOMINATOR: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum StakingRewardKey {
+    StakerShareBps,
+    /// Rewards per unit of stake, scaled by `REWARD_PRECISION`, per asset.
+    RewardPerStake,
+    /// Accumulator values a provider's rewards were last settled at.
+    Checkpoint(Address),
+    /// Settled, unclaimed rewards, per asset.
+    Pending(Address),
+    /// Sum of every provider's stake amount.
+    TotalStaked,
+    /// Set once stake placed before staking rewards existed is counted.
+    StakesMigrated,
+}
+
+pub fn get_share_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&StakingRewardKey::StakerShareBps)
+        .unwrap_or(0)
+}
+
+pub fn set_share_bps(env: &Env, share_bps: u32) -> Result<(), FeeError> {
+    if share_bps > BPS_DENOMINATOR {
+        return Err(FeeError::InvalidShare);
+    }
+    env.storage()
+        .instance()
+        .set(&StakingRewardKey::StakerShareBps, &share_bps);
+    Ok(())
+}
+
+fn reward_per_stake(env: &Env) -> Map<Asset, i128> {
+    env.storage()
+        .instance()
+        .get(&StakingRewardKey::RewardPerStake)
+        .unwrap_or(Map::new(env))
+}
+
+fn stakes(env: &Env) -> Map<Address, StakeInfo> {
+    env.storage()
+        .instance()
+        .get(&crate::StorageKey::ProviderStakes)
+        .unwrap_or(Map::new(env))
+}
+
+fn stake_of(env: &Env, provider: &Address) -> i128 {
+    stakes(env).get(provider.clone()).map_or(0, |info| info.amount)
+}
+
+pub fn total_staked(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StakingRewardKey::TotalStaked)
+        .unwrap_or(0)
+}
+
+/// Add `delta` to the running total of stake. Call alongside `settle`
+/// whenever a provider's stake amount changes.
+pub fn adjust_total_staked(env: &Env, delta: i128) {
+    env.storage()
+        .instance()
+        .set(&StakingRewardKey::TotalStaked, &(total_staked(env) + delta));
+}
+
+pub fn is_migrated(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&StakingRewardKey::StakesMigrated)
+        .unwrap_or(false)
+}
+
+/// Record that there is no uncounted stake, on a fresh deployment.
+pub fn mark_migrated(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&StakingRewardKey::StakesMigrated, &true);
+}
+
+/// Seed the running total from the recorded stakes and checkpoint every
+/// staker without a checkpoint at the current accumulator, so stake placed
+/// before staking rewards existed is counted from now on and earns nothing
+/// paid out before. Idempotent: the total is recomputed and existing
+/// checkpoints are kept. Returns the total.
+pub fn migrate_existing_stakes(env: &Env) -> i128 {
+    let acc = reward_per_stake(env);
+    let mut total: i128 = 0;
+    for (provider, info) in stakes(env).iter() {
+        total += info.amount;
+        let key = StakingRewardKey::Checkpoint(provider);
+        if !env.storage().persistent().has(&key) {
+            env.storage().persistent().set(&key, &acc);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&StakingRewardKey::TotalStaked, &total);
+    mark_migrated(env);
+    total
+}
+
+/// Move `share_bps` of `platform_amount` into the reward pool. Returns the
+/// amount moved, which the caller takes out of the treasury; nothing is moved
+/// while no one is staked or existing stake is still to be migrated.
+pub fn fund(env: &Env, asset: &Asset, platform_amount: i128) -> i128 {
+    let total = total_staked(env);
+    let amount = platform_amount * get_share_bps(env) as i128 / BPS_DENOMINATOR as i128;
+    if amount <= 0 || total <= 0 || !is_migrated(env) {
+        return 0;
+    }
+    let mut acc = reward_per_stake(env);
+    let current = acc.get(asset.clone()).unwrap_or(0);
+    acc.set(asset.clone(), current + amount * REWARD_PRECISION / total);
+    env.storage()
+        .instance()
+        .set(&StakingRewardKey::RewardPerStake, &acc);
+    amount
+}
+
+/// `pending` plus what `stake` earned since the checkpoint.
+fn accrued(env: &Env, provider: &Address, stake: i128) -> Map<Asset, i128> {
+    let checkpoint: Map<Asset, i128> = env
+        .storage()
+        .persistent()
+        .get(&StakingRewardKey::Checkpoint(provider.clone()))
+        .unwrap_or(Map::new(env));
+    let mut pending: Map<Asset, i128> = env
+        .storage()
+        .persistent()
+        .get(&StakingRewardKey::Pending(provider.clone()))
+        .unwrap_or(Map::new(env));
+    for (asset, acc) in reward_per_stake(env).iter() {
+        let earned = stake * (acc - checkpoint.get(asset.clone()).unwrap_or(0)) / REWARD_PRECISION;
+        if earned > 0 {
+            pending.set(asset.clone(), pending.get(asset).unwrap_or(0) + earned);
+        }
+    }
+    pending
+}
+
+/// Credit `provider` with what `stake`, their stake up to now, has earned
+/// and checkpoint the accumulator. Call before their stake changes.
+pub fn settle(env: &Env, provider: &Address, stake: i128) {
+    let pending = accrued(env, provider, stake);
+    env.storage()
+        .persistent()
+        .set(&StakingRewardKey::Pending(provider.clone()), &pending);
+    env.storage().persistent().set(
+        &StakingRewardKey::Checkpoint(provider.clone()),
+        &reward_per_stake(env),
+    );
+}
+
+/// Unclaimed rewards of `provider`, per asset.
+pub fn get_pending(env: &Env, provider: &Address) -> Map<Asset, i128> {
+    accrued(env, provider, stake_of(env, provider))
+}
+
+/// Settle and hand back everything `provider` has earned, leaving nothing
+/// pending. The caller pays it out.
+pub fn take_pending(env: &Env, provider: &Address) -> Map<Asset, i128> {
+    settle(env, provider, stake_of(env, provider));
+    let key = StakingRewardKey::Pending(provider.clone());
+    let pending = env.storage().persistent().get(&key).unwrap_or(Map::new(env));
+    env.storage().persistent().remove(&key);
+    pending
+}
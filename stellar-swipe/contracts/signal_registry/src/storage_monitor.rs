@@ -4,20 +4,31 @@
 //! number of entries in the three largest instance maps (Signals, ProviderStats,
 //! ProviderStakes) as a proxy for usage and emit a warning event when the total
 //! exceeds 80% of the configured limit.
+//!
+//! Persistent entries (signal indexes and signal version history) are
+//! extended on write and their expiries tracked, so operators can see which
+//! categories are close to archival and bump them.
 
-use soroban_sdk::{Address, Env, Map};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, Map, Symbol, Val, Vec};
+use stellar_swipe_common::storage_health::{
+    self, CategoryHealth, TrackedEntry, DEFAULT_ENTRY_TTL_LEDGERS,
+};
 
 use crate::events::emit_storage_capacity_warning;
 use crate::expiry::archive_old_signals;
-use crate::types::{ProviderPerformance, Signal};
+use crate::types::{ProviderPerformance, Signal, SignalStatus};
 use crate::stake::StakeInfo;
+use crate::signal_index::SignalIndexKey;
+use crate::versioning::VersioningStorageKey;
 use crate::StorageKey;
+use stellar_swipe_common::AssetPair;
 
 /// Default entry-count limit for instance storage (conservative for 64 KB cap).
 pub const INSTANCE_ENTRY_LIMIT: u32 = 1000;
 /// Warning threshold: 80%.
 const WARNING_THRESHOLD_BPS: u32 = 8000;
 
+#[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct StorageUsage {
     pub signal_count: u32,
@@ -89,6 +100,82 @@ pub fn admin_cleanup_storage(env: &Env, batch_size: u32) -> u32 {
     archive_old_signals(env, &signals, batch_size)
 }
 
+/// A persistent entry whose expiry is tracked.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StorageEntry {
+    ProviderIndex(Address),
+    PairIndex(AssetPair),
+    StatusIndex(SignalStatus),
+    SignalVersion(u64, u32),
+}
+
+impl TrackedEntry for StorageEntry {
+    fn category(&self) -> Symbol {
+        match self {
+            StorageEntry::ProviderIndex(_)
+            | StorageEntry::PairIndex(_)
+            | StorageEntry::StatusIndex(_) => symbol_short!("sig_index"),
+            StorageEntry::SignalVersion(_, _) => symbol_short!("sig_vers"),
+        }
+    }
+
+    fn extend(&self, env: &Env, ledgers: u32) -> bool {
+        match self {
+            StorageEntry::ProviderIndex(provider) => {
+                extend_if_present(env, &SignalIndexKey::Provider(provider.clone()), ledgers)
+            }
+            StorageEntry::PairIndex(pair) => {
+                extend_if_present(env, &SignalIndexKey::Pair(pair.clone()), ledgers)
+            }
+            StorageEntry::StatusIndex(status) => {
+                extend_if_present(env, &SignalIndexKey::Status(status.clone()), ledgers)
+            }
+            StorageEntry::SignalVersion(signal_id, version) => extend_if_present(
+                env,
+                &VersioningStorageKey::SignalVersions(*signal_id, *version),
+                ledgers,
+            ),
+        }
+    }
+}
+
+fn extend_if_present<K: IntoVal<Env, Val>>(env: &Env, key: &K, ledgers: u32) -> bool {
+    if !env.storage().persistent().has(key) {
+        return false;
+    }
+    env.storage().persistent().extend_ttl(key, ledgers, ledgers);
+    true
+}
+
+/// Categories `get_storage_health` reports and `bump_ttls` accepts.
+pub fn storage_categories() -> [Symbol; 2] {
+    [symbol_short!("sig_index"), symbol_short!("sig_vers")]
+}
+
+/// Extend a freshly written entry and record its expiry.
+pub fn touch(env: &Env, entry: StorageEntry) {
+    if entry.extend(env, DEFAULT_ENTRY_TTL_LEDGERS) {
+        storage_health::record(env, &entry, DEFAULT_ENTRY_TTL_LEDGERS);
+    }
+}
+
+/// Stop tracking a removed entry.
+pub fn forget(env: &Env, entry: StorageEntry) {
+    storage_health::forget(env, &entry);
+}
+
+/// Tracked persistent entries and their minimum remaining TTL, per category.
+pub fn get_storage_health(env: &Env) -> Vec<CategoryHealth> {
+    storage_health::category_health::<StorageEntry>(env, &storage_categories())
+}
+
+/// Extend up to `limit` tracked entries of `category`, soonest expiring
+/// first. Returns the number extended.
+pub fn bump_ttls(env: &Env, category: &Symbol, limit: u32) -> u32 {
+    storage_health::bump::<StorageEntry>(env, category, limit, DEFAULT_ENTRY_TTL_LEDGERS)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,7 +188,7 @@ mod tests {
         Signal {
             id,
             provider: Address::generate(env),
-            asset_pair: String::from_str(env, "XLM/USDC"),
+            asset_pair: AssetPair::parse(env, &String::from_str(env, "XLM/USDC")).unwrap(),
             action: SignalAction::Buy,
             price: 100,
             rationale: String::from_str(env, "test"),
@@ -126,6 +213,9 @@ mod tests {
             warning_emitted: false,
             benchmark_return_bps: None,
             alpha_bps: None,
+            stop_loss: None,
+            take_profit: None,
+            premium: false,
         }
     }
 
@@ -212,4 +302,35 @@ mod tests {
             assert_eq!(after.signal_count, 3);
         });
     }
+
+    #[test]
+    fn test_storage_health_tracks_signal_indexes() {
+        let env = Env::default();
+        #[allow(deprecated)]
+        let cid = env.register_contract(None, crate::SignalRegistry);
+        env.as_contract(&cid, || {
+            let signal = make_signal(&env, 1, SignalStatus::Active, 1_000);
+            crate::signal_index::index_signal(&env, &signal);
+
+            // Provider, pair and status index entries.
+            let health = get_storage_health(&env);
+            let indexes = health.get(0).unwrap();
+            assert_eq!(indexes.category, symbol_short!("sig_index"));
+            assert_eq!(indexes.tracked, 3);
+            assert_eq!(indexes.min_ttl_ledgers, DEFAULT_ENTRY_TTL_LEDGERS);
+            assert_eq!(health.get(1).unwrap().tracked, 0);
+        });
+
+        env.ledger()
+            .set_sequence_number(env.ledger().sequence() + DEFAULT_ENTRY_TTL_LEDGERS - 100);
+        env.as_contract(&cid, || {
+            let indexes = get_storage_health(&env).get(0).unwrap();
+            assert_eq!(indexes.expiring, 3);
+
+            assert_eq!(bump_ttls(&env, &symbol_short!("sig_index"), 2), 2);
+            let indexes = get_storage_health(&env).get(0).unwrap();
+            assert_eq!(indexes.expiring, 1);
+            assert_eq!(indexes.min_ttl_ledgers, 100);
+        });
+    }
 }
@@ -0,0 +1,193 @@
+//! Paid subscriptions to providers.
+//!
+//! A provider sets a monthly price in a token of their choice. `subscribe`
+//! pulls one month's price from the subscriber and splits it between the
+//! provider and the platform treasury using the trade-fee split
+//! (`fees::subscription_breakdown`). The subscriber names the token and the
+//! most they will pay, so a provider changing their terms just before the
+//! subscription lands can't charge more than agreed. Renewing before expiry
+//! extends the current period rather than restarting it. An active
+//! subscription unlocks the provider's premium signals.
+
+use soroban_sdk::{contracttype, token, Address, Env};
+
+use crate::errors::SubscriptionError;
+use crate::fees;
+use crate::types::FeeBreakdown;
+
+/// One subscription period.
+pub const SUBSCRIPTION_PERIOD_SECS: u64 = 30 * 86_400;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubscriptionTerms {
+    /// Token the price is paid in.
+    pub token: Address,
+    pub monthly_price: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Subscription {
+    pub started_at: u64,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum SubscriptionKey {
+    Terms(Address),
+    /// (subscriber, provider)
+    Subscription(Address, Address),
+}
+
+pub fn set_terms(
+    env: &Env,
+    provider: &Address,
+    token: Address,
+    monthly_price: i128,
+) -> Result<(), SubscriptionError> {
+    if monthly_price <= 0 {
+        return Err(SubscriptionError::InvalidPrice);
+    }
+    env.storage().persistent().set(
+        &SubscriptionKey::Terms(provider.clone()),
+        &SubscriptionTerms {
+            token,
+            monthly_price,
+        },
+    );
+    Ok(())
+}
+
+pub fn get_terms(env: &Env, provider: &Address) -> Option<SubscriptionTerms> {
+    env.storage()
+        .persistent()
+        .get(&SubscriptionKey::Terms(provider.clone()))
+}
+
+pub fn get_subscription(env: &Env, subscriber: &Address, provider: &Address) -> Option<Subscription> {
+    env.storage()
+        .persistent()
+        .get(&SubscriptionKey::Subscription(subscriber.clone(), provider.clone()))
+}
+
+pub fn is_active(env: &Env, subscriber: &Address, provider: &Address) -> bool {
+    get_subscription(env, subscriber, provider)
+        .map(|s| s.expires_at > env.ledger().timestamp())
+        .unwrap_or(false)
+}
+
+/// Charge `subscriber` one month of `provider`'s price and extend their
+/// subscription, provided the price is in `expected_token` and at most
+/// `max_price`. Returns the new subscription and how the payment was split.
+pub fn subscribe(
+    env: &Env,
+    subscriber: &Address,
+    provider: &Address,
+    expected_token: &Address,
+    max_price: i128,
+) -> Result<(Subscription, FeeBreakdown), SubscriptionError> {
+    if subscriber == provider {
+        return Err(SubscriptionError::SelfSubscribe);
+    }
+    let terms = get_terms(env, provider).ok_or(SubscriptionError::TermsNotSet)?;
+    if terms.token != *expected_token {
+        return Err(SubscriptionError::TokenMismatch);
+    }
+    if terms.monthly_price > max_price {
+        return Err(SubscriptionError::PriceAboveMax);
+    }
+    let treasury = fees::get_platform_treasury(env).ok_or(SubscriptionError::TreasuryNotSet)?;
+    let breakdown = fees::subscription_breakdown(terms.monthly_price)
+        .map_err(|_| SubscriptionError::InvalidPrice)?;
+
+    let token = token::Client::new(env, &terms.token);
+    if breakdown.provider_fee > 0 {
+        token.transfer(subscriber, provider, &breakdown.provider_fee);
+    }
+    if breakdown.platform_fee > 0 {
+        token.transfer(subscriber, &treasury, &breakdown.platform_fee);
+    }
+
+    let now = env.ledger().timestamp();
+    let subscription = match get_subscription(env, subscriber, provider) {
+        Some(current) if current.expires_at > now => Subscription {
+            started_at: current.started_at,
+            expires_at: current.expires_at + SUBSCRIPTION_PERIOD_SECS,
+        },
+        _ => Subscription {
+            started_at: now,
+            expires_at: now + SUBSCRIPTION_PERIOD_SECS,
+        },
+    };
+    env.storage().persistent().set(
+        &SubscriptionKey::Subscription(subscriber.clone(), provider.clone()),
+        &subscription,
+    );
+    Ok((subscription, breakdown))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::token::StellarAssetClient;
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn subscribe_splits_payment_and_extends_expiry() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register(TestContract, ());
+        let admin = Address::generate(&env);
+        let provider = Address::generate(&env);
+        let subscriber = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let sac = env.register_stellar_asset_contract_v2(admin);
+        StellarAssetClient::new(&env, &sac.address()).mint(&subscriber, &1_000);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                subscribe(&env, &subscriber, &provider, &sac.address(), 100).err(),
+                Some(SubscriptionError::TermsNotSet)
+            );
+            set_terms(&env, &provider, sac.address(), 100).unwrap();
+            assert_eq!(
+                subscribe(&env, &subscriber, &provider, &sac.address(), 99).err(),
+                Some(SubscriptionError::PriceAboveMax)
+            );
+            assert_eq!(
+                subscribe(&env, &subscriber, &provider, &treasury, 100).err(),
+                Some(SubscriptionError::TokenMismatch)
+            );
+            assert_eq!(
+                subscribe(&env, &subscriber, &provider, &sac.address(), 100).err(),
+                Some(SubscriptionError::TreasuryNotSet)
+            );
+            fees::set_platform_treasury(&env, treasury.clone());
+
+            let (first, breakdown) = subscribe(&env, &subscriber, &provider, &sac.address(), 100).unwrap();
+            assert_eq!(breakdown.platform_fee, 70);
+            assert_eq!(breakdown.provider_fee, 30);
+            assert!(is_active(&env, &subscriber, &provider));
+
+            // Renewing early stacks another period on the current one.
+            env.ledger().with_mut(|l| l.timestamp += 1_000);
+            let (second, _) = subscribe(&env, &subscriber, &provider, &sac.address(), 100).unwrap();
+            assert_eq!(second.started_at, first.started_at);
+            assert_eq!(second.expires_at, first.expires_at + SUBSCRIPTION_PERIOD_SECS);
+
+            env.ledger().with_mut(|l| l.timestamp = second.expires_at);
+            assert!(!is_active(&env, &subscriber, &provider));
+        });
+
+        let token = token::Client::new(&env, &sac.address());
+        assert_eq!(token.balance(&provider), 60);
+        assert_eq!(token.balance(&treasury), 140);
+        assert_eq!(token.balance(&subscriber), 800);
+    }
+}
@@ -4,12 +4,12 @@ extern crate std;
 
 use super::*;
 use crate::categories::{RiskLevel, SignalCategory};
-use crate::errors::AdminError;
-use crate::migration::test_seed_v1_signals;
+use crate::errors::{AdminError, ExportError};
+use crate::migration::{test_seed_v1_signals, test_seed_v2_signals};
 use soroban_sdk::{
     testutils::Address as _,
     testutils::Ledger,
-    vec, Env, Map, String,
+    vec, BytesN, Env, Map, String,
 };
 
 #[test]
@@ -56,6 +56,7 @@ fn create_and_read_signal() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let expiry = env.ledger().timestamp() + 60;
@@ -89,10 +90,11 @@ fn test_submit_signal_from_template() {
     let admin = Address::generate(&env);
     let provider = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let template_id = client.save_signal_template(
         &provider,
-        &SignalTemplate {
+        &SavedSignalTemplate {
             asset_pair: String::from_str(&env, "XLM/USDC"),
             action: SignalAction::Buy,
             risk_rating: 3,
@@ -116,7 +118,7 @@ fn test_submit_signal_from_template() {
     );
 
     let signal = client.get_signal(&signal_id).unwrap();
-    assert_eq!(signal.asset_pair, String::from_str(&env, "XLM/USDC"));
+    assert_eq!(signal.asset_pair.to_string(&env), String::from_str(&env, "XLM/USDC"));
     assert_eq!(signal.price, 100_000);
     assert_eq!(
         signal.rationale,
@@ -135,6 +137,7 @@ fn test_invalid_asset_pair_rejected() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let expiry = env.ledger().timestamp() + 60;
@@ -190,6 +193,7 @@ fn test_custom_asset_pair_with_issuer() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let expiry = env.ledger().timestamp() + 60;
@@ -212,6 +216,38 @@ fn test_custom_asset_pair_with_issuer() {
     assert!(signal_id > 0);
 }
 
+#[test]
+fn test_asset_pair_stored_in_canonical_form() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+
+    let provider = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 60;
+
+    let signal_id = client.create_signal(
+        &provider,
+        &String::from_str(&env, "xlm/usdc"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Lower case pair"),
+        &expiry,
+        &SignalCategory::SWING,
+        &vec![&env, String::from_str(&env, "test")],
+        &RiskLevel::Medium,
+    );
+
+    let signal = client.get_signal(&signal_id).unwrap();
+    assert_eq!(signal.asset_pair.to_string(&env), String::from_str(&env, "XLM/USDC"));
+}
+
 #[test]
 fn test_pause_blocks_signals() {
     let env = Env::default();
@@ -223,6 +259,7 @@ fn test_pause_blocks_signals() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let expiry = env.ledger().timestamp() + 60;
@@ -539,7 +576,7 @@ fn test_invalid_parameter_updates() {
 }
 
 #[test]
-fn provider_stats_initialized() {
+fn test_active_signal_cap_per_tier() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -549,28 +586,49 @@ fn provider_stats_initialized() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
-    let expiry = env.ledger().timestamp() + 120;
+    let expiry = env.ledger().timestamp() + 3600;
+    let submit = |client: &SignalRegistryClient| {
+        client.try_create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Capped"),
+            &expiry,
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        )
+    };
 
-    client.create_signal(
-        &provider,
-        &String::from_str(&env, "XLM/BTC"),
-        &SignalAction::Sell,
-        &200_000,
-        &String::from_str(&env, "Resistance hit"),
-        &expiry,
-        &SignalCategory::SWING,
-        &vec![&env, String::from_str(&env, "test")],
-        &RiskLevel::Medium,
+    // Unstaked providers fall into the bronze tier (default 5 active signals).
+    for _ in 0..5 {
+        assert!(submit(&client).is_ok());
+    }
+    assert_eq!(client.get_active_signal_count(&provider), 5);
+    assert_eq!(
+        submit(&client),
+        Err(Ok(AdminError::TooManyActiveSignals))
     );
 
-    let stats = client.get_provider_stats(&provider).unwrap();
-    assert_eq!(stats.total_copies, 0);
+    // Limits must be non-zero and non-decreasing across tiers.
+    assert!(client
+        .try_set_tier_signal_limits(&admin, &0, &10, &20)
+        .is_err());
+    assert!(client
+        .try_set_tier_signal_limits(&admin, &12, &10, &20)
+        .is_err());
+
+    client.set_tier_signal_limits(&admin, &6, &10, &20);
+    assert!(submit(&client).is_ok());
+    assert_eq!(client.get_active_signal_count(&provider), 6);
 }
 
 #[test]
-fn test_fee_calculation_and_collection() {
+fn test_signal_creation_cooldown() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -580,22 +638,34 @@ fn test_fee_calculation_and_collection() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+    client.set_signal_cooldown(&admin, &600);
+    assert_eq!(client.get_signal_cooldown(), 600);
 
-    // Set platform treasury
-    let platform_treasury = Address::generate(&env);
-    client.set_platform_treasury(&admin, &platform_treasury);
+    let provider = Address::generate(&env);
+    let submit = |client: &SignalRegistryClient| {
+        client.try_create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Cooldown"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        )
+    };
 
-    // Preview fee for 1000 XLM trade
-    let breakdown = client.calculate_fee_preview(&1_000_000_000);
+    assert!(submit(&client).is_ok());
+    assert_eq!(submit(&client), Err(Ok(AdminError::SignalCooldownActive)));
 
-    assert_eq!(breakdown.total_fee, 1_000_000); // 1 XLM (0.1%)
-    assert_eq!(breakdown.platform_fee, 700_000); // 0.7 XLM (70%)
-    assert_eq!(breakdown.provider_fee, 300_000); // 0.3 XLM (30%)
-    assert_eq!(breakdown.trade_amount_after_fee, 999_000_000); // 999 XLM
+    env.ledger().with_mut(|li| li.timestamp += 600);
+    assert!(submit(&client).is_ok());
 }
 
 #[test]
-fn test_minimum_trade_enforcement() {
+fn test_provider_registration() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -605,18 +675,42 @@ fn test_minimum_trade_enforcement() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+    client.set_registration_required(&admin, &true);
 
-    // Trade below minimum should fail
-    let result = client.try_calculate_fee_preview(&999);
-    assert!(result.is_err());
+    let provider = Address::generate(&env);
+    let submit = |client: &SignalRegistryClient| {
+        client.try_create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Registered"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        )
+    };
+    assert_eq!(submit(&client), Err(Ok(AdminError::ProviderNotRegistered)));
 
-    // Trade at minimum should work
-    let result = client.try_calculate_fee_preview(&1000);
-    assert!(result.is_ok());
+    assert_eq!(
+        client.try_register_provider(&provider, &String::from_str(&env, ""), &String::from_str(&env, "")),
+        Err(Ok(RegistrationError::InvalidDisplayName))
+    );
+    client.register_provider(
+        &provider,
+        &String::from_str(&env, "Alpha Desk"),
+        &String::from_str(&env, "ipfs://bio"),
+    );
+    let registration = client.get_provider_registration(&provider).unwrap();
+    assert_eq!(registration.display_name, String::from_str(&env, "Alpha Desk"));
+    assert_eq!(registration.bio_uri, String::from_str(&env, "ipfs://bio"));
+    assert!(submit(&client).is_ok());
 }
 
 #[test]
-fn test_platform_treasury_management() {
+fn test_daily_analytics_snapshots() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -626,18 +720,44 @@ fn test_platform_treasury_management() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
-    // No treasury set initially
-    assert_eq!(client.get_platform_treasury(), None);
-
-    // Set treasury
-    let treasury = Address::generate(&env);
-    client.set_platform_treasury(&admin, &treasury);
-    assert_eq!(client.get_platform_treasury(), Some(treasury));
+    let provider = Address::generate(&env);
+    let create = |env: &Env| {
+        client.create_signal(
+            &provider,
+            &String::from_str(env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(env, "Range breakout"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SWING,
+            &vec![env, String::from_str(env, "test")],
+            &RiskLevel::Medium,
+        );
+    };
+
+    env.ledger().with_mut(|l| l.timestamp = 10 * 86_400 + 100);
+    create(&env);
+    create(&env);
+    assert_eq!(client.snapshot_analytics().signal_count, 2);
+
+    // Next day only counts its own signals.
+    env.ledger().with_mut(|l| l.timestamp = 11 * 86_400 + 100);
+    create(&env);
+    let day_11 = client.snapshot_analytics();
+    assert_eq!(day_11.day, 11);
+    assert_eq!(day_11.signal_count, 1);
+
+    let history = client.get_analytics_history(&9, &12);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().day, 10);
+    assert_eq!(history.get(1).unwrap(), day_11);
+    assert_eq!(client.get_analytics_history(&12, &9).len(), 0);
 }
 
 #[test]
-fn test_unauthorized_treasury_update() {
+fn test_premium_signal_gating() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -646,18 +766,58 @@ fn test_unauthorized_treasury_update() {
     let client = SignalRegistryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    let attacker = Address::generate(&env);
-    let treasury = Address::generate(&env);
-
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
-    // Non-admin cannot set treasury
-    let result = client.try_set_platform_treasury(&attacker, &treasury);
-    assert!(result.is_err());
+    let provider = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let signal_id = client.create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Breakout above range"),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::SWING,
+        &vec![&env, String::from_str(&env, "test")],
+        &RiskLevel::Medium,
+    );
+    assert!(client.try_get_premium_content(&signal_id, &stranger).is_ok());
+
+    assert_eq!(
+        client.try_set_signal_premium(&stranger, &signal_id, &true),
+        Err(Ok(PremiumError::NotSignalOwner))
+    );
+    client.set_signal_premium(&provider, &signal_id, &true);
+
+    let public = client.get_signal_public(&signal_id).unwrap();
+    assert!(public.premium);
+    assert_eq!(public.asset_pair.to_string(&env), String::from_str(&env, "XLM/USDC"));
+    assert_eq!(
+        client.try_get_premium_content(&signal_id, &stranger),
+        Err(Ok(PremiumError::NotSubscribed))
+    );
+    assert!(client.get_signal_for_viewer(&signal_id, &stranger).is_none());
+
+    let content = client.get_premium_content(&signal_id, &provider);
+    assert_eq!(content.price, 100_000);
+    assert_eq!(content.rationale, String::from_str(&env, "Breakout above range"));
+
+    // The open getters serve it redacted; the provider still sees it whole.
+    let redacted = client.get_signal(&signal_id).unwrap();
+    assert_eq!(redacted.price, 0);
+    assert_eq!(redacted.rationale, String::from_str(&env, ""));
+    assert_eq!(redacted.asset_pair.to_string(&env), String::from_str(&env, "XLM/USDC"));
+    let listed = client.get_signals(&0, &10).signals.get(0).unwrap();
+    assert_eq!(listed.price, 0);
+    let by_provider = client.get_signals_by_provider(&provider, &0, &10);
+    assert_eq!(by_provider.signals.get(0).unwrap().price, 0);
+    let full = client.get_signal_for_viewer(&signal_id, &provider).unwrap();
+    assert_eq!(full.price, 100_000);
 }
 
 #[test]
-fn test_get_active_signals_excludes_expired() {
+fn provider_stats_initialized() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -667,61 +827,45 @@ fn test_get_active_signals_excludes_expired() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
-
-    // Set a known timestamp
-    use soroban_sdk::testutils::Ledger;
-    env.ledger().set_timestamp(10000);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
-    let current_time = env.ledger().timestamp();
-
-    // Create 3 active signals
-    for _i in 0..3 {
-        client.create_signal(
-            &provider,
-            &String::from_str(&env, "XLM/USDC"),
-            &SignalAction::Buy,
-            &100_000,
-            &String::from_str(&env, "Active"),
-            &(current_time + 10000),
-            &SignalCategory::SWING,
-            &vec![&env, String::from_str(&env, "test")],
-            &RiskLevel::Medium,
-        );
-    }
-
-    // Create 2 expired signals
-    for _i in 0..2 {
-        client.create_signal(
-            &provider,
-            &String::from_str(&env, "XLM/BTC"),
-            &SignalAction::Sell,
-            &200_000,
-            &String::from_str(&env, "Expired"),
-            &(current_time + 10),
-            &SignalCategory::SWING,
-            &vec![&env, String::from_str(&env, "test")],
-            &RiskLevel::Medium,
-        );
-    }
+    let expiry = env.ledger().timestamp() + 120;
 
-    // Move time to expire the second batch
-    env.ledger().set_timestamp(current_time + 100);
+    client.create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/BTC"),
+        &SignalAction::Sell,
+        &200_000,
+        &String::from_str(&env, "Resistance hit"),
+        &expiry,
+        &SignalCategory::SWING,
+        &vec![&env, String::from_str(&env, "test")],
+        &RiskLevel::Medium,
+    );
 
-    // Get active signals - should only return 3 (followed_only = false)
-    let any_user = Address::generate(&env);
-    let active = client.get_active_signals_archived(&any_user, &false);
-    assert_eq!(active.len(), 3);
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.total_copies, 0);
+}
 
-    // All returned signals should be active
-    for i in 0..active.len() {
-        let signal = active.get(i).unwrap();
-        assert_eq!(signal.rationale, String::from_str(&env, "Active"));
-    }
+fn create_attribution_signal(env: &Env, client: &SignalRegistryClient, provider: &Address) -> u64 {
+    // Attribution tests don't exercise staking.
+    client.set_min_stake(&client.get_admin(), &0);
+    client.create_signal(
+        provider,
+        &String::from_str(env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(env, "Attribution"),
+        &(env.ledger().timestamp() + 86_400),
+        &SignalCategory::SWING,
+        &vec![env, String::from_str(env, "test")],
+        &RiskLevel::Medium,
+    )
 }
 
 #[test]
-fn test_cleanup_batch_limit() {
+fn test_only_executors_can_rate_signals() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -732,47 +876,92 @@ fn test_cleanup_batch_limit() {
     let admin = Address::generate(&env);
     client.initialize(&admin);
 
-    // Set a known timestamp
-    use soroban_sdk::testutils::Ledger;
-    env.ledger().set_timestamp(10000);
-
     let provider = Address::generate(&env);
-    let current_time = env.ledger().timestamp();
+    let signal_id = create_attribution_signal(&env, &client, &provider);
+    let executor = Address::generate(&env);
+    let bystander = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
 
-    // Create 150 expired signals
-    for _ in 0..150 {
-        client.create_signal(
-            &provider,
-            &String::from_str(&env, "XLM/USDC"),
-            &SignalAction::Buy,
-            &100_000,
-            &String::from_str(&env, "Test"),
-            &(current_time + 10),
-            &SignalCategory::SWING,
-            &vec![&env, String::from_str(&env, "test")],
-            &RiskLevel::Medium,
-        );
-    }
+    client.record_trade_execution(&executor, &signal_id, &100_000, &105_000, &500);
+    assert_eq!(
+        client.try_rate_signal(&bystander, &signal_id, &5, &hash),
+        Err(Ok(RatingError::NotExecutor))
+    );
+    client.rate_signal(&executor, &signal_id, &4, &hash);
 
-    // Move time to expire all
-    env.ledger().set_timestamp(current_time + 100);
+    assert_eq!(client.get_signal_rating(&signal_id).average_x100, 400);
+    assert_eq!(client.get_provider_rating(&provider).count, 1);
+    assert_eq!(client.get_executor_rating(&signal_id, &executor).unwrap().comment_hash, hash);
 
-    // Cleanup with limit of 50
-    let (processed, expired) = client.cleanup_expired_signals(&50);
+    // A trade recorded before the executor index existed counts once backfilled.
+    let early = Address::generate(&env);
+    client.record_trade_execution(&early, &signal_id, &100_000, &105_000, &500);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .remove(&ratings::RatingKey::Executed(signal_id, early.clone()));
+    });
+    assert_eq!(
+        client.try_rate_signal(&early, &signal_id, &3, &hash),
+        Err(Ok(RatingError::NotExecutor))
+    );
+    let progress = client.backfill_executor_index(&admin, &0, &50);
+    assert_eq!(progress.indexed, 1);
+    assert_eq!(progress.total, 2);
+    assert!(progress.done);
+    client.rate_signal(&early, &signal_id, &3, &hash);
+    assert_eq!(client.get_signal_rating(&signal_id).count, 2);
+}
 
-    assert_eq!(processed, 50);
-    assert_eq!(expired, 50);
+#[test]
+fn test_copier_stats_only_include_consenting_executors() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // Run again to process more
-    let (processed2, expired2) = client.cleanup_expired_signals(&50);
-    assert_eq!(processed2, 50);
-    assert_eq!(expired2, 50);
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let signal_id = create_attribution_signal(&env, &client, &provider);
+    let sharer = Address::generate(&env);
+    let private = Address::generate(&env);
+    let revoker = Address::generate(&env);
+
+    // Executions before opting in stay private.
+    client.record_trade_execution(&sharer, &signal_id, &100_000, &105_000, &500);
+    client.set_data_sharing(&sharer, &provider, &true);
+    client.set_data_sharing(&revoker, &provider, &true);
+    assert!(client.is_sharing_data(&sharer, &provider));
+    assert!(!client.is_sharing_data(&private, &provider));
+
+    client.record_trade_execution(&sharer, &signal_id, &100_000, &101_000, &1_000);
+    client.record_trade_execution(&private, &signal_id, &100_000, &110_000, &9_000);
+    client.record_trade_execution(&revoker, &signal_id, &100_000, &103_000, &2_000);
+
+    let stats = client.get_my_copier_stats(&provider);
+    assert_eq!(stats.consenting_copiers, 2);
+    assert_eq!(stats.total_trades, 2);
+    assert_eq!(stats.total_volume, 3_000);
+    assert_eq!(stats.avg_roi, 200);
+
+    // Revoking removes what was shared.
+    client.set_data_sharing(&revoker, &provider, &false);
+    let stats = client.get_my_copier_stats(&provider);
+    assert_eq!(stats.consenting_copiers, 1);
+    assert_eq!(stats.copiers.get(0).unwrap().copier, sharer);
+    assert_eq!(stats.total_volume, 1_000);
+    assert_eq!(stats.avg_roi, 100);
 }
 
 #[test]
-fn test_pending_expiry_count() {
+fn test_export_with_metadata_describes_export() {
     let env = Env::default();
     env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
 
     #[allow(deprecated)]
     let contract_id = env.register_contract(None, SignalRegistry);
@@ -781,50 +970,50 @@ fn test_pending_expiry_count() {
     let admin = Address::generate(&env);
     client.initialize(&admin);
 
-    // Set a known timestamp
-    use soroban_sdk::testutils::Ledger;
-    env.ledger().set_timestamp(10000);
-
     let provider = Address::generate(&env);
-    let current_time = env.ledger().timestamp();
+    let executor = Address::generate(&env);
+    let signal_id = create_attribution_signal(&env, &client, &provider);
+    create_attribution_signal(&env, &client, &provider);
+    client.record_trade_execution(&executor, &signal_id, &100_000, &101_000, &1_000);
+
+    let signals = client.export_with_metadata(&provider, &0, &0, &None);
+    assert_eq!(signals.data, client.export_signals(&provider, &0, &None));
+    assert_eq!(signals.metadata.entity, 0);
+    assert_eq!(signals.metadata.format, 0);
+    assert_eq!(signals.metadata.record_count, 2);
+    assert_eq!(signals.metadata.range_start, 0);
+    assert_eq!(signals.metadata.range_end, u64::MAX);
+    assert_eq!(signals.metadata.generated_at, 1_700_000_000);
+    let hash: BytesN<32> = env.crypto().sha256(&signals.data).into();
+    assert_eq!(signals.metadata.content_hash, hash);
+
+    // Same data, same hash, regardless of when it is generated.
+    env.ledger().set_timestamp(1_700_000_100);
+    let again = client.export_with_metadata(&provider, &0, &0, &None);
+    assert_eq!(again.metadata.content_hash, signals.metadata.content_hash);
+    assert_eq!(again.metadata.generated_at, 1_700_000_100);
+
+    let range = (1_600_000_000u64, 1_800_000_000u64);
+    let trades = client.export_with_metadata(&executor, &1, &1, &Some(range));
+    assert_eq!(trades.metadata.record_count, 1);
+    assert_eq!(trades.metadata.range_start, range.0);
+    assert_eq!(trades.metadata.range_end, range.1);
 
-    // Create signals that will be past expiry
-    for _i in 0..4 {
-        client.create_signal(
-            &provider,
-            &String::from_str(&env, "XLM/USDC"),
-            &SignalAction::Buy,
-            &100_000,
-            &String::from_str(&env, "Test"),
-            &(current_time + 10),
-            &SignalCategory::SWING,
-            &vec![&env, String::from_str(&env, "test")],
-            &RiskLevel::Medium,
-        );
-    }
-
-    // Initially no pending expiry
-    assert_eq!(client.get_pending_expiry_count(), 0);
-
-    // Move time forward
-    env.ledger().set_timestamp(current_time + 100);
-
-    // Now should have 4 pending expiry
-    assert_eq!(client.get_pending_expiry_count(), 4);
-
-    // After cleanup, none pending
-    client.cleanup_expired_signals(&10);
-    assert_eq!(client.get_pending_expiry_count(), 0);
+    assert_eq!(
+        client.try_export_with_metadata(&provider, &0, &7, &None),
+        Err(Ok(ExportError::UnsupportedFormat))
+    );
+    assert_eq!(
+        client.try_export_with_metadata(&provider, &9, &0, &None),
+        Err(Ok(ExportError::UnsupportedFormat))
+    );
 }
 
-// ========================================
-// Social / Follow Tests
-// ========================================
-
 #[test]
-fn test_follow_provider() {
+fn test_export_executor_performance() {
     let env = Env::default();
     env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
 
     #[allow(deprecated)]
     let contract_id = env.register_contract(None, SignalRegistry);
@@ -833,21 +1022,31 @@ fn test_follow_provider() {
     let admin = Address::generate(&env);
     client.initialize(&admin);
 
-    let user = Address::generate(&env);
     let provider = Address::generate(&env);
-
-    client.follow_provider(&user, &provider);
-    assert_eq!(client.get_follower_count(&provider), 1);
-
-    let followed = client.get_followed_providers(&user);
-    assert_eq!(followed.len(), 1);
-    assert_eq!(followed.get(0).unwrap(), provider);
+    let executor = Address::generate(&env);
+    let first = create_attribution_signal(&env, &client, &provider);
+    let second = create_attribution_signal(&env, &client, &provider);
+
+    env.ledger().set_timestamp(1_700_000_600);
+    client.record_trade_execution(&executor, &first, &100_000, &101_000, &1_000);
+    env.ledger().set_timestamp(1_700_001_800);
+    client.record_trade_execution(&executor, &second, &100_000, &99_500, &2_000);
+
+    let json = client.export_executor_performance(&executor, &1, &None);
+    let expected = r#"{"total_trades":2,"winning_trades":1,"win_rate":"50.00%","total_volume":3000,"total_pnl":0,"best_pair":"XLM/USDC","worst_pair":"XLM/USDC","avg_hold_secs":1200}"#;
+    assert_eq!(json, Bytes::from_slice(&env, expected.as_bytes()));
+
+    let meta = client.export_with_metadata(&executor, &5, &0, &None);
+    assert_eq!(meta.data, client.export_executor_performance(&executor, &0, &None));
+    assert_eq!(meta.metadata.record_count, 1);
 }
 
 #[test]
-fn test_follow_idempotent() {
+fn test_export_tax_report_by_year() {
     let env = Env::default();
     env.mock_all_auths();
+    // 2023-12-31T05:20:00Z
+    env.ledger().set_timestamp(1_704_000_000);
 
     #[allow(deprecated)]
     let contract_id = env.register_contract(None, SignalRegistry);
@@ -856,16 +1055,52 @@ fn test_follow_idempotent() {
     let admin = Address::generate(&env);
     client.initialize(&admin);
 
-    let user = Address::generate(&env);
     let provider = Address::generate(&env);
+    let executor = Address::generate(&env);
+    let first = create_attribution_signal(&env, &client, &provider);
+    let second = create_attribution_signal(&env, &client, &provider);
+
+    env.ledger().set_timestamp(1_704_000_600);
+    client.record_trade_execution(&executor, &first, &100_000, &101_000, &1_000);
+    // 2024-01-01T00:06:40Z
+    env.ledger().set_timestamp(1_704_067_600);
+    client.record_trade_execution(&executor, &second, &100_000, &99_500, &2_000);
+
+    let csv = client.export_tax_report(&executor, &0, &0);
+    let expected = std::format!(
+        "tax_year,trade_id,signal_id,asset_pair,side,opened_at,closed_at,volume,entry_price,exit_price,realized_pnl\n\
+         2023,1,{},XLM/USDC,BUY,1704000000,1704000600,1000,100000,101000,10\n\
+         2024,2,{},XLM/USDC,BUY,1704000000,1704067600,2000,100000,99500,-10\n",
+        first, second
+    );
+    assert_eq!(csv, Bytes::from_slice(&env, expected.as_bytes()));
 
-    client.follow_provider(&user, &provider);
-    client.follow_provider(&user, &provider); // idempotent
-    assert_eq!(client.get_follower_count(&provider), 1);
+    let json = client.export_tax_report(&executor, &1, &0);
+    let expected = std::format!(
+        r#"[{{"tax_year":2023,"realized_pnl":10,"lots":[{{"trade_id":1,"signal_id":{},"asset_pair":"XLM/USDC","side":"BUY","opened_at":1704000000,"closed_at":1704000600,"volume":1000,"entry_price":100000,"exit_price":101000,"realized_pnl":10}}]}},{{"tax_year":2024,"realized_pnl":-10,"lots":[{{"trade_id":2,"signal_id":{},"asset_pair":"XLM/USDC","side":"BUY","opened_at":1704000000,"closed_at":1704067600,"volume":2000,"entry_price":100000,"exit_price":99500,"realized_pnl":-10}}]}}]"#,
+        first, second
+    );
+    assert_eq!(json, Bytes::from_slice(&env, expected.as_bytes()));
+
+    // The 7-day preset covers both trades now; a week after the first, only
+    // the second.
+    assert_eq!(client.export_tax_report(&executor, &1, &7), json);
+    env.ledger().set_timestamp(1_704_000_600 + 7 * 86_400 + 1);
+    let recent = client.export_tax_report(&executor, &0, &7);
+    let expected = std::format!(
+        "tax_year,trade_id,signal_id,asset_pair,side,opened_at,closed_at,volume,entry_price,exit_price,realized_pnl\n\
+         2024,2,{},XLM/USDC,BUY,1704000000,1704067600,2000,100000,99500,-10\n",
+        second
+    );
+    assert_eq!(recent, Bytes::from_slice(&env, expected.as_bytes()));
+    assert_eq!(
+        client.try_export_tax_report(&executor, &0, &14),
+        Err(Ok(ExportError::InvalidRangePreset))
+    );
 }
 
 #[test]
-fn test_cannot_follow_self() {
+fn test_export_filtered_by_pair_action_and_status() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -875,14 +1110,59 @@ fn test_cannot_follow_self() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
-    let user = Address::generate(&env);
-    let result = client.try_follow_provider(&user, &user);
-    assert!(result.is_err());
+    let provider = Address::generate(&env);
+    let executor = Address::generate(&env);
+    let xlm_buy = create_attribution_signal(&env, &client, &provider);
+    let btc_sell = client.create_signal(
+        &provider,
+        &String::from_str(&env, "BTC/USDC"),
+        &SignalAction::Sell,
+        &100_000,
+        &String::from_str(&env, "Rejected at resistance"),
+        &(env.ledger().timestamp() + 86_400),
+        &SignalCategory::SWING,
+        &vec![&env, String::from_str(&env, "test")],
+        &RiskLevel::Medium,
+    );
+    client.record_trade_execution(&executor, &xlm_buy, &100_000, &101_000, &1_000);
+    client.record_trade_execution(&executor, &btc_sell, &100_000, &101_000, &1_000);
+
+    let btc_sells = export::ExportFilter {
+        asset_pairs: vec![&env, AssetPair::parse(&env, &String::from_str(&env, "BTC/USDC")).unwrap()],
+        actions: vec![&env, SignalAction::Sell],
+        statuses: Vec::new(&env),
+    };
+    let trades = client.export_filtered(&executor, &1, &0, &None, &btc_sells);
+    assert_eq!(trades.metadata.record_count, 1);
+    let csv = bytes_to_std(&trades.data);
+    assert!(csv.contains("BTC/USDC"));
+    assert!(!csv.contains("XLM/USDC"));
+
+    // No BTC/USDC Buys were published.
+    let btc_buys = export::ExportFilter {
+        actions: vec![&env, SignalAction::Buy],
+        ..btc_sells.clone()
+    };
+    let signals = client.export_filtered(&provider, &0, &0, &None, &btc_buys);
+    assert_eq!(signals.metadata.record_count, 0);
+
+    let active = export::ExportFilter {
+        asset_pairs: Vec::new(&env),
+        actions: Vec::new(&env),
+        statuses: vec![&env, SignalStatus::Active],
+    };
+    let signals = client.export_filtered(&provider, &0, &0, &None, &active);
+    assert_eq!(signals.metadata.record_count, 2);
+}
+
+fn bytes_to_std(bytes: &Bytes) -> std::string::String {
+    std::string::String::from_utf8(bytes.iter().collect()).unwrap()
 }
 
 #[test]
-fn test_unfollow_provider() {
+fn test_export_long_rationale_with_quotes_and_commas() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -892,20 +1172,36 @@ fn test_unfollow_provider() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
-    let user = Address::generate(&env);
+    // 1.2KB of text followed by a quote and a comma.
+    let long = "a".repeat(1_200);
+    let rationale = std::format!("{}, then \"breakout\"", long);
     let provider = Address::generate(&env);
+    client.create_signal(
+        &provider,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, &rationale),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::SWING,
+        &vec![&env, String::from_str(&env, "test")],
+        &RiskLevel::Medium,
+    );
 
-    client.follow_provider(&user, &provider);
-    assert_eq!(client.get_follower_count(&provider), 1);
+    let csv = bytes_to_std(&client.export_signals(&provider, &0, &None));
+    assert!(csv.contains(&std::format!(",\"{}, then \"\"breakout\"\"\",", long)));
 
-    client.unfollow_provider(&user, &provider);
-    assert_eq!(client.get_follower_count(&provider), 0);
-    assert_eq!(client.get_followed_providers(&user).len(), 0);
+    let json = bytes_to_std(&client.export_signals(&provider, &1, &None));
+    assert!(json.contains(&std::format!(
+        r#""rationale":"{}, then \"breakout\"","#,
+        long
+    )));
 }
 
 #[test]
-fn test_unfollow_not_following_no_error() {
+fn test_volume_weighted_attribution() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -915,15 +1211,29 @@ fn test_unfollow_not_following_no_error() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    assert_eq!(client.get_attribution_mode(), types::AttributionMode::AverageExecution);
+    client.set_attribution_mode(&admin, &types::AttributionMode::VolumeWeighted, &0);
 
-    let user = Address::generate(&env);
     let provider = Address::generate(&env);
+    let signal_id = create_attribution_signal(&env, &client, &provider);
 
-    client.unfollow_provider(&user, &provider); // no error
+    // Small +1% fill, then a large -8% fill. The plain average (-3.5%) would
+    // leave the signal active; weighted by volume it is -7.1%.
+    client.record_trade_execution(&Address::generate(&env), &signal_id, &100_000, &101_000, &1_000);
+    client.record_trade_execution(&Address::generate(&env), &signal_id, &100_000, &92_000, &9_000);
+
+    let perf = client.get_signal_performance(&signal_id).unwrap();
+    assert_eq!(perf.average_roi, -350);
+    assert_eq!(perf.status, SignalStatus::Failed);
+
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.failed_signals, 1);
+    assert_eq!(stats.avg_return, -710);
+    assert_eq!(stats.attribution_mode, types::AttributionMode::VolumeWeighted);
 }
 
 #[test]
-fn test_feed_filtered_by_followed() {
+fn test_oracle_horizon_attribution() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -933,54 +1243,45 @@ fn test_feed_filtered_by_followed() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    assert_eq!(
+        client.try_set_attribution_mode(&admin, &types::AttributionMode::OracleHorizon, &0),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+    client.set_attribution_mode(&admin, &types::AttributionMode::OracleHorizon, &3_600);
+    assert_eq!(client.get_attribution_horizon(), 3_600);
 
-    use soroban_sdk::testutils::Ledger;
-    env.ledger().set_timestamp(10000);
-    let current_time = env.ledger().timestamp();
+    let provider = Address::generate(&env);
+    let signal_id = create_attribution_signal(&env, &client, &provider);
 
-    let provider_a = Address::generate(&env);
-    let provider_b = Address::generate(&env);
-    let user = Address::generate(&env);
+    // Execution ROI does not settle the signal in this mode
+    client.record_trade_execution(&Address::generate(&env), &signal_id, &100_000, &120_000, &1_000);
+    let perf = client.get_signal_performance(&signal_id).unwrap();
+    assert_ne!(perf.status, SignalStatus::Successful);
 
-    // Create signals from both providers
-    client.create_signal(
-        &provider_a,
-        &String::from_str(&env, "XLM/USDC"),
-        &SignalAction::Buy,
-        &100_000,
-        &String::from_str(&env, "A1"),
-        &(current_time + 10000),
-        &SignalCategory::SWING,
-        &vec![&env, String::from_str(&env, "A")],
-        &RiskLevel::Medium,
-    );
-    client.create_signal(
-        &provider_b,
-        &String::from_str(&env, "XLM/USDC"),
-        &SignalAction::Buy,
-        &100_000,
-        &String::from_str(&env, "B1"),
-        &(current_time + 10000),
-        &SignalCategory::SCALP,
-        &vec![&env, String::from_str(&env, "B")],
-        &RiskLevel::High,
+    assert_eq!(
+        client.try_record_horizon_price(&admin, &signal_id, &105_000),
+        Err(Ok(AdminError::InvalidTimestamp))
     );
 
-    // User follows only provider_a
-    client.follow_provider(&user, &provider_a);
+    env.ledger().with_mut(|li| li.timestamp += 3_600);
+    client.record_horizon_price(&admin, &signal_id, &105_000);
 
-    // All signals (followed_only = false)
-    let all_active = client.get_active_signals_archived(&user, &false);
-    assert_eq!(all_active.len(), 2);
+    let perf = client.get_signal_performance(&signal_id).unwrap();
+    assert_eq!(perf.status, SignalStatus::Successful);
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.successful_signals, 1);
+    assert_eq!(stats.avg_return, 500);
+    assert_eq!(stats.attribution_mode, types::AttributionMode::OracleHorizon);
 
-    // Filtered feed (followed_only = true) - only provider_a
-    let followed_active = client.get_active_signals_archived(&user, &true);
-    assert_eq!(followed_active.len(), 1);
-    assert_eq!(followed_active.get(0).unwrap().provider, provider_a);
+    // The horizon price is recorded once
+    assert_eq!(
+        client.try_record_horizon_price(&admin, &signal_id, &110_000),
+        Err(Ok(AdminError::InvalidParameter))
+    );
 }
 
 #[test]
-fn test_follow_multiple_providers_follower_counts() {
+fn test_fee_calculation_and_collection() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -991,45 +1292,42 @@ fn test_follow_multiple_providers_follower_counts() {
     let admin = Address::generate(&env);
     client.initialize(&admin);
 
-    let user = Address::generate(&env);
-    let p1 = Address::generate(&env);
-    let p2 = Address::generate(&env);
-    let p3 = Address::generate(&env);
+    // Set platform treasury
+    let platform_treasury = Address::generate(&env);
+    client.set_platform_treasury(&admin, &platform_treasury);
 
-    client.follow_provider(&user, &p1);
-    client.follow_provider(&user, &p2);
-    client.follow_provider(&user, &p3);
+    // Preview fee for 1000 XLM trade
+    let breakdown = client.calculate_fee_preview(&1_000_000_000);
 
-    assert_eq!(client.get_follower_count(&p1), 1);
-    assert_eq!(client.get_follower_count(&p2), 1);
-    assert_eq!(client.get_follower_count(&p3), 1);
+    assert_eq!(breakdown.total_fee, 1_000_000); // 1 XLM (0.1%)
+    assert_eq!(breakdown.platform_fee, 700_000); // 0.7 XLM (70%)
+    assert_eq!(breakdown.provider_fee, 300_000); // 0.3 XLM (30%)
+    assert_eq!(breakdown.trade_amount_after_fee, 999_000_000); // 999 XLM
+}
 
-    let followed = client.get_followed_providers(&user);
-    assert_eq!(followed.len(), 3);
+#[test]
+fn test_minimum_trade_enforcement() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    // Unfollow 2
-    client.unfollow_provider(&user, &p1);
-    client.unfollow_provider(&user, &p2);
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
 
-    assert_eq!(client.get_follower_count(&p1), 0);
-    assert_eq!(client.get_follower_count(&p2), 0);
-    assert_eq!(client.get_follower_count(&p3), 1);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
 
-    let followed_after = client.get_followed_providers(&user);
-    assert_eq!(followed_after.len(), 1);
-    assert_eq!(followed_after.get(0).unwrap(), p3);
-}
+    // Trade below minimum should fail
+    let result = client.try_calculate_fee_preview(&999);
+    assert!(result.is_err());
 
-fn build_vars(env: &Env, entries: &[(&str, &str)]) -> Map<String, String> {
-    let mut vars = Map::new(env);
-    for (k, v) in entries {
-        vars.set(String::from_str(env, k), String::from_str(env, v));
-    }
-    vars
+    // Trade at minimum should work
+    let result = client.try_calculate_fee_preview(&1000);
+    assert!(result.is_ok());
 }
 
 #[test]
-fn test_create_template_with_variables() {
+fn test_trade_fees_accrue_and_pay_out() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -1039,30 +1337,51 @@ fn test_create_template_with_variables() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_trade_fee(&admin, &20);
 
     let provider = Address::generate(&env);
-    let template_id = client.create_template(
-        &provider,
-        &String::from_str(&env, "Daily BTC Analysis"),
-        &Some(String::from_str(&env, "BTC/USDC")),
-        &String::from_str(
-            &env,
-            "BTC technical analysis for {date}. Entry at {price}, target {target}.",
-        ),
+    let trader = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&trader, &1_000_000);
+    let asset = Asset {
+        symbol: soroban_sdk::Symbol::new(&env, "USDC"),
+        contract: sac.address(),
+    };
+    assert_eq!(
+        client.try_set_trade_fee_asset(&admin, &Some(asset.clone())),
+        Err(Ok(AdminError::InvalidParameter))
     );
+    let treasury = Address::generate(&env);
+    client.set_platform_treasury(&admin, &treasury);
+    client.set_trade_fee_asset(&admin, &Some(asset.clone()));
+    let signal_id = create_attribution_signal(&env, &client, &provider);
 
-    let template = client.get_template(&template_id).unwrap();
-    assert_eq!(template.id, template_id);
-    assert_eq!(template.provider, provider);
+    // The fee is charged on the recorded volume and credited to the
+    // signal's provider.
     assert_eq!(
-        template.asset_pair,
-        Some(String::from_str(&env, "BTC/USDC"))
+        client.try_record_trade_execution(&trader, &signal_id, &100_000, &100_000, &500),
+        Err(Ok(errors::PerformanceError::InvalidVolume))
     );
-    assert_eq!(template.use_count, 0);
+    client.record_trade_execution(&trader, &signal_id, &100_000, &100_000, &500_000);
+    assert_eq!(client.get_treasury_balance(&asset), 700);
+    assert_eq!(client.get_provider_fee_balances(&provider).get(asset.clone()), Some(300));
+
+    client.claim_provider_fees(&provider);
+    client.withdraw_platform_fees(&admin);
+    assert_eq!(
+        client.try_claim_provider_fees(&provider),
+        Err(Ok(errors::FeeError::NothingToClaim))
+    );
+
+    let token = soroban_sdk::token::Client::new(&env, &sac.address());
+    assert_eq!(token.balance(&provider), 300);
+    assert_eq!(token.balance(&treasury), 700);
+    assert_eq!(token.balance(&trader), 999_000);
+    assert_eq!(client.get_treasury_balance(&asset), 0);
 }
 
 #[test]
-fn test_submit_signal_from_template_with_variables() {
+fn test_referrer_earns_share_of_referred_fees() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -1072,37 +1391,45 @@ fn test_submit_signal_from_template_with_variables() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_platform_treasury(&admin, &Address::generate(&env));
 
     let provider = Address::generate(&env);
-    let template_id = client.create_template(
-        &provider,
-        &String::from_str(&env, "Quick Long"),
-        &Some(String::from_str(&env, "XLM/USDC")),
-        &String::from_str(&env, "Buy setup at {price}, target {target}"),
-    );
+    let trader = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&trader, &1_000_000);
+    let asset = Asset {
+        symbol: soroban_sdk::Symbol::new(&env, "USDC"),
+        contract: sac.address(),
+    };
+    client.set_trade_fee_asset(&admin, &Some(asset.clone()));
+    let signal_id = create_attribution_signal(&env, &client, &provider);
 
-    let vars = build_vars(
-        &env,
-        &[("action", "buy"), ("price", "101000"), ("target", "120000")],
+    assert_eq!(
+        client.try_set_referrer(&trader, &trader),
+        Err(Ok(ReferralError::SelfReferral))
     );
-
-    let signal_id = client.submit_from_template(&provider, &template_id, &vars);
-    let signal = client.get_signal(&signal_id).unwrap();
-    assert_eq!(signal.provider, provider);
-    assert_eq!(signal.asset_pair, String::from_str(&env, "XLM/USDC"));
-    assert_eq!(signal.action, SignalAction::Buy);
-    assert_eq!(signal.price, 101000);
+    client.set_referrer(&trader, &referrer);
     assert_eq!(
-        signal.rationale,
-        String::from_str(&env, "Buy setup at 101000, target 120000")
+        client.try_set_referrer(&trader, &provider),
+        Err(Ok(ReferralError::ReferrerAlreadySet))
     );
 
-    let template = client.get_template(&template_id).unwrap();
-    assert_eq!(template.use_count, 1);
+    // 0.1% of 500,000 is 500; the referrer gets 10% of it from the platform's 350.
+    client.record_trade_execution(&trader, &signal_id, &100_000, &100_000, &500_000);
+    assert_eq!(client.get_treasury_balance(&asset), 300);
+    assert_eq!(client.get_referral_balance(&referrer).get(asset.clone()), Some(50));
+
+    client.claim_referral_rewards(&referrer);
+    let token = soroban_sdk::token::Client::new(&env, &sac.address());
+    assert_eq!(token.balance(&referrer), 50);
+    let stats = client.get_referral_stats(&referrer);
+    assert_eq!(stats.referred_users, 1);
+    assert_eq!(stats.lifetime_rewards.get(asset), Some(50));
 }
 
 #[test]
-fn test_submit_signal_from_template_missing_variables_should_error() {
+fn test_creation_fee_charged_on_create_and_import() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -1112,22 +1439,79 @@ fn test_submit_signal_from_template_missing_variables_should_error() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+    let treasury = Address::generate(&env);
+    client.set_platform_treasury(&admin, &treasury);
 
     let provider = Address::generate(&env);
-    let template_id = client.create_template(
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&provider, &1_000);
+    let token = soroban_sdk::token::Client::new(&env, &sac.address());
+
+    // A discount large enough to overflow u32 once scaled by tier is rejected.
+    let mut config = CreationFeeConfig {
+        token: sac.address(),
+        amount: 100,
+        burn_bps: 4_000,
+        tier_discount_bps: u32::MAX,
+    };
+    assert_eq!(
+        client.try_set_creation_fee(&admin, &Some(config.clone())),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+    config.tier_discount_bps = 2_000;
+    client.set_creation_fee(&admin, &Some(config));
+    assert_eq!(client.get_creation_fee_for(&provider), 100);
+
+    // 60 to the treasury, 40 burned.
+    client.create_signal(
         &provider,
-        &String::from_str(&env, "Missing Vars"),
-        &Some(String::from_str(&env, "XLM/USDC")),
-        &String::from_str(&env, "Entry {price}, stop {stop_loss}"),
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "Paid"),
+        &(env.ledger().timestamp() + 3600),
+        &SignalCategory::SWING,
+        &vec![&env, String::from_str(&env, "test")],
+        &RiskLevel::Medium,
+    );
+    assert_eq!(token.balance(&treasury), 60);
+    assert_eq!(token.balance(&provider), 900);
+
+    // Imported signals pay the same fee.
+    let chain = String::from_str(&env, "ethereum");
+    let source_address = String::from_str(&env, "0xprovider");
+    let source_id = String::from_str(&env, "sig-1");
+    client.register_cross_chain_address(&provider, &chain, &source_address, &Bytes::new(&env));
+    client.request_signal_import(&provider, &chain, &source_id, &source_address, &Bytes::new(&env));
+    client.import_verified_signal(
+        &chain,
+        &source_id,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Sell,
+        &100_000,
+        &String::from_str(&env, "Imported"),
+        &(env.ledger().timestamp() + 3600),
     );
+    // The provider signed for the fee pulled from them.
+    assert_eq!(env.auths()[0].0, provider);
+    assert_eq!(token.balance(&treasury), 120);
+    assert_eq!(token.balance(&provider), 800);
+}
 
-    let vars = build_vars(&env, &[("action", "buy"), ("price", "100000")]);
-    let result = client.try_submit_from_template(&provider, &template_id, &vars);
-    assert!(result.is_err());
+/// Install a stake token and mint each of `stakers` the amount they will stake.
+fn fund_stakers(env: &Env, client: &SignalRegistryClient, admin: &Address, stakers: &[(&Address, i128)]) -> Address {
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_stake_token(admin, &sac.address());
+    let minter = soroban_sdk::token::StellarAssetClient::new(env, &sac.address());
+    for (staker, amount) in stakers {
+        minter.mint(staker, amount);
+    }
+    sac.address()
 }
 
 #[test]
-fn test_share_template_and_submit_from_another_provider() {
+fn test_staked_payer_gets_fee_discount() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -1138,82 +1522,1662 @@ fn test_share_template_and_submit_from_another_provider() {
     let admin = Address::generate(&env);
     client.initialize(&admin);
 
-    let owner = Address::generate(&env);
-    let other_provider = Address::generate(&env);
+    let trader = Address::generate(&env);
+    let full = client.calculate_fee_preview_for(&trader, &1_000_000_000);
+    assert_eq!(full.total_fee, 1_000_000);
+    assert_eq!(full.discount, 0);
+
+    // 1,000 XLM staked: 25% off.
+    fund_stakers(&env, &client, &admin, &[(&trader, 1_000_000_000)]);
+    client.stake_tokens(&trader, &1_000_000_000);
+    let discounted = client.calculate_fee_preview_for(&trader, &1_000_000_000);
+    assert_eq!(discounted.discount, 250_000);
+    assert_eq!(discounted.total_fee, 750_000);
+    assert_eq!(discounted.platform_fee + discounted.provider_fee, 750_000);
+    assert_eq!(discounted.trade_amount_after_fee, 999_250_000);
+}
 
-    let template_id = client.create_template(
+#[test]
+fn test_platform_treasury_management() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    // No treasury set initially
+    assert_eq!(client.get_platform_treasury(), None);
+
+    // Set treasury
+    let treasury = Address::generate(&env);
+    client.set_platform_treasury(&admin, &treasury);
+    assert_eq!(client.get_platform_treasury(), Some(treasury));
+}
+
+#[test]
+fn test_unauthorized_treasury_update() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    // Non-admin cannot set treasury
+    let result = client.try_set_platform_treasury(&attacker, &treasury);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_get_active_signals_excludes_expired() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+
+    // Set a known timestamp
+    use soroban_sdk::testutils::Ledger;
+    env.ledger().set_timestamp(10000);
+
+    let provider = Address::generate(&env);
+    let current_time = env.ledger().timestamp();
+
+    // Create 3 active signals
+    for _i in 0..3 {
+        client.create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Active"),
+            &(current_time + 10000),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        );
+    }
+
+    // Create 2 expired signals
+    for _i in 0..2 {
+        client.create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/BTC"),
+            &SignalAction::Sell,
+            &200_000,
+            &String::from_str(&env, "Expired"),
+            &(current_time + 10),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        );
+    }
+
+    // Move time to expire the second batch
+    env.ledger().set_timestamp(current_time + 100);
+
+    // Get active signals - should only return 3 (followed_only = false)
+    let any_user = Address::generate(&env);
+    let active = client.get_active_signals_archived(&any_user, &false);
+    assert_eq!(active.len(), 3);
+
+    // All returned signals should be active
+    for i in 0..active.len() {
+        let signal = active.get(i).unwrap();
+        assert_eq!(signal.rationale, String::from_str(&env, "Active"));
+    }
+}
+
+#[test]
+fn test_cleanup_batch_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+
+    env.cost_estimate().budget().reset_unlimited();
+
+    // Set a known timestamp
+    use soroban_sdk::testutils::Ledger;
+    env.ledger().set_timestamp(10000);
+
+    let current_time = env.ledger().timestamp();
+
+    // Create 150 expired signals, each from its own provider to stay under
+    // the per-provider active signal cap and submission rate limit
+    for _ in 0..150 {
+        client.create_signal(
+            &Address::generate(&env),
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Test"),
+            &(current_time + 10),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        );
+    }
+
+    // Move time to expire all
+    env.ledger().set_timestamp(current_time + 100);
+
+    // Cleanup with limit of 50
+    let (processed, expired) = client.cleanup_expired_signals(&50);
+
+    assert_eq!(processed, 50);
+    assert_eq!(expired, 50);
+
+    // Run again to process more
+    let (processed2, expired2) = client.cleanup_expired_signals(&50);
+    assert_eq!(processed2, 50);
+    assert_eq!(expired2, 50);
+}
+
+#[test]
+fn test_pending_expiry_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+
+    // Set a known timestamp
+    use soroban_sdk::testutils::Ledger;
+    env.ledger().set_timestamp(10000);
+
+    let provider = Address::generate(&env);
+    let current_time = env.ledger().timestamp();
+
+    // Create signals that will be past expiry
+    for _i in 0..4 {
+        client.create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Test"),
+            &(current_time + 10),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        );
+    }
+
+    // Initially no pending expiry
+    assert_eq!(client.get_pending_expiry_count(), 0);
+
+    // Move time forward
+    env.ledger().set_timestamp(current_time + 100);
+
+    // Now should have 4 pending expiry
+    assert_eq!(client.get_pending_expiry_count(), 4);
+
+    // After cleanup, none pending
+    client.cleanup_expired_signals(&10);
+    assert_eq!(client.get_pending_expiry_count(), 0);
+}
+
+// ========================================
+// Social / Follow Tests
+// ========================================
+
+#[test]
+fn test_follow_provider() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let user = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    client.follow_provider(&user, &provider);
+    assert_eq!(client.get_follower_count(&provider), 1);
+
+    let followed = client.get_followed_providers(&user);
+    assert_eq!(followed.len(), 1);
+    assert_eq!(followed.get(0).unwrap(), provider);
+}
+
+#[test]
+fn test_followers_and_following_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let users = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for user in users.iter() {
+        client.follow_provider(user, &provider);
+    }
+
+    let first = client.get_followers(&provider, &0, &2);
+    assert_eq!(first.addresses.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+    let last = client.get_followers(&provider, &2, &2);
+    assert_eq!(last.addresses.get(0).unwrap(), users[2]);
+    assert_eq!(last.next_cursor, None);
+
+    client.unfollow_provider(&users[0], &provider);
+    let all = client.get_followers(&provider, &0, &10);
+    assert_eq!(all.addresses.len(), 2);
+    assert_eq!(all.addresses.get(0).unwrap(), users[1]);
+
+    let following = client.get_following(&users[1], &0, &10);
+    assert_eq!(following.addresses.len(), 1);
+    assert_eq!(following.addresses.get(0).unwrap(), provider);
+}
+
+#[test]
+fn test_follow_idempotent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let user = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    client.follow_provider(&user, &provider);
+    client.follow_provider(&user, &provider); // idempotent
+    assert_eq!(client.get_follower_count(&provider), 1);
+}
+
+#[test]
+fn test_cannot_follow_self() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let user = Address::generate(&env);
+    let result = client.try_follow_provider(&user, &user);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_unfollow_provider() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let user = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    client.follow_provider(&user, &provider);
+    assert_eq!(client.get_follower_count(&provider), 1);
+
+    client.unfollow_provider(&user, &provider);
+    assert_eq!(client.get_follower_count(&provider), 0);
+    assert_eq!(client.get_followed_providers(&user).len(), 0);
+}
+
+#[test]
+fn test_unfollow_not_following_no_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let user = Address::generate(&env);
+    let provider = Address::generate(&env);
+
+    client.unfollow_provider(&user, &provider); // no error
+}
+
+#[test]
+fn test_feed_filtered_by_followed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+
+    use soroban_sdk::testutils::Ledger;
+    env.ledger().set_timestamp(10000);
+    let current_time = env.ledger().timestamp();
+
+    let provider_a = Address::generate(&env);
+    let provider_b = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    // Create signals from both providers
+    client.create_signal(
+        &provider_a,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "A1"),
+        &(current_time + 10000),
+        &SignalCategory::SWING,
+        &vec![&env, String::from_str(&env, "A")],
+        &RiskLevel::Medium,
+    );
+    client.create_signal(
+        &provider_b,
+        &String::from_str(&env, "XLM/USDC"),
+        &SignalAction::Buy,
+        &100_000,
+        &String::from_str(&env, "B1"),
+        &(current_time + 10000),
+        &SignalCategory::SCALP,
+        &vec![&env, String::from_str(&env, "B")],
+        &RiskLevel::High,
+    );
+
+    // User follows only provider_a
+    client.follow_provider(&user, &provider_a);
+
+    // All signals (followed_only = false)
+    let all_active = client.get_active_signals_archived(&user, &false);
+    assert_eq!(all_active.len(), 2);
+
+    // Filtered feed (followed_only = true) - only provider_a
+    let followed_active = client.get_active_signals_archived(&user, &true);
+    assert_eq!(followed_active.len(), 1);
+    assert_eq!(followed_active.get(0).unwrap().provider, provider_a);
+}
+
+#[test]
+fn test_follow_multiple_providers_follower_counts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let user = Address::generate(&env);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+    let p3 = Address::generate(&env);
+
+    client.follow_provider(&user, &p1);
+    client.follow_provider(&user, &p2);
+    client.follow_provider(&user, &p3);
+
+    assert_eq!(client.get_follower_count(&p1), 1);
+    assert_eq!(client.get_follower_count(&p2), 1);
+    assert_eq!(client.get_follower_count(&p3), 1);
+
+    let followed = client.get_followed_providers(&user);
+    assert_eq!(followed.len(), 3);
+
+    // Unfollow 2
+    client.unfollow_provider(&user, &p1);
+    client.unfollow_provider(&user, &p2);
+
+    assert_eq!(client.get_follower_count(&p1), 0);
+    assert_eq!(client.get_follower_count(&p2), 0);
+    assert_eq!(client.get_follower_count(&p3), 1);
+
+    let followed_after = client.get_followed_providers(&user);
+    assert_eq!(followed_after.len(), 1);
+    assert_eq!(followed_after.get(0).unwrap(), p3);
+}
+
+fn build_vars(env: &Env, entries: &[(&str, &str)]) -> Map<String, String> {
+    let mut vars = Map::new(env);
+    for (k, v) in entries {
+        vars.set(String::from_str(env, k), String::from_str(env, v));
+    }
+    vars
+}
+
+#[test]
+fn test_create_template_with_variables() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let template_id = client.create_template(
+        &provider,
+        &String::from_str(&env, "Daily BTC Analysis"),
+        &Some(String::from_str(&env, "BTC/USDC")),
+        &String::from_str(
+            &env,
+            "BTC technical analysis for {date}. Entry at {price}, target {target}.",
+        ),
+    );
+
+    let template = client.get_template(&template_id).unwrap();
+    assert_eq!(template.id, template_id);
+    assert_eq!(template.provider, provider);
+    assert_eq!(
+        template.asset_pair,
+        Some(String::from_str(&env, "BTC/USDC"))
+    );
+    assert_eq!(template.use_count, 0);
+}
+
+#[test]
+fn test_submit_signal_from_template_with_variables() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+
+    let provider = Address::generate(&env);
+    let template_id = client.create_template(
+        &provider,
+        &String::from_str(&env, "Quick Long"),
+        &Some(String::from_str(&env, "XLM/USDC")),
+        &String::from_str(&env, "Buy setup at {price}, target {target}"),
+    );
+
+    let vars = build_vars(
+        &env,
+        &[("action", "buy"), ("price", "101000"), ("target", "120000")],
+    );
+
+    let signal_id = client.submit_from_template(&provider, &template_id, &vars);
+    let signal = client.get_signal(&signal_id).unwrap();
+    assert_eq!(signal.provider, provider);
+    assert_eq!(signal.asset_pair.to_string(&env), String::from_str(&env, "XLM/USDC"));
+    assert_eq!(signal.action, SignalAction::Buy);
+    assert_eq!(signal.price, 101000);
+    assert_eq!(
+        signal.rationale,
+        String::from_str(&env, "Buy setup at 101000, target 120000")
+    );
+
+    let template = client.get_template(&template_id).unwrap();
+    assert_eq!(template.use_count, 1);
+}
+
+#[test]
+fn test_submit_signal_from_template_missing_variables_should_error() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
+
+    let provider = Address::generate(&env);
+    let template_id = client.create_template(
+        &provider,
+        &String::from_str(&env, "Missing Vars"),
+        &Some(String::from_str(&env, "XLM/USDC")),
+        &String::from_str(&env, "Entry {price}, stop {stop_loss}"),
+    );
+
+    let vars = build_vars(&env, &[("action", "buy"), ("price", "100000")]);
+    let result = client.try_submit_from_template(&provider, &template_id, &vars);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_share_template_and_submit_from_another_provider() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let owner = Address::generate(&env);
+    let other_provider = Address::generate(&env);
+
+    let template_id = client.create_template(
         &owner,
         &String::from_str(&env, "Shared Template"),
         &None,
         &String::from_str(&env, "Momentum on {asset_pair} at {price}"),
     );
 
-    // Private template cannot be used by another provider
-    let private_vars = build_vars(
-        &env,
-        &[
-            ("asset_pair", "BTC/USDC"),
-            ("action", "sell"),
-            ("price", "90000"),
-        ],
+    // Private template cannot be used by another provider
+    let private_vars = build_vars(
+        &env,
+        &[
+            ("asset_pair", "BTC/USDC"),
+            ("action", "sell"),
+            ("price", "90000"),
+        ],
+    );
+    let private_result =
+        client.try_submit_from_template(&other_provider, &template_id, &private_vars);
+    assert!(private_result.is_err());
+
+    // Share publicly; the other provider still needs the minimum stake
+    client.set_template_public(&owner, &template_id, &true);
+    assert_eq!(
+        client.try_submit_from_template(&other_provider, &template_id, &private_vars),
+        Err(Ok(TemplateError::NoStake))
+    );
+
+    fund_stakers(&env, &client, &admin, &[(&other_provider, admin::DEFAULT_MIN_STAKE)]);
+    client.stake_tokens(&other_provider, &admin::DEFAULT_MIN_STAKE);
+    let signal_id = client.submit_from_template(&other_provider, &template_id, &private_vars);
+    let signal = client.get_signal(&signal_id).unwrap();
+    assert_eq!(signal.provider, other_provider);
+    assert_eq!(signal.asset_pair.to_string(&env), String::from_str(&env, "BTC/USDC"));
+    assert_eq!(signal.action, SignalAction::Sell);
+}
+
+/// Hundred v1 records, ten batches of ten; all readable as v2; extra admin runs are no-ops (idempotent).
+#[test]
+fn migration_v1_to_v2_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    env.as_contract(&contract_id, || {
+        test_seed_v1_signals(&env, 100);
+    });
+
+    for _ in 0..10 {
+        client.migrate_signals_v1_to_v2(&admin, &10u32);
+    }
+
+    for sid in 1u64..=100u64 {
+        let s = client.get_signal(&sid).expect("migrated v2");
+        assert_eq!(s.id, sid);
+        assert_eq!(s.confidence, 50u32);
+        assert_eq!(s.adoption_count, 0u32);
+        assert_eq!(s.submitted_at, 1_000u64);
+    }
+
+    env.as_contract(&contract_id, || {
+        let m: Map<u64, crate::types::SignalV1> = env
+            .storage()
+            .instance()
+            .get(&StorageKey::SignalsV1)
+            .unwrap_or(Map::new(&env));
+        assert_eq!(m.len(), 0u32);
+    });
+
+    for _ in 0..2 {
+        client.migrate_signals_v1_to_v2(&admin, &10u32);
+    }
+    for sid in 1u64..=100u64 {
+        let s = client.get_signal(&sid).expect("still v2");
+        assert_eq!(s.id, sid);
+    }
+}
+
+/// A v1 record with an unreadable pair is skipped and reported; the rest of the batch migrates.
+#[test]
+fn migration_v1_to_v2_skips_unparsable_pair() {
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::{Symbol, TryFromVal, Val};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    env.as_contract(&contract_id, || {
+        test_seed_v1_signals(&env, 5);
+        let mut m: Map<u64, crate::types::SignalV1> = env
+            .storage()
+            .instance()
+            .get(&StorageKey::SignalsV1)
+            .unwrap();
+        let mut bad = m.get(3).unwrap();
+        bad.asset_pair = String::from_str(&env, "XLMUSDC");
+        m.set(3, bad);
+        env.storage().instance().set(&StorageKey::SignalsV1, &m);
+    });
+
+    client.migrate_signals_v1_to_v2(&admin, &10u32);
+
+    let skipped = env.events().all().iter().find(|e| {
+        let topics: soroban_sdk::Vec<Val> = e.1.clone();
+        Symbol::try_from_val(&env, &topics.get(0).unwrap())
+            .map(|s| s == Symbol::new(&env, "migration_skipped"))
+            .unwrap_or(false)
+    });
+    assert_eq!(u64::try_from_val(&env, &skipped.unwrap().2).unwrap(), 3);
+
+    for sid in [1u64, 2, 4, 5] {
+        assert!(client.get_signal(&sid).is_some());
+    }
+    assert!(client.get_signal(&3).is_none());
+
+    env.as_contract(&contract_id, || {
+        let m: Map<u64, crate::types::SignalV1> = env
+            .storage()
+            .instance()
+            .get(&StorageKey::SignalsV1)
+            .unwrap();
+        assert_eq!(m.len(), 1);
+        assert!(m.get(3).is_some());
+        let cursor: u64 = env
+            .storage()
+            .instance()
+            .get(&StorageKey::MigrationCursor)
+            .unwrap();
+        assert_eq!(cursor, 6);
+    });
+}
+
+/// v2 records stored before the pair became an `AssetPair` are staged and converted in
+/// batches; one with an unreadable pair stays staged. Extra runs are no-ops.
+#[test]
+fn migration_v2_to_v3_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    env.as_contract(&contract_id, || {
+        test_seed_v2_signals(&env, 5);
+        let mut m: Map<u64, crate::types::SignalV2> =
+            env.storage().instance().get(&StorageKey::Signals).unwrap();
+        let mut bad = m.get(4).unwrap();
+        bad.asset_pair = String::from_str(&env, "XLMUSDC");
+        m.set(4, bad);
+        env.storage().instance().set(&StorageKey::Signals, &m);
+    });
+
+    for _ in 0..3 {
+        client.migrate_signals_v2_to_v3(&admin, &2u32);
+    }
+
+    let pair = AssetPair::parse(&env, &String::from_str(&env, "XLM/USDC")).unwrap();
+    for sid in [1u64, 2, 3, 5] {
+        let s = client.get_signal(&sid).expect("migrated v3");
+        assert_eq!(s.asset_pair, pair);
+        assert_eq!(s.confidence, 70);
+        assert_eq!(s.adoption_count, 3);
+        assert_eq!(s.stop_loss, None);
+        assert!(!s.premium);
+    }
+    assert!(client.get_signal(&4).is_none());
+
+    env.as_contract(&contract_id, || {
+        let staged: Map<u64, crate::types::SignalV2> = env
+            .storage()
+            .instance()
+            .get(&StorageKey::SignalsV2)
+            .unwrap();
+        assert_eq!(staged.len(), 1);
+        assert!(staged.get(4).is_some());
+    });
+
+    for _ in 0..2 {
+        client.migrate_signals_v2_to_v3(&admin, &2u32);
+    }
+    assert_eq!(client.get_signal(&5).unwrap().id, 5);
+
+    assert_eq!(
+        client.try_migrate_signals_v2_to_v3(&admin, &0u32),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+}
+
+/// Provider stats stored before the extra fields existed are rewritten with defaults.
+#[test]
+fn migration_provider_stats_v1_to_v2() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let mut m: Map<Address, crate::types::ProviderPerformanceV1> = Map::new(&env);
+        m.set(
+            provider.clone(),
+            crate::types::ProviderPerformanceV1 {
+                total_signals: 10,
+                successful_signals: 7,
+                failed_signals: 3,
+                total_copies: 40,
+                success_rate: 7_000,
+                avg_return: 250,
+                total_volume: 1_000_000,
+                follower_count: 5,
+            },
+        );
+        env.storage().instance().set(&StorageKey::ProviderStats, &m);
+    });
+
+    client.migrate_provider_stats_v1_to_v2(&admin);
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.total_signals, 10);
+    assert_eq!(stats.success_rate, 7_000);
+    assert_eq!(stats.follower_count, 5);
+    assert_eq!(stats.attribution_mode, crate::types::AttributionMode::AverageExecution);
+    assert_eq!(stats.tip_count, 0);
+    assert_eq!(stats.best_streak, 0);
+
+    // Already current: a second run leaves the stats as they are.
+    client.migrate_provider_stats_v1_to_v2(&admin);
+    assert_eq!(client.get_provider_stats(&provider).unwrap().total_copies, 40);
+}
+
+#[test]
+fn test_backfill_indexes_covers_legacy_signals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    // Migrated v1 signals were never indexed.
+    env.as_contract(&contract_id, || {
+        test_seed_v1_signals(&env, 5);
+    });
+    client.migrate_signals_v1_to_v2(&admin, &10u32);
+    let provider = client.get_signal(&1).unwrap().provider;
+    env.as_contract(&contract_id, || {
+        assert_eq!(signal_index::ids_by_provider(&env, &provider).len(), 0);
+    });
+
+    assert_eq!(
+        client.try_backfill_indexes(&admin, &0, &0),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+
+    let first = client.backfill_indexes(&admin, &0, &3);
+    assert_eq!(first.indexed, 3);
+    assert_eq!(first.next_cursor, 4);
+    assert!(!first.done);
+    assert_eq!(client.get_backfill_cursor(), 4);
+
+    let second = client.backfill_indexes(&admin, &0, &3);
+    assert_eq!(second.indexed, 2);
+    assert!(second.done);
+
+    // Re-running from the start adds nothing.
+    let rerun = client.backfill_indexes(&admin, &1, &10);
+    assert_eq!(rerun.indexed, 0);
+    assert!(rerun.done);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(signal_index::ids_by_provider(&env, &provider).len(), 5);
+        // The legacy "XLM-USDC" pair is migrated as XLM/USDC.
+        let pair = AssetPair::parse(&env, &String::from_str(&env, "XLM/USDC")).unwrap();
+        assert_eq!(signal_index::ids_by_pair(&env, &pair).len(), 5);
+        assert_eq!(signal_index::ids_by_status(&env, &SignalStatus::Active).len(), 5);
+    });
+}
+
+#[test]
+fn test_cursor_pagination() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    env.as_contract(&contract_id, || {
+        test_seed_v1_signals(&env, 5);
+    });
+    client.migrate_signals_v1_to_v2(&admin, &10u32);
+    client.backfill_indexes(&admin, &0, &10);
+
+    let first = client.get_signals(&0, &2);
+    assert_eq!(first.signals.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+    let last = client.get_signals(&4, &2);
+    assert_eq!(last.signals.len(), 1);
+    assert_eq!(last.next_cursor, None);
+
+    let provider = client.get_signal(&1).unwrap().provider;
+    let page = client.get_signals_by_provider(&provider, &0, &3);
+    assert_eq!(page.signals.len(), 3);
+    assert_eq!(page.signals.get(2).unwrap().id, 3);
+    let rest = client.get_signals_by_provider(&provider, &page.next_cursor.unwrap(), &3);
+    assert_eq!(rest.signals.len(), 2);
+    assert_eq!(rest.next_cursor, None);
+
+    let active = client.get_signals_by_status(&SignalStatus::Active, &2, &10);
+    assert_eq!(active.signals.len(), 3);
+    assert_eq!(active.signals.get(0).unwrap().id, 3);
+    assert_eq!(
+        client.get_signals_by_status(&SignalStatus::Expired, &0, &10).signals.len(),
+        0
+    );
+}
+
+#[test]
+fn test_get_signals_by_pair_newest_first() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    env.as_contract(&contract_id, || {
+        test_seed_v1_signals(&env, 4);
+    });
+    client.migrate_signals_v1_to_v2(&admin, &10u32);
+    client.backfill_indexes(&admin, &0, &10);
+
+    let pair = String::from_str(&env, "XLM-USDC");
+    let recent = client.get_signals_by_pair(&pair, &3);
+    assert_eq!(recent.len(), 3);
+    assert_eq!(recent.get(0).unwrap().id, 4);
+    assert_eq!(recent.get(2).unwrap().id, 2);
+
+    // Both spellings of the pair hit the same index.
+    let slash = String::from_str(&env, "XLM/USDC");
+    assert_eq!(client.get_signals_by_pair(&slash, &10).len(), 4);
+
+    let other = String::from_str(&env, "BTC-USDC");
+    assert_eq!(client.get_signals_by_pair(&other, &3).len(), 0);
+}
+
+#[test]
+fn test_expire_signals_closes_overdue_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    env.as_contract(&contract_id, || {
+        test_seed_v1_signals(&env, 3);
+    });
+    client.migrate_signals_v1_to_v2(&admin, &10u32);
+    client.backfill_indexes(&admin, &0, &10);
+
+    // Nothing is overdue yet.
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.expire_signals(&10).failed, 0);
+
+    // Past expiry without executions: the signals close as Failed.
+    env.ledger().set_timestamp(1_000 + 86_401);
+    let first = client.expire_signals(&2);
+    assert_eq!(first.failed, 2);
+    assert!(first.more);
+    let second = client.expire_signals(&2);
+    assert_eq!(second.failed, 1);
+    assert!(!second.more);
+
+    assert_eq!(client.get_signal(&3).unwrap().status, SignalStatus::Failed);
+    let provider = client.get_signal(&1).unwrap().provider;
+    let stats = client.get_provider_stats(&provider).unwrap();
+    assert_eq!(stats.failed_signals, 3);
+}
+
+#[test]
+fn test_sweeps_leave_expired_signals_to_oracle_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let feed = PriceFeed::deploy(&env, &admin);
+    client.set_price_oracle(&admin, &feed.client.address);
+
+    let loser = create_attribution_signal(&env, &client, &Address::generate(&env));
+    let unsettled = create_attribution_signal(&env, &client, &Address::generate(&env));
+    env.ledger().with_mut(|l| l.timestamp += 86_401);
+
+    // Inside the settlement window neither sweep closes them.
+    let sweep = client.expire_signals(&10);
+    assert_eq!((sweep.successful, sweep.failed, sweep.expired), (0, 0, 0));
+    assert_eq!(client.cleanup_expired_signals(&10), (0, 0));
+
+    // So the loser is settled Failed on the oracle price and counts as such.
+    feed.publish(&env, 99_000);
+    assert_eq!(client.settle_expired_signal(&loser), SignalStatus::Failed);
+    let provider = client.get_signal(&loser).unwrap().provider;
+    assert_eq!(client.get_provider_stats(&provider).unwrap().failed_signals, 1);
+
+    // Once the window has passed the sweep closes what is left.
+    env.ledger()
+        .with_mut(|l| l.timestamp += settlement::SETTLEMENT_WINDOW);
+    assert_eq!(client.expire_signals(&10).failed, 1);
+    assert_eq!(client.get_signal(&unsettled).unwrap().status, SignalStatus::Failed);
+}
+
+/// A deployed `OracleContract` and the three reporters that close its
+/// rounds.
+struct PriceFeed<'a> {
+    client: oracle::OracleContractClient<'a>,
+    reporters: [Address; 3],
+}
+
+impl PriceFeed<'_> {
+    fn deploy(env: &Env, admin: &Address) -> Self {
+        let client = oracle::OracleContractClient::new(env, &env.register(oracle::OracleContract, ()));
+        let pair = AssetPair::parse(env, &String::from_str(env, "XLM/USDC")).unwrap();
+        client.initialize(admin, &pair.quote);
+        let reporters = [
+            Address::generate(env),
+            Address::generate(env),
+            Address::generate(env),
+        ];
+        for reporter in reporters.iter() {
+            client.register_oracle(admin, reporter);
+        }
+        PriceFeed { client, reporters }
+    }
+
+    /// Close an XLM/USDC round at `price`, observed now.
+    fn publish(&self, env: &Env, price: i128) {
+        let pair = AssetPair::parse(env, &String::from_str(env, "XLM/USDC")).unwrap();
+        for reporter in self.reporters.iter() {
+            self.client
+                .submit_price(reporter, &pair, &price, &env.ledger().timestamp());
+        }
+    }
+}
+
+#[test]
+fn test_execution_prices_verified_against_oracle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    // Oracle prices stamped at time zero count as stale.
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    let provider = Address::generate(&env);
+    let signal_id = create_attribution_signal(&env, &client, &provider);
+
+    let feed = PriceFeed::deploy(&env, &admin);
+    feed.publish(&env, 101_000);
+
+    // Off by default: any price is accepted and nothing is flagged.
+    let executor = Address::generate(&env);
+    client.record_trade_execution(&executor, &signal_id, &100_000, &150_000, &1_000);
+    assert_eq!(client.get_trade_flag(&1), None);
+
+    assert_eq!(
+        client.try_set_execution_verification(
+            &admin,
+            &exec_verification::VerificationMode::Flag,
+            &0
+        ),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+    client.set_execution_verification(&admin, &exec_verification::VerificationMode::Flag, &200);
+
+    // No oracle configured yet.
+    let executor = Address::generate(&env);
+    assert_eq!(
+        client.try_record_trade_execution(&executor, &signal_id, &100_000, &101_000, &1_000),
+        Err(Ok(errors::PerformanceError::OracleUnavailable))
+    );
+    client.set_price_oracle(&admin, &feed.client.address);
+
+    // Both legs within tolerance of the oracle's 0.0101.
+    client.record_trade_execution(&executor, &signal_id, &100_000, &102_000, &1_000);
+    assert_eq!(client.get_trade_flag(&2), None);
+
+    // Exit 10% away from the oracle is recorded but flagged.
+    let executor = Address::generate(&env);
+    client.record_trade_execution(&executor, &signal_id, &100_000, &111_100, &1_000);
+    assert_eq!(client.get_trade_flag(&3), Some(1_000));
+
+    // The price moves to 0.011: an entry at the earlier 0.0101 still
+    // matches the oracle's history.
+    env.ledger().with_mut(|l| l.timestamp += 600);
+    feed.publish(&env, 110_000);
+    let executor = Address::generate(&env);
+    client.record_trade_execution(&executor, &signal_id, &101_000, &110_000, &1_000);
+    assert_eq!(client.get_trade_flag(&4), None);
+
+    // An entry the oracle never published anything near is flagged.
+    let executor = Address::generate(&env);
+    client.record_trade_execution(&executor, &signal_id, &90_000, &110_000, &1_000);
+    assert_eq!(client.get_trade_flag(&5), Some(1_089));
+
+    client.set_execution_verification(&admin, &exec_verification::VerificationMode::Reject, &200);
+    let executor = Address::generate(&env);
+    assert_eq!(
+        client.try_record_trade_execution(&executor, &signal_id, &100_000, &90_000, &1_000),
+        Err(Ok(errors::PerformanceError::PriceOutsideTolerance))
+    );
+    assert_eq!(
+        client.get_execution_verification().mode,
+        exec_verification::VerificationMode::Reject
+    );
+}
+
+#[test]
+fn test_executions_restricted_to_trusted_executors() {
+    use soroban_sdk::testutils::Events as _;
+    use soroban_sdk::{Symbol, TryFromVal};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let signal_id = create_attribution_signal(&env, &client, &provider);
+    let user = Address::generate(&env);
+    let auto_trade = Address::generate(&env);
+
+    // Permissionless by default.
+    assert!(!client.are_executions_restricted());
+    client.record_trade_execution(&user, &signal_id, &100_000, &101_000, &1_000);
+
+    client.set_executions_restricted(&admin, &true);
+    let user = Address::generate(&env);
+    assert_eq!(
+        client.try_record_trade_execution(&user, &signal_id, &100_000, &101_000, &1_000),
+        Err(Ok(errors::PerformanceError::UntrustedExecutor))
+    );
+    assert_eq!(
+        client.try_record_trade_execution(&auto_trade, &signal_id, &100_000, &101_000, &1_000),
+        Err(Ok(errors::PerformanceError::UntrustedExecutor))
     );
-    let private_result =
-        client.try_submit_from_template(&other_provider, &template_id, &private_vars);
-    assert!(private_result.is_err());
 
-    // Share publicly and submit successfully from another provider
-    client.set_template_public(&owner, &template_id, &true);
-    let signal_id = client.submit_from_template(&other_provider, &template_id, &private_vars);
-    let signal = client.get_signal(&signal_id).unwrap();
-    assert_eq!(signal.provider, other_provider);
-    assert_eq!(signal.asset_pair, String::from_str(&env, "BTC/USDC"));
-    assert_eq!(signal.action, SignalAction::Sell);
+    client.set_trusted_executor(&admin, &auto_trade);
+    let added = env.events().all().iter().find(|e| {
+        Symbol::try_from_val(&env, &e.1.get(0).unwrap())
+            .map(|s| s == Symbol::new(&env, "trusted_executor_added"))
+            .unwrap_or(false)
+    });
+    assert_eq!(
+        <(Address, Address)>::try_from_val(&env, &added.unwrap().2).unwrap(),
+        (auto_trade.clone(), admin.clone())
+    );
+    assert_eq!(
+        client.try_set_trusted_executor(&admin, &auto_trade),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+    assert_eq!(client.get_trusted_executors(), vec![&env, auto_trade.clone()]);
+    client.record_trade_execution(&auto_trade, &signal_id, &100_000, &101_000, &1_000);
+    assert_eq!(client.get_signal(&signal_id).unwrap().executions, 2);
+
+    client.remove_trusted_executor(&admin, &auto_trade);
+    assert!(env.events().all().iter().any(|e| {
+        Symbol::try_from_val(&env, &e.1.get(0).unwrap())
+            .map(|s| s == Symbol::new(&env, "trusted_executor_removed"))
+            .unwrap_or(false)
+    }));
+    assert_eq!(
+        client.try_remove_trusted_executor(&admin, &auto_trade),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+    assert_eq!(
+        client.try_record_trade_execution(&auto_trade, &signal_id, &100_000, &101_000, &1_000),
+        Err(Ok(errors::PerformanceError::UntrustedExecutor))
+    );
 }
 
-/// Hundred v1 records, ten batches of ten; all readable as v2; extra admin runs are no-ops (idempotent).
 #[test]
-fn migration_v1_to_v2_batches() {
+fn test_settle_signal_on_targets() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let feed = PriceFeed::deploy(&env, &admin);
+
+    let provider = Address::generate(&env);
+    let stopped = create_attribution_signal(&env, &client, &provider);
+    let open = create_attribution_signal(&env, &client, &provider);
+    client.set_signal_targets(&provider, &stopped, &Some(98_000), &Some(110_000));
+    client.set_signal_targets(&provider, &open, &Some(90_000), &Some(110_000));
+
+    assert_eq!(
+        client.try_settle_signal(&stopped),
+        Err(Ok(crate::errors::SettlementError::OracleNotConfigured))
+    );
+    client.set_price_oracle(&admin, &feed.client.address);
+
+    // 0.0097 at the canonical 7 decimals, as signal prices are quoted; a
+    // price truncated to whole units would read as zero.
+    feed.publish(&env, 97_000);
+    assert_eq!(
+        client.try_settle_signal(&open),
+        Err(Ok(crate::errors::SettlementError::NotTriggered))
+    );
+    assert_eq!(client.settle_signal(&stopped), SignalStatus::Failed);
+}
+
+#[test]
+fn test_settle_expired_signal_on_oracle_price() {
     let env = Env::default();
     env.mock_all_auths();
-    env.ledger().set_timestamp(1_000);
 
     #[allow(deprecated)]
     let contract_id = env.register_contract(None, SignalRegistry);
     let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let feed = PriceFeed::deploy(&env, &admin);
+    let set_oracle_price = |price: i128| feed.publish(&env, price);
+    client.set_price_oracle(&admin, &feed.client.address);
+
+    let winner = create_attribution_signal(&env, &client, &Address::generate(&env));
+    let loser = create_attribution_signal(&env, &client, &Address::generate(&env));
+    let late = create_attribution_signal(&env, &client, &Address::generate(&env));
+    set_oracle_price(105_000);
+    assert_eq!(
+        client.try_settle_expired_signal(&winner),
+        Err(Ok(crate::errors::SettlementError::NotExpired))
+    );
+
+    // A fresh observation taken before expiry can't stand in for the
+    // expiry price.
+    env.ledger().with_mut(|l| l.timestamp += 86_300);
+    set_oracle_price(105_000);
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        client.try_settle_expired_signal(&winner),
+        Err(Ok(crate::errors::SettlementError::PriceUnavailable))
+    );
+
+    set_oracle_price(105_000);
+    assert_eq!(client.settle_expired_signal(&winner), SignalStatus::Successful);
+    assert_eq!(
+        client.try_settle_expired_signal(&winner),
+        Err(Ok(crate::errors::SettlementError::SignalNotActive))
+    );
+
+    set_oracle_price(99_000);
+    assert_eq!(client.settle_expired_signal(&loser), SignalStatus::Failed);
+    assert_eq!(client.get_signal(&loser).unwrap().status, SignalStatus::Failed);
+
+    // Too long after expiry to stand in for the expiry price.
+    env.ledger().with_mut(|l| l.timestamp += settlement::SETTLEMENT_WINDOW + 1);
+    set_oracle_price(105_000);
+    assert_eq!(
+        client.try_settle_expired_signal(&late),
+        Err(Ok(crate::errors::SettlementError::SettlementWindowClosed))
+    );
+    // settle_signal can't get round the window either.
+    assert_eq!(
+        client.try_settle_signal(&late),
+        Err(Ok(crate::errors::SettlementError::SettlementWindowClosed))
+    );
+}
+
+#[test]
+fn test_create_signal_enforces_min_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
 
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &200_000_000);
+    client.set_signal_stake_config(&admin, &150_000_000, &0);
 
-    env.as_contract(&contract_id, || {
-        test_seed_v1_signals(&env, 100);
-    });
+    let provider = Address::generate(&env);
+    let submit = |client: &SignalRegistryClient| {
+        client.try_create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Staked"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        )
+    };
+    assert_eq!(submit(&client), Err(Ok(AdminError::NoStake)));
 
-    for _ in 0..10 {
-        client.migrate_signals_v1_to_v2(&admin, &10u32);
-    }
+    fund_stakers(&env, &client, &admin, &[(&provider, 200_000_000)]);
+    client.stake_tokens(&provider, &100_000_000);
+    assert_eq!(submit(&client), Err(Ok(AdminError::StakeBelowMinimum)));
 
-    for sid in 1u64..=100u64 {
-        let s = client.get_signal(&sid).expect("migrated v2");
-        assert_eq!(s.id, sid);
-        assert_eq!(s.confidence, 50u32);
-        assert_eq!(s.adoption_count, 0u32);
-        assert_eq!(s.submitted_at, 1_000u64);
-    }
+    client.stake_tokens(&provider, &100_000_000);
+    assert!(submit(&client).is_ok());
+
+    // Part of the stake is locked behind the new signal.
+    assert_eq!(client.get_locked_stake(&provider), 150_000_000);
+    assert_eq!(
+        client.try_request_unstake(&provider, &100_000_000),
+        Err(Ok(AdminError::InsufficientStake))
+    );
+
+    // Without a minimum, unstaked providers can post again.
+    client.set_min_stake(&admin, &0);
+    client.set_signal_stake_config(&admin, &0, &0);
+    assert!(client
+        .try_create_signal(
+            &Address::generate(&env),
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Unstaked"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        )
+        .is_ok());
+}
+
+#[test]
+fn test_default_min_stake_locks_stake_behind_signals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let submit = |client: &SignalRegistryClient| {
+        client.create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Locked"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        )
+    };
+    fund_stakers(&env, &client, &admin, &[(&provider, 300_000_000)]);
+    client.stake_tokens(&provider, &300_000_000);
+    submit(&client);
+
+    let pending = client.request_unstake(&provider, &100_000_000);
+    // A signal posted after the request extends the lock past it.
+    env.ledger().with_mut(|l| l.timestamp += 86_400);
+    submit(&client);
+    let relocked_until = env.ledger().timestamp() + stake::UNSTAKE_LOCK_PERIOD;
+
+    env.ledger().with_mut(|l| l.timestamp = pending.available_at);
+    assert_eq!(
+        client.try_claim_unstake(&provider),
+        Err(Ok(AdminError::StakeLocked))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = relocked_until);
+    assert_eq!(client.claim_unstake(&provider), 100_000_000);
+}
+
+#[test]
+fn test_staking_rewards_from_trade_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_platform_treasury(&admin, &Address::generate(&env));
+    client.set_trade_fee(&admin, &20);
+    assert_eq!(
+        client.try_set_staking_reward_share(&admin, &10_001),
+        Err(Ok(errors::FeeError::InvalidShare))
+    );
+    client.set_staking_reward_share(&admin, &4_000);
+
+    let provider = Address::generate(&env);
+    let trader = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&trader, &1_000_000);
+    let asset = Asset {
+        symbol: soroban_sdk::Symbol::new(&env, "USDC"),
+        contract: sac.address(),
+    };
+    client.set_trade_fee_asset(&admin, &Some(asset.clone()));
+    let signal_id = create_attribution_signal(&env, &client, &provider);
+
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    fund_stakers(&env, &client, &admin, &[(&early, 100_000_000), (&late, 300_000_000)]);
+    client.stake_tokens(&early, &100_000_000);
+
+    // Each fee is 1,000; stakers get 40% of the platform's 700.
+    client.record_trade_execution(&trader, &signal_id, &100_000, &100_000, &500_000);
+    client.stake_tokens(&late, &300_000_000);
+    client.record_trade_execution(&trader, &signal_id, &100_000, &100_000, &500_000);
+
+    assert_eq!(client.get_treasury_balance(&asset), 840);
+    assert_eq!(client.get_staking_rewards(&early).get(asset.clone()), Some(350));
+    assert_eq!(client.get_staking_rewards(&late).get(asset.clone()), Some(210));
+
+    client.claim_staking_rewards(&early);
+    assert_eq!(
+        client.try_claim_staking_rewards(&early),
+        Err(Ok(errors::FeeError::NothingToClaim))
+    );
+    let token = soroban_sdk::token::Client::new(&env, &sac.address());
+    assert_eq!(token.balance(&early), 350);
+    assert_eq!(client.get_staking_rewards(&late).get(asset), Some(210));
+}
+
+#[test]
+fn test_staking_rewards_count_stake_from_before_the_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
 
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_platform_treasury(&admin, &Address::generate(&env));
+    client.set_trade_fee(&admin, &20);
+    client.set_staking_reward_share(&admin, &4_000);
+
+    let provider = Address::generate(&env);
+    let trader = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    soroban_sdk::token::StellarAssetClient::new(&env, &sac.address()).mint(&trader, &1_000_000);
+    let asset = Asset {
+        symbol: soroban_sdk::Symbol::new(&env, "USDC"),
+        contract: sac.address(),
+    };
+    client.set_trade_fee_asset(&admin, &Some(asset.clone()));
+    let signal_id = create_attribution_signal(&env, &client, &provider);
+
+    // `old` staked before staking rewards existed: no total, no checkpoint.
+    let old = Address::generate(&env);
+    let late = Address::generate(&env);
+    fund_stakers(&env, &client, &admin, &[(&late, 300_000_000)]);
     env.as_contract(&contract_id, || {
-        let m: Map<u64, crate::types::SignalV1> = env
-            .storage()
+        let mut stakes: Map<Address, stake::StakeInfo> = Map::new(&env);
+        stakes.set(
+            old.clone(),
+            stake::StakeInfo {
+                amount: 100_000_000,
+                last_signal_time: 0,
+                locked_until: 0,
+            },
+        );
+        env.storage().instance().set(&StorageKey::ProviderStakes, &stakes);
+        env.storage()
             .instance()
-            .get(&StorageKey::SignalsV1)
-            .unwrap_or(Map::new(&env));
-        assert_eq!(m.len(), 0u32);
+            .remove(&staking_rewards::StakingRewardKey::StakesMigrated);
     });
 
-    for _ in 0..2 {
-        client.migrate_signals_v1_to_v2(&admin, &10u32);
-    }
-    for sid in 1u64..=100u64 {
-        let s = client.get_signal(&sid).expect("still v2");
-        assert_eq!(s.id, sid);
+    // Until the stake is counted, fees fund no rewards.
+    client.record_trade_execution(&trader, &signal_id, &100_000, &100_000, &500_000);
+    assert_eq!(client.get_treasury_balance(&asset), 700);
+    assert_eq!(client.migrate_staking_rewards(&admin), 100_000_000);
+
+    client.record_trade_execution(&trader, &signal_id, &100_000, &100_000, &500_000);
+    client.stake_tokens(&late, &300_000_000);
+    client.record_trade_execution(&trader, &signal_id, &100_000, &100_000, &500_000);
+    assert_eq!(client.get_treasury_balance(&asset), 700 + 420 + 420);
+    assert_eq!(client.get_staking_rewards(&old).get(asset.clone()), Some(280 + 70));
+    assert_eq!(client.get_staking_rewards(&late).get(asset), Some(210));
+
+    // Re-running it recomputes the same total.
+    assert_eq!(client.migrate_staking_rewards(&admin), 400_000_000);
+}
+
+#[test]
+fn test_two_step_unstake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    let submit = |client: &SignalRegistryClient| {
+        client.try_create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &SignalAction::Buy,
+            &100_000,
+            &String::from_str(&env, "Unstaking"),
+            &(env.ledger().timestamp() + 3600),
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        )
+    };
+    let stake_token = fund_stakers(&env, &client, &admin, &[(&provider, 300_000_000)]);
+    client.stake_tokens(&provider, &300_000_000);
+    assert!(submit(&client).is_ok());
+
+    env.ledger().with_mut(|l| l.timestamp += 86_400);
+    let pending = client.request_unstake(&provider, &100_000_000);
+    assert_eq!(pending.available_at, env.ledger().timestamp() + stake::UNSTAKE_LOCK_PERIOD);
+    // 200 XLM would remain, still above the 100 XLM minimum.
+    assert!(submit(&client).is_ok());
+
+    let pending = client.request_unstake(&provider, &150_000_000);
+    assert_eq!(pending.amount, 250_000_000);
+    assert_eq!(submit(&client), Err(Ok(AdminError::UnstakePending)));
+    assert_eq!(
+        client.try_request_unstake(&provider, &100_000_000),
+        Err(Ok(AdminError::InsufficientStake))
+    );
+    assert_eq!(
+        client.try_claim_unstake(&provider),
+        Err(Ok(AdminError::StakeLocked))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = pending.available_at);
+    assert_eq!(client.claim_unstake(&provider), 250_000_000);
+    assert_eq!(
+        soroban_sdk::token::Client::new(&env, &stake_token).balance(&provider),
+        250_000_000
+    );
+    assert_eq!(client.get_pending_unstake(&provider), None);
+    assert_eq!(
+        client.try_claim_unstake(&provider),
+        Err(Ok(AdminError::NoPendingUnstake))
+    );
+    assert_eq!(submit(&client), Err(Ok(AdminError::StakeBelowMinimum)));
+}
+
+#[test]
+fn test_signal_stake_released_on_cleanup_and_slashed_into_insurance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    #[allow(deprecated)]
+    let contract_id = env.register_contract(None, SignalRegistry);
+    let client = SignalRegistryClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let provider = Address::generate(&env);
+    assert_eq!(
+        client.try_stake_tokens(&provider, &300_000_000),
+        Err(Ok(AdminError::StakeTokenNotSet))
+    );
+    let stake_token = fund_stakers(&env, &client, &admin, &[(&provider, 300_000_000)]);
+    let token = soroban_sdk::token::Client::new(&env, &stake_token);
+    client.stake_tokens(&provider, &300_000_000);
+    assert_eq!(token.balance(&contract_id), 300_000_000);
+    // Stake held in one token can't be paid out in another.
+    let other_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    assert_eq!(
+        client.try_set_stake_token(&admin, &other_token),
+        Err(Ok(AdminError::InvalidParameter))
+    );
+    client.set_signal_stake_config(&admin, &100_000_000, &5_000);
+
+    let expiry = env.ledger().timestamp() + 3600;
+    for action in [SignalAction::Buy, SignalAction::Sell] {
+        client.create_signal(
+            &provider,
+            &String::from_str(&env, "XLM/USDC"),
+            &action,
+            &100_000,
+            &String::from_str(&env, "Locked"),
+            &expiry,
+            &SignalCategory::SWING,
+            &vec![&env, String::from_str(&env, "test")],
+            &RiskLevel::Medium,
+        );
     }
+    assert_eq!(client.get_locked_stake(&provider), 200_000_000);
+
+    // The cleanup marks the first signal Expired and releases its lock whole.
+    env.ledger().set_timestamp(expiry + 1);
+    assert_eq!(client.cleanup_expired_signals(&1), (1, 1));
+    assert_eq!(client.get_locked_stake(&provider), 100_000_000);
+    assert_eq!(client.get_insurance_pool(), 0);
+
+    // The second closes Failed and half its lock is slashed.
+    assert_eq!(client.expire_signals(&10).failed, 1);
+    assert_eq!(client.get_locked_stake(&provider), 0);
+    assert_eq!(client.get_insurance_pool(), 50_000_000);
+
+    // Slashed stake stays in the contract and pays out of the pool.
+    let claimant = Address::generate(&env);
+    client.withdraw_insurance(&admin, &claimant, &50_000_000);
+    assert_eq!(token.balance(&claimant), 50_000_000);
+    assert_eq!(token.balance(&contract_id), 250_000_000);
+    assert_eq!(client.get_insurance_pool(), 0);
+    assert_eq!(
+        client.try_withdraw_insurance(&admin, &claimant, &1),
+        Err(Ok(AdminError::InsufficientStake))
+    );
 }
@@ -17,6 +17,7 @@ fn test_increment_adoption() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -67,6 +68,7 @@ fn test_wrong_caller() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let auth_executor = Address::generate(&env);
     client.set_trade_executor(&admin, &auth_executor);
 
@@ -102,6 +104,7 @@ fn test_adoption_on_inactive_signal() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -144,6 +147,7 @@ fn signal_adopted_event_has_notification_fields() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
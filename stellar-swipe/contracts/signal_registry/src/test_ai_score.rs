@@ -19,6 +19,8 @@ fn ai_oracle_can_set_score() {
     let provider = Address::generate(&env);
 
     client.initialize(&admin);
+
+    client.set_min_stake(&admin, &0);
     client.set_ai_oracle(&admin, &oracle);
 
     let tags = Vec::new(&env);
@@ -55,6 +57,8 @@ fn unauthorized_cannot_set_ai_score() {
     let provider = Address::generate(&env);
 
     client.initialize(&admin);
+
+    client.set_min_stake(&admin, &0);
     client.set_ai_oracle(&admin, &oracle);
 
     let tags = Vec::new(&env);
@@ -89,6 +93,8 @@ fn signal_without_score_still_readable() {
 
     client.initialize(&admin);
 
+    client.set_min_stake(&admin, &0);
+
     let tags = Vec::new(&env);
     let signal_id = client.create_signal(
         &provider,
@@ -120,6 +126,8 @@ fn score_above_100_rejected() {
     let provider = Address::generate(&env);
 
     client.initialize(&admin);
+
+    client.set_min_stake(&admin, &0);
     client.set_ai_oracle(&admin, &oracle);
 
     let tags = Vec::new(&env);
@@ -13,6 +13,7 @@ fn setup_env() -> (Env, Address, SignalRegistryClient<'static>) {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     (env, admin, client)
 }
@@ -15,6 +15,7 @@ fn setup<'a>(env: &'a Env) -> (Address, SignalRegistryClient<'a>) {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     (admin, client)
 }
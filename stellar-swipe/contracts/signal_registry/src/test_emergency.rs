@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
 use stellar_swipe_common::emergency::{CAT_SIGNALS, CAT_ALL, CircuitBreakerConfig};
 
 #[test]
@@ -14,6 +14,7 @@ fn test_granular_pause() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let expiry = env.ledger().timestamp() + 60;
@@ -68,6 +69,7 @@ fn test_pause_all_blocks_everything() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     // Pause ALL
     client.pause_category(
@@ -105,6 +107,7 @@ fn test_circuit_breaker_trigger() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     // Set circuit breaker config: >50% failure rate
     let cb_config = CircuitBreakerConfig {
@@ -116,10 +119,9 @@ fn test_circuit_breaker_trigger() {
     client.set_circuit_breaker_config(&admin, &cb_config);
 
     let provider = Address::generate(&env);
-    let executor = Address::generate(&env);
     
     // Create a signal to record trades against
-    let signal_id = client.create_signal(
+    let _signal_id = client.create_signal(
         &provider,
         &String::from_str(&env, "XLM/USDC"),
         &SignalAction::Buy,
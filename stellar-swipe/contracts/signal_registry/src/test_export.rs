@@ -20,6 +20,7 @@ fn setup() -> (Env, Address, SignalRegistryClient) {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     (env, admin, client)
 }
@@ -99,7 +100,7 @@ fn test_export_signals_csv_header() {
 
     assert!(bytes_starts_with(
         &result,
-        b"signal_id,timestamp,asset_pair,action,price,rationale,executions,total_roi,status\n"
+        b"signal_id,registry,timestamp,asset_pair,action,price,rationale,executions,total_roi,status\n"
     ));
 }
 
@@ -111,7 +112,7 @@ fn test_export_signals_csv_empty_returns_header_only() {
     let result = client.export_signals(&provider, &0, &None).unwrap();
     // Should have header but no data rows — length equals header line
     let header =
-        b"signal_id,timestamp,asset_pair,action,price,rationale,executions,total_roi,status\n";
+        b"signal_id,registry,timestamp,asset_pair,action,price,rationale,executions,total_roi,status\n";
     assert_eq!(result.len(), header.len() as u32);
 }
 
@@ -132,6 +133,11 @@ fn test_export_signals_csv_contains_signal_data() {
     assert!(bytes_contains(&result, b"BUY"));
     // Must contain the signal id (1)
     assert!(bytes_contains(&result, b"1,"));
+    // Must name the issuing registry so the id is unambiguous
+    let registry = client.address.to_string();
+    let mut strkey = [0u8; 56];
+    registry.copy_into_slice(&mut strkey);
+    assert!(bytes_contains(&result, &strkey));
 }
 
 #[test]
@@ -214,7 +220,7 @@ fn test_export_trades_csv_header() {
 
     assert!(bytes_starts_with(
         &result,
-        b"trade_id,timestamp,signal_id,asset_pair,volume,entry_price,exit_price,roi_bps,pnl\n"
+        b"trade_id,timestamp,signal_id,registry,asset_pair,volume,entry_price,exit_price,roi_bps,pnl\n"
     ));
 }
 
@@ -225,7 +231,7 @@ fn test_export_trades_csv_empty_returns_header_only() {
 
     let result = client.export_trades(&executor, &0, &None).unwrap();
     let header =
-        b"trade_id,timestamp,signal_id,asset_pair,volume,entry_price,exit_price,roi_bps,pnl\n";
+        b"trade_id,timestamp,signal_id,registry,asset_pair,volume,entry_price,exit_price,roi_bps,pnl\n";
     assert_eq!(result.len(), header.len() as u32);
 }
 
@@ -309,6 +315,8 @@ fn test_export_performance_json_fields() {
     assert!(bytes_contains(&result, b"best_pair"));
     assert!(bytes_contains(&result, b"worst_pair"));
     assert!(bytes_contains(&result, b"avg_signal_lifetime_hours"));
+    assert!(bytes_contains(&result, b"sortino_x100"));
+    assert!(bytes_contains(&result, b"profit_factor_x100"));
 }
 
 #[test]
@@ -416,7 +424,7 @@ fn test_export_signals_date_range_no_results_returns_header() {
 
     // CSV returns header-only
     let header =
-        b"signal_id,timestamp,asset_pair,action,price,rationale,executions,total_roi,status\n";
+        b"signal_id,registry,timestamp,asset_pair,action,price,rationale,executions,total_roi,status\n";
     assert_eq!(result.len(), header.len() as u32);
 }
 
@@ -501,6 +509,46 @@ fn test_export_signals_roi_format() {
     assert!(bytes_contains(&result, b"+5.00%"));
 }
 
+// ---------------------------------------------------------------------------
+// Attribution export
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_export_attribution_groups_by_provider_pair_and_month() {
+    let (env, _admin, client) = setup();
+    let winner = Address::generate(&env);
+    let loser = Address::generate(&env);
+    let executor = Address::generate(&env);
+
+    // 1_700_000_000 is 2023-11-14.
+    let xlm = create_signal_now(&env, &client, &winner, "XLM/USDC");
+    let btc = create_signal_now(&env, &client, &loser, "BTC/USDC");
+    execute_trade(&env, &client, xlm, &executor, true);
+    execute_trade(&env, &client, btc, &executor, false);
+
+    // 2023-12-01.
+    env.ledger().set_timestamp(1_701_388_800);
+    let xlm_dec = create_signal_now(&env, &client, &winner, "XLM/USDC");
+    execute_trade(&env, &client, xlm_dec, &executor, true);
+
+    let csv = client.export_attribution(&executor, &0, &None).unwrap();
+    assert!(bytes_starts_with(
+        &csv,
+        b"provider,asset_pair,month,trades,volume,realized_pnl\n"
+    ));
+    // +10% and -8% on 1_000_000 each.
+    assert!(bytes_contains(&csv, b",XLM/USDC,2023-11,1,1000000,100000\n"));
+    assert!(bytes_contains(&csv, b",BTC/USDC,2023-11,1,1000000,-80000\n"));
+    assert!(bytes_contains(&csv, b",XLM/USDC,2023-12,1,1000000,100000\n"));
+
+    let json = client.export_attribution(&executor, &1, &None).unwrap();
+    assert!(bytes_contains(&json, b"\"month\":\"2023-12\""));
+    assert!(bytes_contains(&json, b"\"realized_pnl\":-80000"));
+
+    let exported = client.export_with_metadata(&executor, &4, &0, &None);
+    assert_eq!(exported.metadata.record_count, 3);
+}
+
 // ---------------------------------------------------------------------------
 // End-to-end workflow — 10 signals + trades
 // ---------------------------------------------------------------------------
@@ -22,6 +22,7 @@ fn test_record_trade_execution_updates_signal() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -58,6 +59,7 @@ fn test_roi_calculation_buy_signal() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -95,6 +97,7 @@ fn test_roi_calculation_sell_signal() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -126,6 +129,7 @@ fn test_signal_becomes_successful() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -165,6 +169,7 @@ fn test_signal_becomes_failed() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -204,6 +209,7 @@ fn test_provider_success_rate_calculation() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -244,6 +250,7 @@ fn test_provider_average_return() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -289,6 +296,7 @@ fn test_multiple_executions_per_signal() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -329,6 +337,7 @@ fn test_edge_case_negative_roi_capped() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -361,6 +370,7 @@ fn test_signal_failed_on_expiry_with_no_executions() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let expiry = env.ledger().timestamp() + 100;
@@ -399,6 +409,7 @@ fn test_all_wins_scenario() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -436,6 +447,7 @@ fn test_all_losses_scenario() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -473,6 +485,7 @@ fn test_query_signal_performance() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -524,6 +537,7 @@ fn test_get_top_providers() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let executor = Address::generate(&env);
     let expiry = env.ledger().timestamp() + 3600;
@@ -594,6 +608,7 @@ fn test_invalid_price_validation() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -627,6 +642,7 @@ fn test_invalid_volume_validation() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let provider = Address::generate(&env);
     let executor = Address::generate(&env);
@@ -758,6 +774,7 @@ fn test_leaderboard_volume_ranking() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
 
     let executor = Address::generate(&env);
 
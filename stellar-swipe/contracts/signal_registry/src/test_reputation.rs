@@ -9,10 +9,10 @@ mod tests {
         TrustScoreComponents, TrustScoreDetails, TrustScoreTier,
     };
     use crate::stake::StakeInfo;
-    use crate::types::ProviderPerformance;
+    use crate::types::{AttributionMode, ProviderPerformance};
     use crate::SignalRegistry;
     use soroban_sdk::testutils::{Address as _, Ledger};
-    use soroban_sdk::{Address, Env, Vec};
+    use soroban_sdk::{Address, Env};
 
     fn setup_env() -> Env {
         let env = Env::default();
@@ -45,6 +45,15 @@ mod tests {
             avg_return: 500,
             total_volume: 1000000,
             follower_count: 0,
+            attribution_mode: AttributionMode::AverageExecution,
+            tip_count: 0,
+            equity: 0,
+            peak_equity: 0,
+            max_drawdown_bps: 0,
+            current_streak: 0,
+            best_streak: 0,
+            sortino_x100: 0,
+            profit_factor_x100: 0,
         }
     }
 
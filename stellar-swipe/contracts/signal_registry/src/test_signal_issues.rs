@@ -27,6 +27,7 @@ fn issue168_update_price_within_window() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let signal_id = client.create_signal(
@@ -55,6 +56,7 @@ fn issue168_edit_window_closed() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let signal_id = client.create_signal(
@@ -83,6 +85,7 @@ fn issue168_signal_already_copied() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let executor = Address::generate(&env);
     client.set_trade_executor(&admin, &executor);
     let provider = Address::generate(&env);
@@ -113,6 +116,7 @@ fn issue168_not_owner() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let attacker = Address::generate(&env);
     let tags = Vec::new(&env);
@@ -144,6 +148,7 @@ fn issue168_field_not_editable_invalid_price_and_rationale() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let signal_id = client.create_signal(
@@ -191,6 +196,7 @@ fn issue170_first_profit_updates_reputation() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let executor = Address::generate(&env);
     client.set_trade_executor(&admin, &executor);
     let provider = Address::generate(&env);
@@ -223,6 +229,7 @@ fn issue170_outcome_twice_fails() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let executor = Address::generate(&env);
     client.set_trade_executor(&admin, &executor);
     let provider = Address::generate(&env);
@@ -255,6 +262,7 @@ fn issue170_non_executor_rejected() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let executor = Address::generate(&env);
     client.set_trade_executor(&admin, &executor);
     let rando = Address::generate(&env);
@@ -287,6 +295,7 @@ fn issue417_signal_within_warning_window_emits_event() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let signal_id = client.create_signal(
@@ -313,6 +322,7 @@ fn issue417_signal_outside_window_no_event() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let signal_id = client.create_signal(
@@ -321,7 +331,7 @@ fn issue417_signal_outside_window_no_event() {
         &crate::types::SignalAction::Buy,
         &100_000,
         &String::from_str(&env, "Rationale"),
-        &2000,
+        &7_200, // two hours out; the window is 720 ledgers, about one hour
         &crate::categories::SignalCategory::SWING,
         &tags,
         &crate::categories::RiskLevel::Medium,
@@ -339,6 +349,7 @@ fn issue417_second_call_no_duplicate_event() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let signal_id = client.create_signal(
@@ -367,6 +378,7 @@ fn issue418_benchmark_fields_initialized() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let signal_id = client.create_signal(
@@ -394,6 +406,7 @@ fn issue418_benchmark_available_stores_values() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let signal_id = client.create_signal(
@@ -421,6 +434,7 @@ fn issue420_provider_profile_initialized() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let _signal_id = client.create_signal(
@@ -445,6 +459,7 @@ fn issue420_cooling_off_validation() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let _signal_id = client.create_signal(
@@ -469,6 +484,7 @@ fn issue420_consecutive_losses_trigger_cooling_off() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let _signal_id = client.create_signal(
@@ -510,6 +526,7 @@ fn issue421_monthly_report_tracks_signals() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let _signal_id = client.create_signal(
@@ -536,6 +553,7 @@ fn issue421_best_worst_signal_identified() {
     let client = SignalRegistryClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     let provider = Address::generate(&env);
     let tags = Vec::new(&env);
     let _signal_id = client.create_signal(
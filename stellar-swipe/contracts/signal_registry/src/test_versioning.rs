@@ -12,7 +12,7 @@ fn create_test_signal(env: &Env, provider: Address, signal_id: u64) -> Signal {
     Signal {
         id: signal_id,
         provider,
-        asset_pair: String::from_str(env, "XLM/USDC"),
+        asset_pair: AssetPair::parse(env, &String::from_str(env, "XLM/USDC")).unwrap(),
         action: SignalAction::Buy,
         price: 100,
         rationale: String::from_str(env, "Initial rationale"),
@@ -641,3 +641,43 @@ fn test_get_update_count() {
     assert_eq!(versioning::get_update_count(&env, signal_id), 2);
     });
 }
+
+#[test]
+fn test_amend_signal_keeps_previous_values() {
+    let env = Env::default();
+    env.ledger().set_timestamp(100_000);
+    env.mock_all_auths();
+    #[allow(deprecated)]
+    let registry_cid = env.register_contract(None, SignalRegistry);
+    env.as_contract(&registry_cid, || {
+
+    let provider = Address::generate(&env);
+    let signal_id = 1;
+    let mut signal = create_test_signal(&env, provider.clone(), signal_id);
+    let old_expiry = signal.expiry;
+
+    assert_eq!(
+        versioning::amend_signal(&env, signal_id, &provider, None, None, None, &mut signal),
+        Err(VersioningError::NothingToAmend)
+    );
+
+    let revision = versioning::amend_signal(
+        &env,
+        signal_id,
+        &provider,
+        Some(120),
+        Some(old_expiry + 3600),
+        None,
+        &mut signal,
+    )
+    .unwrap();
+    assert_eq!(revision, 2);
+    assert_eq!(signal.price, 120);
+    assert_eq!(signal.expiry, old_expiry + 3600);
+
+    let history = versioning::get_signal_history(&env, signal_id);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().price, 100);
+    assert_eq!(history.get(0).unwrap().expiry, old_expiry);
+    });
+}
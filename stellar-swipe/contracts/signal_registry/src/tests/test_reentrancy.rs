@@ -1,9 +1,14 @@
 #![cfg(test)]
-//! Reentrancy guard tests for `unstake_tokens` (Issue #264).
+//! Reentrancy guard tests for `claim_unstake` (Issue #264).
 
 use crate::errors::AdminError;
+use crate::stake::UNSTAKE_LOCK_PERIOD;
 use crate::{SignalRegistry, SignalRegistryClient};
-use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env, Symbol,
+};
 
 fn setup() -> (Env, Address, SignalRegistryClient<'static>) {
     let env = Env::default();
@@ -13,19 +18,30 @@ fn setup() -> (Env, Address, SignalRegistryClient<'static>) {
     let client = SignalRegistryClient::new(&env, &id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    let stake_token = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_stake_token(&admin, &stake_token.address());
     (env, admin, client)
 }
 
+/// Stake and queue the whole stake for withdrawal, then wait out the lock.
+fn stake_and_request(env: &Env, client: &SignalRegistryClient, provider: &Address) {
+    StellarAssetClient::new(env, &client.get_stake_token().unwrap())
+        .mint(provider, &100_000_000i128);
+    client.stake_tokens(provider, &100_000_000i128);
+    client.request_unstake(provider, &100_000_000i128);
+    env.ledger().with_mut(|l| l.timestamp += UNSTAKE_LOCK_PERIOD);
+}
+
 /// Simulate a reentrant call by manually setting the `UnstakeLock` flag in
-/// temporary storage before calling `unstake_tokens`. The function must return
+/// temporary storage before calling `claim_unstake`. The function must return
 /// `ReentrancyDetected` without modifying any state.
 #[test]
-fn unstake_tokens_rejects_reentrant_call() {
+fn claim_unstake_rejects_reentrant_call() {
     let (env, _, client) = setup();
     let provider = Address::generate(&env);
 
-    // Stake enough to be eligible for unstaking.
-    client.stake_tokens(&provider, &100_000_000i128).unwrap();
+    // Stake and request enough to be eligible for claiming.
+    stake_and_request(&env, &client, &provider);
 
     // Simulate reentrancy: set the lock flag as if a reentrant call is in progress.
     let contract_id = client.address.clone();
@@ -35,28 +51,29 @@ fn unstake_tokens_rejects_reentrant_call() {
     });
 
     // The call must be rejected with ReentrancyDetected.
-    let err = client.try_unstake_tokens(&provider).unwrap_err().unwrap();
+    let err = client.try_claim_unstake(&provider).unwrap_err().unwrap();
     assert_eq!(err, AdminError::ReentrancyDetected);
 
-    // State must be unchanged: stake balance still present.
+    // State must be unchanged: the request is still pending.
+    assert!(client.get_pending_unstake(&provider).is_some());
     env.as_contract(&contract_id, || {
         let lock_key = Symbol::new(&env, "UnstakeLock");
-        // Clear the simulated lock so we can verify stake state.
+        // Clear the simulated lock so the claim can go through.
         env.storage().temporary().remove(&lock_key);
     });
 
-    // After clearing the simulated lock, a legitimate unstake succeeds.
-    client.unstake_tokens(&provider).unwrap();
+    // After clearing the simulated lock, a legitimate claim succeeds.
+    assert_eq!(client.claim_unstake(&provider), 100_000_000);
 }
 
-/// Verify the lock is cleared after a successful unstake (no lock leak).
+/// Verify the lock is cleared after a successful claim (no lock leak).
 #[test]
-fn unstake_tokens_clears_lock_on_success() {
+fn claim_unstake_clears_lock_on_success() {
     let (env, _, client) = setup();
     let provider = Address::generate(&env);
 
-    client.stake_tokens(&provider, &100_000_000i128).unwrap();
-    client.unstake_tokens(&provider).unwrap();
+    stake_and_request(&env, &client, &provider);
+    client.claim_unstake(&provider);
 
     // Lock must not be set after a successful call.
     let contract_id = client.address.clone();
@@ -67,19 +84,19 @@ fn unstake_tokens_clears_lock_on_success() {
             .temporary()
             .get(&lock_key)
             .unwrap_or(false);
-        assert!(!locked, "UnstakeLock was not cleared after successful unstake");
+        assert!(!locked, "UnstakeLock was not cleared after successful claim");
     });
 }
 
-/// Verify the lock is cleared after a failed unstake (no lock leak on error).
+/// Verify the lock is cleared after a failed claim (no lock leak on error).
 #[test]
-fn unstake_tokens_clears_lock_on_error() {
+fn claim_unstake_clears_lock_on_error() {
     let (env, _, client) = setup();
     let provider = Address::generate(&env);
 
-    // No stake — unstake will fail with InvalidParameter.
-    let err = client.try_unstake_tokens(&provider).unwrap_err().unwrap();
-    assert_eq!(err, AdminError::InvalidParameter);
+    // Nothing pending — the claim will fail with NoPendingUnstake.
+    let err = client.try_claim_unstake(&provider).unwrap_err().unwrap();
+    assert_eq!(err, AdminError::NoPendingUnstake);
 
     // Lock must not be set after a failed call.
     let contract_id = client.address.clone();
@@ -90,6 +107,6 @@ fn unstake_tokens_clears_lock_on_error() {
             .temporary()
             .get(&lock_key)
             .unwrap_or(false);
-        assert!(!locked, "UnstakeLock was not cleared after failed unstake");
+        assert!(!locked, "UnstakeLock was not cleared after failed claim");
     });
 }
@@ -22,6 +22,7 @@ fn setup() -> (Env, Address, SignalRegistryClient<'static>) {
     let client = SignalRegistryClient::new(&env, &id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_min_stake(&admin, &0);
     (env, admin, client)
 }
 
@@ -120,7 +121,7 @@ fn submit_unsupported_pair_rejected() {
 fn submit_spam_limit_enforced() {
     let (env, admin, client) = setup();
     // Set a tight rate limit: max 2 signal submissions per window.
-    use stellar_swipe_common::rate_limit::{ActionType, RateLimitConfig};
+    use stellar_swipe_common::rate_limit::ActionType;
     client.set_rate_limit_config(
         &admin,
         &ActionType::SignalSubmission,
@@ -211,14 +212,14 @@ fn expire_past_expiry_marks_expired_and_emits_event() {
     env.ledger().set_timestamp(env.ledger().timestamp() + 200);
 
     use soroban_sdk::testutils::Events;
-    let events_before = env.events().all().len();
     client.cleanup_expired_signals(&10);
-    let events_after = env.events().all().len();
+    // The test env only keeps the last invocation's events.
+    let cleanup_events = env.events().all().len();
 
     // Status transitioned.
     assert_eq!(client.get_signal(&id).unwrap().status, SignalStatus::Expired);
     // At least one event was emitted (signal_expired).
-    assert!(events_after > events_before, "expiry event must be emitted");
+    assert!(cleanup_events > 0, "expiry event must be emitted");
 }
 
 /// Signal not yet past expiry stays Active after cleanup.
@@ -0,0 +1,125 @@
+//! Provider tiers earned through performance.
+//!
+//! A provider's tier is derived from their closed signals, success rate and
+//! traded volume, and recomputed whenever their stats change. Tiers sit
+//! alongside stake tiers: wherever a perk scales with stake tier (active
+//! signal limits, creation fee discounts) the provider gets the better of
+//! their stake tier and the perk level of their performance tier.
+
+use soroban_sdk::{contracttype, Address, Env};
+use stellar_swipe_common::STELLAR_AMOUNT_SCALE;
+
+use crate::events;
+use crate::types::ProviderPerformance;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ProviderTier {
+    Bronze = 0,
+    Silver = 1,
+    Gold = 2,
+    Diamond = 3,
+}
+
+/// What a tier requires: closed signals, success rate (BPS) and volume.
+struct TierRequirement {
+    tier: ProviderTier,
+    min_signals: u32,
+    min_success_rate: u32,
+    min_volume: i128,
+}
+
+/// Highest tier first.
+const REQUIREMENTS: [TierRequirement; 3] = [
+    TierRequirement {
+        tier: ProviderTier::Diamond,
+        min_signals: 200,
+        min_success_rate: 7_500,
+        min_volume: 1_000_000 * STELLAR_AMOUNT_SCALE,
+    },
+    TierRequirement {
+        tier: ProviderTier::Gold,
+        min_signals: 50,
+        min_success_rate: 6_500,
+        min_volume: 50_000 * STELLAR_AMOUNT_SCALE,
+    },
+    TierRequirement {
+        tier: ProviderTier::Silver,
+        min_signals: 10,
+        min_success_rate: 5_500,
+        min_volume: 1_000 * STELLAR_AMOUNT_SCALE,
+    },
+];
+
+#[contracttype]
+#[derive(Clone)]
+pub enum TierKey {
+    Tier(Address),
+}
+
+pub fn compute_tier(stats: &ProviderPerformance) -> ProviderTier {
+    for req in REQUIREMENTS.iter() {
+        if stats.total_signals >= req.min_signals
+            && stats.success_rate >= req.min_success_rate
+            && stats.total_volume >= req.min_volume
+        {
+            return req.tier;
+        }
+    }
+    ProviderTier::Bronze
+}
+
+pub fn get_tier(env: &Env, provider: &Address) -> ProviderTier {
+    env.storage()
+        .persistent()
+        .get(&TierKey::Tier(provider.clone()))
+        .unwrap_or(ProviderTier::Bronze)
+}
+
+/// Recompute the provider's tier from fresh stats. Returns the new tier.
+pub fn refresh(env: &Env, provider: &Address, stats: &ProviderPerformance) -> ProviderTier {
+    let old = get_tier(env, provider);
+    let new = compute_tier(stats);
+    if new != old {
+        env.storage()
+            .persistent()
+            .set(&TierKey::Tier(provider.clone()), &new);
+        events::emit_provider_tier_changed(env, provider.clone(), old as u32, new as u32);
+    }
+    new
+}
+
+/// Stake-tier equivalent of a performance tier, for perks keyed by stake
+/// tier (1 = bronze .. 3 = gold): each tier above Bronze counts as one stake
+/// tier, so Silver ranks with a bronze stake and Diamond with a gold one.
+pub fn perk_level(tier: ProviderTier) -> u32 {
+    tier as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(total_signals: u32, success_rate: u32, volume_xlm: i128) -> ProviderPerformance {
+        ProviderPerformance {
+            total_signals,
+            success_rate,
+            total_volume: volume_xlm * STELLAR_AMOUNT_SCALE,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_tier_requires_every_threshold() {
+        assert_eq!(compute_tier(&stats(0, 0, 0)), ProviderTier::Bronze);
+        assert_eq!(compute_tier(&stats(10, 5_500, 1_000)), ProviderTier::Silver);
+        assert_eq!(compute_tier(&stats(60, 7_000, 60_000)), ProviderTier::Gold);
+        assert_eq!(
+            compute_tier(&stats(250, 8_000, 2_000_000)),
+            ProviderTier::Diamond
+        );
+        // Plenty of signals and volume but a poor success rate.
+        assert_eq!(compute_tier(&stats(250, 5_000, 2_000_000)), ProviderTier::Bronze);
+    }
+}
@@ -0,0 +1,158 @@
+//! Tips from the community to providers of successful signals.
+//!
+//! A tip is paid straight from the tipper: the platform keeps
+//! `TIP_PLATFORM_CUT_BPS` and the rest goes to the provider. Cumulative tips
+//! are recorded per (provider, token), since amounts in different tokens
+//! can't be added up. The Tips leaderboard ranks on the total in one
+//! admin-chosen token, so a flood of dust tips in a worthless token can't
+//! buy a ranking.
+
+use soroban_sdk::{contracttype, token, Address, Env};
+
+use crate::errors::TippingError;
+use crate::fees;
+
+/// Platform cut of each tip (2.5%).
+pub const TIP_PLATFORM_CUT_BPS: i128 = 250;
+const BPS_DENOMINATOR: i128 = 10_000;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum TipKey {
+    /// Cumulative net tips received by a provider in one token.
+    ProviderTotal(Address, Address),
+    /// Token whose tip totals rank the Tips leaderboard.
+    RankingToken,
+}
+
+/// Platform cut of a tip of `amount`.
+pub fn platform_cut(amount: i128) -> i128 {
+    amount * TIP_PLATFORM_CUT_BPS / BPS_DENOMINATOR
+}
+
+pub fn get_total_tips(env: &Env, provider: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TipKey::ProviderTotal(provider.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_ranking_token(env: &Env, token: &Address) {
+    env.storage().instance().set(&TipKey::RankingToken, token);
+}
+
+pub fn get_ranking_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&TipKey::RankingToken)
+}
+
+/// Net tips a provider has received in the ranking token; zero while none
+/// is set.
+pub fn ranked_total(env: &Env, provider: &Address) -> i128 {
+    get_ranking_token(env).map_or(0, |token| get_total_tips(env, provider, &token))
+}
+
+/// Move `amount` of `token` from `from` to `provider`, minus the platform
+/// cut, and add it to the provider's total. Returns what the provider received.
+pub fn tip(
+    env: &Env,
+    from: &Address,
+    provider: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<i128, TippingError> {
+    if amount <= 0 {
+        return Err(TippingError::InvalidAmount);
+    }
+    if from == provider {
+        return Err(TippingError::SelfTip);
+    }
+
+    let cut = platform_cut(amount);
+    let net = amount - cut;
+    let client = token::Client::new(env, token);
+    if cut > 0 {
+        let treasury = fees::get_platform_treasury(env).ok_or(TippingError::TreasuryNotSet)?;
+        client.transfer(from, &treasury, &cut);
+    }
+    client.transfer(from, provider, &net);
+
+    let total = get_total_tips(env, provider, token)
+        .checked_add(net)
+        .ok_or(TippingError::InvalidAmount)?;
+    env.storage()
+        .persistent()
+        .set(&TipKey::ProviderTotal(provider.clone(), token.clone()), &total);
+    Ok(net)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::token::StellarAssetClient;
+    use soroban_sdk::{contract, Env};
+
+    #[contract]
+    struct TestContract;
+
+    #[test]
+    fn tip_takes_platform_cut_and_accumulates() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register(TestContract, ());
+        let admin = Address::generate(&env);
+        let from = Address::generate(&env);
+        let provider = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        let sac = env.register_stellar_asset_contract_v2(admin);
+        let token_id = sac.address();
+        StellarAssetClient::new(&env, &token_id).mint(&from, &10_000);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                tip(&env, &from, &provider, &token_id, 1_000),
+                Err(TippingError::TreasuryNotSet)
+            );
+            fees::set_platform_treasury(&env, treasury.clone());
+            assert_eq!(tip(&env, &from, &provider, &token_id, 0), Err(TippingError::InvalidAmount));
+            assert_eq!(tip(&env, &from, &provider, &token_id, 1_000), Ok(975));
+            // Too small for any cut: everything reaches the provider.
+            assert_eq!(tip(&env, &from, &provider, &token_id, 39), Ok(39));
+            assert_eq!(get_total_tips(&env, &provider, &token_id), 1_014);
+        });
+
+        let client = token::Client::new(&env, &token_id);
+        assert_eq!(client.balance(&provider), 1_014);
+        assert_eq!(client.balance(&treasury), 25);
+    }
+
+    #[test]
+    fn only_ranking_token_tips_rank() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register(TestContract, ());
+        let from = Address::generate(&env);
+        let provider = Address::generate(&env);
+        let ranked_token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let dust_token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        StellarAssetClient::new(&env, &ranked_token).mint(&from, &10_000);
+        StellarAssetClient::new(&env, &dust_token).mint(&from, &10_000);
+
+        env.as_contract(&contract_id, || {
+            fees::set_platform_treasury(&env, Address::generate(&env));
+            for _ in 0..5 {
+                tip(&env, &from, &provider, &dust_token, 1).unwrap();
+            }
+            assert_eq!(ranked_total(&env, &provider), 0);
+
+            set_ranking_token(&env, &ranked_token);
+            assert_eq!(ranked_total(&env, &provider), 0);
+            tip(&env, &from, &provider, &ranked_token, 1_000).unwrap();
+            assert_eq!(ranked_total(&env, &provider), 975);
+        });
+    }
+}
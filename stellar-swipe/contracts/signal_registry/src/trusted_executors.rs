@@ -0,0 +1,70 @@
+//! Trusted trade executors.
+//!
+//! `record_trade_execution` lets any executor report its own trades, which
+//! is convenient for testing but lets anyone feed ROI and leaderboards.
+//! Once the admin turns the restriction on, only whitelisted executors
+//! (normally the auto_trade contract) are accepted. Contract calls authorize
+//! their own address, so the check is simply `executor.require_auth()` plus
+//! membership.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum TrustedExecutorKey {
+    Executors,
+    /// Whether self-reported executions are refused.
+    Restricted,
+}
+
+pub fn get_all(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&TrustedExecutorKey::Executors)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn is_trusted(env: &Env, executor: &Address) -> bool {
+    get_all(env).contains(executor)
+}
+
+/// Add `executor` to the whitelist. Returns false if it was already there.
+pub fn add(env: &Env, executor: &Address) -> bool {
+    let mut executors = get_all(env);
+    if executors.contains(executor) {
+        return false;
+    }
+    executors.push_back(executor.clone());
+    env.storage()
+        .instance()
+        .set(&TrustedExecutorKey::Executors, &executors);
+    true
+}
+
+/// Remove `executor` from the whitelist. Returns false if it wasn't there.
+pub fn remove(env: &Env, executor: &Address) -> bool {
+    let mut executors = get_all(env);
+    match executors.first_index_of(executor) {
+        Some(i) => {
+            executors.remove(i);
+            env.storage()
+                .instance()
+                .set(&TrustedExecutorKey::Executors, &executors);
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn is_restricted(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&TrustedExecutorKey::Restricted)
+        .unwrap_or(false)
+}
+
+pub fn set_restricted(env: &Env, restricted: bool) {
+    env.storage()
+        .instance()
+        .set(&TrustedExecutorKey::Restricted, &restricted);
+}
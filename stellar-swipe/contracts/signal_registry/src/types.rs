@@ -1,5 +1,6 @@
 use crate::categories::{RiskLevel, SignalCategory};
 use soroban_sdk::{contracttype, Address, String, Symbol, Vec};
+use stellar_swipe_common::AssetPair;
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -14,7 +15,7 @@ pub enum SortOption {
 pub struct SignalSummary {
     pub id: u64,
     pub provider: Address,
-    pub asset_pair: String,
+    pub asset_pair: AssetPair,
     pub action: SignalAction,
     pub price: i128,
     pub success_rate: u32,
@@ -50,7 +51,7 @@ pub enum SignalAction {
 pub struct Signal {
     pub id: u64,
     pub provider: Address,
-    pub asset_pair: String,
+    pub asset_pair: AssetPair,
     pub action: SignalAction,
     pub price: i128,
     pub rationale: String,
@@ -87,6 +88,12 @@ pub struct Signal {
     pub benchmark_return_bps: Option<i64>,
     /// Alpha (outperformance) in basis points at signal close (Issue #418).
     pub alpha_bps: Option<i64>,
+    /// Oracle price at which the signal is settled as Failed.
+    pub stop_loss: Option<i128>,
+    /// Oracle price at which the signal is settled as Successful.
+    pub take_profit: Option<i128>,
+    /// Price, rationale and targets are served only to subscribers.
+    pub premium: bool,
 }
 
 #[contracttype]
@@ -126,6 +133,41 @@ pub struct SignalV1 {
     pub is_collaborative: bool,
 }
 
+/// Legacy on-chain format (v2) before v3 stored the pair as an [`AssetPair`] and
+/// added `stop_loss`, `take_profit`, and `premium`. Used only for admin migration
+/// to [`Signal`].
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignalV2 {
+    pub id: u64,
+    pub provider: Address,
+    pub asset_pair: String,
+    pub action: SignalAction,
+    pub price: i128,
+    pub rationale: String,
+    pub timestamp: u64,
+    pub expiry: u64,
+    pub status: SignalStatus,
+    pub executions: u32,
+    pub successful_executions: u32,
+    pub total_volume: i128,
+    pub total_roi: i128,
+    pub category: SignalCategory,
+    pub tags: Vec<String>,
+    pub risk_level: RiskLevel,
+    pub is_collaborative: bool,
+    pub submitted_at: u64,
+    pub rationale_hash: String,
+    pub confidence: u32,
+    pub adoption_count: u32,
+    pub ai_validation_score: Option<u32>,
+    pub avg_copier_roi_bps: i32,
+    pub copier_closed_count: u32,
+    pub warning_emitted: bool,
+    pub benchmark_return_bps: Option<i64>,
+    pub alpha_bps: Option<i64>,
+}
+
 /// Emitted each time `migrate_signals_v1_to_v2` processes a batch.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -158,6 +200,42 @@ pub struct SignalEditInput {
 }
 
 
+/// How a signal's success is judged when it settles.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributionMode {
+    /// Equal-weighted average ROI of all executions.
+    AverageExecution,
+    /// ROI of the first recorded execution only.
+    FirstExecution,
+    /// ROI of all executions weighted by their volume.
+    VolumeWeighted,
+    /// Signal price against the oracle price a fixed horizon after publication.
+    OracleHorizon,
+}
+
+impl Default for AttributionMode {
+    fn default() -> Self {
+        AttributionMode::AverageExecution
+    }
+}
+
+/// Legacy provider stats (v1) before attribution mode, tip count, equity,
+/// streak, and risk-ratio fields were added. Used only for admin migration to
+/// [`ProviderPerformance`].
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ProviderPerformanceV1 {
+    pub total_signals: u32,
+    pub successful_signals: u32,
+    pub failed_signals: u32,
+    pub total_copies: u64,
+    pub success_rate: u32,
+    pub avg_return: i128,
+    pub total_volume: i128,
+    pub follower_count: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Default)]
 pub struct ProviderPerformance {
@@ -169,6 +247,27 @@ pub struct ProviderPerformance {
     pub avg_return: i128,
     pub total_volume: i128,
     pub follower_count: u32,
+    /// Attribution mode in effect when these stats were last updated, so
+    /// providers are only compared on a like-for-like basis.
+    pub attribution_mode: AttributionMode,
+    /// Tips received across all tokens; amounts are tracked per token in `tipping`.
+    pub tip_count: u32,
+    /// Compounded equity of following every settled signal, scaled by
+    /// `performance::EQUITY_SCALE`. 0 until the first signal settles.
+    pub equity: i128,
+    /// Highest `equity` reached so far.
+    pub peak_equity: i128,
+    /// Largest peak-to-trough fall in `equity`, in BPS.
+    pub max_drawdown_bps: u32,
+    /// Consecutive settled signals with the same outcome: positive for wins,
+    /// negative for losses.
+    pub current_streak: i32,
+    /// Longest run of successful signals.
+    pub best_streak: u32,
+    /// Mean ROI over downside deviation of settled signals, ×100.
+    pub sortino_x100: i128,
+    /// Gross winning ROI over gross losing ROI, ×100.
+    pub profit_factor_x100: i128,
 }
 
 #[contracttype]
@@ -191,7 +290,12 @@ pub struct ProviderProfile {
 pub enum FeeStorageKey {
     PlatformTreasury,
     ProviderTreasury,
+    /// Accrued platform share, per asset.
     TreasuryBalances,
+    /// Accrued share of one provider, per asset.
+    ProviderBalances(Address),
+    /// Asset trade fees are charged in when executions are recorded.
+    FeeAsset,
 }
 
 #[contracttype]
@@ -201,6 +305,31 @@ pub struct FeeBreakdown {
     pub platform_fee: i128,
     pub provider_fee: i128,
     pub trade_amount_after_fee: i128,
+    /// Fee waived by the payer's stake discount; already excluded from `total_fee`.
+    pub discount: i128,
+}
+
+/// Fee breakdown without `discount`, as returned by `calculate_fee_preview`
+/// before stake discounts existed. Kept so that entrypoint's return type does
+/// not change under existing callers.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FeeBreakdownV1 {
+    pub total_fee: i128,
+    pub platform_fee: i128,
+    pub provider_fee: i128,
+    pub trade_amount_after_fee: i128,
+}
+
+impl From<FeeBreakdown> for FeeBreakdownV1 {
+    fn from(b: FeeBreakdown) -> Self {
+        FeeBreakdownV1 {
+            total_fee: b.total_fee,
+            platform_fee: b.platform_fee,
+            provider_fee: b.provider_fee,
+            trade_amount_after_fee: b.trade_amount_after_fee,
+        }
+    }
 }
 
 #[contracttype]
@@ -3,6 +3,7 @@ use crate::submission::{Action, Signal};
 use crate::types::{ProviderProfile, Outcome, SignalStatus};
 use crate::errors::AdminError;
 use crate::admin;
+use crate::signal_index;
 
 /// Maximum allowed price deviation from oracle price (in basis points)
 /// 2000 = 20% deviation allowed
@@ -34,19 +35,38 @@ pub enum PriceReasonablenessError {
     PriceUnreasonable,
 }
 
-pub fn count_active_provider_signals(storage: &Map<u64, Signal>, provider: &Address) -> u32 {
+/// Active signals owned by `provider`, counted through whichever of the
+/// provider's index bucket and the Active status bucket is smaller. Each
+/// candidate is checked against the signal itself, since a bucket can lag.
+pub fn count_active_provider_signals(
+    env: &Env,
+    storage: &Map<u64, crate::types::Signal>,
+    provider: &Address,
+) -> u32 {
+    let by_provider = signal_index::ids_by_provider(env, provider);
+    let active = signal_index::ids_by_status(env, &SignalStatus::Active);
+    let candidates = if by_provider.len() <= active.len() {
+        by_provider
+    } else {
+        active
+    };
+
     let mut count: u32 = 0;
-    for (_signal_id, signal) in storage.iter() {
-        if signal.provider == *provider && signal.status == SignalStatus::Active {
-            count = count.saturating_add(1);
+    for id in candidates.iter() {
+        if let Some(signal) = storage.get(id) {
+            if signal.provider == *provider && signal.status == SignalStatus::Active {
+                count = count.saturating_add(1);
+            }
         }
     }
     count
 }
 
+/// Reject a new signal when the provider already has as many concurrently
+/// Active signals as their stake tier allows.
 pub fn validate_provider_signal_limit(
     env: &Env,
-    storage: &Map<u64, Signal>,
+    storage: &Map<u64, crate::types::Signal>,
     provider: &Address,
     tier: u32,
 ) -> Result<(), AdminError> {
@@ -56,12 +76,26 @@ pub fn validate_provider_signal_limit(
         _ => admin::get_bronze_signal_limit(env),
     };
 
-    if count_active_provider_signals(storage, provider) >= limit {
-        return Err(AdminError::SignalLimitExceeded);
+    if count_active_provider_signals(env, storage, provider) >= limit {
+        return Err(AdminError::TooManyActiveSignals);
     }
     Ok(())
 }
 
+/// Reject a new signal while the provider's creation cooldown is running.
+pub fn validate_signal_cooldown(env: &Env, provider: &Address) -> Result<(), AdminError> {
+    let cooldown = admin::get_signal_cooldown(env);
+    if cooldown == 0 {
+        return Ok(());
+    }
+    match admin::get_last_signal_at(env, provider) {
+        Some(last) if env.ledger().timestamp() < last.saturating_add(cooldown) => {
+            Err(AdminError::SignalCooldownActive)
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Check if a new signal is a duplicate of an existing active signal.
 /// 
 /// Duplicate criteria:
@@ -177,7 +211,7 @@ pub fn validate_rationale_hash(
 /// # Returns
 /// Ok(()) if valid, or Err with appropriate error
 pub fn validate_rationale_hash_string(
-    env: &Env,
+    _env: &Env,
     rationale_hash_str: &String,
 ) -> Result<(), RationaleHashError> {
     let hash_bytes = rationale_hash_str.to_bytes();
@@ -257,8 +291,11 @@ pub fn check_price_reasonableness(
         Err(_) => return Ok(None),
     };
     
-    // Convert oracle price to i128
-    let oracle_price = oracle_price_to_i128(&oracle_price_data);
+    // Rescale to the precision signal prices are quoted in
+    let oracle_price = match oracle_price_to_i128(&oracle_price_data) {
+        Some(price) => price,
+        None => return Ok(None),
+    };
     
     // Check if prices are within acceptable deviation
     if is_price_reasonable(signal_price, oracle_price) {
@@ -306,7 +343,7 @@ pub fn is_provider_cooling_off(env: &Env, profile: &ProviderProfile) -> bool {
         return false;
     }
 
-    let current_ledger = env.ledger().sequence();
+    let current_ledger = env.ledger().sequence() as u64;
     if current_ledger >= profile.cooling_off_ends_at {
         return false;
     }
@@ -317,7 +354,7 @@ pub fn is_provider_cooling_off(env: &Env, profile: &ProviderProfile) -> bool {
 
     for i in 0..5 {
         if let Some(outcome) = profile.last_5_outcomes.get(i as u32) {
-            if outcome != &Outcome::Loss {
+            if outcome != Outcome::Loss {
                 return false;
             }
         } else {
@@ -340,7 +377,14 @@ pub fn update_provider_outcomes(profile: &mut ProviderProfile, outcome: Outcome)
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::{testutils::Address as TestAddress, Env, Map};
+    use soroban_sdk::{
+        contract,
+        testutils::{Address as TestAddress, Ledger as _},
+        Env, Map,
+    };
+
+    #[contract]
+    struct TestContract;
 
     fn sdk_string(env: &Env, s: &str) -> String {
         #[allow(deprecated)]
@@ -463,6 +507,7 @@ mod tests {
     #[test]
     fn test_expired_signal_not_duplicate() {
         let env = Env::default();
+        env.ledger().set_timestamp(10_000);
         let mut storage: Map<u64, Signal> = Map::new(&env);
         let provider = <Address as TestAddress>::generate(&env);
         
@@ -586,6 +631,7 @@ mod tests {
     #[test]
     fn test_old_signal_not_duplicate() {
         let env = Env::default();
+        env.ledger().set_timestamp(10_000);
         let mut storage: Map<u64, Signal> = Map::new(&env);
         let provider = <Address as TestAddress>::generate(&env);
         
@@ -787,32 +833,32 @@ mod tests {
     #[test]
     fn test_check_price_reasonableness_with_mock_oracle_within_range() {
         let env = Env::default();
+        let cid = env.register(TestContract, ());
+        env.as_contract(&cid, || {
+            // Set up mock oracle price
+            use stellar_swipe_common::oracle::{MockOracleClient, OraclePrice};
+            use soroban_sdk::Symbol;
         
-        // Set up mock oracle price
-        use stellar_swipe_common::oracle::{MockOracleClient, OraclePrice};
-        use soroban_sdk::Symbol;
-        
-        let oracle_price = OraclePrice {
-            price: 100_000_000,
-            decimals: 0,
-            timestamp: env.ledger().timestamp(),
-            source: Symbol::new(&env, "test"),
-        };
+            let oracle_price = OraclePrice {
+                price: 100_000_000,
+                decimals: 0,
+                timestamp: env.ledger().timestamp(),
+                source: Symbol::new(&env, "test"),
+            };
         
-        MockOracleClient::set_price(&env, 1, oracle_price);
+            MockOracleClient::set_price(&env, 1, oracle_price);
         
-        // Signal price within 20% (110 vs 100)
-        let signal_price = 110_000_000;
+            // Signal price within 20% (110 vs 100)
+            let signal_price = 110_000_000;
         
-        // Note: This test would need the MockOracleClient to be used instead of OnChainOracleClient
-        // For now, we test the is_price_reasonable function directly
-        assert!(is_price_reasonable(signal_price, 100_000_000));
+            // Note: This test would need the MockOracleClient to be used instead of OnChainOracleClient
+            // For now, we test the is_price_reasonable function directly
+            assert!(is_price_reasonable(signal_price, 100_000_000));
+        });
     }
 
     #[test]
     fn test_check_price_reasonableness_with_mock_oracle_outside_range() {
-        let env = Env::default();
-        
         // Signal price outside 20% (130 vs 100 = 30% deviation)
         let signal_price = 130_000_000;
         let oracle_price = 100_000_000;
@@ -1,7 +1,8 @@
 use crate::errors::VersioningError;
 use crate::events;
+use crate::storage_monitor::{self, StorageEntry};
 use crate::types::{Signal, SignalStatus};
-use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 use stellar_swipe_common::SECONDS_PER_HOUR;
 
 const MAX_UPDATES_PER_SIGNAL: u32 = 5;
@@ -106,6 +107,7 @@ pub fn update_signal(
     env.storage()
         .persistent()
         .set(&version_storage_key, &version_record);
+    storage_monitor::touch(env, StorageEntry::SignalVersion(signal_id, current_version));
 
     // Apply updates
     if let Some(price) = new_price {
@@ -141,6 +143,56 @@ pub fn update_signal(
     Ok(new_version)
 }
 
+/// Amend the target price, expiry or rationale of an active signal. The
+/// values being replaced are kept as a revision in the signal's history and
+/// published with the new ones, so an edit is never silent. Returns the new
+/// revision number.
+pub fn amend_signal(
+    env: &Env,
+    signal_id: u64,
+    provider: &Address,
+    new_price: Option<i128>,
+    new_expiry: Option<u64>,
+    new_rationale: Option<String>,
+    signal: &mut Signal,
+) -> Result<u32, VersioningError> {
+    if new_price.is_none() && new_expiry.is_none() && new_rationale.is_none() {
+        return Err(VersioningError::NothingToAmend);
+    }
+
+    let previous_price = signal.price;
+    let previous_expiry = signal.expiry;
+    let rationale_changed = new_rationale
+        .as_ref()
+        .map(|r| *r != signal.rationale)
+        .unwrap_or(false);
+
+    let revision = update_signal(
+        env,
+        signal_id,
+        provider,
+        new_price,
+        new_rationale,
+        new_expiry,
+        signal,
+    )?;
+
+    events::emit_signal_amended(
+        env,
+        events::SignalAmendedEvent {
+            signal_id,
+            provider: provider.clone(),
+            revision,
+            previous_price,
+            price: signal.price,
+            previous_expiry,
+            expiry: signal.expiry,
+            rationale_changed,
+        },
+    );
+    Ok(revision)
+}
+
 pub fn get_signal_history(env: &Env, signal_id: u64) -> Vec<SignalVersion> {
     let version_key = VersioningStorageKey::LatestVersion(signal_id);
     let latest_version: u32 = env.storage().persistent().get(&version_key).unwrap_or(1);
@@ -0,0 +1,266 @@
+//! Aggregations computed from paged registry reads.
+//!
+//! Provider queries read only that provider's signals, through the registry's
+//! provider index. Platform totals and the leaderboard span every provider,
+//! so they are built by a resumable scan: each call reads at most one
+//! registry page of signals after `cursor`, folds it into the running
+//! per-provider totals the caller passes back in, and returns the totals with
+//! the cursor to resume from. A result is complete once `next_cursor` is
+//! `None`. Nothing is cached: the viewer holds no copy of registry data, and
+//! the partial totals live with the caller.
+
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+use stellar_swipe_common::{div_round, BASIS_POINTS_DENOMINATOR_I128, DEFAULT_ROUNDING_MODE};
+
+use crate::registry::{Signal, SignalPage, SignalRegistryClient, SignalStatus};
+
+/// Signals requested per registry call. Matches the registry's page cap, and
+/// bounds the signals a single scan step reads.
+pub const SCAN_PAGE_SIZE: u32 = 50;
+pub const DEFAULT_LEADERBOARD_LIMIT: u32 = 10;
+pub const MAX_LEADERBOARD_LIMIT: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ViewerMetric {
+    Volume,
+    SuccessRate,
+    Executions,
+    AvgRoi,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderSummary {
+    pub provider: Address,
+    pub total_signals: u32,
+    pub active_signals: u32,
+    pub executions: u32,
+    pub successful_executions: u32,
+    /// Successful executions over all executions, in bps.
+    pub success_rate: u32,
+    pub total_volume: i128,
+    /// Average ROI per execution, in bps.
+    pub avg_roi: i128,
+    /// Sum of per-execution ROI, in bps.
+    pub total_roi: i128,
+}
+
+impl ProviderSummary {
+    fn empty(provider: Address) -> Self {
+        ProviderSummary {
+            provider,
+            total_signals: 0,
+            active_signals: 0,
+            executions: 0,
+            successful_executions: 0,
+            success_rate: 0,
+            total_volume: 0,
+            avg_roi: 0,
+            total_roi: 0,
+        }
+    }
+
+    fn add(&mut self, signal: &Signal) {
+        self.total_signals += 1;
+        if signal.status == SignalStatus::Active {
+            self.active_signals += 1;
+        }
+        self.executions += signal.executions;
+        self.successful_executions += signal.successful_executions;
+        self.total_volume += signal.total_volume;
+        self.total_roi += signal.total_roi;
+    }
+
+    fn finalize(&mut self) {
+        if self.executions == 0 {
+            return;
+        }
+        let executions = self.executions as i128;
+        self.success_rate = (self.successful_executions as i128 * BASIS_POINTS_DENOMINATOR_I128
+            / executions) as u32;
+        self.avg_roi = div_round(self.total_roi, executions, DEFAULT_ROUNDING_MODE).unwrap_or(0);
+    }
+
+    fn metric(&self, metric: ViewerMetric) -> i128 {
+        match metric {
+            ViewerMetric::Volume => self.total_volume,
+            ViewerMetric::SuccessRate => self.success_rate as i128,
+            ViewerMetric::Executions => self.executions as i128,
+            ViewerMetric::AvgRoi => self.avg_roi,
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlatformStats {
+    pub total_signals: u32,
+    pub active_signals: u32,
+    pub provider_count: u32,
+    pub total_executions: u32,
+    pub successful_executions: u32,
+    /// Successful executions over all executions, in bps.
+    pub success_rate: u32,
+    pub total_volume: i128,
+}
+
+/// Running per-provider totals over the signals scanned so far, and where to
+/// resume.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderScan {
+    pub summaries: Map<Address, ProviderSummary>,
+    /// Cursor for the next step; `None` once every signal has been scanned.
+    pub next_cursor: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlatformStatsPage {
+    /// Totals over `scan.summaries`; final once `scan.next_cursor` is `None`.
+    pub stats: PlatformStats,
+    pub scan: ProviderScan,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeaderboardPage {
+    /// Ranking over `scan.summaries`; final once `scan.next_cursor` is `None`.
+    pub ranked: Vec<ProviderSummary>,
+    pub scan: ProviderScan,
+}
+
+/// Feed every page of a cursor-paged registry query to `f`.
+pub(crate) fn for_each_page(mut fetch: impl FnMut(u64) -> SignalPage, mut f: impl FnMut(Signal)) {
+    let mut cursor = 0u64;
+    loop {
+        let page = fetch(cursor);
+        for signal in page.signals.iter() {
+            f(signal);
+        }
+        match page.next_cursor {
+            Some(next) => cursor = next,
+            None => break,
+        }
+    }
+}
+
+/// One scan step: up to `limit` signals with ids above `cursor`. `limit` 0
+/// or above `SCAN_PAGE_SIZE` reads a full page.
+pub(crate) fn scan_page(env: &Env, registry: &Address, cursor: u64, limit: u32) -> SignalPage {
+    let limit = match limit {
+        0 => SCAN_PAGE_SIZE,
+        n => n.min(SCAN_PAGE_SIZE),
+    };
+    SignalRegistryClient::new(env, registry).get_signals(&cursor, &limit)
+}
+
+/// Fold one scan step (see `scan_page`) into `carry`, the summaries
+/// returned by the previous step (empty for the first).
+pub fn scan_providers(
+    env: &Env,
+    registry: &Address,
+    cursor: u64,
+    limit: u32,
+    carry: Map<Address, ProviderSummary>,
+) -> ProviderScan {
+    let page = scan_page(env, registry, cursor, limit);
+    let mut summaries = carry;
+    for signal in page.signals.iter() {
+        let mut summary = summaries
+            .get(signal.provider.clone())
+            .unwrap_or_else(|| ProviderSummary::empty(signal.provider.clone()));
+        summary.add(&signal);
+        summary.finalize();
+        summaries.set(signal.provider.clone(), summary);
+    }
+    ProviderScan {
+        summaries,
+        next_cursor: page.next_cursor,
+    }
+}
+
+pub fn provider_summary(env: &Env, registry: &Address, provider: &Address) -> ProviderSummary {
+    let client = SignalRegistryClient::new(env, registry);
+    let mut summary = ProviderSummary::empty(provider.clone());
+    for_each_page(
+        |cursor| client.get_signals_by_provider(provider, &cursor, &SCAN_PAGE_SIZE),
+        |signal| summary.add(&signal),
+    );
+    summary.finalize();
+    summary
+}
+
+/// Platform totals, one scan step at a time; see `scan_providers`.
+pub fn platform_stats(
+    env: &Env,
+    registry: &Address,
+    cursor: u64,
+    limit: u32,
+    carry: Map<Address, ProviderSummary>,
+) -> PlatformStatsPage {
+    let scan = scan_providers(env, registry, cursor, limit, carry);
+    let mut stats = PlatformStats {
+        total_signals: 0,
+        active_signals: 0,
+        provider_count: scan.summaries.len(),
+        total_executions: 0,
+        successful_executions: 0,
+        success_rate: 0,
+        total_volume: 0,
+    };
+    for summary in scan.summaries.values().iter() {
+        stats.total_signals += summary.total_signals;
+        stats.active_signals += summary.active_signals;
+        stats.total_executions += summary.executions;
+        stats.successful_executions += summary.successful_executions;
+        stats.total_volume += summary.total_volume;
+    }
+    if stats.total_executions > 0 {
+        stats.success_rate = (stats.successful_executions as i128 * BASIS_POINTS_DENOMINATOR_I128
+            / stats.total_executions as i128) as u32;
+    }
+    PlatformStatsPage { stats, scan }
+}
+
+/// Providers ranked by `metric`, highest first, one scan step at a time;
+/// see `scan_providers`. Providers without executions are left out. `top` 0
+/// means the default.
+pub fn leaderboard(
+    env: &Env,
+    registry: &Address,
+    metric: ViewerMetric,
+    top: u32,
+    cursor: u64,
+    limit: u32,
+    carry: Map<Address, ProviderSummary>,
+) -> LeaderboardPage {
+    let top = match top {
+        0 => DEFAULT_LEADERBOARD_LIMIT,
+        n => n.min(MAX_LEADERBOARD_LIMIT),
+    };
+    let scan = scan_providers(env, registry, cursor, limit, carry);
+
+    let mut ranked: Vec<ProviderSummary> = Vec::new(env);
+    for summary in scan.summaries.values().iter() {
+        if summary.executions == 0 {
+            continue;
+        }
+        let value = summary.metric(metric);
+        let mut index = ranked.len();
+        for (i, other) in ranked.iter().enumerate() {
+            if value > other.metric(metric) {
+                index = i as u32;
+                break;
+            }
+        }
+        if index < top {
+            ranked.insert(index, summary);
+            if ranked.len() > top {
+                ranked.pop_back();
+            }
+        }
+    }
+    LeaderboardPage { ranked, scan }
+}
@@ -0,0 +1,426 @@
+//! Signal analytics, moved here from the registry.
+//!
+//! Provider analytics read one provider's signals through the registry's
+//! provider index. Trending pairs, global and category analytics span every
+//! signal, so they are resumable scans like the platform totals in
+//! `aggregate`: each call folds one registry page into the tally the caller
+//! passes back in and returns the figures so far with the cursor to resume
+//! from. A result is final once `next_cursor` is `None`.
+
+use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+use stellar_swipe_common::{
+    div_round, AssetPair, RoundingMode, BASIS_POINTS_DENOMINATOR_I128, SECONDS_PER_DAY,
+    SECONDS_PER_HOUR,
+};
+
+use crate::aggregate::{for_each_page, scan_page, SCAN_PAGE_SIZE};
+use crate::registry::{Signal, SignalCategory, SignalRegistryClient, SignalStatus};
+
+/// Fewer signals than this and a provider gets no analytics.
+pub const MIN_SIGNALS_FOR_ANALYTICS: u32 = 10;
+/// Longest look-back accepted by `get_trending_assets` (30 days).
+pub const MAX_TRENDING_WINDOW_HOURS: u64 = 720;
+pub const TRENDING_LIMIT: u32 = 10;
+/// Closed signals a provider needs in a category to be its top provider.
+pub const MIN_CLOSED_FOR_TOP_PROVIDER: u32 = 3;
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderAnalytics {
+    pub provider: Address,
+    pub total_signals: u32,
+    pub avg_roi: i128,
+    pub best_asset_pair: String,
+    pub best_time_of_day: u32,
+    pub win_streak: u32,
+    pub avg_signal_lifetime: u64,
+    pub follower_growth_rate: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrendingAsset {
+    pub asset_pair: AssetPair,
+    /// Signals published on the pair inside the window.
+    pub signal_count: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrendingPage {
+    /// Busiest pairs in `pair_counts`; final once `next_cursor` is `None`.
+    pub trending: Vec<TrendingAsset>,
+    pub pair_counts: Map<AssetPair, u32>,
+    pub next_cursor: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlobalAnalytics {
+    pub total_signals_24h: u32,
+    pub most_traded_pairs: Vec<TrendingAsset>,
+    /// Successful share of adopted, closed signals (bps).
+    pub avg_success_rate: u32,
+    pub total_volume_24h: i128,
+}
+
+/// Running totals behind `GlobalAnalytics`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlobalTally {
+    pub signals_24h: u32,
+    pub volume_24h: i128,
+    pub closed: u32,
+    pub successful: u32,
+    /// Signals per pair over the last 24 hours.
+    pub pair_counts: Map<AssetPair, u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlobalAnalyticsPage {
+    /// Figures over `tally`; final once `next_cursor` is `None`.
+    pub analytics: GlobalAnalytics,
+    pub tally: GlobalTally,
+    pub next_cursor: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CategoryAnalytics {
+    /// Successful share of adopted, closed signals in the category (bps).
+    pub avg_success_rate: u32,
+    /// Average ROI of those signals, in bps.
+    pub avg_roi_bps: i128,
+    /// Signals in the category, any status.
+    pub total_signals: u32,
+    pub total_adopters: u32,
+    /// Highest success rate among providers with at least
+    /// `MIN_CLOSED_FOR_TOP_PROVIDER` closed signals, if any qualify.
+    pub top_provider: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClosedSignals {
+    pub closed: u32,
+    pub successful: u32,
+}
+
+/// Running totals behind `CategoryAnalytics`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CategoryTally {
+    pub total_signals: u32,
+    pub total_adopters: u32,
+    pub closed: u32,
+    pub successful: u32,
+    pub total_roi: i128,
+    pub by_provider: Map<Address, ClosedSignals>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CategoryAnalyticsPage {
+    /// Figures over `tally`; final once `next_cursor` is `None`.
+    pub analytics: CategoryAnalytics,
+    pub tally: CategoryTally,
+    pub next_cursor: Option<u64>,
+}
+
+fn average_roi(signal: &Signal, rounding: RoundingMode) -> i128 {
+    if signal.executions == 0 {
+        0
+    } else {
+        div_round(signal.total_roi, signal.executions as i128, rounding).unwrap_or(0)
+    }
+}
+
+/// Closed by the market and copied by someone: the signals success rates
+/// are taken over.
+fn counts_toward_success(signal: &Signal) -> bool {
+    matches!(signal.status, SignalStatus::Successful | SignalStatus::Failed)
+        && signal.adoption_count > 0
+}
+
+fn success_rate(successful: u32, closed: u32) -> u32 {
+    if closed == 0 {
+        return 0;
+    }
+    (successful as i128 * BASIS_POINTS_DENOMINATOR_I128 / closed as i128) as u32
+}
+
+/// The `TRENDING_LIMIT` busiest pairs, busiest first; ties keep map order.
+fn rank_pairs(env: &Env, pair_counts: &Map<AssetPair, u32>) -> Vec<TrendingAsset> {
+    let mut ranked: Vec<TrendingAsset> = Vec::new(env);
+    for (asset_pair, signal_count) in pair_counts.iter() {
+        let mut index = ranked.len();
+        for (i, other) in ranked.iter().enumerate() {
+            if signal_count > other.signal_count {
+                index = i as u32;
+                break;
+            }
+        }
+        if index < TRENDING_LIMIT {
+            ranked.insert(
+                index,
+                TrendingAsset {
+                    asset_pair,
+                    signal_count,
+                },
+            );
+            if ranked.len() > TRENDING_LIMIT {
+                ranked.pop_back();
+            }
+        }
+    }
+    ranked
+}
+
+fn count_pair(pair_counts: &mut Map<AssetPair, u32>, signal: &Signal) {
+    let count = pair_counts.get(signal.asset_pair.clone()).unwrap_or(0);
+    pair_counts.set(signal.asset_pair.clone(), count + 1);
+}
+
+/// Analytics over all of `provider`'s signals, or `None` below
+/// `MIN_SIGNALS_FOR_ANALYTICS`.
+pub fn provider_analytics(
+    env: &Env,
+    registry: &Address,
+    provider: &Address,
+) -> Option<ProviderAnalytics> {
+    let client = SignalRegistryClient::new(env, registry);
+    let mut signals: Vec<Signal> = Vec::new(env);
+    for_each_page(
+        |cursor| client.get_signals_by_provider(provider, &cursor, &SCAN_PAGE_SIZE),
+        |signal| signals.push_back(signal),
+    );
+    let total = signals.len();
+    if total < MIN_SIGNALS_FOR_ANALYTICS {
+        return None;
+    }
+
+    let rounding = client.get_rounding_mode();
+    let mut roi_sum = 0i128;
+    let mut executed = 0u32;
+    let mut pair_roi: Map<AssetPair, i128> = Map::new(env);
+    let mut hour_roi = [0i128; 24];
+    let mut hour_counts = [0u32; 24];
+    let mut streak = 0u32;
+    let mut win_streak = 0u32;
+    let mut lifetime = 0u64;
+
+    for signal in signals.iter() {
+        if signal.executions > 0 {
+            let roi = average_roi(&signal, rounding);
+            roi_sum = roi_sum.saturating_add(roi);
+            executed += 1;
+            let pair_total = pair_roi.get(signal.asset_pair.clone()).unwrap_or(0);
+            pair_roi.set(signal.asset_pair.clone(), pair_total + roi);
+
+            let hour = ((signal.timestamp % SECONDS_PER_DAY) / SECONDS_PER_HOUR) as usize;
+            hour_roi[hour] =
+                hour_roi[hour].saturating_add(signal.total_roi / signal.executions as i128);
+            hour_counts[hour] += 1;
+        }
+        match signal.status {
+            SignalStatus::Successful => {
+                streak += 1;
+                win_streak = win_streak.max(streak);
+            }
+            SignalStatus::Failed => streak = 0,
+            _ => {}
+        }
+        lifetime = lifetime.saturating_add(signal.expiry.saturating_sub(signal.timestamp));
+    }
+
+    let avg_roi = if executed > 0 {
+        div_round(roi_sum, executed as i128, rounding).unwrap_or(0)
+    } else {
+        0
+    };
+
+    let mut best_asset_pair = String::from_str(env, "");
+    let mut best_pair_roi = i128::MIN;
+    for (pair, roi) in pair_roi.iter() {
+        if roi > best_pair_roi {
+            best_pair_roi = roi;
+            best_asset_pair = pair.to_string(env);
+        }
+    }
+
+    let mut best_time_of_day = 0u32;
+    let mut best_hour_roi = i128::MIN;
+    for hour in 0..24 {
+        if hour_counts[hour] > 0 {
+            let avg = hour_roi[hour] / hour_counts[hour] as i128;
+            if avg > best_hour_roi {
+                best_hour_roi = avg;
+                best_time_of_day = hour as u32;
+            }
+        }
+    }
+
+    Some(ProviderAnalytics {
+        provider: provider.clone(),
+        total_signals: total,
+        avg_roi,
+        best_asset_pair,
+        best_time_of_day,
+        win_streak,
+        avg_signal_lifetime: lifetime / total as u64,
+        // No follower history is kept; the current count stands in for growth.
+        follower_growth_rate: client.get_follower_count(provider) as i128,
+    })
+}
+
+/// Pairs signalled in the last `window_hours` (capped at
+/// `MAX_TRENDING_WINDOW_HOURS`), one scan step at a time.
+pub fn trending_assets(
+    env: &Env,
+    registry: &Address,
+    window_hours: u64,
+    cursor: u64,
+    limit: u32,
+    carry: Map<AssetPair, u32>,
+) -> TrendingPage {
+    let window_hours = window_hours.min(MAX_TRENDING_WINDOW_HOURS);
+    let cutoff = env
+        .ledger()
+        .timestamp()
+        .saturating_sub(window_hours * SECONDS_PER_HOUR);
+    let page = scan_page(env, registry, cursor, limit);
+    let mut pair_counts = carry;
+    for signal in page.signals.iter() {
+        if signal.timestamp >= cutoff {
+            count_pair(&mut pair_counts, &signal);
+        }
+    }
+    TrendingPage {
+        trending: rank_pairs(env, &pair_counts),
+        pair_counts,
+        next_cursor: page.next_cursor,
+    }
+}
+
+/// Platform activity over the last 24 hours and the all-time success rate,
+/// one scan step at a time. Pass `None` as `carry` on the first step.
+pub fn global_analytics(
+    env: &Env,
+    registry: &Address,
+    cursor: u64,
+    limit: u32,
+    carry: Option<GlobalTally>,
+) -> GlobalAnalyticsPage {
+    let cutoff = env.ledger().timestamp().saturating_sub(SECONDS_PER_DAY);
+    let mut tally = carry.unwrap_or_else(|| GlobalTally {
+        signals_24h: 0,
+        volume_24h: 0,
+        closed: 0,
+        successful: 0,
+        pair_counts: Map::new(env),
+    });
+    let page = scan_page(env, registry, cursor, limit);
+    for signal in page.signals.iter() {
+        if signal.timestamp >= cutoff {
+            tally.signals_24h += 1;
+            tally.volume_24h = tally.volume_24h.saturating_add(signal.total_volume);
+            count_pair(&mut tally.pair_counts, &signal);
+        }
+        if counts_toward_success(&signal) {
+            tally.closed += 1;
+            if signal.status == SignalStatus::Successful {
+                tally.successful += 1;
+            }
+        }
+    }
+    GlobalAnalyticsPage {
+        analytics: GlobalAnalytics {
+            total_signals_24h: tally.signals_24h,
+            most_traded_pairs: rank_pairs(env, &tally.pair_counts),
+            avg_success_rate: success_rate(tally.successful, tally.closed),
+            total_volume_24h: tally.volume_24h,
+        },
+        tally,
+        next_cursor: page.next_cursor,
+    }
+}
+
+/// Performance of the signals in `category`, one scan step at a time. Pass
+/// `None` as `carry` on the first step.
+pub fn category_analytics(
+    env: &Env,
+    registry: &Address,
+    category: SignalCategory,
+    cursor: u64,
+    limit: u32,
+    carry: Option<CategoryTally>,
+) -> CategoryAnalyticsPage {
+    let rounding = SignalRegistryClient::new(env, registry).get_rounding_mode();
+    let mut tally = carry.unwrap_or_else(|| CategoryTally {
+        total_signals: 0,
+        total_adopters: 0,
+        closed: 0,
+        successful: 0,
+        total_roi: 0,
+        by_provider: Map::new(env),
+    });
+    let page = scan_page(env, registry, cursor, limit);
+    for signal in page.signals.iter() {
+        if signal.category != category {
+            continue;
+        }
+        tally.total_signals += 1;
+        tally.total_adopters = tally.total_adopters.saturating_add(signal.adoption_count);
+        if !counts_toward_success(&signal) {
+            continue;
+        }
+        let won = signal.status == SignalStatus::Successful;
+        tally.closed += 1;
+        let mut provider = tally
+            .by_provider
+            .get(signal.provider.clone())
+            .unwrap_or(ClosedSignals {
+                closed: 0,
+                successful: 0,
+            });
+        provider.closed += 1;
+        if won {
+            tally.successful += 1;
+            provider.successful += 1;
+        }
+        tally.by_provider.set(signal.provider.clone(), provider);
+        tally.total_roi = tally
+            .total_roi
+            .saturating_add(average_roi(&signal, rounding));
+    }
+
+    let mut top_provider = None;
+    let mut top_rate = 0u32;
+    for (provider, closed) in tally.by_provider.iter() {
+        if closed.closed < MIN_CLOSED_FOR_TOP_PROVIDER {
+            continue;
+        }
+        let rate = success_rate(closed.successful, closed.closed);
+        if rate > top_rate {
+            top_rate = rate;
+            top_provider = Some(provider);
+        }
+    }
+
+    CategoryAnalyticsPage {
+        analytics: CategoryAnalytics {
+            avg_success_rate: success_rate(tally.successful, tally.closed),
+            avg_roi_bps: if tally.closed > 0 {
+                div_round(tally.total_roi, tally.closed as i128, rounding).unwrap_or(0)
+            } else {
+                0
+            },
+            total_signals: tally.total_signals,
+            total_adopters: tally.total_adopters,
+            top_provider,
+        },
+        tally,
+        next_cursor: page.next_cursor,
+    }
+}
@@ -0,0 +1,200 @@
+#![no_std]
+//! Signal viewer contract: read-only aggregation over the signal registry.
+//!
+//! Analytics, leaderboards and provider comparisons are computed here from the
+//! registry's paged getters (`get_signals`, `get_signals_by_provider`) rather
+//! than inside the registry itself, so heavy query code does not grow the
+//! registry WASM and the two contracts can be upgraded independently. The
+//! viewer stores nothing but its admin and the registry address.
+//!
+//! The registry keeps only the analytics that write its own storage: the
+//! daily snapshots behind `get_analytics_history`.
+
+mod aggregate;
+mod analytics;
+mod registry;
+
+#[cfg(test)]
+mod test;
+
+pub use aggregate::{
+    LeaderboardPage, PlatformStats, PlatformStatsPage, ProviderScan, ProviderSummary, ViewerMetric,
+};
+pub use analytics::{
+    CategoryAnalytics, CategoryAnalyticsPage, CategoryTally, ClosedSignals, GlobalAnalytics,
+    GlobalAnalyticsPage, GlobalTally, ProviderAnalytics, TrendingAsset, TrendingPage,
+};
+pub use registry::{SignalCategory, SignalRegistryClient, SignalRegistryView};
+
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Map, Vec};
+use stellar_swipe_common::AssetPair;
+
+// ── Storage keys ──────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Registry,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ViewerError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+}
+
+// ── Contract ──────────────────────────────────────────────────────────────────
+
+#[contract]
+pub struct SignalViewer;
+
+#[contractimpl]
+impl SignalViewer {
+    /// One-time setup. Must be called before any other function.
+    pub fn initialize(env: Env, admin: Address, registry: Address) -> Result<(), ViewerError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(ViewerError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Registry, &registry);
+        Ok(())
+    }
+
+    /// Point the viewer at a different registry deployment. Admin auth required.
+    pub fn set_registry(env: Env, registry: Address) -> Result<(), ViewerError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ViewerError::NotInitialized)?;
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Registry, &registry);
+        Ok(())
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, ViewerError> {
+        Self::registry(&env)
+    }
+
+    /// Totals across every signal in the registry, built over several calls.
+    /// Start with cursor 0 and an empty `carry`, then pass back
+    /// `scan.next_cursor` and `scan.summaries` until `next_cursor` is `None`.
+    /// Each call reads at most `limit` signals (0 or above 50 = 50).
+    pub fn get_platform_stats(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+        carry: Map<Address, ProviderSummary>,
+    ) -> Result<PlatformStatsPage, ViewerError> {
+        Ok(aggregate::platform_stats(&env, &Self::registry(&env)?, cursor, limit, carry))
+    }
+
+    /// Per-provider totals over every signal, resumed like
+    /// `get_platform_stats`.
+    pub fn get_provider_summaries(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+        carry: Map<Address, ProviderSummary>,
+    ) -> Result<ProviderScan, ViewerError> {
+        Ok(aggregate::scan_providers(&env, &Self::registry(&env)?, cursor, limit, carry))
+    }
+
+    /// Aggregate performance of one provider's signals.
+    pub fn get_provider_summary(env: Env, provider: Address) -> Result<ProviderSummary, ViewerError> {
+        Ok(aggregate::provider_summary(&env, &Self::registry(&env)?, &provider))
+    }
+
+    /// Side-by-side summaries for `providers`, in the order given.
+    pub fn compare_providers(
+        env: Env,
+        providers: Vec<Address>,
+    ) -> Result<Vec<ProviderSummary>, ViewerError> {
+        let registry = Self::registry(&env)?;
+        let mut result = Vec::new(&env);
+        for provider in providers.iter() {
+            result.push_back(aggregate::provider_summary(&env, &registry, &provider));
+        }
+        Ok(result)
+    }
+
+    /// The `top` providers ranked by `metric` (0 = default 10, max 50),
+    /// resumed like `get_platform_stats`.
+    pub fn get_leaderboard(
+        env: Env,
+        metric: ViewerMetric,
+        top: u32,
+        cursor: u64,
+        limit: u32,
+        carry: Map<Address, ProviderSummary>,
+    ) -> Result<LeaderboardPage, ViewerError> {
+        Ok(aggregate::leaderboard(&env, &Self::registry(&env)?, metric, top, cursor, limit, carry))
+    }
+
+    /// Analytics over all of `provider`'s signals; `None` below 10 signals.
+    pub fn get_provider_analytics(
+        env: Env,
+        provider: Address,
+    ) -> Result<Option<ProviderAnalytics>, ViewerError> {
+        Ok(analytics::provider_analytics(&env, &Self::registry(&env)?, &provider))
+    }
+
+    /// The 10 most signalled asset pairs in the last `window_hours` (max
+    /// 720), resumed like `get_platform_stats` with `page.pair_counts` as
+    /// the carry.
+    pub fn get_trending_assets(
+        env: Env,
+        window_hours: u64,
+        cursor: u64,
+        limit: u32,
+        carry: Map<AssetPair, u32>,
+    ) -> Result<TrendingPage, ViewerError> {
+        Ok(analytics::trending_assets(
+            &env,
+            &Self::registry(&env)?,
+            window_hours,
+            cursor,
+            limit,
+            carry,
+        ))
+    }
+
+    /// 24h activity and the platform success rate. Start with `None` and
+    /// pass back `page.tally` until `next_cursor` is `None`.
+    pub fn get_global_analytics(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+        carry: Option<GlobalTally>,
+    ) -> Result<GlobalAnalyticsPage, ViewerError> {
+        Ok(analytics::global_analytics(&env, &Self::registry(&env)?, cursor, limit, carry))
+    }
+
+    /// Performance of one signal category, resumed like
+    /// `get_global_analytics`.
+    pub fn get_category_analytics(
+        env: Env,
+        category: SignalCategory,
+        cursor: u64,
+        limit: u32,
+        carry: Option<CategoryTally>,
+    ) -> Result<CategoryAnalyticsPage, ViewerError> {
+        Ok(analytics::category_analytics(
+            &env,
+            &Self::registry(&env)?,
+            category,
+            cursor,
+            limit,
+            carry,
+        ))
+    }
+
+    fn registry(env: &Env) -> Result<Address, ViewerError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Registry)
+            .ok_or(ViewerError::NotInitialized)
+    }
+}
@@ -0,0 +1,101 @@
+//! Cross-contract view of the signal registry.
+//!
+//! Only the registry getters the viewer aggregates over are declared here. The
+//! types mirror the registry's on-chain encoding field for field: a struct
+//! decodes only if every field matches, so any change to
+//! `signal_registry::types::Signal` must be made here too. The `test` module
+//! decodes real registry signals through these types to catch drift.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String, Vec};
+use stellar_swipe_common::{AssetPair, RoundingMode};
+
+#[contractclient(name = "SignalRegistryClient")]
+pub trait SignalRegistryView {
+    /// Signals with ids above `cursor`, in id order.
+    fn get_signals(env: Env, cursor: u64, limit: u32) -> SignalPage;
+    /// A provider's signals, paginated as in `get_signals`.
+    fn get_signals_by_provider(env: Env, provider: Address, cursor: u64, limit: u32) -> SignalPage;
+    fn get_follower_count(env: Env, provider: Address) -> u32;
+    /// How the registry rounds ROI averages.
+    fn get_rounding_mode(env: Env) -> RoundingMode;
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignalAction {
+    Buy,
+    Sell,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignalStatus {
+    Pending,
+    Active,
+    Executed,
+    Expired,
+    Successful,
+    Failed,
+    ProviderDeleted,
+}
+
+#[allow(non_camel_case_types)]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignalCategory {
+    SCALP,
+    SWING,
+    LONG_TERM,
+    ARBITRAGE,
+    PREMIUM,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Signal {
+    pub id: u64,
+    pub provider: Address,
+    pub asset_pair: AssetPair,
+    pub action: SignalAction,
+    pub price: i128,
+    pub rationale: String,
+    pub timestamp: u64,
+    pub expiry: u64,
+    pub status: SignalStatus,
+    pub executions: u32,
+    pub successful_executions: u32,
+    pub total_volume: i128,
+    pub total_roi: i128,
+    pub category: SignalCategory,
+    pub tags: Vec<String>,
+    pub risk_level: RiskLevel,
+    pub is_collaborative: bool,
+    pub submitted_at: u64,
+    pub rationale_hash: String,
+    pub confidence: u32,
+    pub adoption_count: u32,
+    pub ai_validation_score: Option<u32>,
+    pub avg_copier_roi_bps: i32,
+    pub copier_closed_count: u32,
+    pub warning_emitted: bool,
+    pub benchmark_return_bps: Option<i64>,
+    pub alpha_bps: Option<i64>,
+    pub stop_loss: Option<i128>,
+    pub take_profit: Option<i128>,
+    pub premium: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignalPage {
+    pub signals: Vec<Signal>,
+    pub next_cursor: Option<u64>,
+}
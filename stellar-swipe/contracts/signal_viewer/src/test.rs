@@ -0,0 +1,232 @@
+use super::*;
+use signal_registry::{
+    RiskLevel as RegistryRiskLevel, SignalAction as RegistrySignalAction,
+    SignalCategory as RegistrySignalCategory, SignalRegistry,
+    SignalRegistryClient as RegistryClient,
+};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, Map, String, Vec};
+use stellar_swipe_common::AssetPair;
+
+fn setup() -> (Env, SignalViewerClient<'static>, RegistryClient<'static>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    let admin = Address::generate(&env);
+
+    #[allow(deprecated)]
+    let registry_id = env.register_contract(None, SignalRegistry);
+    let registry = RegistryClient::new(&env, &registry_id);
+    registry.initialize(&admin);
+    // The viewer only reads signals; their providers don't need to stake.
+    registry.set_min_stake(&admin, &0);
+
+    let viewer_id = env.register(SignalViewer, ());
+    let viewer = SignalViewerClient::new(&env, &viewer_id);
+    viewer.initialize(&admin, &registry_id);
+
+    (env, viewer, registry)
+}
+
+fn create_signal(env: &Env, registry: &RegistryClient, provider: &Address) -> u64 {
+    create_signal_on(env, registry, provider, "XLM/USDC")
+}
+
+fn create_signal_on(env: &Env, registry: &RegistryClient, provider: &Address, pair: &str) -> u64 {
+    registry.create_signal(
+        provider,
+        &String::from_str(env, pair),
+        &RegistrySignalAction::Buy,
+        &1_000_000,
+        &String::from_str(env, "Rationale"),
+        &(env.ledger().timestamp() + 86_400),
+        &RegistrySignalCategory::SWING,
+        &Vec::new(env),
+        &RegistryRiskLevel::Medium,
+    )
+}
+
+#[test]
+fn aggregates_registry_signals() {
+    let (env, viewer, registry) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let executor = Address::generate(&env);
+
+    let a1 = create_signal(&env, &registry, &alice);
+    let a2 = create_signal(&env, &registry, &alice);
+    let b1 = create_signal(&env, &registry, &bob);
+    // Premium signals reach the viewer redacted but must still decode.
+    registry.set_signal_premium(&alice, &a2, &true);
+
+    // +10% on 1_000 for alice, +20% on 5_000 for bob.
+    registry.record_trade_execution(&executor, &a1, &100, &110, &1_000);
+    registry.record_trade_execution(&executor, &b1, &100, &120, &5_000);
+
+    let page = viewer.get_platform_stats(&0, &0, &Map::new(&env));
+    assert_eq!(page.scan.next_cursor, None);
+    let stats = page.stats;
+    assert_eq!(stats.total_signals, 3);
+    assert_eq!(stats.provider_count, 2);
+    assert_eq!(stats.total_executions, 2);
+    assert_eq!(stats.total_volume, 6_000);
+
+    let summary = viewer.get_provider_summary(&alice);
+    assert_eq!(summary.total_signals, 2);
+    assert_eq!(summary.executions, 1);
+    assert_eq!(summary.avg_roi, 1_000);
+
+    let by_volume = viewer
+        .get_leaderboard(&ViewerMetric::Volume, &0, &0, &0, &Map::new(&env))
+        .ranked;
+    assert_eq!(by_volume.len(), 2);
+    assert_eq!(by_volume.get(0).unwrap().provider, bob);
+    assert_eq!(by_volume.get(1).unwrap().provider, alice);
+
+    let stranger = Address::generate(&env);
+    let compared = viewer.compare_providers(&vec![&env, alice.clone(), stranger.clone()]);
+    assert_eq!(compared.get(0).unwrap().total_volume, 1_000);
+    assert_eq!(compared.get(1).unwrap().provider, stranger);
+    assert_eq!(compared.get(1).unwrap().total_signals, 0);
+}
+
+#[test]
+fn platform_stats_resume_across_calls_within_default_budget() {
+    let (env, viewer, registry) = setup();
+    // Five signals per provider, and an executor each, stay within the
+    // default tier and rate limits.
+    let mut provider = Address::generate(&env);
+    let mut executor = Address::generate(&env);
+    for i in 0..120u32 {
+        if i % 5 == 0 {
+            provider = Address::generate(&env);
+            executor = Address::generate(&env);
+        }
+        let id = create_signal(&env, &registry, &provider);
+        if i % 3 == 0 {
+            registry.record_trade_execution(&executor, &id, &100, &110, &1_000);
+        }
+    }
+
+    let mut cursor = 0;
+    let mut carry = Map::new(&env);
+    let mut steps = 0;
+    let stats = loop {
+        // Every step has to fit a single invocation's default budget.
+        env.cost_estimate().budget().reset_default();
+        let page = viewer.get_platform_stats(&cursor, &0, &carry);
+        steps += 1;
+        match page.scan.next_cursor {
+            Some(next) => {
+                cursor = next;
+                carry = page.scan.summaries;
+            }
+            None => break page.stats,
+        }
+    };
+    assert_eq!(steps, 3);
+    assert_eq!(stats.total_signals, 120);
+    assert_eq!(stats.provider_count, 24);
+    assert_eq!(stats.total_executions, 40);
+    assert_eq!(stats.total_volume, 40_000);
+
+    // The leaderboard resumes the same way; a short first step only ranks
+    // what it has seen.
+    env.cost_estimate().budget().reset_default();
+    let first = viewer.get_leaderboard(&ViewerMetric::Executions, &0, &0, &10, &Map::new(&env));
+    assert_eq!(first.ranked.len(), 2);
+    assert_eq!(first.ranked.get(0).unwrap().executions, 2);
+    assert_eq!(first.scan.next_cursor, Some(10));
+}
+
+#[test]
+fn initialize_once_and_require_it() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let registry = Address::generate(&env);
+    let viewer = SignalViewerClient::new(&env, &env.register(SignalViewer, ()));
+
+    assert_eq!(viewer.try_set_registry(&registry), Err(Ok(ViewerError::NotInitialized)));
+    assert_eq!(
+        viewer.try_get_provider_summary(&admin),
+        Err(Ok(ViewerError::NotInitialized))
+    );
+
+    viewer.initialize(&admin, &registry);
+    assert_eq!(
+        viewer.try_initialize(&admin, &registry),
+        Err(Ok(ViewerError::AlreadyInitialized))
+    );
+    assert_eq!(viewer.get_registry(), registry);
+}
+
+#[test]
+fn signal_analytics() {
+    let (env, viewer, registry) = setup();
+    let provider = Address::generate(&env);
+    for pair in ["XLM/USDC", "XLM/USDC", "BTC/USDC"] {
+        create_signal_on(&env, &registry, &provider, pair);
+    }
+    let xlm = AssetPair::parse(&env, &String::from_str(&env, "XLM/USDC")).unwrap();
+
+    let trending = viewer.get_trending_assets(&24, &0, &0, &Map::new(&env));
+    assert_eq!(trending.next_cursor, None);
+    assert_eq!(trending.trending.len(), 2);
+    assert_eq!(trending.trending.get(0).unwrap().asset_pair, xlm);
+    assert_eq!(trending.trending.get(0).unwrap().signal_count, 2);
+    // Oversized windows are clamped rather than overflowing.
+    assert_eq!(
+        viewer.get_trending_assets(&u64::MAX, &0, &0, &Map::new(&env)).trending.len(),
+        2
+    );
+
+    // Two steps of two signals add up to the same figures as one.
+    let first = viewer.get_global_analytics(&0, &2, &None);
+    assert_eq!(first.next_cursor, Some(2));
+    assert_eq!(first.analytics.total_signals_24h, 2);
+    let global = viewer.get_global_analytics(&2, &2, &Some(first.tally));
+    assert_eq!(global.next_cursor, None);
+    assert_eq!(global.analytics.total_signals_24h, 3);
+    assert_eq!(global.analytics.most_traded_pairs, trending.trending);
+    assert_eq!(global.analytics.avg_success_rate, 0);
+
+    let swing = viewer
+        .get_category_analytics(&SignalCategory::SWING, &0, &0, &None)
+        .analytics;
+    assert_eq!(swing.total_signals, 3);
+    assert_eq!(swing.top_provider, None);
+    let scalp = viewer
+        .get_category_analytics(&SignalCategory::SCALP, &0, &0, &None)
+        .analytics;
+    assert_eq!(scalp.total_signals, 0);
+
+    // Too few signals for per-provider analytics.
+    assert_eq!(viewer.get_provider_analytics(&provider), None);
+}
+
+#[test]
+fn provider_analytics_read_follower_count_and_rounding() {
+    let (env, viewer, registry) = setup();
+    let provider = Address::generate(&env);
+    // Let the first batch expire so the provider stays under its active
+    // signal cap.
+    for _ in 0..5 {
+        create_signal(&env, &registry, &provider);
+    }
+    env.ledger().with_mut(|l| l.timestamp += 2 * 86_400);
+    registry.expire_signals(&50);
+    let mut last = 0;
+    for _ in 0..5 {
+        last = create_signal(&env, &registry, &provider);
+    }
+    registry.record_trade_execution(&Address::generate(&env), &last, &100, &110, &1_000);
+    registry.follow_provider(&Address::generate(&env), &provider);
+
+    let analytics = viewer.get_provider_analytics(&provider).unwrap();
+    assert_eq!(analytics.total_signals, 10);
+    assert_eq!(analytics.avg_roi, 1_000);
+    assert_eq!(analytics.best_asset_pair, String::from_str(&env, "XLM/USDC"));
+    assert_eq!(analytics.avg_signal_lifetime, 86_400);
+    assert_eq!(analytics.follower_growth_rate, 1);
+}
@@ -1,9 +1,11 @@
 #![no_std]
 
 pub mod migration;
+pub mod yield_router;
 
 use migration::{MigrationKey, StakeInfoV2};
-use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, Symbol};
+use yield_router::{YieldConfig, YieldPosition};
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, token, Address, Env, Symbol};
 
 /// Temporary-storage key for the reentrancy lock on `withdraw_stake`.
 const EXECUTION_LOCK: &str = "WithdrawLock";
@@ -62,16 +64,24 @@ pub enum StorageKey {
     StakeBelowMinSince(Address),
 }
 
-#[contracttype]
-#[derive(Debug, PartialEq)]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum StakeVaultError {
-    NotInitialized,
-    Unauthorized,
-    NoStake,
-    StakeLocked,
-    ReentrancyDetected,
+    NotInitialized = 1,
+    Unauthorized = 2,
+    NoStake = 3,
+    StakeLocked = 4,
+    ReentrancyDetected = 5,
     /// Provider stake is below minimum and grace period has expired.
-    StakeBelowMinimum,
+    StakeBelowMinimum = 6,
+    /// Yield caps out of range, or adapter swapped with open positions.
+    InvalidYieldConfig = 7,
+    YieldNotConfigured = 8,
+    /// Staker has not opted in to yield routing.
+    YieldNotOptedIn = 9,
+    /// The adapter holds nothing against outstanding shares, so new shares
+    /// can't be priced.
+    YieldAdapterDepleted = 10,
 }
 
 #[contract]
@@ -239,7 +249,7 @@ impl StakeVaultContract {
             .get(&MigrationKey::StakesV2)
             .unwrap_or_else(|| soroban_sdk::Map::new(env));
 
-        let info = stakes
+        let mut info = stakes
             .get(staker.clone())
             .ok_or(StakeVaultError::NoStake)?;
 
@@ -252,6 +262,9 @@ impl StakeVaultContract {
             return Err(StakeVaultError::StakeLocked);
         }
 
+        // Pull any routed stake back, settling its yield into the balance.
+        Self::unwind_yield(env, staker, &mut info);
+
         let amount = info.balance;
         let old_tier = stake_tier_for_amount(info.balance);
         let new_tier = stake_tier_for_amount(0);
@@ -275,6 +288,7 @@ impl StakeVaultContract {
             staker,
             &amount,
         );
+        emit_provider_tier_change(env, staker, old_tier, new_tier, 0);
 
         Ok(amount)
     }
@@ -298,6 +312,7 @@ impl StakeVaultContract {
             .unwrap_or_else(|| soroban_sdk::Map::new(&env));
 
         let mut info = stakes.get(provider.clone()).ok_or(StakeVaultError::NoStake)?;
+        Self::unwind_yield(&env, &provider, &mut info);
 
         if amount <= 0 || amount > info.balance {
             return Err(StakeVaultError::NoStake);
@@ -324,13 +339,82 @@ impl StakeVaultContract {
 
         token::Client::new(&env, &token).transfer(
             &env.current_contract_address(),
-            &env.current_contract_address(),
+            env.current_contract_address(),
             &amount,
         );
 
         Ok(())
     }
 
+    /// Admin (governance): whitelist the yield adapter and set routing caps.
+    pub fn set_yield_config(env: Env, config: YieldConfig) -> Result<(), StakeVaultError> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&StorageKey::Admin)
+            .ok_or(StakeVaultError::NotInitialized)?;
+        admin.require_auth();
+        yield_router::set_config(&env, &config)
+    }
+
+    pub fn get_yield_config(env: Env) -> Option<YieldConfig> {
+        yield_router::get_config(&env)
+    }
+
+    /// Opt in to (or out of) yield routing. Opting out pulls any routed stake
+    /// back into the vault.
+    pub fn set_yield_opt_in(env: Env, staker: Address, enabled: bool) {
+        staker.require_auth();
+        yield_router::set_opt_in(&env, &staker, enabled);
+        if !enabled {
+            let mut stakes = Self::load_stakes(&env);
+            if let Some(mut info) = stakes.get(staker.clone()) {
+                Self::unwind_yield(&env, &staker, &mut info);
+                stakes.set(staker, info);
+                env.storage()
+                    .persistent()
+                    .set(&MigrationKey::StakesV2, &stakes);
+            }
+        }
+    }
+
+    pub fn is_yield_opted_in(env: Env, staker: Address) -> bool {
+        yield_router::is_opted_in(&env, &staker)
+    }
+
+    /// Route an opted-in staker's idle stake to the adapter, up to the caps.
+    /// Permissionless so keepers can sweep. Returns the amount routed.
+    pub fn route_stake_to_yield(env: Env, staker: Address) -> Result<i128, StakeVaultError> {
+        let balance = Self::get_stake(env.clone(), staker.clone());
+        yield_router::route(&env, &staker, balance)
+    }
+
+    pub fn get_yield_position(env: Env, staker: Address) -> YieldPosition {
+        yield_router::get_position(&env, &staker)
+    }
+
+    /// Current value of the staker's routed stake, including accrued yield.
+    pub fn get_yield_value(env: Env, staker: Address) -> i128 {
+        yield_router::position_value(&env, &staker)
+    }
+
+    fn load_stakes(env: &Env) -> soroban_sdk::Map<Address, StakeInfoV2> {
+        env.storage()
+            .persistent()
+            .get(&MigrationKey::StakesV2)
+            .unwrap_or_else(|| soroban_sdk::Map::new(env))
+    }
+
+    /// Redeem the staker's yield position and fold the gain or loss into
+    /// `info.balance`. The caller persists `info`.
+    fn unwind_yield(env: &Env, staker: &Address, info: &mut StakeInfoV2) {
+        let delta = yield_router::redeem_all(env, staker);
+        if delta != 0 {
+            info.balance = (info.balance + delta).max(0);
+            info.last_updated = env.ledger().timestamp();
+        }
+    }
+
     /// Read the current stake balance for `staker` (0 if no record).
     pub fn get_stake(env: Env, staker: Address) -> i128 {
         let stakes: soroban_sdk::Map<Address, StakeInfoV2> = env
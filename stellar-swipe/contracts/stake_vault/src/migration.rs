@@ -243,7 +243,7 @@ mod tests {
         let env = setup();
         let contract_addr = env.register(TestContract, ());
 
-        env.as_contract(&contract_addr, || {
+        let (admin, providers) = env.as_contract(&contract_addr, || {
             let admin = Address::generate(&env);
             let mut v1: Map<Address, i128> = Map::new(&env);
 
@@ -260,7 +260,11 @@ mod tests {
             let r1 = migrate_stakes_v1_to_v2(&env, &admin, 30).unwrap();
             assert_eq!(r1.migrated_this_batch, 30);
             assert!(!r1.complete);
+            (admin, providers)
+        });
 
+        // Each batch is its own invocation, so the admin authorises it afresh.
+        env.as_contract(&contract_addr, || {
             // Batch 2: migrate remaining 20
             let r2 = migrate_stakes_v1_to_v2(&env, &admin, 30).unwrap();
             assert_eq!(r2.migrated_this_batch, 20);
@@ -281,7 +285,7 @@ mod tests {
         let env = setup();
         let contract_addr = env.register(TestContract, ());
 
-        env.as_contract(&contract_addr, || {
+        let admin = env.as_contract(&contract_addr, || {
             let admin = Address::generate(&env);
             let mut v1: Map<Address, i128> = Map::new(&env);
             let p = Address::generate(&env);
@@ -289,7 +293,10 @@ mod tests {
             seed_v1_stakes(&env, v1);
 
             migrate_stakes_v1_to_v2(&env, &admin, 10).unwrap();
+            admin
+        });
 
+        env.as_contract(&contract_addr, || {
             // Second call should return AlreadyComplete
             let err = migrate_stakes_v1_to_v2(&env, &admin, 10).unwrap_err();
             assert_eq!(err, MigrationError::AlreadyComplete);
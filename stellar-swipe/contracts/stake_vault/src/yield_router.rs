@@ -0,0 +1,240 @@
+//! Optional yield routing for idle stake.
+//!
+//! Governance whitelists a single yield adapter. Stakers who opt in can have
+//! part of their locked stake deposited there; the vault keeps per-staker share
+//! accounting so accrued yield (or loss) is settled when the position unwinds.
+//!
+//! Two caps bound the exposure:
+//! - `max_routed_bps`: share of each staker's stake that may leave the vault.
+//!   The remainder is an instant-withdraw buffer that also covers slashing.
+//! - `max_total_routed`: total principal the vault may hold in the adapter.
+//!
+//! Only provider stake is routed. The escrow auto_trade keeps for open orders
+//! is bookkeeping against user balances, not tokens held by a contract, so
+//! there is no idle escrowed balance to deposit.
+
+use soroban_sdk::{contractclient, contracttype, token, Address, Env, Symbol};
+
+use crate::{StakeVaultError, StorageKey};
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Interface a whitelisted yield protocol exposes to the vault.
+///
+/// The vault transfers the underlying token to the adapter before calling
+/// `deposit`; `withdraw` must transfer the underlying back to `owner`.
+#[contractclient(name = "YieldAdapterClient")]
+pub trait YieldAdapter {
+    /// Credit `amount` of underlying, already transferred in, to `owner`.
+    fn deposit(env: Env, owner: Address, amount: i128);
+    /// Return `amount` of underlying held for `owner` to `owner`.
+    fn withdraw(env: Env, owner: Address, amount: i128);
+    /// Underlying value held for `owner`, including accrued yield.
+    fn balance(env: Env, owner: Address) -> i128;
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YieldConfig {
+    pub adapter: Address,
+    /// Max share of a staker's stake routed to the adapter, in bps.
+    pub max_routed_bps: u32,
+    /// Max total principal routed across all stakers.
+    pub max_total_routed: i128,
+}
+
+/// A staker's claim on the vault's adapter balance.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct YieldPosition {
+    pub shares: i128,
+    /// Stake moved into the adapter, excluding yield.
+    pub principal: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum YieldKey {
+    Config,
+    OptIn(Address),
+    Position(Address),
+    TotalShares,
+    TotalPrincipal,
+}
+
+pub fn get_config(env: &Env) -> Option<YieldConfig> {
+    env.storage().instance().get(&YieldKey::Config)
+}
+
+/// Install or update the adapter whitelist and caps. The adapter can only be
+/// swapped once every position has been unwound.
+pub fn set_config(env: &Env, config: &YieldConfig) -> Result<(), StakeVaultError> {
+    if config.max_routed_bps as i128 > BPS_DENOMINATOR || config.max_total_routed < 0 {
+        return Err(StakeVaultError::InvalidYieldConfig);
+    }
+    if let Some(current) = get_config(env) {
+        if current.adapter != config.adapter && total_shares(env) > 0 {
+            return Err(StakeVaultError::InvalidYieldConfig);
+        }
+    }
+    env.storage().instance().set(&YieldKey::Config, config);
+    Ok(())
+}
+
+pub fn is_opted_in(env: &Env, staker: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&YieldKey::OptIn(staker.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_opt_in(env: &Env, staker: &Address, enabled: bool) {
+    let key = YieldKey::OptIn(staker.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_position(env: &Env, staker: &Address) -> YieldPosition {
+    env.storage()
+        .persistent()
+        .get(&YieldKey::Position(staker.clone()))
+        .unwrap_or_default()
+}
+
+fn total_shares(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::TotalShares)
+        .unwrap_or(0)
+}
+
+fn total_principal(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::TotalPrincipal)
+        .unwrap_or(0)
+}
+
+fn save_totals(env: &Env, shares: i128, principal: i128) {
+    env.storage().instance().set(&YieldKey::TotalShares, &shares);
+    env.storage()
+        .instance()
+        .set(&YieldKey::TotalPrincipal, &principal);
+}
+
+fn adapter_value(env: &Env, config: &YieldConfig) -> i128 {
+    YieldAdapterClient::new(env, &config.adapter).balance(&env.current_contract_address())
+}
+
+/// Current underlying value of the staker's position.
+pub fn position_value(env: &Env, staker: &Address) -> i128 {
+    let position = get_position(env, staker);
+    let total = total_shares(env);
+    if position.shares == 0 || total == 0 {
+        return 0;
+    }
+    match get_config(env) {
+        Some(config) => position.shares * adapter_value(env, &config) / total,
+        None => 0,
+    }
+}
+
+/// Move as much of `stake_balance` into the adapter as the caps allow.
+/// Returns the amount routed.
+pub fn route(env: &Env, staker: &Address, stake_balance: i128) -> Result<i128, StakeVaultError> {
+    let config = get_config(env).ok_or(StakeVaultError::YieldNotConfigured)?;
+    if !is_opted_in(env, staker) {
+        return Err(StakeVaultError::YieldNotOptedIn);
+    }
+
+    let mut position = get_position(env, staker);
+    let staker_room =
+        stake_balance * config.max_routed_bps as i128 / BPS_DENOMINATOR - position.principal;
+    let vault_room = config.max_total_routed - total_principal(env);
+    let amount = staker_room.min(vault_room);
+    if amount <= 0 {
+        return Ok(0);
+    }
+
+    let value_before = adapter_value(env, &config);
+    let shares_before = total_shares(env);
+    // With shares outstanding but nothing behind them, any share price would
+    // hand part of the new stake to the existing holders.
+    if shares_before > 0 && value_before <= 0 {
+        return Err(StakeVaultError::YieldAdapterDepleted);
+    }
+    let shares = if shares_before == 0 {
+        amount
+    } else {
+        amount * shares_before / value_before
+    };
+
+    let token: Address = env
+        .storage()
+        .instance()
+        .get(&StorageKey::StakeToken)
+        .ok_or(StakeVaultError::NotInitialized)?;
+    let vault = env.current_contract_address();
+    token::Client::new(env, &token).transfer(&vault, &config.adapter, &amount);
+    YieldAdapterClient::new(env, &config.adapter).deposit(&vault, &amount);
+
+    position.shares += shares;
+    position.principal += amount;
+    env.storage()
+        .persistent()
+        .set(&YieldKey::Position(staker.clone()), &position);
+    save_totals(
+        env,
+        shares_before + shares,
+        total_principal(env) + amount,
+    );
+
+    env.events().publish(
+        (
+            Symbol::new(env, "stake_vault"),
+            Symbol::new(env, "yield_routed"),
+        ),
+        (staker.clone(), amount, shares),
+    );
+    Ok(amount)
+}
+
+/// Pull the staker's whole position back into the vault. Returns the realised
+/// gain (negative on loss) to apply to the staker's stake balance.
+pub fn redeem_all(env: &Env, staker: &Address) -> i128 {
+    let position = get_position(env, staker);
+    if position.shares == 0 {
+        return 0;
+    }
+    let config = match get_config(env) {
+        Some(config) => config,
+        None => return 0,
+    };
+
+    let value = position_value(env, staker);
+    if value > 0 {
+        YieldAdapterClient::new(env, &config.adapter)
+            .withdraw(&env.current_contract_address(), &value);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&YieldKey::Position(staker.clone()));
+    save_totals(
+        env,
+        total_shares(env) - position.shares,
+        total_principal(env) - position.principal,
+    );
+
+    env.events().publish(
+        (
+            Symbol::new(env, "stake_vault"),
+            Symbol::new(env, "yield_redeemed"),
+        ),
+        (staker.clone(), value, position.principal),
+    );
+    value - position.principal
+}
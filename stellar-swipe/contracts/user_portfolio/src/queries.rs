@@ -75,7 +75,7 @@ pub fn compute_get_pnl(env: &Env, user: Address) -> PnlSummary {
         .and_then(|price| {
             validate_freshness(env, &price)
                 .ok()
-                .map(|_| oracle_price_to_i128(&price))
+                .and_then(|_| oracle_price_to_i128(&price))
         });
 
     let unrealized_pnl: Option<i128> = if !has_open {